@@ -108,6 +108,9 @@ fn generate_bindings(include_dir: &Path) {
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         // Set `size_t` to be translated to `usize` for win32 compatibility.
         .size_t_is_usize(true)
+        // Only refer to `core`, so that the bindings compile in `no_std` crates
+        .use_core()
+        .ctypes_prefix("::core::ffi")
         // Format using rustfmt
         .rustfmt_bindings(true)
         .rustified_enum("*")
@@ -174,7 +174,7 @@ pub const _BITS_TYPES_LOCALE_T_H: u32 = 1;
 pub const _BITS_TYPES___LOCALE_T_H: u32 = 1;
 pub const _STRINGS_H: u32 = 1;
 pub const ORT_API_VERSION: u32 = 15;
-pub type wchar_t = ::std::os::raw::c_int;
+pub type wchar_t = ::core::ffi::c_int;
 pub type _Float32 = f32;
 pub type _Float64 = f64;
 pub type _Float32x = f64;
@@ -182,27 +182,27 @@ pub type _Float64x = u128;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct div_t {
-    pub quot: ::std::os::raw::c_int,
-    pub rem: ::std::os::raw::c_int,
+    pub quot: ::core::ffi::c_int,
+    pub rem: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_div_t() {
     assert_eq!(
-        ::std::mem::size_of::<div_t>(),
+        ::core::mem::size_of::<div_t>(),
         8usize,
         concat!("Size of: ", stringify!(div_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<div_t>(),
+        ::core::mem::align_of::<div_t>(),
         4usize,
         concat!("Alignment of ", stringify!(div_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<div_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<div_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -217,9 +217,9 @@ fn bindgen_test_layout_div_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<div_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<div_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -235,27 +235,27 @@ fn bindgen_test_layout_div_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ldiv_t {
-    pub quot: ::std::os::raw::c_long,
-    pub rem: ::std::os::raw::c_long,
+    pub quot: ::core::ffi::c_long,
+    pub rem: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_ldiv_t() {
     assert_eq!(
-        ::std::mem::size_of::<ldiv_t>(),
+        ::core::mem::size_of::<ldiv_t>(),
         16usize,
         concat!("Size of: ", stringify!(ldiv_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<ldiv_t>(),
+        ::core::mem::align_of::<ldiv_t>(),
         8usize,
         concat!("Alignment of ", stringify!(ldiv_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<ldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<ldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -270,9 +270,9 @@ fn bindgen_test_layout_ldiv_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<ldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<ldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -288,27 +288,27 @@ fn bindgen_test_layout_ldiv_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lldiv_t {
-    pub quot: ::std::os::raw::c_longlong,
-    pub rem: ::std::os::raw::c_longlong,
+    pub quot: ::core::ffi::c_longlong,
+    pub rem: ::core::ffi::c_longlong,
 }
 #[test]
 fn bindgen_test_layout_lldiv_t() {
     assert_eq!(
-        ::std::mem::size_of::<lldiv_t>(),
+        ::core::mem::size_of::<lldiv_t>(),
         16usize,
         concat!("Size of: ", stringify!(lldiv_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<lldiv_t>(),
+        ::core::mem::align_of::<lldiv_t>(),
         8usize,
         concat!("Alignment of ", stringify!(lldiv_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<lldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<lldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -323,9 +323,9 @@ fn bindgen_test_layout_lldiv_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<lldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<lldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -342,95 +342,95 @@ extern "C" {
     pub fn __ctype_get_mb_cur_max() -> usize;
 }
 extern "C" {
-    pub fn atof(__nptr: *const ::std::os::raw::c_char) -> f64;
+    pub fn atof(__nptr: *const ::core::ffi::c_char) -> f64;
 }
 extern "C" {
-    pub fn atoi(__nptr: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn atoi(__nptr: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn atol(__nptr: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+    pub fn atol(__nptr: *const ::core::ffi::c_char) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn atoll(__nptr: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
+    pub fn atoll(__nptr: *const ::core::ffi::c_char) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn strtod(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
     ) -> f64;
 }
 extern "C" {
     pub fn strtof(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
     ) -> f32;
 }
 extern "C" {
     pub fn strtold(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
     ) -> u128;
 }
 extern "C" {
     pub fn strtol(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_long;
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn strtoul(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strtoq(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn strtouq(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn strtoll(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn strtoull(
-        __nptr: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        __nptr: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
-    pub fn l64a(__n: ::std::os::raw::c_long) -> *mut ::std::os::raw::c_char;
+    pub fn l64a(__n: ::core::ffi::c_long) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn a64l(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+    pub fn a64l(__s: *const ::core::ffi::c_char) -> ::core::ffi::c_long;
 }
-pub type __u_char = ::std::os::raw::c_uchar;
-pub type __u_short = ::std::os::raw::c_ushort;
-pub type __u_int = ::std::os::raw::c_uint;
-pub type __u_long = ::std::os::raw::c_ulong;
-pub type __int8_t = ::std::os::raw::c_schar;
-pub type __uint8_t = ::std::os::raw::c_uchar;
-pub type __int16_t = ::std::os::raw::c_short;
-pub type __uint16_t = ::std::os::raw::c_ushort;
-pub type __int32_t = ::std::os::raw::c_int;
-pub type __uint32_t = ::std::os::raw::c_uint;
-pub type __int64_t = ::std::os::raw::c_long;
-pub type __uint64_t = ::std::os::raw::c_ulong;
+pub type __u_char = ::core::ffi::c_uchar;
+pub type __u_short = ::core::ffi::c_ushort;
+pub type __u_int = ::core::ffi::c_uint;
+pub type __u_long = ::core::ffi::c_ulong;
+pub type __int8_t = ::core::ffi::c_schar;
+pub type __uint8_t = ::core::ffi::c_uchar;
+pub type __int16_t = ::core::ffi::c_short;
+pub type __uint16_t = ::core::ffi::c_ushort;
+pub type __int32_t = ::core::ffi::c_int;
+pub type __uint32_t = ::core::ffi::c_uint;
+pub type __int64_t = ::core::ffi::c_long;
+pub type __uint64_t = ::core::ffi::c_ulong;
 pub type __int_least8_t = __int8_t;
 pub type __uint_least8_t = __uint8_t;
 pub type __int_least16_t = __int16_t;
@@ -439,43 +439,43 @@ pub type __int_least32_t = __int32_t;
 pub type __uint_least32_t = __uint32_t;
 pub type __int_least64_t = __int64_t;
 pub type __uint_least64_t = __uint64_t;
-pub type __quad_t = ::std::os::raw::c_long;
-pub type __u_quad_t = ::std::os::raw::c_ulong;
-pub type __intmax_t = ::std::os::raw::c_long;
-pub type __uintmax_t = ::std::os::raw::c_ulong;
-pub type __dev_t = ::std::os::raw::c_ulong;
-pub type __uid_t = ::std::os::raw::c_uint;
-pub type __gid_t = ::std::os::raw::c_uint;
-pub type __ino_t = ::std::os::raw::c_ulong;
-pub type __ino64_t = ::std::os::raw::c_ulong;
-pub type __mode_t = ::std::os::raw::c_uint;
-pub type __nlink_t = ::std::os::raw::c_ulong;
-pub type __off_t = ::std::os::raw::c_long;
-pub type __off64_t = ::std::os::raw::c_long;
-pub type __pid_t = ::std::os::raw::c_int;
+pub type __quad_t = ::core::ffi::c_long;
+pub type __u_quad_t = ::core::ffi::c_ulong;
+pub type __intmax_t = ::core::ffi::c_long;
+pub type __uintmax_t = ::core::ffi::c_ulong;
+pub type __dev_t = ::core::ffi::c_ulong;
+pub type __uid_t = ::core::ffi::c_uint;
+pub type __gid_t = ::core::ffi::c_uint;
+pub type __ino_t = ::core::ffi::c_ulong;
+pub type __ino64_t = ::core::ffi::c_ulong;
+pub type __mode_t = ::core::ffi::c_uint;
+pub type __nlink_t = ::core::ffi::c_ulong;
+pub type __off_t = ::core::ffi::c_long;
+pub type __off64_t = ::core::ffi::c_long;
+pub type __pid_t = ::core::ffi::c_int;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __fsid_t {
-    pub __val: [::std::os::raw::c_int; 2usize],
+    pub __val: [::core::ffi::c_int; 2usize],
 }
 #[test]
 fn bindgen_test_layout___fsid_t() {
     assert_eq!(
-        ::std::mem::size_of::<__fsid_t>(),
+        ::core::mem::size_of::<__fsid_t>(),
         8usize,
         concat!("Size of: ", stringify!(__fsid_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<__fsid_t>(),
+        ::core::mem::align_of::<__fsid_t>(),
         4usize,
         concat!("Alignment of ", stringify!(__fsid_t))
     );
     fn test_field___val() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__fsid_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__fsid_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__val) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__val) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -488,34 +488,34 @@ fn bindgen_test_layout___fsid_t() {
     }
     test_field___val();
 }
-pub type __clock_t = ::std::os::raw::c_long;
-pub type __rlim_t = ::std::os::raw::c_ulong;
-pub type __rlim64_t = ::std::os::raw::c_ulong;
-pub type __id_t = ::std::os::raw::c_uint;
-pub type __time_t = ::std::os::raw::c_long;
-pub type __useconds_t = ::std::os::raw::c_uint;
-pub type __suseconds_t = ::std::os::raw::c_long;
-pub type __suseconds64_t = ::std::os::raw::c_long;
-pub type __daddr_t = ::std::os::raw::c_int;
-pub type __key_t = ::std::os::raw::c_int;
-pub type __clockid_t = ::std::os::raw::c_int;
-pub type __timer_t = *mut ::std::os::raw::c_void;
-pub type __blksize_t = ::std::os::raw::c_long;
-pub type __blkcnt_t = ::std::os::raw::c_long;
-pub type __blkcnt64_t = ::std::os::raw::c_long;
-pub type __fsblkcnt_t = ::std::os::raw::c_ulong;
-pub type __fsblkcnt64_t = ::std::os::raw::c_ulong;
-pub type __fsfilcnt_t = ::std::os::raw::c_ulong;
-pub type __fsfilcnt64_t = ::std::os::raw::c_ulong;
-pub type __fsword_t = ::std::os::raw::c_long;
-pub type __ssize_t = ::std::os::raw::c_long;
-pub type __syscall_slong_t = ::std::os::raw::c_long;
-pub type __syscall_ulong_t = ::std::os::raw::c_ulong;
+pub type __clock_t = ::core::ffi::c_long;
+pub type __rlim_t = ::core::ffi::c_ulong;
+pub type __rlim64_t = ::core::ffi::c_ulong;
+pub type __id_t = ::core::ffi::c_uint;
+pub type __time_t = ::core::ffi::c_long;
+pub type __useconds_t = ::core::ffi::c_uint;
+pub type __suseconds_t = ::core::ffi::c_long;
+pub type __suseconds64_t = ::core::ffi::c_long;
+pub type __daddr_t = ::core::ffi::c_int;
+pub type __key_t = ::core::ffi::c_int;
+pub type __clockid_t = ::core::ffi::c_int;
+pub type __timer_t = *mut ::core::ffi::c_void;
+pub type __blksize_t = ::core::ffi::c_long;
+pub type __blkcnt_t = ::core::ffi::c_long;
+pub type __blkcnt64_t = ::core::ffi::c_long;
+pub type __fsblkcnt_t = ::core::ffi::c_ulong;
+pub type __fsblkcnt64_t = ::core::ffi::c_ulong;
+pub type __fsfilcnt_t = ::core::ffi::c_ulong;
+pub type __fsfilcnt64_t = ::core::ffi::c_ulong;
+pub type __fsword_t = ::core::ffi::c_long;
+pub type __ssize_t = ::core::ffi::c_long;
+pub type __syscall_slong_t = ::core::ffi::c_long;
+pub type __syscall_ulong_t = ::core::ffi::c_ulong;
 pub type __loff_t = __off64_t;
-pub type __caddr_t = *mut ::std::os::raw::c_char;
-pub type __intptr_t = ::std::os::raw::c_long;
-pub type __socklen_t = ::std::os::raw::c_uint;
-pub type __sig_atomic_t = ::std::os::raw::c_int;
+pub type __caddr_t = *mut ::core::ffi::c_char;
+pub type __intptr_t = ::core::ffi::c_long;
+pub type __socklen_t = ::core::ffi::c_uint;
+pub type __sig_atomic_t = ::core::ffi::c_int;
 pub type u_char = __u_char;
 pub type u_short = __u_short;
 pub type u_int = __u_int;
@@ -540,37 +540,37 @@ pub type clock_t = __clock_t;
 pub type clockid_t = __clockid_t;
 pub type time_t = __time_t;
 pub type timer_t = __timer_t;
-pub type ulong = ::std::os::raw::c_ulong;
-pub type ushort = ::std::os::raw::c_ushort;
-pub type uint = ::std::os::raw::c_uint;
+pub type ulong = ::core::ffi::c_ulong;
+pub type ushort = ::core::ffi::c_ushort;
+pub type uint = ::core::ffi::c_uint;
 pub type u_int8_t = __uint8_t;
 pub type u_int16_t = __uint16_t;
 pub type u_int32_t = __uint32_t;
 pub type u_int64_t = __uint64_t;
-pub type register_t = ::std::os::raw::c_long;
+pub type register_t = ::core::ffi::c_long;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __sigset_t {
-    pub __val: [::std::os::raw::c_ulong; 16usize],
+    pub __val: [::core::ffi::c_ulong; 16usize],
 }
 #[test]
 fn bindgen_test_layout___sigset_t() {
     assert_eq!(
-        ::std::mem::size_of::<__sigset_t>(),
+        ::core::mem::size_of::<__sigset_t>(),
         128usize,
         concat!("Size of: ", stringify!(__sigset_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<__sigset_t>(),
+        ::core::mem::align_of::<__sigset_t>(),
         8usize,
         concat!("Alignment of ", stringify!(__sigset_t))
     );
     fn test_field___val() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__sigset_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__sigset_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__val) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__val) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -593,21 +593,21 @@ pub struct timeval {
 #[test]
 fn bindgen_test_layout_timeval() {
     assert_eq!(
-        ::std::mem::size_of::<timeval>(),
+        ::core::mem::size_of::<timeval>(),
         16usize,
         concat!("Size of: ", stringify!(timeval))
     );
     assert_eq!(
-        ::std::mem::align_of::<timeval>(),
+        ::core::mem::align_of::<timeval>(),
         8usize,
         concat!("Alignment of ", stringify!(timeval))
     );
     fn test_field_tv_sec() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<timeval>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<timeval>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tv_sec) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tv_sec) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -622,9 +622,9 @@ fn bindgen_test_layout_timeval() {
     fn test_field_tv_usec() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<timeval>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<timeval>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tv_usec) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tv_usec) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -646,21 +646,21 @@ pub struct timespec {
 #[test]
 fn bindgen_test_layout_timespec() {
     assert_eq!(
-        ::std::mem::size_of::<timespec>(),
+        ::core::mem::size_of::<timespec>(),
         16usize,
         concat!("Size of: ", stringify!(timespec))
     );
     assert_eq!(
-        ::std::mem::align_of::<timespec>(),
+        ::core::mem::align_of::<timespec>(),
         8usize,
         concat!("Alignment of ", stringify!(timespec))
     );
     fn test_field_tv_sec() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<timespec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<timespec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tv_sec) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tv_sec) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -675,9 +675,9 @@ fn bindgen_test_layout_timespec() {
     fn test_field_tv_nsec() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<timespec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<timespec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tv_nsec) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tv_nsec) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -691,7 +691,7 @@ fn bindgen_test_layout_timespec() {
     test_field_tv_nsec();
 }
 pub type suseconds_t = __suseconds_t;
-pub type __fd_mask = ::std::os::raw::c_long;
+pub type __fd_mask = ::core::ffi::c_long;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct fd_set {
@@ -700,21 +700,21 @@ pub struct fd_set {
 #[test]
 fn bindgen_test_layout_fd_set() {
     assert_eq!(
-        ::std::mem::size_of::<fd_set>(),
+        ::core::mem::size_of::<fd_set>(),
         128usize,
         concat!("Size of: ", stringify!(fd_set))
     );
     assert_eq!(
-        ::std::mem::align_of::<fd_set>(),
+        ::core::mem::align_of::<fd_set>(),
         8usize,
         concat!("Alignment of ", stringify!(fd_set))
     );
     fn test_field___fds_bits() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<fd_set>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<fd_set>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fds_bits) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fds_bits) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -730,22 +730,22 @@ fn bindgen_test_layout_fd_set() {
 pub type fd_mask = __fd_mask;
 extern "C" {
     pub fn select(
-        __nfds: ::std::os::raw::c_int,
+        __nfds: ::core::ffi::c_int,
         __readfds: *mut fd_set,
         __writefds: *mut fd_set,
         __exceptfds: *mut fd_set,
         __timeout: *mut timeval,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn pselect(
-        __nfds: ::std::os::raw::c_int,
+        __nfds: ::core::ffi::c_int,
         __readfds: *mut fd_set,
         __writefds: *mut fd_set,
         __exceptfds: *mut fd_set,
         __timeout: *const timespec,
         __sigmask: *const __sigset_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub type blksize_t = __blksize_t;
 pub type blkcnt_t = __blkcnt_t;
@@ -754,24 +754,24 @@ pub type fsfilcnt_t = __fsfilcnt_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union __atomic_wide_counter {
-    pub __value64: ::std::os::raw::c_ulonglong,
+    pub __value64: ::core::ffi::c_ulonglong,
     pub __value32: __atomic_wide_counter__bindgen_ty_1,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __atomic_wide_counter__bindgen_ty_1 {
-    pub __low: ::std::os::raw::c_uint,
-    pub __high: ::std::os::raw::c_uint,
+    pub __low: ::core::ffi::c_uint,
+    pub __high: ::core::ffi::c_uint,
 }
 #[test]
 fn bindgen_test_layout___atomic_wide_counter__bindgen_ty_1() {
     assert_eq!(
-        ::std::mem::size_of::<__atomic_wide_counter__bindgen_ty_1>(),
+        ::core::mem::size_of::<__atomic_wide_counter__bindgen_ty_1>(),
         8usize,
         concat!("Size of: ", stringify!(__atomic_wide_counter__bindgen_ty_1))
     );
     assert_eq!(
-        ::std::mem::align_of::<__atomic_wide_counter__bindgen_ty_1>(),
+        ::core::mem::align_of::<__atomic_wide_counter__bindgen_ty_1>(),
         4usize,
         concat!(
             "Alignment of ",
@@ -782,9 +782,9 @@ fn bindgen_test_layout___atomic_wide_counter__bindgen_ty_1() {
         assert_eq!(
             unsafe {
                 let uninit =
-                    ::std::mem::MaybeUninit::<__atomic_wide_counter__bindgen_ty_1>::uninit();
+                    ::core::mem::MaybeUninit::<__atomic_wide_counter__bindgen_ty_1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__low) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__low) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -800,9 +800,9 @@ fn bindgen_test_layout___atomic_wide_counter__bindgen_ty_1() {
         assert_eq!(
             unsafe {
                 let uninit =
-                    ::std::mem::MaybeUninit::<__atomic_wide_counter__bindgen_ty_1>::uninit();
+                    ::core::mem::MaybeUninit::<__atomic_wide_counter__bindgen_ty_1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__high) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__high) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -818,21 +818,21 @@ fn bindgen_test_layout___atomic_wide_counter__bindgen_ty_1() {
 #[test]
 fn bindgen_test_layout___atomic_wide_counter() {
     assert_eq!(
-        ::std::mem::size_of::<__atomic_wide_counter>(),
+        ::core::mem::size_of::<__atomic_wide_counter>(),
         8usize,
         concat!("Size of: ", stringify!(__atomic_wide_counter))
     );
     assert_eq!(
-        ::std::mem::align_of::<__atomic_wide_counter>(),
+        ::core::mem::align_of::<__atomic_wide_counter>(),
         8usize,
         concat!("Alignment of ", stringify!(__atomic_wide_counter))
     );
     fn test_field___value64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__atomic_wide_counter>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__atomic_wide_counter>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__value64) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__value64) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -847,9 +847,9 @@ fn bindgen_test_layout___atomic_wide_counter() {
     fn test_field___value32() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__atomic_wide_counter>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__atomic_wide_counter>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__value32) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__value32) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -871,21 +871,21 @@ pub struct __pthread_internal_list {
 #[test]
 fn bindgen_test_layout___pthread_internal_list() {
     assert_eq!(
-        ::std::mem::size_of::<__pthread_internal_list>(),
+        ::core::mem::size_of::<__pthread_internal_list>(),
         16usize,
         concat!("Size of: ", stringify!(__pthread_internal_list))
     );
     assert_eq!(
-        ::std::mem::align_of::<__pthread_internal_list>(),
+        ::core::mem::align_of::<__pthread_internal_list>(),
         8usize,
         concat!("Alignment of ", stringify!(__pthread_internal_list))
     );
     fn test_field___prev() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_internal_list>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_internal_list>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__prev) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__prev) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -900,9 +900,9 @@ fn bindgen_test_layout___pthread_internal_list() {
     fn test_field___next() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_internal_list>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_internal_list>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__next) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__next) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -924,21 +924,21 @@ pub struct __pthread_internal_slist {
 #[test]
 fn bindgen_test_layout___pthread_internal_slist() {
     assert_eq!(
-        ::std::mem::size_of::<__pthread_internal_slist>(),
+        ::core::mem::size_of::<__pthread_internal_slist>(),
         8usize,
         concat!("Size of: ", stringify!(__pthread_internal_slist))
     );
     assert_eq!(
-        ::std::mem::align_of::<__pthread_internal_slist>(),
+        ::core::mem::align_of::<__pthread_internal_slist>(),
         8usize,
         concat!("Alignment of ", stringify!(__pthread_internal_slist))
     );
     fn test_field___next() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_internal_slist>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_internal_slist>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__next) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__next) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -955,33 +955,33 @@ pub type __pthread_slist_t = __pthread_internal_slist;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __pthread_mutex_s {
-    pub __lock: ::std::os::raw::c_int,
-    pub __count: ::std::os::raw::c_uint,
-    pub __owner: ::std::os::raw::c_int,
-    pub __nusers: ::std::os::raw::c_uint,
-    pub __kind: ::std::os::raw::c_int,
-    pub __spins: ::std::os::raw::c_short,
-    pub __elision: ::std::os::raw::c_short,
+    pub __lock: ::core::ffi::c_int,
+    pub __count: ::core::ffi::c_uint,
+    pub __owner: ::core::ffi::c_int,
+    pub __nusers: ::core::ffi::c_uint,
+    pub __kind: ::core::ffi::c_int,
+    pub __spins: ::core::ffi::c_short,
+    pub __elision: ::core::ffi::c_short,
     pub __list: __pthread_list_t,
 }
 #[test]
 fn bindgen_test_layout___pthread_mutex_s() {
     assert_eq!(
-        ::std::mem::size_of::<__pthread_mutex_s>(),
+        ::core::mem::size_of::<__pthread_mutex_s>(),
         40usize,
         concat!("Size of: ", stringify!(__pthread_mutex_s))
     );
     assert_eq!(
-        ::std::mem::align_of::<__pthread_mutex_s>(),
+        ::core::mem::align_of::<__pthread_mutex_s>(),
         8usize,
         concat!("Alignment of ", stringify!(__pthread_mutex_s))
     );
     fn test_field___lock() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__lock) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__lock) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -996,9 +996,9 @@ fn bindgen_test_layout___pthread_mutex_s() {
     fn test_field___count() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__count) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__count) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -1013,9 +1013,9 @@ fn bindgen_test_layout___pthread_mutex_s() {
     fn test_field___owner() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__owner) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__owner) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1030,9 +1030,9 @@ fn bindgen_test_layout___pthread_mutex_s() {
     fn test_field___nusers() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__nusers) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__nusers) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -1047,9 +1047,9 @@ fn bindgen_test_layout___pthread_mutex_s() {
     fn test_field___kind() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__kind) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__kind) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -1064,9 +1064,9 @@ fn bindgen_test_layout___pthread_mutex_s() {
     fn test_field___spins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__spins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__spins) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -1081,9 +1081,9 @@ fn bindgen_test_layout___pthread_mutex_s() {
     fn test_field___elision() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__elision) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__elision) as usize - ptr as usize
             },
             22usize,
             concat!(
@@ -1098,9 +1098,9 @@ fn bindgen_test_layout___pthread_mutex_s() {
     fn test_field___list() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_mutex_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__list) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__list) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -1116,37 +1116,37 @@ fn bindgen_test_layout___pthread_mutex_s() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __pthread_rwlock_arch_t {
-    pub __readers: ::std::os::raw::c_uint,
-    pub __writers: ::std::os::raw::c_uint,
-    pub __wrphase_futex: ::std::os::raw::c_uint,
-    pub __writers_futex: ::std::os::raw::c_uint,
-    pub __pad3: ::std::os::raw::c_uint,
-    pub __pad4: ::std::os::raw::c_uint,
-    pub __cur_writer: ::std::os::raw::c_int,
-    pub __shared: ::std::os::raw::c_int,
-    pub __rwelision: ::std::os::raw::c_schar,
-    pub __pad1: [::std::os::raw::c_uchar; 7usize],
-    pub __pad2: ::std::os::raw::c_ulong,
-    pub __flags: ::std::os::raw::c_uint,
+    pub __readers: ::core::ffi::c_uint,
+    pub __writers: ::core::ffi::c_uint,
+    pub __wrphase_futex: ::core::ffi::c_uint,
+    pub __writers_futex: ::core::ffi::c_uint,
+    pub __pad3: ::core::ffi::c_uint,
+    pub __pad4: ::core::ffi::c_uint,
+    pub __cur_writer: ::core::ffi::c_int,
+    pub __shared: ::core::ffi::c_int,
+    pub __rwelision: ::core::ffi::c_schar,
+    pub __pad1: [::core::ffi::c_uchar; 7usize],
+    pub __pad2: ::core::ffi::c_ulong,
+    pub __flags: ::core::ffi::c_uint,
 }
 #[test]
 fn bindgen_test_layout___pthread_rwlock_arch_t() {
     assert_eq!(
-        ::std::mem::size_of::<__pthread_rwlock_arch_t>(),
+        ::core::mem::size_of::<__pthread_rwlock_arch_t>(),
         56usize,
         concat!("Size of: ", stringify!(__pthread_rwlock_arch_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<__pthread_rwlock_arch_t>(),
+        ::core::mem::align_of::<__pthread_rwlock_arch_t>(),
         8usize,
         concat!("Alignment of ", stringify!(__pthread_rwlock_arch_t))
     );
     fn test_field___readers() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__readers) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__readers) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1161,9 +1161,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___writers() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__writers) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__writers) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -1178,9 +1178,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___wrphase_futex() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__wrphase_futex) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__wrphase_futex) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1195,9 +1195,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___writers_futex() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__writers_futex) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__writers_futex) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -1212,9 +1212,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___pad3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__pad3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__pad3) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -1229,9 +1229,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___pad4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__pad4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__pad4) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -1246,9 +1246,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___cur_writer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__cur_writer) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__cur_writer) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -1263,9 +1263,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___shared() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__shared) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__shared) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -1280,9 +1280,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___rwelision() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rwelision) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rwelision) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -1297,9 +1297,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___pad1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__pad1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__pad1) as usize - ptr as usize
             },
             33usize,
             concat!(
@@ -1314,9 +1314,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___pad2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__pad2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__pad2) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -1331,9 +1331,9 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     fn test_field___flags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_rwlock_arch_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__flags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__flags) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -1351,30 +1351,30 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
 pub struct __pthread_cond_s {
     pub __wseq: __atomic_wide_counter,
     pub __g1_start: __atomic_wide_counter,
-    pub __g_refs: [::std::os::raw::c_uint; 2usize],
-    pub __g_size: [::std::os::raw::c_uint; 2usize],
-    pub __g1_orig_size: ::std::os::raw::c_uint,
-    pub __wrefs: ::std::os::raw::c_uint,
-    pub __g_signals: [::std::os::raw::c_uint; 2usize],
+    pub __g_refs: [::core::ffi::c_uint; 2usize],
+    pub __g_size: [::core::ffi::c_uint; 2usize],
+    pub __g1_orig_size: ::core::ffi::c_uint,
+    pub __wrefs: ::core::ffi::c_uint,
+    pub __g_signals: [::core::ffi::c_uint; 2usize],
 }
 #[test]
 fn bindgen_test_layout___pthread_cond_s() {
     assert_eq!(
-        ::std::mem::size_of::<__pthread_cond_s>(),
+        ::core::mem::size_of::<__pthread_cond_s>(),
         48usize,
         concat!("Size of: ", stringify!(__pthread_cond_s))
     );
     assert_eq!(
-        ::std::mem::align_of::<__pthread_cond_s>(),
+        ::core::mem::align_of::<__pthread_cond_s>(),
         8usize,
         concat!("Alignment of ", stringify!(__pthread_cond_s))
     );
     fn test_field___wseq() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_cond_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_cond_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__wseq) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__wseq) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1389,9 +1389,9 @@ fn bindgen_test_layout___pthread_cond_s() {
     fn test_field___g1_start() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_cond_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_cond_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__g1_start) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__g1_start) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1406,9 +1406,9 @@ fn bindgen_test_layout___pthread_cond_s() {
     fn test_field___g_refs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_cond_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_cond_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__g_refs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__g_refs) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -1423,9 +1423,9 @@ fn bindgen_test_layout___pthread_cond_s() {
     fn test_field___g_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_cond_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_cond_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__g_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__g_size) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -1440,9 +1440,9 @@ fn bindgen_test_layout___pthread_cond_s() {
     fn test_field___g1_orig_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_cond_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_cond_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__g1_orig_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__g1_orig_size) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -1457,9 +1457,9 @@ fn bindgen_test_layout___pthread_cond_s() {
     fn test_field___wrefs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_cond_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_cond_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__wrefs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__wrefs) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -1474,9 +1474,9 @@ fn bindgen_test_layout___pthread_cond_s() {
     fn test_field___g_signals() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__pthread_cond_s>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__pthread_cond_s>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__g_signals) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__g_signals) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -1489,31 +1489,31 @@ fn bindgen_test_layout___pthread_cond_s() {
     }
     test_field___g_signals();
 }
-pub type __tss_t = ::std::os::raw::c_uint;
-pub type __thrd_t = ::std::os::raw::c_ulong;
+pub type __tss_t = ::core::ffi::c_uint;
+pub type __thrd_t = ::core::ffi::c_ulong;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __once_flag {
-    pub __data: ::std::os::raw::c_int,
+    pub __data: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout___once_flag() {
     assert_eq!(
-        ::std::mem::size_of::<__once_flag>(),
+        ::core::mem::size_of::<__once_flag>(),
         4usize,
         concat!("Size of: ", stringify!(__once_flag))
     );
     assert_eq!(
-        ::std::mem::align_of::<__once_flag>(),
+        ::core::mem::align_of::<__once_flag>(),
         4usize,
         concat!("Alignment of ", stringify!(__once_flag))
     );
     fn test_field___data() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__once_flag>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__once_flag>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1526,31 +1526,31 @@ fn bindgen_test_layout___once_flag() {
     }
     test_field___data();
 }
-pub type pthread_t = ::std::os::raw::c_ulong;
+pub type pthread_t = ::core::ffi::c_ulong;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union pthread_mutexattr_t {
-    pub __size: [::std::os::raw::c_char; 4usize],
-    pub __align: ::std::os::raw::c_int,
+    pub __size: [::core::ffi::c_char; 4usize],
+    pub __align: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_pthread_mutexattr_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_mutexattr_t>(),
+        ::core::mem::size_of::<pthread_mutexattr_t>(),
         4usize,
         concat!("Size of: ", stringify!(pthread_mutexattr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_mutexattr_t>(),
+        ::core::mem::align_of::<pthread_mutexattr_t>(),
         4usize,
         concat!("Alignment of ", stringify!(pthread_mutexattr_t))
     );
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_mutexattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_mutexattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1565,9 +1565,9 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_mutexattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_mutexattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1583,27 +1583,27 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union pthread_condattr_t {
-    pub __size: [::std::os::raw::c_char; 4usize],
-    pub __align: ::std::os::raw::c_int,
+    pub __size: [::core::ffi::c_char; 4usize],
+    pub __align: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_pthread_condattr_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_condattr_t>(),
+        ::core::mem::size_of::<pthread_condattr_t>(),
         4usize,
         concat!("Size of: ", stringify!(pthread_condattr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_condattr_t>(),
+        ::core::mem::align_of::<pthread_condattr_t>(),
         4usize,
         concat!("Alignment of ", stringify!(pthread_condattr_t))
     );
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_condattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_condattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1618,9 +1618,9 @@ fn bindgen_test_layout_pthread_condattr_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_condattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_condattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1633,32 +1633,32 @@ fn bindgen_test_layout_pthread_condattr_t() {
     }
     test_field___align();
 }
-pub type pthread_key_t = ::std::os::raw::c_uint;
-pub type pthread_once_t = ::std::os::raw::c_int;
+pub type pthread_key_t = ::core::ffi::c_uint;
+pub type pthread_once_t = ::core::ffi::c_int;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union pthread_attr_t {
-    pub __size: [::std::os::raw::c_char; 56usize],
-    pub __align: ::std::os::raw::c_long,
+    pub __size: [::core::ffi::c_char; 56usize],
+    pub __align: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_pthread_attr_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_attr_t>(),
+        ::core::mem::size_of::<pthread_attr_t>(),
         56usize,
         concat!("Size of: ", stringify!(pthread_attr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_attr_t>(),
+        ::core::mem::align_of::<pthread_attr_t>(),
         8usize,
         concat!("Alignment of ", stringify!(pthread_attr_t))
     );
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_attr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_attr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1673,9 +1673,9 @@ fn bindgen_test_layout_pthread_attr_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_attr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_attr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1692,27 +1692,27 @@ fn bindgen_test_layout_pthread_attr_t() {
 #[derive(Copy, Clone)]
 pub union pthread_mutex_t {
     pub __data: __pthread_mutex_s,
-    pub __size: [::std::os::raw::c_char; 40usize],
-    pub __align: ::std::os::raw::c_long,
+    pub __size: [::core::ffi::c_char; 40usize],
+    pub __align: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_pthread_mutex_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_mutex_t>(),
+        ::core::mem::size_of::<pthread_mutex_t>(),
         40usize,
         concat!("Size of: ", stringify!(pthread_mutex_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_mutex_t>(),
+        ::core::mem::align_of::<pthread_mutex_t>(),
         8usize,
         concat!("Alignment of ", stringify!(pthread_mutex_t))
     );
     fn test_field___data() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_mutex_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_mutex_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1727,9 +1727,9 @@ fn bindgen_test_layout_pthread_mutex_t() {
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_mutex_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_mutex_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1744,9 +1744,9 @@ fn bindgen_test_layout_pthread_mutex_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_mutex_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_mutex_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1763,27 +1763,27 @@ fn bindgen_test_layout_pthread_mutex_t() {
 #[derive(Copy, Clone)]
 pub union pthread_cond_t {
     pub __data: __pthread_cond_s,
-    pub __size: [::std::os::raw::c_char; 48usize],
-    pub __align: ::std::os::raw::c_longlong,
+    pub __size: [::core::ffi::c_char; 48usize],
+    pub __align: ::core::ffi::c_longlong,
 }
 #[test]
 fn bindgen_test_layout_pthread_cond_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_cond_t>(),
+        ::core::mem::size_of::<pthread_cond_t>(),
         48usize,
         concat!("Size of: ", stringify!(pthread_cond_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_cond_t>(),
+        ::core::mem::align_of::<pthread_cond_t>(),
         8usize,
         concat!("Alignment of ", stringify!(pthread_cond_t))
     );
     fn test_field___data() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_cond_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_cond_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1798,9 +1798,9 @@ fn bindgen_test_layout_pthread_cond_t() {
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_cond_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_cond_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1815,9 +1815,9 @@ fn bindgen_test_layout_pthread_cond_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_cond_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_cond_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1834,27 +1834,27 @@ fn bindgen_test_layout_pthread_cond_t() {
 #[derive(Copy, Clone)]
 pub union pthread_rwlock_t {
     pub __data: __pthread_rwlock_arch_t,
-    pub __size: [::std::os::raw::c_char; 56usize],
-    pub __align: ::std::os::raw::c_long,
+    pub __size: [::core::ffi::c_char; 56usize],
+    pub __align: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_pthread_rwlock_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_rwlock_t>(),
+        ::core::mem::size_of::<pthread_rwlock_t>(),
         56usize,
         concat!("Size of: ", stringify!(pthread_rwlock_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_rwlock_t>(),
+        ::core::mem::align_of::<pthread_rwlock_t>(),
         8usize,
         concat!("Alignment of ", stringify!(pthread_rwlock_t))
     );
     fn test_field___data() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_rwlock_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_rwlock_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__data) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1869,9 +1869,9 @@ fn bindgen_test_layout_pthread_rwlock_t() {
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_rwlock_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_rwlock_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1886,9 +1886,9 @@ fn bindgen_test_layout_pthread_rwlock_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_rwlock_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_rwlock_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1904,27 +1904,27 @@ fn bindgen_test_layout_pthread_rwlock_t() {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union pthread_rwlockattr_t {
-    pub __size: [::std::os::raw::c_char; 8usize],
-    pub __align: ::std::os::raw::c_long,
+    pub __size: [::core::ffi::c_char; 8usize],
+    pub __align: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_pthread_rwlockattr_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_rwlockattr_t>(),
+        ::core::mem::size_of::<pthread_rwlockattr_t>(),
         8usize,
         concat!("Size of: ", stringify!(pthread_rwlockattr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_rwlockattr_t>(),
+        ::core::mem::align_of::<pthread_rwlockattr_t>(),
         8usize,
         concat!("Alignment of ", stringify!(pthread_rwlockattr_t))
     );
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_rwlockattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_rwlockattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1939,9 +1939,9 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_rwlockattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_rwlockattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1954,31 +1954,31 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
     }
     test_field___align();
 }
-pub type pthread_spinlock_t = ::std::os::raw::c_int;
+pub type pthread_spinlock_t = ::core::ffi::c_int;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union pthread_barrier_t {
-    pub __size: [::std::os::raw::c_char; 32usize],
-    pub __align: ::std::os::raw::c_long,
+    pub __size: [::core::ffi::c_char; 32usize],
+    pub __align: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_pthread_barrier_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_barrier_t>(),
+        ::core::mem::size_of::<pthread_barrier_t>(),
         32usize,
         concat!("Size of: ", stringify!(pthread_barrier_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_barrier_t>(),
+        ::core::mem::align_of::<pthread_barrier_t>(),
         8usize,
         concat!("Alignment of ", stringify!(pthread_barrier_t))
     );
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_barrier_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_barrier_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1993,9 +1993,9 @@ fn bindgen_test_layout_pthread_barrier_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_barrier_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_barrier_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2011,27 +2011,27 @@ fn bindgen_test_layout_pthread_barrier_t() {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union pthread_barrierattr_t {
-    pub __size: [::std::os::raw::c_char; 4usize],
-    pub __align: ::std::os::raw::c_int,
+    pub __size: [::core::ffi::c_char; 4usize],
+    pub __align: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_pthread_barrierattr_t() {
     assert_eq!(
-        ::std::mem::size_of::<pthread_barrierattr_t>(),
+        ::core::mem::size_of::<pthread_barrierattr_t>(),
         4usize,
         concat!("Size of: ", stringify!(pthread_barrierattr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<pthread_barrierattr_t>(),
+        ::core::mem::align_of::<pthread_barrierattr_t>(),
         4usize,
         concat!("Alignment of ", stringify!(pthread_barrierattr_t))
     );
     fn test_field___size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_barrierattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_barrierattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__size) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2046,9 +2046,9 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
     fn test_field___align() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<pthread_barrierattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<pthread_barrierattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__align) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2062,20 +2062,20 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
     test_field___align();
 }
 extern "C" {
-    pub fn random() -> ::std::os::raw::c_long;
+    pub fn random() -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn srandom(__seed: ::std::os::raw::c_uint);
+    pub fn srandom(__seed: ::core::ffi::c_uint);
 }
 extern "C" {
     pub fn initstate(
-        __seed: ::std::os::raw::c_uint,
-        __statebuf: *mut ::std::os::raw::c_char,
+        __seed: ::core::ffi::c_uint,
+        __statebuf: *mut ::core::ffi::c_char,
         __statelen: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn setstate(__statebuf: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn setstate(__statebuf: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -2083,29 +2083,29 @@ pub struct random_data {
     pub fptr: *mut i32,
     pub rptr: *mut i32,
     pub state: *mut i32,
-    pub rand_type: ::std::os::raw::c_int,
-    pub rand_deg: ::std::os::raw::c_int,
-    pub rand_sep: ::std::os::raw::c_int,
+    pub rand_type: ::core::ffi::c_int,
+    pub rand_deg: ::core::ffi::c_int,
+    pub rand_sep: ::core::ffi::c_int,
     pub end_ptr: *mut i32,
 }
 #[test]
 fn bindgen_test_layout_random_data() {
     assert_eq!(
-        ::std::mem::size_of::<random_data>(),
+        ::core::mem::size_of::<random_data>(),
         48usize,
         concat!("Size of: ", stringify!(random_data))
     );
     assert_eq!(
-        ::std::mem::align_of::<random_data>(),
+        ::core::mem::align_of::<random_data>(),
         8usize,
         concat!("Alignment of ", stringify!(random_data))
     );
     fn test_field_fptr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<random_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<random_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).fptr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).fptr) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2120,9 +2120,9 @@ fn bindgen_test_layout_random_data() {
     fn test_field_rptr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<random_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<random_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rptr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rptr) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -2137,9 +2137,9 @@ fn bindgen_test_layout_random_data() {
     fn test_field_state() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<random_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<random_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).state) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).state) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -2154,9 +2154,9 @@ fn bindgen_test_layout_random_data() {
     fn test_field_rand_type() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<random_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<random_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rand_type) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rand_type) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -2171,9 +2171,9 @@ fn bindgen_test_layout_random_data() {
     fn test_field_rand_deg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<random_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<random_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rand_deg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rand_deg) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -2188,9 +2188,9 @@ fn bindgen_test_layout_random_data() {
     fn test_field_rand_sep() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<random_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<random_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rand_sep) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rand_sep) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -2205,9 +2205,9 @@ fn bindgen_test_layout_random_data() {
     fn test_field_end_ptr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<random_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<random_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).end_ptr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).end_ptr) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -2221,91 +2221,91 @@ fn bindgen_test_layout_random_data() {
     test_field_end_ptr();
 }
 extern "C" {
-    pub fn random_r(__buf: *mut random_data, __result: *mut i32) -> ::std::os::raw::c_int;
+    pub fn random_r(__buf: *mut random_data, __result: *mut i32) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn srandom_r(
-        __seed: ::std::os::raw::c_uint,
+        __seed: ::core::ffi::c_uint,
         __buf: *mut random_data,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn initstate_r(
-        __seed: ::std::os::raw::c_uint,
-        __statebuf: *mut ::std::os::raw::c_char,
+        __seed: ::core::ffi::c_uint,
+        __statebuf: *mut ::core::ffi::c_char,
         __statelen: usize,
         __buf: *mut random_data,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn setstate_r(
-        __statebuf: *mut ::std::os::raw::c_char,
+        __statebuf: *mut ::core::ffi::c_char,
         __buf: *mut random_data,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn rand() -> ::std::os::raw::c_int;
+    pub fn rand() -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn srand(__seed: ::std::os::raw::c_uint);
+    pub fn srand(__seed: ::core::ffi::c_uint);
 }
 extern "C" {
-    pub fn rand_r(__seed: *mut ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+    pub fn rand_r(__seed: *mut ::core::ffi::c_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn drand48() -> f64;
 }
 extern "C" {
-    pub fn erand48(__xsubi: *mut ::std::os::raw::c_ushort) -> f64;
+    pub fn erand48(__xsubi: *mut ::core::ffi::c_ushort) -> f64;
 }
 extern "C" {
-    pub fn lrand48() -> ::std::os::raw::c_long;
+    pub fn lrand48() -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn nrand48(__xsubi: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+    pub fn nrand48(__xsubi: *mut ::core::ffi::c_ushort) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn mrand48() -> ::std::os::raw::c_long;
+    pub fn mrand48() -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn jrand48(__xsubi: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+    pub fn jrand48(__xsubi: *mut ::core::ffi::c_ushort) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn srand48(__seedval: ::std::os::raw::c_long);
+    pub fn srand48(__seedval: ::core::ffi::c_long);
 }
 extern "C" {
-    pub fn seed48(__seed16v: *mut ::std::os::raw::c_ushort) -> *mut ::std::os::raw::c_ushort;
+    pub fn seed48(__seed16v: *mut ::core::ffi::c_ushort) -> *mut ::core::ffi::c_ushort;
 }
 extern "C" {
-    pub fn lcong48(__param: *mut ::std::os::raw::c_ushort);
+    pub fn lcong48(__param: *mut ::core::ffi::c_ushort);
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct drand48_data {
-    pub __x: [::std::os::raw::c_ushort; 3usize],
-    pub __old_x: [::std::os::raw::c_ushort; 3usize],
-    pub __c: ::std::os::raw::c_ushort,
-    pub __init: ::std::os::raw::c_ushort,
-    pub __a: ::std::os::raw::c_ulonglong,
+    pub __x: [::core::ffi::c_ushort; 3usize],
+    pub __old_x: [::core::ffi::c_ushort; 3usize],
+    pub __c: ::core::ffi::c_ushort,
+    pub __init: ::core::ffi::c_ushort,
+    pub __a: ::core::ffi::c_ulonglong,
 }
 #[test]
 fn bindgen_test_layout_drand48_data() {
     assert_eq!(
-        ::std::mem::size_of::<drand48_data>(),
+        ::core::mem::size_of::<drand48_data>(),
         24usize,
         concat!("Size of: ", stringify!(drand48_data))
     );
     assert_eq!(
-        ::std::mem::align_of::<drand48_data>(),
+        ::core::mem::align_of::<drand48_data>(),
         8usize,
         concat!("Alignment of ", stringify!(drand48_data))
     );
     fn test_field___x() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<drand48_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<drand48_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__x) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__x) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2320,9 +2320,9 @@ fn bindgen_test_layout_drand48_data() {
     fn test_field___old_x() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<drand48_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<drand48_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__old_x) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__old_x) as usize - ptr as usize
             },
             6usize,
             concat!(
@@ -2337,9 +2337,9 @@ fn bindgen_test_layout_drand48_data() {
     fn test_field___c() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<drand48_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<drand48_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__c) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__c) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -2354,9 +2354,9 @@ fn bindgen_test_layout_drand48_data() {
     fn test_field___init() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<drand48_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<drand48_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__init) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__init) as usize - ptr as usize
             },
             14usize,
             concat!(
@@ -2371,9 +2371,9 @@ fn bindgen_test_layout_drand48_data() {
     fn test_field___a() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<drand48_data>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<drand48_data>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__a) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__a) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -2387,339 +2387,339 @@ fn bindgen_test_layout_drand48_data() {
     test_field___a();
 }
 extern "C" {
-    pub fn drand48_r(__buffer: *mut drand48_data, __result: *mut f64) -> ::std::os::raw::c_int;
+    pub fn drand48_r(__buffer: *mut drand48_data, __result: *mut f64) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn erand48_r(
-        __xsubi: *mut ::std::os::raw::c_ushort,
+        __xsubi: *mut ::core::ffi::c_ushort,
         __buffer: *mut drand48_data,
         __result: *mut f64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn lrand48_r(
         __buffer: *mut drand48_data,
-        __result: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        __result: *mut ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn nrand48_r(
-        __xsubi: *mut ::std::os::raw::c_ushort,
+        __xsubi: *mut ::core::ffi::c_ushort,
         __buffer: *mut drand48_data,
-        __result: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        __result: *mut ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn mrand48_r(
         __buffer: *mut drand48_data,
-        __result: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        __result: *mut ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn jrand48_r(
-        __xsubi: *mut ::std::os::raw::c_ushort,
+        __xsubi: *mut ::core::ffi::c_ushort,
         __buffer: *mut drand48_data,
-        __result: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        __result: *mut ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn srand48_r(
-        __seedval: ::std::os::raw::c_long,
+        __seedval: ::core::ffi::c_long,
         __buffer: *mut drand48_data,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn seed48_r(
-        __seed16v: *mut ::std::os::raw::c_ushort,
+        __seed16v: *mut ::core::ffi::c_ushort,
         __buffer: *mut drand48_data,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn lcong48_r(
-        __param: *mut ::std::os::raw::c_ushort,
+        __param: *mut ::core::ffi::c_ushort,
         __buffer: *mut drand48_data,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn malloc(__size: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+    pub fn malloc(__size: ::core::ffi::c_ulong) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn calloc(
-        __nmemb: ::std::os::raw::c_ulong,
-        __size: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __nmemb: ::core::ffi::c_ulong,
+        __size: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn realloc(
-        __ptr: *mut ::std::os::raw::c_void,
-        __size: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __ptr: *mut ::core::ffi::c_void,
+        __size: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn free(__ptr: *mut ::std::os::raw::c_void);
+    pub fn free(__ptr: *mut ::core::ffi::c_void);
 }
 extern "C" {
     pub fn reallocarray(
-        __ptr: *mut ::std::os::raw::c_void,
+        __ptr: *mut ::core::ffi::c_void,
         __nmemb: usize,
         __size: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn alloca(__size: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+    pub fn alloca(__size: ::core::ffi::c_ulong) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn valloc(__size: usize) -> *mut ::std::os::raw::c_void;
+    pub fn valloc(__size: usize) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn posix_memalign(
-        __memptr: *mut *mut ::std::os::raw::c_void,
+        __memptr: *mut *mut ::core::ffi::c_void,
         __alignment: usize,
         __size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn aligned_alloc(
-        __alignment: ::std::os::raw::c_ulong,
-        __size: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __alignment: ::core::ffi::c_ulong,
+        __size: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn abort();
 }
 extern "C" {
-    pub fn atexit(__func: ::std::option::Option<unsafe extern "C" fn()>) -> ::std::os::raw::c_int;
+    pub fn atexit(__func: ::core::option::Option<unsafe extern "C" fn()>) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn at_quick_exit(
-        __func: ::std::option::Option<unsafe extern "C" fn()>,
-    ) -> ::std::os::raw::c_int;
+        __func: ::core::option::Option<unsafe extern "C" fn()>,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn on_exit(
-        __func: ::std::option::Option<
+        __func: ::core::option::Option<
             unsafe extern "C" fn(
-                __status: ::std::os::raw::c_int,
-                __arg: *mut ::std::os::raw::c_void,
+                __status: ::core::ffi::c_int,
+                __arg: *mut ::core::ffi::c_void,
             ),
         >,
-        __arg: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        __arg: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn exit(__status: ::std::os::raw::c_int);
+    pub fn exit(__status: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn quick_exit(__status: ::std::os::raw::c_int);
+    pub fn quick_exit(__status: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn _Exit(__status: ::std::os::raw::c_int);
+    pub fn _Exit(__status: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn getenv(__name: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn getenv(__name: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn putenv(__string: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn putenv(__string: *mut ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn setenv(
-        __name: *const ::std::os::raw::c_char,
-        __value: *const ::std::os::raw::c_char,
-        __replace: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        __name: *const ::core::ffi::c_char,
+        __value: *const ::core::ffi::c_char,
+        __replace: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn unsetenv(__name: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn unsetenv(__name: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn clearenv() -> ::std::os::raw::c_int;
+    pub fn clearenv() -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn mktemp(__template: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn mktemp(__template: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn mkstemp(__template: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn mkstemp(__template: *mut ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn mkstemps(
-        __template: *mut ::std::os::raw::c_char,
-        __suffixlen: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        __template: *mut ::core::ffi::c_char,
+        __suffixlen: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn mkdtemp(__template: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn mkdtemp(__template: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn system(__command: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn system(__command: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn realpath(
-        __name: *const ::std::os::raw::c_char,
-        __resolved: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __name: *const ::core::ffi::c_char,
+        __resolved: *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
-pub type __compar_fn_t = ::std::option::Option<
+pub type __compar_fn_t = ::core::option::Option<
     unsafe extern "C" fn(
-        arg1: *const ::std::os::raw::c_void,
-        arg2: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int,
+        arg1: *const ::core::ffi::c_void,
+        arg2: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int,
 >;
 extern "C" {
     pub fn bsearch(
-        __key: *const ::std::os::raw::c_void,
-        __base: *const ::std::os::raw::c_void,
+        __key: *const ::core::ffi::c_void,
+        __base: *const ::core::ffi::c_void,
         __nmemb: usize,
         __size: usize,
         __compar: __compar_fn_t,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn qsort(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nmemb: usize,
         __size: usize,
         __compar: __compar_fn_t,
     );
 }
 extern "C" {
-    pub fn abs(__x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn abs(__x: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn labs(__x: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+    pub fn labs(__x: ::core::ffi::c_long) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn llabs(__x: ::std::os::raw::c_longlong) -> ::std::os::raw::c_longlong;
+    pub fn llabs(__x: ::core::ffi::c_longlong) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn div(__numer: ::std::os::raw::c_int, __denom: ::std::os::raw::c_int) -> div_t;
+    pub fn div(__numer: ::core::ffi::c_int, __denom: ::core::ffi::c_int) -> div_t;
 }
 extern "C" {
-    pub fn ldiv(__numer: ::std::os::raw::c_long, __denom: ::std::os::raw::c_long) -> ldiv_t;
+    pub fn ldiv(__numer: ::core::ffi::c_long, __denom: ::core::ffi::c_long) -> ldiv_t;
 }
 extern "C" {
     pub fn lldiv(
-        __numer: ::std::os::raw::c_longlong,
-        __denom: ::std::os::raw::c_longlong,
+        __numer: ::core::ffi::c_longlong,
+        __denom: ::core::ffi::c_longlong,
     ) -> lldiv_t;
 }
 extern "C" {
     pub fn ecvt(
         __value: f64,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn fcvt(
         __value: f64,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gcvt(
         __value: f64,
-        __ndigit: ::std::os::raw::c_int,
-        __buf: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __ndigit: ::core::ffi::c_int,
+        __buf: *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn qecvt(
         __value: u128,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn qfcvt(
         __value: u128,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn qgcvt(
         __value: u128,
-        __ndigit: ::std::os::raw::c_int,
-        __buf: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __ndigit: ::core::ffi::c_int,
+        __buf: *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn ecvt_r(
         __value: f64,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-        __buf: *mut ::std::os::raw::c_char,
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+        __buf: *mut ::core::ffi::c_char,
         __len: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn fcvt_r(
         __value: f64,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-        __buf: *mut ::std::os::raw::c_char,
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+        __buf: *mut ::core::ffi::c_char,
         __len: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn qecvt_r(
         __value: u128,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-        __buf: *mut ::std::os::raw::c_char,
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+        __buf: *mut ::core::ffi::c_char,
         __len: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn qfcvt_r(
         __value: u128,
-        __ndigit: ::std::os::raw::c_int,
-        __decpt: *mut ::std::os::raw::c_int,
-        __sign: *mut ::std::os::raw::c_int,
-        __buf: *mut ::std::os::raw::c_char,
+        __ndigit: ::core::ffi::c_int,
+        __decpt: *mut ::core::ffi::c_int,
+        __sign: *mut ::core::ffi::c_int,
+        __buf: *mut ::core::ffi::c_char,
         __len: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn mblen(__s: *const ::std::os::raw::c_char, __n: usize) -> ::std::os::raw::c_int;
+    pub fn mblen(__s: *const ::core::ffi::c_char, __n: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn mbtowc(
         __pwc: *mut wchar_t,
-        __s: *const ::std::os::raw::c_char,
+        __s: *const ::core::ffi::c_char,
         __n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn wctomb(__s: *mut ::std::os::raw::c_char, __wchar: wchar_t) -> ::std::os::raw::c_int;
+    pub fn wctomb(__s: *mut ::core::ffi::c_char, __wchar: wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn mbstowcs(__pwcs: *mut wchar_t, __s: *const ::std::os::raw::c_char, __n: usize) -> usize;
+    pub fn mbstowcs(__pwcs: *mut wchar_t, __s: *const ::core::ffi::c_char, __n: usize) -> usize;
 }
 extern "C" {
-    pub fn wcstombs(__s: *mut ::std::os::raw::c_char, __pwcs: *const wchar_t, __n: usize) -> usize;
+    pub fn wcstombs(__s: *mut ::core::ffi::c_char, __pwcs: *const wchar_t, __n: usize) -> usize;
 }
 extern "C" {
-    pub fn rpmatch(__response: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn rpmatch(__response: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn getsubopt(
-        __optionp: *mut *mut ::std::os::raw::c_char,
-        __tokens: *const *mut ::std::os::raw::c_char,
-        __valuep: *mut *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        __optionp: *mut *mut ::core::ffi::c_char,
+        __tokens: *const *mut ::core::ffi::c_char,
+        __valuep: *mut *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn getloadavg(__loadavg: *mut f64, __nelem: ::std::os::raw::c_int)
-        -> ::std::os::raw::c_int;
+    pub fn getloadavg(__loadavg: *mut f64, __nelem: ::core::ffi::c_int)
+        -> ::core::ffi::c_int;
 }
 pub type int_least8_t = __int_least8_t;
 pub type int_least16_t = __int_least16_t;
@@ -2729,145 +2729,145 @@ pub type uint_least8_t = __uint_least8_t;
 pub type uint_least16_t = __uint_least16_t;
 pub type uint_least32_t = __uint_least32_t;
 pub type uint_least64_t = __uint_least64_t;
-pub type int_fast8_t = ::std::os::raw::c_schar;
-pub type int_fast16_t = ::std::os::raw::c_long;
-pub type int_fast32_t = ::std::os::raw::c_long;
-pub type int_fast64_t = ::std::os::raw::c_long;
-pub type uint_fast8_t = ::std::os::raw::c_uchar;
-pub type uint_fast16_t = ::std::os::raw::c_ulong;
-pub type uint_fast32_t = ::std::os::raw::c_ulong;
-pub type uint_fast64_t = ::std::os::raw::c_ulong;
+pub type int_fast8_t = ::core::ffi::c_schar;
+pub type int_fast16_t = ::core::ffi::c_long;
+pub type int_fast32_t = ::core::ffi::c_long;
+pub type int_fast64_t = ::core::ffi::c_long;
+pub type uint_fast8_t = ::core::ffi::c_uchar;
+pub type uint_fast16_t = ::core::ffi::c_ulong;
+pub type uint_fast32_t = ::core::ffi::c_ulong;
+pub type uint_fast64_t = ::core::ffi::c_ulong;
 pub type intmax_t = __intmax_t;
 pub type uintmax_t = __uintmax_t;
 extern "C" {
     pub fn memcpy(
-        __dest: *mut ::std::os::raw::c_void,
-        __src: *const ::std::os::raw::c_void,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __dest: *mut ::core::ffi::c_void,
+        __src: *const ::core::ffi::c_void,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memmove(
-        __dest: *mut ::std::os::raw::c_void,
-        __src: *const ::std::os::raw::c_void,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __dest: *mut ::core::ffi::c_void,
+        __src: *const ::core::ffi::c_void,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memccpy(
-        __dest: *mut ::std::os::raw::c_void,
-        __src: *const ::std::os::raw::c_void,
-        __c: ::std::os::raw::c_int,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __dest: *mut ::core::ffi::c_void,
+        __src: *const ::core::ffi::c_void,
+        __c: ::core::ffi::c_int,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memset(
-        __s: *mut ::std::os::raw::c_void,
-        __c: ::std::os::raw::c_int,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __s: *mut ::core::ffi::c_void,
+        __c: ::core::ffi::c_int,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memcmp(
-        __s1: *const ::std::os::raw::c_void,
-        __s2: *const ::std::os::raw::c_void,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_void,
+        __s2: *const ::core::ffi::c_void,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn __memcmpeq(
-        __s1: *const ::std::os::raw::c_void,
-        __s2: *const ::std::os::raw::c_void,
+        __s1: *const ::core::ffi::c_void,
+        __s2: *const ::core::ffi::c_void,
         __n: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn memchr(
-        __s: *const ::std::os::raw::c_void,
-        __c: ::std::os::raw::c_int,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __s: *const ::core::ffi::c_void,
+        __c: ::core::ffi::c_int,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn strcpy(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strncpy(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcat(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strncat(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcmp(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strncmp(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcoll(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strxfrm(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_ulong;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_ulong;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __locale_struct {
     pub __locales: [*mut __locale_data; 13usize],
-    pub __ctype_b: *const ::std::os::raw::c_ushort,
-    pub __ctype_tolower: *const ::std::os::raw::c_int,
-    pub __ctype_toupper: *const ::std::os::raw::c_int,
-    pub __names: [*const ::std::os::raw::c_char; 13usize],
+    pub __ctype_b: *const ::core::ffi::c_ushort,
+    pub __ctype_tolower: *const ::core::ffi::c_int,
+    pub __ctype_toupper: *const ::core::ffi::c_int,
+    pub __names: [*const ::core::ffi::c_char; 13usize],
 }
 #[test]
 fn bindgen_test_layout___locale_struct() {
     assert_eq!(
-        ::std::mem::size_of::<__locale_struct>(),
+        ::core::mem::size_of::<__locale_struct>(),
         232usize,
         concat!("Size of: ", stringify!(__locale_struct))
     );
     assert_eq!(
-        ::std::mem::align_of::<__locale_struct>(),
+        ::core::mem::align_of::<__locale_struct>(),
         8usize,
         concat!("Alignment of ", stringify!(__locale_struct))
     );
     fn test_field___locales() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__locale_struct>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__locale_struct>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__locales) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__locales) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2882,9 +2882,9 @@ fn bindgen_test_layout___locale_struct() {
     fn test_field___ctype_b() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__locale_struct>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__locale_struct>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ctype_b) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ctype_b) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -2899,9 +2899,9 @@ fn bindgen_test_layout___locale_struct() {
     fn test_field___ctype_tolower() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__locale_struct>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__locale_struct>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ctype_tolower) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ctype_tolower) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -2916,9 +2916,9 @@ fn bindgen_test_layout___locale_struct() {
     fn test_field___ctype_toupper() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__locale_struct>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__locale_struct>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ctype_toupper) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ctype_toupper) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -2933,9 +2933,9 @@ fn bindgen_test_layout___locale_struct() {
     fn test_field___names() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__locale_struct>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__locale_struct>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__names) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__names) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -2952,210 +2952,210 @@ pub type __locale_t = *mut __locale_struct;
 pub type locale_t = __locale_t;
 extern "C" {
     pub fn strcoll_l(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
         __l: locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strxfrm_l(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
         __n: usize,
         __l: locale_t,
     ) -> usize;
 }
 extern "C" {
-    pub fn strdup(__s: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn strdup(__s: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strndup(
-        __string: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __string: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strchr(
-        __s: *const ::std::os::raw::c_char,
-        __c: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __c: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strrchr(
-        __s: *const ::std::os::raw::c_char,
-        __c: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __c: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcspn(
-        __s: *const ::std::os::raw::c_char,
-        __reject: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_ulong;
+        __s: *const ::core::ffi::c_char,
+        __reject: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strspn(
-        __s: *const ::std::os::raw::c_char,
-        __accept: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_ulong;
+        __s: *const ::core::ffi::c_char,
+        __accept: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strpbrk(
-        __s: *const ::std::os::raw::c_char,
-        __accept: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __accept: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strstr(
-        __haystack: *const ::std::os::raw::c_char,
-        __needle: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __haystack: *const ::core::ffi::c_char,
+        __needle: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strtok(
-        __s: *mut ::std::os::raw::c_char,
-        __delim: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *mut ::core::ffi::c_char,
+        __delim: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn __strtok_r(
-        __s: *mut ::std::os::raw::c_char,
-        __delim: *const ::std::os::raw::c_char,
-        __save_ptr: *mut *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *mut ::core::ffi::c_char,
+        __delim: *const ::core::ffi::c_char,
+        __save_ptr: *mut *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strtok_r(
-        __s: *mut ::std::os::raw::c_char,
-        __delim: *const ::std::os::raw::c_char,
-        __save_ptr: *mut *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *mut ::core::ffi::c_char,
+        __delim: *const ::core::ffi::c_char,
+        __save_ptr: *mut *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn strlen(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_ulong;
+    pub fn strlen(__s: *const ::core::ffi::c_char) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn strnlen(__string: *const ::std::os::raw::c_char, __maxlen: usize) -> usize;
+    pub fn strnlen(__string: *const ::core::ffi::c_char, __maxlen: usize) -> usize;
 }
 extern "C" {
-    pub fn strerror(__errnum: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+    pub fn strerror(__errnum: ::core::ffi::c_int) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     #[link_name = "\u{1}__xpg_strerror_r"]
     pub fn strerror_r(
-        __errnum: ::std::os::raw::c_int,
-        __buf: *mut ::std::os::raw::c_char,
+        __errnum: ::core::ffi::c_int,
+        __buf: *mut ::core::ffi::c_char,
         __buflen: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strerror_l(
-        __errnum: ::std::os::raw::c_int,
+        __errnum: ::core::ffi::c_int,
         __l: locale_t,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn bcmp(
-        __s1: *const ::std::os::raw::c_void,
-        __s2: *const ::std::os::raw::c_void,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_void,
+        __s2: *const ::core::ffi::c_void,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn bcopy(
-        __src: *const ::std::os::raw::c_void,
-        __dest: *mut ::std::os::raw::c_void,
+        __src: *const ::core::ffi::c_void,
+        __dest: *mut ::core::ffi::c_void,
         __n: usize,
     );
 }
 extern "C" {
-    pub fn bzero(__s: *mut ::std::os::raw::c_void, __n: ::std::os::raw::c_ulong);
+    pub fn bzero(__s: *mut ::core::ffi::c_void, __n: ::core::ffi::c_ulong);
 }
 extern "C" {
     pub fn index(
-        __s: *const ::std::os::raw::c_char,
-        __c: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __c: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn rindex(
-        __s: *const ::std::os::raw::c_char,
-        __c: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __c: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn ffs(__i: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn ffs(__i: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn ffsl(__l: ::std::os::raw::c_long) -> ::std::os::raw::c_int;
+    pub fn ffsl(__l: ::core::ffi::c_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn ffsll(__ll: ::std::os::raw::c_longlong) -> ::std::os::raw::c_int;
+    pub fn ffsll(__ll: ::core::ffi::c_longlong) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcasecmp(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strncasecmp(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcasecmp_l(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
         __loc: locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strncasecmp_l(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
         __n: usize,
         __loc: locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn explicit_bzero(__s: *mut ::std::os::raw::c_void, __n: usize);
+    pub fn explicit_bzero(__s: *mut ::core::ffi::c_void, __n: usize);
 }
 extern "C" {
     pub fn strsep(
-        __stringp: *mut *mut ::std::os::raw::c_char,
-        __delim: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __stringp: *mut *mut ::core::ffi::c_char,
+        __delim: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn strsignal(__sig: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+    pub fn strsignal(__sig: ::core::ffi::c_int) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn __stpcpy(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn stpcpy(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn __stpncpy(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
         __n: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn stpncpy(
-        __dest: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __dest: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 #[repr(u32)]
 #[doc = " Copied from TensorProto::DataType"]
@@ -3393,36 +3393,36 @@ pub struct OrtAllocator {
     #[doc = "< Must be initialized to ORT_API_VERSION"]
     pub version: u32,
     #[doc = "< Returns a pointer to an allocated block of `size` bytes"]
-    pub Alloc: ::std::option::Option<
-        unsafe extern "C" fn(this_: *mut OrtAllocator, size: usize) -> *mut ::std::os::raw::c_void,
+    pub Alloc: ::core::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, size: usize) -> *mut ::core::ffi::c_void,
     >,
     #[doc = "< Free a block of memory previously allocated with OrtAllocator::Alloc"]
-    pub Free: ::std::option::Option<
-        unsafe extern "C" fn(this_: *mut OrtAllocator, p: *mut ::std::os::raw::c_void),
+    pub Free: ::core::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, p: *mut ::core::ffi::c_void),
     >,
     #[doc = "< Return a pointer to an ::OrtMemoryInfo that describes this allocator"]
-    pub Info: ::std::option::Option<
+    pub Info: ::core::option::Option<
         unsafe extern "C" fn(this_: *const OrtAllocator) -> *const OrtMemoryInfo,
     >,
 }
 #[test]
 fn bindgen_test_layout_OrtAllocator() {
     assert_eq!(
-        ::std::mem::size_of::<OrtAllocator>(),
+        ::core::mem::size_of::<OrtAllocator>(),
         32usize,
         concat!("Size of: ", stringify!(OrtAllocator))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtAllocator>(),
+        ::core::mem::align_of::<OrtAllocator>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtAllocator))
     );
     fn test_field_version() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).version) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3437,9 +3437,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Alloc() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Alloc) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Alloc) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3454,9 +3454,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Free() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Free) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Free) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3471,9 +3471,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Info() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Info) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Info) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3486,14 +3486,14 @@ fn bindgen_test_layout_OrtAllocator() {
     }
     test_field_Info();
 }
-pub type OrtLoggingFunction = ::std::option::Option<
+pub type OrtLoggingFunction = ::core::option::Option<
     unsafe extern "C" fn(
-        param: *mut ::std::os::raw::c_void,
+        param: *mut ::core::ffi::c_void,
         severity: OrtLoggingLevel,
-        category: *const ::std::os::raw::c_char,
-        logid: *const ::std::os::raw::c_char,
-        code_location: *const ::std::os::raw::c_char,
-        message: *const ::std::os::raw::c_char,
+        category: *const ::core::ffi::c_char,
+        logid: *const ::core::ffi::c_char,
+        code_location: *const ::core::ffi::c_char,
+        message: *const ::core::ffi::c_char,
     ),
 >;
 #[repr(u32)]
@@ -3582,7 +3582,7 @@ pub enum OrtCudnnConvAlgoSearch {
 pub struct OrtCUDAProviderOptions {
     #[doc = " \\brief CUDA device Id"]
     #[doc = "   Defaults to 0."]
-    pub device_id: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
     #[doc = " \\brief CUDA Convolution algorithm search configuration."]
     #[doc = "   See enum OrtCudnnConvAlgoSearch for more details."]
     #[doc = "   Defaults to OrtCudnnConvAlgoSearchExhaustive."]
@@ -3596,49 +3596,49 @@ pub struct OrtCUDAProviderOptions {
     #[doc = "   1 = kSameAsRequested<br>"]
     #[doc = "   Defaults to 0."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
-    pub arena_extend_strategy: ::std::os::raw::c_int,
+    pub arena_extend_strategy: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the CUDA EP"]
     #[doc = "   0 = Use separate streams for copying and compute."]
     #[doc = "   1 = Use the same stream for copying and compute."]
     #[doc = "   Defaults to 1."]
     #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
     #[doc = "   Please see issue #4829 for more details."]
-    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    pub do_copy_in_default_stream: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
     #[doc = "   Defaults to 0."]
-    pub has_user_compute_stream: ::std::os::raw::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
     #[doc = " \\brief User provided compute stream."]
     #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
     #[doc = " \\brief CUDA memory arena configuration parameters"]
     pub default_memory_arena_cfg: *mut OrtArenaCfg,
     #[doc = " \\brief Enable TunableOp for using."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_CUDA_TUNABLE_OP_ENABLE."]
-    pub tunable_op_enable: ::std::os::raw::c_int,
+    pub tunable_op_enable: ::core::ffi::c_int,
     #[doc = " \\brief Enable TunableOp for tuning."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp tuning. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_CUDA_TUNABLE_OP_TUNING_ENABLE."]
-    pub tunable_op_tuning_enable: ::std::os::raw::c_int,
+    pub tunable_op_tuning_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtCUDAProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCUDAProviderOptions>(),
+        ::core::mem::size_of::<OrtCUDAProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtCUDAProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCUDAProviderOptions>(),
+        ::core::mem::align_of::<OrtCUDAProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtCUDAProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3653,9 +3653,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_cudnn_conv_algo_search() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).cudnn_conv_algo_search) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).cudnn_conv_algo_search) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -3670,9 +3670,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_gpu_mem_limit() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3687,9 +3687,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_arena_extend_strategy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3704,9 +3704,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_do_copy_in_default_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -3721,9 +3721,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3738,9 +3738,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -3755,9 +3755,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_default_memory_arena_cfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -3772,9 +3772,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_tunable_op_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -3789,9 +3789,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_tunable_op_tuning_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -3812,10 +3812,10 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
 pub struct OrtROCMProviderOptions {
     #[doc = " \\brief ROCM device Id"]
     #[doc = "   Defaults to 0."]
-    pub device_id: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
     #[doc = " \\brief ROCM MIOpen Convolution algorithm exaustive search option."]
     #[doc = "   Defaults to 0 (false)."]
-    pub miopen_conv_exhaustive_search: ::std::os::raw::c_int,
+    pub miopen_conv_exhaustive_search: ::core::ffi::c_int,
     #[doc = " \\brief ROCM memory limit (To use all possible memory pass in maximum size_t)"]
     #[doc = "   Defaults to SIZE_MAX."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
@@ -3825,49 +3825,49 @@ pub struct OrtROCMProviderOptions {
     #[doc = "   1 = kSameAsRequested<br>"]
     #[doc = "   Defaults to 0."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
-    pub arena_extend_strategy: ::std::os::raw::c_int,
+    pub arena_extend_strategy: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the ROCM EP"]
     #[doc = "   0 = Use separate streams for copying and compute."]
     #[doc = "   1 = Use the same stream for copying and compute."]
     #[doc = "   Defaults to 1."]
     #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
     #[doc = "   Please see issue #4829 for more details."]
-    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    pub do_copy_in_default_stream: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
     #[doc = "   Defaults to 0."]
-    pub has_user_compute_stream: ::std::os::raw::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
     #[doc = " \\brief User provided compute stream."]
     #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
     #[doc = " \\brief ROCM memory arena configuration parameters"]
     pub default_memory_arena_cfg: *mut OrtArenaCfg,
     #[doc = " \\brief Enable TunableOp for using."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_ROCM_TUNABLE_OP_ENABLE."]
-    pub tunable_op_enable: ::std::os::raw::c_int,
+    pub tunable_op_enable: ::core::ffi::c_int,
     #[doc = " \\brief Enable TunableOp for tuning."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp tuning. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_ROCM_TUNABLE_OP_TUNING_ENABLE."]
-    pub tunable_op_tuning_enable: ::std::os::raw::c_int,
+    pub tunable_op_tuning_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtROCMProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtROCMProviderOptions>(),
+        ::core::mem::size_of::<OrtROCMProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtROCMProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtROCMProviderOptions>(),
+        ::core::mem::align_of::<OrtROCMProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtROCMProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3882,9 +3882,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_miopen_conv_exhaustive_search() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).miopen_conv_exhaustive_search) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).miopen_conv_exhaustive_search) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -3899,9 +3899,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_gpu_mem_limit() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3916,9 +3916,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_arena_extend_strategy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3933,9 +3933,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_do_copy_in_default_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -3950,9 +3950,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3967,9 +3967,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -3984,9 +3984,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_default_memory_arena_cfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -4001,9 +4001,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_tunable_op_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -4018,9 +4018,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_tunable_op_tuning_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -4040,43 +4040,43 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
 #[derive(Debug, Copy, Clone)]
 pub struct OrtTensorRTProviderOptions {
     #[doc = "< CUDA device id (0 = default device)"]
-    pub device_id: ::std::os::raw::c_int,
-    pub has_user_compute_stream: ::std::os::raw::c_int,
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
-    pub trt_max_partition_iterations: ::std::os::raw::c_int,
-    pub trt_min_subgraph_size: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
+    pub trt_max_partition_iterations: ::core::ffi::c_int,
+    pub trt_min_subgraph_size: ::core::ffi::c_int,
     pub trt_max_workspace_size: usize,
-    pub trt_fp16_enable: ::std::os::raw::c_int,
-    pub trt_int8_enable: ::std::os::raw::c_int,
-    pub trt_int8_calibration_table_name: *const ::std::os::raw::c_char,
-    pub trt_int8_use_native_calibration_table: ::std::os::raw::c_int,
-    pub trt_dla_enable: ::std::os::raw::c_int,
-    pub trt_dla_core: ::std::os::raw::c_int,
-    pub trt_dump_subgraphs: ::std::os::raw::c_int,
-    pub trt_engine_cache_enable: ::std::os::raw::c_int,
-    pub trt_engine_cache_path: *const ::std::os::raw::c_char,
-    pub trt_engine_decryption_enable: ::std::os::raw::c_int,
-    pub trt_engine_decryption_lib_path: *const ::std::os::raw::c_char,
-    pub trt_force_sequential_engine_build: ::std::os::raw::c_int,
+    pub trt_fp16_enable: ::core::ffi::c_int,
+    pub trt_int8_enable: ::core::ffi::c_int,
+    pub trt_int8_calibration_table_name: *const ::core::ffi::c_char,
+    pub trt_int8_use_native_calibration_table: ::core::ffi::c_int,
+    pub trt_dla_enable: ::core::ffi::c_int,
+    pub trt_dla_core: ::core::ffi::c_int,
+    pub trt_dump_subgraphs: ::core::ffi::c_int,
+    pub trt_engine_cache_enable: ::core::ffi::c_int,
+    pub trt_engine_cache_path: *const ::core::ffi::c_char,
+    pub trt_engine_decryption_enable: ::core::ffi::c_int,
+    pub trt_engine_decryption_lib_path: *const ::core::ffi::c_char,
+    pub trt_force_sequential_engine_build: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtTensorRTProviderOptions>(),
+        ::core::mem::size_of::<OrtTensorRTProviderOptions>(),
         104usize,
         concat!("Size of: ", stringify!(OrtTensorRTProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtTensorRTProviderOptions>(),
+        ::core::mem::align_of::<OrtTensorRTProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtTensorRTProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4091,9 +4091,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -4108,9 +4108,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4125,9 +4125,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_max_partition_iterations() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_max_partition_iterations) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_max_partition_iterations) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -4142,9 +4142,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_min_subgraph_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_min_subgraph_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_min_subgraph_size) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -4159,9 +4159,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_max_workspace_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_max_workspace_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_max_workspace_size) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -4176,9 +4176,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_fp16_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_fp16_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_fp16_enable) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -4193,9 +4193,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_enable) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -4210,9 +4210,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_calibration_table_name() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_calibration_table_name) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_calibration_table_name) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -4227,9 +4227,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_use_native_calibration_table() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_use_native_calibration_table) as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_use_native_calibration_table) as usize
                     - ptr as usize
             },
             48usize,
@@ -4245,9 +4245,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dla_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dla_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dla_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -4262,9 +4262,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dla_core() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dla_core) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dla_core) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -4279,9 +4279,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dump_subgraphs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dump_subgraphs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dump_subgraphs) as usize - ptr as usize
             },
             60usize,
             concat!(
@@ -4296,9 +4296,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_cache_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_cache_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_cache_enable) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -4313,9 +4313,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_cache_path() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_cache_path) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_cache_path) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -4330,9 +4330,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_decryption_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_decryption_enable) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -4347,9 +4347,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_decryption_lib_path() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_lib_path) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_decryption_lib_path) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -4364,9 +4364,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_force_sequential_engine_build() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_force_sequential_engine_build) as usize
+                ::core::ptr::addr_of!((*ptr).trt_force_sequential_engine_build) as usize
                     - ptr as usize
             },
             96usize,
@@ -4386,28 +4386,28 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OrtMIGraphXProviderOptions {
-    pub device_id: ::std::os::raw::c_int,
-    pub migraphx_fp16_enable: ::std::os::raw::c_int,
-    pub migraphx_int8_enable: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
+    pub migraphx_fp16_enable: ::core::ffi::c_int,
+    pub migraphx_int8_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtMIGraphXProviderOptions>(),
+        ::core::mem::size_of::<OrtMIGraphXProviderOptions>(),
         12usize,
         concat!("Size of: ", stringify!(OrtMIGraphXProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtMIGraphXProviderOptions>(),
+        ::core::mem::align_of::<OrtMIGraphXProviderOptions>(),
         4usize,
         concat!("Alignment of ", stringify!(OrtMIGraphXProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4422,9 +4422,9 @@ fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     fn test_field_migraphx_fp16_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).migraphx_fp16_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).migraphx_fp16_enable) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -4439,9 +4439,9 @@ fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     fn test_field_migraphx_int8_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).migraphx_int8_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).migraphx_int8_enable) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4463,37 +4463,37 @@ pub struct OrtOpenVINOProviderOptions {
     #[doc = " \\brief Device type string"]
     #[doc = ""]
     #[doc = " Valid settings are one of: \"CPU_FP32\", \"CPU_FP16\", \"GPU_FP32\", \"GPU_FP16\""]
-    pub device_type: *const ::std::os::raw::c_char,
+    pub device_type: *const ::core::ffi::c_char,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_vpu_fast_compile: ::std::os::raw::c_uchar,
-    pub device_id: *const ::std::os::raw::c_char,
+    pub enable_vpu_fast_compile: ::core::ffi::c_uchar,
+    pub device_id: *const ::core::ffi::c_char,
     #[doc = "< 0 = Use default number of threads"]
     pub num_of_threads: usize,
-    pub cache_dir: *const ::std::os::raw::c_char,
-    pub context: *mut ::std::os::raw::c_void,
+    pub cache_dir: *const ::core::ffi::c_char,
+    pub context: *mut ::core::ffi::c_void,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_opencl_throttling: ::std::os::raw::c_uchar,
+    pub enable_opencl_throttling: ::core::ffi::c_uchar,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_dynamic_shapes: ::std::os::raw::c_uchar,
+    pub enable_dynamic_shapes: ::core::ffi::c_uchar,
 }
 #[test]
 fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtOpenVINOProviderOptions>(),
+        ::core::mem::size_of::<OrtOpenVINOProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtOpenVINOProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtOpenVINOProviderOptions>(),
+        ::core::mem::align_of::<OrtOpenVINOProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtOpenVINOProviderOptions))
     );
     fn test_field_device_type() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4508,9 +4508,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_vpu_fast_compile() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_vpu_fast_compile) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_vpu_fast_compile) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4525,9 +4525,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -4542,9 +4542,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_num_of_threads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).num_of_threads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).num_of_threads) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -4559,9 +4559,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_cache_dir() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).cache_dir) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).cache_dir) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -4576,9 +4576,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_context() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).context) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -4593,9 +4593,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_opencl_throttling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_opencl_throttling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_opencl_throttling) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -4610,9 +4610,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_dynamic_shapes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_dynamic_shapes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_dynamic_shapes) as usize - ptr as usize
             },
             49usize,
             concat!(
@@ -4644,31 +4644,31 @@ pub struct OrtApiBase {
     #[doc = ""]
     #[doc = " One can call GetVersionString() to get the version of the Onnxruntime library for logging"]
     #[doc = " and error reporting purposes."]
-    pub GetApi: ::std::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtApi>,
+    pub GetApi: ::core::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtApi>,
     #[doc = " \\brief Returns a null terminated string of the version of the Onnxruntime library (eg: \"1.8.1\")"]
     #[doc = ""]
     #[doc = "  \\return UTF-8 encoded version string. Do not deallocate the returned buffer."]
     pub GetVersionString:
-        ::std::option::Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+        ::core::option::Option<unsafe extern "C" fn() -> *const ::core::ffi::c_char>,
 }
 #[test]
 fn bindgen_test_layout_OrtApiBase() {
     assert_eq!(
-        ::std::mem::size_of::<OrtApiBase>(),
+        ::core::mem::size_of::<OrtApiBase>(),
         16usize,
         concat!("Size of: ", stringify!(OrtApiBase))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtApiBase>(),
+        ::core::mem::align_of::<OrtApiBase>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtApiBase))
     );
     fn test_field_GetApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApiBase>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetApi) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4683,9 +4683,9 @@ fn bindgen_test_layout_OrtApiBase() {
     fn test_field_GetVersionString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApiBase>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVersionString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVersionString) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4709,30 +4709,30 @@ extern "C" {
 #[doc = " Onnxruntime will provide the working loop on custom thread creation"]
 #[doc = " Argument is an onnxruntime built-in type which will be provided when thread pool calls OrtCustomCreateThreadFn"]
 pub type OrtThreadWorkerFn =
-    ::std::option::Option<unsafe extern "C" fn(ort_worker_fn_param: *mut ::std::os::raw::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(ort_worker_fn_param: *mut ::core::ffi::c_void)>;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OrtCustomHandleType {
-    pub __place_holder: ::std::os::raw::c_char,
+    pub __place_holder: ::core::ffi::c_char,
 }
 #[test]
 fn bindgen_test_layout_OrtCustomHandleType() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCustomHandleType>(),
+        ::core::mem::size_of::<OrtCustomHandleType>(),
         1usize,
         concat!("Size of: ", stringify!(OrtCustomHandleType))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCustomHandleType>(),
+        ::core::mem::align_of::<OrtCustomHandleType>(),
         1usize,
         concat!("Alignment of ", stringify!(OrtCustomHandleType))
     );
     fn test_field___place_holder() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomHandleType>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomHandleType>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__place_holder) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__place_holder) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4750,11 +4750,11 @@ pub type OrtCustomThreadHandle = *const OrtCustomHandleType;
 #[doc = ""]
 #[doc = " The function should return a thread handle to be used in onnxruntime thread pools"]
 #[doc = " Onnxruntime will throw exception on return value of nullptr or 0, indicating that the function failed to create a thread"]
-pub type OrtCustomCreateThreadFn = ::std::option::Option<
+pub type OrtCustomCreateThreadFn = ::core::option::Option<
     unsafe extern "C" fn(
-        ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+        ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ort_thread_worker_fn: OrtThreadWorkerFn,
-        ort_worker_fn_param: *mut ::std::os::raw::c_void,
+        ort_worker_fn_param: *mut ::core::ffi::c_void,
     ) -> OrtCustomThreadHandle,
 >;
 #[doc = " \\brief Custom thread join function"]
@@ -4762,8 +4762,8 @@ pub type OrtCustomCreateThreadFn = ::std::option::Option<
 #[doc = " Onnxruntime thread pool destructor will call the function to join a custom thread."]
 #[doc = " Argument ort_custom_thread_handle is the value returned by OrtCustomCreateThreadFn"]
 pub type OrtCustomJoinThreadFn =
-    ::std::option::Option<unsafe extern "C" fn(ort_custom_thread_handle: OrtCustomThreadHandle)>;
-pub type RegisterCustomOpsFn = ::std::option::Option<
+    ::core::option::Option<unsafe extern "C" fn(ort_custom_thread_handle: OrtCustomThreadHandle)>;
+pub type RegisterCustomOpsFn = ::core::option::Option<
     unsafe extern "C" fn(options: *mut OrtSessionOptions, api: *const OrtApiBase) -> *mut OrtStatus,
 >;
 #[doc = " \\brief The C API"]
@@ -4780,10 +4780,10 @@ pub struct OrtApi {
     #[doc = " \\param[in] code"]
     #[doc = " \\param[in] msg A null-terminated string. Its contents will be copied."]
     #[doc = " \\return A new OrtStatus object, must be destroyed with OrtApi::ReleaseStatus"]
-    pub CreateStatus: ::std::option::Option<
+    pub CreateStatus: ::core::option::Option<
         unsafe extern "C" fn(
             code: OrtErrorCode,
-            msg: *const ::std::os::raw::c_char,
+            msg: *const ::core::ffi::c_char,
         ) -> *mut OrtStatus,
     >,
     #[doc = " \\brief Get OrtErrorCode from OrtStatus"]
@@ -4791,263 +4791,263 @@ pub struct OrtApi {
     #[doc = " \\param[in] status"]
     #[doc = " \\return OrtErrorCode that \\p status was created with"]
     pub GetErrorCode:
-        ::std::option::Option<unsafe extern "C" fn(status: *const OrtStatus) -> OrtErrorCode>,
+        ::core::option::Option<unsafe extern "C" fn(status: *const OrtStatus) -> OrtErrorCode>,
     #[doc = " \\brief Get error string from OrtStatus"]
     #[doc = ""]
     #[doc = " \\param[in] status"]
     #[doc = " \\return The error message inside the `status`. Do not free the returned value."]
-    pub GetErrorMessage: ::std::option::Option<
-        unsafe extern "C" fn(status: *const OrtStatus) -> *const ::std::os::raw::c_char,
+    pub GetErrorMessage: ::core::option::Option<
+        unsafe extern "C" fn(status: *const OrtStatus) -> *const ::core::ffi::c_char,
     >,
-    pub CreateEnv: ::std::option::Option<
+    pub CreateEnv: ::core::option::Option<
         unsafe extern "C" fn(
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub CreateEnvWithCustomLogger: ::std::option::Option<
+    pub CreateEnvWithCustomLogger: ::core::option::Option<
         unsafe extern "C" fn(
             logging_function: OrtLoggingFunction,
-            logger_param: *mut ::std::os::raw::c_void,
+            logger_param: *mut ::core::ffi::c_void,
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
     pub EnableTelemetryEvents:
-        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
     pub DisableTelemetryEvents:
-        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
-    pub CreateSession: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+    pub CreateSession: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_path: *const ::std::os::raw::c_char,
+            model_path: *const ::core::ffi::c_char,
             options: *const OrtSessionOptions,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionFromArray: ::std::option::Option<
+    pub CreateSessionFromArray: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_data: *const ::std::os::raw::c_void,
+            model_data: *const ::core::ffi::c_void,
             model_data_length: usize,
             options: *const OrtSessionOptions,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub Run: ::std::option::Option<
+    pub Run: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             run_options: *const OrtRunOptions,
-            input_names: *const *const ::std::os::raw::c_char,
+            input_names: *const *const ::core::ffi::c_char,
             inputs: *const *const OrtValue,
             input_len: usize,
-            output_names: *const *const ::std::os::raw::c_char,
+            output_names: *const *const ::core::ffi::c_char,
             output_names_len: usize,
             outputs: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionOptions: ::std::option::Option<
+    pub CreateSessionOptions: ::core::option::Option<
         unsafe extern "C" fn(options: *mut *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub SetOptimizedModelFilePath: ::std::option::Option<
+    pub SetOptimizedModelFilePath: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            optimized_model_filepath: *const ::std::os::raw::c_char,
+            optimized_model_filepath: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CloneSessionOptions: ::std::option::Option<
+    pub CloneSessionOptions: ::core::option::Option<
         unsafe extern "C" fn(
             in_options: *const OrtSessionOptions,
             out_options: *mut *mut OrtSessionOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionExecutionMode: ::std::option::Option<
+    pub SetSessionExecutionMode: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             execution_mode: ExecutionMode,
         ) -> OrtStatusPtr,
     >,
-    pub EnableProfiling: ::std::option::Option<
+    pub EnableProfiling: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            profile_file_prefix: *const ::std::os::raw::c_char,
+            profile_file_prefix: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub DisableProfiling: ::std::option::Option<
+    pub DisableProfiling: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub EnableMemPattern: ::std::option::Option<
+    pub EnableMemPattern: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub DisableMemPattern: ::std::option::Option<
+    pub DisableMemPattern: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub EnableCpuMemArena: ::std::option::Option<
+    pub EnableCpuMemArena: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub DisableCpuMemArena: ::std::option::Option<
+    pub DisableCpuMemArena: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub SetSessionLogId: ::std::option::Option<
+    pub SetSessionLogId: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionLogVerbosityLevel: ::std::option::Option<
+    pub SetSessionLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            session_log_verbosity_level: ::std::os::raw::c_int,
+            session_log_verbosity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionLogSeverityLevel: ::std::option::Option<
+    pub SetSessionLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            session_log_severity_level: ::std::os::raw::c_int,
+            session_log_severity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionGraphOptimizationLevel: ::std::option::Option<
+    pub SetSessionGraphOptimizationLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             graph_optimization_level: GraphOptimizationLevel,
         ) -> OrtStatusPtr,
     >,
-    pub SetIntraOpNumThreads: ::std::option::Option<
+    pub SetIntraOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            intra_op_num_threads: ::std::os::raw::c_int,
+            intra_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetInterOpNumThreads: ::std::option::Option<
+    pub SetInterOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            inter_op_num_threads: ::std::os::raw::c_int,
+            inter_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCustomOpDomain: ::std::option::Option<
+    pub CreateCustomOpDomain: ::core::option::Option<
         unsafe extern "C" fn(
-            domain: *const ::std::os::raw::c_char,
+            domain: *const ::core::ffi::c_char,
             out: *mut *mut OrtCustomOpDomain,
         ) -> OrtStatusPtr,
     >,
-    pub CustomOpDomain_Add: ::std::option::Option<
+    pub CustomOpDomain_Add: ::core::option::Option<
         unsafe extern "C" fn(
             custom_op_domain: *mut OrtCustomOpDomain,
             op: *const OrtCustomOp,
         ) -> OrtStatusPtr,
     >,
-    pub AddCustomOpDomain: ::std::option::Option<
+    pub AddCustomOpDomain: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             custom_op_domain: *mut OrtCustomOpDomain,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsLibrary: ::std::option::Option<
+    pub RegisterCustomOpsLibrary: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            library_path: *const ::std::os::raw::c_char,
-            library_handle: *mut *mut ::std::os::raw::c_void,
+            library_path: *const ::core::ffi::c_char,
+            library_handle: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetInputCount: ::std::option::Option<
+    pub SessionGetInputCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputCount: ::std::option::Option<
+    pub SessionGetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerCount: ::std::option::Option<
+    pub SessionGetOverridableInitializerCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetInputTypeInfo: ::std::option::Option<
+    pub SessionGetInputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputTypeInfo: ::std::option::Option<
+    pub SessionGetOutputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerTypeInfo: ::std::option::Option<
+    pub SessionGetOverridableInitializerTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetInputName: ::std::option::Option<
+    pub SessionGetInputName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputName: ::std::option::Option<
+    pub SessionGetOutputName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerName: ::std::option::Option<
+    pub SessionGetOverridableInitializerName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     pub CreateRunOptions:
-        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtRunOptions) -> OrtStatusPtr>,
-    pub RunOptionsSetRunLogVerbosityLevel: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(out: *mut *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub RunOptionsSetRunLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            log_verbosity_level: ::std::os::raw::c_int,
+            log_verbosity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsSetRunLogSeverityLevel: ::std::option::Option<
+    pub RunOptionsSetRunLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            log_severity_level: ::std::os::raw::c_int,
+            log_severity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsSetRunTag: ::std::option::Option<
+    pub RunOptionsSetRunTag: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            run_tag: *const ::std::os::raw::c_char,
+            run_tag: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunLogVerbosityLevel: ::std::option::Option<
+    pub RunOptionsGetRunLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            log_verbosity_level: *mut ::std::os::raw::c_int,
+            log_verbosity_level: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunLogSeverityLevel: ::std::option::Option<
+    pub RunOptionsGetRunLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            log_severity_level: *mut ::std::os::raw::c_int,
+            log_severity_level: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunTag: ::std::option::Option<
+    pub RunOptionsGetRunTag: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            run_tag: *mut *const ::std::os::raw::c_char,
+            run_tag: *mut *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     pub RunOptionsSetTerminate:
-        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
     pub RunOptionsUnsetTerminate:
-        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
-    pub CreateTensorAsOrtValue: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub CreateTensorAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             allocator: *mut OrtAllocator,
             shape: *const i64,
@@ -5056,10 +5056,10 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateTensorWithDataAsOrtValue: ::std::option::Option<
+    pub CreateTensorWithDataAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtMemoryInfo,
-            p_data: *mut ::std::os::raw::c_void,
+            p_data: *mut ::core::ffi::c_void,
             p_data_len: usize,
             shape: *const i64,
             shape_len: usize,
@@ -5067,187 +5067,187 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub IsTensor: ::std::option::Option<
+    pub IsTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorMutableData: ::std::option::Option<
+    pub GetTensorMutableData: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub FillStringTensor: ::std::option::Option<
+    pub FillStringTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            s: *const *const ::std::os::raw::c_char,
+            s: *const *const ::core::ffi::c_char,
             s_len: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetStringTensorDataLength: ::std::option::Option<
+    pub GetStringTensorDataLength: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, len: *mut usize) -> OrtStatusPtr,
     >,
-    pub GetStringTensorContent: ::std::option::Option<
+    pub GetStringTensorContent: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            s: *mut ::std::os::raw::c_void,
+            s: *mut ::core::ffi::c_void,
             s_len: usize,
             offsets: *mut usize,
             offsets_len: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToTensorInfo: ::std::option::Option<
+    pub CastTypeInfoToTensorInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetOnnxTypeFromTypeInfo: ::std::option::Option<
+    pub GetOnnxTypeFromTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(type_info: *const OrtTypeInfo, out: *mut ONNXType) -> OrtStatusPtr,
     >,
-    pub CreateTensorTypeAndShapeInfo: ::std::option::Option<
+    pub CreateTensorTypeAndShapeInfo: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtTensorTypeAndShapeInfo) -> OrtStatusPtr,
     >,
-    pub SetTensorElementType: ::std::option::Option<
+    pub SetTensorElementType: ::core::option::Option<
         unsafe extern "C" fn(
             info: *mut OrtTensorTypeAndShapeInfo,
             type_: ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub SetDimensions: ::std::option::Option<
+    pub SetDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *mut OrtTensorTypeAndShapeInfo,
             dim_values: *const i64,
             dim_count: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorElementType: ::std::option::Option<
+    pub GetTensorElementType: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub GetDimensionsCount: ::std::option::Option<
+    pub GetDimensionsCount: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetDimensions: ::std::option::Option<
+    pub GetDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             dim_values: *mut i64,
             dim_values_length: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetSymbolicDimensions: ::std::option::Option<
+    pub GetSymbolicDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
-            dim_params: *mut *const ::std::os::raw::c_char,
+            dim_params: *mut *const ::core::ffi::c_char,
             dim_params_length: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorShapeElementCount: ::std::option::Option<
+    pub GetTensorShapeElementCount: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorTypeAndShape: ::std::option::Option<
+    pub GetTensorTypeAndShape: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetTypeInfo: ::std::option::Option<
+    pub GetTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut *mut OrtTypeInfo) -> OrtStatusPtr,
     >,
-    pub GetValueType: ::std::option::Option<
+    pub GetValueType: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut ONNXType) -> OrtStatusPtr,
     >,
-    pub CreateMemoryInfo: ::std::option::Option<
+    pub CreateMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             type_: OrtAllocatorType,
-            id: ::std::os::raw::c_int,
+            id: ::core::ffi::c_int,
             mem_type: OrtMemType,
             out: *mut *mut OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCpuMemoryInfo: ::std::option::Option<
+    pub CreateCpuMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_: OrtAllocatorType,
             mem_type: OrtMemType,
             out: *mut *mut OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CompareMemoryInfo: ::std::option::Option<
+    pub CompareMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info1: *const OrtMemoryInfo,
             info2: *const OrtMemoryInfo,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetName: ::std::option::Option<
+    pub MemoryInfoGetName: ::core::option::Option<
         unsafe extern "C" fn(
             ptr: *const OrtMemoryInfo,
-            out: *mut *const ::std::os::raw::c_char,
+            out: *mut *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetId: ::std::option::Option<
+    pub MemoryInfoGetId: ::core::option::Option<
         unsafe extern "C" fn(
             ptr: *const OrtMemoryInfo,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetMemType: ::std::option::Option<
+    pub MemoryInfoGetMemType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtMemType) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetType: ::std::option::Option<
+    pub MemoryInfoGetType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtAllocatorType) -> OrtStatusPtr,
     >,
-    pub AllocatorAlloc: ::std::option::Option<
+    pub AllocatorAlloc: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *mut OrtAllocator,
             size: usize,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub AllocatorFree: ::std::option::Option<
+    pub AllocatorFree: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *mut OrtAllocator,
-            p: *mut ::std::os::raw::c_void,
+            p: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub AllocatorGetInfo: ::std::option::Option<
+    pub AllocatorGetInfo: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *const OrtAllocator,
             out: *mut *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
     pub GetAllocatorWithDefaultOptions:
-        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtAllocator) -> OrtStatusPtr>,
-    pub AddFreeDimensionOverride: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(out: *mut *mut OrtAllocator) -> OrtStatusPtr>,
+    pub AddFreeDimensionOverride: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            dim_denotation: *const ::std::os::raw::c_char,
+            dim_denotation: *const ::core::ffi::c_char,
             dim_value: i64,
         ) -> OrtStatusPtr,
     >,
-    pub GetValue: ::std::option::Option<
+    pub GetValue: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            index: ::std::os::raw::c_int,
+            index: ::core::ffi::c_int,
             allocator: *mut OrtAllocator,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub GetValueCount: ::std::option::Option<
+    pub GetValueCount: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub CreateValue: ::std::option::Option<
+    pub CreateValue: ::core::option::Option<
         unsafe extern "C" fn(
             in_: *const *const OrtValue,
             num_values: usize,
@@ -5255,60 +5255,60 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateOpaqueValue: ::std::option::Option<
+    pub CreateOpaqueValue: ::core::option::Option<
         unsafe extern "C" fn(
-            domain_name: *const ::std::os::raw::c_char,
-            type_name: *const ::std::os::raw::c_char,
-            data_container: *const ::std::os::raw::c_void,
+            domain_name: *const ::core::ffi::c_char,
+            type_name: *const ::core::ffi::c_char,
+            data_container: *const ::core::ffi::c_void,
             data_container_size: usize,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub GetOpaqueValue: ::std::option::Option<
+    pub GetOpaqueValue: ::core::option::Option<
         unsafe extern "C" fn(
-            domain_name: *const ::std::os::raw::c_char,
-            type_name: *const ::std::os::raw::c_char,
+            domain_name: *const ::core::ffi::c_char,
+            type_name: *const ::core::ffi::c_char,
             in_: *const OrtValue,
-            data_container: *mut ::std::os::raw::c_void,
+            data_container: *mut ::core::ffi::c_void,
             data_container_size: usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_float: ::std::option::Option<
+    pub KernelInfoGetAttribute_float: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut f32,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_int64: ::std::option::Option<
+    pub KernelInfoGetAttribute_int64: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut i64,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_string: ::std::option::Option<
+    pub KernelInfoGetAttribute_string: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
-            out: *mut ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetInputCount: ::std::option::Option<
+    pub KernelContext_GetInputCount: ::core::option::Option<
         unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetOutputCount: ::std::option::Option<
+    pub KernelContext_GetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetInput: ::std::option::Option<
+    pub KernelContext_GetInput: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             index: usize,
             out: *mut *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetOutput: ::std::option::Option<
+    pub KernelContext_GetOutput: ::core::option::Option<
         unsafe extern "C" fn(
             context: *mut OrtKernelContext,
             index: usize,
@@ -5317,234 +5317,234 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseEnv: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtEnv)>,
-    pub ReleaseStatus: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtStatus)>,
-    pub ReleaseMemoryInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMemoryInfo)>,
-    pub ReleaseSession: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSession)>,
-    pub ReleaseValue: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtValue)>,
-    pub ReleaseRunOptions: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtRunOptions)>,
-    pub ReleaseTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTypeInfo)>,
+    pub ReleaseEnv: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtEnv)>,
+    pub ReleaseStatus: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtStatus)>,
+    pub ReleaseMemoryInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtMemoryInfo)>,
+    pub ReleaseSession: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSession)>,
+    pub ReleaseValue: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtValue)>,
+    pub ReleaseRunOptions: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtRunOptions)>,
+    pub ReleaseTypeInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTypeInfo)>,
     pub ReleaseTensorTypeAndShapeInfo:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorTypeAndShapeInfo)>,
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTensorTypeAndShapeInfo)>,
     pub ReleaseSessionOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSessionOptions)>,
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSessionOptions)>,
     pub ReleaseCustomOpDomain:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCustomOpDomain)>,
-    pub GetDenotationFromTypeInfo: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCustomOpDomain)>,
+    pub GetDenotationFromTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
-            denotation: *mut *const ::std::os::raw::c_char,
+            denotation: *mut *const ::core::ffi::c_char,
             len: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToMapTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToMapTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtMapTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToSequenceTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToSequenceTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtSequenceTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetMapKeyType: ::std::option::Option<
+    pub GetMapKeyType: ::core::option::Option<
         unsafe extern "C" fn(
             map_type_info: *const OrtMapTypeInfo,
             out: *mut ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub GetMapValueType: ::std::option::Option<
+    pub GetMapValueType: ::core::option::Option<
         unsafe extern "C" fn(
             map_type_info: *const OrtMapTypeInfo,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSequenceElementType: ::std::option::Option<
+    pub GetSequenceElementType: ::core::option::Option<
         unsafe extern "C" fn(
             sequence_type_info: *const OrtSequenceTypeInfo,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseMapTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMapTypeInfo)>,
+    pub ReleaseMapTypeInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtMapTypeInfo)>,
     pub ReleaseSequenceTypeInfo:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSequenceTypeInfo)>,
-    pub SessionEndProfiling: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSequenceTypeInfo)>,
+    pub SessionEndProfiling: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             allocator: *mut OrtAllocator,
-            out: *mut *mut ::std::os::raw::c_char,
+            out: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetModelMetadata: ::std::option::Option<
+    pub SessionGetModelMetadata: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             out: *mut *mut OrtModelMetadata,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetProducerName: ::std::option::Option<
+    pub ModelMetadataGetProducerName: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetGraphName: ::std::option::Option<
+    pub ModelMetadataGetGraphName: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetDomain: ::std::option::Option<
+    pub ModelMetadataGetDomain: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetDescription: ::std::option::Option<
+    pub ModelMetadataGetDescription: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataLookupCustomMetadataMap: ::std::option::Option<
+    pub ModelMetadataLookupCustomMetadataMap: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            key: *const ::std::os::raw::c_char,
-            value: *mut *mut ::std::os::raw::c_char,
+            key: *const ::core::ffi::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetVersion: ::std::option::Option<
+    pub ModelMetadataGetVersion: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             value: *mut i64,
         ) -> OrtStatusPtr,
     >,
     pub ReleaseModelMetadata:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtModelMetadata)>,
-    pub CreateEnvWithGlobalThreadPools: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtModelMetadata)>,
+    pub CreateEnvWithGlobalThreadPools: ::core::option::Option<
         unsafe extern "C" fn(
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             tp_options: *const OrtThreadingOptions,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub DisablePerSessionThreads: ::std::option::Option<
+    pub DisablePerSessionThreads: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub CreateThreadingOptions: ::std::option::Option<
+    pub CreateThreadingOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtThreadingOptions) -> OrtStatusPtr,
     >,
     pub ReleaseThreadingOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtThreadingOptions)>,
-    pub ModelMetadataGetCustomMetadataMapKeys: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtThreadingOptions)>,
+    pub ModelMetadataGetCustomMetadataMapKeys: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            keys: *mut *mut *mut ::std::os::raw::c_char,
+            keys: *mut *mut *mut ::core::ffi::c_char,
             num_keys: *mut i64,
         ) -> OrtStatusPtr,
     >,
-    pub AddFreeDimensionOverrideByName: ::std::option::Option<
+    pub AddFreeDimensionOverrideByName: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            dim_name: *const ::std::os::raw::c_char,
+            dim_name: *const ::core::ffi::c_char,
             dim_value: i64,
         ) -> OrtStatusPtr,
     >,
-    pub GetAvailableProviders: ::std::option::Option<
+    pub GetAvailableProviders: ::core::option::Option<
         unsafe extern "C" fn(
-            out_ptr: *mut *mut *mut ::std::os::raw::c_char,
-            provider_length: *mut ::std::os::raw::c_int,
+            out_ptr: *mut *mut *mut ::core::ffi::c_char,
+            provider_length: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseAvailableProviders: ::std::option::Option<
+    pub ReleaseAvailableProviders: ::core::option::Option<
         unsafe extern "C" fn(
-            ptr: *mut *mut ::std::os::raw::c_char,
-            providers_length: ::std::os::raw::c_int,
+            ptr: *mut *mut ::core::ffi::c_char,
+            providers_length: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetStringTensorElementLength: ::std::option::Option<
+    pub GetStringTensorElementLength: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, index: usize, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub GetStringTensorElement: ::std::option::Option<
+    pub GetStringTensorElement: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             s_len: usize,
             index: usize,
-            s: *mut ::std::os::raw::c_void,
+            s: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub FillStringTensorElement: ::std::option::Option<
+    pub FillStringTensorElement: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            s: *const ::std::os::raw::c_char,
+            s: *const ::core::ffi::c_char,
             index: usize,
         ) -> OrtStatusPtr,
     >,
-    pub AddSessionConfigEntry: ::std::option::Option<
+    pub AddSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *const ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CreateAllocator: ::std::option::Option<
+    pub CreateAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             mem_info: *const OrtMemoryInfo,
             out: *mut *mut OrtAllocator,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseAllocator: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtAllocator)>,
-    pub RunWithBinding: ::std::option::Option<
+    pub ReleaseAllocator: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtAllocator)>,
+    pub RunWithBinding: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             run_options: *const OrtRunOptions,
             binding_ptr: *const OrtIoBinding,
         ) -> OrtStatusPtr,
     >,
-    pub CreateIoBinding: ::std::option::Option<
+    pub CreateIoBinding: ::core::option::Option<
         unsafe extern "C" fn(session: *mut OrtSession, out: *mut *mut OrtIoBinding) -> OrtStatusPtr,
     >,
-    pub ReleaseIoBinding: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtIoBinding)>,
-    pub BindInput: ::std::option::Option<
+    pub ReleaseIoBinding: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtIoBinding)>,
+    pub BindInput: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val_ptr: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub BindOutput: ::std::option::Option<
+    pub BindOutput: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val_ptr: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub BindOutputToDevice: ::std::option::Option<
+    pub BindOutputToDevice: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             mem_info_ptr: *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetBoundOutputNames: ::std::option::Option<
+    pub GetBoundOutputNames: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *const OrtIoBinding,
             allocator: *mut OrtAllocator,
-            buffer: *mut *mut ::std::os::raw::c_char,
+            buffer: *mut *mut ::core::ffi::c_char,
             lengths: *mut *mut usize,
             count: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetBoundOutputValues: ::std::option::Option<
+    pub GetBoundOutputValues: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *const OrtIoBinding,
             allocator: *mut OrtAllocator,
@@ -5554,219 +5554,219 @@ pub struct OrtApi {
     >,
     #[doc = " \\brief Clears any previously set Inputs for an ::OrtIoBinding"]
     pub ClearBoundInputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
     #[doc = " \\brief Clears any previously set Outputs for an ::OrtIoBinding"]
     pub ClearBoundOutputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
-    pub TensorAt: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+    pub TensorAt: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
             location_values: *const i64,
             location_values_count: usize,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub CreateAndRegisterAllocator: ::std::option::Option<
+    pub CreateAndRegisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             env: *mut OrtEnv,
             mem_info: *const OrtMemoryInfo,
             arena_cfg: *const OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub SetLanguageProjection: ::std::option::Option<
+    pub SetLanguageProjection: ::core::option::Option<
         unsafe extern "C" fn(
             ort_env: *const OrtEnv,
             projection: OrtLanguageProjection,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetProfilingStartTimeNs: ::std::option::Option<
+    pub SessionGetProfilingStartTimeNs: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut u64) -> OrtStatusPtr,
     >,
-    pub SetGlobalIntraOpNumThreads: ::std::option::Option<
+    pub SetGlobalIntraOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            intra_op_num_threads: ::std::os::raw::c_int,
+            intra_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalInterOpNumThreads: ::std::option::Option<
+    pub SetGlobalInterOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            inter_op_num_threads: ::std::os::raw::c_int,
+            inter_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalSpinControl: ::std::option::Option<
+    pub SetGlobalSpinControl: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            allow_spinning: ::std::os::raw::c_int,
+            allow_spinning: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub AddInitializer: ::std::option::Option<
+    pub AddInitializer: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateEnvWithCustomLoggerAndGlobalThreadPools: ::std::option::Option<
+    pub CreateEnvWithCustomLoggerAndGlobalThreadPools: ::core::option::Option<
         unsafe extern "C" fn(
             logging_function: OrtLoggingFunction,
-            logger_param: *mut ::std::os::raw::c_void,
+            logger_param: *mut ::core::ffi::c_void,
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             tp_options: *const OrtThreadingOptions,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_CUDA: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_CUDA: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cuda_options: *const OrtCUDAProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_ROCM: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_ROCM: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             rocm_options: *const OrtROCMProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_OpenVINO: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_OpenVINO: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             provider_options: *const OrtOpenVINOProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalDenormalAsZero: ::std::option::Option<
+    pub SetGlobalDenormalAsZero: ::core::option::Option<
         unsafe extern "C" fn(tp_options: *mut OrtThreadingOptions) -> OrtStatusPtr,
     >,
-    pub CreateArenaCfg: ::std::option::Option<
+    pub CreateArenaCfg: ::core::option::Option<
         unsafe extern "C" fn(
             max_mem: usize,
-            arena_extend_strategy: ::std::os::raw::c_int,
-            initial_chunk_size_bytes: ::std::os::raw::c_int,
-            max_dead_bytes_per_chunk: ::std::os::raw::c_int,
+            arena_extend_strategy: ::core::ffi::c_int,
+            initial_chunk_size_bytes: ::core::ffi::c_int,
+            max_dead_bytes_per_chunk: ::core::ffi::c_int,
             out: *mut *mut OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseArenaCfg: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtArenaCfg)>,
-    pub ModelMetadataGetGraphDescription: ::std::option::Option<
+    pub ReleaseArenaCfg: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtArenaCfg)>,
+    pub ModelMetadataGetGraphDescription: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_TensorRT: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_TensorRT: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             tensorrt_options: *const OrtTensorRTProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetCurrentGpuDeviceId: ::std::option::Option<
-        unsafe extern "C" fn(device_id: ::std::os::raw::c_int) -> OrtStatusPtr,
+    pub SetCurrentGpuDeviceId: ::core::option::Option<
+        unsafe extern "C" fn(device_id: ::core::ffi::c_int) -> OrtStatusPtr,
     >,
-    pub GetCurrentGpuDeviceId: ::std::option::Option<
-        unsafe extern "C" fn(device_id: *mut ::std::os::raw::c_int) -> OrtStatusPtr,
+    pub GetCurrentGpuDeviceId: ::core::option::Option<
+        unsafe extern "C" fn(device_id: *mut ::core::ffi::c_int) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttributeArray_float: ::std::option::Option<
+    pub KernelInfoGetAttributeArray_float: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut f32,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttributeArray_int64: ::std::option::Option<
+    pub KernelInfoGetAttributeArray_int64: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut i64,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub CreateArenaCfgV2: ::std::option::Option<
+    pub CreateArenaCfgV2: ::core::option::Option<
         unsafe extern "C" fn(
-            arena_config_keys: *const *const ::std::os::raw::c_char,
+            arena_config_keys: *const *const ::core::ffi::c_char,
             arena_config_values: *const usize,
             num_keys: usize,
             out: *mut *mut OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub AddRunConfigEntry: ::std::option::Option<
+    pub AddRunConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *const ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CreatePrepackedWeightsContainer: ::std::option::Option<
+    pub CreatePrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtPrepackedWeightsContainer) -> OrtStatusPtr,
     >,
     pub ReleasePrepackedWeightsContainer:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtPrepackedWeightsContainer)>,
-    pub CreateSessionWithPrepackedWeightsContainer: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtPrepackedWeightsContainer)>,
+    pub CreateSessionWithPrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_path: *const ::std::os::raw::c_char,
+            model_path: *const ::core::ffi::c_char,
             options: *const OrtSessionOptions,
             prepacked_weights_container: *mut OrtPrepackedWeightsContainer,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionFromArrayWithPrepackedWeightsContainer: ::std::option::Option<
+    pub CreateSessionFromArrayWithPrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_data: *const ::std::os::raw::c_void,
+            model_data: *const ::core::ffi::c_void,
             model_data_length: usize,
             options: *const OrtSessionOptions,
             prepacked_weights_container: *mut OrtPrepackedWeightsContainer,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_TensorRT_V2: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_TensorRT_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             tensorrt_options: *const OrtTensorRTProviderOptionsV2,
         ) -> OrtStatusPtr,
     >,
-    pub CreateTensorRTProviderOptions: ::std::option::Option<
+    pub CreateTensorRTProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtTensorRTProviderOptionsV2) -> OrtStatusPtr,
     >,
-    pub UpdateTensorRTProviderOptions: ::std::option::Option<
+    pub UpdateTensorRTProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             tensorrt_options: *mut OrtTensorRTProviderOptionsV2,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorRTProviderOptionsAsString: ::std::option::Option<
+    pub GetTensorRTProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             tensorrt_options: *const OrtTensorRTProviderOptionsV2,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtTensorRTProviderOptionsV2"]
     #[doc = ""]
     #[doc = " \\note This is an exception in the naming convention of other Release* functions, as the name of the method does not have the V2 suffix, but the type does"]
     pub ReleaseTensorRTProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorRTProviderOptionsV2)>,
-    pub EnableOrtCustomOps: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTensorRTProviderOptionsV2)>,
+    pub EnableOrtCustomOps: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub RegisterAllocator: ::std::option::Option<
+    pub RegisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(env: *mut OrtEnv, allocator: *mut OrtAllocator) -> OrtStatusPtr,
     >,
-    pub UnregisterAllocator: ::std::option::Option<
+    pub UnregisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(env: *mut OrtEnv, mem_info: *const OrtMemoryInfo) -> OrtStatusPtr,
     >,
-    pub IsSparseTensor: ::std::option::Option<
+    pub IsSparseTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSparseTensorAsOrtValue: ::std::option::Option<
+    pub CreateSparseTensorAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             allocator: *mut OrtAllocator,
             dense_shape: *const i64,
@@ -5775,46 +5775,46 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorCoo: ::std::option::Option<
+    pub FillSparseTensorCoo: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             indices_data: *const i64,
             indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorCsr: ::std::option::Option<
+    pub FillSparseTensorCsr: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             inner_indices_data: *const i64,
             inner_indices_num: usize,
             outer_indices_data: *const i64,
             outer_indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorBlockSparse: ::std::option::Option<
+    pub FillSparseTensorBlockSparse: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             indices_shape_data: *const i64,
             indices_shape_len: usize,
             indices_data: *const i32,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSparseTensorWithValuesAsOrtValue: ::std::option::Option<
+    pub CreateSparseTensorWithValuesAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtMemoryInfo,
-            p_data: *mut ::std::os::raw::c_void,
+            p_data: *mut ::core::ffi::c_void,
             dense_shape: *const i64,
             dense_shape_len: usize,
             values_shape: *const i64,
@@ -5823,14 +5823,14 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub UseCooIndices: ::std::option::Option<
+    pub UseCooIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             indices_data: *mut i64,
             indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub UseCsrIndices: ::std::option::Option<
+    pub UseCsrIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             inner_data: *mut i64,
@@ -5839,7 +5839,7 @@ pub struct OrtApi {
             outer_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub UseBlockSparseIndices: ::std::option::Option<
+    pub UseBlockSparseIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             indices_shape: *const i64,
@@ -5847,123 +5847,123 @@ pub struct OrtApi {
             indices_data: *mut i32,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorFormat: ::std::option::Option<
+    pub GetSparseTensorFormat: ::core::option::Option<
         unsafe extern "C" fn(ort_value: *const OrtValue, out: *mut OrtSparseFormat) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorValuesTypeAndShape: ::std::option::Option<
+    pub GetSparseTensorValuesTypeAndShape: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorValues: ::std::option::Option<
+    pub GetSparseTensorValues: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
-            out: *mut *const ::std::os::raw::c_void,
+            out: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorIndicesTypeShape: ::std::option::Option<
+    pub GetSparseTensorIndicesTypeShape: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             indices_format: OrtSparseIndicesFormat,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorIndices: ::std::option::Option<
+    pub GetSparseTensorIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             indices_format: OrtSparseIndicesFormat,
             num_indices: *mut usize,
-            indices: *mut *const ::std::os::raw::c_void,
+            indices: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub HasValue: ::std::option::Option<
+    pub HasValue: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetGPUComputeStream: ::std::option::Option<
+    pub KernelContext_GetGPUComputeStream: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorMemoryInfo: ::std::option::Option<
+    pub GetTensorMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             mem_info: *mut *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetExecutionProviderApi: ::std::option::Option<
+    pub GetExecutionProviderApi: ::core::option::Option<
         unsafe extern "C" fn(
-            provider_name: *const ::std::os::raw::c_char,
+            provider_name: *const ::core::ffi::c_char,
             version: u32,
-            provider_api: *mut *const ::std::os::raw::c_void,
+            provider_api: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomCreateThreadFn: ::std::option::Option<
+    pub SessionOptionsSetCustomCreateThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomThreadCreationOptions: ::std::option::Option<
+    pub SessionOptionsSetCustomThreadCreationOptions: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+            ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomJoinThreadFn: ::std::option::Option<
+    pub SessionOptionsSetCustomJoinThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomCreateThreadFn: ::std::option::Option<
+    pub SetGlobalCustomCreateThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
             ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomThreadCreationOptions: ::std::option::Option<
+    pub SetGlobalCustomThreadCreationOptions: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+            ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomJoinThreadFn: ::std::option::Option<
+    pub SetGlobalCustomJoinThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
             ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
         ) -> OrtStatusPtr,
     >,
     pub SynchronizeBoundInputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
     pub SynchronizeBoundOutputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
-    pub SessionOptionsAppendExecutionProvider_CUDA_V2: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+    pub SessionOptionsAppendExecutionProvider_CUDA_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cuda_options: *const OrtCUDAProviderOptionsV2,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCUDAProviderOptions: ::std::option::Option<
+    pub CreateCUDAProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtCUDAProviderOptionsV2) -> OrtStatusPtr,
     >,
-    pub UpdateCUDAProviderOptions: ::std::option::Option<
+    pub UpdateCUDAProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             cuda_options: *mut OrtCUDAProviderOptionsV2,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetCUDAProviderOptionsAsString: ::std::option::Option<
+    pub GetCUDAProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             cuda_options: *const OrtCUDAProviderOptionsV2,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtCUDAProviderOptionsV2"]
@@ -5972,74 +5972,74 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.11."]
     pub ReleaseCUDAProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCUDAProviderOptionsV2)>,
-    pub SessionOptionsAppendExecutionProvider_MIGraphX: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCUDAProviderOptionsV2)>,
+    pub SessionOptionsAppendExecutionProvider_MIGraphX: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             migraphx_options: *const OrtMIGraphXProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub AddExternalInitializers: ::std::option::Option<
+    pub AddExternalInitializers: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            initializer_names: *const *const ::std::os::raw::c_char,
+            initializer_names: *const *const ::core::ffi::c_char,
             initializers: *const *const OrtValue,
             initializers_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CreateOpAttr: ::std::option::Option<
+    pub CreateOpAttr: ::core::option::Option<
         unsafe extern "C" fn(
-            name: *const ::std::os::raw::c_char,
-            data: *const ::std::os::raw::c_void,
-            len: ::std::os::raw::c_int,
+            name: *const ::core::ffi::c_char,
+            data: *const ::core::ffi::c_void,
+            len: ::core::ffi::c_int,
             type_: OrtOpAttrType,
             op_attr: *mut *mut OrtOpAttr,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseOpAttr: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOpAttr)>,
-    pub CreateOp: ::std::option::Option<
+    pub ReleaseOpAttr: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtOpAttr)>,
+    pub CreateOp: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            op_name: *const ::std::os::raw::c_char,
-            domain: *const ::std::os::raw::c_char,
-            version: ::std::os::raw::c_int,
-            type_constraint_names: *mut *const ::std::os::raw::c_char,
+            op_name: *const ::core::ffi::c_char,
+            domain: *const ::core::ffi::c_char,
+            version: ::core::ffi::c_int,
+            type_constraint_names: *mut *const ::core::ffi::c_char,
             type_constraint_values: *const ONNXTensorElementDataType,
-            type_constraint_count: ::std::os::raw::c_int,
+            type_constraint_count: ::core::ffi::c_int,
             attr_values: *const *const OrtOpAttr,
-            attr_count: ::std::os::raw::c_int,
-            input_count: ::std::os::raw::c_int,
-            output_count: ::std::os::raw::c_int,
+            attr_count: ::core::ffi::c_int,
+            input_count: ::core::ffi::c_int,
+            output_count: ::core::ffi::c_int,
             ort_op: *mut *mut OrtOp,
         ) -> OrtStatusPtr,
     >,
-    pub InvokeOp: ::std::option::Option<
+    pub InvokeOp: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             ort_op: *const OrtOp,
             input_values: *const *const OrtValue,
-            input_count: ::std::os::raw::c_int,
+            input_count: ::core::ffi::c_int,
             output_values: *const *mut OrtValue,
-            output_count: ::std::os::raw::c_int,
+            output_count: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseOp: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOp)>,
-    pub SessionOptionsAppendExecutionProvider: ::std::option::Option<
+    pub ReleaseOp: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtOp)>,
+    pub SessionOptionsAppendExecutionProvider: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            provider_name: *const ::std::os::raw::c_char,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_name: *const ::core::ffi::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CopyKernelInfo: ::std::option::Option<
+    pub CopyKernelInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             info_copy: *mut *mut OrtKernelInfo,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseKernelInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtKernelInfo)>,
+    pub ReleaseKernelInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtKernelInfo)>,
     #[doc = " \\name Ort Training"]
     #[doc = " @{"]
     #[doc = "** \\brief Gets the Training C Api struct"]
@@ -6057,29 +6057,29 @@ pub struct OrtApi {
     #[doc = "* \\since Version 1.13"]
     #[doc = "*/"]
     pub GetTrainingApi:
-        ::std::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtTrainingApi>,
-    pub SessionOptionsAppendExecutionProvider_CANN: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtTrainingApi>,
+    pub SessionOptionsAppendExecutionProvider_CANN: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cann_options: *const OrtCANNProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCANNProviderOptions: ::std::option::Option<
+    pub CreateCANNProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtCANNProviderOptions) -> OrtStatusPtr,
     >,
-    pub UpdateCANNProviderOptions: ::std::option::Option<
+    pub UpdateCANNProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             cann_options: *mut OrtCANNProviderOptions,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetCANNProviderOptionsAsString: ::std::option::Option<
+    pub GetCANNProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             cann_options: *const OrtCANNProviderOptions,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an OrtCANNProviderOptions"]
@@ -6088,183 +6088,183 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.13."]
     pub ReleaseCANNProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCANNProviderOptions)>,
-    pub MemoryInfoGetDeviceType: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCANNProviderOptions)>,
+    pub MemoryInfoGetDeviceType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtMemoryInfoDeviceType),
     >,
-    pub UpdateEnvWithCustomLogLevel: ::std::option::Option<
+    pub UpdateEnvWithCustomLogLevel: ::core::option::Option<
         unsafe extern "C" fn(
             ort_env: *mut OrtEnv,
             log_severity_level: OrtLoggingLevel,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalIntraOpThreadAffinity: ::std::option::Option<
+    pub SetGlobalIntraOpThreadAffinity: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            affinity_string: *const ::std::os::raw::c_char,
+            affinity_string: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsLibrary_V2: ::std::option::Option<
+    pub RegisterCustomOpsLibrary_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            library_name: *const ::std::os::raw::c_char,
+            library_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsUsingFunction: ::std::option::Option<
+    pub RegisterCustomOpsUsingFunction: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            registration_func_name: *const ::std::os::raw::c_char,
+            registration_func_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputCount: ::std::option::Option<
+    pub KernelInfo_GetInputCount: ::core::option::Option<
         unsafe extern "C" fn(info: *const OrtKernelInfo, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputCount: ::std::option::Option<
+    pub KernelInfo_GetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(info: *const OrtKernelInfo, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputName: ::std::option::Option<
+    pub KernelInfo_GetInputName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputName: ::std::option::Option<
+    pub KernelInfo_GetOutputName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputTypeInfo: ::std::option::Option<
+    pub KernelInfo_GetInputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputTypeInfo: ::std::option::Option<
+    pub KernelInfo_GetOutputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_tensor: ::std::option::Option<
+    pub KernelInfoGetAttribute_tensor: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             allocator: *mut OrtAllocator,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub HasSessionConfigEntry: ::std::option::Option<
+    pub HasSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            out: *mut ::std::os::raw::c_int,
+            config_key: *const ::core::ffi::c_char,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetSessionConfigEntry: ::std::option::Option<
+    pub GetSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *mut ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_Dnnl: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_Dnnl: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             dnnl_options: *const OrtDnnlProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub CreateDnnlProviderOptions: ::std::option::Option<
+    pub CreateDnnlProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtDnnlProviderOptions) -> OrtStatusPtr,
     >,
-    pub UpdateDnnlProviderOptions: ::std::option::Option<
+    pub UpdateDnnlProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             dnnl_options: *mut OrtDnnlProviderOptions,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetDnnlProviderOptionsAsString: ::std::option::Option<
+    pub GetDnnlProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             dnnl_options: *const OrtDnnlProviderOptions,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtDnnlProviderOptions"]
     #[doc = ""]
     #[doc = " \\since Version 1.15."]
     pub ReleaseDnnlProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtDnnlProviderOptions)>,
-    pub KernelInfo_GetNodeName: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtDnnlProviderOptions)>,
+    pub KernelInfo_GetNodeName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetLogger: ::std::option::Option<
+    pub KernelInfo_GetLogger: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             logger: *mut *const OrtLogger,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetLogger: ::std::option::Option<
+    pub KernelContext_GetLogger: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             logger: *mut *const OrtLogger,
         ) -> OrtStatusPtr,
     >,
-    pub Logger_LogMessage: ::std::option::Option<
+    pub Logger_LogMessage: ::core::option::Option<
         unsafe extern "C" fn(
             logger: *const OrtLogger,
             log_severity_level: OrtLoggingLevel,
-            message: *const ::std::os::raw::c_char,
-            file_path: *const ::std::os::raw::c_char,
-            line_number: ::std::os::raw::c_int,
-            func_name: *const ::std::os::raw::c_char,
+            message: *const ::core::ffi::c_char,
+            file_path: *const ::core::ffi::c_char,
+            line_number: ::core::ffi::c_int,
+            func_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub Logger_GetLoggingSeverityLevel: ::std::option::Option<
+    pub Logger_GetLoggingSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(logger: *const OrtLogger, out: *mut OrtLoggingLevel) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetConstantInput_tensor: ::std::option::Option<
+    pub KernelInfoGetConstantInput_tensor: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            is_constant: *mut ::std::os::raw::c_int,
+            is_constant: *mut ::core::ffi::c_int,
             out: *mut *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToOptionalTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToOptionalTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtOptionalTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetOptionalContainedTypeInfo: ::std::option::Option<
+    pub GetOptionalContainedTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             optional_type_info: *const OrtOptionalTypeInfo,
             out: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetResizedStringTensorElementBuffer: ::std::option::Option<
+    pub GetResizedStringTensorElementBuffer: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
             index: usize,
             length_in_bytes: usize,
-            buffer: *mut *mut ::std::os::raw::c_char,
+            buffer: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetAllocator: ::std::option::Option<
+    pub KernelContext_GetAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             mem_info: *const OrtMemoryInfo,
@@ -6277,26 +6277,26 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.15."]
     pub GetBuildInfoString:
-        ::std::option::Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+        ::core::option::Option<unsafe extern "C" fn() -> *const ::core::ffi::c_char>,
 }
 #[test]
 fn bindgen_test_layout_OrtApi() {
     assert_eq!(
-        ::std::mem::size_of::<OrtApi>(),
+        ::core::mem::size_of::<OrtApi>(),
         2040usize,
         concat!("Size of: ", stringify!(OrtApi))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtApi>(),
+        ::core::mem::align_of::<OrtApi>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtApi))
     );
     fn test_field_CreateStatus() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateStatus) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateStatus) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -6311,9 +6311,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetErrorCode() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetErrorCode) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetErrorCode) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -6328,9 +6328,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetErrorMessage() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetErrorMessage) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetErrorMessage) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -6345,9 +6345,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnv) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -6362,9 +6362,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithCustomLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithCustomLogger) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -6379,9 +6379,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableTelemetryEvents() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableTelemetryEvents) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableTelemetryEvents) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -6396,9 +6396,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableTelemetryEvents() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableTelemetryEvents) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableTelemetryEvents) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -6413,9 +6413,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSession() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSession) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSession) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -6430,9 +6430,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionFromArray() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionFromArray) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionFromArray) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -6447,9 +6447,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Run() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Run) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Run) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -6464,9 +6464,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionOptions) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -6481,9 +6481,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetOptimizedModelFilePath() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetOptimizedModelFilePath) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetOptimizedModelFilePath) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -6498,9 +6498,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CloneSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CloneSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CloneSessionOptions) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -6515,9 +6515,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionExecutionMode() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionExecutionMode) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionExecutionMode) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -6532,9 +6532,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableProfiling) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -6549,9 +6549,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableProfiling) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -6566,9 +6566,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableMemPattern() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableMemPattern) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableMemPattern) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -6583,9 +6583,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableMemPattern() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableMemPattern) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableMemPattern) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -6600,9 +6600,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableCpuMemArena() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableCpuMemArena) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableCpuMemArena) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -6617,9 +6617,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableCpuMemArena() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableCpuMemArena) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableCpuMemArena) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -6634,9 +6634,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogId) as usize - ptr as usize
             },
             160usize,
             concat!(
@@ -6651,9 +6651,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogVerbosityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogVerbosityLevel) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -6668,9 +6668,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogSeverityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogSeverityLevel) as usize - ptr as usize
             },
             176usize,
             concat!(
@@ -6685,9 +6685,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionGraphOptimizationLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionGraphOptimizationLevel) as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionGraphOptimizationLevel) as usize
                     - ptr as usize
             },
             184usize,
@@ -6703,9 +6703,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetIntraOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetIntraOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetIntraOpNumThreads) as usize - ptr as usize
             },
             192usize,
             concat!(
@@ -6720,9 +6720,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetInterOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetInterOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetInterOpNumThreads) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -6737,9 +6737,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCustomOpDomain) as usize - ptr as usize
             },
             208usize,
             concat!(
@@ -6754,9 +6754,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CustomOpDomain_Add() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CustomOpDomain_Add) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CustomOpDomain_Add) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -6771,9 +6771,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddCustomOpDomain) as usize - ptr as usize
             },
             224usize,
             concat!(
@@ -6788,9 +6788,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsLibrary() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -6805,9 +6805,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputCount) as usize - ptr as usize
             },
             240usize,
             concat!(
@@ -6822,9 +6822,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputCount) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -6839,9 +6839,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerCount) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerCount) as usize
                     - ptr as usize
             },
             256usize,
@@ -6857,9 +6857,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputTypeInfo) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -6874,9 +6874,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputTypeInfo) as usize - ptr as usize
             },
             272usize,
             concat!(
@@ -6891,9 +6891,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerTypeInfo) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerTypeInfo) as usize
                     - ptr as usize
             },
             280usize,
@@ -6909,9 +6909,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputName) as usize - ptr as usize
             },
             288usize,
             concat!(
@@ -6926,9 +6926,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputName) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -6943,9 +6943,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerName) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerName) as usize
                     - ptr as usize
             },
             304usize,
@@ -6961,9 +6961,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateRunOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateRunOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateRunOptions) as usize - ptr as usize
             },
             312usize,
             concat!(
@@ -6978,9 +6978,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogVerbosityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunLogVerbosityLevel) as usize
                     - ptr as usize
             },
             320usize,
@@ -6996,9 +6996,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogSeverityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunLogSeverityLevel) as usize
                     - ptr as usize
             },
             328usize,
@@ -7014,9 +7014,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunTag() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunTag) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunTag) as usize - ptr as usize
             },
             336usize,
             concat!(
@@ -7031,9 +7031,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogVerbosityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunLogVerbosityLevel) as usize
                     - ptr as usize
             },
             344usize,
@@ -7049,9 +7049,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogSeverityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunLogSeverityLevel) as usize
                     - ptr as usize
             },
             352usize,
@@ -7067,9 +7067,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunTag() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunTag) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunTag) as usize - ptr as usize
             },
             360usize,
             concat!(
@@ -7084,9 +7084,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetTerminate() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetTerminate) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetTerminate) as usize - ptr as usize
             },
             368usize,
             concat!(
@@ -7101,9 +7101,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsUnsetTerminate() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsUnsetTerminate) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsUnsetTerminate) as usize - ptr as usize
             },
             376usize,
             concat!(
@@ -7118,9 +7118,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorAsOrtValue) as usize - ptr as usize
             },
             384usize,
             concat!(
@@ -7135,9 +7135,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorWithDataAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorWithDataAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorWithDataAsOrtValue) as usize - ptr as usize
             },
             392usize,
             concat!(
@@ -7152,9 +7152,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_IsTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).IsTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).IsTensor) as usize - ptr as usize
             },
             400usize,
             concat!(
@@ -7169,9 +7169,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorMutableData() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorMutableData) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorMutableData) as usize - ptr as usize
             },
             408usize,
             concat!(
@@ -7186,9 +7186,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillStringTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillStringTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillStringTensor) as usize - ptr as usize
             },
             416usize,
             concat!(
@@ -7203,9 +7203,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorDataLength() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorDataLength) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorDataLength) as usize - ptr as usize
             },
             424usize,
             concat!(
@@ -7220,9 +7220,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorContent() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorContent) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorContent) as usize - ptr as usize
             },
             432usize,
             concat!(
@@ -7237,9 +7237,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToTensorInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToTensorInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToTensorInfo) as usize - ptr as usize
             },
             440usize,
             concat!(
@@ -7254,9 +7254,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOnnxTypeFromTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOnnxTypeFromTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOnnxTypeFromTypeInfo) as usize - ptr as usize
             },
             448usize,
             concat!(
@@ -7271,9 +7271,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorTypeAndShapeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorTypeAndShapeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorTypeAndShapeInfo) as usize - ptr as usize
             },
             456usize,
             concat!(
@@ -7288,9 +7288,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetTensorElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetTensorElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetTensorElementType) as usize - ptr as usize
             },
             464usize,
             concat!(
@@ -7305,9 +7305,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetDimensions) as usize - ptr as usize
             },
             472usize,
             concat!(
@@ -7322,9 +7322,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorElementType) as usize - ptr as usize
             },
             480usize,
             concat!(
@@ -7339,9 +7339,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDimensionsCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDimensionsCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDimensionsCount) as usize - ptr as usize
             },
             488usize,
             concat!(
@@ -7356,9 +7356,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDimensions) as usize - ptr as usize
             },
             496usize,
             concat!(
@@ -7373,9 +7373,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSymbolicDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSymbolicDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSymbolicDimensions) as usize - ptr as usize
             },
             504usize,
             concat!(
@@ -7390,9 +7390,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorShapeElementCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorShapeElementCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorShapeElementCount) as usize - ptr as usize
             },
             512usize,
             concat!(
@@ -7407,9 +7407,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorTypeAndShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorTypeAndShape) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorTypeAndShape) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -7424,9 +7424,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTypeInfo) as usize - ptr as usize
             },
             528usize,
             concat!(
@@ -7441,9 +7441,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValueType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValueType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValueType) as usize - ptr as usize
             },
             536usize,
             concat!(
@@ -7458,9 +7458,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateMemoryInfo) as usize - ptr as usize
             },
             544usize,
             concat!(
@@ -7475,9 +7475,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCpuMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCpuMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCpuMemoryInfo) as usize - ptr as usize
             },
             552usize,
             concat!(
@@ -7492,9 +7492,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CompareMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CompareMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CompareMemoryInfo) as usize - ptr as usize
             },
             560usize,
             concat!(
@@ -7509,9 +7509,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetName) as usize - ptr as usize
             },
             568usize,
             concat!(
@@ -7526,9 +7526,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetId) as usize - ptr as usize
             },
             576usize,
             concat!(
@@ -7543,9 +7543,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetMemType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetMemType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetMemType) as usize - ptr as usize
             },
             584usize,
             concat!(
@@ -7560,9 +7560,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetType) as usize - ptr as usize
             },
             592usize,
             concat!(
@@ -7577,9 +7577,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorAlloc() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorAlloc) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorAlloc) as usize - ptr as usize
             },
             600usize,
             concat!(
@@ -7594,9 +7594,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorFree() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorFree) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorFree) as usize - ptr as usize
             },
             608usize,
             concat!(
@@ -7611,9 +7611,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorGetInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorGetInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorGetInfo) as usize - ptr as usize
             },
             616usize,
             concat!(
@@ -7628,9 +7628,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetAllocatorWithDefaultOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetAllocatorWithDefaultOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetAllocatorWithDefaultOptions) as usize - ptr as usize
             },
             624usize,
             concat!(
@@ -7645,9 +7645,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddFreeDimensionOverride() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverride) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddFreeDimensionOverride) as usize - ptr as usize
             },
             632usize,
             concat!(
@@ -7662,9 +7662,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValue) as usize - ptr as usize
             },
             640usize,
             concat!(
@@ -7679,9 +7679,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValueCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValueCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValueCount) as usize - ptr as usize
             },
             648usize,
             concat!(
@@ -7696,9 +7696,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateValue) as usize - ptr as usize
             },
             656usize,
             concat!(
@@ -7713,9 +7713,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOpaqueValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOpaqueValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOpaqueValue) as usize - ptr as usize
             },
             664usize,
             concat!(
@@ -7730,9 +7730,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOpaqueValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOpaqueValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOpaqueValue) as usize - ptr as usize
             },
             672usize,
             concat!(
@@ -7747,9 +7747,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_float() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_float) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_float) as usize - ptr as usize
             },
             680usize,
             concat!(
@@ -7764,9 +7764,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_int64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_int64) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_int64) as usize - ptr as usize
             },
             688usize,
             concat!(
@@ -7781,9 +7781,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_string() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_string) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_string) as usize - ptr as usize
             },
             696usize,
             concat!(
@@ -7798,9 +7798,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetInputCount) as usize - ptr as usize
             },
             704usize,
             concat!(
@@ -7815,9 +7815,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetOutputCount) as usize - ptr as usize
             },
             712usize,
             concat!(
@@ -7832,9 +7832,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetInput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetInput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetInput) as usize - ptr as usize
             },
             720usize,
             concat!(
@@ -7849,9 +7849,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetOutput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetOutput) as usize - ptr as usize
             },
             728usize,
             concat!(
@@ -7866,9 +7866,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseEnv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseEnv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseEnv) as usize - ptr as usize
             },
             736usize,
             concat!(
@@ -7883,9 +7883,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseStatus() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseStatus) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseStatus) as usize - ptr as usize
             },
             744usize,
             concat!(
@@ -7900,9 +7900,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseMemoryInfo) as usize - ptr as usize
             },
             752usize,
             concat!(
@@ -7917,9 +7917,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSession() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSession) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSession) as usize - ptr as usize
             },
             760usize,
             concat!(
@@ -7934,9 +7934,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseValue) as usize - ptr as usize
             },
             768usize,
             concat!(
@@ -7951,9 +7951,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseRunOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseRunOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseRunOptions) as usize - ptr as usize
             },
             776usize,
             concat!(
@@ -7968,9 +7968,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTypeInfo) as usize - ptr as usize
             },
             784usize,
             concat!(
@@ -7985,9 +7985,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTensorTypeAndShapeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTensorTypeAndShapeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTensorTypeAndShapeInfo) as usize - ptr as usize
             },
             792usize,
             concat!(
@@ -8002,9 +8002,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSessionOptions) as usize - ptr as usize
             },
             800usize,
             concat!(
@@ -8019,9 +8019,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCustomOpDomain) as usize - ptr as usize
             },
             808usize,
             concat!(
@@ -8036,9 +8036,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDenotationFromTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDenotationFromTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDenotationFromTypeInfo) as usize - ptr as usize
             },
             816usize,
             concat!(
@@ -8053,9 +8053,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToMapTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToMapTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToMapTypeInfo) as usize - ptr as usize
             },
             824usize,
             concat!(
@@ -8070,9 +8070,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToSequenceTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToSequenceTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToSequenceTypeInfo) as usize - ptr as usize
             },
             832usize,
             concat!(
@@ -8087,9 +8087,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetMapKeyType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetMapKeyType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetMapKeyType) as usize - ptr as usize
             },
             840usize,
             concat!(
@@ -8104,9 +8104,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetMapValueType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetMapValueType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetMapValueType) as usize - ptr as usize
             },
             848usize,
             concat!(
@@ -8121,9 +8121,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSequenceElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSequenceElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSequenceElementType) as usize - ptr as usize
             },
             856usize,
             concat!(
@@ -8138,9 +8138,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseMapTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseMapTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseMapTypeInfo) as usize - ptr as usize
             },
             864usize,
             concat!(
@@ -8155,9 +8155,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSequenceTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSequenceTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSequenceTypeInfo) as usize - ptr as usize
             },
             872usize,
             concat!(
@@ -8172,9 +8172,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionEndProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionEndProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionEndProfiling) as usize - ptr as usize
             },
             880usize,
             concat!(
@@ -8189,9 +8189,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetModelMetadata() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetModelMetadata) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetModelMetadata) as usize - ptr as usize
             },
             888usize,
             concat!(
@@ -8206,9 +8206,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetProducerName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetProducerName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetProducerName) as usize - ptr as usize
             },
             896usize,
             concat!(
@@ -8223,9 +8223,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetGraphName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetGraphName) as usize - ptr as usize
             },
             904usize,
             concat!(
@@ -8240,9 +8240,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetDomain) as usize - ptr as usize
             },
             912usize,
             concat!(
@@ -8257,9 +8257,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetDescription() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDescription) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetDescription) as usize - ptr as usize
             },
             920usize,
             concat!(
@@ -8274,9 +8274,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataLookupCustomMetadataMap() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataLookupCustomMetadataMap) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataLookupCustomMetadataMap) as usize
                     - ptr as usize
             },
             928usize,
@@ -8292,9 +8292,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetVersion() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetVersion) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetVersion) as usize - ptr as usize
             },
             936usize,
             concat!(
@@ -8309,9 +8309,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseModelMetadata() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseModelMetadata) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseModelMetadata) as usize - ptr as usize
             },
             944usize,
             concat!(
@@ -8326,9 +8326,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithGlobalThreadPools() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithGlobalThreadPools) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithGlobalThreadPools) as usize - ptr as usize
             },
             952usize,
             concat!(
@@ -8343,9 +8343,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisablePerSessionThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisablePerSessionThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisablePerSessionThreads) as usize - ptr as usize
             },
             960usize,
             concat!(
@@ -8360,9 +8360,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateThreadingOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateThreadingOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateThreadingOptions) as usize - ptr as usize
             },
             968usize,
             concat!(
@@ -8377,9 +8377,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseThreadingOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseThreadingOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseThreadingOptions) as usize - ptr as usize
             },
             976usize,
             concat!(
@@ -8394,9 +8394,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetCustomMetadataMapKeys() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetCustomMetadataMapKeys) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetCustomMetadataMapKeys) as usize
                     - ptr as usize
             },
             984usize,
@@ -8412,9 +8412,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddFreeDimensionOverrideByName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverrideByName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddFreeDimensionOverrideByName) as usize - ptr as usize
             },
             992usize,
             concat!(
@@ -8429,9 +8429,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetAvailableProviders() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetAvailableProviders) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetAvailableProviders) as usize - ptr as usize
             },
             1000usize,
             concat!(
@@ -8446,9 +8446,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseAvailableProviders() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseAvailableProviders) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseAvailableProviders) as usize - ptr as usize
             },
             1008usize,
             concat!(
@@ -8463,9 +8463,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorElementLength() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorElementLength) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorElementLength) as usize - ptr as usize
             },
             1016usize,
             concat!(
@@ -8480,9 +8480,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorElement() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorElement) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorElement) as usize - ptr as usize
             },
             1024usize,
             concat!(
@@ -8497,9 +8497,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillStringTensorElement() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillStringTensorElement) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillStringTensorElement) as usize - ptr as usize
             },
             1032usize,
             concat!(
@@ -8514,9 +8514,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddSessionConfigEntry) as usize - ptr as usize
             },
             1040usize,
             concat!(
@@ -8531,9 +8531,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateAllocator) as usize - ptr as usize
             },
             1048usize,
             concat!(
@@ -8548,9 +8548,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseAllocator) as usize - ptr as usize
             },
             1056usize,
             concat!(
@@ -8565,9 +8565,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunWithBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunWithBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunWithBinding) as usize - ptr as usize
             },
             1064usize,
             concat!(
@@ -8582,9 +8582,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateIoBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateIoBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateIoBinding) as usize - ptr as usize
             },
             1072usize,
             concat!(
@@ -8599,9 +8599,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseIoBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseIoBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseIoBinding) as usize - ptr as usize
             },
             1080usize,
             concat!(
@@ -8616,9 +8616,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindInput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindInput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindInput) as usize - ptr as usize
             },
             1088usize,
             concat!(
@@ -8633,9 +8633,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindOutput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindOutput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindOutput) as usize - ptr as usize
             },
             1096usize,
             concat!(
@@ -8650,9 +8650,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindOutputToDevice() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindOutputToDevice) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindOutputToDevice) as usize - ptr as usize
             },
             1104usize,
             concat!(
@@ -8667,9 +8667,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBoundOutputNames() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBoundOutputNames) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBoundOutputNames) as usize - ptr as usize
             },
             1112usize,
             concat!(
@@ -8684,9 +8684,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBoundOutputValues() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBoundOutputValues) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBoundOutputValues) as usize - ptr as usize
             },
             1120usize,
             concat!(
@@ -8701,9 +8701,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ClearBoundInputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ClearBoundInputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ClearBoundInputs) as usize - ptr as usize
             },
             1128usize,
             concat!(
@@ -8718,9 +8718,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ClearBoundOutputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ClearBoundOutputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ClearBoundOutputs) as usize - ptr as usize
             },
             1136usize,
             concat!(
@@ -8735,9 +8735,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_TensorAt() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).TensorAt) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).TensorAt) as usize - ptr as usize
             },
             1144usize,
             concat!(
@@ -8752,9 +8752,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateAndRegisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateAndRegisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateAndRegisterAllocator) as usize - ptr as usize
             },
             1152usize,
             concat!(
@@ -8769,9 +8769,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetLanguageProjection() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetLanguageProjection) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetLanguageProjection) as usize - ptr as usize
             },
             1160usize,
             concat!(
@@ -8786,9 +8786,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetProfilingStartTimeNs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetProfilingStartTimeNs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetProfilingStartTimeNs) as usize - ptr as usize
             },
             1168usize,
             concat!(
@@ -8803,9 +8803,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalIntraOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalIntraOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalIntraOpNumThreads) as usize - ptr as usize
             },
             1176usize,
             concat!(
@@ -8820,9 +8820,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalInterOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalInterOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalInterOpNumThreads) as usize - ptr as usize
             },
             1184usize,
             concat!(
@@ -8837,9 +8837,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalSpinControl() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalSpinControl) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalSpinControl) as usize - ptr as usize
             },
             1192usize,
             concat!(
@@ -8854,9 +8854,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddInitializer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddInitializer) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddInitializer) as usize - ptr as usize
             },
             1200usize,
             concat!(
@@ -8871,9 +8871,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithCustomLoggerAndGlobalThreadPools() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLoggerAndGlobalThreadPools) as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithCustomLoggerAndGlobalThreadPools) as usize
                     - ptr as usize
             },
             1208usize,
@@ -8889,9 +8889,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CUDA() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA) as usize
                     - ptr as usize
             },
             1216usize,
@@ -8907,9 +8907,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_ROCM() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_ROCM) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_ROCM) as usize
                     - ptr as usize
             },
             1224usize,
@@ -8925,9 +8925,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_OpenVINO() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_OpenVINO) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_OpenVINO) as usize
                     - ptr as usize
             },
             1232usize,
@@ -8943,9 +8943,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalDenormalAsZero() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalDenormalAsZero) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalDenormalAsZero) as usize - ptr as usize
             },
             1240usize,
             concat!(
@@ -8960,9 +8960,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateArenaCfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateArenaCfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateArenaCfg) as usize - ptr as usize
             },
             1248usize,
             concat!(
@@ -8977,9 +8977,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseArenaCfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseArenaCfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseArenaCfg) as usize - ptr as usize
             },
             1256usize,
             concat!(
@@ -8994,9 +8994,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetGraphDescription() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphDescription) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetGraphDescription) as usize
                     - ptr as usize
             },
             1264usize,
@@ -9012,9 +9012,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_TensorRT() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT) as usize
                     - ptr as usize
             },
             1272usize,
@@ -9030,9 +9030,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetCurrentGpuDeviceId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetCurrentGpuDeviceId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetCurrentGpuDeviceId) as usize - ptr as usize
             },
             1280usize,
             concat!(
@@ -9047,9 +9047,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCurrentGpuDeviceId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCurrentGpuDeviceId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCurrentGpuDeviceId) as usize - ptr as usize
             },
             1288usize,
             concat!(
@@ -9064,9 +9064,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttributeArray_float() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_float) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_float) as usize
                     - ptr as usize
             },
             1296usize,
@@ -9082,9 +9082,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttributeArray_int64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_int64) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_int64) as usize
                     - ptr as usize
             },
             1304usize,
@@ -9100,9 +9100,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateArenaCfgV2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateArenaCfgV2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateArenaCfgV2) as usize - ptr as usize
             },
             1312usize,
             concat!(
@@ -9117,9 +9117,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddRunConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddRunConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddRunConfigEntry) as usize - ptr as usize
             },
             1320usize,
             concat!(
@@ -9134,9 +9134,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreatePrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreatePrepackedWeightsContainer) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreatePrepackedWeightsContainer) as usize - ptr as usize
             },
             1328usize,
             concat!(
@@ -9151,9 +9151,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleasePrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleasePrepackedWeightsContainer) as usize
+                ::core::ptr::addr_of!((*ptr).ReleasePrepackedWeightsContainer) as usize
                     - ptr as usize
             },
             1336usize,
@@ -9169,9 +9169,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionWithPrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionWithPrepackedWeightsContainer) as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionWithPrepackedWeightsContainer) as usize
                     - ptr as usize
             },
             1344usize,
@@ -9187,9 +9187,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionFromArrayWithPrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionFromArrayWithPrepackedWeightsContainer)
+                ::core::ptr::addr_of!((*ptr).CreateSessionFromArrayWithPrepackedWeightsContainer)
                     as usize
                     - ptr as usize
             },
@@ -9206,9 +9206,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_TensorRT_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT_V2)
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT_V2)
                     as usize
                     - ptr as usize
             },
@@ -9225,9 +9225,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorRTProviderOptions) as usize - ptr as usize
             },
             1368usize,
             concat!(
@@ -9242,9 +9242,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateTensorRTProviderOptions) as usize - ptr as usize
             },
             1376usize,
             concat!(
@@ -9259,9 +9259,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorRTProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorRTProviderOptionsAsString) as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorRTProviderOptionsAsString) as usize
                     - ptr as usize
             },
             1384usize,
@@ -9277,9 +9277,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTensorRTProviderOptions) as usize - ptr as usize
             },
             1392usize,
             concat!(
@@ -9294,9 +9294,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableOrtCustomOps() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableOrtCustomOps) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableOrtCustomOps) as usize - ptr as usize
             },
             1400usize,
             concat!(
@@ -9311,9 +9311,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterAllocator) as usize - ptr as usize
             },
             1408usize,
             concat!(
@@ -9328,9 +9328,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UnregisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UnregisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UnregisterAllocator) as usize - ptr as usize
             },
             1416usize,
             concat!(
@@ -9345,9 +9345,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_IsSparseTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).IsSparseTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).IsSparseTensor) as usize - ptr as usize
             },
             1424usize,
             concat!(
@@ -9362,9 +9362,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSparseTensorAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSparseTensorAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSparseTensorAsOrtValue) as usize - ptr as usize
             },
             1432usize,
             concat!(
@@ -9379,9 +9379,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorCoo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorCoo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorCoo) as usize - ptr as usize
             },
             1440usize,
             concat!(
@@ -9396,9 +9396,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorCsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorCsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorCsr) as usize - ptr as usize
             },
             1448usize,
             concat!(
@@ -9413,9 +9413,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorBlockSparse() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorBlockSparse) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorBlockSparse) as usize - ptr as usize
             },
             1456usize,
             concat!(
@@ -9430,9 +9430,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSparseTensorWithValuesAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSparseTensorWithValuesAsOrtValue) as usize
+                ::core::ptr::addr_of!((*ptr).CreateSparseTensorWithValuesAsOrtValue) as usize
                     - ptr as usize
             },
             1464usize,
@@ -9448,9 +9448,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseCooIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseCooIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseCooIndices) as usize - ptr as usize
             },
             1472usize,
             concat!(
@@ -9465,9 +9465,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseCsrIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseCsrIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseCsrIndices) as usize - ptr as usize
             },
             1480usize,
             concat!(
@@ -9482,9 +9482,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseBlockSparseIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseBlockSparseIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseBlockSparseIndices) as usize - ptr as usize
             },
             1488usize,
             concat!(
@@ -9499,9 +9499,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorFormat() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorFormat) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorFormat) as usize - ptr as usize
             },
             1496usize,
             concat!(
@@ -9516,9 +9516,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorValuesTypeAndShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorValuesTypeAndShape) as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorValuesTypeAndShape) as usize
                     - ptr as usize
             },
             1504usize,
@@ -9534,9 +9534,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorValues() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorValues) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorValues) as usize - ptr as usize
             },
             1512usize,
             concat!(
@@ -9551,9 +9551,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorIndicesTypeShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndicesTypeShape) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorIndicesTypeShape) as usize - ptr as usize
             },
             1520usize,
             concat!(
@@ -9568,9 +9568,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorIndices) as usize - ptr as usize
             },
             1528usize,
             concat!(
@@ -9585,9 +9585,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_HasValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).HasValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).HasValue) as usize - ptr as usize
             },
             1536usize,
             concat!(
@@ -9602,9 +9602,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetGPUComputeStream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetGPUComputeStream) as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetGPUComputeStream) as usize
                     - ptr as usize
             },
             1544usize,
@@ -9620,9 +9620,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorMemoryInfo) as usize - ptr as usize
             },
             1552usize,
             concat!(
@@ -9637,9 +9637,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetExecutionProviderApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetExecutionProviderApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetExecutionProviderApi) as usize - ptr as usize
             },
             1560usize,
             concat!(
@@ -9654,9 +9654,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomCreateThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomCreateThreadFn) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomCreateThreadFn) as usize
                     - ptr as usize
             },
             1568usize,
@@ -9672,9 +9672,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomThreadCreationOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomThreadCreationOptions) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomThreadCreationOptions) as usize
                     - ptr as usize
             },
             1576usize,
@@ -9690,9 +9690,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomJoinThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomJoinThreadFn) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomJoinThreadFn) as usize
                     - ptr as usize
             },
             1584usize,
@@ -9708,9 +9708,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomCreateThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomCreateThreadFn) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomCreateThreadFn) as usize - ptr as usize
             },
             1592usize,
             concat!(
@@ -9725,9 +9725,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomThreadCreationOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomThreadCreationOptions) as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomThreadCreationOptions) as usize
                     - ptr as usize
             },
             1600usize,
@@ -9743,9 +9743,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomJoinThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomJoinThreadFn) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomJoinThreadFn) as usize - ptr as usize
             },
             1608usize,
             concat!(
@@ -9760,9 +9760,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SynchronizeBoundInputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SynchronizeBoundInputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SynchronizeBoundInputs) as usize - ptr as usize
             },
             1616usize,
             concat!(
@@ -9777,9 +9777,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SynchronizeBoundOutputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SynchronizeBoundOutputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SynchronizeBoundOutputs) as usize - ptr as usize
             },
             1624usize,
             concat!(
@@ -9794,9 +9794,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CUDA_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA_V2) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA_V2) as usize
                     - ptr as usize
             },
             1632usize,
@@ -9812,9 +9812,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCUDAProviderOptions) as usize - ptr as usize
             },
             1640usize,
             concat!(
@@ -9829,9 +9829,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateCUDAProviderOptions) as usize - ptr as usize
             },
             1648usize,
             concat!(
@@ -9846,9 +9846,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCUDAProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCUDAProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCUDAProviderOptionsAsString) as usize - ptr as usize
             },
             1656usize,
             concat!(
@@ -9863,9 +9863,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCUDAProviderOptions) as usize - ptr as usize
             },
             1664usize,
             concat!(
@@ -9880,9 +9880,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_MIGraphX() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_MIGraphX) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_MIGraphX) as usize
                     - ptr as usize
             },
             1672usize,
@@ -9898,9 +9898,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddExternalInitializers() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddExternalInitializers) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddExternalInitializers) as usize - ptr as usize
             },
             1680usize,
             concat!(
@@ -9915,9 +9915,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOpAttr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOpAttr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOpAttr) as usize - ptr as usize
             },
             1688usize,
             concat!(
@@ -9932,9 +9932,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseOpAttr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseOpAttr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseOpAttr) as usize - ptr as usize
             },
             1696usize,
             concat!(
@@ -9949,9 +9949,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOp) as usize - ptr as usize
             },
             1704usize,
             concat!(
@@ -9966,9 +9966,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_InvokeOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).InvokeOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).InvokeOp) as usize - ptr as usize
             },
             1712usize,
             concat!(
@@ -9983,9 +9983,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseOp) as usize - ptr as usize
             },
             1720usize,
             concat!(
@@ -10000,9 +10000,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider) as usize
                     - ptr as usize
             },
             1728usize,
@@ -10018,9 +10018,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CopyKernelInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CopyKernelInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CopyKernelInfo) as usize - ptr as usize
             },
             1736usize,
             concat!(
@@ -10035,9 +10035,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseKernelInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseKernelInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseKernelInfo) as usize - ptr as usize
             },
             1744usize,
             concat!(
@@ -10052,9 +10052,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTrainingApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTrainingApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTrainingApi) as usize - ptr as usize
             },
             1752usize,
             concat!(
@@ -10069,9 +10069,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CANN() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CANN) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CANN) as usize
                     - ptr as usize
             },
             1760usize,
@@ -10087,9 +10087,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCANNProviderOptions) as usize - ptr as usize
             },
             1768usize,
             concat!(
@@ -10104,9 +10104,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateCANNProviderOptions) as usize - ptr as usize
             },
             1776usize,
             concat!(
@@ -10121,9 +10121,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCANNProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCANNProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCANNProviderOptionsAsString) as usize - ptr as usize
             },
             1784usize,
             concat!(
@@ -10138,9 +10138,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCANNProviderOptions) as usize - ptr as usize
             },
             1792usize,
             concat!(
@@ -10155,9 +10155,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetDeviceType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetDeviceType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetDeviceType) as usize - ptr as usize
             },
             1800usize,
             concat!(
@@ -10172,9 +10172,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateEnvWithCustomLogLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateEnvWithCustomLogLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateEnvWithCustomLogLevel) as usize - ptr as usize
             },
             1808usize,
             concat!(
@@ -10189,9 +10189,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalIntraOpThreadAffinity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalIntraOpThreadAffinity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalIntraOpThreadAffinity) as usize - ptr as usize
             },
             1816usize,
             concat!(
@@ -10206,9 +10206,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsLibrary_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary_V2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary_V2) as usize - ptr as usize
             },
             1824usize,
             concat!(
@@ -10223,9 +10223,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsUsingFunction() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsUsingFunction) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsUsingFunction) as usize - ptr as usize
             },
             1832usize,
             concat!(
@@ -10240,9 +10240,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputCount) as usize - ptr as usize
             },
             1840usize,
             concat!(
@@ -10257,9 +10257,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputCount) as usize - ptr as usize
             },
             1848usize,
             concat!(
@@ -10274,9 +10274,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputName) as usize - ptr as usize
             },
             1856usize,
             concat!(
@@ -10291,9 +10291,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputName) as usize - ptr as usize
             },
             1864usize,
             concat!(
@@ -10308,9 +10308,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputTypeInfo) as usize - ptr as usize
             },
             1872usize,
             concat!(
@@ -10325,9 +10325,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputTypeInfo) as usize - ptr as usize
             },
             1880usize,
             concat!(
@@ -10342,9 +10342,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_tensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_tensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_tensor) as usize - ptr as usize
             },
             1888usize,
             concat!(
@@ -10359,9 +10359,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_HasSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).HasSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).HasSessionConfigEntry) as usize - ptr as usize
             },
             1896usize,
             concat!(
@@ -10376,9 +10376,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSessionConfigEntry) as usize - ptr as usize
             },
             1904usize,
             concat!(
@@ -10393,9 +10393,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_Dnnl() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_Dnnl) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_Dnnl) as usize
                     - ptr as usize
             },
             1912usize,
@@ -10411,9 +10411,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateDnnlProviderOptions) as usize - ptr as usize
             },
             1920usize,
             concat!(
@@ -10428,9 +10428,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateDnnlProviderOptions) as usize - ptr as usize
             },
             1928usize,
             concat!(
@@ -10445,9 +10445,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDnnlProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDnnlProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDnnlProviderOptionsAsString) as usize - ptr as usize
             },
             1936usize,
             concat!(
@@ -10462,9 +10462,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseDnnlProviderOptions) as usize - ptr as usize
             },
             1944usize,
             concat!(
@@ -10479,9 +10479,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetNodeName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetNodeName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetNodeName) as usize - ptr as usize
             },
             1952usize,
             concat!(
@@ -10496,9 +10496,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetLogger) as usize - ptr as usize
             },
             1960usize,
             concat!(
@@ -10513,9 +10513,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetLogger) as usize - ptr as usize
             },
             1968usize,
             concat!(
@@ -10530,9 +10530,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Logger_LogMessage() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Logger_LogMessage) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Logger_LogMessage) as usize - ptr as usize
             },
             1976usize,
             concat!(
@@ -10547,9 +10547,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Logger_GetLoggingSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Logger_GetLoggingSeverityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Logger_GetLoggingSeverityLevel) as usize - ptr as usize
             },
             1984usize,
             concat!(
@@ -10564,9 +10564,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetConstantInput_tensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetConstantInput_tensor) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetConstantInput_tensor) as usize
                     - ptr as usize
             },
             1992usize,
@@ -10582,9 +10582,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToOptionalTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToOptionalTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToOptionalTypeInfo) as usize - ptr as usize
             },
             2000usize,
             concat!(
@@ -10599,9 +10599,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOptionalContainedTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOptionalContainedTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOptionalContainedTypeInfo) as usize - ptr as usize
             },
             2008usize,
             concat!(
@@ -10616,9 +10616,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetResizedStringTensorElementBuffer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetResizedStringTensorElementBuffer) as usize
+                ::core::ptr::addr_of!((*ptr).GetResizedStringTensorElementBuffer) as usize
                     - ptr as usize
             },
             2016usize,
@@ -10634,9 +10634,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetAllocator) as usize - ptr as usize
             },
             2024usize,
             concat!(
@@ -10651,9 +10651,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBuildInfoString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBuildInfoString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBuildInfoString) as usize - ptr as usize
             },
             2032usize,
             concat!(
@@ -10677,83 +10677,83 @@ pub enum OrtCustomOpInputOutputCharacteristic {
 #[derive(Debug, Copy, Clone)]
 pub struct OrtCustomOp {
     pub version: u32,
-    pub CreateKernel: ::std::option::Option<
+    pub CreateKernel: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             api: *const OrtApi,
             info: *const OrtKernelInfo,
-        ) -> *mut ::std::os::raw::c_void,
+        ) -> *mut ::core::ffi::c_void,
     >,
-    pub GetName: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    pub GetName: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::core::ffi::c_char,
     >,
-    pub GetExecutionProviderType: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    pub GetExecutionProviderType: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::core::ffi::c_char,
     >,
-    pub GetInputType: ::std::option::Option<
+    pub GetInputType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
     >,
     pub GetInputTypeCount:
-        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
-    pub GetOutputType: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub GetOutputType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
     >,
     pub GetOutputTypeCount:
-        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
-    pub KernelCompute: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub KernelCompute: ::core::option::Option<
         unsafe extern "C" fn(
-            op_kernel: *mut ::std::os::raw::c_void,
+            op_kernel: *mut ::core::ffi::c_void,
             context: *mut OrtKernelContext,
         ),
     >,
     pub KernelDestroy:
-        ::std::option::Option<unsafe extern "C" fn(op_kernel: *mut ::std::os::raw::c_void)>,
-    pub GetInputCharacteristic: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op_kernel: *mut ::core::ffi::c_void)>,
+    pub GetInputCharacteristic: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             index: usize,
         ) -> OrtCustomOpInputOutputCharacteristic,
     >,
-    pub GetOutputCharacteristic: ::std::option::Option<
+    pub GetOutputCharacteristic: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             index: usize,
         ) -> OrtCustomOpInputOutputCharacteristic,
     >,
-    pub GetInputMemoryType: ::std::option::Option<
+    pub GetInputMemoryType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> OrtMemType,
     >,
-    pub GetVariadicInputMinArity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicInputMinArity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicInputHomogeneity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicInputHomogeneity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicOutputMinArity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicOutputMinArity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicOutputHomogeneity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicOutputHomogeneity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
 }
 #[test]
 fn bindgen_test_layout_OrtCustomOp() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCustomOp>(),
+        ::core::mem::size_of::<OrtCustomOp>(),
         136usize,
         concat!("Size of: ", stringify!(OrtCustomOp))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCustomOp>(),
+        ::core::mem::align_of::<OrtCustomOp>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtCustomOp))
     );
     fn test_field_version() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).version) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10768,9 +10768,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_CreateKernel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateKernel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateKernel) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10785,9 +10785,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetName) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10802,9 +10802,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetExecutionProviderType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetExecutionProviderType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetExecutionProviderType) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -10819,9 +10819,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputType) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -10836,9 +10836,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputTypeCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputTypeCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputTypeCount) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -10853,9 +10853,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputType) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -10870,9 +10870,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputTypeCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputTypeCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputTypeCount) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -10887,9 +10887,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_KernelCompute() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelCompute) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelCompute) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -10904,9 +10904,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_KernelDestroy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelDestroy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelDestroy) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -10921,9 +10921,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputCharacteristic() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputCharacteristic) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputCharacteristic) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -10938,9 +10938,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputCharacteristic() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputCharacteristic) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputCharacteristic) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -10955,9 +10955,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputMemoryType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputMemoryType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputMemoryType) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -10972,9 +10972,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicInputMinArity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicInputMinArity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicInputMinArity) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -10989,9 +10989,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicInputHomogeneity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicInputHomogeneity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicInputHomogeneity) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -11006,9 +11006,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicOutputMinArity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicOutputMinArity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicOutputMinArity) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -11023,9 +11023,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicOutputHomogeneity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicOutputHomogeneity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicOutputHomogeneity) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -11041,19 +11041,19 @@ fn bindgen_test_layout_OrtCustomOp() {
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_CUDA(
         options: *mut OrtSessionOptions,
-        device_id: ::std::os::raw::c_int,
+        device_id: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_MIGraphX(
         options: *mut OrtSessionOptions,
-        device_id: ::std::os::raw::c_int,
+        device_id: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_Dnnl(
         options: *mut OrtSessionOptions,
-        use_arena: ::std::os::raw::c_int,
+        use_arena: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 #[repr(C)]
@@ -585,41 +585,41 @@ pub const RAND_MAX: u32 = 2147483647;
 pub const _USE_FORTIFY_LEVEL: u32 = 2;
 pub const __HAS_FIXED_CHK_PROTOTYPES: u32 = 1;
 pub const ORT_API_VERSION: u32 = 15;
-pub type __int8_t = ::std::os::raw::c_schar;
-pub type __uint8_t = ::std::os::raw::c_uchar;
-pub type __int16_t = ::std::os::raw::c_short;
-pub type __uint16_t = ::std::os::raw::c_ushort;
-pub type __int32_t = ::std::os::raw::c_int;
-pub type __uint32_t = ::std::os::raw::c_uint;
-pub type __int64_t = ::std::os::raw::c_longlong;
-pub type __uint64_t = ::std::os::raw::c_ulonglong;
-pub type __darwin_intptr_t = ::std::os::raw::c_long;
-pub type __darwin_natural_t = ::std::os::raw::c_uint;
-pub type __darwin_ct_rune_t = ::std::os::raw::c_int;
+pub type __int8_t = ::core::ffi::c_schar;
+pub type __uint8_t = ::core::ffi::c_uchar;
+pub type __int16_t = ::core::ffi::c_short;
+pub type __uint16_t = ::core::ffi::c_ushort;
+pub type __int32_t = ::core::ffi::c_int;
+pub type __uint32_t = ::core::ffi::c_uint;
+pub type __int64_t = ::core::ffi::c_longlong;
+pub type __uint64_t = ::core::ffi::c_ulonglong;
+pub type __darwin_intptr_t = ::core::ffi::c_long;
+pub type __darwin_natural_t = ::core::ffi::c_uint;
+pub type __darwin_ct_rune_t = ::core::ffi::c_int;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union __mbstate_t {
-    pub __mbstate8: [::std::os::raw::c_char; 128usize],
-    pub _mbstateL: ::std::os::raw::c_longlong,
+    pub __mbstate8: [::core::ffi::c_char; 128usize],
+    pub _mbstateL: ::core::ffi::c_longlong,
 }
 #[test]
 fn bindgen_test_layout___mbstate_t() {
     assert_eq!(
-        ::std::mem::size_of::<__mbstate_t>(),
+        ::core::mem::size_of::<__mbstate_t>(),
         128usize,
         concat!("Size of: ", stringify!(__mbstate_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<__mbstate_t>(),
+        ::core::mem::align_of::<__mbstate_t>(),
         8usize,
         concat!("Alignment of ", stringify!(__mbstate_t))
     );
     fn test_field___mbstate8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__mbstate_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__mbstate_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__mbstate8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__mbstate8) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -634,9 +634,9 @@ fn bindgen_test_layout___mbstate_t() {
     fn test_field__mbstateL() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__mbstate_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__mbstate_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr)._mbstateL) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr)._mbstateL) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -650,21 +650,21 @@ fn bindgen_test_layout___mbstate_t() {
     test_field__mbstateL();
 }
 pub type __darwin_mbstate_t = __mbstate_t;
-pub type __darwin_ptrdiff_t = ::std::os::raw::c_long;
-pub type __darwin_size_t = ::std::os::raw::c_ulong;
+pub type __darwin_ptrdiff_t = ::core::ffi::c_long;
+pub type __darwin_size_t = ::core::ffi::c_ulong;
 pub type __darwin_va_list = __builtin_va_list;
-pub type __darwin_wchar_t = ::std::os::raw::c_int;
+pub type __darwin_wchar_t = ::core::ffi::c_int;
 pub type __darwin_rune_t = __darwin_wchar_t;
-pub type __darwin_wint_t = ::std::os::raw::c_int;
-pub type __darwin_clock_t = ::std::os::raw::c_ulong;
+pub type __darwin_wint_t = ::core::ffi::c_int;
+pub type __darwin_clock_t = ::core::ffi::c_ulong;
 pub type __darwin_socklen_t = __uint32_t;
-pub type __darwin_ssize_t = ::std::os::raw::c_long;
-pub type __darwin_time_t = ::std::os::raw::c_long;
+pub type __darwin_ssize_t = ::core::ffi::c_long;
+pub type __darwin_time_t = ::core::ffi::c_long;
 pub type __darwin_blkcnt_t = __int64_t;
 pub type __darwin_blksize_t = __int32_t;
 pub type __darwin_dev_t = __int32_t;
-pub type __darwin_fsblkcnt_t = ::std::os::raw::c_uint;
-pub type __darwin_fsfilcnt_t = ::std::os::raw::c_uint;
+pub type __darwin_fsblkcnt_t = ::core::ffi::c_uint;
+pub type __darwin_fsfilcnt_t = ::core::ffi::c_uint;
 pub type __darwin_gid_t = __uint32_t;
 pub type __darwin_id_t = __uint32_t;
 pub type __darwin_ino64_t = __uint64_t;
@@ -678,33 +678,33 @@ pub type __darwin_sigset_t = __uint32_t;
 pub type __darwin_suseconds_t = __int32_t;
 pub type __darwin_uid_t = __uint32_t;
 pub type __darwin_useconds_t = __uint32_t;
-pub type __darwin_uuid_t = [::std::os::raw::c_uchar; 16usize];
-pub type __darwin_uuid_string_t = [::std::os::raw::c_char; 37usize];
+pub type __darwin_uuid_t = [::core::ffi::c_uchar; 16usize];
+pub type __darwin_uuid_string_t = [::core::ffi::c_char; 37usize];
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_pthread_handler_rec {
-    pub __routine: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
-    pub __arg: *mut ::std::os::raw::c_void,
+    pub __routine: ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void)>,
+    pub __arg: *mut ::core::ffi::c_void,
     pub __next: *mut __darwin_pthread_handler_rec,
 }
 #[test]
 fn bindgen_test_layout___darwin_pthread_handler_rec() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_pthread_handler_rec>(),
+        ::core::mem::size_of::<__darwin_pthread_handler_rec>(),
         24usize,
         concat!("Size of: ", stringify!(__darwin_pthread_handler_rec))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_pthread_handler_rec>(),
+        ::core::mem::align_of::<__darwin_pthread_handler_rec>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_pthread_handler_rec))
     );
     fn test_field___routine() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__routine) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__routine) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -719,9 +719,9 @@ fn bindgen_test_layout___darwin_pthread_handler_rec() {
     fn test_field___arg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__arg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__arg) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -736,9 +736,9 @@ fn bindgen_test_layout___darwin_pthread_handler_rec() {
     fn test_field___next() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_pthread_handler_rec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__next) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__next) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -754,27 +754,27 @@ fn bindgen_test_layout___darwin_pthread_handler_rec() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_attr_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 56usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 56usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_attr_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_attr_t>(),
+        ::core::mem::size_of::<_opaque_pthread_attr_t>(),
         64usize,
         concat!("Size of: ", stringify!(_opaque_pthread_attr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_attr_t>(),
+        ::core::mem::align_of::<_opaque_pthread_attr_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_attr_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_attr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_attr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -789,9 +789,9 @@ fn bindgen_test_layout__opaque_pthread_attr_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_attr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_attr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -807,27 +807,27 @@ fn bindgen_test_layout__opaque_pthread_attr_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_cond_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 40usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 40usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_cond_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_cond_t>(),
+        ::core::mem::size_of::<_opaque_pthread_cond_t>(),
         48usize,
         concat!("Size of: ", stringify!(_opaque_pthread_cond_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_cond_t>(),
+        ::core::mem::align_of::<_opaque_pthread_cond_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_cond_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_cond_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_cond_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -842,9 +842,9 @@ fn bindgen_test_layout__opaque_pthread_cond_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_cond_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_cond_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -860,27 +860,27 @@ fn bindgen_test_layout__opaque_pthread_cond_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_condattr_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 8usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 8usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_condattr_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_condattr_t>(),
+        ::core::mem::size_of::<_opaque_pthread_condattr_t>(),
         16usize,
         concat!("Size of: ", stringify!(_opaque_pthread_condattr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_condattr_t>(),
+        ::core::mem::align_of::<_opaque_pthread_condattr_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_condattr_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_condattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_condattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -895,9 +895,9 @@ fn bindgen_test_layout__opaque_pthread_condattr_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_condattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_condattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -913,27 +913,27 @@ fn bindgen_test_layout__opaque_pthread_condattr_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_mutex_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 56usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 56usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_mutex_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_mutex_t>(),
+        ::core::mem::size_of::<_opaque_pthread_mutex_t>(),
         64usize,
         concat!("Size of: ", stringify!(_opaque_pthread_mutex_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_mutex_t>(),
+        ::core::mem::align_of::<_opaque_pthread_mutex_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_mutex_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutex_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_mutex_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -948,9 +948,9 @@ fn bindgen_test_layout__opaque_pthread_mutex_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutex_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_mutex_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -966,27 +966,27 @@ fn bindgen_test_layout__opaque_pthread_mutex_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_mutexattr_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 8usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 8usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_mutexattr_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_mutexattr_t>(),
+        ::core::mem::size_of::<_opaque_pthread_mutexattr_t>(),
         16usize,
         concat!("Size of: ", stringify!(_opaque_pthread_mutexattr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_mutexattr_t>(),
+        ::core::mem::align_of::<_opaque_pthread_mutexattr_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_mutexattr_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutexattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_mutexattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1001,9 +1001,9 @@ fn bindgen_test_layout__opaque_pthread_mutexattr_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_mutexattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_mutexattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1019,27 +1019,27 @@ fn bindgen_test_layout__opaque_pthread_mutexattr_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_once_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 8usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 8usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_once_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_once_t>(),
+        ::core::mem::size_of::<_opaque_pthread_once_t>(),
         16usize,
         concat!("Size of: ", stringify!(_opaque_pthread_once_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_once_t>(),
+        ::core::mem::align_of::<_opaque_pthread_once_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_once_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_once_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_once_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1054,9 +1054,9 @@ fn bindgen_test_layout__opaque_pthread_once_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_once_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_once_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1072,27 +1072,27 @@ fn bindgen_test_layout__opaque_pthread_once_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_rwlock_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 192usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 192usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_rwlock_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_rwlock_t>(),
+        ::core::mem::size_of::<_opaque_pthread_rwlock_t>(),
         200usize,
         concat!("Size of: ", stringify!(_opaque_pthread_rwlock_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_rwlock_t>(),
+        ::core::mem::align_of::<_opaque_pthread_rwlock_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_rwlock_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlock_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_rwlock_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1107,9 +1107,9 @@ fn bindgen_test_layout__opaque_pthread_rwlock_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlock_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_rwlock_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1125,27 +1125,27 @@ fn bindgen_test_layout__opaque_pthread_rwlock_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_rwlockattr_t {
-    pub __sig: ::std::os::raw::c_long,
-    pub __opaque: [::std::os::raw::c_char; 16usize],
+    pub __sig: ::core::ffi::c_long,
+    pub __opaque: [::core::ffi::c_char; 16usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_rwlockattr_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_rwlockattr_t>(),
+        ::core::mem::size_of::<_opaque_pthread_rwlockattr_t>(),
         24usize,
         concat!("Size of: ", stringify!(_opaque_pthread_rwlockattr_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_rwlockattr_t>(),
+        ::core::mem::align_of::<_opaque_pthread_rwlockattr_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_rwlockattr_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlockattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_rwlockattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1160,9 +1160,9 @@ fn bindgen_test_layout__opaque_pthread_rwlockattr_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_rwlockattr_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_rwlockattr_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1178,28 +1178,28 @@ fn bindgen_test_layout__opaque_pthread_rwlockattr_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _opaque_pthread_t {
-    pub __sig: ::std::os::raw::c_long,
+    pub __sig: ::core::ffi::c_long,
     pub __cleanup_stack: *mut __darwin_pthread_handler_rec,
-    pub __opaque: [::std::os::raw::c_char; 8176usize],
+    pub __opaque: [::core::ffi::c_char; 8176usize],
 }
 #[test]
 fn bindgen_test_layout__opaque_pthread_t() {
     assert_eq!(
-        ::std::mem::size_of::<_opaque_pthread_t>(),
+        ::core::mem::size_of::<_opaque_pthread_t>(),
         8192usize,
         concat!("Size of: ", stringify!(_opaque_pthread_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_opaque_pthread_t>(),
+        ::core::mem::align_of::<_opaque_pthread_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_opaque_pthread_t))
     );
     fn test_field___sig() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sig) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1214,9 +1214,9 @@ fn bindgen_test_layout__opaque_pthread_t() {
     fn test_field___cleanup_stack() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__cleanup_stack) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__cleanup_stack) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1231,9 +1231,9 @@ fn bindgen_test_layout__opaque_pthread_t() {
     fn test_field___opaque() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_opaque_pthread_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opaque) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -1249,15 +1249,15 @@ fn bindgen_test_layout__opaque_pthread_t() {
 pub type __darwin_pthread_attr_t = _opaque_pthread_attr_t;
 pub type __darwin_pthread_cond_t = _opaque_pthread_cond_t;
 pub type __darwin_pthread_condattr_t = _opaque_pthread_condattr_t;
-pub type __darwin_pthread_key_t = ::std::os::raw::c_ulong;
+pub type __darwin_pthread_key_t = ::core::ffi::c_ulong;
 pub type __darwin_pthread_mutex_t = _opaque_pthread_mutex_t;
 pub type __darwin_pthread_mutexattr_t = _opaque_pthread_mutexattr_t;
 pub type __darwin_pthread_once_t = _opaque_pthread_once_t;
 pub type __darwin_pthread_rwlock_t = _opaque_pthread_rwlock_t;
 pub type __darwin_pthread_rwlockattr_t = _opaque_pthread_rwlockattr_t;
 pub type __darwin_pthread_t = *mut _opaque_pthread_t;
-pub type __darwin_nl_item = ::std::os::raw::c_int;
-pub type __darwin_wctrans_t = ::std::os::raw::c_int;
+pub type __darwin_nl_item = ::core::ffi::c_int;
+pub type __darwin_wctrans_t = ::core::ffi::c_int;
 pub type __darwin_wctype_t = __uint32_t;
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
@@ -1268,11 +1268,11 @@ pub enum idtype_t {
 }
 pub type pid_t = __darwin_pid_t;
 pub type id_t = __darwin_id_t;
-pub type sig_atomic_t = ::std::os::raw::c_int;
-pub type u_int8_t = ::std::os::raw::c_uchar;
-pub type u_int16_t = ::std::os::raw::c_ushort;
-pub type u_int32_t = ::std::os::raw::c_uint;
-pub type u_int64_t = ::std::os::raw::c_ulonglong;
+pub type sig_atomic_t = ::core::ffi::c_int;
+pub type u_int8_t = ::core::ffi::c_uchar;
+pub type u_int16_t = ::core::ffi::c_ushort;
+pub type u_int32_t = ::core::ffi::c_uint;
+pub type u_int64_t = ::core::ffi::c_ulonglong;
 pub type register_t = i64;
 pub type user_addr_t = u_int64_t;
 pub type user_size_t = u_int64_t;
@@ -1285,41 +1285,41 @@ pub type syscall_arg_t = u_int64_t;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_i386_thread_state {
-    pub __eax: ::std::os::raw::c_uint,
-    pub __ebx: ::std::os::raw::c_uint,
-    pub __ecx: ::std::os::raw::c_uint,
-    pub __edx: ::std::os::raw::c_uint,
-    pub __edi: ::std::os::raw::c_uint,
-    pub __esi: ::std::os::raw::c_uint,
-    pub __ebp: ::std::os::raw::c_uint,
-    pub __esp: ::std::os::raw::c_uint,
-    pub __ss: ::std::os::raw::c_uint,
-    pub __eflags: ::std::os::raw::c_uint,
-    pub __eip: ::std::os::raw::c_uint,
-    pub __cs: ::std::os::raw::c_uint,
-    pub __ds: ::std::os::raw::c_uint,
-    pub __es: ::std::os::raw::c_uint,
-    pub __fs: ::std::os::raw::c_uint,
-    pub __gs: ::std::os::raw::c_uint,
+    pub __eax: ::core::ffi::c_uint,
+    pub __ebx: ::core::ffi::c_uint,
+    pub __ecx: ::core::ffi::c_uint,
+    pub __edx: ::core::ffi::c_uint,
+    pub __edi: ::core::ffi::c_uint,
+    pub __esi: ::core::ffi::c_uint,
+    pub __ebp: ::core::ffi::c_uint,
+    pub __esp: ::core::ffi::c_uint,
+    pub __ss: ::core::ffi::c_uint,
+    pub __eflags: ::core::ffi::c_uint,
+    pub __eip: ::core::ffi::c_uint,
+    pub __cs: ::core::ffi::c_uint,
+    pub __ds: ::core::ffi::c_uint,
+    pub __es: ::core::ffi::c_uint,
+    pub __fs: ::core::ffi::c_uint,
+    pub __gs: ::core::ffi::c_uint,
 }
 #[test]
 fn bindgen_test_layout___darwin_i386_thread_state() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_i386_thread_state>(),
+        ::core::mem::size_of::<__darwin_i386_thread_state>(),
         64usize,
         concat!("Size of: ", stringify!(__darwin_i386_thread_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_i386_thread_state>(),
+        ::core::mem::align_of::<__darwin_i386_thread_state>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_i386_thread_state))
     );
     fn test_field___eax() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__eax) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__eax) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1334,9 +1334,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___ebx() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ebx) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ebx) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -1351,9 +1351,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___ecx() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ecx) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ecx) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1368,9 +1368,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___edx() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__edx) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__edx) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -1385,9 +1385,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___edi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__edi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__edi) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -1402,9 +1402,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___esi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__esi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__esi) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -1419,9 +1419,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___ebp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ebp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ebp) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -1436,9 +1436,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___esp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__esp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__esp) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -1453,9 +1453,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -1470,9 +1470,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___eflags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__eflags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__eflags) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -1487,9 +1487,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___eip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__eip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__eip) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -1504,9 +1504,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__cs) as usize - ptr as usize
             },
             44usize,
             concat!(
@@ -1521,9 +1521,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ds) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -1538,9 +1538,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -1555,9 +1555,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -1572,9 +1572,9 @@ fn bindgen_test_layout___darwin_i386_thread_state() {
     fn test_field___gs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_thread_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__gs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__gs) as usize - ptr as usize
             },
             60usize,
             concat!(
@@ -1597,147 +1597,147 @@ pub struct __darwin_fp_control {
 #[test]
 fn bindgen_test_layout___darwin_fp_control() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_fp_control>(),
+        ::core::mem::size_of::<__darwin_fp_control>(),
         2usize,
         concat!("Size of: ", stringify!(__darwin_fp_control))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_fp_control>(),
+        ::core::mem::align_of::<__darwin_fp_control>(),
         2usize,
         concat!("Alignment of ", stringify!(__darwin_fp_control))
     );
 }
 impl __darwin_fp_control {
     #[inline]
-    pub fn __invalid(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u16) }
+    pub fn __invalid(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___invalid(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___invalid(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __denorm(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u16) }
+    pub fn __denorm(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___denorm(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___denorm(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(1usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __zdiv(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u16) }
+    pub fn __zdiv(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___zdiv(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___zdiv(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(2usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __ovrfl(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u16) }
+    pub fn __ovrfl(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___ovrfl(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___ovrfl(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(3usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __undfl(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u16) }
+    pub fn __undfl(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___undfl(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___undfl(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(4usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __precis(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u16) }
+    pub fn __precis(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___precis(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___precis(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(5usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __pc(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 2u8) as u16) }
+    pub fn __pc(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(8usize, 2u8) as u16) }
     }
     #[inline]
-    pub fn set___pc(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___pc(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(8usize, 2u8, val as u64)
         }
     }
     #[inline]
-    pub fn __rc(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(10usize, 2u8) as u16) }
+    pub fn __rc(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(10usize, 2u8) as u16) }
     }
     #[inline]
-    pub fn set___rc(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___rc(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(10usize, 2u8, val as u64)
         }
     }
     #[inline]
     pub fn new_bitfield_1(
-        __invalid: ::std::os::raw::c_ushort,
-        __denorm: ::std::os::raw::c_ushort,
-        __zdiv: ::std::os::raw::c_ushort,
-        __ovrfl: ::std::os::raw::c_ushort,
-        __undfl: ::std::os::raw::c_ushort,
-        __precis: ::std::os::raw::c_ushort,
-        __pc: ::std::os::raw::c_ushort,
-        __rc: ::std::os::raw::c_ushort,
+        __invalid: ::core::ffi::c_ushort,
+        __denorm: ::core::ffi::c_ushort,
+        __zdiv: ::core::ffi::c_ushort,
+        __ovrfl: ::core::ffi::c_ushort,
+        __undfl: ::core::ffi::c_ushort,
+        __precis: ::core::ffi::c_ushort,
+        __pc: ::core::ffi::c_ushort,
+        __rc: ::core::ffi::c_ushort,
     ) -> __BindgenBitfieldUnit<[u8; 2usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 2usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let __invalid: u16 = unsafe { ::std::mem::transmute(__invalid) };
+            let __invalid: u16 = unsafe { ::core::mem::transmute(__invalid) };
             __invalid as u64
         });
         __bindgen_bitfield_unit.set(1usize, 1u8, {
-            let __denorm: u16 = unsafe { ::std::mem::transmute(__denorm) };
+            let __denorm: u16 = unsafe { ::core::mem::transmute(__denorm) };
             __denorm as u64
         });
         __bindgen_bitfield_unit.set(2usize, 1u8, {
-            let __zdiv: u16 = unsafe { ::std::mem::transmute(__zdiv) };
+            let __zdiv: u16 = unsafe { ::core::mem::transmute(__zdiv) };
             __zdiv as u64
         });
         __bindgen_bitfield_unit.set(3usize, 1u8, {
-            let __ovrfl: u16 = unsafe { ::std::mem::transmute(__ovrfl) };
+            let __ovrfl: u16 = unsafe { ::core::mem::transmute(__ovrfl) };
             __ovrfl as u64
         });
         __bindgen_bitfield_unit.set(4usize, 1u8, {
-            let __undfl: u16 = unsafe { ::std::mem::transmute(__undfl) };
+            let __undfl: u16 = unsafe { ::core::mem::transmute(__undfl) };
             __undfl as u64
         });
         __bindgen_bitfield_unit.set(5usize, 1u8, {
-            let __precis: u16 = unsafe { ::std::mem::transmute(__precis) };
+            let __precis: u16 = unsafe { ::core::mem::transmute(__precis) };
             __precis as u64
         });
         __bindgen_bitfield_unit.set(8usize, 2u8, {
-            let __pc: u16 = unsafe { ::std::mem::transmute(__pc) };
+            let __pc: u16 = unsafe { ::core::mem::transmute(__pc) };
             __pc as u64
         });
         __bindgen_bitfield_unit.set(10usize, 2u8, {
-            let __rc: u16 = unsafe { ::std::mem::transmute(__rc) };
+            let __rc: u16 = unsafe { ::core::mem::transmute(__rc) };
             __rc as u64
         });
         __bindgen_bitfield_unit
@@ -1754,243 +1754,243 @@ pub struct __darwin_fp_status {
 #[test]
 fn bindgen_test_layout___darwin_fp_status() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_fp_status>(),
+        ::core::mem::size_of::<__darwin_fp_status>(),
         2usize,
         concat!("Size of: ", stringify!(__darwin_fp_status))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_fp_status>(),
+        ::core::mem::align_of::<__darwin_fp_status>(),
         2usize,
         concat!("Alignment of ", stringify!(__darwin_fp_status))
     );
 }
 impl __darwin_fp_status {
     #[inline]
-    pub fn __invalid(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u16) }
+    pub fn __invalid(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___invalid(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___invalid(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __denorm(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u16) }
+    pub fn __denorm(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___denorm(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___denorm(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(1usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __zdiv(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u16) }
+    pub fn __zdiv(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___zdiv(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___zdiv(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(2usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __ovrfl(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u16) }
+    pub fn __ovrfl(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___ovrfl(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___ovrfl(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(3usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __undfl(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u16) }
+    pub fn __undfl(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___undfl(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___undfl(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(4usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __precis(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u16) }
+    pub fn __precis(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___precis(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___precis(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(5usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __stkflt(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(6usize, 1u8) as u16) }
+    pub fn __stkflt(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(6usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___stkflt(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___stkflt(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(6usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __errsumm(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u16) }
+    pub fn __errsumm(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___errsumm(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___errsumm(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(7usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __c0(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 1u8) as u16) }
+    pub fn __c0(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(8usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___c0(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___c0(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(8usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __c1(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(9usize, 1u8) as u16) }
+    pub fn __c1(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(9usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___c1(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___c1(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(9usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __c2(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(10usize, 1u8) as u16) }
+    pub fn __c2(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(10usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___c2(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___c2(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(10usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __tos(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(11usize, 3u8) as u16) }
+    pub fn __tos(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(11usize, 3u8) as u16) }
     }
     #[inline]
-    pub fn set___tos(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___tos(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(11usize, 3u8, val as u64)
         }
     }
     #[inline]
-    pub fn __c3(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(14usize, 1u8) as u16) }
+    pub fn __c3(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(14usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___c3(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___c3(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(14usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn __busy(&self) -> ::std::os::raw::c_ushort {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(15usize, 1u8) as u16) }
+    pub fn __busy(&self) -> ::core::ffi::c_ushort {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(15usize, 1u8) as u16) }
     }
     #[inline]
-    pub fn set___busy(&mut self, val: ::std::os::raw::c_ushort) {
+    pub fn set___busy(&mut self, val: ::core::ffi::c_ushort) {
         unsafe {
-            let val: u16 = ::std::mem::transmute(val);
+            let val: u16 = ::core::mem::transmute(val);
             self._bitfield_1.set(15usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub fn new_bitfield_1(
-        __invalid: ::std::os::raw::c_ushort,
-        __denorm: ::std::os::raw::c_ushort,
-        __zdiv: ::std::os::raw::c_ushort,
-        __ovrfl: ::std::os::raw::c_ushort,
-        __undfl: ::std::os::raw::c_ushort,
-        __precis: ::std::os::raw::c_ushort,
-        __stkflt: ::std::os::raw::c_ushort,
-        __errsumm: ::std::os::raw::c_ushort,
-        __c0: ::std::os::raw::c_ushort,
-        __c1: ::std::os::raw::c_ushort,
-        __c2: ::std::os::raw::c_ushort,
-        __tos: ::std::os::raw::c_ushort,
-        __c3: ::std::os::raw::c_ushort,
-        __busy: ::std::os::raw::c_ushort,
+        __invalid: ::core::ffi::c_ushort,
+        __denorm: ::core::ffi::c_ushort,
+        __zdiv: ::core::ffi::c_ushort,
+        __ovrfl: ::core::ffi::c_ushort,
+        __undfl: ::core::ffi::c_ushort,
+        __precis: ::core::ffi::c_ushort,
+        __stkflt: ::core::ffi::c_ushort,
+        __errsumm: ::core::ffi::c_ushort,
+        __c0: ::core::ffi::c_ushort,
+        __c1: ::core::ffi::c_ushort,
+        __c2: ::core::ffi::c_ushort,
+        __tos: ::core::ffi::c_ushort,
+        __c3: ::core::ffi::c_ushort,
+        __busy: ::core::ffi::c_ushort,
     ) -> __BindgenBitfieldUnit<[u8; 2usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 2usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let __invalid: u16 = unsafe { ::std::mem::transmute(__invalid) };
+            let __invalid: u16 = unsafe { ::core::mem::transmute(__invalid) };
             __invalid as u64
         });
         __bindgen_bitfield_unit.set(1usize, 1u8, {
-            let __denorm: u16 = unsafe { ::std::mem::transmute(__denorm) };
+            let __denorm: u16 = unsafe { ::core::mem::transmute(__denorm) };
             __denorm as u64
         });
         __bindgen_bitfield_unit.set(2usize, 1u8, {
-            let __zdiv: u16 = unsafe { ::std::mem::transmute(__zdiv) };
+            let __zdiv: u16 = unsafe { ::core::mem::transmute(__zdiv) };
             __zdiv as u64
         });
         __bindgen_bitfield_unit.set(3usize, 1u8, {
-            let __ovrfl: u16 = unsafe { ::std::mem::transmute(__ovrfl) };
+            let __ovrfl: u16 = unsafe { ::core::mem::transmute(__ovrfl) };
             __ovrfl as u64
         });
         __bindgen_bitfield_unit.set(4usize, 1u8, {
-            let __undfl: u16 = unsafe { ::std::mem::transmute(__undfl) };
+            let __undfl: u16 = unsafe { ::core::mem::transmute(__undfl) };
             __undfl as u64
         });
         __bindgen_bitfield_unit.set(5usize, 1u8, {
-            let __precis: u16 = unsafe { ::std::mem::transmute(__precis) };
+            let __precis: u16 = unsafe { ::core::mem::transmute(__precis) };
             __precis as u64
         });
         __bindgen_bitfield_unit.set(6usize, 1u8, {
-            let __stkflt: u16 = unsafe { ::std::mem::transmute(__stkflt) };
+            let __stkflt: u16 = unsafe { ::core::mem::transmute(__stkflt) };
             __stkflt as u64
         });
         __bindgen_bitfield_unit.set(7usize, 1u8, {
-            let __errsumm: u16 = unsafe { ::std::mem::transmute(__errsumm) };
+            let __errsumm: u16 = unsafe { ::core::mem::transmute(__errsumm) };
             __errsumm as u64
         });
         __bindgen_bitfield_unit.set(8usize, 1u8, {
-            let __c0: u16 = unsafe { ::std::mem::transmute(__c0) };
+            let __c0: u16 = unsafe { ::core::mem::transmute(__c0) };
             __c0 as u64
         });
         __bindgen_bitfield_unit.set(9usize, 1u8, {
-            let __c1: u16 = unsafe { ::std::mem::transmute(__c1) };
+            let __c1: u16 = unsafe { ::core::mem::transmute(__c1) };
             __c1 as u64
         });
         __bindgen_bitfield_unit.set(10usize, 1u8, {
-            let __c2: u16 = unsafe { ::std::mem::transmute(__c2) };
+            let __c2: u16 = unsafe { ::core::mem::transmute(__c2) };
             __c2 as u64
         });
         __bindgen_bitfield_unit.set(11usize, 3u8, {
-            let __tos: u16 = unsafe { ::std::mem::transmute(__tos) };
+            let __tos: u16 = unsafe { ::core::mem::transmute(__tos) };
             __tos as u64
         });
         __bindgen_bitfield_unit.set(14usize, 1u8, {
-            let __c3: u16 = unsafe { ::std::mem::transmute(__c3) };
+            let __c3: u16 = unsafe { ::core::mem::transmute(__c3) };
             __c3 as u64
         });
         __bindgen_bitfield_unit.set(15usize, 1u8, {
-            let __busy: u16 = unsafe { ::std::mem::transmute(__busy) };
+            let __busy: u16 = unsafe { ::core::mem::transmute(__busy) };
             __busy as u64
         });
         __bindgen_bitfield_unit
@@ -2000,27 +2000,27 @@ pub type __darwin_fp_status_t = __darwin_fp_status;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_mmst_reg {
-    pub __mmst_reg: [::std::os::raw::c_char; 10usize],
-    pub __mmst_rsrv: [::std::os::raw::c_char; 6usize],
+    pub __mmst_reg: [::core::ffi::c_char; 10usize],
+    pub __mmst_rsrv: [::core::ffi::c_char; 6usize],
 }
 #[test]
 fn bindgen_test_layout___darwin_mmst_reg() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mmst_reg>(),
+        ::core::mem::size_of::<__darwin_mmst_reg>(),
         16usize,
         concat!("Size of: ", stringify!(__darwin_mmst_reg))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mmst_reg>(),
+        ::core::mem::align_of::<__darwin_mmst_reg>(),
         1usize,
         concat!("Alignment of ", stringify!(__darwin_mmst_reg))
     );
     fn test_field___mmst_reg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mmst_reg>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mmst_reg>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__mmst_reg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__mmst_reg) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2035,9 +2035,9 @@ fn bindgen_test_layout___darwin_mmst_reg() {
     fn test_field___mmst_rsrv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mmst_reg>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mmst_reg>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__mmst_rsrv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__mmst_rsrv) as usize - ptr as usize
             },
             10usize,
             concat!(
@@ -2053,26 +2053,26 @@ fn bindgen_test_layout___darwin_mmst_reg() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_xmm_reg {
-    pub __xmm_reg: [::std::os::raw::c_char; 16usize],
+    pub __xmm_reg: [::core::ffi::c_char; 16usize],
 }
 #[test]
 fn bindgen_test_layout___darwin_xmm_reg() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_xmm_reg>(),
+        ::core::mem::size_of::<__darwin_xmm_reg>(),
         16usize,
         concat!("Size of: ", stringify!(__darwin_xmm_reg))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_xmm_reg>(),
+        ::core::mem::align_of::<__darwin_xmm_reg>(),
         1usize,
         concat!("Alignment of ", stringify!(__darwin_xmm_reg))
     );
     fn test_field___xmm_reg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_xmm_reg>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_xmm_reg>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__xmm_reg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__xmm_reg) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2088,26 +2088,26 @@ fn bindgen_test_layout___darwin_xmm_reg() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_ymm_reg {
-    pub __ymm_reg: [::std::os::raw::c_char; 32usize],
+    pub __ymm_reg: [::core::ffi::c_char; 32usize],
 }
 #[test]
 fn bindgen_test_layout___darwin_ymm_reg() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_ymm_reg>(),
+        ::core::mem::size_of::<__darwin_ymm_reg>(),
         32usize,
         concat!("Size of: ", stringify!(__darwin_ymm_reg))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_ymm_reg>(),
+        ::core::mem::align_of::<__darwin_ymm_reg>(),
         1usize,
         concat!("Alignment of ", stringify!(__darwin_ymm_reg))
     );
     fn test_field___ymm_reg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_ymm_reg>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_ymm_reg>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ymm_reg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ymm_reg) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2123,26 +2123,26 @@ fn bindgen_test_layout___darwin_ymm_reg() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_zmm_reg {
-    pub __zmm_reg: [::std::os::raw::c_char; 64usize],
+    pub __zmm_reg: [::core::ffi::c_char; 64usize],
 }
 #[test]
 fn bindgen_test_layout___darwin_zmm_reg() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_zmm_reg>(),
+        ::core::mem::size_of::<__darwin_zmm_reg>(),
         64usize,
         concat!("Size of: ", stringify!(__darwin_zmm_reg))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_zmm_reg>(),
+        ::core::mem::align_of::<__darwin_zmm_reg>(),
         1usize,
         concat!("Alignment of ", stringify!(__darwin_zmm_reg))
     );
     fn test_field___zmm_reg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_zmm_reg>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_zmm_reg>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__zmm_reg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__zmm_reg) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2158,26 +2158,26 @@ fn bindgen_test_layout___darwin_zmm_reg() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_opmask_reg {
-    pub __opmask_reg: [::std::os::raw::c_char; 8usize],
+    pub __opmask_reg: [::core::ffi::c_char; 8usize],
 }
 #[test]
 fn bindgen_test_layout___darwin_opmask_reg() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_opmask_reg>(),
+        ::core::mem::size_of::<__darwin_opmask_reg>(),
         8usize,
         concat!("Size of: ", stringify!(__darwin_opmask_reg))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_opmask_reg>(),
+        ::core::mem::align_of::<__darwin_opmask_reg>(),
         1usize,
         concat!("Alignment of ", stringify!(__darwin_opmask_reg))
     );
     fn test_field___opmask_reg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_opmask_reg>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_opmask_reg>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__opmask_reg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__opmask_reg) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2193,7 +2193,7 @@ fn bindgen_test_layout___darwin_opmask_reg() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_i386_float_state {
-    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_reserved: [::core::ffi::c_int; 2usize],
     pub __fpu_fcw: __darwin_fp_control,
     pub __fpu_fsw: __darwin_fp_status,
     pub __fpu_ftw: __uint8_t,
@@ -2223,27 +2223,27 @@ pub struct __darwin_i386_float_state {
     pub __fpu_xmm5: __darwin_xmm_reg,
     pub __fpu_xmm6: __darwin_xmm_reg,
     pub __fpu_xmm7: __darwin_xmm_reg,
-    pub __fpu_rsrv4: [::std::os::raw::c_char; 224usize],
-    pub __fpu_reserved1: ::std::os::raw::c_int,
+    pub __fpu_rsrv4: [::core::ffi::c_char; 224usize],
+    pub __fpu_reserved1: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout___darwin_i386_float_state() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_i386_float_state>(),
+        ::core::mem::size_of::<__darwin_i386_float_state>(),
         524usize,
         concat!("Size of: ", stringify!(__darwin_i386_float_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_i386_float_state>(),
+        ::core::mem::align_of::<__darwin_i386_float_state>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_i386_float_state))
     );
     fn test_field___fpu_reserved() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2258,9 +2258,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_fcw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -2275,9 +2275,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_fsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
             },
             10usize,
             concat!(
@@ -2292,9 +2292,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_ftw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -2309,9 +2309,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_rsrv1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
             },
             13usize,
             concat!(
@@ -2326,9 +2326,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_fop() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
             },
             14usize,
             concat!(
@@ -2343,9 +2343,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -2360,9 +2360,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -2377,9 +2377,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_rsrv2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
             },
             22usize,
             concat!(
@@ -2394,9 +2394,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_dp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -2411,9 +2411,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -2428,9 +2428,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_rsrv3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
             },
             30usize,
             concat!(
@@ -2445,9 +2445,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_mxcsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -2462,9 +2462,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_mxcsrmask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -2479,9 +2479,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -2496,9 +2496,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -2513,9 +2513,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -2530,9 +2530,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -2547,9 +2547,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -2564,9 +2564,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -2581,9 +2581,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -2598,9 +2598,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_stmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -2615,9 +2615,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -2632,9 +2632,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -2649,9 +2649,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -2666,9 +2666,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -2683,9 +2683,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -2700,9 +2700,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -2717,9 +2717,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -2734,9 +2734,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_xmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -2751,9 +2751,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_rsrv4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -2768,9 +2768,9 @@ fn bindgen_test_layout___darwin_i386_float_state() {
     fn test_field___fpu_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_float_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -2786,7 +2786,7 @@ fn bindgen_test_layout___darwin_i386_float_state() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_i386_avx_state {
-    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_reserved: [::core::ffi::c_int; 2usize],
     pub __fpu_fcw: __darwin_fp_control,
     pub __fpu_fsw: __darwin_fp_status,
     pub __fpu_ftw: __uint8_t,
@@ -2816,9 +2816,9 @@ pub struct __darwin_i386_avx_state {
     pub __fpu_xmm5: __darwin_xmm_reg,
     pub __fpu_xmm6: __darwin_xmm_reg,
     pub __fpu_xmm7: __darwin_xmm_reg,
-    pub __fpu_rsrv4: [::std::os::raw::c_char; 224usize],
-    pub __fpu_reserved1: ::std::os::raw::c_int,
-    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_rsrv4: [::core::ffi::c_char; 224usize],
+    pub __fpu_reserved1: ::core::ffi::c_int,
+    pub __avx_reserved1: [::core::ffi::c_char; 64usize],
     pub __fpu_ymmh0: __darwin_xmm_reg,
     pub __fpu_ymmh1: __darwin_xmm_reg,
     pub __fpu_ymmh2: __darwin_xmm_reg,
@@ -2831,21 +2831,21 @@ pub struct __darwin_i386_avx_state {
 #[test]
 fn bindgen_test_layout___darwin_i386_avx_state() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_i386_avx_state>(),
+        ::core::mem::size_of::<__darwin_i386_avx_state>(),
         716usize,
         concat!("Size of: ", stringify!(__darwin_i386_avx_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_i386_avx_state>(),
+        ::core::mem::align_of::<__darwin_i386_avx_state>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_i386_avx_state))
     );
     fn test_field___fpu_reserved() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -2860,9 +2860,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_fcw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -2877,9 +2877,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_fsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
             },
             10usize,
             concat!(
@@ -2894,9 +2894,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ftw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -2911,9 +2911,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_rsrv1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
             },
             13usize,
             concat!(
@@ -2928,9 +2928,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_fop() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
             },
             14usize,
             concat!(
@@ -2945,9 +2945,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -2962,9 +2962,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -2979,9 +2979,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_rsrv2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
             },
             22usize,
             concat!(
@@ -2996,9 +2996,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_dp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3013,9 +3013,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -3030,9 +3030,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_rsrv3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
             },
             30usize,
             concat!(
@@ -3047,9 +3047,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_mxcsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -3064,9 +3064,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_mxcsrmask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -3081,9 +3081,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -3098,9 +3098,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -3115,9 +3115,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -3132,9 +3132,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -3149,9 +3149,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -3166,9 +3166,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -3183,9 +3183,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -3200,9 +3200,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_stmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -3217,9 +3217,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -3234,9 +3234,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -3251,9 +3251,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -3268,9 +3268,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -3285,9 +3285,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -3302,9 +3302,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -3319,9 +3319,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -3336,9 +3336,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_xmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -3353,9 +3353,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_rsrv4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -3370,9 +3370,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -3387,9 +3387,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___avx_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
             },
             524usize,
             concat!(
@@ -3404,9 +3404,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
             },
             588usize,
             concat!(
@@ -3421,9 +3421,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
             },
             604usize,
             concat!(
@@ -3438,9 +3438,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
             },
             620usize,
             concat!(
@@ -3455,9 +3455,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
             },
             636usize,
             concat!(
@@ -3472,9 +3472,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
             },
             652usize,
             concat!(
@@ -3489,9 +3489,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
             },
             668usize,
             concat!(
@@ -3506,9 +3506,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
             },
             684usize,
             concat!(
@@ -3523,9 +3523,9 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
     fn test_field___fpu_ymmh7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
             },
             700usize,
             concat!(
@@ -3541,7 +3541,7 @@ fn bindgen_test_layout___darwin_i386_avx_state() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_i386_avx512_state {
-    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_reserved: [::core::ffi::c_int; 2usize],
     pub __fpu_fcw: __darwin_fp_control,
     pub __fpu_fsw: __darwin_fp_status,
     pub __fpu_ftw: __uint8_t,
@@ -3571,9 +3571,9 @@ pub struct __darwin_i386_avx512_state {
     pub __fpu_xmm5: __darwin_xmm_reg,
     pub __fpu_xmm6: __darwin_xmm_reg,
     pub __fpu_xmm7: __darwin_xmm_reg,
-    pub __fpu_rsrv4: [::std::os::raw::c_char; 224usize],
-    pub __fpu_reserved1: ::std::os::raw::c_int,
-    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_rsrv4: [::core::ffi::c_char; 224usize],
+    pub __fpu_reserved1: ::core::ffi::c_int,
+    pub __avx_reserved1: [::core::ffi::c_char; 64usize],
     pub __fpu_ymmh0: __darwin_xmm_reg,
     pub __fpu_ymmh1: __darwin_xmm_reg,
     pub __fpu_ymmh2: __darwin_xmm_reg,
@@ -3602,21 +3602,21 @@ pub struct __darwin_i386_avx512_state {
 #[test]
 fn bindgen_test_layout___darwin_i386_avx512_state() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_i386_avx512_state>(),
+        ::core::mem::size_of::<__darwin_i386_avx512_state>(),
         1036usize,
         concat!("Size of: ", stringify!(__darwin_i386_avx512_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_i386_avx512_state>(),
+        ::core::mem::align_of::<__darwin_i386_avx512_state>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_i386_avx512_state))
     );
     fn test_field___fpu_reserved() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3631,9 +3631,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_fcw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3648,9 +3648,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_fsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
             },
             10usize,
             concat!(
@@ -3665,9 +3665,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ftw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -3682,9 +3682,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_rsrv1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
             },
             13usize,
             concat!(
@@ -3699,9 +3699,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_fop() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
             },
             14usize,
             concat!(
@@ -3716,9 +3716,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3733,9 +3733,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -3750,9 +3750,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_rsrv2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
             },
             22usize,
             concat!(
@@ -3767,9 +3767,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_dp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3784,9 +3784,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -3801,9 +3801,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_rsrv3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
             },
             30usize,
             concat!(
@@ -3818,9 +3818,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_mxcsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -3835,9 +3835,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_mxcsrmask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -3852,9 +3852,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -3869,9 +3869,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -3886,9 +3886,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -3903,9 +3903,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -3920,9 +3920,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -3937,9 +3937,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -3954,9 +3954,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -3971,9 +3971,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_stmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -3988,9 +3988,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -4005,9 +4005,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -4022,9 +4022,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -4039,9 +4039,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -4056,9 +4056,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -4073,9 +4073,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -4090,9 +4090,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -4107,9 +4107,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_xmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -4124,9 +4124,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_rsrv4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -4141,9 +4141,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -4158,9 +4158,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___avx_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
             },
             524usize,
             concat!(
@@ -4175,9 +4175,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
             },
             588usize,
             concat!(
@@ -4192,9 +4192,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
             },
             604usize,
             concat!(
@@ -4209,9 +4209,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
             },
             620usize,
             concat!(
@@ -4226,9 +4226,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
             },
             636usize,
             concat!(
@@ -4243,9 +4243,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
             },
             652usize,
             concat!(
@@ -4260,9 +4260,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
             },
             668usize,
             concat!(
@@ -4277,9 +4277,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
             },
             684usize,
             concat!(
@@ -4294,9 +4294,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_ymmh7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
             },
             700usize,
             concat!(
@@ -4311,9 +4311,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k0) as usize - ptr as usize
             },
             716usize,
             concat!(
@@ -4328,9 +4328,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k1) as usize - ptr as usize
             },
             724usize,
             concat!(
@@ -4345,9 +4345,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k2) as usize - ptr as usize
             },
             732usize,
             concat!(
@@ -4362,9 +4362,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k3) as usize - ptr as usize
             },
             740usize,
             concat!(
@@ -4379,9 +4379,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k4) as usize - ptr as usize
             },
             748usize,
             concat!(
@@ -4396,9 +4396,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k5) as usize - ptr as usize
             },
             756usize,
             concat!(
@@ -4413,9 +4413,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k6) as usize - ptr as usize
             },
             764usize,
             concat!(
@@ -4430,9 +4430,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_k7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k7) as usize - ptr as usize
             },
             772usize,
             concat!(
@@ -4447,9 +4447,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh0) as usize - ptr as usize
             },
             780usize,
             concat!(
@@ -4464,9 +4464,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh1) as usize - ptr as usize
             },
             812usize,
             concat!(
@@ -4481,9 +4481,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh2) as usize - ptr as usize
             },
             844usize,
             concat!(
@@ -4498,9 +4498,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh3) as usize - ptr as usize
             },
             876usize,
             concat!(
@@ -4515,9 +4515,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh4) as usize - ptr as usize
             },
             908usize,
             concat!(
@@ -4532,9 +4532,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh5) as usize - ptr as usize
             },
             940usize,
             concat!(
@@ -4549,9 +4549,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh6) as usize - ptr as usize
             },
             972usize,
             concat!(
@@ -4566,9 +4566,9 @@ fn bindgen_test_layout___darwin_i386_avx512_state() {
     fn test_field___fpu_zmmh7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_avx512_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh7) as usize - ptr as usize
             },
             1004usize,
             concat!(
@@ -4592,21 +4592,21 @@ pub struct __darwin_i386_exception_state {
 #[test]
 fn bindgen_test_layout___darwin_i386_exception_state() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_i386_exception_state>(),
+        ::core::mem::size_of::<__darwin_i386_exception_state>(),
         12usize,
         concat!("Size of: ", stringify!(__darwin_i386_exception_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_i386_exception_state>(),
+        ::core::mem::align_of::<__darwin_i386_exception_state>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_i386_exception_state))
     );
     fn test_field___trapno() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__trapno) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__trapno) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4621,9 +4621,9 @@ fn bindgen_test_layout___darwin_i386_exception_state() {
     fn test_field___cpu() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__cpu) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__cpu) as usize - ptr as usize
             },
             2usize,
             concat!(
@@ -4638,9 +4638,9 @@ fn bindgen_test_layout___darwin_i386_exception_state() {
     fn test_field___err() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__err) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__err) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -4655,9 +4655,9 @@ fn bindgen_test_layout___darwin_i386_exception_state() {
     fn test_field___faultvaddr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_i386_exception_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__faultvaddr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__faultvaddr) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4673,33 +4673,33 @@ fn bindgen_test_layout___darwin_i386_exception_state() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_x86_debug_state32 {
-    pub __dr0: ::std::os::raw::c_uint,
-    pub __dr1: ::std::os::raw::c_uint,
-    pub __dr2: ::std::os::raw::c_uint,
-    pub __dr3: ::std::os::raw::c_uint,
-    pub __dr4: ::std::os::raw::c_uint,
-    pub __dr5: ::std::os::raw::c_uint,
-    pub __dr6: ::std::os::raw::c_uint,
-    pub __dr7: ::std::os::raw::c_uint,
+    pub __dr0: ::core::ffi::c_uint,
+    pub __dr1: ::core::ffi::c_uint,
+    pub __dr2: ::core::ffi::c_uint,
+    pub __dr3: ::core::ffi::c_uint,
+    pub __dr4: ::core::ffi::c_uint,
+    pub __dr5: ::core::ffi::c_uint,
+    pub __dr6: ::core::ffi::c_uint,
+    pub __dr7: ::core::ffi::c_uint,
 }
 #[test]
 fn bindgen_test_layout___darwin_x86_debug_state32() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_debug_state32>(),
+        ::core::mem::size_of::<__darwin_x86_debug_state32>(),
         32usize,
         concat!("Size of: ", stringify!(__darwin_x86_debug_state32))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_debug_state32>(),
+        ::core::mem::align_of::<__darwin_x86_debug_state32>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_x86_debug_state32))
     );
     fn test_field___dr0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr0) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4714,9 +4714,9 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
     fn test_field___dr1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr1) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -4731,9 +4731,9 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
     fn test_field___dr2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr2) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4748,9 +4748,9 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
     fn test_field___dr3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr3) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -4765,9 +4765,9 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
     fn test_field___dr4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr4) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -4782,9 +4782,9 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
     fn test_field___dr5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr5) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -4799,9 +4799,9 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
     fn test_field___dr6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr6) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -4816,9 +4816,9 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
     fn test_field___dr7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr7) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -4834,30 +4834,30 @@ fn bindgen_test_layout___darwin_x86_debug_state32() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __x86_instruction_state {
-    pub __insn_stream_valid_bytes: ::std::os::raw::c_int,
-    pub __insn_offset: ::std::os::raw::c_int,
-    pub __out_of_synch: ::std::os::raw::c_int,
+    pub __insn_stream_valid_bytes: ::core::ffi::c_int,
+    pub __insn_offset: ::core::ffi::c_int,
+    pub __out_of_synch: ::core::ffi::c_int,
     pub __insn_bytes: [__uint8_t; 2380usize],
     pub __insn_cacheline: [__uint8_t; 64usize],
 }
 #[test]
 fn bindgen_test_layout___x86_instruction_state() {
     assert_eq!(
-        ::std::mem::size_of::<__x86_instruction_state>(),
+        ::core::mem::size_of::<__x86_instruction_state>(),
         2456usize,
         concat!("Size of: ", stringify!(__x86_instruction_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__x86_instruction_state>(),
+        ::core::mem::align_of::<__x86_instruction_state>(),
         4usize,
         concat!("Alignment of ", stringify!(__x86_instruction_state))
     );
     fn test_field___insn_stream_valid_bytes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__x86_instruction_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__insn_stream_valid_bytes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__insn_stream_valid_bytes) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4872,9 +4872,9 @@ fn bindgen_test_layout___x86_instruction_state() {
     fn test_field___insn_offset() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__x86_instruction_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__insn_offset) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__insn_offset) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -4889,9 +4889,9 @@ fn bindgen_test_layout___x86_instruction_state() {
     fn test_field___out_of_synch() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__x86_instruction_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__out_of_synch) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__out_of_synch) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4906,9 +4906,9 @@ fn bindgen_test_layout___x86_instruction_state() {
     fn test_field___insn_bytes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__x86_instruction_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__insn_bytes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__insn_bytes) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -4923,9 +4923,9 @@ fn bindgen_test_layout___x86_instruction_state() {
     fn test_field___insn_cacheline() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__x86_instruction_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__x86_instruction_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__insn_cacheline) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__insn_cacheline) as usize - ptr as usize
             },
             2392usize,
             concat!(
@@ -4950,21 +4950,21 @@ pub struct __last_branch_record {
 #[test]
 fn bindgen_test_layout___last_branch_record() {
     assert_eq!(
-        ::std::mem::size_of::<__last_branch_record>(),
+        ::core::mem::size_of::<__last_branch_record>(),
         24usize,
         concat!("Size of: ", stringify!(__last_branch_record))
     );
     assert_eq!(
-        ::std::mem::align_of::<__last_branch_record>(),
+        ::core::mem::align_of::<__last_branch_record>(),
         8usize,
         concat!("Alignment of ", stringify!(__last_branch_record))
     );
     fn test_field___from_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__last_branch_record>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__last_branch_record>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__from_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__from_ip) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4979,9 +4979,9 @@ fn bindgen_test_layout___last_branch_record() {
     fn test_field___to_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__last_branch_record>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__last_branch_record>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__to_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__to_ip) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4997,56 +4997,56 @@ fn bindgen_test_layout___last_branch_record() {
 impl __last_branch_record {
     #[inline]
     pub fn __mispredict(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
     }
     #[inline]
     pub fn set___mispredict(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub fn __tsx_abort(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
     }
     #[inline]
     pub fn set___tsx_abort(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(1usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub fn __in_tsx(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u32) }
     }
     #[inline]
     pub fn set___in_tsx(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(2usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub fn __cycle_count(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 16u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 16u8) as u32) }
     }
     #[inline]
     pub fn set___cycle_count(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(3usize, 16u8, val as u64)
         }
     }
     #[inline]
     pub fn __reserved(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(19usize, 13u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(19usize, 13u8) as u32) }
     }
     #[inline]
     pub fn set___reserved(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(19usize, 13u8, val as u64)
         }
     }
@@ -5060,23 +5060,23 @@ impl __last_branch_record {
     ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let __mispredict: u32 = unsafe { ::std::mem::transmute(__mispredict) };
+            let __mispredict: u32 = unsafe { ::core::mem::transmute(__mispredict) };
             __mispredict as u64
         });
         __bindgen_bitfield_unit.set(1usize, 1u8, {
-            let __tsx_abort: u32 = unsafe { ::std::mem::transmute(__tsx_abort) };
+            let __tsx_abort: u32 = unsafe { ::core::mem::transmute(__tsx_abort) };
             __tsx_abort as u64
         });
         __bindgen_bitfield_unit.set(2usize, 1u8, {
-            let __in_tsx: u32 = unsafe { ::std::mem::transmute(__in_tsx) };
+            let __in_tsx: u32 = unsafe { ::core::mem::transmute(__in_tsx) };
             __in_tsx as u64
         });
         __bindgen_bitfield_unit.set(3usize, 16u8, {
-            let __cycle_count: u32 = unsafe { ::std::mem::transmute(__cycle_count) };
+            let __cycle_count: u32 = unsafe { ::core::mem::transmute(__cycle_count) };
             __cycle_count as u64
         });
         __bindgen_bitfield_unit.set(19usize, 13u8, {
-            let __reserved: u32 = unsafe { ::std::mem::transmute(__reserved) };
+            let __reserved: u32 = unsafe { ::core::mem::transmute(__reserved) };
             __reserved as u64
         });
         __bindgen_bitfield_unit
@@ -5085,7 +5085,7 @@ impl __last_branch_record {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __last_branch_state {
-    pub __lbr_count: ::std::os::raw::c_int,
+    pub __lbr_count: ::core::ffi::c_int,
     pub _bitfield_align_1: [u32; 0],
     pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
     pub __lbrs: [__last_branch_record; 32usize],
@@ -5093,21 +5093,21 @@ pub struct __last_branch_state {
 #[test]
 fn bindgen_test_layout___last_branch_state() {
     assert_eq!(
-        ::std::mem::size_of::<__last_branch_state>(),
+        ::core::mem::size_of::<__last_branch_state>(),
         776usize,
         concat!("Size of: ", stringify!(__last_branch_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__last_branch_state>(),
+        ::core::mem::align_of::<__last_branch_state>(),
         8usize,
         concat!("Alignment of ", stringify!(__last_branch_state))
     );
     fn test_field___lbr_count() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__last_branch_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__last_branch_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__lbr_count) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__lbr_count) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -5122,9 +5122,9 @@ fn bindgen_test_layout___last_branch_state() {
     fn test_field___lbrs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__last_branch_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__last_branch_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__lbrs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__lbrs) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -5140,34 +5140,34 @@ fn bindgen_test_layout___last_branch_state() {
 impl __last_branch_state {
     #[inline]
     pub fn __lbr_supported_tsx(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
     }
     #[inline]
     pub fn set___lbr_supported_tsx(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub fn __lbr_supported_cycle_count(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
     }
     #[inline]
     pub fn set___lbr_supported_cycle_count(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(1usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub fn __reserved(&self) -> __uint32_t {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 30u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 30u8) as u32) }
     }
     #[inline]
     pub fn set___reserved(&mut self, val: __uint32_t) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(2usize, 30u8, val as u64)
         }
     }
@@ -5179,16 +5179,16 @@ impl __last_branch_state {
     ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let __lbr_supported_tsx: u32 = unsafe { ::std::mem::transmute(__lbr_supported_tsx) };
+            let __lbr_supported_tsx: u32 = unsafe { ::core::mem::transmute(__lbr_supported_tsx) };
             __lbr_supported_tsx as u64
         });
         __bindgen_bitfield_unit.set(1usize, 1u8, {
             let __lbr_supported_cycle_count: u32 =
-                unsafe { ::std::mem::transmute(__lbr_supported_cycle_count) };
+                unsafe { ::core::mem::transmute(__lbr_supported_cycle_count) };
             __lbr_supported_cycle_count as u64
         });
         __bindgen_bitfield_unit.set(2usize, 30u8, {
-            let __reserved: u32 = unsafe { ::std::mem::transmute(__reserved) };
+            let __reserved: u32 = unsafe { ::core::mem::transmute(__reserved) };
             __reserved as u64
         });
         __bindgen_bitfield_unit
@@ -5197,26 +5197,26 @@ impl __last_branch_state {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __x86_pagein_state {
-    pub __pagein_error: ::std::os::raw::c_int,
+    pub __pagein_error: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout___x86_pagein_state() {
     assert_eq!(
-        ::std::mem::size_of::<__x86_pagein_state>(),
+        ::core::mem::size_of::<__x86_pagein_state>(),
         4usize,
         concat!("Size of: ", stringify!(__x86_pagein_state))
     );
     assert_eq!(
-        ::std::mem::align_of::<__x86_pagein_state>(),
+        ::core::mem::align_of::<__x86_pagein_state>(),
         4usize,
         concat!("Alignment of ", stringify!(__x86_pagein_state))
     );
     fn test_field___pagein_error() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__x86_pagein_state>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__x86_pagein_state>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__pagein_error) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__pagein_error) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -5257,21 +5257,21 @@ pub struct __darwin_x86_thread_state64 {
 #[test]
 fn bindgen_test_layout___darwin_x86_thread_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_thread_state64>(),
+        ::core::mem::size_of::<__darwin_x86_thread_state64>(),
         168usize,
         concat!("Size of: ", stringify!(__darwin_x86_thread_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_thread_state64>(),
+        ::core::mem::align_of::<__darwin_x86_thread_state64>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_x86_thread_state64))
     );
     fn test_field___rax() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rax) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rax) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -5286,9 +5286,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rbx() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rbx) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rbx) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -5303,9 +5303,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rcx() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rcx) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rcx) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -5320,9 +5320,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rdx() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rdx) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rdx) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -5337,9 +5337,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rdi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rdi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rdi) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -5354,9 +5354,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rsi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rsi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rsi) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -5371,9 +5371,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rbp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rbp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rbp) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -5388,9 +5388,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rsp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rsp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rsp) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -5405,9 +5405,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r8) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -5422,9 +5422,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r9() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r9) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r9) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -5439,9 +5439,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r10() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r10) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r10) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -5456,9 +5456,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r11() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r11) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r11) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -5473,9 +5473,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r12) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -5490,9 +5490,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r13() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r13) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r13) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -5507,9 +5507,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r14() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r14) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r14) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -5524,9 +5524,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___r15() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__r15) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__r15) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -5541,9 +5541,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rip) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -5558,9 +5558,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___rflags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__rflags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__rflags) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -5575,9 +5575,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__cs) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -5592,9 +5592,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -5609,9 +5609,9 @@ fn bindgen_test_layout___darwin_x86_thread_state64() {
     fn test_field___gs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__gs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__gs) as usize - ptr as usize
             },
             160usize,
             concat!(
@@ -5636,12 +5636,12 @@ pub struct __darwin_x86_thread_full_state64 {
 #[test]
 fn bindgen_test_layout___darwin_x86_thread_full_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_thread_full_state64>(),
+        ::core::mem::size_of::<__darwin_x86_thread_full_state64>(),
         200usize,
         concat!("Size of: ", stringify!(__darwin_x86_thread_full_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_thread_full_state64>(),
+        ::core::mem::align_of::<__darwin_x86_thread_full_state64>(),
         8usize,
         concat!(
             "Alignment of ",
@@ -5651,9 +5651,9 @@ fn bindgen_test_layout___darwin_x86_thread_full_state64() {
     fn test_field___ss64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss64) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss64) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -5668,9 +5668,9 @@ fn bindgen_test_layout___darwin_x86_thread_full_state64() {
     fn test_field___ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ds) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -5685,9 +5685,9 @@ fn bindgen_test_layout___darwin_x86_thread_full_state64() {
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             176usize,
             concat!(
@@ -5702,9 +5702,9 @@ fn bindgen_test_layout___darwin_x86_thread_full_state64() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -5719,9 +5719,9 @@ fn bindgen_test_layout___darwin_x86_thread_full_state64() {
     fn test_field___gsbase() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_thread_full_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__gsbase) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__gsbase) as usize - ptr as usize
             },
             192usize,
             concat!(
@@ -5737,7 +5737,7 @@ fn bindgen_test_layout___darwin_x86_thread_full_state64() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_x86_float_state64 {
-    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_reserved: [::core::ffi::c_int; 2usize],
     pub __fpu_fcw: __darwin_fp_control,
     pub __fpu_fsw: __darwin_fp_status,
     pub __fpu_ftw: __uint8_t,
@@ -5775,27 +5775,27 @@ pub struct __darwin_x86_float_state64 {
     pub __fpu_xmm13: __darwin_xmm_reg,
     pub __fpu_xmm14: __darwin_xmm_reg,
     pub __fpu_xmm15: __darwin_xmm_reg,
-    pub __fpu_rsrv4: [::std::os::raw::c_char; 96usize],
-    pub __fpu_reserved1: ::std::os::raw::c_int,
+    pub __fpu_rsrv4: [::core::ffi::c_char; 96usize],
+    pub __fpu_reserved1: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout___darwin_x86_float_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_float_state64>(),
+        ::core::mem::size_of::<__darwin_x86_float_state64>(),
         524usize,
         concat!("Size of: ", stringify!(__darwin_x86_float_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_float_state64>(),
+        ::core::mem::align_of::<__darwin_x86_float_state64>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_x86_float_state64))
     );
     fn test_field___fpu_reserved() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -5810,9 +5810,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_fcw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -5827,9 +5827,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_fsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
             },
             10usize,
             concat!(
@@ -5844,9 +5844,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_ftw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -5861,9 +5861,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_rsrv1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
             },
             13usize,
             concat!(
@@ -5878,9 +5878,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_fop() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
             },
             14usize,
             concat!(
@@ -5895,9 +5895,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -5912,9 +5912,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -5929,9 +5929,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_rsrv2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
             },
             22usize,
             concat!(
@@ -5946,9 +5946,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_dp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -5963,9 +5963,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -5980,9 +5980,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_rsrv3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
             },
             30usize,
             concat!(
@@ -5997,9 +5997,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_mxcsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -6014,9 +6014,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_mxcsrmask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -6031,9 +6031,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -6048,9 +6048,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -6065,9 +6065,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -6082,9 +6082,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -6099,9 +6099,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -6116,9 +6116,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -6133,9 +6133,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -6150,9 +6150,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_stmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -6167,9 +6167,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -6184,9 +6184,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -6201,9 +6201,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -6218,9 +6218,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -6235,9 +6235,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -6252,9 +6252,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -6269,9 +6269,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -6286,9 +6286,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -6303,9 +6303,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -6320,9 +6320,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm9() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
             },
             312usize,
             concat!(
@@ -6337,9 +6337,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm10() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
             },
             328usize,
             concat!(
@@ -6354,9 +6354,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm11() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
             },
             344usize,
             concat!(
@@ -6371,9 +6371,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
             },
             360usize,
             concat!(
@@ -6388,9 +6388,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm13() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
             },
             376usize,
             concat!(
@@ -6405,9 +6405,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm14() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
             },
             392usize,
             concat!(
@@ -6422,9 +6422,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_xmm15() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
             },
             408usize,
             concat!(
@@ -6439,9 +6439,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_rsrv4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
             },
             424usize,
             concat!(
@@ -6456,9 +6456,9 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
     fn test_field___fpu_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_float_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -6474,7 +6474,7 @@ fn bindgen_test_layout___darwin_x86_float_state64() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_x86_avx_state64 {
-    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_reserved: [::core::ffi::c_int; 2usize],
     pub __fpu_fcw: __darwin_fp_control,
     pub __fpu_fsw: __darwin_fp_status,
     pub __fpu_ftw: __uint8_t,
@@ -6512,9 +6512,9 @@ pub struct __darwin_x86_avx_state64 {
     pub __fpu_xmm13: __darwin_xmm_reg,
     pub __fpu_xmm14: __darwin_xmm_reg,
     pub __fpu_xmm15: __darwin_xmm_reg,
-    pub __fpu_rsrv4: [::std::os::raw::c_char; 96usize],
-    pub __fpu_reserved1: ::std::os::raw::c_int,
-    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_rsrv4: [::core::ffi::c_char; 96usize],
+    pub __fpu_reserved1: ::core::ffi::c_int,
+    pub __avx_reserved1: [::core::ffi::c_char; 64usize],
     pub __fpu_ymmh0: __darwin_xmm_reg,
     pub __fpu_ymmh1: __darwin_xmm_reg,
     pub __fpu_ymmh2: __darwin_xmm_reg,
@@ -6535,21 +6535,21 @@ pub struct __darwin_x86_avx_state64 {
 #[test]
 fn bindgen_test_layout___darwin_x86_avx_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_avx_state64>(),
+        ::core::mem::size_of::<__darwin_x86_avx_state64>(),
         844usize,
         concat!("Size of: ", stringify!(__darwin_x86_avx_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_avx_state64>(),
+        ::core::mem::align_of::<__darwin_x86_avx_state64>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_x86_avx_state64))
     );
     fn test_field___fpu_reserved() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -6564,9 +6564,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_fcw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -6581,9 +6581,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_fsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
             },
             10usize,
             concat!(
@@ -6598,9 +6598,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ftw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -6615,9 +6615,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_rsrv1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
             },
             13usize,
             concat!(
@@ -6632,9 +6632,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_fop() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
             },
             14usize,
             concat!(
@@ -6649,9 +6649,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -6666,9 +6666,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -6683,9 +6683,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_rsrv2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
             },
             22usize,
             concat!(
@@ -6700,9 +6700,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_dp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -6717,9 +6717,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -6734,9 +6734,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_rsrv3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
             },
             30usize,
             concat!(
@@ -6751,9 +6751,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_mxcsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -6768,9 +6768,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_mxcsrmask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -6785,9 +6785,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -6802,9 +6802,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -6819,9 +6819,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -6836,9 +6836,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -6853,9 +6853,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -6870,9 +6870,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -6887,9 +6887,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -6904,9 +6904,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_stmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -6921,9 +6921,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -6938,9 +6938,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -6955,9 +6955,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -6972,9 +6972,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -6989,9 +6989,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -7006,9 +7006,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -7023,9 +7023,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -7040,9 +7040,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -7057,9 +7057,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -7074,9 +7074,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm9() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
             },
             312usize,
             concat!(
@@ -7091,9 +7091,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm10() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
             },
             328usize,
             concat!(
@@ -7108,9 +7108,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm11() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
             },
             344usize,
             concat!(
@@ -7125,9 +7125,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
             },
             360usize,
             concat!(
@@ -7142,9 +7142,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm13() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
             },
             376usize,
             concat!(
@@ -7159,9 +7159,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm14() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
             },
             392usize,
             concat!(
@@ -7176,9 +7176,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_xmm15() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
             },
             408usize,
             concat!(
@@ -7193,9 +7193,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_rsrv4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
             },
             424usize,
             concat!(
@@ -7210,9 +7210,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -7227,9 +7227,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___avx_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
             },
             524usize,
             concat!(
@@ -7244,9 +7244,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
             },
             588usize,
             concat!(
@@ -7261,9 +7261,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
             },
             604usize,
             concat!(
@@ -7278,9 +7278,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
             },
             620usize,
             concat!(
@@ -7295,9 +7295,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
             },
             636usize,
             concat!(
@@ -7312,9 +7312,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
             },
             652usize,
             concat!(
@@ -7329,9 +7329,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
             },
             668usize,
             concat!(
@@ -7346,9 +7346,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
             },
             684usize,
             concat!(
@@ -7363,9 +7363,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
             },
             700usize,
             concat!(
@@ -7380,9 +7380,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh8) as usize - ptr as usize
             },
             716usize,
             concat!(
@@ -7397,9 +7397,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh9() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh9) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh9) as usize - ptr as usize
             },
             732usize,
             concat!(
@@ -7414,9 +7414,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh10() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh10) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh10) as usize - ptr as usize
             },
             748usize,
             concat!(
@@ -7431,9 +7431,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh11() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh11) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh11) as usize - ptr as usize
             },
             764usize,
             concat!(
@@ -7448,9 +7448,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh12) as usize - ptr as usize
             },
             780usize,
             concat!(
@@ -7465,9 +7465,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh13() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh13) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh13) as usize - ptr as usize
             },
             796usize,
             concat!(
@@ -7482,9 +7482,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh14() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh14) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh14) as usize - ptr as usize
             },
             812usize,
             concat!(
@@ -7499,9 +7499,9 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
     fn test_field___fpu_ymmh15() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh15) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh15) as usize - ptr as usize
             },
             828usize,
             concat!(
@@ -7517,7 +7517,7 @@ fn bindgen_test_layout___darwin_x86_avx_state64() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_x86_avx512_state64 {
-    pub __fpu_reserved: [::std::os::raw::c_int; 2usize],
+    pub __fpu_reserved: [::core::ffi::c_int; 2usize],
     pub __fpu_fcw: __darwin_fp_control,
     pub __fpu_fsw: __darwin_fp_status,
     pub __fpu_ftw: __uint8_t,
@@ -7555,9 +7555,9 @@ pub struct __darwin_x86_avx512_state64 {
     pub __fpu_xmm13: __darwin_xmm_reg,
     pub __fpu_xmm14: __darwin_xmm_reg,
     pub __fpu_xmm15: __darwin_xmm_reg,
-    pub __fpu_rsrv4: [::std::os::raw::c_char; 96usize],
-    pub __fpu_reserved1: ::std::os::raw::c_int,
-    pub __avx_reserved1: [::std::os::raw::c_char; 64usize],
+    pub __fpu_rsrv4: [::core::ffi::c_char; 96usize],
+    pub __fpu_reserved1: ::core::ffi::c_int,
+    pub __avx_reserved1: [::core::ffi::c_char; 64usize],
     pub __fpu_ymmh0: __darwin_xmm_reg,
     pub __fpu_ymmh1: __darwin_xmm_reg,
     pub __fpu_ymmh2: __darwin_xmm_reg,
@@ -7618,21 +7618,21 @@ pub struct __darwin_x86_avx512_state64 {
 #[test]
 fn bindgen_test_layout___darwin_x86_avx512_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_avx512_state64>(),
+        ::core::mem::size_of::<__darwin_x86_avx512_state64>(),
         2444usize,
         concat!("Size of: ", stringify!(__darwin_x86_avx512_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_avx512_state64>(),
+        ::core::mem::align_of::<__darwin_x86_avx512_state64>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_x86_avx512_state64))
     );
     fn test_field___fpu_reserved() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -7647,9 +7647,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_fcw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fcw) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -7664,9 +7664,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_fsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fsw) as usize - ptr as usize
             },
             10usize,
             concat!(
@@ -7681,9 +7681,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ftw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ftw) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -7698,9 +7698,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_rsrv1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv1) as usize - ptr as usize
             },
             13usize,
             concat!(
@@ -7715,9 +7715,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_fop() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_fop) as usize - ptr as usize
             },
             14usize,
             concat!(
@@ -7732,9 +7732,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ip() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ip) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -7749,9 +7749,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_cs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_cs) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -7766,9 +7766,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_rsrv2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv2) as usize - ptr as usize
             },
             22usize,
             concat!(
@@ -7783,9 +7783,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_dp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_dp) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -7800,9 +7800,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ds() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ds) as usize - ptr as usize
             },
             28usize,
             concat!(
@@ -7817,9 +7817,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_rsrv3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv3) as usize - ptr as usize
             },
             30usize,
             concat!(
@@ -7834,9 +7834,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_mxcsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsr) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -7851,9 +7851,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_mxcsrmask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_mxcsrmask) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -7868,9 +7868,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm0) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -7885,9 +7885,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm1) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -7902,9 +7902,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm2) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -7919,9 +7919,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm3) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -7936,9 +7936,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm4) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -7953,9 +7953,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm5) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -7970,9 +7970,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm6) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -7987,9 +7987,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_stmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_stmm7) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -8004,9 +8004,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm0) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -8021,9 +8021,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm1) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -8038,9 +8038,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm2) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -8055,9 +8055,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm3) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -8072,9 +8072,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm4) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -8089,9 +8089,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm5) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -8106,9 +8106,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm6) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -8123,9 +8123,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm7) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -8140,9 +8140,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm8) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -8157,9 +8157,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm9() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm9) as usize - ptr as usize
             },
             312usize,
             concat!(
@@ -8174,9 +8174,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm10() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm10) as usize - ptr as usize
             },
             328usize,
             concat!(
@@ -8191,9 +8191,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm11() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm11) as usize - ptr as usize
             },
             344usize,
             concat!(
@@ -8208,9 +8208,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm12) as usize - ptr as usize
             },
             360usize,
             concat!(
@@ -8225,9 +8225,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm13() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm13) as usize - ptr as usize
             },
             376usize,
             concat!(
@@ -8242,9 +8242,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm14() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm14) as usize - ptr as usize
             },
             392usize,
             concat!(
@@ -8259,9 +8259,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_xmm15() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_xmm15) as usize - ptr as usize
             },
             408usize,
             concat!(
@@ -8276,9 +8276,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_rsrv4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_rsrv4) as usize - ptr as usize
             },
             424usize,
             concat!(
@@ -8293,9 +8293,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_reserved1) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -8310,9 +8310,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___avx_reserved1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__avx_reserved1) as usize - ptr as usize
             },
             524usize,
             concat!(
@@ -8327,9 +8327,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh0) as usize - ptr as usize
             },
             588usize,
             concat!(
@@ -8344,9 +8344,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh1) as usize - ptr as usize
             },
             604usize,
             concat!(
@@ -8361,9 +8361,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh2) as usize - ptr as usize
             },
             620usize,
             concat!(
@@ -8378,9 +8378,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh3) as usize - ptr as usize
             },
             636usize,
             concat!(
@@ -8395,9 +8395,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh4) as usize - ptr as usize
             },
             652usize,
             concat!(
@@ -8412,9 +8412,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh5) as usize - ptr as usize
             },
             668usize,
             concat!(
@@ -8429,9 +8429,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh6) as usize - ptr as usize
             },
             684usize,
             concat!(
@@ -8446,9 +8446,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh7) as usize - ptr as usize
             },
             700usize,
             concat!(
@@ -8463,9 +8463,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh8) as usize - ptr as usize
             },
             716usize,
             concat!(
@@ -8480,9 +8480,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh9() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh9) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh9) as usize - ptr as usize
             },
             732usize,
             concat!(
@@ -8497,9 +8497,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh10() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh10) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh10) as usize - ptr as usize
             },
             748usize,
             concat!(
@@ -8514,9 +8514,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh11() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh11) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh11) as usize - ptr as usize
             },
             764usize,
             concat!(
@@ -8531,9 +8531,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh12) as usize - ptr as usize
             },
             780usize,
             concat!(
@@ -8548,9 +8548,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh13() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh13) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh13) as usize - ptr as usize
             },
             796usize,
             concat!(
@@ -8565,9 +8565,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh14() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh14) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh14) as usize - ptr as usize
             },
             812usize,
             concat!(
@@ -8582,9 +8582,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_ymmh15() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_ymmh15) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_ymmh15) as usize - ptr as usize
             },
             828usize,
             concat!(
@@ -8599,9 +8599,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k0) as usize - ptr as usize
             },
             844usize,
             concat!(
@@ -8616,9 +8616,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k1) as usize - ptr as usize
             },
             852usize,
             concat!(
@@ -8633,9 +8633,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k2) as usize - ptr as usize
             },
             860usize,
             concat!(
@@ -8650,9 +8650,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k3) as usize - ptr as usize
             },
             868usize,
             concat!(
@@ -8667,9 +8667,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k4) as usize - ptr as usize
             },
             876usize,
             concat!(
@@ -8684,9 +8684,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k5) as usize - ptr as usize
             },
             884usize,
             concat!(
@@ -8701,9 +8701,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k6) as usize - ptr as usize
             },
             892usize,
             concat!(
@@ -8718,9 +8718,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_k7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_k7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_k7) as usize - ptr as usize
             },
             900usize,
             concat!(
@@ -8735,9 +8735,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh0) as usize - ptr as usize
             },
             908usize,
             concat!(
@@ -8752,9 +8752,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh1) as usize - ptr as usize
             },
             940usize,
             concat!(
@@ -8769,9 +8769,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh2) as usize - ptr as usize
             },
             972usize,
             concat!(
@@ -8786,9 +8786,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh3) as usize - ptr as usize
             },
             1004usize,
             concat!(
@@ -8803,9 +8803,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh4) as usize - ptr as usize
             },
             1036usize,
             concat!(
@@ -8820,9 +8820,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh5) as usize - ptr as usize
             },
             1068usize,
             concat!(
@@ -8837,9 +8837,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh6) as usize - ptr as usize
             },
             1100usize,
             concat!(
@@ -8854,9 +8854,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh7) as usize - ptr as usize
             },
             1132usize,
             concat!(
@@ -8871,9 +8871,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh8() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh8) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh8) as usize - ptr as usize
             },
             1164usize,
             concat!(
@@ -8888,9 +8888,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh9() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh9) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh9) as usize - ptr as usize
             },
             1196usize,
             concat!(
@@ -8905,9 +8905,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh10() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh10) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh10) as usize - ptr as usize
             },
             1228usize,
             concat!(
@@ -8922,9 +8922,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh11() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh11) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh11) as usize - ptr as usize
             },
             1260usize,
             concat!(
@@ -8939,9 +8939,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh12) as usize - ptr as usize
             },
             1292usize,
             concat!(
@@ -8956,9 +8956,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh13() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh13) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh13) as usize - ptr as usize
             },
             1324usize,
             concat!(
@@ -8973,9 +8973,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh14() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh14) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh14) as usize - ptr as usize
             },
             1356usize,
             concat!(
@@ -8990,9 +8990,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmmh15() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmmh15) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmmh15) as usize - ptr as usize
             },
             1388usize,
             concat!(
@@ -9007,9 +9007,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm16() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm16) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm16) as usize - ptr as usize
             },
             1420usize,
             concat!(
@@ -9024,9 +9024,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm17() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm17) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm17) as usize - ptr as usize
             },
             1484usize,
             concat!(
@@ -9041,9 +9041,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm18() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm18) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm18) as usize - ptr as usize
             },
             1548usize,
             concat!(
@@ -9058,9 +9058,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm19() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm19) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm19) as usize - ptr as usize
             },
             1612usize,
             concat!(
@@ -9075,9 +9075,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm20() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm20) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm20) as usize - ptr as usize
             },
             1676usize,
             concat!(
@@ -9092,9 +9092,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm21() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm21) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm21) as usize - ptr as usize
             },
             1740usize,
             concat!(
@@ -9109,9 +9109,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm22() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm22) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm22) as usize - ptr as usize
             },
             1804usize,
             concat!(
@@ -9126,9 +9126,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm23() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm23) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm23) as usize - ptr as usize
             },
             1868usize,
             concat!(
@@ -9143,9 +9143,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm24() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm24) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm24) as usize - ptr as usize
             },
             1932usize,
             concat!(
@@ -9160,9 +9160,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm25() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm25) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm25) as usize - ptr as usize
             },
             1996usize,
             concat!(
@@ -9177,9 +9177,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm26() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm26) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm26) as usize - ptr as usize
             },
             2060usize,
             concat!(
@@ -9194,9 +9194,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm27() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm27) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm27) as usize - ptr as usize
             },
             2124usize,
             concat!(
@@ -9211,9 +9211,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm28() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm28) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm28) as usize - ptr as usize
             },
             2188usize,
             concat!(
@@ -9228,9 +9228,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm29() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm29) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm29) as usize - ptr as usize
             },
             2252usize,
             concat!(
@@ -9245,9 +9245,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm30() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm30) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm30) as usize - ptr as usize
             },
             2316usize,
             concat!(
@@ -9262,9 +9262,9 @@ fn bindgen_test_layout___darwin_x86_avx512_state64() {
     fn test_field___fpu_zmm31() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_avx512_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fpu_zmm31) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fpu_zmm31) as usize - ptr as usize
             },
             2380usize,
             concat!(
@@ -9288,21 +9288,21 @@ pub struct __darwin_x86_exception_state64 {
 #[test]
 fn bindgen_test_layout___darwin_x86_exception_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_exception_state64>(),
+        ::core::mem::size_of::<__darwin_x86_exception_state64>(),
         16usize,
         concat!("Size of: ", stringify!(__darwin_x86_exception_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_exception_state64>(),
+        ::core::mem::align_of::<__darwin_x86_exception_state64>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_x86_exception_state64))
     );
     fn test_field___trapno() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__trapno) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__trapno) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9317,9 +9317,9 @@ fn bindgen_test_layout___darwin_x86_exception_state64() {
     fn test_field___cpu() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__cpu) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__cpu) as usize - ptr as usize
             },
             2usize,
             concat!(
@@ -9334,9 +9334,9 @@ fn bindgen_test_layout___darwin_x86_exception_state64() {
     fn test_field___err() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__err) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__err) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -9351,9 +9351,9 @@ fn bindgen_test_layout___darwin_x86_exception_state64() {
     fn test_field___faultvaddr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_exception_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__faultvaddr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__faultvaddr) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -9381,21 +9381,21 @@ pub struct __darwin_x86_debug_state64 {
 #[test]
 fn bindgen_test_layout___darwin_x86_debug_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_debug_state64>(),
+        ::core::mem::size_of::<__darwin_x86_debug_state64>(),
         64usize,
         concat!("Size of: ", stringify!(__darwin_x86_debug_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_debug_state64>(),
+        ::core::mem::align_of::<__darwin_x86_debug_state64>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_x86_debug_state64))
     );
     fn test_field___dr0() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr0) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr0) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9410,9 +9410,9 @@ fn bindgen_test_layout___darwin_x86_debug_state64() {
     fn test_field___dr1() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr1) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr1) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -9427,9 +9427,9 @@ fn bindgen_test_layout___darwin_x86_debug_state64() {
     fn test_field___dr2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr2) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -9444,9 +9444,9 @@ fn bindgen_test_layout___darwin_x86_debug_state64() {
     fn test_field___dr3() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr3) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr3) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -9461,9 +9461,9 @@ fn bindgen_test_layout___darwin_x86_debug_state64() {
     fn test_field___dr4() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr4) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr4) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -9478,9 +9478,9 @@ fn bindgen_test_layout___darwin_x86_debug_state64() {
     fn test_field___dr5() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr5) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr5) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -9495,9 +9495,9 @@ fn bindgen_test_layout___darwin_x86_debug_state64() {
     fn test_field___dr6() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr6) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr6) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -9512,9 +9512,9 @@ fn bindgen_test_layout___darwin_x86_debug_state64() {
     fn test_field___dr7() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_debug_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__dr7) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__dr7) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -9535,21 +9535,21 @@ pub struct __darwin_x86_cpmu_state64 {
 #[test]
 fn bindgen_test_layout___darwin_x86_cpmu_state64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_x86_cpmu_state64>(),
+        ::core::mem::size_of::<__darwin_x86_cpmu_state64>(),
         128usize,
         concat!("Size of: ", stringify!(__darwin_x86_cpmu_state64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_x86_cpmu_state64>(),
+        ::core::mem::align_of::<__darwin_x86_cpmu_state64>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_x86_cpmu_state64))
     );
     fn test_field___ctrs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_x86_cpmu_state64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_x86_cpmu_state64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ctrs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ctrs) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9572,21 +9572,21 @@ pub struct __darwin_mcontext32 {
 #[test]
 fn bindgen_test_layout___darwin_mcontext32() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext32>(),
+        ::core::mem::size_of::<__darwin_mcontext32>(),
         600usize,
         concat!("Size of: ", stringify!(__darwin_mcontext32))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext32>(),
+        ::core::mem::align_of::<__darwin_mcontext32>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext32))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9601,9 +9601,9 @@ fn bindgen_test_layout___darwin_mcontext32() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -9618,9 +9618,9 @@ fn bindgen_test_layout___darwin_mcontext32() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             76usize,
             concat!(
@@ -9643,21 +9643,21 @@ pub struct __darwin_mcontext_avx32 {
 #[test]
 fn bindgen_test_layout___darwin_mcontext_avx32() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext_avx32>(),
+        ::core::mem::size_of::<__darwin_mcontext_avx32>(),
         792usize,
         concat!("Size of: ", stringify!(__darwin_mcontext_avx32))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext_avx32>(),
+        ::core::mem::align_of::<__darwin_mcontext_avx32>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext_avx32))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9672,9 +9672,9 @@ fn bindgen_test_layout___darwin_mcontext_avx32() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -9689,9 +9689,9 @@ fn bindgen_test_layout___darwin_mcontext_avx32() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             76usize,
             concat!(
@@ -9714,21 +9714,21 @@ pub struct __darwin_mcontext_avx512_32 {
 #[test]
 fn bindgen_test_layout___darwin_mcontext_avx512_32() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext_avx512_32>(),
+        ::core::mem::size_of::<__darwin_mcontext_avx512_32>(),
         1112usize,
         concat!("Size of: ", stringify!(__darwin_mcontext_avx512_32))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext_avx512_32>(),
+        ::core::mem::align_of::<__darwin_mcontext_avx512_32>(),
         4usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext_avx512_32))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9743,9 +9743,9 @@ fn bindgen_test_layout___darwin_mcontext_avx512_32() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -9760,9 +9760,9 @@ fn bindgen_test_layout___darwin_mcontext_avx512_32() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_32>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             76usize,
             concat!(
@@ -9785,21 +9785,21 @@ pub struct __darwin_mcontext64 {
 #[test]
 fn bindgen_test_layout___darwin_mcontext64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext64>(),
+        ::core::mem::size_of::<__darwin_mcontext64>(),
         712usize,
         concat!("Size of: ", stringify!(__darwin_mcontext64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext64>(),
+        ::core::mem::align_of::<__darwin_mcontext64>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext64))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9814,9 +9814,9 @@ fn bindgen_test_layout___darwin_mcontext64() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -9831,9 +9831,9 @@ fn bindgen_test_layout___darwin_mcontext64() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -9856,21 +9856,21 @@ pub struct __darwin_mcontext64_full {
 #[test]
 fn bindgen_test_layout___darwin_mcontext64_full() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext64_full>(),
+        ::core::mem::size_of::<__darwin_mcontext64_full>(),
         744usize,
         concat!("Size of: ", stringify!(__darwin_mcontext64_full))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext64_full>(),
+        ::core::mem::align_of::<__darwin_mcontext64_full>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext64_full))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9885,9 +9885,9 @@ fn bindgen_test_layout___darwin_mcontext64_full() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -9902,9 +9902,9 @@ fn bindgen_test_layout___darwin_mcontext64_full() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -9927,21 +9927,21 @@ pub struct __darwin_mcontext_avx64 {
 #[test]
 fn bindgen_test_layout___darwin_mcontext_avx64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext_avx64>(),
+        ::core::mem::size_of::<__darwin_mcontext_avx64>(),
         1032usize,
         concat!("Size of: ", stringify!(__darwin_mcontext_avx64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext_avx64>(),
+        ::core::mem::align_of::<__darwin_mcontext_avx64>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext_avx64))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -9956,9 +9956,9 @@ fn bindgen_test_layout___darwin_mcontext_avx64() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -9973,9 +9973,9 @@ fn bindgen_test_layout___darwin_mcontext_avx64() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -9998,21 +9998,21 @@ pub struct __darwin_mcontext_avx64_full {
 #[test]
 fn bindgen_test_layout___darwin_mcontext_avx64_full() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext_avx64_full>(),
+        ::core::mem::size_of::<__darwin_mcontext_avx64_full>(),
         1064usize,
         concat!("Size of: ", stringify!(__darwin_mcontext_avx64_full))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext_avx64_full>(),
+        ::core::mem::align_of::<__darwin_mcontext_avx64_full>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext_avx64_full))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10027,9 +10027,9 @@ fn bindgen_test_layout___darwin_mcontext_avx64_full() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10044,9 +10044,9 @@ fn bindgen_test_layout___darwin_mcontext_avx64_full() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -10069,21 +10069,21 @@ pub struct __darwin_mcontext_avx512_64 {
 #[test]
 fn bindgen_test_layout___darwin_mcontext_avx512_64() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext_avx512_64>(),
+        ::core::mem::size_of::<__darwin_mcontext_avx512_64>(),
         2632usize,
         concat!("Size of: ", stringify!(__darwin_mcontext_avx512_64))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext_avx512_64>(),
+        ::core::mem::align_of::<__darwin_mcontext_avx512_64>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_mcontext_avx512_64))
     );
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10098,9 +10098,9 @@ fn bindgen_test_layout___darwin_mcontext_avx512_64() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10115,9 +10115,9 @@ fn bindgen_test_layout___darwin_mcontext_avx512_64() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_64>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -10140,12 +10140,12 @@ pub struct __darwin_mcontext_avx512_64_full {
 #[test]
 fn bindgen_test_layout___darwin_mcontext_avx512_64_full() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_mcontext_avx512_64_full>(),
+        ::core::mem::size_of::<__darwin_mcontext_avx512_64_full>(),
         2664usize,
         concat!("Size of: ", stringify!(__darwin_mcontext_avx512_64_full))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_mcontext_avx512_64_full>(),
+        ::core::mem::align_of::<__darwin_mcontext_avx512_64_full>(),
         8usize,
         concat!(
             "Alignment of ",
@@ -10155,9 +10155,9 @@ fn bindgen_test_layout___darwin_mcontext_avx512_64_full() {
     fn test_field___es() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__es) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10172,9 +10172,9 @@ fn bindgen_test_layout___darwin_mcontext_avx512_64_full() {
     fn test_field___ss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__ss) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10189,9 +10189,9 @@ fn bindgen_test_layout___darwin_mcontext_avx512_64_full() {
     fn test_field___fs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_mcontext_avx512_64_full>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__fs) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -10209,28 +10209,28 @@ pub type pthread_attr_t = __darwin_pthread_attr_t;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_sigaltstack {
-    pub ss_sp: *mut ::std::os::raw::c_void,
+    pub ss_sp: *mut ::core::ffi::c_void,
     pub ss_size: __darwin_size_t,
-    pub ss_flags: ::std::os::raw::c_int,
+    pub ss_flags: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout___darwin_sigaltstack() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_sigaltstack>(),
+        ::core::mem::size_of::<__darwin_sigaltstack>(),
         24usize,
         concat!("Size of: ", stringify!(__darwin_sigaltstack))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_sigaltstack>(),
+        ::core::mem::align_of::<__darwin_sigaltstack>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_sigaltstack))
     );
     fn test_field_ss_sp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ss_sp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ss_sp) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10245,9 +10245,9 @@ fn bindgen_test_layout___darwin_sigaltstack() {
     fn test_field_ss_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ss_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ss_size) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10262,9 +10262,9 @@ fn bindgen_test_layout___darwin_sigaltstack() {
     fn test_field_ss_flags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_sigaltstack>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ss_flags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ss_flags) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10281,7 +10281,7 @@ pub type stack_t = __darwin_sigaltstack;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __darwin_ucontext {
-    pub uc_onstack: ::std::os::raw::c_int,
+    pub uc_onstack: ::core::ffi::c_int,
     pub uc_sigmask: __darwin_sigset_t,
     pub uc_stack: __darwin_sigaltstack,
     pub uc_link: *mut __darwin_ucontext,
@@ -10291,21 +10291,21 @@ pub struct __darwin_ucontext {
 #[test]
 fn bindgen_test_layout___darwin_ucontext() {
     assert_eq!(
-        ::std::mem::size_of::<__darwin_ucontext>(),
+        ::core::mem::size_of::<__darwin_ucontext>(),
         56usize,
         concat!("Size of: ", stringify!(__darwin_ucontext))
     );
     assert_eq!(
-        ::std::mem::align_of::<__darwin_ucontext>(),
+        ::core::mem::align_of::<__darwin_ucontext>(),
         8usize,
         concat!("Alignment of ", stringify!(__darwin_ucontext))
     );
     fn test_field_uc_onstack() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_ucontext>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).uc_onstack) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).uc_onstack) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10320,9 +10320,9 @@ fn bindgen_test_layout___darwin_ucontext() {
     fn test_field_uc_sigmask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_ucontext>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).uc_sigmask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).uc_sigmask) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -10337,9 +10337,9 @@ fn bindgen_test_layout___darwin_ucontext() {
     fn test_field_uc_stack() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_ucontext>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).uc_stack) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).uc_stack) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10354,9 +10354,9 @@ fn bindgen_test_layout___darwin_ucontext() {
     fn test_field_uc_link() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_ucontext>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).uc_link) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).uc_link) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -10371,9 +10371,9 @@ fn bindgen_test_layout___darwin_ucontext() {
     fn test_field_uc_mcsize() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_ucontext>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).uc_mcsize) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).uc_mcsize) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -10388,9 +10388,9 @@ fn bindgen_test_layout___darwin_ucontext() {
     fn test_field_uc_mcontext() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__darwin_ucontext>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__darwin_ucontext>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).uc_mcontext) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).uc_mcontext) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -10409,27 +10409,27 @@ pub type uid_t = __darwin_uid_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union sigval {
-    pub sival_int: ::std::os::raw::c_int,
-    pub sival_ptr: *mut ::std::os::raw::c_void,
+    pub sival_int: ::core::ffi::c_int,
+    pub sival_ptr: *mut ::core::ffi::c_void,
 }
 #[test]
 fn bindgen_test_layout_sigval() {
     assert_eq!(
-        ::std::mem::size_of::<sigval>(),
+        ::core::mem::size_of::<sigval>(),
         8usize,
         concat!("Size of: ", stringify!(sigval))
     );
     assert_eq!(
-        ::std::mem::align_of::<sigval>(),
+        ::core::mem::align_of::<sigval>(),
         8usize,
         concat!("Alignment of ", stringify!(sigval))
     );
     fn test_field_sival_int() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigval>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigval>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sival_int) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sival_int) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10444,9 +10444,9 @@ fn bindgen_test_layout_sigval() {
     fn test_field_sival_ptr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigval>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigval>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sival_ptr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sival_ptr) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10462,30 +10462,30 @@ fn bindgen_test_layout_sigval() {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct sigevent {
-    pub sigev_notify: ::std::os::raw::c_int,
-    pub sigev_signo: ::std::os::raw::c_int,
+    pub sigev_notify: ::core::ffi::c_int,
+    pub sigev_signo: ::core::ffi::c_int,
     pub sigev_value: sigval,
-    pub sigev_notify_function: ::std::option::Option<unsafe extern "C" fn(arg1: sigval)>,
+    pub sigev_notify_function: ::core::option::Option<unsafe extern "C" fn(arg1: sigval)>,
     pub sigev_notify_attributes: *mut pthread_attr_t,
 }
 #[test]
 fn bindgen_test_layout_sigevent() {
     assert_eq!(
-        ::std::mem::size_of::<sigevent>(),
+        ::core::mem::size_of::<sigevent>(),
         32usize,
         concat!("Size of: ", stringify!(sigevent))
     );
     assert_eq!(
-        ::std::mem::align_of::<sigevent>(),
+        ::core::mem::align_of::<sigevent>(),
         8usize,
         concat!("Alignment of ", stringify!(sigevent))
     );
     fn test_field_sigev_notify() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigevent>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sigev_notify) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sigev_notify) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10500,9 +10500,9 @@ fn bindgen_test_layout_sigevent() {
     fn test_field_sigev_signo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigevent>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sigev_signo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sigev_signo) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -10517,9 +10517,9 @@ fn bindgen_test_layout_sigevent() {
     fn test_field_sigev_value() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigevent>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sigev_value) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sigev_value) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10534,9 +10534,9 @@ fn bindgen_test_layout_sigevent() {
     fn test_field_sigev_notify_function() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigevent>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sigev_notify_function) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sigev_notify_function) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10551,9 +10551,9 @@ fn bindgen_test_layout_sigevent() {
     fn test_field_sigev_notify_attributes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigevent>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigevent>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sigev_notify_attributes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sigev_notify_attributes) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -10569,35 +10569,35 @@ fn bindgen_test_layout_sigevent() {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct __siginfo {
-    pub si_signo: ::std::os::raw::c_int,
-    pub si_errno: ::std::os::raw::c_int,
-    pub si_code: ::std::os::raw::c_int,
+    pub si_signo: ::core::ffi::c_int,
+    pub si_errno: ::core::ffi::c_int,
+    pub si_code: ::core::ffi::c_int,
     pub si_pid: pid_t,
     pub si_uid: uid_t,
-    pub si_status: ::std::os::raw::c_int,
-    pub si_addr: *mut ::std::os::raw::c_void,
+    pub si_status: ::core::ffi::c_int,
+    pub si_addr: *mut ::core::ffi::c_void,
     pub si_value: sigval,
-    pub si_band: ::std::os::raw::c_long,
-    pub __pad: [::std::os::raw::c_ulong; 7usize],
+    pub si_band: ::core::ffi::c_long,
+    pub __pad: [::core::ffi::c_ulong; 7usize],
 }
 #[test]
 fn bindgen_test_layout___siginfo() {
     assert_eq!(
-        ::std::mem::size_of::<__siginfo>(),
+        ::core::mem::size_of::<__siginfo>(),
         104usize,
         concat!("Size of: ", stringify!(__siginfo))
     );
     assert_eq!(
-        ::std::mem::align_of::<__siginfo>(),
+        ::core::mem::align_of::<__siginfo>(),
         8usize,
         concat!("Alignment of ", stringify!(__siginfo))
     );
     fn test_field_si_signo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_signo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_signo) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10612,9 +10612,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_errno() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_errno) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_errno) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -10629,9 +10629,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_code() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_code) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_code) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10646,9 +10646,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_pid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_pid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_pid) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -10663,9 +10663,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_uid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_uid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_uid) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10680,9 +10680,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_status() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_status) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_status) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -10697,9 +10697,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_addr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_addr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_addr) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -10714,9 +10714,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_value() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_value) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_value) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -10731,9 +10731,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field_si_band() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).si_band) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).si_band) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -10748,9 +10748,9 @@ fn bindgen_test_layout___siginfo() {
     fn test_field___pad() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__siginfo>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__siginfo>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__pad) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__pad) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -10767,33 +10767,33 @@ pub type siginfo_t = __siginfo;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union __sigaction_u {
-    pub __sa_handler: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
-    pub __sa_sigaction: ::std::option::Option<
+    pub __sa_handler: ::core::option::Option<unsafe extern "C" fn(arg1: ::core::ffi::c_int)>,
+    pub __sa_sigaction: ::core::option::Option<
         unsafe extern "C" fn(
-            arg1: ::std::os::raw::c_int,
+            arg1: ::core::ffi::c_int,
             arg2: *mut __siginfo,
-            arg3: *mut ::std::os::raw::c_void,
+            arg3: *mut ::core::ffi::c_void,
         ),
     >,
 }
 #[test]
 fn bindgen_test_layout___sigaction_u() {
     assert_eq!(
-        ::std::mem::size_of::<__sigaction_u>(),
+        ::core::mem::size_of::<__sigaction_u>(),
         8usize,
         concat!("Size of: ", stringify!(__sigaction_u))
     );
     assert_eq!(
-        ::std::mem::align_of::<__sigaction_u>(),
+        ::core::mem::align_of::<__sigaction_u>(),
         8usize,
         concat!("Alignment of ", stringify!(__sigaction_u))
     );
     fn test_field___sa_handler() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__sigaction_u>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__sigaction_u>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sa_handler) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sa_handler) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10808,9 +10808,9 @@ fn bindgen_test_layout___sigaction_u() {
     fn test_field___sa_sigaction() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__sigaction_u>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__sigaction_u>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sa_sigaction) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sa_sigaction) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10827,36 +10827,36 @@ fn bindgen_test_layout___sigaction_u() {
 #[derive(Copy, Clone)]
 pub struct __sigaction {
     pub __sigaction_u: __sigaction_u,
-    pub sa_tramp: ::std::option::Option<
+    pub sa_tramp: ::core::option::Option<
         unsafe extern "C" fn(
-            arg1: *mut ::std::os::raw::c_void,
-            arg2: ::std::os::raw::c_int,
-            arg3: ::std::os::raw::c_int,
+            arg1: *mut ::core::ffi::c_void,
+            arg2: ::core::ffi::c_int,
+            arg3: ::core::ffi::c_int,
             arg4: *mut siginfo_t,
-            arg5: *mut ::std::os::raw::c_void,
+            arg5: *mut ::core::ffi::c_void,
         ),
     >,
     pub sa_mask: sigset_t,
-    pub sa_flags: ::std::os::raw::c_int,
+    pub sa_flags: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout___sigaction() {
     assert_eq!(
-        ::std::mem::size_of::<__sigaction>(),
+        ::core::mem::size_of::<__sigaction>(),
         24usize,
         concat!("Size of: ", stringify!(__sigaction))
     );
     assert_eq!(
-        ::std::mem::align_of::<__sigaction>(),
+        ::core::mem::align_of::<__sigaction>(),
         8usize,
         concat!("Alignment of ", stringify!(__sigaction))
     );
     fn test_field___sigaction_u() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__sigaction>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sigaction_u) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sigaction_u) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10871,9 +10871,9 @@ fn bindgen_test_layout___sigaction() {
     fn test_field_sa_tramp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__sigaction>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sa_tramp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sa_tramp) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10888,9 +10888,9 @@ fn bindgen_test_layout___sigaction() {
     fn test_field_sa_mask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__sigaction>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sa_mask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sa_mask) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10905,9 +10905,9 @@ fn bindgen_test_layout___sigaction() {
     fn test_field_sa_flags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__sigaction>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__sigaction>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sa_flags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sa_flags) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -10925,26 +10925,26 @@ fn bindgen_test_layout___sigaction() {
 pub struct sigaction {
     pub __sigaction_u: __sigaction_u,
     pub sa_mask: sigset_t,
-    pub sa_flags: ::std::os::raw::c_int,
+    pub sa_flags: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_sigaction() {
     assert_eq!(
-        ::std::mem::size_of::<sigaction>(),
+        ::core::mem::size_of::<sigaction>(),
         16usize,
         concat!("Size of: ", stringify!(sigaction))
     );
     assert_eq!(
-        ::std::mem::align_of::<sigaction>(),
+        ::core::mem::align_of::<sigaction>(),
         8usize,
         concat!("Alignment of ", stringify!(sigaction))
     );
     fn test_field___sigaction_u() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigaction>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigaction>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__sigaction_u) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__sigaction_u) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10959,9 +10959,9 @@ fn bindgen_test_layout_sigaction() {
     fn test_field_sa_mask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigaction>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigaction>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sa_mask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sa_mask) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10976,9 +10976,9 @@ fn bindgen_test_layout_sigaction() {
     fn test_field_sa_flags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigaction>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigaction>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sa_flags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sa_flags) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -10991,32 +10991,32 @@ fn bindgen_test_layout_sigaction() {
     }
     test_field_sa_flags();
 }
-pub type sig_t = ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>;
+pub type sig_t = ::core::option::Option<unsafe extern "C" fn(arg1: ::core::ffi::c_int)>;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct sigvec {
-    pub sv_handler: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
-    pub sv_mask: ::std::os::raw::c_int,
-    pub sv_flags: ::std::os::raw::c_int,
+    pub sv_handler: ::core::option::Option<unsafe extern "C" fn(arg1: ::core::ffi::c_int)>,
+    pub sv_mask: ::core::ffi::c_int,
+    pub sv_flags: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_sigvec() {
     assert_eq!(
-        ::std::mem::size_of::<sigvec>(),
+        ::core::mem::size_of::<sigvec>(),
         16usize,
         concat!("Size of: ", stringify!(sigvec))
     );
     assert_eq!(
-        ::std::mem::align_of::<sigvec>(),
+        ::core::mem::align_of::<sigvec>(),
         8usize,
         concat!("Alignment of ", stringify!(sigvec))
     );
     fn test_field_sv_handler() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigvec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigvec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sv_handler) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sv_handler) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -11031,9 +11031,9 @@ fn bindgen_test_layout_sigvec() {
     fn test_field_sv_mask() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigvec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigvec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sv_mask) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sv_mask) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -11048,9 +11048,9 @@ fn bindgen_test_layout_sigvec() {
     fn test_field_sv_flags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigvec>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigvec>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).sv_flags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).sv_flags) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -11066,27 +11066,27 @@ fn bindgen_test_layout_sigvec() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct sigstack {
-    pub ss_sp: *mut ::std::os::raw::c_char,
-    pub ss_onstack: ::std::os::raw::c_int,
+    pub ss_sp: *mut ::core::ffi::c_char,
+    pub ss_onstack: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_sigstack() {
     assert_eq!(
-        ::std::mem::size_of::<sigstack>(),
+        ::core::mem::size_of::<sigstack>(),
         16usize,
         concat!("Size of: ", stringify!(sigstack))
     );
     assert_eq!(
-        ::std::mem::align_of::<sigstack>(),
+        ::core::mem::align_of::<sigstack>(),
         8usize,
         concat!("Alignment of ", stringify!(sigstack))
     );
     fn test_field_ss_sp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigstack>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigstack>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ss_sp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ss_sp) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -11101,9 +11101,9 @@ fn bindgen_test_layout_sigstack() {
     fn test_field_ss_onstack() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<sigstack>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<sigstack>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ss_onstack) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ss_onstack) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -11118,12 +11118,12 @@ fn bindgen_test_layout_sigstack() {
 }
 extern "C" {
     pub fn signal(
-        arg1: ::std::os::raw::c_int,
-        arg2: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
-    ) -> ::std::option::Option<
+        arg1: ::core::ffi::c_int,
+        arg2: ::core::option::Option<unsafe extern "C" fn(arg1: ::core::ffi::c_int)>,
+    ) -> ::core::option::Option<
         unsafe extern "C" fn(
-            arg1: ::std::os::raw::c_int,
-            arg2: ::std::option::Option<unsafe extern "C" fn(arg1: ::std::os::raw::c_int)>,
+            arg1: ::core::ffi::c_int,
+            arg2: ::core::option::Option<unsafe extern "C" fn(arg1: ::core::ffi::c_int)>,
         ),
     >;
 }
@@ -11143,8 +11143,8 @@ pub type uint_fast8_t = u8;
 pub type uint_fast16_t = u16;
 pub type uint_fast32_t = u32;
 pub type uint_fast64_t = u64;
-pub type intmax_t = ::std::os::raw::c_long;
-pub type uintmax_t = ::std::os::raw::c_ulong;
+pub type intmax_t = ::core::ffi::c_long;
+pub type uintmax_t = ::core::ffi::c_ulong;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct timeval {
@@ -11154,21 +11154,21 @@ pub struct timeval {
 #[test]
 fn bindgen_test_layout_timeval() {
     assert_eq!(
-        ::std::mem::size_of::<timeval>(),
+        ::core::mem::size_of::<timeval>(),
         16usize,
         concat!("Size of: ", stringify!(timeval))
     );
     assert_eq!(
-        ::std::mem::align_of::<timeval>(),
+        ::core::mem::align_of::<timeval>(),
         8usize,
         concat!("Alignment of ", stringify!(timeval))
     );
     fn test_field_tv_sec() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<timeval>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<timeval>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tv_sec) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tv_sec) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -11183,9 +11183,9 @@ fn bindgen_test_layout_timeval() {
     fn test_field_tv_usec() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<timeval>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<timeval>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tv_usec) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tv_usec) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -11204,39 +11204,39 @@ pub type rlim_t = __uint64_t;
 pub struct rusage {
     pub ru_utime: timeval,
     pub ru_stime: timeval,
-    pub ru_maxrss: ::std::os::raw::c_long,
-    pub ru_ixrss: ::std::os::raw::c_long,
-    pub ru_idrss: ::std::os::raw::c_long,
-    pub ru_isrss: ::std::os::raw::c_long,
-    pub ru_minflt: ::std::os::raw::c_long,
-    pub ru_majflt: ::std::os::raw::c_long,
-    pub ru_nswap: ::std::os::raw::c_long,
-    pub ru_inblock: ::std::os::raw::c_long,
-    pub ru_oublock: ::std::os::raw::c_long,
-    pub ru_msgsnd: ::std::os::raw::c_long,
-    pub ru_msgrcv: ::std::os::raw::c_long,
-    pub ru_nsignals: ::std::os::raw::c_long,
-    pub ru_nvcsw: ::std::os::raw::c_long,
-    pub ru_nivcsw: ::std::os::raw::c_long,
+    pub ru_maxrss: ::core::ffi::c_long,
+    pub ru_ixrss: ::core::ffi::c_long,
+    pub ru_idrss: ::core::ffi::c_long,
+    pub ru_isrss: ::core::ffi::c_long,
+    pub ru_minflt: ::core::ffi::c_long,
+    pub ru_majflt: ::core::ffi::c_long,
+    pub ru_nswap: ::core::ffi::c_long,
+    pub ru_inblock: ::core::ffi::c_long,
+    pub ru_oublock: ::core::ffi::c_long,
+    pub ru_msgsnd: ::core::ffi::c_long,
+    pub ru_msgrcv: ::core::ffi::c_long,
+    pub ru_nsignals: ::core::ffi::c_long,
+    pub ru_nvcsw: ::core::ffi::c_long,
+    pub ru_nivcsw: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_rusage() {
     assert_eq!(
-        ::std::mem::size_of::<rusage>(),
+        ::core::mem::size_of::<rusage>(),
         144usize,
         concat!("Size of: ", stringify!(rusage))
     );
     assert_eq!(
-        ::std::mem::align_of::<rusage>(),
+        ::core::mem::align_of::<rusage>(),
         8usize,
         concat!("Alignment of ", stringify!(rusage))
     );
     fn test_field_ru_utime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_utime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_utime) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -11251,9 +11251,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_stime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_stime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_stime) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -11268,9 +11268,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_maxrss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_maxrss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_maxrss) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -11285,9 +11285,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_ixrss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_ixrss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_ixrss) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -11302,9 +11302,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_idrss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_idrss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_idrss) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -11319,9 +11319,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_isrss() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_isrss) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_isrss) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -11336,9 +11336,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_minflt() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_minflt) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_minflt) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -11353,9 +11353,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_majflt() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_majflt) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_majflt) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -11370,9 +11370,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_nswap() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_nswap) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_nswap) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -11387,9 +11387,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_inblock() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_inblock) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_inblock) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -11404,9 +11404,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_oublock() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_oublock) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_oublock) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -11421,9 +11421,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_msgsnd() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_msgsnd) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_msgsnd) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -11438,9 +11438,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_msgrcv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_msgrcv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_msgrcv) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -11455,9 +11455,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_nsignals() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_nsignals) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_nsignals) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -11472,9 +11472,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_nvcsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_nvcsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_nvcsw) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -11489,9 +11489,9 @@ fn bindgen_test_layout_rusage() {
     fn test_field_ru_nivcsw() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ru_nivcsw) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ru_nivcsw) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -11504,7 +11504,7 @@ fn bindgen_test_layout_rusage() {
     }
     test_field_ru_nivcsw();
 }
-pub type rusage_info_t = *mut ::std::os::raw::c_void;
+pub type rusage_info_t = *mut ::core::ffi::c_void;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct rusage_info_v0 {
@@ -11523,21 +11523,21 @@ pub struct rusage_info_v0 {
 #[test]
 fn bindgen_test_layout_rusage_info_v0() {
     assert_eq!(
-        ::std::mem::size_of::<rusage_info_v0>(),
+        ::core::mem::size_of::<rusage_info_v0>(),
         96usize,
         concat!("Size of: ", stringify!(rusage_info_v0))
     );
     assert_eq!(
-        ::std::mem::align_of::<rusage_info_v0>(),
+        ::core::mem::align_of::<rusage_info_v0>(),
         8usize,
         concat!("Alignment of ", stringify!(rusage_info_v0))
     );
     fn test_field_ri_uuid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -11552,9 +11552,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -11569,9 +11569,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -11586,9 +11586,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -11603,9 +11603,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -11620,9 +11620,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -11637,9 +11637,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_wired_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -11654,9 +11654,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_resident_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -11671,9 +11671,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -11688,9 +11688,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_proc_start_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -11705,9 +11705,9 @@ fn bindgen_test_layout_rusage_info_v0() {
     fn test_field_ri_proc_exit_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v0>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v0>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -11744,21 +11744,21 @@ pub struct rusage_info_v1 {
 #[test]
 fn bindgen_test_layout_rusage_info_v1() {
     assert_eq!(
-        ::std::mem::size_of::<rusage_info_v1>(),
+        ::core::mem::size_of::<rusage_info_v1>(),
         144usize,
         concat!("Size of: ", stringify!(rusage_info_v1))
     );
     assert_eq!(
-        ::std::mem::align_of::<rusage_info_v1>(),
+        ::core::mem::align_of::<rusage_info_v1>(),
         8usize,
         concat!("Alignment of ", stringify!(rusage_info_v1))
     );
     fn test_field_ri_uuid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -11773,9 +11773,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -11790,9 +11790,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -11807,9 +11807,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -11824,9 +11824,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -11841,9 +11841,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -11858,9 +11858,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_wired_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -11875,9 +11875,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_resident_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -11892,9 +11892,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -11909,9 +11909,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_proc_start_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -11926,9 +11926,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_proc_exit_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -11943,9 +11943,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_child_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -11960,9 +11960,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_child_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -11977,9 +11977,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_child_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -11994,9 +11994,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_child_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -12011,9 +12011,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_child_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -12028,9 +12028,9 @@ fn bindgen_test_layout_rusage_info_v1() {
     fn test_field_ri_child_elapsed_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v1>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v1>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -12069,21 +12069,21 @@ pub struct rusage_info_v2 {
 #[test]
 fn bindgen_test_layout_rusage_info_v2() {
     assert_eq!(
-        ::std::mem::size_of::<rusage_info_v2>(),
+        ::core::mem::size_of::<rusage_info_v2>(),
         160usize,
         concat!("Size of: ", stringify!(rusage_info_v2))
     );
     assert_eq!(
-        ::std::mem::align_of::<rusage_info_v2>(),
+        ::core::mem::align_of::<rusage_info_v2>(),
         8usize,
         concat!("Alignment of ", stringify!(rusage_info_v2))
     );
     fn test_field_ri_uuid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -12098,9 +12098,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -12115,9 +12115,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -12132,9 +12132,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -12149,9 +12149,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -12166,9 +12166,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -12183,9 +12183,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_wired_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -12200,9 +12200,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_resident_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -12217,9 +12217,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -12234,9 +12234,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_proc_start_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -12251,9 +12251,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_proc_exit_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -12268,9 +12268,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_child_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -12285,9 +12285,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_child_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -12302,9 +12302,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_child_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -12319,9 +12319,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_child_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -12336,9 +12336,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_child_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -12353,9 +12353,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_child_elapsed_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -12370,9 +12370,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_diskio_bytesread() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -12387,9 +12387,9 @@ fn bindgen_test_layout_rusage_info_v2() {
     fn test_field_ri_diskio_byteswritten() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v2>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v2>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -12437,21 +12437,21 @@ pub struct rusage_info_v3 {
 #[test]
 fn bindgen_test_layout_rusage_info_v3() {
     assert_eq!(
-        ::std::mem::size_of::<rusage_info_v3>(),
+        ::core::mem::size_of::<rusage_info_v3>(),
         232usize,
         concat!("Size of: ", stringify!(rusage_info_v3))
     );
     assert_eq!(
-        ::std::mem::align_of::<rusage_info_v3>(),
+        ::core::mem::align_of::<rusage_info_v3>(),
         8usize,
         concat!("Alignment of ", stringify!(rusage_info_v3))
     );
     fn test_field_ri_uuid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -12466,9 +12466,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -12483,9 +12483,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -12500,9 +12500,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -12517,9 +12517,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -12534,9 +12534,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -12551,9 +12551,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_wired_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -12568,9 +12568,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_resident_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -12585,9 +12585,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -12602,9 +12602,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_proc_start_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -12619,9 +12619,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_proc_exit_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -12636,9 +12636,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_child_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -12653,9 +12653,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_child_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -12670,9 +12670,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_child_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -12687,9 +12687,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_child_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -12704,9 +12704,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_child_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -12721,9 +12721,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_child_elapsed_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -12738,9 +12738,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_diskio_bytesread() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -12755,9 +12755,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_diskio_byteswritten() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -12772,9 +12772,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_cpu_time_qos_default() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
             },
             160usize,
             concat!(
@@ -12789,9 +12789,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_cpu_time_qos_maintenance() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -12806,9 +12806,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_cpu_time_qos_background() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
             },
             176usize,
             concat!(
@@ -12823,9 +12823,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_cpu_time_qos_utility() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -12840,9 +12840,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_cpu_time_qos_legacy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
             },
             192usize,
             concat!(
@@ -12857,9 +12857,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_cpu_time_qos_user_initiated() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -12874,9 +12874,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_cpu_time_qos_user_interactive() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
                     - ptr as usize
             },
             208usize,
@@ -12892,9 +12892,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_billed_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -12909,9 +12909,9 @@ fn bindgen_test_layout_rusage_info_v3() {
     fn test_field_ri_serviced_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v3>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v3>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
             },
             224usize,
             concat!(
@@ -12967,21 +12967,21 @@ pub struct rusage_info_v4 {
 #[test]
 fn bindgen_test_layout_rusage_info_v4() {
     assert_eq!(
-        ::std::mem::size_of::<rusage_info_v4>(),
+        ::core::mem::size_of::<rusage_info_v4>(),
         296usize,
         concat!("Size of: ", stringify!(rusage_info_v4))
     );
     assert_eq!(
-        ::std::mem::align_of::<rusage_info_v4>(),
+        ::core::mem::align_of::<rusage_info_v4>(),
         8usize,
         concat!("Alignment of ", stringify!(rusage_info_v4))
     );
     fn test_field_ri_uuid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -12996,9 +12996,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -13013,9 +13013,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -13030,9 +13030,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -13047,9 +13047,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -13064,9 +13064,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -13081,9 +13081,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_wired_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -13098,9 +13098,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_resident_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -13115,9 +13115,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -13132,9 +13132,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_proc_start_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -13149,9 +13149,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_proc_exit_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -13166,9 +13166,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_child_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -13183,9 +13183,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_child_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -13200,9 +13200,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_child_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -13217,9 +13217,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_child_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -13234,9 +13234,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_child_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -13251,9 +13251,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_child_elapsed_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -13268,9 +13268,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_diskio_bytesread() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -13285,9 +13285,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_diskio_byteswritten() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -13302,9 +13302,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cpu_time_qos_default() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
             },
             160usize,
             concat!(
@@ -13319,9 +13319,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cpu_time_qos_maintenance() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -13336,9 +13336,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cpu_time_qos_background() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
             },
             176usize,
             concat!(
@@ -13353,9 +13353,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cpu_time_qos_utility() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -13370,9 +13370,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cpu_time_qos_legacy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
             },
             192usize,
             concat!(
@@ -13387,9 +13387,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cpu_time_qos_user_initiated() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -13404,9 +13404,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cpu_time_qos_user_interactive() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
                     - ptr as usize
             },
             208usize,
@@ -13422,9 +13422,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_billed_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -13439,9 +13439,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_serviced_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
             },
             224usize,
             concat!(
@@ -13456,9 +13456,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_logical_writes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_logical_writes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_logical_writes) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -13473,9 +13473,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_lifetime_max_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_lifetime_max_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_lifetime_max_phys_footprint) as usize - ptr as usize
             },
             240usize,
             concat!(
@@ -13490,9 +13490,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_instructions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_instructions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_instructions) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -13507,9 +13507,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_cycles() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cycles) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cycles) as usize - ptr as usize
             },
             256usize,
             concat!(
@@ -13524,9 +13524,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_billed_energy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_billed_energy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_billed_energy) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -13541,9 +13541,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_serviced_energy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_serviced_energy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_serviced_energy) as usize - ptr as usize
             },
             272usize,
             concat!(
@@ -13558,9 +13558,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_interval_max_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interval_max_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interval_max_phys_footprint) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -13575,9 +13575,9 @@ fn bindgen_test_layout_rusage_info_v4() {
     fn test_field_ri_runnable_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v4>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v4>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_runnable_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_runnable_time) as usize - ptr as usize
             },
             288usize,
             concat!(
@@ -13634,21 +13634,21 @@ pub struct rusage_info_v5 {
 #[test]
 fn bindgen_test_layout_rusage_info_v5() {
     assert_eq!(
-        ::std::mem::size_of::<rusage_info_v5>(),
+        ::core::mem::size_of::<rusage_info_v5>(),
         304usize,
         concat!("Size of: ", stringify!(rusage_info_v5))
     );
     assert_eq!(
-        ::std::mem::align_of::<rusage_info_v5>(),
+        ::core::mem::align_of::<rusage_info_v5>(),
         8usize,
         concat!("Alignment of ", stringify!(rusage_info_v5))
     );
     fn test_field_ri_uuid() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_uuid) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -13663,9 +13663,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_user_time) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -13680,9 +13680,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_system_time) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -13697,9 +13697,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pkg_idle_wkups) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -13714,9 +13714,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interrupt_wkups) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -13731,9 +13731,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_pageins) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -13748,9 +13748,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_wired_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_wired_size) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -13765,9 +13765,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_resident_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_resident_size) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -13782,9 +13782,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_phys_footprint) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -13799,9 +13799,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_proc_start_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_start_abstime) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -13816,9 +13816,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_proc_exit_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_proc_exit_abstime) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -13833,9 +13833,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_child_user_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_user_time) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -13850,9 +13850,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_child_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_system_time) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -13867,9 +13867,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_child_pkg_idle_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pkg_idle_wkups) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -13884,9 +13884,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_child_interrupt_wkups() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_interrupt_wkups) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -13901,9 +13901,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_child_pageins() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_pageins) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -13918,9 +13918,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_child_elapsed_abstime() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_child_elapsed_abstime) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -13935,9 +13935,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_diskio_bytesread() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_bytesread) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -13952,9 +13952,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_diskio_byteswritten() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_diskio_byteswritten) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -13969,9 +13969,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cpu_time_qos_default() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_default) as usize - ptr as usize
             },
             160usize,
             concat!(
@@ -13986,9 +13986,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cpu_time_qos_maintenance() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_maintenance) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -14003,9 +14003,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cpu_time_qos_background() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_background) as usize - ptr as usize
             },
             176usize,
             concat!(
@@ -14020,9 +14020,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cpu_time_qos_utility() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_utility) as usize - ptr as usize
             },
             184usize,
             concat!(
@@ -14037,9 +14037,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cpu_time_qos_legacy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_legacy) as usize - ptr as usize
             },
             192usize,
             concat!(
@@ -14054,9 +14054,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cpu_time_qos_user_initiated() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_initiated) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -14071,9 +14071,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cpu_time_qos_user_interactive() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
+                ::core::ptr::addr_of!((*ptr).ri_cpu_time_qos_user_interactive) as usize
                     - ptr as usize
             },
             208usize,
@@ -14089,9 +14089,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_billed_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_billed_system_time) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -14106,9 +14106,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_serviced_system_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_serviced_system_time) as usize - ptr as usize
             },
             224usize,
             concat!(
@@ -14123,9 +14123,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_logical_writes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_logical_writes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_logical_writes) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -14140,9 +14140,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_lifetime_max_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_lifetime_max_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_lifetime_max_phys_footprint) as usize - ptr as usize
             },
             240usize,
             concat!(
@@ -14157,9 +14157,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_instructions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_instructions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_instructions) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -14174,9 +14174,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_cycles() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_cycles) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_cycles) as usize - ptr as usize
             },
             256usize,
             concat!(
@@ -14191,9 +14191,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_billed_energy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_billed_energy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_billed_energy) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -14208,9 +14208,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_serviced_energy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_serviced_energy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_serviced_energy) as usize - ptr as usize
             },
             272usize,
             concat!(
@@ -14225,9 +14225,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_interval_max_phys_footprint() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_interval_max_phys_footprint) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_interval_max_phys_footprint) as usize - ptr as usize
             },
             280usize,
             concat!(
@@ -14242,9 +14242,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_runnable_time() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_runnable_time) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_runnable_time) as usize - ptr as usize
             },
             288usize,
             concat!(
@@ -14259,9 +14259,9 @@ fn bindgen_test_layout_rusage_info_v5() {
     fn test_field_ri_flags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rusage_info_v5>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rusage_info_v5>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ri_flags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ri_flags) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -14284,21 +14284,21 @@ pub struct rlimit {
 #[test]
 fn bindgen_test_layout_rlimit() {
     assert_eq!(
-        ::std::mem::size_of::<rlimit>(),
+        ::core::mem::size_of::<rlimit>(),
         16usize,
         concat!("Size of: ", stringify!(rlimit))
     );
     assert_eq!(
-        ::std::mem::align_of::<rlimit>(),
+        ::core::mem::align_of::<rlimit>(),
         8usize,
         concat!("Alignment of ", stringify!(rlimit))
     );
     fn test_field_rlim_cur() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rlimit>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rlimit>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rlim_cur) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rlim_cur) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -14313,9 +14313,9 @@ fn bindgen_test_layout_rlimit() {
     fn test_field_rlim_max() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<rlimit>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<rlimit>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rlim_max) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rlim_max) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -14337,21 +14337,21 @@ pub struct proc_rlimit_control_wakeupmon {
 #[test]
 fn bindgen_test_layout_proc_rlimit_control_wakeupmon() {
     assert_eq!(
-        ::std::mem::size_of::<proc_rlimit_control_wakeupmon>(),
+        ::core::mem::size_of::<proc_rlimit_control_wakeupmon>(),
         8usize,
         concat!("Size of: ", stringify!(proc_rlimit_control_wakeupmon))
     );
     assert_eq!(
-        ::std::mem::align_of::<proc_rlimit_control_wakeupmon>(),
+        ::core::mem::align_of::<proc_rlimit_control_wakeupmon>(),
         4usize,
         concat!("Alignment of ", stringify!(proc_rlimit_control_wakeupmon))
     );
     fn test_field_wm_flags() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<proc_rlimit_control_wakeupmon>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<proc_rlimit_control_wakeupmon>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).wm_flags) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).wm_flags) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -14366,9 +14366,9 @@ fn bindgen_test_layout_proc_rlimit_control_wakeupmon() {
     fn test_field_wm_rate() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<proc_rlimit_control_wakeupmon>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<proc_rlimit_control_wakeupmon>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).wm_rate) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).wm_rate) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -14382,41 +14382,41 @@ fn bindgen_test_layout_proc_rlimit_control_wakeupmon() {
     test_field_wm_rate();
 }
 extern "C" {
-    pub fn getpriority(arg1: ::std::os::raw::c_int, arg2: id_t) -> ::std::os::raw::c_int;
+    pub fn getpriority(arg1: ::core::ffi::c_int, arg2: id_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn getiopolicy_np(
-        arg1: ::std::os::raw::c_int,
-        arg2: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        arg1: ::core::ffi::c_int,
+        arg2: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn getrlimit(arg1: ::std::os::raw::c_int, arg2: *mut rlimit) -> ::std::os::raw::c_int;
+    pub fn getrlimit(arg1: ::core::ffi::c_int, arg2: *mut rlimit) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn getrusage(arg1: ::std::os::raw::c_int, arg2: *mut rusage) -> ::std::os::raw::c_int;
+    pub fn getrusage(arg1: ::core::ffi::c_int, arg2: *mut rusage) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn setpriority(
-        arg1: ::std::os::raw::c_int,
+        arg1: ::core::ffi::c_int,
         arg2: id_t,
-        arg3: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        arg3: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn setiopolicy_np(
-        arg1: ::std::os::raw::c_int,
-        arg2: ::std::os::raw::c_int,
-        arg3: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        arg1: ::core::ffi::c_int,
+        arg2: ::core::ffi::c_int,
+        arg3: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn setrlimit(arg1: ::std::os::raw::c_int, arg2: *const rlimit) -> ::std::os::raw::c_int;
+    pub fn setrlimit(arg1: ::core::ffi::c_int, arg2: *const rlimit) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union wait {
-    pub w_status: ::std::os::raw::c_int,
+    pub w_status: ::core::ffi::c_int,
     pub w_T: wait__bindgen_ty_1,
     pub w_S: wait__bindgen_ty_2,
 }
@@ -14430,83 +14430,83 @@ pub struct wait__bindgen_ty_1 {
 #[test]
 fn bindgen_test_layout_wait__bindgen_ty_1() {
     assert_eq!(
-        ::std::mem::size_of::<wait__bindgen_ty_1>(),
+        ::core::mem::size_of::<wait__bindgen_ty_1>(),
         4usize,
         concat!("Size of: ", stringify!(wait__bindgen_ty_1))
     );
     assert_eq!(
-        ::std::mem::align_of::<wait__bindgen_ty_1>(),
+        ::core::mem::align_of::<wait__bindgen_ty_1>(),
         4usize,
         concat!("Alignment of ", stringify!(wait__bindgen_ty_1))
     );
 }
 impl wait__bindgen_ty_1 {
     #[inline]
-    pub fn w_Termsig(&self) -> ::std::os::raw::c_uint {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 7u8) as u32) }
+    pub fn w_Termsig(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 7u8) as u32) }
     }
     #[inline]
-    pub fn set_w_Termsig(&mut self, val: ::std::os::raw::c_uint) {
+    pub fn set_w_Termsig(&mut self, val: ::core::ffi::c_uint) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 7u8, val as u64)
         }
     }
     #[inline]
-    pub fn w_Coredump(&self) -> ::std::os::raw::c_uint {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u32) }
+    pub fn w_Coredump(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_w_Coredump(&mut self, val: ::std::os::raw::c_uint) {
+    pub fn set_w_Coredump(&mut self, val: ::core::ffi::c_uint) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(7usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn w_Retcode(&self) -> ::std::os::raw::c_uint {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u32) }
+    pub fn w_Retcode(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u32) }
     }
     #[inline]
-    pub fn set_w_Retcode(&mut self, val: ::std::os::raw::c_uint) {
+    pub fn set_w_Retcode(&mut self, val: ::core::ffi::c_uint) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(8usize, 8u8, val as u64)
         }
     }
     #[inline]
-    pub fn w_Filler(&self) -> ::std::os::raw::c_uint {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(16usize, 16u8) as u32) }
+    pub fn w_Filler(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(16usize, 16u8) as u32) }
     }
     #[inline]
-    pub fn set_w_Filler(&mut self, val: ::std::os::raw::c_uint) {
+    pub fn set_w_Filler(&mut self, val: ::core::ffi::c_uint) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(16usize, 16u8, val as u64)
         }
     }
     #[inline]
     pub fn new_bitfield_1(
-        w_Termsig: ::std::os::raw::c_uint,
-        w_Coredump: ::std::os::raw::c_uint,
-        w_Retcode: ::std::os::raw::c_uint,
-        w_Filler: ::std::os::raw::c_uint,
+        w_Termsig: ::core::ffi::c_uint,
+        w_Coredump: ::core::ffi::c_uint,
+        w_Retcode: ::core::ffi::c_uint,
+        w_Filler: ::core::ffi::c_uint,
     ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 7u8, {
-            let w_Termsig: u32 = unsafe { ::std::mem::transmute(w_Termsig) };
+            let w_Termsig: u32 = unsafe { ::core::mem::transmute(w_Termsig) };
             w_Termsig as u64
         });
         __bindgen_bitfield_unit.set(7usize, 1u8, {
-            let w_Coredump: u32 = unsafe { ::std::mem::transmute(w_Coredump) };
+            let w_Coredump: u32 = unsafe { ::core::mem::transmute(w_Coredump) };
             w_Coredump as u64
         });
         __bindgen_bitfield_unit.set(8usize, 8u8, {
-            let w_Retcode: u32 = unsafe { ::std::mem::transmute(w_Retcode) };
+            let w_Retcode: u32 = unsafe { ::core::mem::transmute(w_Retcode) };
             w_Retcode as u64
         });
         __bindgen_bitfield_unit.set(16usize, 16u8, {
-            let w_Filler: u32 = unsafe { ::std::mem::transmute(w_Filler) };
+            let w_Filler: u32 = unsafe { ::core::mem::transmute(w_Filler) };
             w_Filler as u64
         });
         __bindgen_bitfield_unit
@@ -14522,67 +14522,67 @@ pub struct wait__bindgen_ty_2 {
 #[test]
 fn bindgen_test_layout_wait__bindgen_ty_2() {
     assert_eq!(
-        ::std::mem::size_of::<wait__bindgen_ty_2>(),
+        ::core::mem::size_of::<wait__bindgen_ty_2>(),
         4usize,
         concat!("Size of: ", stringify!(wait__bindgen_ty_2))
     );
     assert_eq!(
-        ::std::mem::align_of::<wait__bindgen_ty_2>(),
+        ::core::mem::align_of::<wait__bindgen_ty_2>(),
         4usize,
         concat!("Alignment of ", stringify!(wait__bindgen_ty_2))
     );
 }
 impl wait__bindgen_ty_2 {
     #[inline]
-    pub fn w_Stopval(&self) -> ::std::os::raw::c_uint {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 8u8) as u32) }
+    pub fn w_Stopval(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 8u8) as u32) }
     }
     #[inline]
-    pub fn set_w_Stopval(&mut self, val: ::std::os::raw::c_uint) {
+    pub fn set_w_Stopval(&mut self, val: ::core::ffi::c_uint) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 8u8, val as u64)
         }
     }
     #[inline]
-    pub fn w_Stopsig(&self) -> ::std::os::raw::c_uint {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u32) }
+    pub fn w_Stopsig(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(8usize, 8u8) as u32) }
     }
     #[inline]
-    pub fn set_w_Stopsig(&mut self, val: ::std::os::raw::c_uint) {
+    pub fn set_w_Stopsig(&mut self, val: ::core::ffi::c_uint) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(8usize, 8u8, val as u64)
         }
     }
     #[inline]
-    pub fn w_Filler(&self) -> ::std::os::raw::c_uint {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(16usize, 16u8) as u32) }
+    pub fn w_Filler(&self) -> ::core::ffi::c_uint {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(16usize, 16u8) as u32) }
     }
     #[inline]
-    pub fn set_w_Filler(&mut self, val: ::std::os::raw::c_uint) {
+    pub fn set_w_Filler(&mut self, val: ::core::ffi::c_uint) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(16usize, 16u8, val as u64)
         }
     }
     #[inline]
     pub fn new_bitfield_1(
-        w_Stopval: ::std::os::raw::c_uint,
-        w_Stopsig: ::std::os::raw::c_uint,
-        w_Filler: ::std::os::raw::c_uint,
+        w_Stopval: ::core::ffi::c_uint,
+        w_Stopsig: ::core::ffi::c_uint,
+        w_Filler: ::core::ffi::c_uint,
     ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 8u8, {
-            let w_Stopval: u32 = unsafe { ::std::mem::transmute(w_Stopval) };
+            let w_Stopval: u32 = unsafe { ::core::mem::transmute(w_Stopval) };
             w_Stopval as u64
         });
         __bindgen_bitfield_unit.set(8usize, 8u8, {
-            let w_Stopsig: u32 = unsafe { ::std::mem::transmute(w_Stopsig) };
+            let w_Stopsig: u32 = unsafe { ::core::mem::transmute(w_Stopsig) };
             w_Stopsig as u64
         });
         __bindgen_bitfield_unit.set(16usize, 16u8, {
-            let w_Filler: u32 = unsafe { ::std::mem::transmute(w_Filler) };
+            let w_Filler: u32 = unsafe { ::core::mem::transmute(w_Filler) };
             w_Filler as u64
         });
         __bindgen_bitfield_unit
@@ -14591,21 +14591,21 @@ impl wait__bindgen_ty_2 {
 #[test]
 fn bindgen_test_layout_wait() {
     assert_eq!(
-        ::std::mem::size_of::<wait>(),
+        ::core::mem::size_of::<wait>(),
         4usize,
         concat!("Size of: ", stringify!(wait))
     );
     assert_eq!(
-        ::std::mem::align_of::<wait>(),
+        ::core::mem::align_of::<wait>(),
         4usize,
         concat!("Alignment of ", stringify!(wait))
     );
     fn test_field_w_status() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<wait>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<wait>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).w_status) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).w_status) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -14620,9 +14620,9 @@ fn bindgen_test_layout_wait() {
     fn test_field_w_T() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<wait>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<wait>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).w_T) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).w_T) as usize - ptr as usize
             },
             0usize,
             concat!("Offset of field: ", stringify!(wait), "::", stringify!(w_T))
@@ -14632,9 +14632,9 @@ fn bindgen_test_layout_wait() {
     fn test_field_w_S() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<wait>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<wait>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).w_S) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).w_S) as usize - ptr as usize
             },
             0usize,
             concat!("Offset of field: ", stringify!(wait), "::", stringify!(w_S))
@@ -14643,13 +14643,13 @@ fn bindgen_test_layout_wait() {
     test_field_w_S();
 }
 extern "C" {
-    pub fn wait(arg1: *mut ::std::os::raw::c_int) -> pid_t;
+    pub fn wait(arg1: *mut ::core::ffi::c_int) -> pid_t;
 }
 extern "C" {
     pub fn waitpid(
         arg1: pid_t,
-        arg2: *mut ::std::os::raw::c_int,
-        arg3: ::std::os::raw::c_int,
+        arg2: *mut ::core::ffi::c_int,
+        arg3: ::core::ffi::c_int,
     ) -> pid_t;
 }
 extern "C" {
@@ -14657,26 +14657,26 @@ extern "C" {
         arg1: idtype_t,
         arg2: id_t,
         arg3: *mut siginfo_t,
-        arg4: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        arg4: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wait3(
-        arg1: *mut ::std::os::raw::c_int,
-        arg2: ::std::os::raw::c_int,
+        arg1: *mut ::core::ffi::c_int,
+        arg2: ::core::ffi::c_int,
         arg3: *mut rusage,
     ) -> pid_t;
 }
 extern "C" {
     pub fn wait4(
         arg1: pid_t,
-        arg2: *mut ::std::os::raw::c_int,
-        arg3: ::std::os::raw::c_int,
+        arg2: *mut ::core::ffi::c_int,
+        arg3: ::core::ffi::c_int,
         arg4: *mut rusage,
     ) -> pid_t;
 }
 extern "C" {
-    pub fn alloca(arg1: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+    pub fn alloca(arg1: ::core::ffi::c_ulong) -> *mut ::core::ffi::c_void;
 }
 pub type ct_rune_t = __darwin_ct_rune_t;
 pub type rune_t = __darwin_rune_t;
@@ -14684,27 +14684,27 @@ pub type wchar_t = __darwin_wchar_t;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct div_t {
-    pub quot: ::std::os::raw::c_int,
-    pub rem: ::std::os::raw::c_int,
+    pub quot: ::core::ffi::c_int,
+    pub rem: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_div_t() {
     assert_eq!(
-        ::std::mem::size_of::<div_t>(),
+        ::core::mem::size_of::<div_t>(),
         8usize,
         concat!("Size of: ", stringify!(div_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<div_t>(),
+        ::core::mem::align_of::<div_t>(),
         4usize,
         concat!("Alignment of ", stringify!(div_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<div_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<div_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -14719,9 +14719,9 @@ fn bindgen_test_layout_div_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<div_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<div_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -14737,27 +14737,27 @@ fn bindgen_test_layout_div_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ldiv_t {
-    pub quot: ::std::os::raw::c_long,
-    pub rem: ::std::os::raw::c_long,
+    pub quot: ::core::ffi::c_long,
+    pub rem: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout_ldiv_t() {
     assert_eq!(
-        ::std::mem::size_of::<ldiv_t>(),
+        ::core::mem::size_of::<ldiv_t>(),
         16usize,
         concat!("Size of: ", stringify!(ldiv_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<ldiv_t>(),
+        ::core::mem::align_of::<ldiv_t>(),
         8usize,
         concat!("Alignment of ", stringify!(ldiv_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<ldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<ldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -14772,9 +14772,9 @@ fn bindgen_test_layout_ldiv_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<ldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<ldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -14790,27 +14790,27 @@ fn bindgen_test_layout_ldiv_t() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lldiv_t {
-    pub quot: ::std::os::raw::c_longlong,
-    pub rem: ::std::os::raw::c_longlong,
+    pub quot: ::core::ffi::c_longlong,
+    pub rem: ::core::ffi::c_longlong,
 }
 #[test]
 fn bindgen_test_layout_lldiv_t() {
     assert_eq!(
-        ::std::mem::size_of::<lldiv_t>(),
+        ::core::mem::size_of::<lldiv_t>(),
         16usize,
         concat!("Size of: ", stringify!(lldiv_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<lldiv_t>(),
+        ::core::mem::align_of::<lldiv_t>(),
         8usize,
         concat!("Alignment of ", stringify!(lldiv_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<lldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<lldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -14825,9 +14825,9 @@ fn bindgen_test_layout_lldiv_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<lldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<lldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -14841,190 +14841,190 @@ fn bindgen_test_layout_lldiv_t() {
     test_field_rem();
 }
 extern "C" {
-    pub static mut __mb_cur_max: ::std::os::raw::c_int;
+    pub static mut __mb_cur_max: ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn malloc(__size: ::std::os::raw::c_ulong) -> *mut ::std::os::raw::c_void;
+    pub fn malloc(__size: ::core::ffi::c_ulong) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn calloc(
-        __count: ::std::os::raw::c_ulong,
-        __size: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __count: ::core::ffi::c_ulong,
+        __size: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn free(arg1: *mut ::std::os::raw::c_void);
+    pub fn free(arg1: *mut ::core::ffi::c_void);
 }
 extern "C" {
     pub fn realloc(
-        __ptr: *mut ::std::os::raw::c_void,
-        __size: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __ptr: *mut ::core::ffi::c_void,
+        __size: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn valloc(arg1: usize) -> *mut ::std::os::raw::c_void;
+    pub fn valloc(arg1: usize) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn aligned_alloc(
-        __alignment: ::std::os::raw::c_ulong,
-        __size: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __alignment: ::core::ffi::c_ulong,
+        __size: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn posix_memalign(
-        __memptr: *mut *mut ::std::os::raw::c_void,
+        __memptr: *mut *mut ::core::ffi::c_void,
         __alignment: usize,
         __size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn abort();
 }
 extern "C" {
-    pub fn abs(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn abs(arg1: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn atexit(arg1: ::std::option::Option<unsafe extern "C" fn()>) -> ::std::os::raw::c_int;
+    pub fn atexit(arg1: ::core::option::Option<unsafe extern "C" fn()>) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn atof(arg1: *const ::std::os::raw::c_char) -> f64;
+    pub fn atof(arg1: *const ::core::ffi::c_char) -> f64;
 }
 extern "C" {
-    pub fn atoi(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn atoi(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn atol(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+    pub fn atol(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn atoll(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
+    pub fn atoll(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn bsearch(
-        __key: *const ::std::os::raw::c_void,
-        __base: *const ::std::os::raw::c_void,
+        __key: *const ::core::ffi::c_void,
+        __base: *const ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: ::std::option::Option<
+        __compar: ::core::option::Option<
             unsafe extern "C" fn(
-                arg1: *const ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                arg1: *const ::core::ffi::c_void,
+                arg2: *const ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn div(arg1: ::std::os::raw::c_int, arg2: ::std::os::raw::c_int) -> div_t;
+    pub fn div(arg1: ::core::ffi::c_int, arg2: ::core::ffi::c_int) -> div_t;
 }
 extern "C" {
-    pub fn exit(arg1: ::std::os::raw::c_int);
+    pub fn exit(arg1: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn getenv(arg1: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn getenv(arg1: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn labs(arg1: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+    pub fn labs(arg1: ::core::ffi::c_long) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn ldiv(arg1: ::std::os::raw::c_long, arg2: ::std::os::raw::c_long) -> ldiv_t;
+    pub fn ldiv(arg1: ::core::ffi::c_long, arg2: ::core::ffi::c_long) -> ldiv_t;
 }
 extern "C" {
-    pub fn llabs(arg1: ::std::os::raw::c_longlong) -> ::std::os::raw::c_longlong;
+    pub fn llabs(arg1: ::core::ffi::c_longlong) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn lldiv(arg1: ::std::os::raw::c_longlong, arg2: ::std::os::raw::c_longlong) -> lldiv_t;
+    pub fn lldiv(arg1: ::core::ffi::c_longlong, arg2: ::core::ffi::c_longlong) -> lldiv_t;
 }
 extern "C" {
-    pub fn mblen(__s: *const ::std::os::raw::c_char, __n: usize) -> ::std::os::raw::c_int;
+    pub fn mblen(__s: *const ::core::ffi::c_char, __n: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn mbstowcs(arg1: *mut wchar_t, arg2: *const ::std::os::raw::c_char, arg3: usize) -> usize;
+    pub fn mbstowcs(arg1: *mut wchar_t, arg2: *const ::core::ffi::c_char, arg3: usize) -> usize;
 }
 extern "C" {
     pub fn mbtowc(
         arg1: *mut wchar_t,
-        arg2: *const ::std::os::raw::c_char,
+        arg2: *const ::core::ffi::c_char,
         arg3: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn qsort(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: ::std::option::Option<
+        __compar: ::core::option::Option<
             unsafe extern "C" fn(
-                arg1: *const ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                arg1: *const ::core::ffi::c_void,
+                arg2: *const ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
     );
 }
 extern "C" {
-    pub fn rand() -> ::std::os::raw::c_int;
+    pub fn rand() -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn srand(arg1: ::std::os::raw::c_uint);
+    pub fn srand(arg1: ::core::ffi::c_uint);
 }
 extern "C" {
     pub fn strtod(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
+        arg1: *const ::core::ffi::c_char,
+        arg2: *mut *mut ::core::ffi::c_char,
     ) -> f64;
 }
 extern "C" {
     pub fn strtof(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
+        arg1: *const ::core::ffi::c_char,
+        arg2: *mut *mut ::core::ffi::c_char,
     ) -> f32;
 }
 extern "C" {
     pub fn strtol(
-        __str: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_long;
+        __str: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn strtold(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
+        arg1: *const ::core::ffi::c_char,
+        arg2: *mut *mut ::core::ffi::c_char,
     ) -> u128;
 }
 extern "C" {
     pub fn strtoll(
-        __str: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        __str: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn strtoul(
-        __str: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
+        __str: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strtoull(
-        __str: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        __str: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
-    pub fn system(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn system(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn wcstombs(arg1: *mut ::std::os::raw::c_char, arg2: *const wchar_t, arg3: usize) -> usize;
+    pub fn wcstombs(arg1: *mut ::core::ffi::c_char, arg2: *const wchar_t, arg3: usize) -> usize;
 }
 extern "C" {
-    pub fn wctomb(arg1: *mut ::std::os::raw::c_char, arg2: wchar_t) -> ::std::os::raw::c_int;
+    pub fn wctomb(arg1: *mut ::core::ffi::c_char, arg2: wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _Exit(arg1: ::std::os::raw::c_int);
+    pub fn _Exit(arg1: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn a64l(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+    pub fn a64l(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn drand48() -> f64;
@@ -15032,126 +15032,126 @@ extern "C" {
 extern "C" {
     pub fn ecvt(
         arg1: f64,
-        arg2: ::std::os::raw::c_int,
-        arg3: *mut ::std::os::raw::c_int,
-        arg4: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        arg2: ::core::ffi::c_int,
+        arg3: *mut ::core::ffi::c_int,
+        arg4: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn erand48(arg1: *mut ::std::os::raw::c_ushort) -> f64;
+    pub fn erand48(arg1: *mut ::core::ffi::c_ushort) -> f64;
 }
 extern "C" {
     pub fn fcvt(
         arg1: f64,
-        arg2: ::std::os::raw::c_int,
-        arg3: *mut ::std::os::raw::c_int,
-        arg4: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        arg2: ::core::ffi::c_int,
+        arg3: *mut ::core::ffi::c_int,
+        arg4: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gcvt(
         arg1: f64,
-        arg2: ::std::os::raw::c_int,
-        arg3: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        arg2: ::core::ffi::c_int,
+        arg3: *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn getsubopt(
-        arg1: *mut *mut ::std::os::raw::c_char,
-        arg2: *const *mut ::std::os::raw::c_char,
-        arg3: *mut *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *mut *mut ::core::ffi::c_char,
+        arg2: *const *mut ::core::ffi::c_char,
+        arg3: *mut *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn grantpt(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn grantpt(arg1: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn initstate(
-        arg1: ::std::os::raw::c_uint,
-        arg2: *mut ::std::os::raw::c_char,
+        arg1: ::core::ffi::c_uint,
+        arg2: *mut ::core::ffi::c_char,
         arg3: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn jrand48(arg1: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+    pub fn jrand48(arg1: *mut ::core::ffi::c_ushort) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn l64a(arg1: ::std::os::raw::c_long) -> *mut ::std::os::raw::c_char;
+    pub fn l64a(arg1: ::core::ffi::c_long) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn lcong48(arg1: *mut ::std::os::raw::c_ushort);
+    pub fn lcong48(arg1: *mut ::core::ffi::c_ushort);
 }
 extern "C" {
-    pub fn lrand48() -> ::std::os::raw::c_long;
+    pub fn lrand48() -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn mktemp(arg1: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn mktemp(arg1: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn mkstemp(arg1: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn mkstemp(arg1: *mut ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn mrand48() -> ::std::os::raw::c_long;
+    pub fn mrand48() -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn nrand48(arg1: *mut ::std::os::raw::c_ushort) -> ::std::os::raw::c_long;
+    pub fn nrand48(arg1: *mut ::core::ffi::c_ushort) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn posix_openpt(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn posix_openpt(arg1: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn ptsname(arg1: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+    pub fn ptsname(arg1: ::core::ffi::c_int) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn ptsname_r(
-        fildes: ::std::os::raw::c_int,
-        buffer: *mut ::std::os::raw::c_char,
+        fildes: ::core::ffi::c_int,
+        buffer: *mut ::core::ffi::c_char,
         buflen: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn putenv(arg1: *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn putenv(arg1: *mut ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn random() -> ::std::os::raw::c_long;
+    pub fn random() -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn rand_r(arg1: *mut ::std::os::raw::c_uint) -> ::std::os::raw::c_int;
+    pub fn rand_r(arg1: *mut ::core::ffi::c_uint) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[link_name = "\u{1}_realpath$DARWIN_EXTSN"]
     pub fn realpath(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        arg1: *const ::core::ffi::c_char,
+        arg2: *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn seed48(arg1: *mut ::std::os::raw::c_ushort) -> *mut ::std::os::raw::c_ushort;
+    pub fn seed48(arg1: *mut ::core::ffi::c_ushort) -> *mut ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn setenv(
-        __name: *const ::std::os::raw::c_char,
-        __value: *const ::std::os::raw::c_char,
-        __overwrite: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        __name: *const ::core::ffi::c_char,
+        __value: *const ::core::ffi::c_char,
+        __overwrite: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn setkey(arg1: *const ::std::os::raw::c_char);
+    pub fn setkey(arg1: *const ::core::ffi::c_char);
 }
 extern "C" {
-    pub fn setstate(arg1: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn setstate(arg1: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn srand48(arg1: ::std::os::raw::c_long);
+    pub fn srand48(arg1: ::core::ffi::c_long);
 }
 extern "C" {
-    pub fn srandom(arg1: ::std::os::raw::c_uint);
+    pub fn srandom(arg1: ::core::ffi::c_uint);
 }
 extern "C" {
-    pub fn unlockpt(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn unlockpt(arg1: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn unsetenv(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn unsetenv(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 pub type dev_t = __darwin_dev_t;
 pub type mode_t = __darwin_mode_t;
@@ -15159,10 +15159,10 @@ extern "C" {
     pub fn arc4random() -> u32;
 }
 extern "C" {
-    pub fn arc4random_addrandom(arg1: *mut ::std::os::raw::c_uchar, arg2: ::std::os::raw::c_int);
+    pub fn arc4random_addrandom(arg1: *mut ::core::ffi::c_uchar, arg2: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn arc4random_buf(__buf: *mut ::std::os::raw::c_void, __nbytes: usize);
+    pub fn arc4random_buf(__buf: *mut ::core::ffi::c_void, __nbytes: usize);
 }
 extern "C" {
     pub fn arc4random_stir();
@@ -15171,228 +15171,228 @@ extern "C" {
     pub fn arc4random_uniform(__upper_bound: u32) -> u32;
 }
 extern "C" {
-    pub fn atexit_b(arg1: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int;
+    pub fn atexit_b(arg1: *mut ::core::ffi::c_void) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn bsearch_b(
-        __key: *const ::std::os::raw::c_void,
-        __base: *const ::std::os::raw::c_void,
+        __key: *const ::core::ffi::c_void,
+        __base: *const ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: *mut ::std::os::raw::c_void,
-    ) -> *mut ::std::os::raw::c_void;
+        __compar: *mut ::core::ffi::c_void,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn cgetcap(
-        arg1: *mut ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-        arg3: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        arg1: *mut ::core::ffi::c_char,
+        arg2: *const ::core::ffi::c_char,
+        arg3: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn cgetclose() -> ::std::os::raw::c_int;
+    pub fn cgetclose() -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn cgetent(
-        arg1: *mut *mut ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-        arg3: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *mut *mut ::core::ffi::c_char,
+        arg2: *mut *mut ::core::ffi::c_char,
+        arg3: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn cgetfirst(
-        arg1: *mut *mut ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *mut *mut ::core::ffi::c_char,
+        arg2: *mut *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn cgetmatch(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *const ::core::ffi::c_char,
+        arg2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn cgetnext(
-        arg1: *mut *mut ::std::os::raw::c_char,
-        arg2: *mut *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *mut *mut ::core::ffi::c_char,
+        arg2: *mut *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn cgetnum(
-        arg1: *mut ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-        arg3: *mut ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int;
+        arg1: *mut ::core::ffi::c_char,
+        arg2: *const ::core::ffi::c_char,
+        arg3: *mut ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn cgetset(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn cgetset(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn cgetstr(
-        arg1: *mut ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-        arg3: *mut *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *mut ::core::ffi::c_char,
+        arg2: *const ::core::ffi::c_char,
+        arg3: *mut *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn cgetustr(
-        arg1: *mut ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-        arg3: *mut *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *mut ::core::ffi::c_char,
+        arg2: *const ::core::ffi::c_char,
+        arg3: *mut *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     #[link_name = "\u{1}_daemon$1050"]
     pub fn daemon(
-        arg1: ::std::os::raw::c_int,
-        arg2: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        arg1: ::core::ffi::c_int,
+        arg2: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn devname(arg1: dev_t, arg2: mode_t) -> *mut ::std::os::raw::c_char;
+    pub fn devname(arg1: dev_t, arg2: mode_t) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn devname_r(
         arg1: dev_t,
         arg2: mode_t,
-        buf: *mut ::std::os::raw::c_char,
-        len: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        buf: *mut ::core::ffi::c_char,
+        len: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn getbsize(
-        arg1: *mut ::std::os::raw::c_int,
-        arg2: *mut ::std::os::raw::c_long,
-    ) -> *mut ::std::os::raw::c_char;
+        arg1: *mut ::core::ffi::c_int,
+        arg2: *mut ::core::ffi::c_long,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn getloadavg(arg1: *mut f64, arg2: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn getloadavg(arg1: *mut f64, arg2: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn getprogname() -> *const ::std::os::raw::c_char;
+    pub fn getprogname() -> *const ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn setprogname(arg1: *const ::std::os::raw::c_char);
+    pub fn setprogname(arg1: *const ::core::ffi::c_char);
 }
 extern "C" {
     pub fn heapsort(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: ::std::option::Option<
+        __compar: ::core::option::Option<
             unsafe extern "C" fn(
-                arg1: *const ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                arg1: *const ::core::ffi::c_void,
+                arg2: *const ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn heapsort_b(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        __compar: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn mergesort(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: ::std::option::Option<
+        __compar: ::core::option::Option<
             unsafe extern "C" fn(
-                arg1: *const ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                arg1: *const ::core::ffi::c_void,
+                arg2: *const ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn mergesort_b(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        __compar: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn psort(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: ::std::option::Option<
+        __compar: ::core::option::Option<
             unsafe extern "C" fn(
-                arg1: *const ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                arg1: *const ::core::ffi::c_void,
+                arg2: *const ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
     );
 }
 extern "C" {
     pub fn psort_b(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: *mut ::std::os::raw::c_void,
+        __compar: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn psort_r(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        arg1: *mut ::std::os::raw::c_void,
-        __compar: ::std::option::Option<
+        arg1: *mut ::core::ffi::c_void,
+        __compar: ::core::option::Option<
             unsafe extern "C" fn(
-                arg1: *mut ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-                arg3: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                arg1: *mut ::core::ffi::c_void,
+                arg2: *const ::core::ffi::c_void,
+                arg3: *const ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
     );
 }
 extern "C" {
     pub fn qsort_b(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        __compar: *mut ::std::os::raw::c_void,
+        __compar: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn qsort_r(
-        __base: *mut ::std::os::raw::c_void,
+        __base: *mut ::core::ffi::c_void,
         __nel: usize,
         __width: usize,
-        arg1: *mut ::std::os::raw::c_void,
-        __compar: ::std::option::Option<
+        arg1: *mut ::core::ffi::c_void,
+        __compar: ::core::option::Option<
             unsafe extern "C" fn(
-                arg1: *mut ::std::os::raw::c_void,
-                arg2: *const ::std::os::raw::c_void,
-                arg3: *const ::std::os::raw::c_void,
-            ) -> ::std::os::raw::c_int,
+                arg1: *mut ::core::ffi::c_void,
+                arg2: *const ::core::ffi::c_void,
+                arg3: *const ::core::ffi::c_void,
+            ) -> ::core::ffi::c_int,
         >,
     );
 }
 extern "C" {
     pub fn radixsort(
-        __base: *mut *const ::std::os::raw::c_uchar,
-        __nel: ::std::os::raw::c_int,
-        __table: *const ::std::os::raw::c_uchar,
-        __endbyte: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        __base: *mut *const ::core::ffi::c_uchar,
+        __nel: ::core::ffi::c_int,
+        __table: *const ::core::ffi::c_uchar,
+        __endbyte: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn rpmatch(arg1: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn rpmatch(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn sradixsort(
-        __base: *mut *const ::std::os::raw::c_uchar,
-        __nel: ::std::os::raw::c_int,
-        __table: *const ::std::os::raw::c_uchar,
-        __endbyte: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        __base: *mut *const ::core::ffi::c_uchar,
+        __nel: ::core::ffi::c_int,
+        __table: *const ::core::ffi::c_uchar,
+        __endbyte: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn sranddev();
@@ -15402,375 +15402,375 @@ extern "C" {
 }
 extern "C" {
     pub fn reallocf(
-        __ptr: *mut ::std::os::raw::c_void,
+        __ptr: *mut ::core::ffi::c_void,
         __size: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn strtonum(
-        __numstr: *const ::std::os::raw::c_char,
-        __minval: ::std::os::raw::c_longlong,
-        __maxval: ::std::os::raw::c_longlong,
-        __errstrp: *mut *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_longlong;
+        __numstr: *const ::core::ffi::c_char,
+        __minval: ::core::ffi::c_longlong,
+        __maxval: ::core::ffi::c_longlong,
+        __errstrp: *mut *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn strtoq(
-        __str: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        __str: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn strtouq(
-        __str: *const ::std::os::raw::c_char,
-        __endptr: *mut *mut ::std::os::raw::c_char,
-        __base: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        __str: *const ::core::ffi::c_char,
+        __endptr: *mut *mut ::core::ffi::c_char,
+        __base: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
-    pub static mut suboptarg: *mut ::std::os::raw::c_char;
+    pub static mut suboptarg: *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn memchr(
-        __s: *const ::std::os::raw::c_void,
-        __c: ::std::os::raw::c_int,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __s: *const ::core::ffi::c_void,
+        __c: ::core::ffi::c_int,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memcmp(
-        __s1: *const ::std::os::raw::c_void,
-        __s2: *const ::std::os::raw::c_void,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_void,
+        __s2: *const ::core::ffi::c_void,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn memcpy(
-        __dst: *mut ::std::os::raw::c_void,
-        __src: *const ::std::os::raw::c_void,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __dst: *mut ::core::ffi::c_void,
+        __src: *const ::core::ffi::c_void,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memmove(
-        __dst: *mut ::std::os::raw::c_void,
-        __src: *const ::std::os::raw::c_void,
-        __len: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __dst: *mut ::core::ffi::c_void,
+        __src: *const ::core::ffi::c_void,
+        __len: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memset(
-        __b: *mut ::std::os::raw::c_void,
-        __c: ::std::os::raw::c_int,
-        __len: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __b: *mut ::core::ffi::c_void,
+        __c: ::core::ffi::c_int,
+        __len: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn strcat(
-        __s1: *mut ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __s1: *mut ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strchr(
-        __s: *const ::std::os::raw::c_char,
-        __c: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __c: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcmp(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcoll(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcpy(
-        __dst: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __dst: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcspn(
-        __s: *const ::std::os::raw::c_char,
-        __charset: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_ulong;
+        __s: *const ::core::ffi::c_char,
+        __charset: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn strerror(__errnum: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+    pub fn strerror(__errnum: ::core::ffi::c_int) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn strlen(__s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_ulong;
+    pub fn strlen(__s: *const ::core::ffi::c_char) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strncat(
-        __s1: *mut ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __s1: *mut ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strncmp(
-        __s1: *const ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        __s1: *const ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strncpy(
-        __dst: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __dst: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strpbrk(
-        __s: *const ::std::os::raw::c_char,
-        __charset: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __charset: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strrchr(
-        __s: *const ::std::os::raw::c_char,
-        __c: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        __s: *const ::core::ffi::c_char,
+        __c: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strspn(
-        __s: *const ::std::os::raw::c_char,
-        __charset: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_ulong;
+        __s: *const ::core::ffi::c_char,
+        __charset: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strstr(
-        __big: *const ::std::os::raw::c_char,
-        __little: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __big: *const ::core::ffi::c_char,
+        __little: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strtok(
-        __str: *mut ::std::os::raw::c_char,
-        __sep: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __str: *mut ::core::ffi::c_char,
+        __sep: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strxfrm(
-        __s1: *mut ::std::os::raw::c_char,
-        __s2: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_ulong;
+        __s1: *mut ::core::ffi::c_char,
+        __s2: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strtok_r(
-        __str: *mut ::std::os::raw::c_char,
-        __sep: *const ::std::os::raw::c_char,
-        __lasts: *mut *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __str: *mut ::core::ffi::c_char,
+        __sep: *const ::core::ffi::c_char,
+        __lasts: *mut *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strerror_r(
-        __errnum: ::std::os::raw::c_int,
-        __strerrbuf: *mut ::std::os::raw::c_char,
+        __errnum: ::core::ffi::c_int,
+        __strerrbuf: *mut ::core::ffi::c_char,
         __buflen: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn strdup(__s1: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn strdup(__s1: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn memccpy(
-        __dst: *mut ::std::os::raw::c_void,
-        __src: *const ::std::os::raw::c_void,
-        __c: ::std::os::raw::c_int,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_void;
+        __dst: *mut ::core::ffi::c_void,
+        __src: *const ::core::ffi::c_void,
+        __c: ::core::ffi::c_int,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn stpcpy(
-        __dst: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __dst: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn stpncpy(
-        __dst: *mut ::std::os::raw::c_char,
-        __src: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __dst: *mut ::core::ffi::c_char,
+        __src: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strndup(
-        __s1: *const ::std::os::raw::c_char,
-        __n: ::std::os::raw::c_ulong,
-    ) -> *mut ::std::os::raw::c_char;
+        __s1: *const ::core::ffi::c_char,
+        __n: ::core::ffi::c_ulong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn strnlen(__s1: *const ::std::os::raw::c_char, __n: usize) -> usize;
+    pub fn strnlen(__s1: *const ::core::ffi::c_char, __n: usize) -> usize;
 }
 extern "C" {
-    pub fn strsignal(__sig: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+    pub fn strsignal(__sig: ::core::ffi::c_int) -> *mut ::core::ffi::c_char;
 }
 pub type rsize_t = __darwin_size_t;
-pub type errno_t = ::std::os::raw::c_int;
+pub type errno_t = ::core::ffi::c_int;
 extern "C" {
     pub fn memset_s(
-        __s: *mut ::std::os::raw::c_void,
+        __s: *mut ::core::ffi::c_void,
         __smax: rsize_t,
-        __c: ::std::os::raw::c_int,
+        __c: ::core::ffi::c_int,
         __n: rsize_t,
     ) -> errno_t;
 }
 extern "C" {
     pub fn memmem(
-        __big: *const ::std::os::raw::c_void,
+        __big: *const ::core::ffi::c_void,
         __big_len: usize,
-        __little: *const ::std::os::raw::c_void,
+        __little: *const ::core::ffi::c_void,
         __little_len: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memset_pattern4(
-        __b: *mut ::std::os::raw::c_void,
-        __pattern4: *const ::std::os::raw::c_void,
+        __b: *mut ::core::ffi::c_void,
+        __pattern4: *const ::core::ffi::c_void,
         __len: usize,
     );
 }
 extern "C" {
     pub fn memset_pattern8(
-        __b: *mut ::std::os::raw::c_void,
-        __pattern8: *const ::std::os::raw::c_void,
+        __b: *mut ::core::ffi::c_void,
+        __pattern8: *const ::core::ffi::c_void,
         __len: usize,
     );
 }
 extern "C" {
     pub fn memset_pattern16(
-        __b: *mut ::std::os::raw::c_void,
-        __pattern16: *const ::std::os::raw::c_void,
+        __b: *mut ::core::ffi::c_void,
+        __pattern16: *const ::core::ffi::c_void,
         __len: usize,
     );
 }
 extern "C" {
     pub fn strcasestr(
-        __big: *const ::std::os::raw::c_char,
-        __little: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __big: *const ::core::ffi::c_char,
+        __little: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strnstr(
-        __big: *const ::std::os::raw::c_char,
-        __little: *const ::std::os::raw::c_char,
+        __big: *const ::core::ffi::c_char,
+        __little: *const ::core::ffi::c_char,
         __len: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strlcat(
-        __dst: *mut ::std::os::raw::c_char,
-        __source: *const ::std::os::raw::c_char,
-        __size: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_ulong;
+        __dst: *mut ::core::ffi::c_char,
+        __source: *const ::core::ffi::c_char,
+        __size: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strlcpy(
-        __dst: *mut ::std::os::raw::c_char,
-        __source: *const ::std::os::raw::c_char,
-        __size: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_ulong;
+        __dst: *mut ::core::ffi::c_char,
+        __source: *const ::core::ffi::c_char,
+        __size: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn strmode(__mode: ::std::os::raw::c_int, __bp: *mut ::std::os::raw::c_char);
+    pub fn strmode(__mode: ::core::ffi::c_int, __bp: *mut ::core::ffi::c_char);
 }
 extern "C" {
     pub fn strsep(
-        __stringp: *mut *mut ::std::os::raw::c_char,
-        __delim: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        __stringp: *mut *mut ::core::ffi::c_char,
+        __delim: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn swab(
-        arg1: *const ::std::os::raw::c_void,
-        arg2: *mut ::std::os::raw::c_void,
+        arg1: *const ::core::ffi::c_void,
+        arg2: *mut ::core::ffi::c_void,
         arg3: isize,
     );
 }
 extern "C" {
     pub fn timingsafe_bcmp(
-        __b1: *const ::std::os::raw::c_void,
-        __b2: *const ::std::os::raw::c_void,
+        __b1: *const ::core::ffi::c_void,
+        __b2: *const ::core::ffi::c_void,
         __len: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strsignal_r(
-        __sig: ::std::os::raw::c_int,
-        __strsignalbuf: *mut ::std::os::raw::c_char,
+        __sig: ::core::ffi::c_int,
+        __strsignalbuf: *mut ::core::ffi::c_char,
         __buflen: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn bcmp(
-        arg1: *const ::std::os::raw::c_void,
-        arg2: *const ::std::os::raw::c_void,
-        arg3: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        arg1: *const ::core::ffi::c_void,
+        arg2: *const ::core::ffi::c_void,
+        arg3: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn bcopy(
-        arg1: *const ::std::os::raw::c_void,
-        arg2: *mut ::std::os::raw::c_void,
+        arg1: *const ::core::ffi::c_void,
+        arg2: *mut ::core::ffi::c_void,
         arg3: usize,
     );
 }
 extern "C" {
-    pub fn bzero(arg1: *mut ::std::os::raw::c_void, arg2: ::std::os::raw::c_ulong);
+    pub fn bzero(arg1: *mut ::core::ffi::c_void, arg2: ::core::ffi::c_ulong);
 }
 extern "C" {
     pub fn index(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        arg1: *const ::core::ffi::c_char,
+        arg2: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn rindex(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        arg1: *const ::core::ffi::c_char,
+        arg2: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn ffs(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn ffs(arg1: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcasecmp(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        arg1: *const ::core::ffi::c_char,
+        arg2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strncasecmp(
-        arg1: *const ::std::os::raw::c_char,
-        arg2: *const ::std::os::raw::c_char,
-        arg3: ::std::os::raw::c_ulong,
-    ) -> ::std::os::raw::c_int;
+        arg1: *const ::core::ffi::c_char,
+        arg2: *const ::core::ffi::c_char,
+        arg3: ::core::ffi::c_ulong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn ffsl(arg1: ::std::os::raw::c_long) -> ::std::os::raw::c_int;
+    pub fn ffsl(arg1: ::core::ffi::c_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn ffsll(arg1: ::std::os::raw::c_longlong) -> ::std::os::raw::c_int;
+    pub fn ffsll(arg1: ::core::ffi::c_longlong) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn fls(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn fls(arg1: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn flsl(arg1: ::std::os::raw::c_long) -> ::std::os::raw::c_int;
+    pub fn flsl(arg1: ::core::ffi::c_long) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn flsll(arg1: ::std::os::raw::c_longlong) -> ::std::os::raw::c_int;
+    pub fn flsll(arg1: ::core::ffi::c_longlong) -> ::core::ffi::c_int;
 }
 #[repr(u32)]
 #[doc = " Copied from TensorProto::DataType"]
@@ -16008,36 +16008,36 @@ pub struct OrtAllocator {
     #[doc = "< Must be initialized to ORT_API_VERSION"]
     pub version: u32,
     #[doc = "< Returns a pointer to an allocated block of `size` bytes"]
-    pub Alloc: ::std::option::Option<
-        unsafe extern "C" fn(this_: *mut OrtAllocator, size: usize) -> *mut ::std::os::raw::c_void,
+    pub Alloc: ::core::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, size: usize) -> *mut ::core::ffi::c_void,
     >,
     #[doc = "< Free a block of memory previously allocated with OrtAllocator::Alloc"]
-    pub Free: ::std::option::Option<
-        unsafe extern "C" fn(this_: *mut OrtAllocator, p: *mut ::std::os::raw::c_void),
+    pub Free: ::core::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, p: *mut ::core::ffi::c_void),
     >,
     #[doc = "< Return a pointer to an ::OrtMemoryInfo that describes this allocator"]
-    pub Info: ::std::option::Option<
+    pub Info: ::core::option::Option<
         unsafe extern "C" fn(this_: *const OrtAllocator) -> *const OrtMemoryInfo,
     >,
 }
 #[test]
 fn bindgen_test_layout_OrtAllocator() {
     assert_eq!(
-        ::std::mem::size_of::<OrtAllocator>(),
+        ::core::mem::size_of::<OrtAllocator>(),
         32usize,
         concat!("Size of: ", stringify!(OrtAllocator))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtAllocator>(),
+        ::core::mem::align_of::<OrtAllocator>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtAllocator))
     );
     fn test_field_version() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).version) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -16052,9 +16052,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Alloc() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Alloc) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Alloc) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -16069,9 +16069,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Free() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Free) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Free) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -16086,9 +16086,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Info() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Info) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Info) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -16101,14 +16101,14 @@ fn bindgen_test_layout_OrtAllocator() {
     }
     test_field_Info();
 }
-pub type OrtLoggingFunction = ::std::option::Option<
+pub type OrtLoggingFunction = ::core::option::Option<
     unsafe extern "C" fn(
-        param: *mut ::std::os::raw::c_void,
+        param: *mut ::core::ffi::c_void,
         severity: OrtLoggingLevel,
-        category: *const ::std::os::raw::c_char,
-        logid: *const ::std::os::raw::c_char,
-        code_location: *const ::std::os::raw::c_char,
-        message: *const ::std::os::raw::c_char,
+        category: *const ::core::ffi::c_char,
+        logid: *const ::core::ffi::c_char,
+        code_location: *const ::core::ffi::c_char,
+        message: *const ::core::ffi::c_char,
     ),
 >;
 #[repr(u32)]
@@ -16197,7 +16197,7 @@ pub enum OrtCudnnConvAlgoSearch {
 pub struct OrtCUDAProviderOptions {
     #[doc = " \\brief CUDA device Id"]
     #[doc = "   Defaults to 0."]
-    pub device_id: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
     #[doc = " \\brief CUDA Convolution algorithm search configuration."]
     #[doc = "   See enum OrtCudnnConvAlgoSearch for more details."]
     #[doc = "   Defaults to OrtCudnnConvAlgoSearchExhaustive."]
@@ -16211,49 +16211,49 @@ pub struct OrtCUDAProviderOptions {
     #[doc = "   1 = kSameAsRequested<br>"]
     #[doc = "   Defaults to 0."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
-    pub arena_extend_strategy: ::std::os::raw::c_int,
+    pub arena_extend_strategy: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the CUDA EP"]
     #[doc = "   0 = Use separate streams for copying and compute."]
     #[doc = "   1 = Use the same stream for copying and compute."]
     #[doc = "   Defaults to 1."]
     #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
     #[doc = "   Please see issue #4829 for more details."]
-    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    pub do_copy_in_default_stream: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
     #[doc = "   Defaults to 0."]
-    pub has_user_compute_stream: ::std::os::raw::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
     #[doc = " \\brief User provided compute stream."]
     #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
     #[doc = " \\brief CUDA memory arena configuration parameters"]
     pub default_memory_arena_cfg: *mut OrtArenaCfg,
     #[doc = " \\brief Enable TunableOp for using."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_CUDA_TUNABLE_OP_ENABLE."]
-    pub tunable_op_enable: ::std::os::raw::c_int,
+    pub tunable_op_enable: ::core::ffi::c_int,
     #[doc = " \\brief Enable TunableOp for tuning."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp tuning. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_CUDA_TUNABLE_OP_TUNING_ENABLE."]
-    pub tunable_op_tuning_enable: ::std::os::raw::c_int,
+    pub tunable_op_tuning_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtCUDAProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCUDAProviderOptions>(),
+        ::core::mem::size_of::<OrtCUDAProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtCUDAProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCUDAProviderOptions>(),
+        ::core::mem::align_of::<OrtCUDAProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtCUDAProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -16268,9 +16268,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_cudnn_conv_algo_search() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).cudnn_conv_algo_search) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).cudnn_conv_algo_search) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -16285,9 +16285,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_gpu_mem_limit() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -16302,9 +16302,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_arena_extend_strategy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -16319,9 +16319,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_do_copy_in_default_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -16336,9 +16336,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -16353,9 +16353,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -16370,9 +16370,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_default_memory_arena_cfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -16387,9 +16387,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_tunable_op_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -16404,9 +16404,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_tunable_op_tuning_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -16427,10 +16427,10 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
 pub struct OrtROCMProviderOptions {
     #[doc = " \\brief ROCM device Id"]
     #[doc = "   Defaults to 0."]
-    pub device_id: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
     #[doc = " \\brief ROCM MIOpen Convolution algorithm exaustive search option."]
     #[doc = "   Defaults to 0 (false)."]
-    pub miopen_conv_exhaustive_search: ::std::os::raw::c_int,
+    pub miopen_conv_exhaustive_search: ::core::ffi::c_int,
     #[doc = " \\brief ROCM memory limit (To use all possible memory pass in maximum size_t)"]
     #[doc = "   Defaults to SIZE_MAX."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
@@ -16440,49 +16440,49 @@ pub struct OrtROCMProviderOptions {
     #[doc = "   1 = kSameAsRequested<br>"]
     #[doc = "   Defaults to 0."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
-    pub arena_extend_strategy: ::std::os::raw::c_int,
+    pub arena_extend_strategy: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the ROCM EP"]
     #[doc = "   0 = Use separate streams for copying and compute."]
     #[doc = "   1 = Use the same stream for copying and compute."]
     #[doc = "   Defaults to 1."]
     #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
     #[doc = "   Please see issue #4829 for more details."]
-    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    pub do_copy_in_default_stream: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
     #[doc = "   Defaults to 0."]
-    pub has_user_compute_stream: ::std::os::raw::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
     #[doc = " \\brief User provided compute stream."]
     #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
     #[doc = " \\brief ROCM memory arena configuration parameters"]
     pub default_memory_arena_cfg: *mut OrtArenaCfg,
     #[doc = " \\brief Enable TunableOp for using."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_ROCM_TUNABLE_OP_ENABLE."]
-    pub tunable_op_enable: ::std::os::raw::c_int,
+    pub tunable_op_enable: ::core::ffi::c_int,
     #[doc = " \\brief Enable TunableOp for tuning."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp tuning. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_ROCM_TUNABLE_OP_TUNING_ENABLE."]
-    pub tunable_op_tuning_enable: ::std::os::raw::c_int,
+    pub tunable_op_tuning_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtROCMProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtROCMProviderOptions>(),
+        ::core::mem::size_of::<OrtROCMProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtROCMProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtROCMProviderOptions>(),
+        ::core::mem::align_of::<OrtROCMProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtROCMProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -16497,9 +16497,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_miopen_conv_exhaustive_search() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).miopen_conv_exhaustive_search) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).miopen_conv_exhaustive_search) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -16514,9 +16514,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_gpu_mem_limit() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -16531,9 +16531,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_arena_extend_strategy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -16548,9 +16548,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_do_copy_in_default_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -16565,9 +16565,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -16582,9 +16582,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -16599,9 +16599,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_default_memory_arena_cfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -16616,9 +16616,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_tunable_op_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -16633,9 +16633,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_tunable_op_tuning_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -16655,43 +16655,43 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
 #[derive(Debug, Copy, Clone)]
 pub struct OrtTensorRTProviderOptions {
     #[doc = "< CUDA device id (0 = default device)"]
-    pub device_id: ::std::os::raw::c_int,
-    pub has_user_compute_stream: ::std::os::raw::c_int,
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
-    pub trt_max_partition_iterations: ::std::os::raw::c_int,
-    pub trt_min_subgraph_size: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
+    pub trt_max_partition_iterations: ::core::ffi::c_int,
+    pub trt_min_subgraph_size: ::core::ffi::c_int,
     pub trt_max_workspace_size: usize,
-    pub trt_fp16_enable: ::std::os::raw::c_int,
-    pub trt_int8_enable: ::std::os::raw::c_int,
-    pub trt_int8_calibration_table_name: *const ::std::os::raw::c_char,
-    pub trt_int8_use_native_calibration_table: ::std::os::raw::c_int,
-    pub trt_dla_enable: ::std::os::raw::c_int,
-    pub trt_dla_core: ::std::os::raw::c_int,
-    pub trt_dump_subgraphs: ::std::os::raw::c_int,
-    pub trt_engine_cache_enable: ::std::os::raw::c_int,
-    pub trt_engine_cache_path: *const ::std::os::raw::c_char,
-    pub trt_engine_decryption_enable: ::std::os::raw::c_int,
-    pub trt_engine_decryption_lib_path: *const ::std::os::raw::c_char,
-    pub trt_force_sequential_engine_build: ::std::os::raw::c_int,
+    pub trt_fp16_enable: ::core::ffi::c_int,
+    pub trt_int8_enable: ::core::ffi::c_int,
+    pub trt_int8_calibration_table_name: *const ::core::ffi::c_char,
+    pub trt_int8_use_native_calibration_table: ::core::ffi::c_int,
+    pub trt_dla_enable: ::core::ffi::c_int,
+    pub trt_dla_core: ::core::ffi::c_int,
+    pub trt_dump_subgraphs: ::core::ffi::c_int,
+    pub trt_engine_cache_enable: ::core::ffi::c_int,
+    pub trt_engine_cache_path: *const ::core::ffi::c_char,
+    pub trt_engine_decryption_enable: ::core::ffi::c_int,
+    pub trt_engine_decryption_lib_path: *const ::core::ffi::c_char,
+    pub trt_force_sequential_engine_build: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtTensorRTProviderOptions>(),
+        ::core::mem::size_of::<OrtTensorRTProviderOptions>(),
         104usize,
         concat!("Size of: ", stringify!(OrtTensorRTProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtTensorRTProviderOptions>(),
+        ::core::mem::align_of::<OrtTensorRTProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtTensorRTProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -16706,9 +16706,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -16723,9 +16723,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -16740,9 +16740,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_max_partition_iterations() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_max_partition_iterations) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_max_partition_iterations) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -16757,9 +16757,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_min_subgraph_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_min_subgraph_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_min_subgraph_size) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -16774,9 +16774,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_max_workspace_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_max_workspace_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_max_workspace_size) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -16791,9 +16791,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_fp16_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_fp16_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_fp16_enable) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -16808,9 +16808,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_enable) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -16825,9 +16825,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_calibration_table_name() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_calibration_table_name) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_calibration_table_name) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -16842,9 +16842,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_use_native_calibration_table() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_use_native_calibration_table) as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_use_native_calibration_table) as usize
                     - ptr as usize
             },
             48usize,
@@ -16860,9 +16860,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dla_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dla_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dla_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -16877,9 +16877,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dla_core() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dla_core) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dla_core) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -16894,9 +16894,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dump_subgraphs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dump_subgraphs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dump_subgraphs) as usize - ptr as usize
             },
             60usize,
             concat!(
@@ -16911,9 +16911,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_cache_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_cache_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_cache_enable) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -16928,9 +16928,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_cache_path() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_cache_path) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_cache_path) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -16945,9 +16945,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_decryption_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_decryption_enable) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -16962,9 +16962,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_decryption_lib_path() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_lib_path) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_decryption_lib_path) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -16979,9 +16979,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_force_sequential_engine_build() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_force_sequential_engine_build) as usize
+                ::core::ptr::addr_of!((*ptr).trt_force_sequential_engine_build) as usize
                     - ptr as usize
             },
             96usize,
@@ -17001,28 +17001,28 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OrtMIGraphXProviderOptions {
-    pub device_id: ::std::os::raw::c_int,
-    pub migraphx_fp16_enable: ::std::os::raw::c_int,
-    pub migraphx_int8_enable: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
+    pub migraphx_fp16_enable: ::core::ffi::c_int,
+    pub migraphx_int8_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtMIGraphXProviderOptions>(),
+        ::core::mem::size_of::<OrtMIGraphXProviderOptions>(),
         12usize,
         concat!("Size of: ", stringify!(OrtMIGraphXProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtMIGraphXProviderOptions>(),
+        ::core::mem::align_of::<OrtMIGraphXProviderOptions>(),
         4usize,
         concat!("Alignment of ", stringify!(OrtMIGraphXProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -17037,9 +17037,9 @@ fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     fn test_field_migraphx_fp16_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).migraphx_fp16_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).migraphx_fp16_enable) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -17054,9 +17054,9 @@ fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     fn test_field_migraphx_int8_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).migraphx_int8_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).migraphx_int8_enable) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -17078,37 +17078,37 @@ pub struct OrtOpenVINOProviderOptions {
     #[doc = " \\brief Device type string"]
     #[doc = ""]
     #[doc = " Valid settings are one of: \"CPU_FP32\", \"CPU_FP16\", \"GPU_FP32\", \"GPU_FP16\""]
-    pub device_type: *const ::std::os::raw::c_char,
+    pub device_type: *const ::core::ffi::c_char,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_vpu_fast_compile: ::std::os::raw::c_uchar,
-    pub device_id: *const ::std::os::raw::c_char,
+    pub enable_vpu_fast_compile: ::core::ffi::c_uchar,
+    pub device_id: *const ::core::ffi::c_char,
     #[doc = "< 0 = Use default number of threads"]
     pub num_of_threads: usize,
-    pub cache_dir: *const ::std::os::raw::c_char,
-    pub context: *mut ::std::os::raw::c_void,
+    pub cache_dir: *const ::core::ffi::c_char,
+    pub context: *mut ::core::ffi::c_void,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_opencl_throttling: ::std::os::raw::c_uchar,
+    pub enable_opencl_throttling: ::core::ffi::c_uchar,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_dynamic_shapes: ::std::os::raw::c_uchar,
+    pub enable_dynamic_shapes: ::core::ffi::c_uchar,
 }
 #[test]
 fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtOpenVINOProviderOptions>(),
+        ::core::mem::size_of::<OrtOpenVINOProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtOpenVINOProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtOpenVINOProviderOptions>(),
+        ::core::mem::align_of::<OrtOpenVINOProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtOpenVINOProviderOptions))
     );
     fn test_field_device_type() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -17123,9 +17123,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_vpu_fast_compile() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_vpu_fast_compile) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_vpu_fast_compile) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -17140,9 +17140,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -17157,9 +17157,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_num_of_threads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).num_of_threads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).num_of_threads) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -17174,9 +17174,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_cache_dir() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).cache_dir) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).cache_dir) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -17191,9 +17191,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_context() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).context) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -17208,9 +17208,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_opencl_throttling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_opencl_throttling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_opencl_throttling) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -17225,9 +17225,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_dynamic_shapes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_dynamic_shapes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_dynamic_shapes) as usize - ptr as usize
             },
             49usize,
             concat!(
@@ -17259,31 +17259,31 @@ pub struct OrtApiBase {
     #[doc = ""]
     #[doc = " One can call GetVersionString() to get the version of the Onnxruntime library for logging"]
     #[doc = " and error reporting purposes."]
-    pub GetApi: ::std::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtApi>,
+    pub GetApi: ::core::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtApi>,
     #[doc = " \\brief Returns a null terminated string of the version of the Onnxruntime library (eg: \"1.8.1\")"]
     #[doc = ""]
     #[doc = "  \\return UTF-8 encoded version string. Do not deallocate the returned buffer."]
     pub GetVersionString:
-        ::std::option::Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+        ::core::option::Option<unsafe extern "C" fn() -> *const ::core::ffi::c_char>,
 }
 #[test]
 fn bindgen_test_layout_OrtApiBase() {
     assert_eq!(
-        ::std::mem::size_of::<OrtApiBase>(),
+        ::core::mem::size_of::<OrtApiBase>(),
         16usize,
         concat!("Size of: ", stringify!(OrtApiBase))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtApiBase>(),
+        ::core::mem::align_of::<OrtApiBase>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtApiBase))
     );
     fn test_field_GetApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApiBase>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetApi) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -17298,9 +17298,9 @@ fn bindgen_test_layout_OrtApiBase() {
     fn test_field_GetVersionString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApiBase>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVersionString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVersionString) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -17324,30 +17324,30 @@ extern "C" {
 #[doc = " Onnxruntime will provide the working loop on custom thread creation"]
 #[doc = " Argument is an onnxruntime built-in type which will be provided when thread pool calls OrtCustomCreateThreadFn"]
 pub type OrtThreadWorkerFn =
-    ::std::option::Option<unsafe extern "C" fn(ort_worker_fn_param: *mut ::std::os::raw::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(ort_worker_fn_param: *mut ::core::ffi::c_void)>;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OrtCustomHandleType {
-    pub __place_holder: ::std::os::raw::c_char,
+    pub __place_holder: ::core::ffi::c_char,
 }
 #[test]
 fn bindgen_test_layout_OrtCustomHandleType() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCustomHandleType>(),
+        ::core::mem::size_of::<OrtCustomHandleType>(),
         1usize,
         concat!("Size of: ", stringify!(OrtCustomHandleType))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCustomHandleType>(),
+        ::core::mem::align_of::<OrtCustomHandleType>(),
         1usize,
         concat!("Alignment of ", stringify!(OrtCustomHandleType))
     );
     fn test_field___place_holder() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomHandleType>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomHandleType>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__place_holder) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__place_holder) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -17365,11 +17365,11 @@ pub type OrtCustomThreadHandle = *const OrtCustomHandleType;
 #[doc = ""]
 #[doc = " The function should return a thread handle to be used in onnxruntime thread pools"]
 #[doc = " Onnxruntime will throw exception on return value of nullptr or 0, indicating that the function failed to create a thread"]
-pub type OrtCustomCreateThreadFn = ::std::option::Option<
+pub type OrtCustomCreateThreadFn = ::core::option::Option<
     unsafe extern "C" fn(
-        ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+        ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ort_thread_worker_fn: OrtThreadWorkerFn,
-        ort_worker_fn_param: *mut ::std::os::raw::c_void,
+        ort_worker_fn_param: *mut ::core::ffi::c_void,
     ) -> OrtCustomThreadHandle,
 >;
 #[doc = " \\brief Custom thread join function"]
@@ -17377,8 +17377,8 @@ pub type OrtCustomCreateThreadFn = ::std::option::Option<
 #[doc = " Onnxruntime thread pool destructor will call the function to join a custom thread."]
 #[doc = " Argument ort_custom_thread_handle is the value returned by OrtCustomCreateThreadFn"]
 pub type OrtCustomJoinThreadFn =
-    ::std::option::Option<unsafe extern "C" fn(ort_custom_thread_handle: OrtCustomThreadHandle)>;
-pub type RegisterCustomOpsFn = ::std::option::Option<
+    ::core::option::Option<unsafe extern "C" fn(ort_custom_thread_handle: OrtCustomThreadHandle)>;
+pub type RegisterCustomOpsFn = ::core::option::Option<
     unsafe extern "C" fn(options: *mut OrtSessionOptions, api: *const OrtApiBase) -> *mut OrtStatus,
 >;
 #[doc = " \\brief The C API"]
@@ -17395,10 +17395,10 @@ pub struct OrtApi {
     #[doc = " \\param[in] code"]
     #[doc = " \\param[in] msg A null-terminated string. Its contents will be copied."]
     #[doc = " \\return A new OrtStatus object, must be destroyed with OrtApi::ReleaseStatus"]
-    pub CreateStatus: ::std::option::Option<
+    pub CreateStatus: ::core::option::Option<
         unsafe extern "C" fn(
             code: OrtErrorCode,
-            msg: *const ::std::os::raw::c_char,
+            msg: *const ::core::ffi::c_char,
         ) -> *mut OrtStatus,
     >,
     #[doc = " \\brief Get OrtErrorCode from OrtStatus"]
@@ -17406,263 +17406,263 @@ pub struct OrtApi {
     #[doc = " \\param[in] status"]
     #[doc = " \\return OrtErrorCode that \\p status was created with"]
     pub GetErrorCode:
-        ::std::option::Option<unsafe extern "C" fn(status: *const OrtStatus) -> OrtErrorCode>,
+        ::core::option::Option<unsafe extern "C" fn(status: *const OrtStatus) -> OrtErrorCode>,
     #[doc = " \\brief Get error string from OrtStatus"]
     #[doc = ""]
     #[doc = " \\param[in] status"]
     #[doc = " \\return The error message inside the `status`. Do not free the returned value."]
-    pub GetErrorMessage: ::std::option::Option<
-        unsafe extern "C" fn(status: *const OrtStatus) -> *const ::std::os::raw::c_char,
+    pub GetErrorMessage: ::core::option::Option<
+        unsafe extern "C" fn(status: *const OrtStatus) -> *const ::core::ffi::c_char,
     >,
-    pub CreateEnv: ::std::option::Option<
+    pub CreateEnv: ::core::option::Option<
         unsafe extern "C" fn(
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub CreateEnvWithCustomLogger: ::std::option::Option<
+    pub CreateEnvWithCustomLogger: ::core::option::Option<
         unsafe extern "C" fn(
             logging_function: OrtLoggingFunction,
-            logger_param: *mut ::std::os::raw::c_void,
+            logger_param: *mut ::core::ffi::c_void,
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
     pub EnableTelemetryEvents:
-        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
     pub DisableTelemetryEvents:
-        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
-    pub CreateSession: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+    pub CreateSession: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_path: *const ::std::os::raw::c_char,
+            model_path: *const ::core::ffi::c_char,
             options: *const OrtSessionOptions,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionFromArray: ::std::option::Option<
+    pub CreateSessionFromArray: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_data: *const ::std::os::raw::c_void,
+            model_data: *const ::core::ffi::c_void,
             model_data_length: usize,
             options: *const OrtSessionOptions,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub Run: ::std::option::Option<
+    pub Run: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             run_options: *const OrtRunOptions,
-            input_names: *const *const ::std::os::raw::c_char,
+            input_names: *const *const ::core::ffi::c_char,
             inputs: *const *const OrtValue,
             input_len: usize,
-            output_names: *const *const ::std::os::raw::c_char,
+            output_names: *const *const ::core::ffi::c_char,
             output_names_len: usize,
             outputs: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionOptions: ::std::option::Option<
+    pub CreateSessionOptions: ::core::option::Option<
         unsafe extern "C" fn(options: *mut *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub SetOptimizedModelFilePath: ::std::option::Option<
+    pub SetOptimizedModelFilePath: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            optimized_model_filepath: *const ::std::os::raw::c_char,
+            optimized_model_filepath: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CloneSessionOptions: ::std::option::Option<
+    pub CloneSessionOptions: ::core::option::Option<
         unsafe extern "C" fn(
             in_options: *const OrtSessionOptions,
             out_options: *mut *mut OrtSessionOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionExecutionMode: ::std::option::Option<
+    pub SetSessionExecutionMode: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             execution_mode: ExecutionMode,
         ) -> OrtStatusPtr,
     >,
-    pub EnableProfiling: ::std::option::Option<
+    pub EnableProfiling: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            profile_file_prefix: *const ::std::os::raw::c_char,
+            profile_file_prefix: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub DisableProfiling: ::std::option::Option<
+    pub DisableProfiling: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub EnableMemPattern: ::std::option::Option<
+    pub EnableMemPattern: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub DisableMemPattern: ::std::option::Option<
+    pub DisableMemPattern: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub EnableCpuMemArena: ::std::option::Option<
+    pub EnableCpuMemArena: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub DisableCpuMemArena: ::std::option::Option<
+    pub DisableCpuMemArena: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub SetSessionLogId: ::std::option::Option<
+    pub SetSessionLogId: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionLogVerbosityLevel: ::std::option::Option<
+    pub SetSessionLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            session_log_verbosity_level: ::std::os::raw::c_int,
+            session_log_verbosity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionLogSeverityLevel: ::std::option::Option<
+    pub SetSessionLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            session_log_severity_level: ::std::os::raw::c_int,
+            session_log_severity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionGraphOptimizationLevel: ::std::option::Option<
+    pub SetSessionGraphOptimizationLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             graph_optimization_level: GraphOptimizationLevel,
         ) -> OrtStatusPtr,
     >,
-    pub SetIntraOpNumThreads: ::std::option::Option<
+    pub SetIntraOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            intra_op_num_threads: ::std::os::raw::c_int,
+            intra_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetInterOpNumThreads: ::std::option::Option<
+    pub SetInterOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            inter_op_num_threads: ::std::os::raw::c_int,
+            inter_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCustomOpDomain: ::std::option::Option<
+    pub CreateCustomOpDomain: ::core::option::Option<
         unsafe extern "C" fn(
-            domain: *const ::std::os::raw::c_char,
+            domain: *const ::core::ffi::c_char,
             out: *mut *mut OrtCustomOpDomain,
         ) -> OrtStatusPtr,
     >,
-    pub CustomOpDomain_Add: ::std::option::Option<
+    pub CustomOpDomain_Add: ::core::option::Option<
         unsafe extern "C" fn(
             custom_op_domain: *mut OrtCustomOpDomain,
             op: *const OrtCustomOp,
         ) -> OrtStatusPtr,
     >,
-    pub AddCustomOpDomain: ::std::option::Option<
+    pub AddCustomOpDomain: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             custom_op_domain: *mut OrtCustomOpDomain,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsLibrary: ::std::option::Option<
+    pub RegisterCustomOpsLibrary: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            library_path: *const ::std::os::raw::c_char,
-            library_handle: *mut *mut ::std::os::raw::c_void,
+            library_path: *const ::core::ffi::c_char,
+            library_handle: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetInputCount: ::std::option::Option<
+    pub SessionGetInputCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputCount: ::std::option::Option<
+    pub SessionGetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerCount: ::std::option::Option<
+    pub SessionGetOverridableInitializerCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetInputTypeInfo: ::std::option::Option<
+    pub SessionGetInputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputTypeInfo: ::std::option::Option<
+    pub SessionGetOutputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerTypeInfo: ::std::option::Option<
+    pub SessionGetOverridableInitializerTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetInputName: ::std::option::Option<
+    pub SessionGetInputName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputName: ::std::option::Option<
+    pub SessionGetOutputName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerName: ::std::option::Option<
+    pub SessionGetOverridableInitializerName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     pub CreateRunOptions:
-        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtRunOptions) -> OrtStatusPtr>,
-    pub RunOptionsSetRunLogVerbosityLevel: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(out: *mut *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub RunOptionsSetRunLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            log_verbosity_level: ::std::os::raw::c_int,
+            log_verbosity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsSetRunLogSeverityLevel: ::std::option::Option<
+    pub RunOptionsSetRunLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            log_severity_level: ::std::os::raw::c_int,
+            log_severity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsSetRunTag: ::std::option::Option<
+    pub RunOptionsSetRunTag: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            run_tag: *const ::std::os::raw::c_char,
+            run_tag: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunLogVerbosityLevel: ::std::option::Option<
+    pub RunOptionsGetRunLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            log_verbosity_level: *mut ::std::os::raw::c_int,
+            log_verbosity_level: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunLogSeverityLevel: ::std::option::Option<
+    pub RunOptionsGetRunLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            log_severity_level: *mut ::std::os::raw::c_int,
+            log_severity_level: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunTag: ::std::option::Option<
+    pub RunOptionsGetRunTag: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            run_tag: *mut *const ::std::os::raw::c_char,
+            run_tag: *mut *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     pub RunOptionsSetTerminate:
-        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
     pub RunOptionsUnsetTerminate:
-        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
-    pub CreateTensorAsOrtValue: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub CreateTensorAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             allocator: *mut OrtAllocator,
             shape: *const i64,
@@ -17671,10 +17671,10 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateTensorWithDataAsOrtValue: ::std::option::Option<
+    pub CreateTensorWithDataAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtMemoryInfo,
-            p_data: *mut ::std::os::raw::c_void,
+            p_data: *mut ::core::ffi::c_void,
             p_data_len: usize,
             shape: *const i64,
             shape_len: usize,
@@ -17682,187 +17682,187 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub IsTensor: ::std::option::Option<
+    pub IsTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorMutableData: ::std::option::Option<
+    pub GetTensorMutableData: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub FillStringTensor: ::std::option::Option<
+    pub FillStringTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            s: *const *const ::std::os::raw::c_char,
+            s: *const *const ::core::ffi::c_char,
             s_len: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetStringTensorDataLength: ::std::option::Option<
+    pub GetStringTensorDataLength: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, len: *mut usize) -> OrtStatusPtr,
     >,
-    pub GetStringTensorContent: ::std::option::Option<
+    pub GetStringTensorContent: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            s: *mut ::std::os::raw::c_void,
+            s: *mut ::core::ffi::c_void,
             s_len: usize,
             offsets: *mut usize,
             offsets_len: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToTensorInfo: ::std::option::Option<
+    pub CastTypeInfoToTensorInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetOnnxTypeFromTypeInfo: ::std::option::Option<
+    pub GetOnnxTypeFromTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(type_info: *const OrtTypeInfo, out: *mut ONNXType) -> OrtStatusPtr,
     >,
-    pub CreateTensorTypeAndShapeInfo: ::std::option::Option<
+    pub CreateTensorTypeAndShapeInfo: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtTensorTypeAndShapeInfo) -> OrtStatusPtr,
     >,
-    pub SetTensorElementType: ::std::option::Option<
+    pub SetTensorElementType: ::core::option::Option<
         unsafe extern "C" fn(
             info: *mut OrtTensorTypeAndShapeInfo,
             type_: ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub SetDimensions: ::std::option::Option<
+    pub SetDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *mut OrtTensorTypeAndShapeInfo,
             dim_values: *const i64,
             dim_count: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorElementType: ::std::option::Option<
+    pub GetTensorElementType: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub GetDimensionsCount: ::std::option::Option<
+    pub GetDimensionsCount: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetDimensions: ::std::option::Option<
+    pub GetDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             dim_values: *mut i64,
             dim_values_length: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetSymbolicDimensions: ::std::option::Option<
+    pub GetSymbolicDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
-            dim_params: *mut *const ::std::os::raw::c_char,
+            dim_params: *mut *const ::core::ffi::c_char,
             dim_params_length: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorShapeElementCount: ::std::option::Option<
+    pub GetTensorShapeElementCount: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorTypeAndShape: ::std::option::Option<
+    pub GetTensorTypeAndShape: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetTypeInfo: ::std::option::Option<
+    pub GetTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut *mut OrtTypeInfo) -> OrtStatusPtr,
     >,
-    pub GetValueType: ::std::option::Option<
+    pub GetValueType: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut ONNXType) -> OrtStatusPtr,
     >,
-    pub CreateMemoryInfo: ::std::option::Option<
+    pub CreateMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             type_: OrtAllocatorType,
-            id: ::std::os::raw::c_int,
+            id: ::core::ffi::c_int,
             mem_type: OrtMemType,
             out: *mut *mut OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCpuMemoryInfo: ::std::option::Option<
+    pub CreateCpuMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_: OrtAllocatorType,
             mem_type: OrtMemType,
             out: *mut *mut OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CompareMemoryInfo: ::std::option::Option<
+    pub CompareMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info1: *const OrtMemoryInfo,
             info2: *const OrtMemoryInfo,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetName: ::std::option::Option<
+    pub MemoryInfoGetName: ::core::option::Option<
         unsafe extern "C" fn(
             ptr: *const OrtMemoryInfo,
-            out: *mut *const ::std::os::raw::c_char,
+            out: *mut *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetId: ::std::option::Option<
+    pub MemoryInfoGetId: ::core::option::Option<
         unsafe extern "C" fn(
             ptr: *const OrtMemoryInfo,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetMemType: ::std::option::Option<
+    pub MemoryInfoGetMemType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtMemType) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetType: ::std::option::Option<
+    pub MemoryInfoGetType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtAllocatorType) -> OrtStatusPtr,
     >,
-    pub AllocatorAlloc: ::std::option::Option<
+    pub AllocatorAlloc: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *mut OrtAllocator,
             size: usize,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub AllocatorFree: ::std::option::Option<
+    pub AllocatorFree: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *mut OrtAllocator,
-            p: *mut ::std::os::raw::c_void,
+            p: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub AllocatorGetInfo: ::std::option::Option<
+    pub AllocatorGetInfo: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *const OrtAllocator,
             out: *mut *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
     pub GetAllocatorWithDefaultOptions:
-        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtAllocator) -> OrtStatusPtr>,
-    pub AddFreeDimensionOverride: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(out: *mut *mut OrtAllocator) -> OrtStatusPtr>,
+    pub AddFreeDimensionOverride: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            dim_denotation: *const ::std::os::raw::c_char,
+            dim_denotation: *const ::core::ffi::c_char,
             dim_value: i64,
         ) -> OrtStatusPtr,
     >,
-    pub GetValue: ::std::option::Option<
+    pub GetValue: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            index: ::std::os::raw::c_int,
+            index: ::core::ffi::c_int,
             allocator: *mut OrtAllocator,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub GetValueCount: ::std::option::Option<
+    pub GetValueCount: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub CreateValue: ::std::option::Option<
+    pub CreateValue: ::core::option::Option<
         unsafe extern "C" fn(
             in_: *const *const OrtValue,
             num_values: usize,
@@ -17870,60 +17870,60 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateOpaqueValue: ::std::option::Option<
+    pub CreateOpaqueValue: ::core::option::Option<
         unsafe extern "C" fn(
-            domain_name: *const ::std::os::raw::c_char,
-            type_name: *const ::std::os::raw::c_char,
-            data_container: *const ::std::os::raw::c_void,
+            domain_name: *const ::core::ffi::c_char,
+            type_name: *const ::core::ffi::c_char,
+            data_container: *const ::core::ffi::c_void,
             data_container_size: usize,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub GetOpaqueValue: ::std::option::Option<
+    pub GetOpaqueValue: ::core::option::Option<
         unsafe extern "C" fn(
-            domain_name: *const ::std::os::raw::c_char,
-            type_name: *const ::std::os::raw::c_char,
+            domain_name: *const ::core::ffi::c_char,
+            type_name: *const ::core::ffi::c_char,
             in_: *const OrtValue,
-            data_container: *mut ::std::os::raw::c_void,
+            data_container: *mut ::core::ffi::c_void,
             data_container_size: usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_float: ::std::option::Option<
+    pub KernelInfoGetAttribute_float: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut f32,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_int64: ::std::option::Option<
+    pub KernelInfoGetAttribute_int64: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut i64,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_string: ::std::option::Option<
+    pub KernelInfoGetAttribute_string: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
-            out: *mut ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetInputCount: ::std::option::Option<
+    pub KernelContext_GetInputCount: ::core::option::Option<
         unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetOutputCount: ::std::option::Option<
+    pub KernelContext_GetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetInput: ::std::option::Option<
+    pub KernelContext_GetInput: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             index: usize,
             out: *mut *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetOutput: ::std::option::Option<
+    pub KernelContext_GetOutput: ::core::option::Option<
         unsafe extern "C" fn(
             context: *mut OrtKernelContext,
             index: usize,
@@ -17932,234 +17932,234 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseEnv: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtEnv)>,
-    pub ReleaseStatus: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtStatus)>,
-    pub ReleaseMemoryInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMemoryInfo)>,
-    pub ReleaseSession: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSession)>,
-    pub ReleaseValue: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtValue)>,
-    pub ReleaseRunOptions: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtRunOptions)>,
-    pub ReleaseTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTypeInfo)>,
+    pub ReleaseEnv: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtEnv)>,
+    pub ReleaseStatus: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtStatus)>,
+    pub ReleaseMemoryInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtMemoryInfo)>,
+    pub ReleaseSession: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSession)>,
+    pub ReleaseValue: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtValue)>,
+    pub ReleaseRunOptions: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtRunOptions)>,
+    pub ReleaseTypeInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTypeInfo)>,
     pub ReleaseTensorTypeAndShapeInfo:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorTypeAndShapeInfo)>,
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTensorTypeAndShapeInfo)>,
     pub ReleaseSessionOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSessionOptions)>,
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSessionOptions)>,
     pub ReleaseCustomOpDomain:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCustomOpDomain)>,
-    pub GetDenotationFromTypeInfo: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCustomOpDomain)>,
+    pub GetDenotationFromTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
-            denotation: *mut *const ::std::os::raw::c_char,
+            denotation: *mut *const ::core::ffi::c_char,
             len: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToMapTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToMapTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtMapTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToSequenceTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToSequenceTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtSequenceTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetMapKeyType: ::std::option::Option<
+    pub GetMapKeyType: ::core::option::Option<
         unsafe extern "C" fn(
             map_type_info: *const OrtMapTypeInfo,
             out: *mut ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub GetMapValueType: ::std::option::Option<
+    pub GetMapValueType: ::core::option::Option<
         unsafe extern "C" fn(
             map_type_info: *const OrtMapTypeInfo,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSequenceElementType: ::std::option::Option<
+    pub GetSequenceElementType: ::core::option::Option<
         unsafe extern "C" fn(
             sequence_type_info: *const OrtSequenceTypeInfo,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseMapTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMapTypeInfo)>,
+    pub ReleaseMapTypeInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtMapTypeInfo)>,
     pub ReleaseSequenceTypeInfo:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSequenceTypeInfo)>,
-    pub SessionEndProfiling: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSequenceTypeInfo)>,
+    pub SessionEndProfiling: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             allocator: *mut OrtAllocator,
-            out: *mut *mut ::std::os::raw::c_char,
+            out: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetModelMetadata: ::std::option::Option<
+    pub SessionGetModelMetadata: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             out: *mut *mut OrtModelMetadata,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetProducerName: ::std::option::Option<
+    pub ModelMetadataGetProducerName: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetGraphName: ::std::option::Option<
+    pub ModelMetadataGetGraphName: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetDomain: ::std::option::Option<
+    pub ModelMetadataGetDomain: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetDescription: ::std::option::Option<
+    pub ModelMetadataGetDescription: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataLookupCustomMetadataMap: ::std::option::Option<
+    pub ModelMetadataLookupCustomMetadataMap: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            key: *const ::std::os::raw::c_char,
-            value: *mut *mut ::std::os::raw::c_char,
+            key: *const ::core::ffi::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetVersion: ::std::option::Option<
+    pub ModelMetadataGetVersion: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             value: *mut i64,
         ) -> OrtStatusPtr,
     >,
     pub ReleaseModelMetadata:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtModelMetadata)>,
-    pub CreateEnvWithGlobalThreadPools: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtModelMetadata)>,
+    pub CreateEnvWithGlobalThreadPools: ::core::option::Option<
         unsafe extern "C" fn(
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             tp_options: *const OrtThreadingOptions,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub DisablePerSessionThreads: ::std::option::Option<
+    pub DisablePerSessionThreads: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub CreateThreadingOptions: ::std::option::Option<
+    pub CreateThreadingOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtThreadingOptions) -> OrtStatusPtr,
     >,
     pub ReleaseThreadingOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtThreadingOptions)>,
-    pub ModelMetadataGetCustomMetadataMapKeys: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtThreadingOptions)>,
+    pub ModelMetadataGetCustomMetadataMapKeys: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            keys: *mut *mut *mut ::std::os::raw::c_char,
+            keys: *mut *mut *mut ::core::ffi::c_char,
             num_keys: *mut i64,
         ) -> OrtStatusPtr,
     >,
-    pub AddFreeDimensionOverrideByName: ::std::option::Option<
+    pub AddFreeDimensionOverrideByName: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            dim_name: *const ::std::os::raw::c_char,
+            dim_name: *const ::core::ffi::c_char,
             dim_value: i64,
         ) -> OrtStatusPtr,
     >,
-    pub GetAvailableProviders: ::std::option::Option<
+    pub GetAvailableProviders: ::core::option::Option<
         unsafe extern "C" fn(
-            out_ptr: *mut *mut *mut ::std::os::raw::c_char,
-            provider_length: *mut ::std::os::raw::c_int,
+            out_ptr: *mut *mut *mut ::core::ffi::c_char,
+            provider_length: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseAvailableProviders: ::std::option::Option<
+    pub ReleaseAvailableProviders: ::core::option::Option<
         unsafe extern "C" fn(
-            ptr: *mut *mut ::std::os::raw::c_char,
-            providers_length: ::std::os::raw::c_int,
+            ptr: *mut *mut ::core::ffi::c_char,
+            providers_length: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetStringTensorElementLength: ::std::option::Option<
+    pub GetStringTensorElementLength: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, index: usize, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub GetStringTensorElement: ::std::option::Option<
+    pub GetStringTensorElement: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             s_len: usize,
             index: usize,
-            s: *mut ::std::os::raw::c_void,
+            s: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub FillStringTensorElement: ::std::option::Option<
+    pub FillStringTensorElement: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            s: *const ::std::os::raw::c_char,
+            s: *const ::core::ffi::c_char,
             index: usize,
         ) -> OrtStatusPtr,
     >,
-    pub AddSessionConfigEntry: ::std::option::Option<
+    pub AddSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *const ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CreateAllocator: ::std::option::Option<
+    pub CreateAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             mem_info: *const OrtMemoryInfo,
             out: *mut *mut OrtAllocator,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseAllocator: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtAllocator)>,
-    pub RunWithBinding: ::std::option::Option<
+    pub ReleaseAllocator: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtAllocator)>,
+    pub RunWithBinding: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             run_options: *const OrtRunOptions,
             binding_ptr: *const OrtIoBinding,
         ) -> OrtStatusPtr,
     >,
-    pub CreateIoBinding: ::std::option::Option<
+    pub CreateIoBinding: ::core::option::Option<
         unsafe extern "C" fn(session: *mut OrtSession, out: *mut *mut OrtIoBinding) -> OrtStatusPtr,
     >,
-    pub ReleaseIoBinding: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtIoBinding)>,
-    pub BindInput: ::std::option::Option<
+    pub ReleaseIoBinding: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtIoBinding)>,
+    pub BindInput: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val_ptr: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub BindOutput: ::std::option::Option<
+    pub BindOutput: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val_ptr: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub BindOutputToDevice: ::std::option::Option<
+    pub BindOutputToDevice: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             mem_info_ptr: *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetBoundOutputNames: ::std::option::Option<
+    pub GetBoundOutputNames: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *const OrtIoBinding,
             allocator: *mut OrtAllocator,
-            buffer: *mut *mut ::std::os::raw::c_char,
+            buffer: *mut *mut ::core::ffi::c_char,
             lengths: *mut *mut usize,
             count: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetBoundOutputValues: ::std::option::Option<
+    pub GetBoundOutputValues: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *const OrtIoBinding,
             allocator: *mut OrtAllocator,
@@ -18169,219 +18169,219 @@ pub struct OrtApi {
     >,
     #[doc = " \\brief Clears any previously set Inputs for an ::OrtIoBinding"]
     pub ClearBoundInputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
     #[doc = " \\brief Clears any previously set Outputs for an ::OrtIoBinding"]
     pub ClearBoundOutputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
-    pub TensorAt: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+    pub TensorAt: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
             location_values: *const i64,
             location_values_count: usize,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub CreateAndRegisterAllocator: ::std::option::Option<
+    pub CreateAndRegisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             env: *mut OrtEnv,
             mem_info: *const OrtMemoryInfo,
             arena_cfg: *const OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub SetLanguageProjection: ::std::option::Option<
+    pub SetLanguageProjection: ::core::option::Option<
         unsafe extern "C" fn(
             ort_env: *const OrtEnv,
             projection: OrtLanguageProjection,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetProfilingStartTimeNs: ::std::option::Option<
+    pub SessionGetProfilingStartTimeNs: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut u64) -> OrtStatusPtr,
     >,
-    pub SetGlobalIntraOpNumThreads: ::std::option::Option<
+    pub SetGlobalIntraOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            intra_op_num_threads: ::std::os::raw::c_int,
+            intra_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalInterOpNumThreads: ::std::option::Option<
+    pub SetGlobalInterOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            inter_op_num_threads: ::std::os::raw::c_int,
+            inter_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalSpinControl: ::std::option::Option<
+    pub SetGlobalSpinControl: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            allow_spinning: ::std::os::raw::c_int,
+            allow_spinning: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub AddInitializer: ::std::option::Option<
+    pub AddInitializer: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateEnvWithCustomLoggerAndGlobalThreadPools: ::std::option::Option<
+    pub CreateEnvWithCustomLoggerAndGlobalThreadPools: ::core::option::Option<
         unsafe extern "C" fn(
             logging_function: OrtLoggingFunction,
-            logger_param: *mut ::std::os::raw::c_void,
+            logger_param: *mut ::core::ffi::c_void,
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             tp_options: *const OrtThreadingOptions,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_CUDA: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_CUDA: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cuda_options: *const OrtCUDAProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_ROCM: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_ROCM: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             rocm_options: *const OrtROCMProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_OpenVINO: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_OpenVINO: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             provider_options: *const OrtOpenVINOProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalDenormalAsZero: ::std::option::Option<
+    pub SetGlobalDenormalAsZero: ::core::option::Option<
         unsafe extern "C" fn(tp_options: *mut OrtThreadingOptions) -> OrtStatusPtr,
     >,
-    pub CreateArenaCfg: ::std::option::Option<
+    pub CreateArenaCfg: ::core::option::Option<
         unsafe extern "C" fn(
             max_mem: usize,
-            arena_extend_strategy: ::std::os::raw::c_int,
-            initial_chunk_size_bytes: ::std::os::raw::c_int,
-            max_dead_bytes_per_chunk: ::std::os::raw::c_int,
+            arena_extend_strategy: ::core::ffi::c_int,
+            initial_chunk_size_bytes: ::core::ffi::c_int,
+            max_dead_bytes_per_chunk: ::core::ffi::c_int,
             out: *mut *mut OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseArenaCfg: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtArenaCfg)>,
-    pub ModelMetadataGetGraphDescription: ::std::option::Option<
+    pub ReleaseArenaCfg: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtArenaCfg)>,
+    pub ModelMetadataGetGraphDescription: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_TensorRT: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_TensorRT: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             tensorrt_options: *const OrtTensorRTProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetCurrentGpuDeviceId: ::std::option::Option<
-        unsafe extern "C" fn(device_id: ::std::os::raw::c_int) -> OrtStatusPtr,
+    pub SetCurrentGpuDeviceId: ::core::option::Option<
+        unsafe extern "C" fn(device_id: ::core::ffi::c_int) -> OrtStatusPtr,
     >,
-    pub GetCurrentGpuDeviceId: ::std::option::Option<
-        unsafe extern "C" fn(device_id: *mut ::std::os::raw::c_int) -> OrtStatusPtr,
+    pub GetCurrentGpuDeviceId: ::core::option::Option<
+        unsafe extern "C" fn(device_id: *mut ::core::ffi::c_int) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttributeArray_float: ::std::option::Option<
+    pub KernelInfoGetAttributeArray_float: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut f32,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttributeArray_int64: ::std::option::Option<
+    pub KernelInfoGetAttributeArray_int64: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut i64,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub CreateArenaCfgV2: ::std::option::Option<
+    pub CreateArenaCfgV2: ::core::option::Option<
         unsafe extern "C" fn(
-            arena_config_keys: *const *const ::std::os::raw::c_char,
+            arena_config_keys: *const *const ::core::ffi::c_char,
             arena_config_values: *const usize,
             num_keys: usize,
             out: *mut *mut OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub AddRunConfigEntry: ::std::option::Option<
+    pub AddRunConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *const ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CreatePrepackedWeightsContainer: ::std::option::Option<
+    pub CreatePrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtPrepackedWeightsContainer) -> OrtStatusPtr,
     >,
     pub ReleasePrepackedWeightsContainer:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtPrepackedWeightsContainer)>,
-    pub CreateSessionWithPrepackedWeightsContainer: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtPrepackedWeightsContainer)>,
+    pub CreateSessionWithPrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_path: *const ::std::os::raw::c_char,
+            model_path: *const ::core::ffi::c_char,
             options: *const OrtSessionOptions,
             prepacked_weights_container: *mut OrtPrepackedWeightsContainer,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionFromArrayWithPrepackedWeightsContainer: ::std::option::Option<
+    pub CreateSessionFromArrayWithPrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_data: *const ::std::os::raw::c_void,
+            model_data: *const ::core::ffi::c_void,
             model_data_length: usize,
             options: *const OrtSessionOptions,
             prepacked_weights_container: *mut OrtPrepackedWeightsContainer,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_TensorRT_V2: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_TensorRT_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             tensorrt_options: *const OrtTensorRTProviderOptionsV2,
         ) -> OrtStatusPtr,
     >,
-    pub CreateTensorRTProviderOptions: ::std::option::Option<
+    pub CreateTensorRTProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtTensorRTProviderOptionsV2) -> OrtStatusPtr,
     >,
-    pub UpdateTensorRTProviderOptions: ::std::option::Option<
+    pub UpdateTensorRTProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             tensorrt_options: *mut OrtTensorRTProviderOptionsV2,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorRTProviderOptionsAsString: ::std::option::Option<
+    pub GetTensorRTProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             tensorrt_options: *const OrtTensorRTProviderOptionsV2,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtTensorRTProviderOptionsV2"]
     #[doc = ""]
     #[doc = " \\note This is an exception in the naming convention of other Release* functions, as the name of the method does not have the V2 suffix, but the type does"]
     pub ReleaseTensorRTProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorRTProviderOptionsV2)>,
-    pub EnableOrtCustomOps: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTensorRTProviderOptionsV2)>,
+    pub EnableOrtCustomOps: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub RegisterAllocator: ::std::option::Option<
+    pub RegisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(env: *mut OrtEnv, allocator: *mut OrtAllocator) -> OrtStatusPtr,
     >,
-    pub UnregisterAllocator: ::std::option::Option<
+    pub UnregisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(env: *mut OrtEnv, mem_info: *const OrtMemoryInfo) -> OrtStatusPtr,
     >,
-    pub IsSparseTensor: ::std::option::Option<
+    pub IsSparseTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSparseTensorAsOrtValue: ::std::option::Option<
+    pub CreateSparseTensorAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             allocator: *mut OrtAllocator,
             dense_shape: *const i64,
@@ -18390,46 +18390,46 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorCoo: ::std::option::Option<
+    pub FillSparseTensorCoo: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             indices_data: *const i64,
             indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorCsr: ::std::option::Option<
+    pub FillSparseTensorCsr: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             inner_indices_data: *const i64,
             inner_indices_num: usize,
             outer_indices_data: *const i64,
             outer_indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorBlockSparse: ::std::option::Option<
+    pub FillSparseTensorBlockSparse: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             indices_shape_data: *const i64,
             indices_shape_len: usize,
             indices_data: *const i32,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSparseTensorWithValuesAsOrtValue: ::std::option::Option<
+    pub CreateSparseTensorWithValuesAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtMemoryInfo,
-            p_data: *mut ::std::os::raw::c_void,
+            p_data: *mut ::core::ffi::c_void,
             dense_shape: *const i64,
             dense_shape_len: usize,
             values_shape: *const i64,
@@ -18438,14 +18438,14 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub UseCooIndices: ::std::option::Option<
+    pub UseCooIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             indices_data: *mut i64,
             indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub UseCsrIndices: ::std::option::Option<
+    pub UseCsrIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             inner_data: *mut i64,
@@ -18454,7 +18454,7 @@ pub struct OrtApi {
             outer_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub UseBlockSparseIndices: ::std::option::Option<
+    pub UseBlockSparseIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             indices_shape: *const i64,
@@ -18462,123 +18462,123 @@ pub struct OrtApi {
             indices_data: *mut i32,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorFormat: ::std::option::Option<
+    pub GetSparseTensorFormat: ::core::option::Option<
         unsafe extern "C" fn(ort_value: *const OrtValue, out: *mut OrtSparseFormat) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorValuesTypeAndShape: ::std::option::Option<
+    pub GetSparseTensorValuesTypeAndShape: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorValues: ::std::option::Option<
+    pub GetSparseTensorValues: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
-            out: *mut *const ::std::os::raw::c_void,
+            out: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorIndicesTypeShape: ::std::option::Option<
+    pub GetSparseTensorIndicesTypeShape: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             indices_format: OrtSparseIndicesFormat,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorIndices: ::std::option::Option<
+    pub GetSparseTensorIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             indices_format: OrtSparseIndicesFormat,
             num_indices: *mut usize,
-            indices: *mut *const ::std::os::raw::c_void,
+            indices: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub HasValue: ::std::option::Option<
+    pub HasValue: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetGPUComputeStream: ::std::option::Option<
+    pub KernelContext_GetGPUComputeStream: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorMemoryInfo: ::std::option::Option<
+    pub GetTensorMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             mem_info: *mut *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetExecutionProviderApi: ::std::option::Option<
+    pub GetExecutionProviderApi: ::core::option::Option<
         unsafe extern "C" fn(
-            provider_name: *const ::std::os::raw::c_char,
+            provider_name: *const ::core::ffi::c_char,
             version: u32,
-            provider_api: *mut *const ::std::os::raw::c_void,
+            provider_api: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomCreateThreadFn: ::std::option::Option<
+    pub SessionOptionsSetCustomCreateThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomThreadCreationOptions: ::std::option::Option<
+    pub SessionOptionsSetCustomThreadCreationOptions: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+            ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomJoinThreadFn: ::std::option::Option<
+    pub SessionOptionsSetCustomJoinThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomCreateThreadFn: ::std::option::Option<
+    pub SetGlobalCustomCreateThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
             ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomThreadCreationOptions: ::std::option::Option<
+    pub SetGlobalCustomThreadCreationOptions: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+            ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomJoinThreadFn: ::std::option::Option<
+    pub SetGlobalCustomJoinThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
             ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
         ) -> OrtStatusPtr,
     >,
     pub SynchronizeBoundInputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
     pub SynchronizeBoundOutputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
-    pub SessionOptionsAppendExecutionProvider_CUDA_V2: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+    pub SessionOptionsAppendExecutionProvider_CUDA_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cuda_options: *const OrtCUDAProviderOptionsV2,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCUDAProviderOptions: ::std::option::Option<
+    pub CreateCUDAProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtCUDAProviderOptionsV2) -> OrtStatusPtr,
     >,
-    pub UpdateCUDAProviderOptions: ::std::option::Option<
+    pub UpdateCUDAProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             cuda_options: *mut OrtCUDAProviderOptionsV2,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetCUDAProviderOptionsAsString: ::std::option::Option<
+    pub GetCUDAProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             cuda_options: *const OrtCUDAProviderOptionsV2,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtCUDAProviderOptionsV2"]
@@ -18587,74 +18587,74 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.11."]
     pub ReleaseCUDAProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCUDAProviderOptionsV2)>,
-    pub SessionOptionsAppendExecutionProvider_MIGraphX: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCUDAProviderOptionsV2)>,
+    pub SessionOptionsAppendExecutionProvider_MIGraphX: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             migraphx_options: *const OrtMIGraphXProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub AddExternalInitializers: ::std::option::Option<
+    pub AddExternalInitializers: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            initializer_names: *const *const ::std::os::raw::c_char,
+            initializer_names: *const *const ::core::ffi::c_char,
             initializers: *const *const OrtValue,
             initializers_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CreateOpAttr: ::std::option::Option<
+    pub CreateOpAttr: ::core::option::Option<
         unsafe extern "C" fn(
-            name: *const ::std::os::raw::c_char,
-            data: *const ::std::os::raw::c_void,
-            len: ::std::os::raw::c_int,
+            name: *const ::core::ffi::c_char,
+            data: *const ::core::ffi::c_void,
+            len: ::core::ffi::c_int,
             type_: OrtOpAttrType,
             op_attr: *mut *mut OrtOpAttr,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseOpAttr: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOpAttr)>,
-    pub CreateOp: ::std::option::Option<
+    pub ReleaseOpAttr: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtOpAttr)>,
+    pub CreateOp: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            op_name: *const ::std::os::raw::c_char,
-            domain: *const ::std::os::raw::c_char,
-            version: ::std::os::raw::c_int,
-            type_constraint_names: *mut *const ::std::os::raw::c_char,
+            op_name: *const ::core::ffi::c_char,
+            domain: *const ::core::ffi::c_char,
+            version: ::core::ffi::c_int,
+            type_constraint_names: *mut *const ::core::ffi::c_char,
             type_constraint_values: *const ONNXTensorElementDataType,
-            type_constraint_count: ::std::os::raw::c_int,
+            type_constraint_count: ::core::ffi::c_int,
             attr_values: *const *const OrtOpAttr,
-            attr_count: ::std::os::raw::c_int,
-            input_count: ::std::os::raw::c_int,
-            output_count: ::std::os::raw::c_int,
+            attr_count: ::core::ffi::c_int,
+            input_count: ::core::ffi::c_int,
+            output_count: ::core::ffi::c_int,
             ort_op: *mut *mut OrtOp,
         ) -> OrtStatusPtr,
     >,
-    pub InvokeOp: ::std::option::Option<
+    pub InvokeOp: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             ort_op: *const OrtOp,
             input_values: *const *const OrtValue,
-            input_count: ::std::os::raw::c_int,
+            input_count: ::core::ffi::c_int,
             output_values: *const *mut OrtValue,
-            output_count: ::std::os::raw::c_int,
+            output_count: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseOp: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOp)>,
-    pub SessionOptionsAppendExecutionProvider: ::std::option::Option<
+    pub ReleaseOp: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtOp)>,
+    pub SessionOptionsAppendExecutionProvider: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            provider_name: *const ::std::os::raw::c_char,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_name: *const ::core::ffi::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CopyKernelInfo: ::std::option::Option<
+    pub CopyKernelInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             info_copy: *mut *mut OrtKernelInfo,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseKernelInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtKernelInfo)>,
+    pub ReleaseKernelInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtKernelInfo)>,
     #[doc = " \\name Ort Training"]
     #[doc = " @{"]
     #[doc = "** \\brief Gets the Training C Api struct"]
@@ -18672,29 +18672,29 @@ pub struct OrtApi {
     #[doc = "* \\since Version 1.13"]
     #[doc = "*/"]
     pub GetTrainingApi:
-        ::std::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtTrainingApi>,
-    pub SessionOptionsAppendExecutionProvider_CANN: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtTrainingApi>,
+    pub SessionOptionsAppendExecutionProvider_CANN: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cann_options: *const OrtCANNProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCANNProviderOptions: ::std::option::Option<
+    pub CreateCANNProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtCANNProviderOptions) -> OrtStatusPtr,
     >,
-    pub UpdateCANNProviderOptions: ::std::option::Option<
+    pub UpdateCANNProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             cann_options: *mut OrtCANNProviderOptions,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetCANNProviderOptionsAsString: ::std::option::Option<
+    pub GetCANNProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             cann_options: *const OrtCANNProviderOptions,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an OrtCANNProviderOptions"]
@@ -18703,183 +18703,183 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.13."]
     pub ReleaseCANNProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCANNProviderOptions)>,
-    pub MemoryInfoGetDeviceType: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCANNProviderOptions)>,
+    pub MemoryInfoGetDeviceType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtMemoryInfoDeviceType),
     >,
-    pub UpdateEnvWithCustomLogLevel: ::std::option::Option<
+    pub UpdateEnvWithCustomLogLevel: ::core::option::Option<
         unsafe extern "C" fn(
             ort_env: *mut OrtEnv,
             log_severity_level: OrtLoggingLevel,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalIntraOpThreadAffinity: ::std::option::Option<
+    pub SetGlobalIntraOpThreadAffinity: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            affinity_string: *const ::std::os::raw::c_char,
+            affinity_string: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsLibrary_V2: ::std::option::Option<
+    pub RegisterCustomOpsLibrary_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            library_name: *const ::std::os::raw::c_char,
+            library_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsUsingFunction: ::std::option::Option<
+    pub RegisterCustomOpsUsingFunction: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            registration_func_name: *const ::std::os::raw::c_char,
+            registration_func_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputCount: ::std::option::Option<
+    pub KernelInfo_GetInputCount: ::core::option::Option<
         unsafe extern "C" fn(info: *const OrtKernelInfo, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputCount: ::std::option::Option<
+    pub KernelInfo_GetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(info: *const OrtKernelInfo, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputName: ::std::option::Option<
+    pub KernelInfo_GetInputName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputName: ::std::option::Option<
+    pub KernelInfo_GetOutputName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputTypeInfo: ::std::option::Option<
+    pub KernelInfo_GetInputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputTypeInfo: ::std::option::Option<
+    pub KernelInfo_GetOutputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_tensor: ::std::option::Option<
+    pub KernelInfoGetAttribute_tensor: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             allocator: *mut OrtAllocator,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub HasSessionConfigEntry: ::std::option::Option<
+    pub HasSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            out: *mut ::std::os::raw::c_int,
+            config_key: *const ::core::ffi::c_char,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetSessionConfigEntry: ::std::option::Option<
+    pub GetSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *mut ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_Dnnl: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_Dnnl: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             dnnl_options: *const OrtDnnlProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub CreateDnnlProviderOptions: ::std::option::Option<
+    pub CreateDnnlProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtDnnlProviderOptions) -> OrtStatusPtr,
     >,
-    pub UpdateDnnlProviderOptions: ::std::option::Option<
+    pub UpdateDnnlProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             dnnl_options: *mut OrtDnnlProviderOptions,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetDnnlProviderOptionsAsString: ::std::option::Option<
+    pub GetDnnlProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             dnnl_options: *const OrtDnnlProviderOptions,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtDnnlProviderOptions"]
     #[doc = ""]
     #[doc = " \\since Version 1.15."]
     pub ReleaseDnnlProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtDnnlProviderOptions)>,
-    pub KernelInfo_GetNodeName: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtDnnlProviderOptions)>,
+    pub KernelInfo_GetNodeName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetLogger: ::std::option::Option<
+    pub KernelInfo_GetLogger: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             logger: *mut *const OrtLogger,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetLogger: ::std::option::Option<
+    pub KernelContext_GetLogger: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             logger: *mut *const OrtLogger,
         ) -> OrtStatusPtr,
     >,
-    pub Logger_LogMessage: ::std::option::Option<
+    pub Logger_LogMessage: ::core::option::Option<
         unsafe extern "C" fn(
             logger: *const OrtLogger,
             log_severity_level: OrtLoggingLevel,
-            message: *const ::std::os::raw::c_char,
-            file_path: *const ::std::os::raw::c_char,
-            line_number: ::std::os::raw::c_int,
-            func_name: *const ::std::os::raw::c_char,
+            message: *const ::core::ffi::c_char,
+            file_path: *const ::core::ffi::c_char,
+            line_number: ::core::ffi::c_int,
+            func_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub Logger_GetLoggingSeverityLevel: ::std::option::Option<
+    pub Logger_GetLoggingSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(logger: *const OrtLogger, out: *mut OrtLoggingLevel) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetConstantInput_tensor: ::std::option::Option<
+    pub KernelInfoGetConstantInput_tensor: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            is_constant: *mut ::std::os::raw::c_int,
+            is_constant: *mut ::core::ffi::c_int,
             out: *mut *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToOptionalTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToOptionalTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtOptionalTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetOptionalContainedTypeInfo: ::std::option::Option<
+    pub GetOptionalContainedTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             optional_type_info: *const OrtOptionalTypeInfo,
             out: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetResizedStringTensorElementBuffer: ::std::option::Option<
+    pub GetResizedStringTensorElementBuffer: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
             index: usize,
             length_in_bytes: usize,
-            buffer: *mut *mut ::std::os::raw::c_char,
+            buffer: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetAllocator: ::std::option::Option<
+    pub KernelContext_GetAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             mem_info: *const OrtMemoryInfo,
@@ -18892,26 +18892,26 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.15."]
     pub GetBuildInfoString:
-        ::std::option::Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+        ::core::option::Option<unsafe extern "C" fn() -> *const ::core::ffi::c_char>,
 }
 #[test]
 fn bindgen_test_layout_OrtApi() {
     assert_eq!(
-        ::std::mem::size_of::<OrtApi>(),
+        ::core::mem::size_of::<OrtApi>(),
         2040usize,
         concat!("Size of: ", stringify!(OrtApi))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtApi>(),
+        ::core::mem::align_of::<OrtApi>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtApi))
     );
     fn test_field_CreateStatus() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateStatus) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateStatus) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -18926,9 +18926,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetErrorCode() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetErrorCode) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetErrorCode) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -18943,9 +18943,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetErrorMessage() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetErrorMessage) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetErrorMessage) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -18960,9 +18960,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnv) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -18977,9 +18977,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithCustomLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithCustomLogger) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -18994,9 +18994,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableTelemetryEvents() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableTelemetryEvents) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableTelemetryEvents) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -19011,9 +19011,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableTelemetryEvents() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableTelemetryEvents) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableTelemetryEvents) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -19028,9 +19028,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSession() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSession) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSession) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -19045,9 +19045,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionFromArray() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionFromArray) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionFromArray) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -19062,9 +19062,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Run() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Run) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Run) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -19079,9 +19079,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionOptions) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -19096,9 +19096,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetOptimizedModelFilePath() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetOptimizedModelFilePath) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetOptimizedModelFilePath) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -19113,9 +19113,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CloneSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CloneSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CloneSessionOptions) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -19130,9 +19130,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionExecutionMode() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionExecutionMode) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionExecutionMode) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -19147,9 +19147,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableProfiling) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -19164,9 +19164,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableProfiling) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -19181,9 +19181,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableMemPattern() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableMemPattern) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableMemPattern) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -19198,9 +19198,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableMemPattern() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableMemPattern) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableMemPattern) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -19215,9 +19215,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableCpuMemArena() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableCpuMemArena) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableCpuMemArena) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -19232,9 +19232,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableCpuMemArena() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableCpuMemArena) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableCpuMemArena) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -19249,9 +19249,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogId) as usize - ptr as usize
             },
             160usize,
             concat!(
@@ -19266,9 +19266,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogVerbosityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogVerbosityLevel) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -19283,9 +19283,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogSeverityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogSeverityLevel) as usize - ptr as usize
             },
             176usize,
             concat!(
@@ -19300,9 +19300,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionGraphOptimizationLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionGraphOptimizationLevel) as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionGraphOptimizationLevel) as usize
                     - ptr as usize
             },
             184usize,
@@ -19318,9 +19318,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetIntraOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetIntraOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetIntraOpNumThreads) as usize - ptr as usize
             },
             192usize,
             concat!(
@@ -19335,9 +19335,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetInterOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetInterOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetInterOpNumThreads) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -19352,9 +19352,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCustomOpDomain) as usize - ptr as usize
             },
             208usize,
             concat!(
@@ -19369,9 +19369,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CustomOpDomain_Add() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CustomOpDomain_Add) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CustomOpDomain_Add) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -19386,9 +19386,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddCustomOpDomain) as usize - ptr as usize
             },
             224usize,
             concat!(
@@ -19403,9 +19403,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsLibrary() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -19420,9 +19420,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputCount) as usize - ptr as usize
             },
             240usize,
             concat!(
@@ -19437,9 +19437,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputCount) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -19454,9 +19454,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerCount) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerCount) as usize
                     - ptr as usize
             },
             256usize,
@@ -19472,9 +19472,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputTypeInfo) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -19489,9 +19489,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputTypeInfo) as usize - ptr as usize
             },
             272usize,
             concat!(
@@ -19506,9 +19506,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerTypeInfo) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerTypeInfo) as usize
                     - ptr as usize
             },
             280usize,
@@ -19524,9 +19524,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputName) as usize - ptr as usize
             },
             288usize,
             concat!(
@@ -19541,9 +19541,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputName) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -19558,9 +19558,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerName) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerName) as usize
                     - ptr as usize
             },
             304usize,
@@ -19576,9 +19576,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateRunOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateRunOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateRunOptions) as usize - ptr as usize
             },
             312usize,
             concat!(
@@ -19593,9 +19593,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogVerbosityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunLogVerbosityLevel) as usize
                     - ptr as usize
             },
             320usize,
@@ -19611,9 +19611,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogSeverityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunLogSeverityLevel) as usize
                     - ptr as usize
             },
             328usize,
@@ -19629,9 +19629,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunTag() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunTag) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunTag) as usize - ptr as usize
             },
             336usize,
             concat!(
@@ -19646,9 +19646,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogVerbosityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunLogVerbosityLevel) as usize
                     - ptr as usize
             },
             344usize,
@@ -19664,9 +19664,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogSeverityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunLogSeverityLevel) as usize
                     - ptr as usize
             },
             352usize,
@@ -19682,9 +19682,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunTag() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunTag) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunTag) as usize - ptr as usize
             },
             360usize,
             concat!(
@@ -19699,9 +19699,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetTerminate() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetTerminate) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetTerminate) as usize - ptr as usize
             },
             368usize,
             concat!(
@@ -19716,9 +19716,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsUnsetTerminate() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsUnsetTerminate) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsUnsetTerminate) as usize - ptr as usize
             },
             376usize,
             concat!(
@@ -19733,9 +19733,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorAsOrtValue) as usize - ptr as usize
             },
             384usize,
             concat!(
@@ -19750,9 +19750,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorWithDataAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorWithDataAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorWithDataAsOrtValue) as usize - ptr as usize
             },
             392usize,
             concat!(
@@ -19767,9 +19767,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_IsTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).IsTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).IsTensor) as usize - ptr as usize
             },
             400usize,
             concat!(
@@ -19784,9 +19784,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorMutableData() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorMutableData) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorMutableData) as usize - ptr as usize
             },
             408usize,
             concat!(
@@ -19801,9 +19801,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillStringTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillStringTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillStringTensor) as usize - ptr as usize
             },
             416usize,
             concat!(
@@ -19818,9 +19818,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorDataLength() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorDataLength) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorDataLength) as usize - ptr as usize
             },
             424usize,
             concat!(
@@ -19835,9 +19835,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorContent() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorContent) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorContent) as usize - ptr as usize
             },
             432usize,
             concat!(
@@ -19852,9 +19852,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToTensorInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToTensorInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToTensorInfo) as usize - ptr as usize
             },
             440usize,
             concat!(
@@ -19869,9 +19869,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOnnxTypeFromTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOnnxTypeFromTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOnnxTypeFromTypeInfo) as usize - ptr as usize
             },
             448usize,
             concat!(
@@ -19886,9 +19886,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorTypeAndShapeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorTypeAndShapeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorTypeAndShapeInfo) as usize - ptr as usize
             },
             456usize,
             concat!(
@@ -19903,9 +19903,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetTensorElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetTensorElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetTensorElementType) as usize - ptr as usize
             },
             464usize,
             concat!(
@@ -19920,9 +19920,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetDimensions) as usize - ptr as usize
             },
             472usize,
             concat!(
@@ -19937,9 +19937,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorElementType) as usize - ptr as usize
             },
             480usize,
             concat!(
@@ -19954,9 +19954,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDimensionsCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDimensionsCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDimensionsCount) as usize - ptr as usize
             },
             488usize,
             concat!(
@@ -19971,9 +19971,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDimensions) as usize - ptr as usize
             },
             496usize,
             concat!(
@@ -19988,9 +19988,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSymbolicDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSymbolicDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSymbolicDimensions) as usize - ptr as usize
             },
             504usize,
             concat!(
@@ -20005,9 +20005,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorShapeElementCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorShapeElementCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorShapeElementCount) as usize - ptr as usize
             },
             512usize,
             concat!(
@@ -20022,9 +20022,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorTypeAndShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorTypeAndShape) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorTypeAndShape) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -20039,9 +20039,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTypeInfo) as usize - ptr as usize
             },
             528usize,
             concat!(
@@ -20056,9 +20056,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValueType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValueType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValueType) as usize - ptr as usize
             },
             536usize,
             concat!(
@@ -20073,9 +20073,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateMemoryInfo) as usize - ptr as usize
             },
             544usize,
             concat!(
@@ -20090,9 +20090,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCpuMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCpuMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCpuMemoryInfo) as usize - ptr as usize
             },
             552usize,
             concat!(
@@ -20107,9 +20107,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CompareMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CompareMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CompareMemoryInfo) as usize - ptr as usize
             },
             560usize,
             concat!(
@@ -20124,9 +20124,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetName) as usize - ptr as usize
             },
             568usize,
             concat!(
@@ -20141,9 +20141,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetId) as usize - ptr as usize
             },
             576usize,
             concat!(
@@ -20158,9 +20158,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetMemType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetMemType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetMemType) as usize - ptr as usize
             },
             584usize,
             concat!(
@@ -20175,9 +20175,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetType) as usize - ptr as usize
             },
             592usize,
             concat!(
@@ -20192,9 +20192,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorAlloc() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorAlloc) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorAlloc) as usize - ptr as usize
             },
             600usize,
             concat!(
@@ -20209,9 +20209,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorFree() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorFree) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorFree) as usize - ptr as usize
             },
             608usize,
             concat!(
@@ -20226,9 +20226,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorGetInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorGetInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorGetInfo) as usize - ptr as usize
             },
             616usize,
             concat!(
@@ -20243,9 +20243,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetAllocatorWithDefaultOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetAllocatorWithDefaultOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetAllocatorWithDefaultOptions) as usize - ptr as usize
             },
             624usize,
             concat!(
@@ -20260,9 +20260,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddFreeDimensionOverride() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverride) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddFreeDimensionOverride) as usize - ptr as usize
             },
             632usize,
             concat!(
@@ -20277,9 +20277,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValue) as usize - ptr as usize
             },
             640usize,
             concat!(
@@ -20294,9 +20294,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValueCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValueCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValueCount) as usize - ptr as usize
             },
             648usize,
             concat!(
@@ -20311,9 +20311,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateValue) as usize - ptr as usize
             },
             656usize,
             concat!(
@@ -20328,9 +20328,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOpaqueValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOpaqueValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOpaqueValue) as usize - ptr as usize
             },
             664usize,
             concat!(
@@ -20345,9 +20345,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOpaqueValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOpaqueValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOpaqueValue) as usize - ptr as usize
             },
             672usize,
             concat!(
@@ -20362,9 +20362,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_float() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_float) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_float) as usize - ptr as usize
             },
             680usize,
             concat!(
@@ -20379,9 +20379,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_int64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_int64) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_int64) as usize - ptr as usize
             },
             688usize,
             concat!(
@@ -20396,9 +20396,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_string() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_string) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_string) as usize - ptr as usize
             },
             696usize,
             concat!(
@@ -20413,9 +20413,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetInputCount) as usize - ptr as usize
             },
             704usize,
             concat!(
@@ -20430,9 +20430,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetOutputCount) as usize - ptr as usize
             },
             712usize,
             concat!(
@@ -20447,9 +20447,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetInput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetInput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetInput) as usize - ptr as usize
             },
             720usize,
             concat!(
@@ -20464,9 +20464,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetOutput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetOutput) as usize - ptr as usize
             },
             728usize,
             concat!(
@@ -20481,9 +20481,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseEnv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseEnv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseEnv) as usize - ptr as usize
             },
             736usize,
             concat!(
@@ -20498,9 +20498,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseStatus() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseStatus) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseStatus) as usize - ptr as usize
             },
             744usize,
             concat!(
@@ -20515,9 +20515,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseMemoryInfo) as usize - ptr as usize
             },
             752usize,
             concat!(
@@ -20532,9 +20532,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSession() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSession) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSession) as usize - ptr as usize
             },
             760usize,
             concat!(
@@ -20549,9 +20549,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseValue) as usize - ptr as usize
             },
             768usize,
             concat!(
@@ -20566,9 +20566,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseRunOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseRunOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseRunOptions) as usize - ptr as usize
             },
             776usize,
             concat!(
@@ -20583,9 +20583,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTypeInfo) as usize - ptr as usize
             },
             784usize,
             concat!(
@@ -20600,9 +20600,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTensorTypeAndShapeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTensorTypeAndShapeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTensorTypeAndShapeInfo) as usize - ptr as usize
             },
             792usize,
             concat!(
@@ -20617,9 +20617,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSessionOptions) as usize - ptr as usize
             },
             800usize,
             concat!(
@@ -20634,9 +20634,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCustomOpDomain) as usize - ptr as usize
             },
             808usize,
             concat!(
@@ -20651,9 +20651,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDenotationFromTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDenotationFromTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDenotationFromTypeInfo) as usize - ptr as usize
             },
             816usize,
             concat!(
@@ -20668,9 +20668,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToMapTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToMapTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToMapTypeInfo) as usize - ptr as usize
             },
             824usize,
             concat!(
@@ -20685,9 +20685,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToSequenceTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToSequenceTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToSequenceTypeInfo) as usize - ptr as usize
             },
             832usize,
             concat!(
@@ -20702,9 +20702,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetMapKeyType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetMapKeyType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetMapKeyType) as usize - ptr as usize
             },
             840usize,
             concat!(
@@ -20719,9 +20719,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetMapValueType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetMapValueType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetMapValueType) as usize - ptr as usize
             },
             848usize,
             concat!(
@@ -20736,9 +20736,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSequenceElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSequenceElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSequenceElementType) as usize - ptr as usize
             },
             856usize,
             concat!(
@@ -20753,9 +20753,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseMapTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseMapTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseMapTypeInfo) as usize - ptr as usize
             },
             864usize,
             concat!(
@@ -20770,9 +20770,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSequenceTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSequenceTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSequenceTypeInfo) as usize - ptr as usize
             },
             872usize,
             concat!(
@@ -20787,9 +20787,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionEndProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionEndProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionEndProfiling) as usize - ptr as usize
             },
             880usize,
             concat!(
@@ -20804,9 +20804,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetModelMetadata() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetModelMetadata) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetModelMetadata) as usize - ptr as usize
             },
             888usize,
             concat!(
@@ -20821,9 +20821,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetProducerName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetProducerName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetProducerName) as usize - ptr as usize
             },
             896usize,
             concat!(
@@ -20838,9 +20838,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetGraphName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetGraphName) as usize - ptr as usize
             },
             904usize,
             concat!(
@@ -20855,9 +20855,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetDomain) as usize - ptr as usize
             },
             912usize,
             concat!(
@@ -20872,9 +20872,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetDescription() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDescription) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetDescription) as usize - ptr as usize
             },
             920usize,
             concat!(
@@ -20889,9 +20889,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataLookupCustomMetadataMap() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataLookupCustomMetadataMap) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataLookupCustomMetadataMap) as usize
                     - ptr as usize
             },
             928usize,
@@ -20907,9 +20907,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetVersion() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetVersion) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetVersion) as usize - ptr as usize
             },
             936usize,
             concat!(
@@ -20924,9 +20924,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseModelMetadata() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseModelMetadata) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseModelMetadata) as usize - ptr as usize
             },
             944usize,
             concat!(
@@ -20941,9 +20941,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithGlobalThreadPools() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithGlobalThreadPools) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithGlobalThreadPools) as usize - ptr as usize
             },
             952usize,
             concat!(
@@ -20958,9 +20958,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisablePerSessionThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisablePerSessionThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisablePerSessionThreads) as usize - ptr as usize
             },
             960usize,
             concat!(
@@ -20975,9 +20975,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateThreadingOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateThreadingOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateThreadingOptions) as usize - ptr as usize
             },
             968usize,
             concat!(
@@ -20992,9 +20992,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseThreadingOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseThreadingOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseThreadingOptions) as usize - ptr as usize
             },
             976usize,
             concat!(
@@ -21009,9 +21009,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetCustomMetadataMapKeys() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetCustomMetadataMapKeys) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetCustomMetadataMapKeys) as usize
                     - ptr as usize
             },
             984usize,
@@ -21027,9 +21027,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddFreeDimensionOverrideByName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverrideByName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddFreeDimensionOverrideByName) as usize - ptr as usize
             },
             992usize,
             concat!(
@@ -21044,9 +21044,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetAvailableProviders() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetAvailableProviders) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetAvailableProviders) as usize - ptr as usize
             },
             1000usize,
             concat!(
@@ -21061,9 +21061,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseAvailableProviders() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseAvailableProviders) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseAvailableProviders) as usize - ptr as usize
             },
             1008usize,
             concat!(
@@ -21078,9 +21078,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorElementLength() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorElementLength) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorElementLength) as usize - ptr as usize
             },
             1016usize,
             concat!(
@@ -21095,9 +21095,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorElement() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorElement) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorElement) as usize - ptr as usize
             },
             1024usize,
             concat!(
@@ -21112,9 +21112,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillStringTensorElement() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillStringTensorElement) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillStringTensorElement) as usize - ptr as usize
             },
             1032usize,
             concat!(
@@ -21129,9 +21129,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddSessionConfigEntry) as usize - ptr as usize
             },
             1040usize,
             concat!(
@@ -21146,9 +21146,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateAllocator) as usize - ptr as usize
             },
             1048usize,
             concat!(
@@ -21163,9 +21163,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseAllocator) as usize - ptr as usize
             },
             1056usize,
             concat!(
@@ -21180,9 +21180,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunWithBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunWithBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunWithBinding) as usize - ptr as usize
             },
             1064usize,
             concat!(
@@ -21197,9 +21197,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateIoBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateIoBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateIoBinding) as usize - ptr as usize
             },
             1072usize,
             concat!(
@@ -21214,9 +21214,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseIoBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseIoBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseIoBinding) as usize - ptr as usize
             },
             1080usize,
             concat!(
@@ -21231,9 +21231,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindInput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindInput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindInput) as usize - ptr as usize
             },
             1088usize,
             concat!(
@@ -21248,9 +21248,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindOutput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindOutput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindOutput) as usize - ptr as usize
             },
             1096usize,
             concat!(
@@ -21265,9 +21265,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindOutputToDevice() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindOutputToDevice) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindOutputToDevice) as usize - ptr as usize
             },
             1104usize,
             concat!(
@@ -21282,9 +21282,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBoundOutputNames() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBoundOutputNames) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBoundOutputNames) as usize - ptr as usize
             },
             1112usize,
             concat!(
@@ -21299,9 +21299,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBoundOutputValues() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBoundOutputValues) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBoundOutputValues) as usize - ptr as usize
             },
             1120usize,
             concat!(
@@ -21316,9 +21316,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ClearBoundInputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ClearBoundInputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ClearBoundInputs) as usize - ptr as usize
             },
             1128usize,
             concat!(
@@ -21333,9 +21333,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ClearBoundOutputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ClearBoundOutputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ClearBoundOutputs) as usize - ptr as usize
             },
             1136usize,
             concat!(
@@ -21350,9 +21350,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_TensorAt() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).TensorAt) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).TensorAt) as usize - ptr as usize
             },
             1144usize,
             concat!(
@@ -21367,9 +21367,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateAndRegisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateAndRegisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateAndRegisterAllocator) as usize - ptr as usize
             },
             1152usize,
             concat!(
@@ -21384,9 +21384,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetLanguageProjection() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetLanguageProjection) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetLanguageProjection) as usize - ptr as usize
             },
             1160usize,
             concat!(
@@ -21401,9 +21401,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetProfilingStartTimeNs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetProfilingStartTimeNs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetProfilingStartTimeNs) as usize - ptr as usize
             },
             1168usize,
             concat!(
@@ -21418,9 +21418,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalIntraOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalIntraOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalIntraOpNumThreads) as usize - ptr as usize
             },
             1176usize,
             concat!(
@@ -21435,9 +21435,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalInterOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalInterOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalInterOpNumThreads) as usize - ptr as usize
             },
             1184usize,
             concat!(
@@ -21452,9 +21452,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalSpinControl() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalSpinControl) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalSpinControl) as usize - ptr as usize
             },
             1192usize,
             concat!(
@@ -21469,9 +21469,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddInitializer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddInitializer) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddInitializer) as usize - ptr as usize
             },
             1200usize,
             concat!(
@@ -21486,9 +21486,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithCustomLoggerAndGlobalThreadPools() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLoggerAndGlobalThreadPools) as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithCustomLoggerAndGlobalThreadPools) as usize
                     - ptr as usize
             },
             1208usize,
@@ -21504,9 +21504,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CUDA() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA) as usize
                     - ptr as usize
             },
             1216usize,
@@ -21522,9 +21522,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_ROCM() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_ROCM) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_ROCM) as usize
                     - ptr as usize
             },
             1224usize,
@@ -21540,9 +21540,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_OpenVINO() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_OpenVINO) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_OpenVINO) as usize
                     - ptr as usize
             },
             1232usize,
@@ -21558,9 +21558,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalDenormalAsZero() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalDenormalAsZero) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalDenormalAsZero) as usize - ptr as usize
             },
             1240usize,
             concat!(
@@ -21575,9 +21575,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateArenaCfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateArenaCfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateArenaCfg) as usize - ptr as usize
             },
             1248usize,
             concat!(
@@ -21592,9 +21592,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseArenaCfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseArenaCfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseArenaCfg) as usize - ptr as usize
             },
             1256usize,
             concat!(
@@ -21609,9 +21609,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetGraphDescription() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphDescription) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetGraphDescription) as usize
                     - ptr as usize
             },
             1264usize,
@@ -21627,9 +21627,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_TensorRT() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT) as usize
                     - ptr as usize
             },
             1272usize,
@@ -21645,9 +21645,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetCurrentGpuDeviceId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetCurrentGpuDeviceId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetCurrentGpuDeviceId) as usize - ptr as usize
             },
             1280usize,
             concat!(
@@ -21662,9 +21662,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCurrentGpuDeviceId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCurrentGpuDeviceId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCurrentGpuDeviceId) as usize - ptr as usize
             },
             1288usize,
             concat!(
@@ -21679,9 +21679,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttributeArray_float() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_float) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_float) as usize
                     - ptr as usize
             },
             1296usize,
@@ -21697,9 +21697,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttributeArray_int64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_int64) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_int64) as usize
                     - ptr as usize
             },
             1304usize,
@@ -21715,9 +21715,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateArenaCfgV2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateArenaCfgV2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateArenaCfgV2) as usize - ptr as usize
             },
             1312usize,
             concat!(
@@ -21732,9 +21732,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddRunConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddRunConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddRunConfigEntry) as usize - ptr as usize
             },
             1320usize,
             concat!(
@@ -21749,9 +21749,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreatePrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreatePrepackedWeightsContainer) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreatePrepackedWeightsContainer) as usize - ptr as usize
             },
             1328usize,
             concat!(
@@ -21766,9 +21766,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleasePrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleasePrepackedWeightsContainer) as usize
+                ::core::ptr::addr_of!((*ptr).ReleasePrepackedWeightsContainer) as usize
                     - ptr as usize
             },
             1336usize,
@@ -21784,9 +21784,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionWithPrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionWithPrepackedWeightsContainer) as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionWithPrepackedWeightsContainer) as usize
                     - ptr as usize
             },
             1344usize,
@@ -21802,9 +21802,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionFromArrayWithPrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionFromArrayWithPrepackedWeightsContainer)
+                ::core::ptr::addr_of!((*ptr).CreateSessionFromArrayWithPrepackedWeightsContainer)
                     as usize
                     - ptr as usize
             },
@@ -21821,9 +21821,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_TensorRT_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT_V2)
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT_V2)
                     as usize
                     - ptr as usize
             },
@@ -21840,9 +21840,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorRTProviderOptions) as usize - ptr as usize
             },
             1368usize,
             concat!(
@@ -21857,9 +21857,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateTensorRTProviderOptions) as usize - ptr as usize
             },
             1376usize,
             concat!(
@@ -21874,9 +21874,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorRTProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorRTProviderOptionsAsString) as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorRTProviderOptionsAsString) as usize
                     - ptr as usize
             },
             1384usize,
@@ -21892,9 +21892,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTensorRTProviderOptions) as usize - ptr as usize
             },
             1392usize,
             concat!(
@@ -21909,9 +21909,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableOrtCustomOps() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableOrtCustomOps) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableOrtCustomOps) as usize - ptr as usize
             },
             1400usize,
             concat!(
@@ -21926,9 +21926,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterAllocator) as usize - ptr as usize
             },
             1408usize,
             concat!(
@@ -21943,9 +21943,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UnregisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UnregisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UnregisterAllocator) as usize - ptr as usize
             },
             1416usize,
             concat!(
@@ -21960,9 +21960,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_IsSparseTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).IsSparseTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).IsSparseTensor) as usize - ptr as usize
             },
             1424usize,
             concat!(
@@ -21977,9 +21977,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSparseTensorAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSparseTensorAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSparseTensorAsOrtValue) as usize - ptr as usize
             },
             1432usize,
             concat!(
@@ -21994,9 +21994,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorCoo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorCoo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorCoo) as usize - ptr as usize
             },
             1440usize,
             concat!(
@@ -22011,9 +22011,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorCsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorCsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorCsr) as usize - ptr as usize
             },
             1448usize,
             concat!(
@@ -22028,9 +22028,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorBlockSparse() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorBlockSparse) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorBlockSparse) as usize - ptr as usize
             },
             1456usize,
             concat!(
@@ -22045,9 +22045,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSparseTensorWithValuesAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSparseTensorWithValuesAsOrtValue) as usize
+                ::core::ptr::addr_of!((*ptr).CreateSparseTensorWithValuesAsOrtValue) as usize
                     - ptr as usize
             },
             1464usize,
@@ -22063,9 +22063,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseCooIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseCooIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseCooIndices) as usize - ptr as usize
             },
             1472usize,
             concat!(
@@ -22080,9 +22080,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseCsrIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseCsrIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseCsrIndices) as usize - ptr as usize
             },
             1480usize,
             concat!(
@@ -22097,9 +22097,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseBlockSparseIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseBlockSparseIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseBlockSparseIndices) as usize - ptr as usize
             },
             1488usize,
             concat!(
@@ -22114,9 +22114,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorFormat() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorFormat) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorFormat) as usize - ptr as usize
             },
             1496usize,
             concat!(
@@ -22131,9 +22131,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorValuesTypeAndShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorValuesTypeAndShape) as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorValuesTypeAndShape) as usize
                     - ptr as usize
             },
             1504usize,
@@ -22149,9 +22149,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorValues() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorValues) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorValues) as usize - ptr as usize
             },
             1512usize,
             concat!(
@@ -22166,9 +22166,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorIndicesTypeShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndicesTypeShape) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorIndicesTypeShape) as usize - ptr as usize
             },
             1520usize,
             concat!(
@@ -22183,9 +22183,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorIndices) as usize - ptr as usize
             },
             1528usize,
             concat!(
@@ -22200,9 +22200,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_HasValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).HasValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).HasValue) as usize - ptr as usize
             },
             1536usize,
             concat!(
@@ -22217,9 +22217,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetGPUComputeStream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetGPUComputeStream) as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetGPUComputeStream) as usize
                     - ptr as usize
             },
             1544usize,
@@ -22235,9 +22235,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorMemoryInfo) as usize - ptr as usize
             },
             1552usize,
             concat!(
@@ -22252,9 +22252,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetExecutionProviderApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetExecutionProviderApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetExecutionProviderApi) as usize - ptr as usize
             },
             1560usize,
             concat!(
@@ -22269,9 +22269,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomCreateThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomCreateThreadFn) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomCreateThreadFn) as usize
                     - ptr as usize
             },
             1568usize,
@@ -22287,9 +22287,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomThreadCreationOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomThreadCreationOptions) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomThreadCreationOptions) as usize
                     - ptr as usize
             },
             1576usize,
@@ -22305,9 +22305,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomJoinThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomJoinThreadFn) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomJoinThreadFn) as usize
                     - ptr as usize
             },
             1584usize,
@@ -22323,9 +22323,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomCreateThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomCreateThreadFn) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomCreateThreadFn) as usize - ptr as usize
             },
             1592usize,
             concat!(
@@ -22340,9 +22340,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomThreadCreationOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomThreadCreationOptions) as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomThreadCreationOptions) as usize
                     - ptr as usize
             },
             1600usize,
@@ -22358,9 +22358,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomJoinThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomJoinThreadFn) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomJoinThreadFn) as usize - ptr as usize
             },
             1608usize,
             concat!(
@@ -22375,9 +22375,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SynchronizeBoundInputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SynchronizeBoundInputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SynchronizeBoundInputs) as usize - ptr as usize
             },
             1616usize,
             concat!(
@@ -22392,9 +22392,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SynchronizeBoundOutputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SynchronizeBoundOutputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SynchronizeBoundOutputs) as usize - ptr as usize
             },
             1624usize,
             concat!(
@@ -22409,9 +22409,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CUDA_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA_V2) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA_V2) as usize
                     - ptr as usize
             },
             1632usize,
@@ -22427,9 +22427,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCUDAProviderOptions) as usize - ptr as usize
             },
             1640usize,
             concat!(
@@ -22444,9 +22444,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateCUDAProviderOptions) as usize - ptr as usize
             },
             1648usize,
             concat!(
@@ -22461,9 +22461,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCUDAProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCUDAProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCUDAProviderOptionsAsString) as usize - ptr as usize
             },
             1656usize,
             concat!(
@@ -22478,9 +22478,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCUDAProviderOptions) as usize - ptr as usize
             },
             1664usize,
             concat!(
@@ -22495,9 +22495,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_MIGraphX() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_MIGraphX) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_MIGraphX) as usize
                     - ptr as usize
             },
             1672usize,
@@ -22513,9 +22513,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddExternalInitializers() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddExternalInitializers) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddExternalInitializers) as usize - ptr as usize
             },
             1680usize,
             concat!(
@@ -22530,9 +22530,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOpAttr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOpAttr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOpAttr) as usize - ptr as usize
             },
             1688usize,
             concat!(
@@ -22547,9 +22547,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseOpAttr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseOpAttr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseOpAttr) as usize - ptr as usize
             },
             1696usize,
             concat!(
@@ -22564,9 +22564,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOp) as usize - ptr as usize
             },
             1704usize,
             concat!(
@@ -22581,9 +22581,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_InvokeOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).InvokeOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).InvokeOp) as usize - ptr as usize
             },
             1712usize,
             concat!(
@@ -22598,9 +22598,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseOp) as usize - ptr as usize
             },
             1720usize,
             concat!(
@@ -22615,9 +22615,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider) as usize
                     - ptr as usize
             },
             1728usize,
@@ -22633,9 +22633,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CopyKernelInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CopyKernelInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CopyKernelInfo) as usize - ptr as usize
             },
             1736usize,
             concat!(
@@ -22650,9 +22650,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseKernelInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseKernelInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseKernelInfo) as usize - ptr as usize
             },
             1744usize,
             concat!(
@@ -22667,9 +22667,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTrainingApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTrainingApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTrainingApi) as usize - ptr as usize
             },
             1752usize,
             concat!(
@@ -22684,9 +22684,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CANN() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CANN) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CANN) as usize
                     - ptr as usize
             },
             1760usize,
@@ -22702,9 +22702,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCANNProviderOptions) as usize - ptr as usize
             },
             1768usize,
             concat!(
@@ -22719,9 +22719,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateCANNProviderOptions) as usize - ptr as usize
             },
             1776usize,
             concat!(
@@ -22736,9 +22736,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCANNProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCANNProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCANNProviderOptionsAsString) as usize - ptr as usize
             },
             1784usize,
             concat!(
@@ -22753,9 +22753,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCANNProviderOptions) as usize - ptr as usize
             },
             1792usize,
             concat!(
@@ -22770,9 +22770,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetDeviceType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetDeviceType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetDeviceType) as usize - ptr as usize
             },
             1800usize,
             concat!(
@@ -22787,9 +22787,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateEnvWithCustomLogLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateEnvWithCustomLogLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateEnvWithCustomLogLevel) as usize - ptr as usize
             },
             1808usize,
             concat!(
@@ -22804,9 +22804,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalIntraOpThreadAffinity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalIntraOpThreadAffinity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalIntraOpThreadAffinity) as usize - ptr as usize
             },
             1816usize,
             concat!(
@@ -22821,9 +22821,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsLibrary_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary_V2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary_V2) as usize - ptr as usize
             },
             1824usize,
             concat!(
@@ -22838,9 +22838,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsUsingFunction() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsUsingFunction) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsUsingFunction) as usize - ptr as usize
             },
             1832usize,
             concat!(
@@ -22855,9 +22855,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputCount) as usize - ptr as usize
             },
             1840usize,
             concat!(
@@ -22872,9 +22872,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputCount) as usize - ptr as usize
             },
             1848usize,
             concat!(
@@ -22889,9 +22889,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputName) as usize - ptr as usize
             },
             1856usize,
             concat!(
@@ -22906,9 +22906,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputName) as usize - ptr as usize
             },
             1864usize,
             concat!(
@@ -22923,9 +22923,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputTypeInfo) as usize - ptr as usize
             },
             1872usize,
             concat!(
@@ -22940,9 +22940,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputTypeInfo) as usize - ptr as usize
             },
             1880usize,
             concat!(
@@ -22957,9 +22957,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_tensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_tensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_tensor) as usize - ptr as usize
             },
             1888usize,
             concat!(
@@ -22974,9 +22974,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_HasSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).HasSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).HasSessionConfigEntry) as usize - ptr as usize
             },
             1896usize,
             concat!(
@@ -22991,9 +22991,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSessionConfigEntry) as usize - ptr as usize
             },
             1904usize,
             concat!(
@@ -23008,9 +23008,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_Dnnl() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_Dnnl) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_Dnnl) as usize
                     - ptr as usize
             },
             1912usize,
@@ -23026,9 +23026,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateDnnlProviderOptions) as usize - ptr as usize
             },
             1920usize,
             concat!(
@@ -23043,9 +23043,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateDnnlProviderOptions) as usize - ptr as usize
             },
             1928usize,
             concat!(
@@ -23060,9 +23060,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDnnlProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDnnlProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDnnlProviderOptionsAsString) as usize - ptr as usize
             },
             1936usize,
             concat!(
@@ -23077,9 +23077,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseDnnlProviderOptions) as usize - ptr as usize
             },
             1944usize,
             concat!(
@@ -23094,9 +23094,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetNodeName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetNodeName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetNodeName) as usize - ptr as usize
             },
             1952usize,
             concat!(
@@ -23111,9 +23111,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetLogger) as usize - ptr as usize
             },
             1960usize,
             concat!(
@@ -23128,9 +23128,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetLogger) as usize - ptr as usize
             },
             1968usize,
             concat!(
@@ -23145,9 +23145,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Logger_LogMessage() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Logger_LogMessage) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Logger_LogMessage) as usize - ptr as usize
             },
             1976usize,
             concat!(
@@ -23162,9 +23162,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Logger_GetLoggingSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Logger_GetLoggingSeverityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Logger_GetLoggingSeverityLevel) as usize - ptr as usize
             },
             1984usize,
             concat!(
@@ -23179,9 +23179,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetConstantInput_tensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetConstantInput_tensor) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetConstantInput_tensor) as usize
                     - ptr as usize
             },
             1992usize,
@@ -23197,9 +23197,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToOptionalTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToOptionalTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToOptionalTypeInfo) as usize - ptr as usize
             },
             2000usize,
             concat!(
@@ -23214,9 +23214,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOptionalContainedTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOptionalContainedTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOptionalContainedTypeInfo) as usize - ptr as usize
             },
             2008usize,
             concat!(
@@ -23231,9 +23231,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetResizedStringTensorElementBuffer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetResizedStringTensorElementBuffer) as usize
+                ::core::ptr::addr_of!((*ptr).GetResizedStringTensorElementBuffer) as usize
                     - ptr as usize
             },
             2016usize,
@@ -23249,9 +23249,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetAllocator) as usize - ptr as usize
             },
             2024usize,
             concat!(
@@ -23266,9 +23266,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBuildInfoString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBuildInfoString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBuildInfoString) as usize - ptr as usize
             },
             2032usize,
             concat!(
@@ -23292,83 +23292,83 @@ pub enum OrtCustomOpInputOutputCharacteristic {
 #[derive(Debug, Copy, Clone)]
 pub struct OrtCustomOp {
     pub version: u32,
-    pub CreateKernel: ::std::option::Option<
+    pub CreateKernel: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             api: *const OrtApi,
             info: *const OrtKernelInfo,
-        ) -> *mut ::std::os::raw::c_void,
+        ) -> *mut ::core::ffi::c_void,
     >,
-    pub GetName: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    pub GetName: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::core::ffi::c_char,
     >,
-    pub GetExecutionProviderType: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    pub GetExecutionProviderType: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::core::ffi::c_char,
     >,
-    pub GetInputType: ::std::option::Option<
+    pub GetInputType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
     >,
     pub GetInputTypeCount:
-        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
-    pub GetOutputType: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub GetOutputType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
     >,
     pub GetOutputTypeCount:
-        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
-    pub KernelCompute: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub KernelCompute: ::core::option::Option<
         unsafe extern "C" fn(
-            op_kernel: *mut ::std::os::raw::c_void,
+            op_kernel: *mut ::core::ffi::c_void,
             context: *mut OrtKernelContext,
         ),
     >,
     pub KernelDestroy:
-        ::std::option::Option<unsafe extern "C" fn(op_kernel: *mut ::std::os::raw::c_void)>,
-    pub GetInputCharacteristic: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op_kernel: *mut ::core::ffi::c_void)>,
+    pub GetInputCharacteristic: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             index: usize,
         ) -> OrtCustomOpInputOutputCharacteristic,
     >,
-    pub GetOutputCharacteristic: ::std::option::Option<
+    pub GetOutputCharacteristic: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             index: usize,
         ) -> OrtCustomOpInputOutputCharacteristic,
     >,
-    pub GetInputMemoryType: ::std::option::Option<
+    pub GetInputMemoryType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> OrtMemType,
     >,
-    pub GetVariadicInputMinArity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicInputMinArity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicInputHomogeneity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicInputHomogeneity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicOutputMinArity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicOutputMinArity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicOutputHomogeneity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicOutputHomogeneity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
 }
 #[test]
 fn bindgen_test_layout_OrtCustomOp() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCustomOp>(),
+        ::core::mem::size_of::<OrtCustomOp>(),
         136usize,
         concat!("Size of: ", stringify!(OrtCustomOp))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCustomOp>(),
+        ::core::mem::align_of::<OrtCustomOp>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtCustomOp))
     );
     fn test_field_version() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).version) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -23383,9 +23383,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_CreateKernel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateKernel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateKernel) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -23400,9 +23400,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetName) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -23417,9 +23417,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetExecutionProviderType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetExecutionProviderType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetExecutionProviderType) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -23434,9 +23434,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputType) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -23451,9 +23451,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputTypeCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputTypeCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputTypeCount) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -23468,9 +23468,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputType) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -23485,9 +23485,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputTypeCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputTypeCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputTypeCount) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -23502,9 +23502,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_KernelCompute() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelCompute) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelCompute) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -23519,9 +23519,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_KernelDestroy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelDestroy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelDestroy) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -23536,9 +23536,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputCharacteristic() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputCharacteristic) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputCharacteristic) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -23553,9 +23553,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputCharacteristic() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputCharacteristic) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputCharacteristic) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -23570,9 +23570,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputMemoryType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputMemoryType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputMemoryType) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -23587,9 +23587,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicInputMinArity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicInputMinArity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicInputMinArity) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -23604,9 +23604,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicInputHomogeneity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicInputHomogeneity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicInputHomogeneity) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -23621,9 +23621,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicOutputMinArity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicOutputMinArity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicOutputMinArity) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -23638,9 +23638,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicOutputHomogeneity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicOutputHomogeneity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicOutputHomogeneity) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -23656,48 +23656,48 @@ fn bindgen_test_layout_OrtCustomOp() {
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_CUDA(
         options: *mut OrtSessionOptions,
-        device_id: ::std::os::raw::c_int,
+        device_id: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_MIGraphX(
         options: *mut OrtSessionOptions,
-        device_id: ::std::os::raw::c_int,
+        device_id: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_Dnnl(
         options: *mut OrtSessionOptions,
-        use_arena: ::std::os::raw::c_int,
+        use_arena: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 pub type __builtin_va_list = [__va_list_tag; 1usize];
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __va_list_tag {
-    pub gp_offset: ::std::os::raw::c_uint,
-    pub fp_offset: ::std::os::raw::c_uint,
-    pub overflow_arg_area: *mut ::std::os::raw::c_void,
-    pub reg_save_area: *mut ::std::os::raw::c_void,
+    pub gp_offset: ::core::ffi::c_uint,
+    pub fp_offset: ::core::ffi::c_uint,
+    pub overflow_arg_area: *mut ::core::ffi::c_void,
+    pub reg_save_area: *mut ::core::ffi::c_void,
 }
 #[test]
 fn bindgen_test_layout___va_list_tag() {
     assert_eq!(
-        ::std::mem::size_of::<__va_list_tag>(),
+        ::core::mem::size_of::<__va_list_tag>(),
         24usize,
         concat!("Size of: ", stringify!(__va_list_tag))
     );
     assert_eq!(
-        ::std::mem::align_of::<__va_list_tag>(),
+        ::core::mem::align_of::<__va_list_tag>(),
         8usize,
         concat!("Alignment of ", stringify!(__va_list_tag))
     );
     fn test_field_gp_offset() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).gp_offset) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).gp_offset) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -23712,9 +23712,9 @@ fn bindgen_test_layout___va_list_tag() {
     fn test_field_fp_offset() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).fp_offset) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).fp_offset) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -23729,9 +23729,9 @@ fn bindgen_test_layout___va_list_tag() {
     fn test_field_overflow_arg_area() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).overflow_arg_area) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).overflow_arg_area) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -23746,9 +23746,9 @@ fn bindgen_test_layout___va_list_tag() {
     fn test_field_reg_save_area() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__va_list_tag>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__va_list_tag>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).reg_save_area) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).reg_save_area) as usize - ptr as usize
             },
             16usize,
             concat!(
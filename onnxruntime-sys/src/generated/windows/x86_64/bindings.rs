@@ -173,12 +173,12 @@ pub const __drv_typeConst: u32 = 0;
 pub const __drv_typeCond: u32 = 1;
 pub const __drv_typeBitset: u32 = 2;
 pub const __drv_typeExpr: u32 = 3;
-pub type va_list = *mut ::std::os::raw::c_char;
+pub type va_list = *mut ::core::ffi::c_char;
 extern "C" {
-    pub fn __va_start(arg1: *mut *mut ::std::os::raw::c_char, ...);
+    pub fn __va_start(arg1: *mut *mut ::core::ffi::c_char, ...);
 }
 pub type __vcrt_bool = bool;
-pub type wchar_t = ::std::os::raw::c_ushort;
+pub type wchar_t = ::core::ffi::c_ushort;
 extern "C" {
     pub fn __security_init_cookie();
 }
@@ -203,40 +203,40 @@ extern "C" {
         _Expression: *const wchar_t,
         _FunctionName: *const wchar_t,
         _FileName: *const wchar_t,
-        _LineNo: ::std::os::raw::c_uint,
+        _LineNo: ::core::ffi::c_uint,
         _Reserved: usize,
     );
 }
-pub type errno_t = ::std::os::raw::c_int;
-pub type wint_t = ::std::os::raw::c_ushort;
-pub type wctype_t = ::std::os::raw::c_ushort;
-pub type __time32_t = ::std::os::raw::c_long;
-pub type __time64_t = ::std::os::raw::c_longlong;
+pub type errno_t = ::core::ffi::c_int;
+pub type wint_t = ::core::ffi::c_ushort;
+pub type wctype_t = ::core::ffi::c_ushort;
+pub type __time32_t = ::core::ffi::c_long;
+pub type __time64_t = ::core::ffi::c_longlong;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __crt_locale_data_public {
-    pub _locale_pctype: *const ::std::os::raw::c_ushort,
-    pub _locale_mb_cur_max: ::std::os::raw::c_int,
-    pub _locale_lc_codepage: ::std::os::raw::c_uint,
+    pub _locale_pctype: *const ::core::ffi::c_ushort,
+    pub _locale_mb_cur_max: ::core::ffi::c_int,
+    pub _locale_lc_codepage: ::core::ffi::c_uint,
 }
 #[test]
 fn bindgen_test_layout___crt_locale_data_public() {
     assert_eq!(
-        ::std::mem::size_of::<__crt_locale_data_public>(),
+        ::core::mem::size_of::<__crt_locale_data_public>(),
         16usize,
         concat!("Size of: ", stringify!(__crt_locale_data_public))
     );
     assert_eq!(
-        ::std::mem::align_of::<__crt_locale_data_public>(),
+        ::core::mem::align_of::<__crt_locale_data_public>(),
         8usize,
         concat!("Alignment of ", stringify!(__crt_locale_data_public))
     );
     fn test_field__locale_pctype() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__crt_locale_data_public>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__crt_locale_data_public>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr)._locale_pctype) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr)._locale_pctype) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -251,9 +251,9 @@ fn bindgen_test_layout___crt_locale_data_public() {
     fn test_field__locale_mb_cur_max() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__crt_locale_data_public>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__crt_locale_data_public>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr)._locale_mb_cur_max) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr)._locale_mb_cur_max) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -268,9 +268,9 @@ fn bindgen_test_layout___crt_locale_data_public() {
     fn test_field__locale_lc_codepage() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__crt_locale_data_public>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__crt_locale_data_public>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr)._locale_lc_codepage) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr)._locale_lc_codepage) as usize - ptr as usize
             },
             12usize,
             concat!(
@@ -292,21 +292,21 @@ pub struct __crt_locale_pointers {
 #[test]
 fn bindgen_test_layout___crt_locale_pointers() {
     assert_eq!(
-        ::std::mem::size_of::<__crt_locale_pointers>(),
+        ::core::mem::size_of::<__crt_locale_pointers>(),
         16usize,
         concat!("Size of: ", stringify!(__crt_locale_pointers))
     );
     assert_eq!(
-        ::std::mem::align_of::<__crt_locale_pointers>(),
+        ::core::mem::align_of::<__crt_locale_pointers>(),
         8usize,
         concat!("Alignment of ", stringify!(__crt_locale_pointers))
     );
     fn test_field_locinfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__crt_locale_pointers>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__crt_locale_pointers>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).locinfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).locinfo) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -321,9 +321,9 @@ fn bindgen_test_layout___crt_locale_pointers() {
     fn test_field_mbcinfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<__crt_locale_pointers>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<__crt_locale_pointers>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).mbcinfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).mbcinfo) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -340,28 +340,28 @@ pub type _locale_t = *mut __crt_locale_pointers;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _Mbstatet {
-    pub _Wchar: ::std::os::raw::c_ulong,
-    pub _Byte: ::std::os::raw::c_ushort,
-    pub _State: ::std::os::raw::c_ushort,
+    pub _Wchar: ::core::ffi::c_ulong,
+    pub _Byte: ::core::ffi::c_ushort,
+    pub _State: ::core::ffi::c_ushort,
 }
 #[test]
 fn bindgen_test_layout__Mbstatet() {
     assert_eq!(
-        ::std::mem::size_of::<_Mbstatet>(),
+        ::core::mem::size_of::<_Mbstatet>(),
         8usize,
         concat!("Size of: ", stringify!(_Mbstatet))
     );
     assert_eq!(
-        ::std::mem::align_of::<_Mbstatet>(),
+        ::core::mem::align_of::<_Mbstatet>(),
         4usize,
         concat!("Alignment of ", stringify!(_Mbstatet))
     );
     fn test_field__Wchar() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_Mbstatet>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_Mbstatet>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr)._Wchar) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr)._Wchar) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -376,9 +376,9 @@ fn bindgen_test_layout__Mbstatet() {
     fn test_field__Byte() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_Mbstatet>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_Mbstatet>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr)._Byte) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr)._Byte) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -393,9 +393,9 @@ fn bindgen_test_layout__Mbstatet() {
     fn test_field__State() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_Mbstatet>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_Mbstatet>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr)._State) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr)._State) as usize - ptr as usize
             },
             6usize,
             concat!(
@@ -412,178 +412,178 @@ pub type mbstate_t = _Mbstatet;
 pub type time_t = __time64_t;
 pub type rsize_t = usize;
 extern "C" {
-    pub fn _calloc_base(_Count: usize, _Size: usize) -> *mut ::std::os::raw::c_void;
+    pub fn _calloc_base(_Count: usize, _Size: usize) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn calloc(
-        _Count: ::std::os::raw::c_ulonglong,
-        _Size: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_void;
+        _Count: ::core::ffi::c_ulonglong,
+        _Size: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn _callnewh(_Size: usize) -> ::std::os::raw::c_int;
+    pub fn _callnewh(_Size: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _expand(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn _free_base(_Block: *mut ::std::os::raw::c_void);
+    pub fn _free_base(_Block: *mut ::core::ffi::c_void);
 }
 extern "C" {
-    pub fn free(_Block: *mut ::std::os::raw::c_void);
+    pub fn free(_Block: *mut ::core::ffi::c_void);
 }
 extern "C" {
-    pub fn _malloc_base(_Size: usize) -> *mut ::std::os::raw::c_void;
+    pub fn _malloc_base(_Size: usize) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn malloc(_Size: ::std::os::raw::c_ulonglong) -> *mut ::std::os::raw::c_void;
+    pub fn malloc(_Size: ::core::ffi::c_ulonglong) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn _msize_base(_Block: *mut ::std::os::raw::c_void) -> usize;
+    pub fn _msize_base(_Block: *mut ::core::ffi::c_void) -> usize;
 }
 extern "C" {
-    pub fn _msize(_Block: *mut ::std::os::raw::c_void) -> usize;
+    pub fn _msize(_Block: *mut ::core::ffi::c_void) -> usize;
 }
 extern "C" {
     pub fn _realloc_base(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn realloc(
-        _Block: *mut ::std::os::raw::c_void,
-        _Size: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_void;
+        _Block: *mut ::core::ffi::c_void,
+        _Size: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _recalloc_base(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Count: usize,
         _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _recalloc(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Count: usize,
         _Size: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn _aligned_free(_Block: *mut ::std::os::raw::c_void);
+    pub fn _aligned_free(_Block: *mut ::core::ffi::c_void);
 }
 extern "C" {
-    pub fn _aligned_malloc(_Size: usize, _Alignment: usize) -> *mut ::std::os::raw::c_void;
+    pub fn _aligned_malloc(_Size: usize, _Alignment: usize) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _aligned_offset_malloc(
         _Size: usize,
         _Alignment: usize,
         _Offset: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _aligned_msize(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Alignment: usize,
         _Offset: usize,
     ) -> usize;
 }
 extern "C" {
     pub fn _aligned_offset_realloc(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Size: usize,
         _Alignment: usize,
         _Offset: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _aligned_offset_recalloc(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Count: usize,
         _Size: usize,
         _Alignment: usize,
         _Offset: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _aligned_realloc(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Size: usize,
         _Alignment: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _aligned_recalloc(
-        _Block: *mut ::std::os::raw::c_void,
+        _Block: *mut ::core::ffi::c_void,
         _Count: usize,
         _Size: usize,
         _Alignment: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
-    pub fn _errno() -> *mut ::std::os::raw::c_int;
+    pub fn _errno() -> *mut ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _set_errno(_Value: ::std::os::raw::c_int) -> errno_t;
+    pub fn _set_errno(_Value: ::core::ffi::c_int) -> errno_t;
 }
 extern "C" {
-    pub fn _get_errno(_Value: *mut ::std::os::raw::c_int) -> errno_t;
+    pub fn _get_errno(_Value: *mut ::core::ffi::c_int) -> errno_t;
 }
 extern "C" {
-    pub fn __threadid() -> ::std::os::raw::c_ulong;
+    pub fn __threadid() -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn __threadhandle() -> usize;
 }
-pub type _CoreCrtSecureSearchSortCompareFunction = ::std::option::Option<
+pub type _CoreCrtSecureSearchSortCompareFunction = ::core::option::Option<
     unsafe extern "C" fn(
-        arg1: *mut ::std::os::raw::c_void,
-        arg2: *const ::std::os::raw::c_void,
-        arg3: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int,
+        arg1: *mut ::core::ffi::c_void,
+        arg2: *const ::core::ffi::c_void,
+        arg3: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int,
 >;
-pub type _CoreCrtNonSecureSearchSortCompareFunction = ::std::option::Option<
+pub type _CoreCrtNonSecureSearchSortCompareFunction = ::core::option::Option<
     unsafe extern "C" fn(
-        arg1: *const ::std::os::raw::c_void,
-        arg2: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int,
+        arg1: *const ::core::ffi::c_void,
+        arg2: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int,
 >;
 extern "C" {
     pub fn bsearch_s(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *const ::std::os::raw::c_void,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *const ::core::ffi::c_void,
         _NumOfElements: rsize_t,
         _SizeOfElements: rsize_t,
         _CompareFunction: _CoreCrtSecureSearchSortCompareFunction,
-        _Context: *mut ::std::os::raw::c_void,
-    ) -> *mut ::std::os::raw::c_void;
+        _Context: *mut ::core::ffi::c_void,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn qsort_s(
-        _Base: *mut ::std::os::raw::c_void,
+        _Base: *mut ::core::ffi::c_void,
         _NumOfElements: rsize_t,
         _SizeOfElements: rsize_t,
         _CompareFunction: _CoreCrtSecureSearchSortCompareFunction,
-        _Context: *mut ::std::os::raw::c_void,
+        _Context: *mut ::core::ffi::c_void,
     );
 }
 extern "C" {
     pub fn bsearch(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *const ::std::os::raw::c_void,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *const ::core::ffi::c_void,
         _NumOfElements: usize,
         _SizeOfElements: usize,
         _CompareFunction: _CoreCrtNonSecureSearchSortCompareFunction,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn qsort(
-        _Base: *mut ::std::os::raw::c_void,
+        _Base: *mut ::core::ffi::c_void,
         _NumOfElements: usize,
         _SizeOfElements: usize,
         _CompareFunction: _CoreCrtNonSecureSearchSortCompareFunction,
@@ -591,103 +591,103 @@ extern "C" {
 }
 extern "C" {
     pub fn _lfind_s(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *const ::std::os::raw::c_void,
-        _NumOfElements: *mut ::std::os::raw::c_uint,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *const ::core::ffi::c_void,
+        _NumOfElements: *mut ::core::ffi::c_uint,
         _SizeOfElements: usize,
         _CompareFunction: _CoreCrtSecureSearchSortCompareFunction,
-        _Context: *mut ::std::os::raw::c_void,
-    ) -> *mut ::std::os::raw::c_void;
+        _Context: *mut ::core::ffi::c_void,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _lfind(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *const ::std::os::raw::c_void,
-        _NumOfElements: *mut ::std::os::raw::c_uint,
-        _SizeOfElements: ::std::os::raw::c_uint,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *const ::core::ffi::c_void,
+        _NumOfElements: *mut ::core::ffi::c_uint,
+        _SizeOfElements: ::core::ffi::c_uint,
         _CompareFunction: _CoreCrtNonSecureSearchSortCompareFunction,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _lsearch_s(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *mut ::std::os::raw::c_void,
-        _NumOfElements: *mut ::std::os::raw::c_uint,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *mut ::core::ffi::c_void,
+        _NumOfElements: *mut ::core::ffi::c_uint,
         _SizeOfElements: usize,
         _CompareFunction: _CoreCrtSecureSearchSortCompareFunction,
-        _Context: *mut ::std::os::raw::c_void,
-    ) -> *mut ::std::os::raw::c_void;
+        _Context: *mut ::core::ffi::c_void,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _lsearch(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *mut ::std::os::raw::c_void,
-        _NumOfElements: *mut ::std::os::raw::c_uint,
-        _SizeOfElements: ::std::os::raw::c_uint,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *mut ::core::ffi::c_void,
+        _NumOfElements: *mut ::core::ffi::c_uint,
+        _SizeOfElements: ::core::ffi::c_uint,
         _CompareFunction: _CoreCrtNonSecureSearchSortCompareFunction,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn lfind(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *const ::std::os::raw::c_void,
-        _NumOfElements: *mut ::std::os::raw::c_uint,
-        _SizeOfElements: ::std::os::raw::c_uint,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *const ::core::ffi::c_void,
+        _NumOfElements: *mut ::core::ffi::c_uint,
+        _SizeOfElements: ::core::ffi::c_uint,
         _CompareFunction: _CoreCrtNonSecureSearchSortCompareFunction,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn lsearch(
-        _Key: *const ::std::os::raw::c_void,
-        _Base: *mut ::std::os::raw::c_void,
-        _NumOfElements: *mut ::std::os::raw::c_uint,
-        _SizeOfElements: ::std::os::raw::c_uint,
+        _Key: *const ::core::ffi::c_void,
+        _Base: *mut ::core::ffi::c_void,
+        _NumOfElements: *mut ::core::ffi::c_uint,
+        _SizeOfElements: ::core::ffi::c_uint,
         _CompareFunction: _CoreCrtNonSecureSearchSortCompareFunction,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn _itow_s(
-        _Value: ::std::os::raw::c_int,
+        _Value: ::core::ffi::c_int,
         _Buffer: *mut wchar_t,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _itow(
-        _Value: ::std::os::raw::c_int,
+        _Value: ::core::ffi::c_int,
         _Buffer: *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> *mut wchar_t;
 }
 extern "C" {
     pub fn _ltow_s(
-        _Value: ::std::os::raw::c_long,
+        _Value: ::core::ffi::c_long,
         _Buffer: *mut wchar_t,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _ltow(
-        _Value: ::std::os::raw::c_long,
+        _Value: ::core::ffi::c_long,
         _Buffer: *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> *mut wchar_t;
 }
 extern "C" {
     pub fn _ultow_s(
-        _Value: ::std::os::raw::c_ulong,
+        _Value: ::core::ffi::c_ulong,
         _Buffer: *mut wchar_t,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _ultow(
-        _Value: ::std::os::raw::c_ulong,
+        _Value: ::core::ffi::c_ulong,
         _Buffer: *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> *mut wchar_t;
 }
 extern "C" {
@@ -704,61 +704,61 @@ extern "C" {
     pub fn wcstol(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_long;
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn _wcstol_l(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn wcstoll(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _wcstoll_l(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn wcstoul(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn _wcstoul_l(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulong;
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn wcstoull(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _wcstoull_l(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulonglong;
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn wcstold(_String: *const wchar_t, _EndPtr: *mut *mut wchar_t) -> f64;
@@ -787,88 +787,88 @@ extern "C" {
     pub fn _wtof_l(_String: *const wchar_t, _Locale: _locale_t) -> f64;
 }
 extern "C" {
-    pub fn _wtoi(_String: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn _wtoi(_String: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _wtoi_l(_String: *const wchar_t, _Locale: _locale_t) -> ::std::os::raw::c_int;
+    pub fn _wtoi_l(_String: *const wchar_t, _Locale: _locale_t) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _wtol(_String: *const wchar_t) -> ::std::os::raw::c_long;
+    pub fn _wtol(_String: *const wchar_t) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn _wtol_l(_String: *const wchar_t, _Locale: _locale_t) -> ::std::os::raw::c_long;
+    pub fn _wtol_l(_String: *const wchar_t, _Locale: _locale_t) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn _wtoll(_String: *const wchar_t) -> ::std::os::raw::c_longlong;
+    pub fn _wtoll(_String: *const wchar_t) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn _wtoll_l(_String: *const wchar_t, _Locale: _locale_t) -> ::std::os::raw::c_longlong;
+    pub fn _wtoll_l(_String: *const wchar_t, _Locale: _locale_t) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _i64tow_s(
-        _Value: ::std::os::raw::c_longlong,
+        _Value: ::core::ffi::c_longlong,
         _Buffer: *mut wchar_t,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _i64tow(
-        _Value: ::std::os::raw::c_longlong,
+        _Value: ::core::ffi::c_longlong,
         _Buffer: *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> *mut wchar_t;
 }
 extern "C" {
     pub fn _ui64tow_s(
-        _Value: ::std::os::raw::c_ulonglong,
+        _Value: ::core::ffi::c_ulonglong,
         _Buffer: *mut wchar_t,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _ui64tow(
-        _Value: ::std::os::raw::c_ulonglong,
+        _Value: ::core::ffi::c_ulonglong,
         _Buffer: *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> *mut wchar_t;
 }
 extern "C" {
-    pub fn _wtoi64(_String: *const wchar_t) -> ::std::os::raw::c_longlong;
+    pub fn _wtoi64(_String: *const wchar_t) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn _wtoi64_l(_String: *const wchar_t, _Locale: _locale_t) -> ::std::os::raw::c_longlong;
+    pub fn _wtoi64_l(_String: *const wchar_t, _Locale: _locale_t) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _wcstoi64(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _wcstoi64_l(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _wcstoui64(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _wcstoui64_l(
         _String: *const wchar_t,
         _EndPtr: *mut *mut wchar_t,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulonglong;
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _wfullpath(
@@ -940,7 +940,7 @@ extern "C" {
     ) -> errno_t;
 }
 extern "C" {
-    pub fn _wputenv(_EnvString: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn _wputenv(_EnvString: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wputenv_s(_Name: *const wchar_t, _Value: *const wchar_t) -> errno_t;
@@ -961,55 +961,55 @@ extern "C" {
     );
 }
 extern "C" {
-    pub fn _wsystem(_Command: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn _wsystem(_Command: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _swab(
-        _Buf1: *mut ::std::os::raw::c_char,
-        _Buf2: *mut ::std::os::raw::c_char,
-        _SizeInBytes: ::std::os::raw::c_int,
+        _Buf1: *mut ::core::ffi::c_char,
+        _Buf2: *mut ::core::ffi::c_char,
+        _SizeInBytes: ::core::ffi::c_int,
     );
 }
 extern "C" {
-    pub fn exit(_Code: ::std::os::raw::c_int);
+    pub fn exit(_Code: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn _exit(_Code: ::std::os::raw::c_int);
+    pub fn _exit(_Code: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn _Exit(_Code: ::std::os::raw::c_int);
+    pub fn _Exit(_Code: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn quick_exit(_Code: ::std::os::raw::c_int);
+    pub fn quick_exit(_Code: ::core::ffi::c_int);
 }
 extern "C" {
     pub fn abort();
 }
 extern "C" {
     pub fn _set_abort_behavior(
-        _Flags: ::std::os::raw::c_uint,
-        _Mask: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_uint;
+        _Flags: ::core::ffi::c_uint,
+        _Mask: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_uint;
 }
-pub type _onexit_t = ::std::option::Option<unsafe extern "C" fn() -> ::std::os::raw::c_int>;
+pub type _onexit_t = ::core::option::Option<unsafe extern "C" fn() -> ::core::ffi::c_int>;
 extern "C" {
-    pub fn atexit(arg1: ::std::option::Option<unsafe extern "C" fn()>) -> ::std::os::raw::c_int;
+    pub fn atexit(arg1: ::core::option::Option<unsafe extern "C" fn()>) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _onexit(_Func: _onexit_t) -> _onexit_t;
 }
 extern "C" {
     pub fn at_quick_exit(
-        arg1: ::std::option::Option<unsafe extern "C" fn()>,
-    ) -> ::std::os::raw::c_int;
+        arg1: ::core::option::Option<unsafe extern "C" fn()>,
+    ) -> ::core::ffi::c_int;
 }
-pub type _purecall_handler = ::std::option::Option<unsafe extern "C" fn()>;
-pub type _invalid_parameter_handler = ::std::option::Option<
+pub type _purecall_handler = ::core::option::Option<unsafe extern "C" fn()>;
+pub type _invalid_parameter_handler = ::core::option::Option<
     unsafe extern "C" fn(
         arg1: *const wchar_t,
         arg2: *const wchar_t,
         arg3: *const wchar_t,
-        arg4: ::std::os::raw::c_uint,
+        arg4: ::core::ffi::c_uint,
         arg5: usize,
     ),
 >;
@@ -1036,71 +1036,71 @@ extern "C" {
     pub fn _get_thread_local_invalid_parameter_handler() -> _invalid_parameter_handler;
 }
 extern "C" {
-    pub fn _set_error_mode(_Mode: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn _set_error_mode(_Mode: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn __doserrno() -> *mut ::std::os::raw::c_ulong;
+    pub fn __doserrno() -> *mut ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn _set_doserrno(_Value: ::std::os::raw::c_ulong) -> errno_t;
+    pub fn _set_doserrno(_Value: ::core::ffi::c_ulong) -> errno_t;
 }
 extern "C" {
-    pub fn _get_doserrno(_Value: *mut ::std::os::raw::c_ulong) -> errno_t;
+    pub fn _get_doserrno(_Value: *mut ::core::ffi::c_ulong) -> errno_t;
 }
 extern "C" {
-    pub fn __sys_errlist() -> *mut *mut ::std::os::raw::c_char;
+    pub fn __sys_errlist() -> *mut *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn __sys_nerr() -> *mut ::std::os::raw::c_int;
+    pub fn __sys_nerr() -> *mut ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn perror(_ErrMsg: *const ::std::os::raw::c_char);
+    pub fn perror(_ErrMsg: *const ::core::ffi::c_char);
 }
 extern "C" {
-    pub fn __p__pgmptr() -> *mut *mut ::std::os::raw::c_char;
+    pub fn __p__pgmptr() -> *mut *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn __p__wpgmptr() -> *mut *mut wchar_t;
 }
 extern "C" {
-    pub fn __p__fmode() -> *mut ::std::os::raw::c_int;
+    pub fn __p__fmode() -> *mut ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _get_pgmptr(_Value: *mut *mut ::std::os::raw::c_char) -> errno_t;
+    pub fn _get_pgmptr(_Value: *mut *mut ::core::ffi::c_char) -> errno_t;
 }
 extern "C" {
     pub fn _get_wpgmptr(_Value: *mut *mut wchar_t) -> errno_t;
 }
 extern "C" {
-    pub fn _set_fmode(_Mode: ::std::os::raw::c_int) -> errno_t;
+    pub fn _set_fmode(_Mode: ::core::ffi::c_int) -> errno_t;
 }
 extern "C" {
-    pub fn _get_fmode(_PMode: *mut ::std::os::raw::c_int) -> errno_t;
+    pub fn _get_fmode(_PMode: *mut ::core::ffi::c_int) -> errno_t;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _div_t {
-    pub quot: ::std::os::raw::c_int,
-    pub rem: ::std::os::raw::c_int,
+    pub quot: ::core::ffi::c_int,
+    pub rem: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout__div_t() {
     assert_eq!(
-        ::std::mem::size_of::<_div_t>(),
+        ::core::mem::size_of::<_div_t>(),
         8usize,
         concat!("Size of: ", stringify!(_div_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_div_t>(),
+        ::core::mem::align_of::<_div_t>(),
         4usize,
         concat!("Alignment of ", stringify!(_div_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_div_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_div_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1115,9 +1115,9 @@ fn bindgen_test_layout__div_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_div_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_div_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -1134,27 +1134,27 @@ pub type div_t = _div_t;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _ldiv_t {
-    pub quot: ::std::os::raw::c_long,
-    pub rem: ::std::os::raw::c_long,
+    pub quot: ::core::ffi::c_long,
+    pub rem: ::core::ffi::c_long,
 }
 #[test]
 fn bindgen_test_layout__ldiv_t() {
     assert_eq!(
-        ::std::mem::size_of::<_ldiv_t>(),
+        ::core::mem::size_of::<_ldiv_t>(),
         8usize,
         concat!("Size of: ", stringify!(_ldiv_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_ldiv_t>(),
+        ::core::mem::align_of::<_ldiv_t>(),
         4usize,
         concat!("Alignment of ", stringify!(_ldiv_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_ldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_ldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1169,9 +1169,9 @@ fn bindgen_test_layout__ldiv_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_ldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_ldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -1188,27 +1188,27 @@ pub type ldiv_t = _ldiv_t;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _lldiv_t {
-    pub quot: ::std::os::raw::c_longlong,
-    pub rem: ::std::os::raw::c_longlong,
+    pub quot: ::core::ffi::c_longlong,
+    pub rem: ::core::ffi::c_longlong,
 }
 #[test]
 fn bindgen_test_layout__lldiv_t() {
     assert_eq!(
-        ::std::mem::size_of::<_lldiv_t>(),
+        ::core::mem::size_of::<_lldiv_t>(),
         16usize,
         concat!("Size of: ", stringify!(_lldiv_t))
     );
     assert_eq!(
-        ::std::mem::align_of::<_lldiv_t>(),
+        ::core::mem::align_of::<_lldiv_t>(),
         8usize,
         concat!("Alignment of ", stringify!(_lldiv_t))
     );
     fn test_field_quot() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_lldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_lldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).quot) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1223,9 +1223,9 @@ fn bindgen_test_layout__lldiv_t() {
     fn test_field_rem() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_lldiv_t>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_lldiv_t>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).rem) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -1240,104 +1240,104 @@ fn bindgen_test_layout__lldiv_t() {
 }
 pub type lldiv_t = _lldiv_t;
 extern "C" {
-    pub fn abs(_Number: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn abs(_Number: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn labs(_Number: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+    pub fn labs(_Number: ::core::ffi::c_long) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn llabs(_Number: ::std::os::raw::c_longlong) -> ::std::os::raw::c_longlong;
+    pub fn llabs(_Number: ::core::ffi::c_longlong) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn _abs64(_Number: ::std::os::raw::c_longlong) -> ::std::os::raw::c_longlong;
+    pub fn _abs64(_Number: ::core::ffi::c_longlong) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn _byteswap_ushort(_Number: ::std::os::raw::c_ushort) -> ::std::os::raw::c_ushort;
+    pub fn _byteswap_ushort(_Number: ::core::ffi::c_ushort) -> ::core::ffi::c_ushort;
 }
 extern "C" {
-    pub fn _byteswap_ulong(_Number: ::std::os::raw::c_ulong) -> ::std::os::raw::c_ulong;
+    pub fn _byteswap_ulong(_Number: ::core::ffi::c_ulong) -> ::core::ffi::c_ulong;
 }
 extern "C" {
-    pub fn _byteswap_uint64(_Number: ::std::os::raw::c_ulonglong) -> ::std::os::raw::c_ulonglong;
+    pub fn _byteswap_uint64(_Number: ::core::ffi::c_ulonglong) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
-    pub fn div(_Numerator: ::std::os::raw::c_int, _Denominator: ::std::os::raw::c_int) -> div_t;
+    pub fn div(_Numerator: ::core::ffi::c_int, _Denominator: ::core::ffi::c_int) -> div_t;
 }
 extern "C" {
-    pub fn ldiv(_Numerator: ::std::os::raw::c_long, _Denominator: ::std::os::raw::c_long)
+    pub fn ldiv(_Numerator: ::core::ffi::c_long, _Denominator: ::core::ffi::c_long)
         -> ldiv_t;
 }
 extern "C" {
     pub fn lldiv(
-        _Numerator: ::std::os::raw::c_longlong,
-        _Denominator: ::std::os::raw::c_longlong,
+        _Numerator: ::core::ffi::c_longlong,
+        _Denominator: ::core::ffi::c_longlong,
     ) -> lldiv_t;
 }
 extern "C" {
     pub fn _rotl(
-        _Value: ::std::os::raw::c_uint,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_uint;
+        _Value: ::core::ffi::c_uint,
+        _Shift: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn _lrotl(
-        _Value: ::std::os::raw::c_ulong,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
+        _Value: ::core::ffi::c_ulong,
+        _Shift: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn _rotl64(
-        _Value: ::std::os::raw::c_ulonglong,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        _Value: ::core::ffi::c_ulonglong,
+        _Shift: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _rotr(
-        _Value: ::std::os::raw::c_uint,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_uint;
+        _Value: ::core::ffi::c_uint,
+        _Shift: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_uint;
 }
 extern "C" {
     pub fn _lrotr(
-        _Value: ::std::os::raw::c_ulong,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
+        _Value: ::core::ffi::c_ulong,
+        _Shift: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn _rotr64(
-        _Value: ::std::os::raw::c_ulonglong,
-        _Shift: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        _Value: ::core::ffi::c_ulonglong,
+        _Shift: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
-    pub fn srand(_Seed: ::std::os::raw::c_uint);
+    pub fn srand(_Seed: ::core::ffi::c_uint);
 }
 extern "C" {
-    pub fn rand() -> ::std::os::raw::c_int;
+    pub fn rand() -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _LDOUBLE {
-    pub ld: [::std::os::raw::c_uchar; 10usize],
+    pub ld: [::core::ffi::c_uchar; 10usize],
 }
 #[test]
 fn bindgen_test_layout__LDOUBLE() {
     assert_eq!(
-        ::std::mem::size_of::<_LDOUBLE>(),
+        ::core::mem::size_of::<_LDOUBLE>(),
         10usize,
         concat!("Size of: ", stringify!(_LDOUBLE))
     );
     assert_eq!(
-        ::std::mem::align_of::<_LDOUBLE>(),
+        ::core::mem::align_of::<_LDOUBLE>(),
         1usize,
         concat!("Alignment of ", stringify!(_LDOUBLE))
     );
     fn test_field_ld() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_LDOUBLE>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_LDOUBLE>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ld) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ld) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1358,21 +1358,21 @@ pub struct _CRT_DOUBLE {
 #[test]
 fn bindgen_test_layout__CRT_DOUBLE() {
     assert_eq!(
-        ::std::mem::size_of::<_CRT_DOUBLE>(),
+        ::core::mem::size_of::<_CRT_DOUBLE>(),
         8usize,
         concat!("Size of: ", stringify!(_CRT_DOUBLE))
     );
     assert_eq!(
-        ::std::mem::align_of::<_CRT_DOUBLE>(),
+        ::core::mem::align_of::<_CRT_DOUBLE>(),
         8usize,
         concat!("Alignment of ", stringify!(_CRT_DOUBLE))
     );
     fn test_field_x() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_CRT_DOUBLE>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_CRT_DOUBLE>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).x) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1393,21 +1393,21 @@ pub struct _CRT_FLOAT {
 #[test]
 fn bindgen_test_layout__CRT_FLOAT() {
     assert_eq!(
-        ::std::mem::size_of::<_CRT_FLOAT>(),
+        ::core::mem::size_of::<_CRT_FLOAT>(),
         4usize,
         concat!("Size of: ", stringify!(_CRT_FLOAT))
     );
     assert_eq!(
-        ::std::mem::align_of::<_CRT_FLOAT>(),
+        ::core::mem::align_of::<_CRT_FLOAT>(),
         4usize,
         concat!("Alignment of ", stringify!(_CRT_FLOAT))
     );
     fn test_field_f() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_CRT_FLOAT>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_CRT_FLOAT>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).f) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).f) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1428,21 +1428,21 @@ pub struct _LONGDOUBLE {
 #[test]
 fn bindgen_test_layout__LONGDOUBLE() {
     assert_eq!(
-        ::std::mem::size_of::<_LONGDOUBLE>(),
+        ::core::mem::size_of::<_LONGDOUBLE>(),
         8usize,
         concat!("Size of: ", stringify!(_LONGDOUBLE))
     );
     assert_eq!(
-        ::std::mem::align_of::<_LONGDOUBLE>(),
+        ::core::mem::align_of::<_LONGDOUBLE>(),
         8usize,
         concat!("Alignment of ", stringify!(_LONGDOUBLE))
     );
     fn test_field_x() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_LONGDOUBLE>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_LONGDOUBLE>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).x) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1458,26 +1458,26 @@ fn bindgen_test_layout__LONGDOUBLE() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _LDBL12 {
-    pub ld12: [::std::os::raw::c_uchar; 12usize],
+    pub ld12: [::core::ffi::c_uchar; 12usize],
 }
 #[test]
 fn bindgen_test_layout__LDBL12() {
     assert_eq!(
-        ::std::mem::size_of::<_LDBL12>(),
+        ::core::mem::size_of::<_LDBL12>(),
         12usize,
         concat!("Size of: ", stringify!(_LDBL12))
     );
     assert_eq!(
-        ::std::mem::align_of::<_LDBL12>(),
+        ::core::mem::align_of::<_LDBL12>(),
         1usize,
         concat!("Alignment of ", stringify!(_LDBL12))
     );
     fn test_field_ld12() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<_LDBL12>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<_LDBL12>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ld12) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ld12) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -1491,369 +1491,369 @@ fn bindgen_test_layout__LDBL12() {
     test_field_ld12();
 }
 extern "C" {
-    pub fn atof(_String: *const ::std::os::raw::c_char) -> f64;
+    pub fn atof(_String: *const ::core::ffi::c_char) -> f64;
 }
 extern "C" {
-    pub fn atoi(_String: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn atoi(_String: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn atol(_String: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+    pub fn atol(_String: *const ::core::ffi::c_char) -> ::core::ffi::c_long;
 }
 extern "C" {
-    pub fn atoll(_String: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
+    pub fn atoll(_String: *const ::core::ffi::c_char) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn _atoi64(_String: *const ::std::os::raw::c_char) -> ::std::os::raw::c_longlong;
+    pub fn _atoi64(_String: *const ::core::ffi::c_char) -> ::core::ffi::c_longlong;
 }
 extern "C" {
-    pub fn _atof_l(_String: *const ::std::os::raw::c_char, _Locale: _locale_t) -> f64;
+    pub fn _atof_l(_String: *const ::core::ffi::c_char, _Locale: _locale_t) -> f64;
 }
 extern "C" {
     pub fn _atoi_l(
-        _String: *const ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _atol_l(
-        _String: *const ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn _atoll_l(
-        _String: *const ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _atoi64_l(
-        _String: *const ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _atoflt(
         _Result: *mut _CRT_FLOAT,
-        _String: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _atodbl(
         _Result: *mut _CRT_DOUBLE,
-        _String: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String: *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _atoldbl(
         _Result: *mut _LDOUBLE,
-        _String: *mut ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String: *mut ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _atoflt_l(
         _Result: *mut _CRT_FLOAT,
-        _String: *const ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _atodbl_l(
         _Result: *mut _CRT_DOUBLE,
-        _String: *mut ::std::os::raw::c_char,
+        _String: *mut ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _atoldbl_l(
         _Result: *mut _LDOUBLE,
-        _String: *mut ::std::os::raw::c_char,
+        _String: *mut ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strtof(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
     ) -> f32;
 }
 extern "C" {
     pub fn _strtof_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
         _Locale: _locale_t,
     ) -> f32;
 }
 extern "C" {
     pub fn strtod(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
     ) -> f64;
 }
 extern "C" {
     pub fn _strtod_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
         _Locale: _locale_t,
     ) -> f64;
 }
 extern "C" {
     pub fn strtold(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
     ) -> f64;
 }
 extern "C" {
     pub fn _strtold_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
         _Locale: _locale_t,
     ) -> f64;
 }
 extern "C" {
     pub fn strtol(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_long;
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn _strtol_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_long;
+    ) -> ::core::ffi::c_long;
 }
 extern "C" {
     pub fn strtoll(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _strtoll_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn strtoul(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulong;
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn _strtoul_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulong;
+    ) -> ::core::ffi::c_ulong;
 }
 extern "C" {
     pub fn strtoull(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _strtoull_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulonglong;
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _strtoi64(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_longlong;
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _strtoi64_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_longlong;
+    ) -> ::core::ffi::c_longlong;
 }
 extern "C" {
     pub fn _strtoui64(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_ulonglong;
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _strtoui64_l(
-        _String: *const ::std::os::raw::c_char,
-        _EndPtr: *mut *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
+        _String: *const ::core::ffi::c_char,
+        _EndPtr: *mut *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_ulonglong;
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _itoa_s(
-        _Value: ::std::os::raw::c_int,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Value: ::core::ffi::c_int,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _itoa(
-        _Value: ::std::os::raw::c_int,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_int,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _ltoa_s(
-        _Value: ::std::os::raw::c_long,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Value: ::core::ffi::c_long,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _ltoa(
-        _Value: ::std::os::raw::c_long,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_long,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _ultoa_s(
-        _Value: ::std::os::raw::c_ulong,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Value: ::core::ffi::c_ulong,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _ultoa(
-        _Value: ::std::os::raw::c_ulong,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_ulong,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _i64toa_s(
-        _Value: ::std::os::raw::c_longlong,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Value: ::core::ffi::c_longlong,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _i64toa(
-        _Value: ::std::os::raw::c_longlong,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_longlong,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _ui64toa_s(
-        _Value: ::std::os::raw::c_ulonglong,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Value: ::core::ffi::c_ulonglong,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
-        _Radix: ::std::os::raw::c_int,
+        _Radix: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _ui64toa(
-        _Value: ::std::os::raw::c_ulonglong,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_ulonglong,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _ecvt_s(
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
         _Value: f64,
-        _DigitCount: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
+        _DigitCount: ::core::ffi::c_int,
+        _PtDec: *mut ::core::ffi::c_int,
+        _PtSign: *mut ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _ecvt(
         _Value: f64,
-        _DigitCount: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _DigitCount: ::core::ffi::c_int,
+        _PtDec: *mut ::core::ffi::c_int,
+        _PtSign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _fcvt_s(
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
         _Value: f64,
-        _FractionalDigitCount: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
+        _FractionalDigitCount: ::core::ffi::c_int,
+        _PtDec: *mut ::core::ffi::c_int,
+        _PtSign: *mut ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _fcvt(
         _Value: f64,
-        _FractionalDigitCount: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _FractionalDigitCount: ::core::ffi::c_int,
+        _PtDec: *mut ::core::ffi::c_int,
+        _PtSign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _gcvt_s(
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
         _Value: f64,
-        _DigitCount: ::std::os::raw::c_int,
+        _DigitCount: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _gcvt(
         _Value: f64,
-        _DigitCount: ::std::os::raw::c_int,
-        _Buffer: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _DigitCount: ::core::ffi::c_int,
+        _Buffer: *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn ___mb_cur_max_func() -> ::std::os::raw::c_int;
+    pub fn ___mb_cur_max_func() -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn ___mb_cur_max_l_func(_Locale: _locale_t) -> ::std::os::raw::c_int;
+    pub fn ___mb_cur_max_l_func(_Locale: _locale_t) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn mblen(_Ch: *const ::std::os::raw::c_char, _MaxCount: usize) -> ::std::os::raw::c_int;
+    pub fn mblen(_Ch: *const ::core::ffi::c_char, _MaxCount: usize) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _mblen_l(
-        _Ch: *const ::std::os::raw::c_char,
+        _Ch: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _mbstrlen(_String: *const ::std::os::raw::c_char) -> usize;
+    pub fn _mbstrlen(_String: *const ::core::ffi::c_char) -> usize;
 }
 extern "C" {
-    pub fn _mbstrlen_l(_String: *const ::std::os::raw::c_char, _Locale: _locale_t) -> usize;
+    pub fn _mbstrlen_l(_String: *const ::core::ffi::c_char, _Locale: _locale_t) -> usize;
 }
 extern "C" {
-    pub fn _mbstrnlen(_String: *const ::std::os::raw::c_char, _MaxCount: usize) -> usize;
+    pub fn _mbstrnlen(_String: *const ::core::ffi::c_char, _MaxCount: usize) -> usize;
 }
 extern "C" {
     pub fn _mbstrnlen_l(
-        _String: *const ::std::os::raw::c_char,
+        _String: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
     ) -> usize;
@@ -1861,31 +1861,31 @@ extern "C" {
 extern "C" {
     pub fn mbtowc(
         _DstCh: *mut wchar_t,
-        _SrcCh: *const ::std::os::raw::c_char,
+        _SrcCh: *const ::core::ffi::c_char,
         _SrcSizeInBytes: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _mbtowc_l(
         _DstCh: *mut wchar_t,
-        _SrcCh: *const ::std::os::raw::c_char,
+        _SrcCh: *const ::core::ffi::c_char,
         _SrcSizeInBytes: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn mbstowcs_s(
         _PtNumOfCharConverted: *mut usize,
         _DstBuf: *mut wchar_t,
         _SizeInWords: usize,
-        _SrcBuf: *const ::std::os::raw::c_char,
+        _SrcBuf: *const ::core::ffi::c_char,
         _MaxCount: usize,
     ) -> errno_t;
 }
 extern "C" {
     pub fn mbstowcs(
         _Dest: *mut wchar_t,
-        _Source: *const ::std::os::raw::c_char,
+        _Source: *const ::core::ffi::c_char,
         _MaxCount: usize,
     ) -> usize;
 }
@@ -1894,7 +1894,7 @@ extern "C" {
         _PtNumOfCharConverted: *mut usize,
         _DstBuf: *mut wchar_t,
         _SizeInWords: usize,
-        _SrcBuf: *const ::std::os::raw::c_char,
+        _SrcBuf: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
     ) -> errno_t;
@@ -1902,33 +1902,33 @@ extern "C" {
 extern "C" {
     pub fn _mbstowcs_l(
         _Dest: *mut wchar_t,
-        _Source: *const ::std::os::raw::c_char,
+        _Source: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
     ) -> usize;
 }
 extern "C" {
-    pub fn wctomb(_MbCh: *mut ::std::os::raw::c_char, _WCh: wchar_t) -> ::std::os::raw::c_int;
+    pub fn wctomb(_MbCh: *mut ::core::ffi::c_char, _WCh: wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wctomb_l(
-        _MbCh: *mut ::std::os::raw::c_char,
+        _MbCh: *mut ::core::ffi::c_char,
         _WCh: wchar_t,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wctomb_s(
-        _SizeConverted: *mut ::std::os::raw::c_int,
-        _MbCh: *mut ::std::os::raw::c_char,
+        _SizeConverted: *mut ::core::ffi::c_int,
+        _MbCh: *mut ::core::ffi::c_char,
         _SizeInBytes: rsize_t,
         _WCh: wchar_t,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _wctomb_s_l(
-        _SizeConverted: *mut ::std::os::raw::c_int,
-        _MbCh: *mut ::std::os::raw::c_char,
+        _SizeConverted: *mut ::core::ffi::c_int,
+        _MbCh: *mut ::core::ffi::c_char,
         _SizeInBytes: usize,
         _WCh: wchar_t,
         _Locale: _locale_t,
@@ -1937,7 +1937,7 @@ extern "C" {
 extern "C" {
     pub fn wcstombs_s(
         _PtNumOfCharConverted: *mut usize,
-        _Dst: *mut ::std::os::raw::c_char,
+        _Dst: *mut ::core::ffi::c_char,
         _DstSizeInBytes: usize,
         _Src: *const wchar_t,
         _MaxCountInBytes: usize,
@@ -1945,7 +1945,7 @@ extern "C" {
 }
 extern "C" {
     pub fn wcstombs(
-        _Dest: *mut ::std::os::raw::c_char,
+        _Dest: *mut ::core::ffi::c_char,
         _Source: *const wchar_t,
         _MaxCount: usize,
     ) -> usize;
@@ -1953,7 +1953,7 @@ extern "C" {
 extern "C" {
     pub fn _wcstombs_s_l(
         _PtNumOfCharConverted: *mut usize,
-        _Dst: *mut ::std::os::raw::c_char,
+        _Dst: *mut ::core::ffi::c_char,
         _DstSizeInBytes: usize,
         _Src: *const wchar_t,
         _MaxCountInBytes: usize,
@@ -1962,7 +1962,7 @@ extern "C" {
 }
 extern "C" {
     pub fn _wcstombs_l(
-        _Dest: *mut ::std::os::raw::c_char,
+        _Dest: *mut ::core::ffi::c_char,
         _Source: *const wchar_t,
         _MaxCount: usize,
         _Locale: _locale_t,
@@ -1970,254 +1970,254 @@ extern "C" {
 }
 extern "C" {
     pub fn _fullpath(
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Path: *const ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Path: *const ::core::ffi::c_char,
         _BufferCount: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _makepath_s(
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
-        _Drive: *const ::std::os::raw::c_char,
-        _Dir: *const ::std::os::raw::c_char,
-        _Filename: *const ::std::os::raw::c_char,
-        _Ext: *const ::std::os::raw::c_char,
+        _Drive: *const ::core::ffi::c_char,
+        _Dir: *const ::core::ffi::c_char,
+        _Filename: *const ::core::ffi::c_char,
+        _Ext: *const ::core::ffi::c_char,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _makepath(
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Drive: *const ::std::os::raw::c_char,
-        _Dir: *const ::std::os::raw::c_char,
-        _Filename: *const ::std::os::raw::c_char,
-        _Ext: *const ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Drive: *const ::core::ffi::c_char,
+        _Dir: *const ::core::ffi::c_char,
+        _Filename: *const ::core::ffi::c_char,
+        _Ext: *const ::core::ffi::c_char,
     );
 }
 extern "C" {
     pub fn _splitpath(
-        _FullPath: *const ::std::os::raw::c_char,
-        _Drive: *mut ::std::os::raw::c_char,
-        _Dir: *mut ::std::os::raw::c_char,
-        _Filename: *mut ::std::os::raw::c_char,
-        _Ext: *mut ::std::os::raw::c_char,
+        _FullPath: *const ::core::ffi::c_char,
+        _Drive: *mut ::core::ffi::c_char,
+        _Dir: *mut ::core::ffi::c_char,
+        _Filename: *mut ::core::ffi::c_char,
+        _Ext: *mut ::core::ffi::c_char,
     );
 }
 extern "C" {
     pub fn _splitpath_s(
-        _FullPath: *const ::std::os::raw::c_char,
-        _Drive: *mut ::std::os::raw::c_char,
+        _FullPath: *const ::core::ffi::c_char,
+        _Drive: *mut ::core::ffi::c_char,
         _DriveCount: usize,
-        _Dir: *mut ::std::os::raw::c_char,
+        _Dir: *mut ::core::ffi::c_char,
         _DirCount: usize,
-        _Filename: *mut ::std::os::raw::c_char,
+        _Filename: *mut ::core::ffi::c_char,
         _FilenameCount: usize,
-        _Ext: *mut ::std::os::raw::c_char,
+        _Ext: *mut ::core::ffi::c_char,
         _ExtCount: usize,
     ) -> errno_t;
 }
 extern "C" {
     pub fn getenv_s(
         _RequiredCount: *mut usize,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: rsize_t,
-        _VarName: *const ::std::os::raw::c_char,
+        _VarName: *const ::core::ffi::c_char,
     ) -> errno_t;
 }
 extern "C" {
-    pub fn __p___argc() -> *mut ::std::os::raw::c_int;
+    pub fn __p___argc() -> *mut ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn __p___argv() -> *mut *mut *mut ::std::os::raw::c_char;
+    pub fn __p___argv() -> *mut *mut *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn __p___wargv() -> *mut *mut *mut wchar_t;
 }
 extern "C" {
-    pub fn __p__environ() -> *mut *mut *mut ::std::os::raw::c_char;
+    pub fn __p__environ() -> *mut *mut *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn __p__wenviron() -> *mut *mut *mut wchar_t;
 }
 extern "C" {
-    pub fn getenv(_VarName: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn getenv(_VarName: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _dupenv_s(
-        _Buffer: *mut *mut ::std::os::raw::c_char,
+        _Buffer: *mut *mut ::core::ffi::c_char,
         _BufferCount: *mut usize,
-        _VarName: *const ::std::os::raw::c_char,
+        _VarName: *const ::core::ffi::c_char,
     ) -> errno_t;
 }
 extern "C" {
-    pub fn system(_Command: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn system(_Command: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _putenv(_EnvString: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn _putenv(_EnvString: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _putenv_s(
-        _Name: *const ::std::os::raw::c_char,
-        _Value: *const ::std::os::raw::c_char,
+        _Name: *const ::core::ffi::c_char,
+        _Value: *const ::core::ffi::c_char,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _searchenv_s(
-        _Filename: *const ::std::os::raw::c_char,
-        _VarName: *const ::std::os::raw::c_char,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Filename: *const ::core::ffi::c_char,
+        _VarName: *const ::core::ffi::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _BufferCount: usize,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _searchenv(
-        _Filename: *const ::std::os::raw::c_char,
-        _VarName: *const ::std::os::raw::c_char,
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Filename: *const ::core::ffi::c_char,
+        _VarName: *const ::core::ffi::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
     );
 }
 extern "C" {
-    pub fn _seterrormode(_Mode: ::std::os::raw::c_int);
+    pub fn _seterrormode(_Mode: ::core::ffi::c_int);
 }
 extern "C" {
-    pub fn _beep(_Frequency: ::std::os::raw::c_uint, _Duration: ::std::os::raw::c_uint);
+    pub fn _beep(_Frequency: ::core::ffi::c_uint, _Duration: ::core::ffi::c_uint);
 }
 extern "C" {
-    pub fn _sleep(_Duration: ::std::os::raw::c_ulong);
+    pub fn _sleep(_Duration: ::core::ffi::c_ulong);
 }
 extern "C" {
     pub fn ecvt(
         _Value: f64,
-        _DigitCount: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _DigitCount: ::core::ffi::c_int,
+        _PtDec: *mut ::core::ffi::c_int,
+        _PtSign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn fcvt(
         _Value: f64,
-        _FractionalDigitCount: ::std::os::raw::c_int,
-        _PtDec: *mut ::std::os::raw::c_int,
-        _PtSign: *mut ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _FractionalDigitCount: ::core::ffi::c_int,
+        _PtDec: *mut ::core::ffi::c_int,
+        _PtSign: *mut ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn gcvt(
         _Value: f64,
-        _DigitCount: ::std::os::raw::c_int,
-        _DstBuf: *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _DigitCount: ::core::ffi::c_int,
+        _DstBuf: *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn itoa(
-        _Value: ::std::os::raw::c_int,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_int,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn ltoa(
-        _Value: ::std::os::raw::c_long,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_long,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn swab(
-        _Buf1: *mut ::std::os::raw::c_char,
-        _Buf2: *mut ::std::os::raw::c_char,
-        _SizeInBytes: ::std::os::raw::c_int,
+        _Buf1: *mut ::core::ffi::c_char,
+        _Buf2: *mut ::core::ffi::c_char,
+        _SizeInBytes: ::core::ffi::c_int,
     );
 }
 extern "C" {
     pub fn ultoa(
-        _Value: ::std::os::raw::c_ulong,
-        _Buffer: *mut ::std::os::raw::c_char,
-        _Radix: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Value: ::core::ffi::c_ulong,
+        _Buffer: *mut ::core::ffi::c_char,
+        _Radix: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn putenv(_EnvString: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn putenv(_EnvString: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn onexit(_Func: _onexit_t) -> _onexit_t;
 }
-pub type int_least8_t = ::std::os::raw::c_schar;
-pub type int_least16_t = ::std::os::raw::c_short;
-pub type int_least32_t = ::std::os::raw::c_int;
-pub type int_least64_t = ::std::os::raw::c_longlong;
-pub type uint_least8_t = ::std::os::raw::c_uchar;
-pub type uint_least16_t = ::std::os::raw::c_ushort;
-pub type uint_least32_t = ::std::os::raw::c_uint;
-pub type uint_least64_t = ::std::os::raw::c_ulonglong;
-pub type int_fast8_t = ::std::os::raw::c_schar;
-pub type int_fast16_t = ::std::os::raw::c_int;
-pub type int_fast32_t = ::std::os::raw::c_int;
-pub type int_fast64_t = ::std::os::raw::c_longlong;
-pub type uint_fast8_t = ::std::os::raw::c_uchar;
-pub type uint_fast16_t = ::std::os::raw::c_uint;
-pub type uint_fast32_t = ::std::os::raw::c_uint;
-pub type uint_fast64_t = ::std::os::raw::c_ulonglong;
-pub type intmax_t = ::std::os::raw::c_longlong;
-pub type uintmax_t = ::std::os::raw::c_ulonglong;
+pub type int_least8_t = ::core::ffi::c_schar;
+pub type int_least16_t = ::core::ffi::c_short;
+pub type int_least32_t = ::core::ffi::c_int;
+pub type int_least64_t = ::core::ffi::c_longlong;
+pub type uint_least8_t = ::core::ffi::c_uchar;
+pub type uint_least16_t = ::core::ffi::c_ushort;
+pub type uint_least32_t = ::core::ffi::c_uint;
+pub type uint_least64_t = ::core::ffi::c_ulonglong;
+pub type int_fast8_t = ::core::ffi::c_schar;
+pub type int_fast16_t = ::core::ffi::c_int;
+pub type int_fast32_t = ::core::ffi::c_int;
+pub type int_fast64_t = ::core::ffi::c_longlong;
+pub type uint_fast8_t = ::core::ffi::c_uchar;
+pub type uint_fast16_t = ::core::ffi::c_uint;
+pub type uint_fast32_t = ::core::ffi::c_uint;
+pub type uint_fast64_t = ::core::ffi::c_ulonglong;
+pub type intmax_t = ::core::ffi::c_longlong;
+pub type uintmax_t = ::core::ffi::c_ulonglong;
 extern "C" {
     pub fn memchr(
-        _Buf: *const ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
-        _MaxCount: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_void;
+        _Buf: *const ::core::ffi::c_void,
+        _Val: ::core::ffi::c_int,
+        _MaxCount: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memcmp(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
-        _Size: ::std::os::raw::c_ulonglong,
-    ) -> ::std::os::raw::c_int;
+        _Buf1: *const ::core::ffi::c_void,
+        _Buf2: *const ::core::ffi::c_void,
+        _Size: ::core::ffi::c_ulonglong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn memcpy(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Size: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_void;
+        _Dst: *mut ::core::ffi::c_void,
+        _Src: *const ::core::ffi::c_void,
+        _Size: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memmove(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Size: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_void;
+        _Dst: *mut ::core::ffi::c_void,
+        _Src: *const ::core::ffi::c_void,
+        _Size: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memset(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
-        _Size: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_void;
+        _Dst: *mut ::core::ffi::c_void,
+        _Val: ::core::ffi::c_int,
+        _Size: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn strchr(
-        _Str: *const ::std::os::raw::c_char,
-        _Val: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Str: *const ::core::ffi::c_char,
+        _Val: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strrchr(
-        _Str: *const ::std::os::raw::c_char,
-        _Ch: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Str: *const ::core::ffi::c_char,
+        _Ch: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strstr(
-        _Str: *const ::std::os::raw::c_char,
-        _SubStr: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _Str: *const ::core::ffi::c_char,
+        _SubStr: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn wcschr(
-        _Str: *const ::std::os::raw::c_ushort,
-        _Ch: ::std::os::raw::c_ushort,
-    ) -> *mut ::std::os::raw::c_ushort;
+        _Str: *const ::core::ffi::c_ushort,
+        _Ch: ::core::ffi::c_ushort,
+    ) -> *mut ::core::ffi::c_ushort;
 }
 extern "C" {
     pub fn wcsrchr(_Str: *const wchar_t, _Ch: wchar_t) -> *mut wchar_t;
@@ -2227,33 +2227,33 @@ extern "C" {
 }
 extern "C" {
     pub fn _memicmp(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
+        _Buf1: *const ::core::ffi::c_void,
+        _Buf2: *const ::core::ffi::c_void,
         _Size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _memicmp_l(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
+        _Buf1: *const ::core::ffi::c_void,
+        _Buf2: *const ::core::ffi::c_void,
         _Size: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn memccpy(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
-        _Size: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_void;
+        _Dst: *mut ::core::ffi::c_void,
+        _Src: *const ::core::ffi::c_void,
+        _Val: ::core::ffi::c_int,
+        _Size: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn memicmp(
-        _Buf1: *const ::std::os::raw::c_void,
-        _Buf2: *const ::std::os::raw::c_void,
+        _Buf1: *const ::core::ffi::c_void,
+        _Buf2: *const ::core::ffi::c_void,
         _Size: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wcscat_s(
@@ -2300,9 +2300,9 @@ extern "C" {
 }
 extern "C" {
     pub fn wcscmp(
-        _String1: *const ::std::os::raw::c_ushort,
-        _String2: *const ::std::os::raw::c_ushort,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_ushort,
+        _String2: *const ::core::ffi::c_ushort,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wcscpy(_Destination: *mut wchar_t, _Source: *const wchar_t) -> *mut wchar_t;
@@ -2311,7 +2311,7 @@ extern "C" {
     pub fn wcscspn(_String: *const wchar_t, _Control: *const wchar_t) -> usize;
 }
 extern "C" {
-    pub fn wcslen(_String: *const ::std::os::raw::c_ushort) -> ::std::os::raw::c_ulonglong;
+    pub fn wcslen(_String: *const ::core::ffi::c_ushort) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn wcsnlen(_Source: *const wchar_t, _MaxCount: usize) -> usize;
@@ -2325,10 +2325,10 @@ extern "C" {
 }
 extern "C" {
     pub fn wcsncmp(
-        _String1: *const ::std::os::raw::c_ushort,
-        _String2: *const ::std::os::raw::c_ushort,
-        _MaxCount: ::std::os::raw::c_ulonglong,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_ushort,
+        _String2: *const ::core::ffi::c_ushort,
+        _MaxCount: ::core::ffi::c_ulonglong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wcsncpy(
@@ -2351,13 +2351,13 @@ extern "C" {
     ) -> *mut wchar_t;
 }
 extern "C" {
-    pub fn _wcserror(_ErrorNumber: ::std::os::raw::c_int) -> *mut wchar_t;
+    pub fn _wcserror(_ErrorNumber: ::core::ffi::c_int) -> *mut wchar_t;
 }
 extern "C" {
     pub fn _wcserror_s(
         _Buffer: *mut wchar_t,
         _SizeInWords: usize,
-        _ErrorNumber: ::std::os::raw::c_int,
+        _ErrorNumber: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
@@ -2371,21 +2371,21 @@ extern "C" {
     ) -> errno_t;
 }
 extern "C" {
-    pub fn _wcsicmp(_String1: *const wchar_t, _String2: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn _wcsicmp(_String1: *const wchar_t, _String2: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsicmp_l(
         _String1: *const wchar_t,
         _String2: *const wchar_t,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsnicmp(
         _String1: *const wchar_t,
         _String2: *const wchar_t,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsnicmp_l(
@@ -2393,7 +2393,7 @@ extern "C" {
         _String2: *const wchar_t,
         _MaxCount: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsnset_s(
@@ -2451,31 +2451,31 @@ extern "C" {
     ) -> usize;
 }
 extern "C" {
-    pub fn wcscoll(_String1: *const wchar_t, _String2: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn wcscoll(_String1: *const wchar_t, _String2: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcscoll_l(
         _String1: *const wchar_t,
         _String2: *const wchar_t,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn _wcsicoll(_String1: *const wchar_t, _String2: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn _wcsicoll(_String1: *const wchar_t, _String2: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsicoll_l(
         _String1: *const wchar_t,
         _String2: *const wchar_t,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsncoll(
         _String1: *const wchar_t,
         _String2: *const wchar_t,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsncoll_l(
@@ -2483,14 +2483,14 @@ extern "C" {
         _String2: *const wchar_t,
         _MaxCount: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsnicoll(
         _String1: *const wchar_t,
         _String2: *const wchar_t,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _wcsnicoll_l(
@@ -2498,20 +2498,20 @@ extern "C" {
         _String2: *const wchar_t,
         _MaxCount: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wcsdup(_String: *const wchar_t) -> *mut wchar_t;
 }
 extern "C" {
-    pub fn wcsicmp(_String1: *const wchar_t, _String2: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn wcsicmp(_String1: *const wchar_t, _String2: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wcsnicmp(
         _String1: *const wchar_t,
         _String2: *const wchar_t,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn wcsnset(_String: *mut wchar_t, _Value: wchar_t, _MaxCount: usize) -> *mut wchar_t;
@@ -2529,365 +2529,365 @@ extern "C" {
     pub fn wcsupr(_String: *mut wchar_t) -> *mut wchar_t;
 }
 extern "C" {
-    pub fn wcsicoll(_String1: *const wchar_t, _String2: *const wchar_t) -> ::std::os::raw::c_int;
+    pub fn wcsicoll(_String1: *const wchar_t, _String2: *const wchar_t) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcpy_s(
-        _Destination: *mut ::std::os::raw::c_char,
+        _Destination: *mut ::core::ffi::c_char,
         _SizeInBytes: rsize_t,
-        _Source: *const ::std::os::raw::c_char,
+        _Source: *const ::core::ffi::c_char,
     ) -> errno_t;
 }
 extern "C" {
     pub fn strcat_s(
-        _Destination: *mut ::std::os::raw::c_char,
+        _Destination: *mut ::core::ffi::c_char,
         _SizeInBytes: rsize_t,
-        _Source: *const ::std::os::raw::c_char,
+        _Source: *const ::core::ffi::c_char,
     ) -> errno_t;
 }
 extern "C" {
     pub fn strerror_s(
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _SizeInBytes: usize,
-        _ErrorNumber: ::std::os::raw::c_int,
+        _ErrorNumber: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn strncat_s(
-        _Destination: *mut ::std::os::raw::c_char,
+        _Destination: *mut ::core::ffi::c_char,
         _SizeInBytes: rsize_t,
-        _Source: *const ::std::os::raw::c_char,
+        _Source: *const ::core::ffi::c_char,
         _MaxCount: rsize_t,
     ) -> errno_t;
 }
 extern "C" {
     pub fn strncpy_s(
-        _Destination: *mut ::std::os::raw::c_char,
+        _Destination: *mut ::core::ffi::c_char,
         _SizeInBytes: rsize_t,
-        _Source: *const ::std::os::raw::c_char,
+        _Source: *const ::core::ffi::c_char,
         _MaxCount: rsize_t,
     ) -> errno_t;
 }
 extern "C" {
     pub fn strtok_s(
-        _String: *mut ::std::os::raw::c_char,
-        _Delimiter: *const ::std::os::raw::c_char,
-        _Context: *mut *mut ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _String: *mut ::core::ffi::c_char,
+        _Delimiter: *const ::core::ffi::c_char,
+        _Context: *mut *mut ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _memccpy(
-        _Dst: *mut ::std::os::raw::c_void,
-        _Src: *const ::std::os::raw::c_void,
-        _Val: ::std::os::raw::c_int,
+        _Dst: *mut ::core::ffi::c_void,
+        _Src: *const ::core::ffi::c_void,
+        _Val: ::core::ffi::c_int,
         _MaxCount: usize,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 extern "C" {
     pub fn strcat(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _Destination: *mut ::core::ffi::c_char,
+        _Source: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _Str1: *const ::core::ffi::c_char,
+        _Str2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strcmpi(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcoll(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strcoll_l(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strcpy(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _Destination: *mut ::core::ffi::c_char,
+        _Source: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcspn(
-        _Str: *const ::std::os::raw::c_char,
-        _Control: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_ulonglong;
+        _Str: *const ::core::ffi::c_char,
+        _Control: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
-    pub fn _strdup(_Source: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn _strdup(_Source: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn _strerror(_ErrorMessage: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn _strerror(_ErrorMessage: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _strerror_s(
-        _Buffer: *mut ::std::os::raw::c_char,
+        _Buffer: *mut ::core::ffi::c_char,
         _SizeInBytes: usize,
-        _ErrorMessage: *const ::std::os::raw::c_char,
+        _ErrorMessage: *const ::core::ffi::c_char,
     ) -> errno_t;
 }
 extern "C" {
-    pub fn strerror(_ErrorMessage: ::std::os::raw::c_int) -> *mut ::std::os::raw::c_char;
+    pub fn strerror(_ErrorMessage: ::core::ffi::c_int) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _stricmp(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _stricoll(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _stricoll_l(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _stricmp_l(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn strlen(_Str: *const ::std::os::raw::c_char) -> ::std::os::raw::c_ulonglong;
+    pub fn strlen(_Str: *const ::core::ffi::c_char) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
-    pub fn _strlwr_s(_String: *mut ::std::os::raw::c_char, _Size: usize) -> errno_t;
+    pub fn _strlwr_s(_String: *mut ::core::ffi::c_char, _Size: usize) -> errno_t;
 }
 extern "C" {
-    pub fn _strlwr(_String: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn _strlwr(_String: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _strlwr_s_l(
-        _String: *mut ::std::os::raw::c_char,
+        _String: *mut ::core::ffi::c_char,
         _Size: usize,
         _Locale: _locale_t,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _strlwr_l(
-        _String: *mut ::std::os::raw::c_char,
+        _String: *mut ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strncat(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-        _Count: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_char;
+        _Destination: *mut ::core::ffi::c_char,
+        _Source: *const ::core::ffi::c_char,
+        _Count: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strncmp(
-        _Str1: *const ::std::os::raw::c_char,
-        _Str2: *const ::std::os::raw::c_char,
-        _MaxCount: ::std::os::raw::c_ulonglong,
-    ) -> ::std::os::raw::c_int;
+        _Str1: *const ::core::ffi::c_char,
+        _Str2: *const ::core::ffi::c_char,
+        _MaxCount: ::core::ffi::c_ulonglong,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strnicmp(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strnicmp_l(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strnicoll(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strnicoll_l(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strncoll(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn _strncoll_l(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn __strncnt(_String: *const ::std::os::raw::c_char, _Count: usize) -> usize;
+    pub fn __strncnt(_String: *const ::core::ffi::c_char, _Count: usize) -> usize;
 }
 extern "C" {
     pub fn strncpy(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-        _Count: ::std::os::raw::c_ulonglong,
-    ) -> *mut ::std::os::raw::c_char;
+        _Destination: *mut ::core::ffi::c_char,
+        _Source: *const ::core::ffi::c_char,
+        _Count: ::core::ffi::c_ulonglong,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn strnlen(_String: *const ::std::os::raw::c_char, _MaxCount: usize) -> usize;
+    pub fn strnlen(_String: *const ::core::ffi::c_char, _MaxCount: usize) -> usize;
 }
 extern "C" {
     pub fn _strnset_s(
-        _String: *mut ::std::os::raw::c_char,
+        _String: *mut ::core::ffi::c_char,
         _SizeInBytes: usize,
-        _Value: ::std::os::raw::c_int,
+        _Value: ::core::ffi::c_int,
         _MaxCount: usize,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _strnset(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Value: ::std::os::raw::c_int,
+        _Destination: *mut ::core::ffi::c_char,
+        _Value: ::core::ffi::c_int,
         _Count: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strpbrk(
-        _Str: *const ::std::os::raw::c_char,
-        _Control: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _Str: *const ::core::ffi::c_char,
+        _Control: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn _strrev(_Str: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn _strrev(_Str: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _strset_s(
-        _Destination: *mut ::std::os::raw::c_char,
+        _Destination: *mut ::core::ffi::c_char,
         _DestinationSize: usize,
-        _Value: ::std::os::raw::c_int,
+        _Value: ::core::ffi::c_int,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _strset(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Value: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _Destination: *mut ::core::ffi::c_char,
+        _Value: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strspn(
-        _Str: *const ::std::os::raw::c_char,
-        _Control: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_ulonglong;
+        _Str: *const ::core::ffi::c_char,
+        _Control: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn strtok(
-        _String: *mut ::std::os::raw::c_char,
-        _Delimiter: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
+        _String: *mut ::core::ffi::c_char,
+        _Delimiter: *const ::core::ffi::c_char,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn _strupr_s(_String: *mut ::std::os::raw::c_char, _Size: usize) -> errno_t;
+    pub fn _strupr_s(_String: *mut ::core::ffi::c_char, _Size: usize) -> errno_t;
 }
 extern "C" {
-    pub fn _strupr(_String: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn _strupr(_String: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn _strupr_s_l(
-        _String: *mut ::std::os::raw::c_char,
+        _String: *mut ::core::ffi::c_char,
         _Size: usize,
         _Locale: _locale_t,
     ) -> errno_t;
 }
 extern "C" {
     pub fn _strupr_l(
-        _String: *mut ::std::os::raw::c_char,
+        _String: *mut ::core::ffi::c_char,
         _Locale: _locale_t,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strxfrm(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
-        _MaxCount: ::std::os::raw::c_ulonglong,
-    ) -> ::std::os::raw::c_ulonglong;
+        _Destination: *mut ::core::ffi::c_char,
+        _Source: *const ::core::ffi::c_char,
+        _MaxCount: ::core::ffi::c_ulonglong,
+    ) -> ::core::ffi::c_ulonglong;
 }
 extern "C" {
     pub fn _strxfrm_l(
-        _Destination: *mut ::std::os::raw::c_char,
-        _Source: *const ::std::os::raw::c_char,
+        _Destination: *mut ::core::ffi::c_char,
+        _Source: *const ::core::ffi::c_char,
         _MaxCount: usize,
         _Locale: _locale_t,
     ) -> usize;
 }
 extern "C" {
-    pub fn strdup(_String: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn strdup(_String: *const ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strcmpi(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn stricmp(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
-    pub fn strlwr(_String: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn strlwr(_String: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strnicmp(
-        _String1: *const ::std::os::raw::c_char,
-        _String2: *const ::std::os::raw::c_char,
+        _String1: *const ::core::ffi::c_char,
+        _String2: *const ::core::ffi::c_char,
         _MaxCount: usize,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 extern "C" {
     pub fn strnset(
-        _String: *mut ::std::os::raw::c_char,
-        _Value: ::std::os::raw::c_int,
+        _String: *mut ::core::ffi::c_char,
+        _Value: ::core::ffi::c_int,
         _MaxCount: usize,
-    ) -> *mut ::std::os::raw::c_char;
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn strrev(_String: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn strrev(_String: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
     pub fn strset(
-        _String: *mut ::std::os::raw::c_char,
-        _Value: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_char;
+        _String: *mut ::core::ffi::c_char,
+        _Value: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_char;
 }
 extern "C" {
-    pub fn strupr(_String: *mut ::std::os::raw::c_char) -> *mut ::std::os::raw::c_char;
+    pub fn strupr(_String: *mut ::core::ffi::c_char) -> *mut ::core::ffi::c_char;
 }
 #[repr(i32)]
 #[doc = " Copied from TensorProto::DataType"]
@@ -3125,36 +3125,36 @@ pub struct OrtAllocator {
     #[doc = "< Must be initialized to ORT_API_VERSION"]
     pub version: u32,
     #[doc = "< Returns a pointer to an allocated block of `size` bytes"]
-    pub Alloc: ::std::option::Option<
-        unsafe extern "C" fn(this_: *mut OrtAllocator, size: usize) -> *mut ::std::os::raw::c_void,
+    pub Alloc: ::core::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, size: usize) -> *mut ::core::ffi::c_void,
     >,
     #[doc = "< Free a block of memory previously allocated with OrtAllocator::Alloc"]
-    pub Free: ::std::option::Option<
-        unsafe extern "C" fn(this_: *mut OrtAllocator, p: *mut ::std::os::raw::c_void),
+    pub Free: ::core::option::Option<
+        unsafe extern "C" fn(this_: *mut OrtAllocator, p: *mut ::core::ffi::c_void),
     >,
     #[doc = "< Return a pointer to an ::OrtMemoryInfo that describes this allocator"]
-    pub Info: ::std::option::Option<
+    pub Info: ::core::option::Option<
         unsafe extern "C" fn(this_: *const OrtAllocator) -> *const OrtMemoryInfo,
     >,
 }
 #[test]
 fn bindgen_test_layout_OrtAllocator() {
     assert_eq!(
-        ::std::mem::size_of::<OrtAllocator>(),
+        ::core::mem::size_of::<OrtAllocator>(),
         32usize,
         concat!("Size of: ", stringify!(OrtAllocator))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtAllocator>(),
+        ::core::mem::align_of::<OrtAllocator>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtAllocator))
     );
     fn test_field_version() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).version) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3169,9 +3169,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Alloc() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Alloc) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Alloc) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3186,9 +3186,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Free() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Free) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Free) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3203,9 +3203,9 @@ fn bindgen_test_layout_OrtAllocator() {
     fn test_field_Info() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtAllocator>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtAllocator>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Info) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Info) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3218,14 +3218,14 @@ fn bindgen_test_layout_OrtAllocator() {
     }
     test_field_Info();
 }
-pub type OrtLoggingFunction = ::std::option::Option<
+pub type OrtLoggingFunction = ::core::option::Option<
     unsafe extern "C" fn(
-        param: *mut ::std::os::raw::c_void,
+        param: *mut ::core::ffi::c_void,
         severity: OrtLoggingLevel,
-        category: *const ::std::os::raw::c_char,
-        logid: *const ::std::os::raw::c_char,
-        code_location: *const ::std::os::raw::c_char,
-        message: *const ::std::os::raw::c_char,
+        category: *const ::core::ffi::c_char,
+        logid: *const ::core::ffi::c_char,
+        code_location: *const ::core::ffi::c_char,
+        message: *const ::core::ffi::c_char,
     ),
 >;
 #[repr(i32)]
@@ -3314,7 +3314,7 @@ pub enum OrtCudnnConvAlgoSearch {
 pub struct OrtCUDAProviderOptions {
     #[doc = " \\brief CUDA device Id"]
     #[doc = "   Defaults to 0."]
-    pub device_id: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
     #[doc = " \\brief CUDA Convolution algorithm search configuration."]
     #[doc = "   See enum OrtCudnnConvAlgoSearch for more details."]
     #[doc = "   Defaults to OrtCudnnConvAlgoSearchExhaustive."]
@@ -3328,49 +3328,49 @@ pub struct OrtCUDAProviderOptions {
     #[doc = "   1 = kSameAsRequested<br>"]
     #[doc = "   Defaults to 0."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
-    pub arena_extend_strategy: ::std::os::raw::c_int,
+    pub arena_extend_strategy: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the CUDA EP"]
     #[doc = "   0 = Use separate streams for copying and compute."]
     #[doc = "   1 = Use the same stream for copying and compute."]
     #[doc = "   Defaults to 1."]
     #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
     #[doc = "   Please see issue #4829 for more details."]
-    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    pub do_copy_in_default_stream: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
     #[doc = "   Defaults to 0."]
-    pub has_user_compute_stream: ::std::os::raw::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
     #[doc = " \\brief User provided compute stream."]
     #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
     #[doc = " \\brief CUDA memory arena configuration parameters"]
     pub default_memory_arena_cfg: *mut OrtArenaCfg,
     #[doc = " \\brief Enable TunableOp for using."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_CUDA_TUNABLE_OP_ENABLE."]
-    pub tunable_op_enable: ::std::os::raw::c_int,
+    pub tunable_op_enable: ::core::ffi::c_int,
     #[doc = " \\brief Enable TunableOp for tuning."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp tuning. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_CUDA_TUNABLE_OP_TUNING_ENABLE."]
-    pub tunable_op_tuning_enable: ::std::os::raw::c_int,
+    pub tunable_op_tuning_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtCUDAProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCUDAProviderOptions>(),
+        ::core::mem::size_of::<OrtCUDAProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtCUDAProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCUDAProviderOptions>(),
+        ::core::mem::align_of::<OrtCUDAProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtCUDAProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3385,9 +3385,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_cudnn_conv_algo_search() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).cudnn_conv_algo_search) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).cudnn_conv_algo_search) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -3402,9 +3402,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_gpu_mem_limit() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3419,9 +3419,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_arena_extend_strategy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3436,9 +3436,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_do_copy_in_default_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -3453,9 +3453,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3470,9 +3470,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -3487,9 +3487,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_default_memory_arena_cfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -3504,9 +3504,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_tunable_op_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -3521,9 +3521,9 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
     fn test_field_tunable_op_tuning_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCUDAProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -3544,10 +3544,10 @@ fn bindgen_test_layout_OrtCUDAProviderOptions() {
 pub struct OrtROCMProviderOptions {
     #[doc = " \\brief ROCM device Id"]
     #[doc = "   Defaults to 0."]
-    pub device_id: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
     #[doc = " \\brief ROCM MIOpen Convolution algorithm exaustive search option."]
     #[doc = "   Defaults to 0 (false)."]
-    pub miopen_conv_exhaustive_search: ::std::os::raw::c_int,
+    pub miopen_conv_exhaustive_search: ::core::ffi::c_int,
     #[doc = " \\brief ROCM memory limit (To use all possible memory pass in maximum size_t)"]
     #[doc = "   Defaults to SIZE_MAX."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
@@ -3557,49 +3557,49 @@ pub struct OrtROCMProviderOptions {
     #[doc = "   1 = kSameAsRequested<br>"]
     #[doc = "   Defaults to 0."]
     #[doc = "   \\note If a ::OrtArenaCfg has been applied, it will override this field"]
-    pub arena_extend_strategy: ::std::os::raw::c_int,
+    pub arena_extend_strategy: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if copying needs to take place on the same stream as the compute stream in the ROCM EP"]
     #[doc = "   0 = Use separate streams for copying and compute."]
     #[doc = "   1 = Use the same stream for copying and compute."]
     #[doc = "   Defaults to 1."]
     #[doc = "   WARNING: Setting this to 0 may result in data races for some models."]
     #[doc = "   Please see issue #4829 for more details."]
-    pub do_copy_in_default_stream: ::std::os::raw::c_int,
+    pub do_copy_in_default_stream: ::core::ffi::c_int,
     #[doc = " \\brief Flag indicating if there is a user provided compute stream"]
     #[doc = "   Defaults to 0."]
-    pub has_user_compute_stream: ::std::os::raw::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
     #[doc = " \\brief User provided compute stream."]
     #[doc = "   If provided, please set `has_user_compute_stream` to 1."]
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
     #[doc = " \\brief ROCM memory arena configuration parameters"]
     pub default_memory_arena_cfg: *mut OrtArenaCfg,
     #[doc = " \\brief Enable TunableOp for using."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_ROCM_TUNABLE_OP_ENABLE."]
-    pub tunable_op_enable: ::std::os::raw::c_int,
+    pub tunable_op_enable: ::core::ffi::c_int,
     #[doc = " \\brief Enable TunableOp for tuning."]
     #[doc = "   Set it to 1/0 to enable/disable TunableOp tuning. Otherwise, it is disabled by default."]
     #[doc = "   This option can be overriden by environment variable ORT_ROCM_TUNABLE_OP_TUNING_ENABLE."]
-    pub tunable_op_tuning_enable: ::std::os::raw::c_int,
+    pub tunable_op_tuning_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtROCMProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtROCMProviderOptions>(),
+        ::core::mem::size_of::<OrtROCMProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtROCMProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtROCMProviderOptions>(),
+        ::core::mem::align_of::<OrtROCMProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtROCMProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3614,9 +3614,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_miopen_conv_exhaustive_search() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).miopen_conv_exhaustive_search) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).miopen_conv_exhaustive_search) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -3631,9 +3631,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_gpu_mem_limit() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).gpu_mem_limit) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3648,9 +3648,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_arena_extend_strategy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).arena_extend_strategy) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3665,9 +3665,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_do_copy_in_default_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).do_copy_in_default_stream) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -3682,9 +3682,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3699,9 +3699,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -3716,9 +3716,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_default_memory_arena_cfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).default_memory_arena_cfg) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -3733,9 +3733,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_tunable_op_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_enable) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -3750,9 +3750,9 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
     fn test_field_tunable_op_tuning_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtROCMProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).tunable_op_tuning_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -3772,43 +3772,43 @@ fn bindgen_test_layout_OrtROCMProviderOptions() {
 #[derive(Debug, Copy, Clone)]
 pub struct OrtTensorRTProviderOptions {
     #[doc = "< CUDA device id (0 = default device)"]
-    pub device_id: ::std::os::raw::c_int,
-    pub has_user_compute_stream: ::std::os::raw::c_int,
-    pub user_compute_stream: *mut ::std::os::raw::c_void,
-    pub trt_max_partition_iterations: ::std::os::raw::c_int,
-    pub trt_min_subgraph_size: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
+    pub has_user_compute_stream: ::core::ffi::c_int,
+    pub user_compute_stream: *mut ::core::ffi::c_void,
+    pub trt_max_partition_iterations: ::core::ffi::c_int,
+    pub trt_min_subgraph_size: ::core::ffi::c_int,
     pub trt_max_workspace_size: usize,
-    pub trt_fp16_enable: ::std::os::raw::c_int,
-    pub trt_int8_enable: ::std::os::raw::c_int,
-    pub trt_int8_calibration_table_name: *const ::std::os::raw::c_char,
-    pub trt_int8_use_native_calibration_table: ::std::os::raw::c_int,
-    pub trt_dla_enable: ::std::os::raw::c_int,
-    pub trt_dla_core: ::std::os::raw::c_int,
-    pub trt_dump_subgraphs: ::std::os::raw::c_int,
-    pub trt_engine_cache_enable: ::std::os::raw::c_int,
-    pub trt_engine_cache_path: *const ::std::os::raw::c_char,
-    pub trt_engine_decryption_enable: ::std::os::raw::c_int,
-    pub trt_engine_decryption_lib_path: *const ::std::os::raw::c_char,
-    pub trt_force_sequential_engine_build: ::std::os::raw::c_int,
+    pub trt_fp16_enable: ::core::ffi::c_int,
+    pub trt_int8_enable: ::core::ffi::c_int,
+    pub trt_int8_calibration_table_name: *const ::core::ffi::c_char,
+    pub trt_int8_use_native_calibration_table: ::core::ffi::c_int,
+    pub trt_dla_enable: ::core::ffi::c_int,
+    pub trt_dla_core: ::core::ffi::c_int,
+    pub trt_dump_subgraphs: ::core::ffi::c_int,
+    pub trt_engine_cache_enable: ::core::ffi::c_int,
+    pub trt_engine_cache_path: *const ::core::ffi::c_char,
+    pub trt_engine_decryption_enable: ::core::ffi::c_int,
+    pub trt_engine_decryption_lib_path: *const ::core::ffi::c_char,
+    pub trt_force_sequential_engine_build: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtTensorRTProviderOptions>(),
+        ::core::mem::size_of::<OrtTensorRTProviderOptions>(),
         104usize,
         concat!("Size of: ", stringify!(OrtTensorRTProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtTensorRTProviderOptions>(),
+        ::core::mem::align_of::<OrtTensorRTProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtTensorRTProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -3823,9 +3823,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_has_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).has_user_compute_stream) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -3840,9 +3840,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_user_compute_stream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).user_compute_stream) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -3857,9 +3857,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_max_partition_iterations() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_max_partition_iterations) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_max_partition_iterations) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -3874,9 +3874,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_min_subgraph_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_min_subgraph_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_min_subgraph_size) as usize - ptr as usize
             },
             20usize,
             concat!(
@@ -3891,9 +3891,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_max_workspace_size() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_max_workspace_size) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_max_workspace_size) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -3908,9 +3908,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_fp16_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_fp16_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_fp16_enable) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -3925,9 +3925,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_enable) as usize - ptr as usize
             },
             36usize,
             concat!(
@@ -3942,9 +3942,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_calibration_table_name() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_calibration_table_name) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_calibration_table_name) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -3959,9 +3959,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_int8_use_native_calibration_table() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_int8_use_native_calibration_table) as usize
+                ::core::ptr::addr_of!((*ptr).trt_int8_use_native_calibration_table) as usize
                     - ptr as usize
             },
             48usize,
@@ -3977,9 +3977,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dla_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dla_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dla_enable) as usize - ptr as usize
             },
             52usize,
             concat!(
@@ -3994,9 +3994,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dla_core() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dla_core) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dla_core) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -4011,9 +4011,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_dump_subgraphs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_dump_subgraphs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_dump_subgraphs) as usize - ptr as usize
             },
             60usize,
             concat!(
@@ -4028,9 +4028,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_cache_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_cache_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_cache_enable) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -4045,9 +4045,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_cache_path() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_cache_path) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_cache_path) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -4062,9 +4062,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_decryption_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_decryption_enable) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -4079,9 +4079,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_engine_decryption_lib_path() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_engine_decryption_lib_path) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).trt_engine_decryption_lib_path) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -4096,9 +4096,9 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
     fn test_field_trt_force_sequential_engine_build() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtTensorRTProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).trt_force_sequential_engine_build) as usize
+                ::core::ptr::addr_of!((*ptr).trt_force_sequential_engine_build) as usize
                     - ptr as usize
             },
             96usize,
@@ -4118,28 +4118,28 @@ fn bindgen_test_layout_OrtTensorRTProviderOptions() {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OrtMIGraphXProviderOptions {
-    pub device_id: ::std::os::raw::c_int,
-    pub migraphx_fp16_enable: ::std::os::raw::c_int,
-    pub migraphx_int8_enable: ::std::os::raw::c_int,
+    pub device_id: ::core::ffi::c_int,
+    pub migraphx_fp16_enable: ::core::ffi::c_int,
+    pub migraphx_int8_enable: ::core::ffi::c_int,
 }
 #[test]
 fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtMIGraphXProviderOptions>(),
+        ::core::mem::size_of::<OrtMIGraphXProviderOptions>(),
         12usize,
         concat!("Size of: ", stringify!(OrtMIGraphXProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtMIGraphXProviderOptions>(),
+        ::core::mem::align_of::<OrtMIGraphXProviderOptions>(),
         4usize,
         concat!("Alignment of ", stringify!(OrtMIGraphXProviderOptions))
     );
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4154,9 +4154,9 @@ fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     fn test_field_migraphx_fp16_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).migraphx_fp16_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).migraphx_fp16_enable) as usize - ptr as usize
             },
             4usize,
             concat!(
@@ -4171,9 +4171,9 @@ fn bindgen_test_layout_OrtMIGraphXProviderOptions() {
     fn test_field_migraphx_int8_enable() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtMIGraphXProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).migraphx_int8_enable) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).migraphx_int8_enable) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4195,37 +4195,37 @@ pub struct OrtOpenVINOProviderOptions {
     #[doc = " \\brief Device type string"]
     #[doc = ""]
     #[doc = " Valid settings are one of: \"CPU_FP32\", \"CPU_FP16\", \"GPU_FP32\", \"GPU_FP16\""]
-    pub device_type: *const ::std::os::raw::c_char,
+    pub device_type: *const ::core::ffi::c_char,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_vpu_fast_compile: ::std::os::raw::c_uchar,
-    pub device_id: *const ::std::os::raw::c_char,
+    pub enable_vpu_fast_compile: ::core::ffi::c_uchar,
+    pub device_id: *const ::core::ffi::c_char,
     #[doc = "< 0 = Use default number of threads"]
     pub num_of_threads: usize,
-    pub cache_dir: *const ::std::os::raw::c_char,
-    pub context: *mut ::std::os::raw::c_void,
+    pub cache_dir: *const ::core::ffi::c_char,
+    pub context: *mut ::core::ffi::c_void,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_opencl_throttling: ::std::os::raw::c_uchar,
+    pub enable_opencl_throttling: ::core::ffi::c_uchar,
     #[doc = "< 0 = disabled, nonzero = enabled"]
-    pub enable_dynamic_shapes: ::std::os::raw::c_uchar,
+    pub enable_dynamic_shapes: ::core::ffi::c_uchar,
 }
 #[test]
 fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     assert_eq!(
-        ::std::mem::size_of::<OrtOpenVINOProviderOptions>(),
+        ::core::mem::size_of::<OrtOpenVINOProviderOptions>(),
         56usize,
         concat!("Size of: ", stringify!(OrtOpenVINOProviderOptions))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtOpenVINOProviderOptions>(),
+        ::core::mem::align_of::<OrtOpenVINOProviderOptions>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtOpenVINOProviderOptions))
     );
     fn test_field_device_type() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_type) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4240,9 +4240,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_vpu_fast_compile() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_vpu_fast_compile) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_vpu_fast_compile) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4257,9 +4257,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_device_id() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -4274,9 +4274,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_num_of_threads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).num_of_threads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).num_of_threads) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -4291,9 +4291,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_cache_dir() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).cache_dir) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).cache_dir) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -4308,9 +4308,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_context() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).context) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).context) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -4325,9 +4325,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_opencl_throttling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_opencl_throttling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_opencl_throttling) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -4342,9 +4342,9 @@ fn bindgen_test_layout_OrtOpenVINOProviderOptions() {
     fn test_field_enable_dynamic_shapes() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtOpenVINOProviderOptions>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).enable_dynamic_shapes) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).enable_dynamic_shapes) as usize - ptr as usize
             },
             49usize,
             concat!(
@@ -4376,31 +4376,31 @@ pub struct OrtApiBase {
     #[doc = ""]
     #[doc = " One can call GetVersionString() to get the version of the Onnxruntime library for logging"]
     #[doc = " and error reporting purposes."]
-    pub GetApi: ::std::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtApi>,
+    pub GetApi: ::core::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtApi>,
     #[doc = " \\brief Returns a null terminated string of the version of the Onnxruntime library (eg: \"1.8.1\")"]
     #[doc = ""]
     #[doc = "  \\return UTF-8 encoded version string. Do not deallocate the returned buffer."]
     pub GetVersionString:
-        ::std::option::Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+        ::core::option::Option<unsafe extern "C" fn() -> *const ::core::ffi::c_char>,
 }
 #[test]
 fn bindgen_test_layout_OrtApiBase() {
     assert_eq!(
-        ::std::mem::size_of::<OrtApiBase>(),
+        ::core::mem::size_of::<OrtApiBase>(),
         16usize,
         concat!("Size of: ", stringify!(OrtApiBase))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtApiBase>(),
+        ::core::mem::align_of::<OrtApiBase>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtApiBase))
     );
     fn test_field_GetApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApiBase>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetApi) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4415,9 +4415,9 @@ fn bindgen_test_layout_OrtApiBase() {
     fn test_field_GetVersionString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApiBase>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApiBase>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVersionString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVersionString) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -4441,30 +4441,30 @@ extern "C" {
 #[doc = " Onnxruntime will provide the working loop on custom thread creation"]
 #[doc = " Argument is an onnxruntime built-in type which will be provided when thread pool calls OrtCustomCreateThreadFn"]
 pub type OrtThreadWorkerFn =
-    ::std::option::Option<unsafe extern "C" fn(ort_worker_fn_param: *mut ::std::os::raw::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(ort_worker_fn_param: *mut ::core::ffi::c_void)>;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OrtCustomHandleType {
-    pub __place_holder: ::std::os::raw::c_char,
+    pub __place_holder: ::core::ffi::c_char,
 }
 #[test]
 fn bindgen_test_layout_OrtCustomHandleType() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCustomHandleType>(),
+        ::core::mem::size_of::<OrtCustomHandleType>(),
         1usize,
         concat!("Size of: ", stringify!(OrtCustomHandleType))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCustomHandleType>(),
+        ::core::mem::align_of::<OrtCustomHandleType>(),
         1usize,
         concat!("Alignment of ", stringify!(OrtCustomHandleType))
     );
     fn test_field___place_holder() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomHandleType>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomHandleType>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).__place_holder) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).__place_holder) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -4482,11 +4482,11 @@ pub type OrtCustomThreadHandle = *const OrtCustomHandleType;
 #[doc = ""]
 #[doc = " The function should return a thread handle to be used in onnxruntime thread pools"]
 #[doc = " Onnxruntime will throw exception on return value of nullptr or 0, indicating that the function failed to create a thread"]
-pub type OrtCustomCreateThreadFn = ::std::option::Option<
+pub type OrtCustomCreateThreadFn = ::core::option::Option<
     unsafe extern "C" fn(
-        ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+        ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ort_thread_worker_fn: OrtThreadWorkerFn,
-        ort_worker_fn_param: *mut ::std::os::raw::c_void,
+        ort_worker_fn_param: *mut ::core::ffi::c_void,
     ) -> OrtCustomThreadHandle,
 >;
 #[doc = " \\brief Custom thread join function"]
@@ -4494,8 +4494,8 @@ pub type OrtCustomCreateThreadFn = ::std::option::Option<
 #[doc = " Onnxruntime thread pool destructor will call the function to join a custom thread."]
 #[doc = " Argument ort_custom_thread_handle is the value returned by OrtCustomCreateThreadFn"]
 pub type OrtCustomJoinThreadFn =
-    ::std::option::Option<unsafe extern "C" fn(ort_custom_thread_handle: OrtCustomThreadHandle)>;
-pub type RegisterCustomOpsFn = ::std::option::Option<
+    ::core::option::Option<unsafe extern "C" fn(ort_custom_thread_handle: OrtCustomThreadHandle)>;
+pub type RegisterCustomOpsFn = ::core::option::Option<
     unsafe extern "C" fn(options: *mut OrtSessionOptions, api: *const OrtApiBase) -> *mut OrtStatus,
 >;
 #[doc = " \\brief The C API"]
@@ -4512,10 +4512,10 @@ pub struct OrtApi {
     #[doc = " \\param[in] code"]
     #[doc = " \\param[in] msg A null-terminated string. Its contents will be copied."]
     #[doc = " \\return A new OrtStatus object, must be destroyed with OrtApi::ReleaseStatus"]
-    pub CreateStatus: ::std::option::Option<
+    pub CreateStatus: ::core::option::Option<
         unsafe extern "C" fn(
             code: OrtErrorCode,
-            msg: *const ::std::os::raw::c_char,
+            msg: *const ::core::ffi::c_char,
         ) -> *mut OrtStatus,
     >,
     #[doc = " \\brief Get OrtErrorCode from OrtStatus"]
@@ -4523,35 +4523,35 @@ pub struct OrtApi {
     #[doc = " \\param[in] status"]
     #[doc = " \\return OrtErrorCode that \\p status was created with"]
     pub GetErrorCode:
-        ::std::option::Option<unsafe extern "C" fn(status: *const OrtStatus) -> OrtErrorCode>,
+        ::core::option::Option<unsafe extern "C" fn(status: *const OrtStatus) -> OrtErrorCode>,
     #[doc = " \\brief Get error string from OrtStatus"]
     #[doc = ""]
     #[doc = " \\param[in] status"]
     #[doc = " \\return The error message inside the `status`. Do not free the returned value."]
-    pub GetErrorMessage: ::std::option::Option<
-        unsafe extern "C" fn(status: *const OrtStatus) -> *const ::std::os::raw::c_char,
+    pub GetErrorMessage: ::core::option::Option<
+        unsafe extern "C" fn(status: *const OrtStatus) -> *const ::core::ffi::c_char,
     >,
-    pub CreateEnv: ::std::option::Option<
+    pub CreateEnv: ::core::option::Option<
         unsafe extern "C" fn(
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub CreateEnvWithCustomLogger: ::std::option::Option<
+    pub CreateEnvWithCustomLogger: ::core::option::Option<
         unsafe extern "C" fn(
             logging_function: OrtLoggingFunction,
-            logger_param: *mut ::std::os::raw::c_void,
+            logger_param: *mut ::core::ffi::c_void,
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
     pub EnableTelemetryEvents:
-        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
     pub DisableTelemetryEvents:
-        ::std::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
-    pub CreateSession: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(env: *const OrtEnv) -> OrtStatusPtr>,
+    pub CreateSession: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
             model_path: *const wchar_t,
@@ -4559,227 +4559,227 @@ pub struct OrtApi {
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionFromArray: ::std::option::Option<
+    pub CreateSessionFromArray: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_data: *const ::std::os::raw::c_void,
+            model_data: *const ::core::ffi::c_void,
             model_data_length: usize,
             options: *const OrtSessionOptions,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub Run: ::std::option::Option<
+    pub Run: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             run_options: *const OrtRunOptions,
-            input_names: *const *const ::std::os::raw::c_char,
+            input_names: *const *const ::core::ffi::c_char,
             inputs: *const *const OrtValue,
             input_len: usize,
-            output_names: *const *const ::std::os::raw::c_char,
+            output_names: *const *const ::core::ffi::c_char,
             output_names_len: usize,
             outputs: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionOptions: ::std::option::Option<
+    pub CreateSessionOptions: ::core::option::Option<
         unsafe extern "C" fn(options: *mut *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub SetOptimizedModelFilePath: ::std::option::Option<
+    pub SetOptimizedModelFilePath: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             optimized_model_filepath: *const wchar_t,
         ) -> OrtStatusPtr,
     >,
-    pub CloneSessionOptions: ::std::option::Option<
+    pub CloneSessionOptions: ::core::option::Option<
         unsafe extern "C" fn(
             in_options: *const OrtSessionOptions,
             out_options: *mut *mut OrtSessionOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionExecutionMode: ::std::option::Option<
+    pub SetSessionExecutionMode: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             execution_mode: ExecutionMode,
         ) -> OrtStatusPtr,
     >,
-    pub EnableProfiling: ::std::option::Option<
+    pub EnableProfiling: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             profile_file_prefix: *const wchar_t,
         ) -> OrtStatusPtr,
     >,
-    pub DisableProfiling: ::std::option::Option<
+    pub DisableProfiling: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub EnableMemPattern: ::std::option::Option<
+    pub EnableMemPattern: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub DisableMemPattern: ::std::option::Option<
+    pub DisableMemPattern: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub EnableCpuMemArena: ::std::option::Option<
+    pub EnableCpuMemArena: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub DisableCpuMemArena: ::std::option::Option<
+    pub DisableCpuMemArena: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub SetSessionLogId: ::std::option::Option<
+    pub SetSessionLogId: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionLogVerbosityLevel: ::std::option::Option<
+    pub SetSessionLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            session_log_verbosity_level: ::std::os::raw::c_int,
+            session_log_verbosity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionLogSeverityLevel: ::std::option::Option<
+    pub SetSessionLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            session_log_severity_level: ::std::os::raw::c_int,
+            session_log_severity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetSessionGraphOptimizationLevel: ::std::option::Option<
+    pub SetSessionGraphOptimizationLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             graph_optimization_level: GraphOptimizationLevel,
         ) -> OrtStatusPtr,
     >,
-    pub SetIntraOpNumThreads: ::std::option::Option<
+    pub SetIntraOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            intra_op_num_threads: ::std::os::raw::c_int,
+            intra_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetInterOpNumThreads: ::std::option::Option<
+    pub SetInterOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            inter_op_num_threads: ::std::os::raw::c_int,
+            inter_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCustomOpDomain: ::std::option::Option<
+    pub CreateCustomOpDomain: ::core::option::Option<
         unsafe extern "C" fn(
-            domain: *const ::std::os::raw::c_char,
+            domain: *const ::core::ffi::c_char,
             out: *mut *mut OrtCustomOpDomain,
         ) -> OrtStatusPtr,
     >,
-    pub CustomOpDomain_Add: ::std::option::Option<
+    pub CustomOpDomain_Add: ::core::option::Option<
         unsafe extern "C" fn(
             custom_op_domain: *mut OrtCustomOpDomain,
             op: *const OrtCustomOp,
         ) -> OrtStatusPtr,
     >,
-    pub AddCustomOpDomain: ::std::option::Option<
+    pub AddCustomOpDomain: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             custom_op_domain: *mut OrtCustomOpDomain,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsLibrary: ::std::option::Option<
+    pub RegisterCustomOpsLibrary: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            library_path: *const ::std::os::raw::c_char,
-            library_handle: *mut *mut ::std::os::raw::c_void,
+            library_path: *const ::core::ffi::c_char,
+            library_handle: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetInputCount: ::std::option::Option<
+    pub SessionGetInputCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputCount: ::std::option::Option<
+    pub SessionGetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerCount: ::std::option::Option<
+    pub SessionGetOverridableInitializerCount: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub SessionGetInputTypeInfo: ::std::option::Option<
+    pub SessionGetInputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputTypeInfo: ::std::option::Option<
+    pub SessionGetOutputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerTypeInfo: ::std::option::Option<
+    pub SessionGetOverridableInitializerTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetInputName: ::std::option::Option<
+    pub SessionGetInputName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOutputName: ::std::option::Option<
+    pub SessionGetOutputName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetOverridableInitializerName: ::std::option::Option<
+    pub SessionGetOverridableInitializerName: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             index: usize,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     pub CreateRunOptions:
-        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtRunOptions) -> OrtStatusPtr>,
-    pub RunOptionsSetRunLogVerbosityLevel: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(out: *mut *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub RunOptionsSetRunLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            log_verbosity_level: ::std::os::raw::c_int,
+            log_verbosity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsSetRunLogSeverityLevel: ::std::option::Option<
+    pub RunOptionsSetRunLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            log_severity_level: ::std::os::raw::c_int,
+            log_severity_level: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsSetRunTag: ::std::option::Option<
+    pub RunOptionsSetRunTag: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            run_tag: *const ::std::os::raw::c_char,
+            run_tag: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunLogVerbosityLevel: ::std::option::Option<
+    pub RunOptionsGetRunLogVerbosityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            log_verbosity_level: *mut ::std::os::raw::c_int,
+            log_verbosity_level: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunLogSeverityLevel: ::std::option::Option<
+    pub RunOptionsGetRunLogSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            log_severity_level: *mut ::std::os::raw::c_int,
+            log_severity_level: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub RunOptionsGetRunTag: ::std::option::Option<
+    pub RunOptionsGetRunTag: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtRunOptions,
-            run_tag: *mut *const ::std::os::raw::c_char,
+            run_tag: *mut *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     pub RunOptionsSetTerminate:
-        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
     pub RunOptionsUnsetTerminate:
-        ::std::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
-    pub CreateTensorAsOrtValue: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(options: *mut OrtRunOptions) -> OrtStatusPtr>,
+    pub CreateTensorAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             allocator: *mut OrtAllocator,
             shape: *const i64,
@@ -4788,10 +4788,10 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateTensorWithDataAsOrtValue: ::std::option::Option<
+    pub CreateTensorWithDataAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtMemoryInfo,
-            p_data: *mut ::std::os::raw::c_void,
+            p_data: *mut ::core::ffi::c_void,
             p_data_len: usize,
             shape: *const i64,
             shape_len: usize,
@@ -4799,187 +4799,187 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub IsTensor: ::std::option::Option<
+    pub IsTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorMutableData: ::std::option::Option<
+    pub GetTensorMutableData: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub FillStringTensor: ::std::option::Option<
+    pub FillStringTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            s: *const *const ::std::os::raw::c_char,
+            s: *const *const ::core::ffi::c_char,
             s_len: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetStringTensorDataLength: ::std::option::Option<
+    pub GetStringTensorDataLength: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, len: *mut usize) -> OrtStatusPtr,
     >,
-    pub GetStringTensorContent: ::std::option::Option<
+    pub GetStringTensorContent: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            s: *mut ::std::os::raw::c_void,
+            s: *mut ::core::ffi::c_void,
             s_len: usize,
             offsets: *mut usize,
             offsets_len: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToTensorInfo: ::std::option::Option<
+    pub CastTypeInfoToTensorInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetOnnxTypeFromTypeInfo: ::std::option::Option<
+    pub GetOnnxTypeFromTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(type_info: *const OrtTypeInfo, out: *mut ONNXType) -> OrtStatusPtr,
     >,
-    pub CreateTensorTypeAndShapeInfo: ::std::option::Option<
+    pub CreateTensorTypeAndShapeInfo: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtTensorTypeAndShapeInfo) -> OrtStatusPtr,
     >,
-    pub SetTensorElementType: ::std::option::Option<
+    pub SetTensorElementType: ::core::option::Option<
         unsafe extern "C" fn(
             info: *mut OrtTensorTypeAndShapeInfo,
             type_: ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub SetDimensions: ::std::option::Option<
+    pub SetDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *mut OrtTensorTypeAndShapeInfo,
             dim_values: *const i64,
             dim_count: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorElementType: ::std::option::Option<
+    pub GetTensorElementType: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub GetDimensionsCount: ::std::option::Option<
+    pub GetDimensionsCount: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetDimensions: ::std::option::Option<
+    pub GetDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             dim_values: *mut i64,
             dim_values_length: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetSymbolicDimensions: ::std::option::Option<
+    pub GetSymbolicDimensions: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
-            dim_params: *mut *const ::std::os::raw::c_char,
+            dim_params: *mut *const ::core::ffi::c_char,
             dim_params_length: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorShapeElementCount: ::std::option::Option<
+    pub GetTensorShapeElementCount: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtTensorTypeAndShapeInfo,
             out: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorTypeAndShape: ::std::option::Option<
+    pub GetTensorTypeAndShape: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetTypeInfo: ::std::option::Option<
+    pub GetTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut *mut OrtTypeInfo) -> OrtStatusPtr,
     >,
-    pub GetValueType: ::std::option::Option<
+    pub GetValueType: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut ONNXType) -> OrtStatusPtr,
     >,
-    pub CreateMemoryInfo: ::std::option::Option<
+    pub CreateMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             type_: OrtAllocatorType,
-            id: ::std::os::raw::c_int,
+            id: ::core::ffi::c_int,
             mem_type: OrtMemType,
             out: *mut *mut OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCpuMemoryInfo: ::std::option::Option<
+    pub CreateCpuMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_: OrtAllocatorType,
             mem_type: OrtMemType,
             out: *mut *mut OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CompareMemoryInfo: ::std::option::Option<
+    pub CompareMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info1: *const OrtMemoryInfo,
             info2: *const OrtMemoryInfo,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetName: ::std::option::Option<
+    pub MemoryInfoGetName: ::core::option::Option<
         unsafe extern "C" fn(
             ptr: *const OrtMemoryInfo,
-            out: *mut *const ::std::os::raw::c_char,
+            out: *mut *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetId: ::std::option::Option<
+    pub MemoryInfoGetId: ::core::option::Option<
         unsafe extern "C" fn(
             ptr: *const OrtMemoryInfo,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetMemType: ::std::option::Option<
+    pub MemoryInfoGetMemType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtMemType) -> OrtStatusPtr,
     >,
-    pub MemoryInfoGetType: ::std::option::Option<
+    pub MemoryInfoGetType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtAllocatorType) -> OrtStatusPtr,
     >,
-    pub AllocatorAlloc: ::std::option::Option<
+    pub AllocatorAlloc: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *mut OrtAllocator,
             size: usize,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub AllocatorFree: ::std::option::Option<
+    pub AllocatorFree: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *mut OrtAllocator,
-            p: *mut ::std::os::raw::c_void,
+            p: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub AllocatorGetInfo: ::std::option::Option<
+    pub AllocatorGetInfo: ::core::option::Option<
         unsafe extern "C" fn(
             ort_allocator: *const OrtAllocator,
             out: *mut *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
     pub GetAllocatorWithDefaultOptions:
-        ::std::option::Option<unsafe extern "C" fn(out: *mut *mut OrtAllocator) -> OrtStatusPtr>,
-    pub AddFreeDimensionOverride: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(out: *mut *mut OrtAllocator) -> OrtStatusPtr>,
+    pub AddFreeDimensionOverride: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            dim_denotation: *const ::std::os::raw::c_char,
+            dim_denotation: *const ::core::ffi::c_char,
             dim_value: i64,
         ) -> OrtStatusPtr,
     >,
-    pub GetValue: ::std::option::Option<
+    pub GetValue: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            index: ::std::os::raw::c_int,
+            index: ::core::ffi::c_int,
             allocator: *mut OrtAllocator,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub GetValueCount: ::std::option::Option<
+    pub GetValueCount: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub CreateValue: ::std::option::Option<
+    pub CreateValue: ::core::option::Option<
         unsafe extern "C" fn(
             in_: *const *const OrtValue,
             num_values: usize,
@@ -4987,60 +4987,60 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateOpaqueValue: ::std::option::Option<
+    pub CreateOpaqueValue: ::core::option::Option<
         unsafe extern "C" fn(
-            domain_name: *const ::std::os::raw::c_char,
-            type_name: *const ::std::os::raw::c_char,
-            data_container: *const ::std::os::raw::c_void,
+            domain_name: *const ::core::ffi::c_char,
+            type_name: *const ::core::ffi::c_char,
+            data_container: *const ::core::ffi::c_void,
             data_container_size: usize,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub GetOpaqueValue: ::std::option::Option<
+    pub GetOpaqueValue: ::core::option::Option<
         unsafe extern "C" fn(
-            domain_name: *const ::std::os::raw::c_char,
-            type_name: *const ::std::os::raw::c_char,
+            domain_name: *const ::core::ffi::c_char,
+            type_name: *const ::core::ffi::c_char,
             in_: *const OrtValue,
-            data_container: *mut ::std::os::raw::c_void,
+            data_container: *mut ::core::ffi::c_void,
             data_container_size: usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_float: ::std::option::Option<
+    pub KernelInfoGetAttribute_float: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut f32,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_int64: ::std::option::Option<
+    pub KernelInfoGetAttribute_int64: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut i64,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_string: ::std::option::Option<
+    pub KernelInfoGetAttribute_string: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
-            out: *mut ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetInputCount: ::std::option::Option<
+    pub KernelContext_GetInputCount: ::core::option::Option<
         unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetOutputCount: ::std::option::Option<
+    pub KernelContext_GetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(context: *const OrtKernelContext, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetInput: ::std::option::Option<
+    pub KernelContext_GetInput: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             index: usize,
             out: *mut *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetOutput: ::std::option::Option<
+    pub KernelContext_GetOutput: ::core::option::Option<
         unsafe extern "C" fn(
             context: *mut OrtKernelContext,
             index: usize,
@@ -5049,234 +5049,234 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseEnv: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtEnv)>,
-    pub ReleaseStatus: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtStatus)>,
-    pub ReleaseMemoryInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMemoryInfo)>,
-    pub ReleaseSession: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSession)>,
-    pub ReleaseValue: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtValue)>,
-    pub ReleaseRunOptions: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtRunOptions)>,
-    pub ReleaseTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTypeInfo)>,
+    pub ReleaseEnv: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtEnv)>,
+    pub ReleaseStatus: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtStatus)>,
+    pub ReleaseMemoryInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtMemoryInfo)>,
+    pub ReleaseSession: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSession)>,
+    pub ReleaseValue: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtValue)>,
+    pub ReleaseRunOptions: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtRunOptions)>,
+    pub ReleaseTypeInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTypeInfo)>,
     pub ReleaseTensorTypeAndShapeInfo:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorTypeAndShapeInfo)>,
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTensorTypeAndShapeInfo)>,
     pub ReleaseSessionOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSessionOptions)>,
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSessionOptions)>,
     pub ReleaseCustomOpDomain:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCustomOpDomain)>,
-    pub GetDenotationFromTypeInfo: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCustomOpDomain)>,
+    pub GetDenotationFromTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
-            denotation: *mut *const ::std::os::raw::c_char,
+            denotation: *mut *const ::core::ffi::c_char,
             len: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToMapTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToMapTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtMapTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToSequenceTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToSequenceTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtSequenceTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetMapKeyType: ::std::option::Option<
+    pub GetMapKeyType: ::core::option::Option<
         unsafe extern "C" fn(
             map_type_info: *const OrtMapTypeInfo,
             out: *mut ONNXTensorElementDataType,
         ) -> OrtStatusPtr,
     >,
-    pub GetMapValueType: ::std::option::Option<
+    pub GetMapValueType: ::core::option::Option<
         unsafe extern "C" fn(
             map_type_info: *const OrtMapTypeInfo,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSequenceElementType: ::std::option::Option<
+    pub GetSequenceElementType: ::core::option::Option<
         unsafe extern "C" fn(
             sequence_type_info: *const OrtSequenceTypeInfo,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseMapTypeInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtMapTypeInfo)>,
+    pub ReleaseMapTypeInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtMapTypeInfo)>,
     pub ReleaseSequenceTypeInfo:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtSequenceTypeInfo)>,
-    pub SessionEndProfiling: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtSequenceTypeInfo)>,
+    pub SessionEndProfiling: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             allocator: *mut OrtAllocator,
-            out: *mut *mut ::std::os::raw::c_char,
+            out: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetModelMetadata: ::std::option::Option<
+    pub SessionGetModelMetadata: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             out: *mut *mut OrtModelMetadata,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetProducerName: ::std::option::Option<
+    pub ModelMetadataGetProducerName: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetGraphName: ::std::option::Option<
+    pub ModelMetadataGetGraphName: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetDomain: ::std::option::Option<
+    pub ModelMetadataGetDomain: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetDescription: ::std::option::Option<
+    pub ModelMetadataGetDescription: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataLookupCustomMetadataMap: ::std::option::Option<
+    pub ModelMetadataLookupCustomMetadataMap: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            key: *const ::std::os::raw::c_char,
-            value: *mut *mut ::std::os::raw::c_char,
+            key: *const ::core::ffi::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub ModelMetadataGetVersion: ::std::option::Option<
+    pub ModelMetadataGetVersion: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             value: *mut i64,
         ) -> OrtStatusPtr,
     >,
     pub ReleaseModelMetadata:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtModelMetadata)>,
-    pub CreateEnvWithGlobalThreadPools: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtModelMetadata)>,
+    pub CreateEnvWithGlobalThreadPools: ::core::option::Option<
         unsafe extern "C" fn(
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             tp_options: *const OrtThreadingOptions,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub DisablePerSessionThreads: ::std::option::Option<
+    pub DisablePerSessionThreads: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub CreateThreadingOptions: ::std::option::Option<
+    pub CreateThreadingOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtThreadingOptions) -> OrtStatusPtr,
     >,
     pub ReleaseThreadingOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtThreadingOptions)>,
-    pub ModelMetadataGetCustomMetadataMapKeys: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtThreadingOptions)>,
+    pub ModelMetadataGetCustomMetadataMapKeys: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            keys: *mut *mut *mut ::std::os::raw::c_char,
+            keys: *mut *mut *mut ::core::ffi::c_char,
             num_keys: *mut i64,
         ) -> OrtStatusPtr,
     >,
-    pub AddFreeDimensionOverrideByName: ::std::option::Option<
+    pub AddFreeDimensionOverrideByName: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            dim_name: *const ::std::os::raw::c_char,
+            dim_name: *const ::core::ffi::c_char,
             dim_value: i64,
         ) -> OrtStatusPtr,
     >,
-    pub GetAvailableProviders: ::std::option::Option<
+    pub GetAvailableProviders: ::core::option::Option<
         unsafe extern "C" fn(
-            out_ptr: *mut *mut *mut ::std::os::raw::c_char,
-            provider_length: *mut ::std::os::raw::c_int,
+            out_ptr: *mut *mut *mut ::core::ffi::c_char,
+            provider_length: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseAvailableProviders: ::std::option::Option<
+    pub ReleaseAvailableProviders: ::core::option::Option<
         unsafe extern "C" fn(
-            ptr: *mut *mut ::std::os::raw::c_char,
-            providers_length: ::std::os::raw::c_int,
+            ptr: *mut *mut ::core::ffi::c_char,
+            providers_length: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetStringTensorElementLength: ::std::option::Option<
+    pub GetStringTensorElementLength: ::core::option::Option<
         unsafe extern "C" fn(value: *const OrtValue, index: usize, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub GetStringTensorElement: ::std::option::Option<
+    pub GetStringTensorElement: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             s_len: usize,
             index: usize,
-            s: *mut ::std::os::raw::c_void,
+            s: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub FillStringTensorElement: ::std::option::Option<
+    pub FillStringTensorElement: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
-            s: *const ::std::os::raw::c_char,
+            s: *const ::core::ffi::c_char,
             index: usize,
         ) -> OrtStatusPtr,
     >,
-    pub AddSessionConfigEntry: ::std::option::Option<
+    pub AddSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *const ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CreateAllocator: ::std::option::Option<
+    pub CreateAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             session: *const OrtSession,
             mem_info: *const OrtMemoryInfo,
             out: *mut *mut OrtAllocator,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseAllocator: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtAllocator)>,
-    pub RunWithBinding: ::std::option::Option<
+    pub ReleaseAllocator: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtAllocator)>,
+    pub RunWithBinding: ::core::option::Option<
         unsafe extern "C" fn(
             session: *mut OrtSession,
             run_options: *const OrtRunOptions,
             binding_ptr: *const OrtIoBinding,
         ) -> OrtStatusPtr,
     >,
-    pub CreateIoBinding: ::std::option::Option<
+    pub CreateIoBinding: ::core::option::Option<
         unsafe extern "C" fn(session: *mut OrtSession, out: *mut *mut OrtIoBinding) -> OrtStatusPtr,
     >,
-    pub ReleaseIoBinding: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtIoBinding)>,
-    pub BindInput: ::std::option::Option<
+    pub ReleaseIoBinding: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtIoBinding)>,
+    pub BindInput: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val_ptr: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub BindOutput: ::std::option::Option<
+    pub BindOutput: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val_ptr: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub BindOutputToDevice: ::std::option::Option<
+    pub BindOutputToDevice: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *mut OrtIoBinding,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             mem_info_ptr: *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetBoundOutputNames: ::std::option::Option<
+    pub GetBoundOutputNames: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *const OrtIoBinding,
             allocator: *mut OrtAllocator,
-            buffer: *mut *mut ::std::os::raw::c_char,
+            buffer: *mut *mut ::core::ffi::c_char,
             lengths: *mut *mut usize,
             count: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetBoundOutputValues: ::std::option::Option<
+    pub GetBoundOutputValues: ::core::option::Option<
         unsafe extern "C" fn(
             binding_ptr: *const OrtIoBinding,
             allocator: *mut OrtAllocator,
@@ -5286,156 +5286,156 @@ pub struct OrtApi {
     >,
     #[doc = " \\brief Clears any previously set Inputs for an ::OrtIoBinding"]
     pub ClearBoundInputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
     #[doc = " \\brief Clears any previously set Outputs for an ::OrtIoBinding"]
     pub ClearBoundOutputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
-    pub TensorAt: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding)>,
+    pub TensorAt: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
             location_values: *const i64,
             location_values_count: usize,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub CreateAndRegisterAllocator: ::std::option::Option<
+    pub CreateAndRegisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             env: *mut OrtEnv,
             mem_info: *const OrtMemoryInfo,
             arena_cfg: *const OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub SetLanguageProjection: ::std::option::Option<
+    pub SetLanguageProjection: ::core::option::Option<
         unsafe extern "C" fn(
             ort_env: *const OrtEnv,
             projection: OrtLanguageProjection,
         ) -> OrtStatusPtr,
     >,
-    pub SessionGetProfilingStartTimeNs: ::std::option::Option<
+    pub SessionGetProfilingStartTimeNs: ::core::option::Option<
         unsafe extern "C" fn(session: *const OrtSession, out: *mut u64) -> OrtStatusPtr,
     >,
-    pub SetGlobalIntraOpNumThreads: ::std::option::Option<
+    pub SetGlobalIntraOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            intra_op_num_threads: ::std::os::raw::c_int,
+            intra_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalInterOpNumThreads: ::std::option::Option<
+    pub SetGlobalInterOpNumThreads: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            inter_op_num_threads: ::std::os::raw::c_int,
+            inter_op_num_threads: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalSpinControl: ::std::option::Option<
+    pub SetGlobalSpinControl: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            allow_spinning: ::std::os::raw::c_int,
+            allow_spinning: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub AddInitializer: ::std::option::Option<
+    pub AddInitializer: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             val: *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CreateEnvWithCustomLoggerAndGlobalThreadPools: ::std::option::Option<
+    pub CreateEnvWithCustomLoggerAndGlobalThreadPools: ::core::option::Option<
         unsafe extern "C" fn(
             logging_function: OrtLoggingFunction,
-            logger_param: *mut ::std::os::raw::c_void,
+            logger_param: *mut ::core::ffi::c_void,
             log_severity_level: OrtLoggingLevel,
-            logid: *const ::std::os::raw::c_char,
+            logid: *const ::core::ffi::c_char,
             tp_options: *const OrtThreadingOptions,
             out: *mut *mut OrtEnv,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_CUDA: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_CUDA: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cuda_options: *const OrtCUDAProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_ROCM: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_ROCM: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             rocm_options: *const OrtROCMProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_OpenVINO: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_OpenVINO: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             provider_options: *const OrtOpenVINOProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalDenormalAsZero: ::std::option::Option<
+    pub SetGlobalDenormalAsZero: ::core::option::Option<
         unsafe extern "C" fn(tp_options: *mut OrtThreadingOptions) -> OrtStatusPtr,
     >,
-    pub CreateArenaCfg: ::std::option::Option<
+    pub CreateArenaCfg: ::core::option::Option<
         unsafe extern "C" fn(
             max_mem: usize,
-            arena_extend_strategy: ::std::os::raw::c_int,
-            initial_chunk_size_bytes: ::std::os::raw::c_int,
-            max_dead_bytes_per_chunk: ::std::os::raw::c_int,
+            arena_extend_strategy: ::core::ffi::c_int,
+            initial_chunk_size_bytes: ::core::ffi::c_int,
+            max_dead_bytes_per_chunk: ::core::ffi::c_int,
             out: *mut *mut OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseArenaCfg: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtArenaCfg)>,
-    pub ModelMetadataGetGraphDescription: ::std::option::Option<
+    pub ReleaseArenaCfg: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtArenaCfg)>,
+    pub ModelMetadataGetGraphDescription: ::core::option::Option<
         unsafe extern "C" fn(
             model_metadata: *const OrtModelMetadata,
             allocator: *mut OrtAllocator,
-            value: *mut *mut ::std::os::raw::c_char,
+            value: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_TensorRT: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_TensorRT: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             tensorrt_options: *const OrtTensorRTProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub SetCurrentGpuDeviceId: ::std::option::Option<
-        unsafe extern "C" fn(device_id: ::std::os::raw::c_int) -> OrtStatusPtr,
+    pub SetCurrentGpuDeviceId: ::core::option::Option<
+        unsafe extern "C" fn(device_id: ::core::ffi::c_int) -> OrtStatusPtr,
     >,
-    pub GetCurrentGpuDeviceId: ::std::option::Option<
-        unsafe extern "C" fn(device_id: *mut ::std::os::raw::c_int) -> OrtStatusPtr,
+    pub GetCurrentGpuDeviceId: ::core::option::Option<
+        unsafe extern "C" fn(device_id: *mut ::core::ffi::c_int) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttributeArray_float: ::std::option::Option<
+    pub KernelInfoGetAttributeArray_float: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut f32,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttributeArray_int64: ::std::option::Option<
+    pub KernelInfoGetAttributeArray_int64: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             out: *mut i64,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub CreateArenaCfgV2: ::std::option::Option<
+    pub CreateArenaCfgV2: ::core::option::Option<
         unsafe extern "C" fn(
-            arena_config_keys: *const *const ::std::os::raw::c_char,
+            arena_config_keys: *const *const ::core::ffi::c_char,
             arena_config_values: *const usize,
             num_keys: usize,
             out: *mut *mut OrtArenaCfg,
         ) -> OrtStatusPtr,
     >,
-    pub AddRunConfigEntry: ::std::option::Option<
+    pub AddRunConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtRunOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *const ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub CreatePrepackedWeightsContainer: ::std::option::Option<
+    pub CreatePrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtPrepackedWeightsContainer) -> OrtStatusPtr,
     >,
     pub ReleasePrepackedWeightsContainer:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtPrepackedWeightsContainer)>,
-    pub CreateSessionWithPrepackedWeightsContainer: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtPrepackedWeightsContainer)>,
+    pub CreateSessionWithPrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
             model_path: *const wchar_t,
@@ -5444,61 +5444,61 @@ pub struct OrtApi {
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSessionFromArrayWithPrepackedWeightsContainer: ::std::option::Option<
+    pub CreateSessionFromArrayWithPrepackedWeightsContainer: ::core::option::Option<
         unsafe extern "C" fn(
             env: *const OrtEnv,
-            model_data: *const ::std::os::raw::c_void,
+            model_data: *const ::core::ffi::c_void,
             model_data_length: usize,
             options: *const OrtSessionOptions,
             prepacked_weights_container: *mut OrtPrepackedWeightsContainer,
             out: *mut *mut OrtSession,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_TensorRT_V2: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_TensorRT_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             tensorrt_options: *const OrtTensorRTProviderOptionsV2,
         ) -> OrtStatusPtr,
     >,
-    pub CreateTensorRTProviderOptions: ::std::option::Option<
+    pub CreateTensorRTProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtTensorRTProviderOptionsV2) -> OrtStatusPtr,
     >,
-    pub UpdateTensorRTProviderOptions: ::std::option::Option<
+    pub UpdateTensorRTProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             tensorrt_options: *mut OrtTensorRTProviderOptionsV2,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorRTProviderOptionsAsString: ::std::option::Option<
+    pub GetTensorRTProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             tensorrt_options: *const OrtTensorRTProviderOptionsV2,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtTensorRTProviderOptionsV2"]
     #[doc = ""]
     #[doc = " \\note This is an exception in the naming convention of other Release* functions, as the name of the method does not have the V2 suffix, but the type does"]
     pub ReleaseTensorRTProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtTensorRTProviderOptionsV2)>,
-    pub EnableOrtCustomOps: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtTensorRTProviderOptionsV2)>,
+    pub EnableOrtCustomOps: ::core::option::Option<
         unsafe extern "C" fn(options: *mut OrtSessionOptions) -> OrtStatusPtr,
     >,
-    pub RegisterAllocator: ::std::option::Option<
+    pub RegisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(env: *mut OrtEnv, allocator: *mut OrtAllocator) -> OrtStatusPtr,
     >,
-    pub UnregisterAllocator: ::std::option::Option<
+    pub UnregisterAllocator: ::core::option::Option<
         unsafe extern "C" fn(env: *mut OrtEnv, mem_info: *const OrtMemoryInfo) -> OrtStatusPtr,
     >,
-    pub IsSparseTensor: ::std::option::Option<
+    pub IsSparseTensor: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSparseTensorAsOrtValue: ::std::option::Option<
+    pub CreateSparseTensorAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             allocator: *mut OrtAllocator,
             dense_shape: *const i64,
@@ -5507,46 +5507,46 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorCoo: ::std::option::Option<
+    pub FillSparseTensorCoo: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             indices_data: *const i64,
             indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorCsr: ::std::option::Option<
+    pub FillSparseTensorCsr: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             inner_indices_data: *const i64,
             inner_indices_num: usize,
             outer_indices_data: *const i64,
             outer_indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub FillSparseTensorBlockSparse: ::std::option::Option<
+    pub FillSparseTensorBlockSparse: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             data_mem_info: *const OrtMemoryInfo,
             values_shape: *const i64,
             values_shape_len: usize,
-            values: *const ::std::os::raw::c_void,
+            values: *const ::core::ffi::c_void,
             indices_shape_data: *const i64,
             indices_shape_len: usize,
             indices_data: *const i32,
         ) -> OrtStatusPtr,
     >,
-    pub CreateSparseTensorWithValuesAsOrtValue: ::std::option::Option<
+    pub CreateSparseTensorWithValuesAsOrtValue: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtMemoryInfo,
-            p_data: *mut ::std::os::raw::c_void,
+            p_data: *mut ::core::ffi::c_void,
             dense_shape: *const i64,
             dense_shape_len: usize,
             values_shape: *const i64,
@@ -5555,14 +5555,14 @@ pub struct OrtApi {
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub UseCooIndices: ::std::option::Option<
+    pub UseCooIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             indices_data: *mut i64,
             indices_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub UseCsrIndices: ::std::option::Option<
+    pub UseCsrIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             inner_data: *mut i64,
@@ -5571,7 +5571,7 @@ pub struct OrtApi {
             outer_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub UseBlockSparseIndices: ::std::option::Option<
+    pub UseBlockSparseIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *mut OrtValue,
             indices_shape: *const i64,
@@ -5579,123 +5579,123 @@ pub struct OrtApi {
             indices_data: *mut i32,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorFormat: ::std::option::Option<
+    pub GetSparseTensorFormat: ::core::option::Option<
         unsafe extern "C" fn(ort_value: *const OrtValue, out: *mut OrtSparseFormat) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorValuesTypeAndShape: ::std::option::Option<
+    pub GetSparseTensorValuesTypeAndShape: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorValues: ::std::option::Option<
+    pub GetSparseTensorValues: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
-            out: *mut *const ::std::os::raw::c_void,
+            out: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorIndicesTypeShape: ::std::option::Option<
+    pub GetSparseTensorIndicesTypeShape: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             indices_format: OrtSparseIndicesFormat,
             out: *mut *mut OrtTensorTypeAndShapeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetSparseTensorIndices: ::std::option::Option<
+    pub GetSparseTensorIndices: ::core::option::Option<
         unsafe extern "C" fn(
             ort_value: *const OrtValue,
             indices_format: OrtSparseIndicesFormat,
             num_indices: *mut usize,
-            indices: *mut *const ::std::os::raw::c_void,
+            indices: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub HasValue: ::std::option::Option<
+    pub HasValue: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
-            out: *mut ::std::os::raw::c_int,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetGPUComputeStream: ::std::option::Option<
+    pub KernelContext_GetGPUComputeStream: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
-            out: *mut *mut ::std::os::raw::c_void,
+            out: *mut *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub GetTensorMemoryInfo: ::std::option::Option<
+    pub GetTensorMemoryInfo: ::core::option::Option<
         unsafe extern "C" fn(
             value: *const OrtValue,
             mem_info: *mut *const OrtMemoryInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetExecutionProviderApi: ::std::option::Option<
+    pub GetExecutionProviderApi: ::core::option::Option<
         unsafe extern "C" fn(
-            provider_name: *const ::std::os::raw::c_char,
+            provider_name: *const ::core::ffi::c_char,
             version: u32,
-            provider_api: *mut *const ::std::os::raw::c_void,
+            provider_api: *mut *const ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomCreateThreadFn: ::std::option::Option<
+    pub SessionOptionsSetCustomCreateThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomThreadCreationOptions: ::std::option::Option<
+    pub SessionOptionsSetCustomThreadCreationOptions: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+            ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsSetCustomJoinThreadFn: ::std::option::Option<
+    pub SessionOptionsSetCustomJoinThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomCreateThreadFn: ::std::option::Option<
+    pub SetGlobalCustomCreateThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
             ort_custom_create_thread_fn: OrtCustomCreateThreadFn,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomThreadCreationOptions: ::std::option::Option<
+    pub SetGlobalCustomThreadCreationOptions: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            ort_custom_thread_creation_options: *mut ::std::os::raw::c_void,
+            ort_custom_thread_creation_options: *mut ::core::ffi::c_void,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalCustomJoinThreadFn: ::std::option::Option<
+    pub SetGlobalCustomJoinThreadFn: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
             ort_custom_join_thread_fn: OrtCustomJoinThreadFn,
         ) -> OrtStatusPtr,
     >,
     pub SynchronizeBoundInputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
     pub SynchronizeBoundOutputs:
-        ::std::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
-    pub SessionOptionsAppendExecutionProvider_CUDA_V2: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(binding_ptr: *mut OrtIoBinding) -> OrtStatusPtr>,
+    pub SessionOptionsAppendExecutionProvider_CUDA_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cuda_options: *const OrtCUDAProviderOptionsV2,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCUDAProviderOptions: ::std::option::Option<
+    pub CreateCUDAProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtCUDAProviderOptionsV2) -> OrtStatusPtr,
     >,
-    pub UpdateCUDAProviderOptions: ::std::option::Option<
+    pub UpdateCUDAProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             cuda_options: *mut OrtCUDAProviderOptionsV2,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetCUDAProviderOptionsAsString: ::std::option::Option<
+    pub GetCUDAProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             cuda_options: *const OrtCUDAProviderOptionsV2,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtCUDAProviderOptionsV2"]
@@ -5704,74 +5704,74 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.11."]
     pub ReleaseCUDAProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCUDAProviderOptionsV2)>,
-    pub SessionOptionsAppendExecutionProvider_MIGraphX: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCUDAProviderOptionsV2)>,
+    pub SessionOptionsAppendExecutionProvider_MIGraphX: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             migraphx_options: *const OrtMIGraphXProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub AddExternalInitializers: ::std::option::Option<
+    pub AddExternalInitializers: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            initializer_names: *const *const ::std::os::raw::c_char,
+            initializer_names: *const *const ::core::ffi::c_char,
             initializers: *const *const OrtValue,
             initializers_num: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CreateOpAttr: ::std::option::Option<
+    pub CreateOpAttr: ::core::option::Option<
         unsafe extern "C" fn(
-            name: *const ::std::os::raw::c_char,
-            data: *const ::std::os::raw::c_void,
-            len: ::std::os::raw::c_int,
+            name: *const ::core::ffi::c_char,
+            data: *const ::core::ffi::c_void,
+            len: ::core::ffi::c_int,
             type_: OrtOpAttrType,
             op_attr: *mut *mut OrtOpAttr,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseOpAttr: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOpAttr)>,
-    pub CreateOp: ::std::option::Option<
+    pub ReleaseOpAttr: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtOpAttr)>,
+    pub CreateOp: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            op_name: *const ::std::os::raw::c_char,
-            domain: *const ::std::os::raw::c_char,
-            version: ::std::os::raw::c_int,
-            type_constraint_names: *mut *const ::std::os::raw::c_char,
+            op_name: *const ::core::ffi::c_char,
+            domain: *const ::core::ffi::c_char,
+            version: ::core::ffi::c_int,
+            type_constraint_names: *mut *const ::core::ffi::c_char,
             type_constraint_values: *const ONNXTensorElementDataType,
-            type_constraint_count: ::std::os::raw::c_int,
+            type_constraint_count: ::core::ffi::c_int,
             attr_values: *const *const OrtOpAttr,
-            attr_count: ::std::os::raw::c_int,
-            input_count: ::std::os::raw::c_int,
-            output_count: ::std::os::raw::c_int,
+            attr_count: ::core::ffi::c_int,
+            input_count: ::core::ffi::c_int,
+            output_count: ::core::ffi::c_int,
             ort_op: *mut *mut OrtOp,
         ) -> OrtStatusPtr,
     >,
-    pub InvokeOp: ::std::option::Option<
+    pub InvokeOp: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             ort_op: *const OrtOp,
             input_values: *const *const OrtValue,
-            input_count: ::std::os::raw::c_int,
+            input_count: ::core::ffi::c_int,
             output_values: *const *mut OrtValue,
-            output_count: ::std::os::raw::c_int,
+            output_count: ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseOp: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtOp)>,
-    pub SessionOptionsAppendExecutionProvider: ::std::option::Option<
+    pub ReleaseOp: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtOp)>,
+    pub SessionOptionsAppendExecutionProvider: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            provider_name: *const ::std::os::raw::c_char,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_name: *const ::core::ffi::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub CopyKernelInfo: ::std::option::Option<
+    pub CopyKernelInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             info_copy: *mut *mut OrtKernelInfo,
         ) -> OrtStatusPtr,
     >,
-    pub ReleaseKernelInfo: ::std::option::Option<unsafe extern "C" fn(input: *mut OrtKernelInfo)>,
+    pub ReleaseKernelInfo: ::core::option::Option<unsafe extern "C" fn(input: *mut OrtKernelInfo)>,
     #[doc = " \\name Ort Training"]
     #[doc = " @{"]
     #[doc = "** \\brief Gets the Training C Api struct"]
@@ -5789,29 +5789,29 @@ pub struct OrtApi {
     #[doc = "* \\since Version 1.13"]
     #[doc = "*/"]
     pub GetTrainingApi:
-        ::std::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtTrainingApi>,
-    pub SessionOptionsAppendExecutionProvider_CANN: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(version: u32) -> *const OrtTrainingApi>,
+    pub SessionOptionsAppendExecutionProvider_CANN: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             cann_options: *const OrtCANNProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub CreateCANNProviderOptions: ::std::option::Option<
+    pub CreateCANNProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtCANNProviderOptions) -> OrtStatusPtr,
     >,
-    pub UpdateCANNProviderOptions: ::std::option::Option<
+    pub UpdateCANNProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             cann_options: *mut OrtCANNProviderOptions,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetCANNProviderOptionsAsString: ::std::option::Option<
+    pub GetCANNProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             cann_options: *const OrtCANNProviderOptions,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an OrtCANNProviderOptions"]
@@ -5820,183 +5820,183 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.13."]
     pub ReleaseCANNProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtCANNProviderOptions)>,
-    pub MemoryInfoGetDeviceType: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtCANNProviderOptions)>,
+    pub MemoryInfoGetDeviceType: ::core::option::Option<
         unsafe extern "C" fn(ptr: *const OrtMemoryInfo, out: *mut OrtMemoryInfoDeviceType),
     >,
-    pub UpdateEnvWithCustomLogLevel: ::std::option::Option<
+    pub UpdateEnvWithCustomLogLevel: ::core::option::Option<
         unsafe extern "C" fn(
             ort_env: *mut OrtEnv,
             log_severity_level: OrtLoggingLevel,
         ) -> OrtStatusPtr,
     >,
-    pub SetGlobalIntraOpThreadAffinity: ::std::option::Option<
+    pub SetGlobalIntraOpThreadAffinity: ::core::option::Option<
         unsafe extern "C" fn(
             tp_options: *mut OrtThreadingOptions,
-            affinity_string: *const ::std::os::raw::c_char,
+            affinity_string: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsLibrary_V2: ::std::option::Option<
+    pub RegisterCustomOpsLibrary_V2: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             library_name: *const wchar_t,
         ) -> OrtStatusPtr,
     >,
-    pub RegisterCustomOpsUsingFunction: ::std::option::Option<
+    pub RegisterCustomOpsUsingFunction: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
-            registration_func_name: *const ::std::os::raw::c_char,
+            registration_func_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputCount: ::std::option::Option<
+    pub KernelInfo_GetInputCount: ::core::option::Option<
         unsafe extern "C" fn(info: *const OrtKernelInfo, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputCount: ::std::option::Option<
+    pub KernelInfo_GetOutputCount: ::core::option::Option<
         unsafe extern "C" fn(info: *const OrtKernelInfo, out: *mut usize) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputName: ::std::option::Option<
+    pub KernelInfo_GetInputName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputName: ::std::option::Option<
+    pub KernelInfo_GetOutputName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetInputTypeInfo: ::std::option::Option<
+    pub KernelInfo_GetInputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetOutputTypeInfo: ::std::option::Option<
+    pub KernelInfo_GetOutputTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
             type_info: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetAttribute_tensor: ::std::option::Option<
+    pub KernelInfoGetAttribute_tensor: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            name: *const ::std::os::raw::c_char,
+            name: *const ::core::ffi::c_char,
             allocator: *mut OrtAllocator,
             out: *mut *mut OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub HasSessionConfigEntry: ::std::option::Option<
+    pub HasSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            out: *mut ::std::os::raw::c_int,
+            config_key: *const ::core::ffi::c_char,
+            out: *mut ::core::ffi::c_int,
         ) -> OrtStatusPtr,
     >,
-    pub GetSessionConfigEntry: ::std::option::Option<
+    pub GetSessionConfigEntry: ::core::option::Option<
         unsafe extern "C" fn(
             options: *const OrtSessionOptions,
-            config_key: *const ::std::os::raw::c_char,
-            config_value: *mut ::std::os::raw::c_char,
+            config_key: *const ::core::ffi::c_char,
+            config_value: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub SessionOptionsAppendExecutionProvider_Dnnl: ::std::option::Option<
+    pub SessionOptionsAppendExecutionProvider_Dnnl: ::core::option::Option<
         unsafe extern "C" fn(
             options: *mut OrtSessionOptions,
             dnnl_options: *const OrtDnnlProviderOptions,
         ) -> OrtStatusPtr,
     >,
-    pub CreateDnnlProviderOptions: ::std::option::Option<
+    pub CreateDnnlProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(out: *mut *mut OrtDnnlProviderOptions) -> OrtStatusPtr,
     >,
-    pub UpdateDnnlProviderOptions: ::std::option::Option<
+    pub UpdateDnnlProviderOptions: ::core::option::Option<
         unsafe extern "C" fn(
             dnnl_options: *mut OrtDnnlProviderOptions,
-            provider_options_keys: *const *const ::std::os::raw::c_char,
-            provider_options_values: *const *const ::std::os::raw::c_char,
+            provider_options_keys: *const *const ::core::ffi::c_char,
+            provider_options_values: *const *const ::core::ffi::c_char,
             num_keys: usize,
         ) -> OrtStatusPtr,
     >,
-    pub GetDnnlProviderOptionsAsString: ::std::option::Option<
+    pub GetDnnlProviderOptionsAsString: ::core::option::Option<
         unsafe extern "C" fn(
             dnnl_options: *const OrtDnnlProviderOptions,
             allocator: *mut OrtAllocator,
-            ptr: *mut *mut ::std::os::raw::c_char,
+            ptr: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
     #[doc = " \\brief Release an ::OrtDnnlProviderOptions"]
     #[doc = ""]
     #[doc = " \\since Version 1.15."]
     pub ReleaseDnnlProviderOptions:
-        ::std::option::Option<unsafe extern "C" fn(input: *mut OrtDnnlProviderOptions)>,
-    pub KernelInfo_GetNodeName: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(input: *mut OrtDnnlProviderOptions)>,
+    pub KernelInfo_GetNodeName: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
-            out: *mut ::std::os::raw::c_char,
+            out: *mut ::core::ffi::c_char,
             size: *mut usize,
         ) -> OrtStatusPtr,
     >,
-    pub KernelInfo_GetLogger: ::std::option::Option<
+    pub KernelInfo_GetLogger: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             logger: *mut *const OrtLogger,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetLogger: ::std::option::Option<
+    pub KernelContext_GetLogger: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             logger: *mut *const OrtLogger,
         ) -> OrtStatusPtr,
     >,
-    pub Logger_LogMessage: ::std::option::Option<
+    pub Logger_LogMessage: ::core::option::Option<
         unsafe extern "C" fn(
             logger: *const OrtLogger,
             log_severity_level: OrtLoggingLevel,
-            message: *const ::std::os::raw::c_char,
+            message: *const ::core::ffi::c_char,
             file_path: *const wchar_t,
-            line_number: ::std::os::raw::c_int,
-            func_name: *const ::std::os::raw::c_char,
+            line_number: ::core::ffi::c_int,
+            func_name: *const ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub Logger_GetLoggingSeverityLevel: ::std::option::Option<
+    pub Logger_GetLoggingSeverityLevel: ::core::option::Option<
         unsafe extern "C" fn(logger: *const OrtLogger, out: *mut OrtLoggingLevel) -> OrtStatusPtr,
     >,
-    pub KernelInfoGetConstantInput_tensor: ::std::option::Option<
+    pub KernelInfoGetConstantInput_tensor: ::core::option::Option<
         unsafe extern "C" fn(
             info: *const OrtKernelInfo,
             index: usize,
-            is_constant: *mut ::std::os::raw::c_int,
+            is_constant: *mut ::core::ffi::c_int,
             out: *mut *const OrtValue,
         ) -> OrtStatusPtr,
     >,
-    pub CastTypeInfoToOptionalTypeInfo: ::std::option::Option<
+    pub CastTypeInfoToOptionalTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             type_info: *const OrtTypeInfo,
             out: *mut *const OrtOptionalTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetOptionalContainedTypeInfo: ::std::option::Option<
+    pub GetOptionalContainedTypeInfo: ::core::option::Option<
         unsafe extern "C" fn(
             optional_type_info: *const OrtOptionalTypeInfo,
             out: *mut *mut OrtTypeInfo,
         ) -> OrtStatusPtr,
     >,
-    pub GetResizedStringTensorElementBuffer: ::std::option::Option<
+    pub GetResizedStringTensorElementBuffer: ::core::option::Option<
         unsafe extern "C" fn(
             value: *mut OrtValue,
             index: usize,
             length_in_bytes: usize,
-            buffer: *mut *mut ::std::os::raw::c_char,
+            buffer: *mut *mut ::core::ffi::c_char,
         ) -> OrtStatusPtr,
     >,
-    pub KernelContext_GetAllocator: ::std::option::Option<
+    pub KernelContext_GetAllocator: ::core::option::Option<
         unsafe extern "C" fn(
             context: *const OrtKernelContext,
             mem_info: *const OrtMemoryInfo,
@@ -6009,26 +6009,26 @@ pub struct OrtApi {
     #[doc = ""]
     #[doc = " \\since Version 1.15."]
     pub GetBuildInfoString:
-        ::std::option::Option<unsafe extern "C" fn() -> *const ::std::os::raw::c_char>,
+        ::core::option::Option<unsafe extern "C" fn() -> *const ::core::ffi::c_char>,
 }
 #[test]
 fn bindgen_test_layout_OrtApi() {
     assert_eq!(
-        ::std::mem::size_of::<OrtApi>(),
+        ::core::mem::size_of::<OrtApi>(),
         2040usize,
         concat!("Size of: ", stringify!(OrtApi))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtApi>(),
+        ::core::mem::align_of::<OrtApi>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtApi))
     );
     fn test_field_CreateStatus() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateStatus) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateStatus) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -6043,9 +6043,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetErrorCode() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetErrorCode) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetErrorCode) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -6060,9 +6060,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetErrorMessage() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetErrorMessage) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetErrorMessage) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -6077,9 +6077,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnv) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -6094,9 +6094,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithCustomLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithCustomLogger) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -6111,9 +6111,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableTelemetryEvents() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableTelemetryEvents) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableTelemetryEvents) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -6128,9 +6128,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableTelemetryEvents() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableTelemetryEvents) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableTelemetryEvents) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -6145,9 +6145,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSession() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSession) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSession) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -6162,9 +6162,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionFromArray() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionFromArray) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionFromArray) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -6179,9 +6179,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Run() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Run) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Run) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -6196,9 +6196,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionOptions) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -6213,9 +6213,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetOptimizedModelFilePath() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetOptimizedModelFilePath) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetOptimizedModelFilePath) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -6230,9 +6230,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CloneSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CloneSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CloneSessionOptions) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -6247,9 +6247,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionExecutionMode() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionExecutionMode) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionExecutionMode) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -6264,9 +6264,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableProfiling) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -6281,9 +6281,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableProfiling) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -6298,9 +6298,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableMemPattern() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableMemPattern) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableMemPattern) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -6315,9 +6315,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableMemPattern() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableMemPattern) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableMemPattern) as usize - ptr as usize
             },
             136usize,
             concat!(
@@ -6332,9 +6332,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableCpuMemArena() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableCpuMemArena) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableCpuMemArena) as usize - ptr as usize
             },
             144usize,
             concat!(
@@ -6349,9 +6349,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisableCpuMemArena() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisableCpuMemArena) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisableCpuMemArena) as usize - ptr as usize
             },
             152usize,
             concat!(
@@ -6366,9 +6366,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogId) as usize - ptr as usize
             },
             160usize,
             concat!(
@@ -6383,9 +6383,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogVerbosityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogVerbosityLevel) as usize - ptr as usize
             },
             168usize,
             concat!(
@@ -6400,9 +6400,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionLogSeverityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionLogSeverityLevel) as usize - ptr as usize
             },
             176usize,
             concat!(
@@ -6417,9 +6417,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetSessionGraphOptimizationLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetSessionGraphOptimizationLevel) as usize
+                ::core::ptr::addr_of!((*ptr).SetSessionGraphOptimizationLevel) as usize
                     - ptr as usize
             },
             184usize,
@@ -6435,9 +6435,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetIntraOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetIntraOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetIntraOpNumThreads) as usize - ptr as usize
             },
             192usize,
             concat!(
@@ -6452,9 +6452,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetInterOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetInterOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetInterOpNumThreads) as usize - ptr as usize
             },
             200usize,
             concat!(
@@ -6469,9 +6469,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCustomOpDomain) as usize - ptr as usize
             },
             208usize,
             concat!(
@@ -6486,9 +6486,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CustomOpDomain_Add() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CustomOpDomain_Add) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CustomOpDomain_Add) as usize - ptr as usize
             },
             216usize,
             concat!(
@@ -6503,9 +6503,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddCustomOpDomain) as usize - ptr as usize
             },
             224usize,
             concat!(
@@ -6520,9 +6520,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsLibrary() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary) as usize - ptr as usize
             },
             232usize,
             concat!(
@@ -6537,9 +6537,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputCount) as usize - ptr as usize
             },
             240usize,
             concat!(
@@ -6554,9 +6554,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputCount) as usize - ptr as usize
             },
             248usize,
             concat!(
@@ -6571,9 +6571,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerCount) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerCount) as usize
                     - ptr as usize
             },
             256usize,
@@ -6589,9 +6589,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputTypeInfo) as usize - ptr as usize
             },
             264usize,
             concat!(
@@ -6606,9 +6606,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputTypeInfo) as usize - ptr as usize
             },
             272usize,
             concat!(
@@ -6623,9 +6623,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerTypeInfo) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerTypeInfo) as usize
                     - ptr as usize
             },
             280usize,
@@ -6641,9 +6641,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetInputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetInputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetInputName) as usize - ptr as usize
             },
             288usize,
             concat!(
@@ -6658,9 +6658,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOutputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOutputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOutputName) as usize - ptr as usize
             },
             296usize,
             concat!(
@@ -6675,9 +6675,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetOverridableInitializerName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetOverridableInitializerName) as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetOverridableInitializerName) as usize
                     - ptr as usize
             },
             304usize,
@@ -6693,9 +6693,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateRunOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateRunOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateRunOptions) as usize - ptr as usize
             },
             312usize,
             concat!(
@@ -6710,9 +6710,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogVerbosityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunLogVerbosityLevel) as usize
                     - ptr as usize
             },
             320usize,
@@ -6728,9 +6728,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunLogSeverityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunLogSeverityLevel) as usize
                     - ptr as usize
             },
             328usize,
@@ -6746,9 +6746,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetRunTag() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetRunTag) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetRunTag) as usize - ptr as usize
             },
             336usize,
             concat!(
@@ -6763,9 +6763,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunLogVerbosityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogVerbosityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunLogVerbosityLevel) as usize
                     - ptr as usize
             },
             344usize,
@@ -6781,9 +6781,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunLogSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunLogSeverityLevel) as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunLogSeverityLevel) as usize
                     - ptr as usize
             },
             352usize,
@@ -6799,9 +6799,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsGetRunTag() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsGetRunTag) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsGetRunTag) as usize - ptr as usize
             },
             360usize,
             concat!(
@@ -6816,9 +6816,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsSetTerminate() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsSetTerminate) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsSetTerminate) as usize - ptr as usize
             },
             368usize,
             concat!(
@@ -6833,9 +6833,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunOptionsUnsetTerminate() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunOptionsUnsetTerminate) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunOptionsUnsetTerminate) as usize - ptr as usize
             },
             376usize,
             concat!(
@@ -6850,9 +6850,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorAsOrtValue) as usize - ptr as usize
             },
             384usize,
             concat!(
@@ -6867,9 +6867,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorWithDataAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorWithDataAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorWithDataAsOrtValue) as usize - ptr as usize
             },
             392usize,
             concat!(
@@ -6884,9 +6884,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_IsTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).IsTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).IsTensor) as usize - ptr as usize
             },
             400usize,
             concat!(
@@ -6901,9 +6901,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorMutableData() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorMutableData) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorMutableData) as usize - ptr as usize
             },
             408usize,
             concat!(
@@ -6918,9 +6918,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillStringTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillStringTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillStringTensor) as usize - ptr as usize
             },
             416usize,
             concat!(
@@ -6935,9 +6935,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorDataLength() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorDataLength) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorDataLength) as usize - ptr as usize
             },
             424usize,
             concat!(
@@ -6952,9 +6952,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorContent() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorContent) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorContent) as usize - ptr as usize
             },
             432usize,
             concat!(
@@ -6969,9 +6969,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToTensorInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToTensorInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToTensorInfo) as usize - ptr as usize
             },
             440usize,
             concat!(
@@ -6986,9 +6986,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOnnxTypeFromTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOnnxTypeFromTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOnnxTypeFromTypeInfo) as usize - ptr as usize
             },
             448usize,
             concat!(
@@ -7003,9 +7003,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorTypeAndShapeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorTypeAndShapeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorTypeAndShapeInfo) as usize - ptr as usize
             },
             456usize,
             concat!(
@@ -7020,9 +7020,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetTensorElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetTensorElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetTensorElementType) as usize - ptr as usize
             },
             464usize,
             concat!(
@@ -7037,9 +7037,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetDimensions) as usize - ptr as usize
             },
             472usize,
             concat!(
@@ -7054,9 +7054,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorElementType) as usize - ptr as usize
             },
             480usize,
             concat!(
@@ -7071,9 +7071,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDimensionsCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDimensionsCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDimensionsCount) as usize - ptr as usize
             },
             488usize,
             concat!(
@@ -7088,9 +7088,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDimensions) as usize - ptr as usize
             },
             496usize,
             concat!(
@@ -7105,9 +7105,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSymbolicDimensions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSymbolicDimensions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSymbolicDimensions) as usize - ptr as usize
             },
             504usize,
             concat!(
@@ -7122,9 +7122,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorShapeElementCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorShapeElementCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorShapeElementCount) as usize - ptr as usize
             },
             512usize,
             concat!(
@@ -7139,9 +7139,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorTypeAndShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorTypeAndShape) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorTypeAndShape) as usize - ptr as usize
             },
             520usize,
             concat!(
@@ -7156,9 +7156,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTypeInfo) as usize - ptr as usize
             },
             528usize,
             concat!(
@@ -7173,9 +7173,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValueType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValueType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValueType) as usize - ptr as usize
             },
             536usize,
             concat!(
@@ -7190,9 +7190,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateMemoryInfo) as usize - ptr as usize
             },
             544usize,
             concat!(
@@ -7207,9 +7207,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCpuMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCpuMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCpuMemoryInfo) as usize - ptr as usize
             },
             552usize,
             concat!(
@@ -7224,9 +7224,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CompareMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CompareMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CompareMemoryInfo) as usize - ptr as usize
             },
             560usize,
             concat!(
@@ -7241,9 +7241,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetName) as usize - ptr as usize
             },
             568usize,
             concat!(
@@ -7258,9 +7258,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetId) as usize - ptr as usize
             },
             576usize,
             concat!(
@@ -7275,9 +7275,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetMemType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetMemType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetMemType) as usize - ptr as usize
             },
             584usize,
             concat!(
@@ -7292,9 +7292,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetType) as usize - ptr as usize
             },
             592usize,
             concat!(
@@ -7309,9 +7309,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorAlloc() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorAlloc) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorAlloc) as usize - ptr as usize
             },
             600usize,
             concat!(
@@ -7326,9 +7326,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorFree() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorFree) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorFree) as usize - ptr as usize
             },
             608usize,
             concat!(
@@ -7343,9 +7343,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AllocatorGetInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AllocatorGetInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AllocatorGetInfo) as usize - ptr as usize
             },
             616usize,
             concat!(
@@ -7360,9 +7360,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetAllocatorWithDefaultOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetAllocatorWithDefaultOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetAllocatorWithDefaultOptions) as usize - ptr as usize
             },
             624usize,
             concat!(
@@ -7377,9 +7377,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddFreeDimensionOverride() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverride) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddFreeDimensionOverride) as usize - ptr as usize
             },
             632usize,
             concat!(
@@ -7394,9 +7394,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValue) as usize - ptr as usize
             },
             640usize,
             concat!(
@@ -7411,9 +7411,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetValueCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetValueCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetValueCount) as usize - ptr as usize
             },
             648usize,
             concat!(
@@ -7428,9 +7428,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateValue) as usize - ptr as usize
             },
             656usize,
             concat!(
@@ -7445,9 +7445,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOpaqueValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOpaqueValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOpaqueValue) as usize - ptr as usize
             },
             664usize,
             concat!(
@@ -7462,9 +7462,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOpaqueValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOpaqueValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOpaqueValue) as usize - ptr as usize
             },
             672usize,
             concat!(
@@ -7479,9 +7479,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_float() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_float) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_float) as usize - ptr as usize
             },
             680usize,
             concat!(
@@ -7496,9 +7496,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_int64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_int64) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_int64) as usize - ptr as usize
             },
             688usize,
             concat!(
@@ -7513,9 +7513,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_string() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_string) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_string) as usize - ptr as usize
             },
             696usize,
             concat!(
@@ -7530,9 +7530,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetInputCount) as usize - ptr as usize
             },
             704usize,
             concat!(
@@ -7547,9 +7547,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetOutputCount) as usize - ptr as usize
             },
             712usize,
             concat!(
@@ -7564,9 +7564,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetInput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetInput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetInput) as usize - ptr as usize
             },
             720usize,
             concat!(
@@ -7581,9 +7581,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetOutput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetOutput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetOutput) as usize - ptr as usize
             },
             728usize,
             concat!(
@@ -7598,9 +7598,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseEnv() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseEnv) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseEnv) as usize - ptr as usize
             },
             736usize,
             concat!(
@@ -7615,9 +7615,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseStatus() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseStatus) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseStatus) as usize - ptr as usize
             },
             744usize,
             concat!(
@@ -7632,9 +7632,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseMemoryInfo) as usize - ptr as usize
             },
             752usize,
             concat!(
@@ -7649,9 +7649,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSession() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSession) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSession) as usize - ptr as usize
             },
             760usize,
             concat!(
@@ -7666,9 +7666,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseValue) as usize - ptr as usize
             },
             768usize,
             concat!(
@@ -7683,9 +7683,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseRunOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseRunOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseRunOptions) as usize - ptr as usize
             },
             776usize,
             concat!(
@@ -7700,9 +7700,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTypeInfo) as usize - ptr as usize
             },
             784usize,
             concat!(
@@ -7717,9 +7717,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTensorTypeAndShapeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTensorTypeAndShapeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTensorTypeAndShapeInfo) as usize - ptr as usize
             },
             792usize,
             concat!(
@@ -7734,9 +7734,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSessionOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSessionOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSessionOptions) as usize - ptr as usize
             },
             800usize,
             concat!(
@@ -7751,9 +7751,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCustomOpDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCustomOpDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCustomOpDomain) as usize - ptr as usize
             },
             808usize,
             concat!(
@@ -7768,9 +7768,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDenotationFromTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDenotationFromTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDenotationFromTypeInfo) as usize - ptr as usize
             },
             816usize,
             concat!(
@@ -7785,9 +7785,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToMapTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToMapTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToMapTypeInfo) as usize - ptr as usize
             },
             824usize,
             concat!(
@@ -7802,9 +7802,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToSequenceTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToSequenceTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToSequenceTypeInfo) as usize - ptr as usize
             },
             832usize,
             concat!(
@@ -7819,9 +7819,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetMapKeyType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetMapKeyType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetMapKeyType) as usize - ptr as usize
             },
             840usize,
             concat!(
@@ -7836,9 +7836,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetMapValueType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetMapValueType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetMapValueType) as usize - ptr as usize
             },
             848usize,
             concat!(
@@ -7853,9 +7853,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSequenceElementType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSequenceElementType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSequenceElementType) as usize - ptr as usize
             },
             856usize,
             concat!(
@@ -7870,9 +7870,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseMapTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseMapTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseMapTypeInfo) as usize - ptr as usize
             },
             864usize,
             concat!(
@@ -7887,9 +7887,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseSequenceTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseSequenceTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseSequenceTypeInfo) as usize - ptr as usize
             },
             872usize,
             concat!(
@@ -7904,9 +7904,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionEndProfiling() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionEndProfiling) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionEndProfiling) as usize - ptr as usize
             },
             880usize,
             concat!(
@@ -7921,9 +7921,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetModelMetadata() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetModelMetadata) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetModelMetadata) as usize - ptr as usize
             },
             888usize,
             concat!(
@@ -7938,9 +7938,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetProducerName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetProducerName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetProducerName) as usize - ptr as usize
             },
             896usize,
             concat!(
@@ -7955,9 +7955,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetGraphName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetGraphName) as usize - ptr as usize
             },
             904usize,
             concat!(
@@ -7972,9 +7972,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetDomain() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDomain) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetDomain) as usize - ptr as usize
             },
             912usize,
             concat!(
@@ -7989,9 +7989,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetDescription() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetDescription) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetDescription) as usize - ptr as usize
             },
             920usize,
             concat!(
@@ -8006,9 +8006,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataLookupCustomMetadataMap() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataLookupCustomMetadataMap) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataLookupCustomMetadataMap) as usize
                     - ptr as usize
             },
             928usize,
@@ -8024,9 +8024,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetVersion() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetVersion) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetVersion) as usize - ptr as usize
             },
             936usize,
             concat!(
@@ -8041,9 +8041,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseModelMetadata() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseModelMetadata) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseModelMetadata) as usize - ptr as usize
             },
             944usize,
             concat!(
@@ -8058,9 +8058,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithGlobalThreadPools() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithGlobalThreadPools) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithGlobalThreadPools) as usize - ptr as usize
             },
             952usize,
             concat!(
@@ -8075,9 +8075,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_DisablePerSessionThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).DisablePerSessionThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).DisablePerSessionThreads) as usize - ptr as usize
             },
             960usize,
             concat!(
@@ -8092,9 +8092,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateThreadingOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateThreadingOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateThreadingOptions) as usize - ptr as usize
             },
             968usize,
             concat!(
@@ -8109,9 +8109,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseThreadingOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseThreadingOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseThreadingOptions) as usize - ptr as usize
             },
             976usize,
             concat!(
@@ -8126,9 +8126,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetCustomMetadataMapKeys() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetCustomMetadataMapKeys) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetCustomMetadataMapKeys) as usize
                     - ptr as usize
             },
             984usize,
@@ -8144,9 +8144,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddFreeDimensionOverrideByName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddFreeDimensionOverrideByName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddFreeDimensionOverrideByName) as usize - ptr as usize
             },
             992usize,
             concat!(
@@ -8161,9 +8161,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetAvailableProviders() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetAvailableProviders) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetAvailableProviders) as usize - ptr as usize
             },
             1000usize,
             concat!(
@@ -8178,9 +8178,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseAvailableProviders() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseAvailableProviders) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseAvailableProviders) as usize - ptr as usize
             },
             1008usize,
             concat!(
@@ -8195,9 +8195,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorElementLength() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorElementLength) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorElementLength) as usize - ptr as usize
             },
             1016usize,
             concat!(
@@ -8212,9 +8212,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetStringTensorElement() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetStringTensorElement) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetStringTensorElement) as usize - ptr as usize
             },
             1024usize,
             concat!(
@@ -8229,9 +8229,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillStringTensorElement() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillStringTensorElement) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillStringTensorElement) as usize - ptr as usize
             },
             1032usize,
             concat!(
@@ -8246,9 +8246,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddSessionConfigEntry) as usize - ptr as usize
             },
             1040usize,
             concat!(
@@ -8263,9 +8263,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateAllocator) as usize - ptr as usize
             },
             1048usize,
             concat!(
@@ -8280,9 +8280,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseAllocator) as usize - ptr as usize
             },
             1056usize,
             concat!(
@@ -8297,9 +8297,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RunWithBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RunWithBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RunWithBinding) as usize - ptr as usize
             },
             1064usize,
             concat!(
@@ -8314,9 +8314,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateIoBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateIoBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateIoBinding) as usize - ptr as usize
             },
             1072usize,
             concat!(
@@ -8331,9 +8331,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseIoBinding() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseIoBinding) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseIoBinding) as usize - ptr as usize
             },
             1080usize,
             concat!(
@@ -8348,9 +8348,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindInput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindInput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindInput) as usize - ptr as usize
             },
             1088usize,
             concat!(
@@ -8365,9 +8365,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindOutput() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindOutput) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindOutput) as usize - ptr as usize
             },
             1096usize,
             concat!(
@@ -8382,9 +8382,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_BindOutputToDevice() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).BindOutputToDevice) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).BindOutputToDevice) as usize - ptr as usize
             },
             1104usize,
             concat!(
@@ -8399,9 +8399,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBoundOutputNames() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBoundOutputNames) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBoundOutputNames) as usize - ptr as usize
             },
             1112usize,
             concat!(
@@ -8416,9 +8416,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBoundOutputValues() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBoundOutputValues) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBoundOutputValues) as usize - ptr as usize
             },
             1120usize,
             concat!(
@@ -8433,9 +8433,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ClearBoundInputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ClearBoundInputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ClearBoundInputs) as usize - ptr as usize
             },
             1128usize,
             concat!(
@@ -8450,9 +8450,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ClearBoundOutputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ClearBoundOutputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ClearBoundOutputs) as usize - ptr as usize
             },
             1136usize,
             concat!(
@@ -8467,9 +8467,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_TensorAt() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).TensorAt) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).TensorAt) as usize - ptr as usize
             },
             1144usize,
             concat!(
@@ -8484,9 +8484,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateAndRegisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateAndRegisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateAndRegisterAllocator) as usize - ptr as usize
             },
             1152usize,
             concat!(
@@ -8501,9 +8501,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetLanguageProjection() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetLanguageProjection) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetLanguageProjection) as usize - ptr as usize
             },
             1160usize,
             concat!(
@@ -8518,9 +8518,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionGetProfilingStartTimeNs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionGetProfilingStartTimeNs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SessionGetProfilingStartTimeNs) as usize - ptr as usize
             },
             1168usize,
             concat!(
@@ -8535,9 +8535,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalIntraOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalIntraOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalIntraOpNumThreads) as usize - ptr as usize
             },
             1176usize,
             concat!(
@@ -8552,9 +8552,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalInterOpNumThreads() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalInterOpNumThreads) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalInterOpNumThreads) as usize - ptr as usize
             },
             1184usize,
             concat!(
@@ -8569,9 +8569,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalSpinControl() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalSpinControl) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalSpinControl) as usize - ptr as usize
             },
             1192usize,
             concat!(
@@ -8586,9 +8586,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddInitializer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddInitializer) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddInitializer) as usize - ptr as usize
             },
             1200usize,
             concat!(
@@ -8603,9 +8603,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateEnvWithCustomLoggerAndGlobalThreadPools() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateEnvWithCustomLoggerAndGlobalThreadPools) as usize
+                ::core::ptr::addr_of!((*ptr).CreateEnvWithCustomLoggerAndGlobalThreadPools) as usize
                     - ptr as usize
             },
             1208usize,
@@ -8621,9 +8621,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CUDA() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA) as usize
                     - ptr as usize
             },
             1216usize,
@@ -8639,9 +8639,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_ROCM() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_ROCM) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_ROCM) as usize
                     - ptr as usize
             },
             1224usize,
@@ -8657,9 +8657,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_OpenVINO() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_OpenVINO) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_OpenVINO) as usize
                     - ptr as usize
             },
             1232usize,
@@ -8675,9 +8675,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalDenormalAsZero() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalDenormalAsZero) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalDenormalAsZero) as usize - ptr as usize
             },
             1240usize,
             concat!(
@@ -8692,9 +8692,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateArenaCfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateArenaCfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateArenaCfg) as usize - ptr as usize
             },
             1248usize,
             concat!(
@@ -8709,9 +8709,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseArenaCfg() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseArenaCfg) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseArenaCfg) as usize - ptr as usize
             },
             1256usize,
             concat!(
@@ -8726,9 +8726,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ModelMetadataGetGraphDescription() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ModelMetadataGetGraphDescription) as usize
+                ::core::ptr::addr_of!((*ptr).ModelMetadataGetGraphDescription) as usize
                     - ptr as usize
             },
             1264usize,
@@ -8744,9 +8744,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_TensorRT() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT) as usize
                     - ptr as usize
             },
             1272usize,
@@ -8762,9 +8762,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetCurrentGpuDeviceId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetCurrentGpuDeviceId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetCurrentGpuDeviceId) as usize - ptr as usize
             },
             1280usize,
             concat!(
@@ -8779,9 +8779,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCurrentGpuDeviceId() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCurrentGpuDeviceId) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCurrentGpuDeviceId) as usize - ptr as usize
             },
             1288usize,
             concat!(
@@ -8796,9 +8796,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttributeArray_float() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_float) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_float) as usize
                     - ptr as usize
             },
             1296usize,
@@ -8814,9 +8814,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttributeArray_int64() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_int64) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttributeArray_int64) as usize
                     - ptr as usize
             },
             1304usize,
@@ -8832,9 +8832,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateArenaCfgV2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateArenaCfgV2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateArenaCfgV2) as usize - ptr as usize
             },
             1312usize,
             concat!(
@@ -8849,9 +8849,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddRunConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddRunConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddRunConfigEntry) as usize - ptr as usize
             },
             1320usize,
             concat!(
@@ -8866,9 +8866,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreatePrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreatePrepackedWeightsContainer) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreatePrepackedWeightsContainer) as usize - ptr as usize
             },
             1328usize,
             concat!(
@@ -8883,9 +8883,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleasePrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleasePrepackedWeightsContainer) as usize
+                ::core::ptr::addr_of!((*ptr).ReleasePrepackedWeightsContainer) as usize
                     - ptr as usize
             },
             1336usize,
@@ -8901,9 +8901,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionWithPrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionWithPrepackedWeightsContainer) as usize
+                ::core::ptr::addr_of!((*ptr).CreateSessionWithPrepackedWeightsContainer) as usize
                     - ptr as usize
             },
             1344usize,
@@ -8919,9 +8919,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSessionFromArrayWithPrepackedWeightsContainer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSessionFromArrayWithPrepackedWeightsContainer)
+                ::core::ptr::addr_of!((*ptr).CreateSessionFromArrayWithPrepackedWeightsContainer)
                     as usize
                     - ptr as usize
             },
@@ -8938,9 +8938,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_TensorRT_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT_V2)
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_TensorRT_V2)
                     as usize
                     - ptr as usize
             },
@@ -8957,9 +8957,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateTensorRTProviderOptions) as usize - ptr as usize
             },
             1368usize,
             concat!(
@@ -8974,9 +8974,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateTensorRTProviderOptions) as usize - ptr as usize
             },
             1376usize,
             concat!(
@@ -8991,9 +8991,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorRTProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorRTProviderOptionsAsString) as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorRTProviderOptionsAsString) as usize
                     - ptr as usize
             },
             1384usize,
@@ -9009,9 +9009,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseTensorRTProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseTensorRTProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseTensorRTProviderOptions) as usize - ptr as usize
             },
             1392usize,
             concat!(
@@ -9026,9 +9026,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_EnableOrtCustomOps() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).EnableOrtCustomOps) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).EnableOrtCustomOps) as usize - ptr as usize
             },
             1400usize,
             concat!(
@@ -9043,9 +9043,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterAllocator) as usize - ptr as usize
             },
             1408usize,
             concat!(
@@ -9060,9 +9060,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UnregisterAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UnregisterAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UnregisterAllocator) as usize - ptr as usize
             },
             1416usize,
             concat!(
@@ -9077,9 +9077,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_IsSparseTensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).IsSparseTensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).IsSparseTensor) as usize - ptr as usize
             },
             1424usize,
             concat!(
@@ -9094,9 +9094,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSparseTensorAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSparseTensorAsOrtValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateSparseTensorAsOrtValue) as usize - ptr as usize
             },
             1432usize,
             concat!(
@@ -9111,9 +9111,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorCoo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorCoo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorCoo) as usize - ptr as usize
             },
             1440usize,
             concat!(
@@ -9128,9 +9128,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorCsr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorCsr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorCsr) as usize - ptr as usize
             },
             1448usize,
             concat!(
@@ -9145,9 +9145,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_FillSparseTensorBlockSparse() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).FillSparseTensorBlockSparse) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).FillSparseTensorBlockSparse) as usize - ptr as usize
             },
             1456usize,
             concat!(
@@ -9162,9 +9162,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateSparseTensorWithValuesAsOrtValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateSparseTensorWithValuesAsOrtValue) as usize
+                ::core::ptr::addr_of!((*ptr).CreateSparseTensorWithValuesAsOrtValue) as usize
                     - ptr as usize
             },
             1464usize,
@@ -9180,9 +9180,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseCooIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseCooIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseCooIndices) as usize - ptr as usize
             },
             1472usize,
             concat!(
@@ -9197,9 +9197,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseCsrIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseCsrIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseCsrIndices) as usize - ptr as usize
             },
             1480usize,
             concat!(
@@ -9214,9 +9214,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UseBlockSparseIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UseBlockSparseIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UseBlockSparseIndices) as usize - ptr as usize
             },
             1488usize,
             concat!(
@@ -9231,9 +9231,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorFormat() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorFormat) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorFormat) as usize - ptr as usize
             },
             1496usize,
             concat!(
@@ -9248,9 +9248,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorValuesTypeAndShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorValuesTypeAndShape) as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorValuesTypeAndShape) as usize
                     - ptr as usize
             },
             1504usize,
@@ -9266,9 +9266,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorValues() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorValues) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorValues) as usize - ptr as usize
             },
             1512usize,
             concat!(
@@ -9283,9 +9283,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorIndicesTypeShape() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndicesTypeShape) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorIndicesTypeShape) as usize - ptr as usize
             },
             1520usize,
             concat!(
@@ -9300,9 +9300,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSparseTensorIndices() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSparseTensorIndices) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSparseTensorIndices) as usize - ptr as usize
             },
             1528usize,
             concat!(
@@ -9317,9 +9317,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_HasValue() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).HasValue) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).HasValue) as usize - ptr as usize
             },
             1536usize,
             concat!(
@@ -9334,9 +9334,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetGPUComputeStream() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetGPUComputeStream) as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetGPUComputeStream) as usize
                     - ptr as usize
             },
             1544usize,
@@ -9352,9 +9352,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTensorMemoryInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTensorMemoryInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTensorMemoryInfo) as usize - ptr as usize
             },
             1552usize,
             concat!(
@@ -9369,9 +9369,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetExecutionProviderApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetExecutionProviderApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetExecutionProviderApi) as usize - ptr as usize
             },
             1560usize,
             concat!(
@@ -9386,9 +9386,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomCreateThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomCreateThreadFn) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomCreateThreadFn) as usize
                     - ptr as usize
             },
             1568usize,
@@ -9404,9 +9404,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomThreadCreationOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomThreadCreationOptions) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomThreadCreationOptions) as usize
                     - ptr as usize
             },
             1576usize,
@@ -9422,9 +9422,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsSetCustomJoinThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsSetCustomJoinThreadFn) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsSetCustomJoinThreadFn) as usize
                     - ptr as usize
             },
             1584usize,
@@ -9440,9 +9440,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomCreateThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomCreateThreadFn) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomCreateThreadFn) as usize - ptr as usize
             },
             1592usize,
             concat!(
@@ -9457,9 +9457,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomThreadCreationOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomThreadCreationOptions) as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomThreadCreationOptions) as usize
                     - ptr as usize
             },
             1600usize,
@@ -9475,9 +9475,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalCustomJoinThreadFn() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalCustomJoinThreadFn) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalCustomJoinThreadFn) as usize - ptr as usize
             },
             1608usize,
             concat!(
@@ -9492,9 +9492,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SynchronizeBoundInputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SynchronizeBoundInputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SynchronizeBoundInputs) as usize - ptr as usize
             },
             1616usize,
             concat!(
@@ -9509,9 +9509,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SynchronizeBoundOutputs() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SynchronizeBoundOutputs) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SynchronizeBoundOutputs) as usize - ptr as usize
             },
             1624usize,
             concat!(
@@ -9526,9 +9526,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CUDA_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA_V2) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CUDA_V2) as usize
                     - ptr as usize
             },
             1632usize,
@@ -9544,9 +9544,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCUDAProviderOptions) as usize - ptr as usize
             },
             1640usize,
             concat!(
@@ -9561,9 +9561,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateCUDAProviderOptions) as usize - ptr as usize
             },
             1648usize,
             concat!(
@@ -9578,9 +9578,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCUDAProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCUDAProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCUDAProviderOptionsAsString) as usize - ptr as usize
             },
             1656usize,
             concat!(
@@ -9595,9 +9595,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCUDAProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCUDAProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCUDAProviderOptions) as usize - ptr as usize
             },
             1664usize,
             concat!(
@@ -9612,9 +9612,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_MIGraphX() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_MIGraphX) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_MIGraphX) as usize
                     - ptr as usize
             },
             1672usize,
@@ -9630,9 +9630,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_AddExternalInitializers() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).AddExternalInitializers) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).AddExternalInitializers) as usize - ptr as usize
             },
             1680usize,
             concat!(
@@ -9647,9 +9647,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOpAttr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOpAttr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOpAttr) as usize - ptr as usize
             },
             1688usize,
             concat!(
@@ -9664,9 +9664,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseOpAttr() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseOpAttr) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseOpAttr) as usize - ptr as usize
             },
             1696usize,
             concat!(
@@ -9681,9 +9681,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateOp) as usize - ptr as usize
             },
             1704usize,
             concat!(
@@ -9698,9 +9698,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_InvokeOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).InvokeOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).InvokeOp) as usize - ptr as usize
             },
             1712usize,
             concat!(
@@ -9715,9 +9715,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseOp() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseOp) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseOp) as usize - ptr as usize
             },
             1720usize,
             concat!(
@@ -9732,9 +9732,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider) as usize
                     - ptr as usize
             },
             1728usize,
@@ -9750,9 +9750,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CopyKernelInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CopyKernelInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CopyKernelInfo) as usize - ptr as usize
             },
             1736usize,
             concat!(
@@ -9767,9 +9767,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseKernelInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseKernelInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseKernelInfo) as usize - ptr as usize
             },
             1744usize,
             concat!(
@@ -9784,9 +9784,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetTrainingApi() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetTrainingApi) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetTrainingApi) as usize - ptr as usize
             },
             1752usize,
             concat!(
@@ -9801,9 +9801,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_CANN() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CANN) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_CANN) as usize
                     - ptr as usize
             },
             1760usize,
@@ -9819,9 +9819,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateCANNProviderOptions) as usize - ptr as usize
             },
             1768usize,
             concat!(
@@ -9836,9 +9836,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateCANNProviderOptions) as usize - ptr as usize
             },
             1776usize,
             concat!(
@@ -9853,9 +9853,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetCANNProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetCANNProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetCANNProviderOptionsAsString) as usize - ptr as usize
             },
             1784usize,
             concat!(
@@ -9870,9 +9870,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseCANNProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseCANNProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseCANNProviderOptions) as usize - ptr as usize
             },
             1792usize,
             concat!(
@@ -9887,9 +9887,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_MemoryInfoGetDeviceType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).MemoryInfoGetDeviceType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).MemoryInfoGetDeviceType) as usize - ptr as usize
             },
             1800usize,
             concat!(
@@ -9904,9 +9904,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateEnvWithCustomLogLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateEnvWithCustomLogLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateEnvWithCustomLogLevel) as usize - ptr as usize
             },
             1808usize,
             concat!(
@@ -9921,9 +9921,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SetGlobalIntraOpThreadAffinity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SetGlobalIntraOpThreadAffinity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).SetGlobalIntraOpThreadAffinity) as usize - ptr as usize
             },
             1816usize,
             concat!(
@@ -9938,9 +9938,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsLibrary_V2() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary_V2) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsLibrary_V2) as usize - ptr as usize
             },
             1824usize,
             concat!(
@@ -9955,9 +9955,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_RegisterCustomOpsUsingFunction() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).RegisterCustomOpsUsingFunction) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).RegisterCustomOpsUsingFunction) as usize - ptr as usize
             },
             1832usize,
             concat!(
@@ -9972,9 +9972,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputCount) as usize - ptr as usize
             },
             1840usize,
             concat!(
@@ -9989,9 +9989,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputCount) as usize - ptr as usize
             },
             1848usize,
             concat!(
@@ -10006,9 +10006,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputName) as usize - ptr as usize
             },
             1856usize,
             concat!(
@@ -10023,9 +10023,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputName) as usize - ptr as usize
             },
             1864usize,
             concat!(
@@ -10040,9 +10040,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetInputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetInputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetInputTypeInfo) as usize - ptr as usize
             },
             1872usize,
             concat!(
@@ -10057,9 +10057,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetOutputTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetOutputTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetOutputTypeInfo) as usize - ptr as usize
             },
             1880usize,
             concat!(
@@ -10074,9 +10074,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetAttribute_tensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetAttribute_tensor) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetAttribute_tensor) as usize - ptr as usize
             },
             1888usize,
             concat!(
@@ -10091,9 +10091,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_HasSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).HasSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).HasSessionConfigEntry) as usize - ptr as usize
             },
             1896usize,
             concat!(
@@ -10108,9 +10108,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetSessionConfigEntry() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetSessionConfigEntry) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetSessionConfigEntry) as usize - ptr as usize
             },
             1904usize,
             concat!(
@@ -10125,9 +10125,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_SessionOptionsAppendExecutionProvider_Dnnl() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_Dnnl) as usize
+                ::core::ptr::addr_of!((*ptr).SessionOptionsAppendExecutionProvider_Dnnl) as usize
                     - ptr as usize
             },
             1912usize,
@@ -10143,9 +10143,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CreateDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateDnnlProviderOptions) as usize - ptr as usize
             },
             1920usize,
             concat!(
@@ -10160,9 +10160,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_UpdateDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).UpdateDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).UpdateDnnlProviderOptions) as usize - ptr as usize
             },
             1928usize,
             concat!(
@@ -10177,9 +10177,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetDnnlProviderOptionsAsString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetDnnlProviderOptionsAsString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetDnnlProviderOptionsAsString) as usize - ptr as usize
             },
             1936usize,
             concat!(
@@ -10194,9 +10194,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_ReleaseDnnlProviderOptions() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).ReleaseDnnlProviderOptions) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).ReleaseDnnlProviderOptions) as usize - ptr as usize
             },
             1944usize,
             concat!(
@@ -10211,9 +10211,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetNodeName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetNodeName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetNodeName) as usize - ptr as usize
             },
             1952usize,
             concat!(
@@ -10228,9 +10228,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfo_GetLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfo_GetLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfo_GetLogger) as usize - ptr as usize
             },
             1960usize,
             concat!(
@@ -10245,9 +10245,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetLogger() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetLogger) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetLogger) as usize - ptr as usize
             },
             1968usize,
             concat!(
@@ -10262,9 +10262,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Logger_LogMessage() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Logger_LogMessage) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Logger_LogMessage) as usize - ptr as usize
             },
             1976usize,
             concat!(
@@ -10279,9 +10279,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_Logger_GetLoggingSeverityLevel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).Logger_GetLoggingSeverityLevel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).Logger_GetLoggingSeverityLevel) as usize - ptr as usize
             },
             1984usize,
             concat!(
@@ -10296,9 +10296,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelInfoGetConstantInput_tensor() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelInfoGetConstantInput_tensor) as usize
+                ::core::ptr::addr_of!((*ptr).KernelInfoGetConstantInput_tensor) as usize
                     - ptr as usize
             },
             1992usize,
@@ -10314,9 +10314,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_CastTypeInfoToOptionalTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CastTypeInfoToOptionalTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CastTypeInfoToOptionalTypeInfo) as usize - ptr as usize
             },
             2000usize,
             concat!(
@@ -10331,9 +10331,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetOptionalContainedTypeInfo() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOptionalContainedTypeInfo) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOptionalContainedTypeInfo) as usize - ptr as usize
             },
             2008usize,
             concat!(
@@ -10348,9 +10348,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetResizedStringTensorElementBuffer() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetResizedStringTensorElementBuffer) as usize
+                ::core::ptr::addr_of!((*ptr).GetResizedStringTensorElementBuffer) as usize
                     - ptr as usize
             },
             2016usize,
@@ -10366,9 +10366,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_KernelContext_GetAllocator() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelContext_GetAllocator) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelContext_GetAllocator) as usize - ptr as usize
             },
             2024usize,
             concat!(
@@ -10383,9 +10383,9 @@ fn bindgen_test_layout_OrtApi() {
     fn test_field_GetBuildInfoString() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtApi>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtApi>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetBuildInfoString) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetBuildInfoString) as usize - ptr as usize
             },
             2032usize,
             concat!(
@@ -10409,83 +10409,83 @@ pub enum OrtCustomOpInputOutputCharacteristic {
 #[derive(Debug, Copy, Clone)]
 pub struct OrtCustomOp {
     pub version: u32,
-    pub CreateKernel: ::std::option::Option<
+    pub CreateKernel: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             api: *const OrtApi,
             info: *const OrtKernelInfo,
-        ) -> *mut ::std::os::raw::c_void,
+        ) -> *mut ::core::ffi::c_void,
     >,
-    pub GetName: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    pub GetName: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::core::ffi::c_char,
     >,
-    pub GetExecutionProviderType: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::std::os::raw::c_char,
+    pub GetExecutionProviderType: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> *const ::core::ffi::c_char,
     >,
-    pub GetInputType: ::std::option::Option<
+    pub GetInputType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
     >,
     pub GetInputTypeCount:
-        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
-    pub GetOutputType: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub GetOutputType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> ONNXTensorElementDataType,
     >,
     pub GetOutputTypeCount:
-        ::std::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
-    pub KernelCompute: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op: *const OrtCustomOp) -> usize>,
+    pub KernelCompute: ::core::option::Option<
         unsafe extern "C" fn(
-            op_kernel: *mut ::std::os::raw::c_void,
+            op_kernel: *mut ::core::ffi::c_void,
             context: *mut OrtKernelContext,
         ),
     >,
     pub KernelDestroy:
-        ::std::option::Option<unsafe extern "C" fn(op_kernel: *mut ::std::os::raw::c_void)>,
-    pub GetInputCharacteristic: ::std::option::Option<
+        ::core::option::Option<unsafe extern "C" fn(op_kernel: *mut ::core::ffi::c_void)>,
+    pub GetInputCharacteristic: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             index: usize,
         ) -> OrtCustomOpInputOutputCharacteristic,
     >,
-    pub GetOutputCharacteristic: ::std::option::Option<
+    pub GetOutputCharacteristic: ::core::option::Option<
         unsafe extern "C" fn(
             op: *const OrtCustomOp,
             index: usize,
         ) -> OrtCustomOpInputOutputCharacteristic,
     >,
-    pub GetInputMemoryType: ::std::option::Option<
+    pub GetInputMemoryType: ::core::option::Option<
         unsafe extern "C" fn(op: *const OrtCustomOp, index: usize) -> OrtMemType,
     >,
-    pub GetVariadicInputMinArity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicInputMinArity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicInputHomogeneity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicInputHomogeneity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicOutputMinArity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicOutputMinArity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
-    pub GetVariadicOutputHomogeneity: ::std::option::Option<
-        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::std::os::raw::c_int,
+    pub GetVariadicOutputHomogeneity: ::core::option::Option<
+        unsafe extern "C" fn(op: *const OrtCustomOp) -> ::core::ffi::c_int,
     >,
 }
 #[test]
 fn bindgen_test_layout_OrtCustomOp() {
     assert_eq!(
-        ::std::mem::size_of::<OrtCustomOp>(),
+        ::core::mem::size_of::<OrtCustomOp>(),
         136usize,
         concat!("Size of: ", stringify!(OrtCustomOp))
     );
     assert_eq!(
-        ::std::mem::align_of::<OrtCustomOp>(),
+        ::core::mem::align_of::<OrtCustomOp>(),
         8usize,
         concat!("Alignment of ", stringify!(OrtCustomOp))
     );
     fn test_field_version() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).version) as usize - ptr as usize
             },
             0usize,
             concat!(
@@ -10500,9 +10500,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_CreateKernel() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).CreateKernel) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).CreateKernel) as usize - ptr as usize
             },
             8usize,
             concat!(
@@ -10517,9 +10517,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetName() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetName) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetName) as usize - ptr as usize
             },
             16usize,
             concat!(
@@ -10534,9 +10534,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetExecutionProviderType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetExecutionProviderType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetExecutionProviderType) as usize - ptr as usize
             },
             24usize,
             concat!(
@@ -10551,9 +10551,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputType) as usize - ptr as usize
             },
             32usize,
             concat!(
@@ -10568,9 +10568,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputTypeCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputTypeCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputTypeCount) as usize - ptr as usize
             },
             40usize,
             concat!(
@@ -10585,9 +10585,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputType) as usize - ptr as usize
             },
             48usize,
             concat!(
@@ -10602,9 +10602,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputTypeCount() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputTypeCount) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputTypeCount) as usize - ptr as usize
             },
             56usize,
             concat!(
@@ -10619,9 +10619,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_KernelCompute() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelCompute) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelCompute) as usize - ptr as usize
             },
             64usize,
             concat!(
@@ -10636,9 +10636,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_KernelDestroy() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).KernelDestroy) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).KernelDestroy) as usize - ptr as usize
             },
             72usize,
             concat!(
@@ -10653,9 +10653,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputCharacteristic() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputCharacteristic) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputCharacteristic) as usize - ptr as usize
             },
             80usize,
             concat!(
@@ -10670,9 +10670,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetOutputCharacteristic() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetOutputCharacteristic) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetOutputCharacteristic) as usize - ptr as usize
             },
             88usize,
             concat!(
@@ -10687,9 +10687,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetInputMemoryType() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetInputMemoryType) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetInputMemoryType) as usize - ptr as usize
             },
             96usize,
             concat!(
@@ -10704,9 +10704,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicInputMinArity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicInputMinArity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicInputMinArity) as usize - ptr as usize
             },
             104usize,
             concat!(
@@ -10721,9 +10721,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicInputHomogeneity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicInputHomogeneity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicInputHomogeneity) as usize - ptr as usize
             },
             112usize,
             concat!(
@@ -10738,9 +10738,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicOutputMinArity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicOutputMinArity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicOutputMinArity) as usize - ptr as usize
             },
             120usize,
             concat!(
@@ -10755,9 +10755,9 @@ fn bindgen_test_layout_OrtCustomOp() {
     fn test_field_GetVariadicOutputHomogeneity() {
         assert_eq!(
             unsafe {
-                let uninit = ::std::mem::MaybeUninit::<OrtCustomOp>::uninit();
+                let uninit = ::core::mem::MaybeUninit::<OrtCustomOp>::uninit();
                 let ptr = uninit.as_ptr();
-                ::std::ptr::addr_of!((*ptr).GetVariadicOutputHomogeneity) as usize - ptr as usize
+                ::core::ptr::addr_of!((*ptr).GetVariadicOutputHomogeneity) as usize - ptr as usize
             },
             128usize,
             concat!(
@@ -10773,19 +10773,19 @@ fn bindgen_test_layout_OrtCustomOp() {
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_CUDA(
         options: *mut OrtSessionOptions,
-        device_id: ::std::os::raw::c_int,
+        device_id: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_MIGraphX(
         options: *mut OrtSessionOptions,
-        device_id: ::std::os::raw::c_int,
+        device_id: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 extern "C" {
     pub fn OrtSessionOptionsAppendExecutionProvider_Dnnl(
         options: *mut OrtSessionOptions,
-        use_arena: ::std::os::raw::c_int,
+        use_arena: ::core::ffi::c_int,
     ) -> OrtStatusPtr;
 }
 #[repr(C)]
@@ -1,3 +1,6 @@
+// The bindings only refer to `core`: the crate can be used without `std`, where the runtime is
+// provided by the platform.
+#![cfg_attr(not(test), no_std)]
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -0,0 +1,105 @@
+//! Module containing the compression formats models can be loaded from
+//!
+//! Compressed models are decompressed to memory while being read, then loaded with
+//! [`SessionBuilder::with_model_from_memory()`](../session/struct.SessionBuilder.html#method.with_model_from_memory),
+//! without going through a temporary file. See
+//! [`with_model_from_compressed()`](../session/struct.SessionBuilder.html#method.with_model_from_compressed)
+//! and [`with_model_from_compressed_file()`](../session/struct.SessionBuilder.html#method.with_model_from_compressed_file).
+
+use std::{io::Read, path::Path};
+
+use crate::error::{OrtError, Result};
+
+/// Compression format of a model
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compression {
+    /// gzip (`.gz`), requires the `gzip` feature
+    #[cfg(feature = "gzip")]
+    Gzip,
+    /// Zstandard (`.zst`), requires the `zstd` feature
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+impl Compression {
+    /// Compression format matching the extension of `path`, if any
+    pub fn from_path<P>(path: P) -> Option<Compression>
+    where
+        P: AsRef<Path>,
+    {
+        match path.as_ref().extension()?.to_str()? {
+            #[cfg(feature = "gzip")]
+            "gz" | "gzip" => Some(Compression::Gzip),
+            #[cfg(feature = "zstd")]
+            "zst" | "zstd" => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Decompress everything `reader` returns into memory
+    ///
+    /// `size_hint` is the expected size of the decompressed model, to avoid reallocations.
+    pub(crate) fn decompress<R>(self, reader: R, size_hint: usize) -> Result<Vec<u8>>
+    where
+        R: Read,
+    {
+        let mut model_bytes = Vec::with_capacity(size_hint);
+        let read = match self {
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => {
+                flate2::read::MultiGzDecoder::new(reader).read_to_end(&mut model_bytes)
+            }
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => zstd::Decoder::new(reader)
+                .and_then(|mut decoder| decoder.read_to_end(&mut model_bytes)),
+        };
+        read.map_err(OrtError::DecompressModel)?;
+        Ok(model_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn model_bytes() -> Vec<u8> {
+        (0..100_000u32).map(|i| (i % 7) as u8).collect()
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn decompress_gzip() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&model_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(
+            Compression::from_path("model.onnx.gz"),
+            Some(Compression::Gzip)
+        );
+        let decompressed = Compression::Gzip.decompress(&compressed[..], 0).unwrap();
+        assert_eq!(decompressed, model_bytes());
+        assert!(matches!(
+            Compression::Gzip.decompress(&compressed[..100], 0),
+            Err(OrtError::DecompressModel(_))
+        ));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn decompress_zstd() {
+        let compressed = zstd::encode_all(&model_bytes()[..], 0).unwrap();
+
+        assert_eq!(
+            Compression::from_path("model.onnx.zst"),
+            Some(Compression::Zstd)
+        );
+        let decompressed = Compression::Zstd.decompress(&compressed[..], 0).unwrap();
+        assert_eq!(decompressed, model_bytes());
+        assert_eq!(Compression::from_path("model.onnx"), None);
+    }
+}
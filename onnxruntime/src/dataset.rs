@@ -0,0 +1,261 @@
+//! Module feeding sessions from iterators of batches, for offline bulk scoring
+//!
+//! A [`Dataset`](struct.Dataset.html) wraps an iterator of pre-batched inputs. Running it pulls
+//! the batches ahead of the sessions from a thread of its own (so that reading or decoding the
+//! next batches overlaps with inference), spreads them over the given sessions, and hands back a
+//! [`BatchIterator`](struct.BatchIterator.html) yielding the outputs of each batch, in order.
+//!
+//! For long running jobs, a [`Job`](struct.Job.html) writes the outputs to an
+//! [`OutputSink`](trait.OutputSink.html) and saves its progress along the way, so that it can
+//! resume after a crash. With the `csv` and `parquet` features, [`CsvSink`](struct.CsvSink.html)
+//! and [`ParquetSink`](struct.ParquetSink.html) stream the outputs to files, so that they need
+//! not be held in memory.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use ndarray::ArrayD;
+
+use crate::{
+    error::Result,
+    session::Session,
+    tensor::{IntoInput, OrtOwnedTensor},
+    TypeToTensorElementDataType,
+};
+
+#[cfg(any(feature = "csv", feature = "parquet"))]
+mod columns;
+#[cfg(feature = "csv")]
+mod csv_sink;
+mod job;
+#[cfg(feature = "parquet")]
+mod parquet_sink;
+
+#[cfg(feature = "csv")]
+pub use csv_sink::CsvSink;
+pub use job::{Checkpoint, Job, JobMetrics, OutputSink};
+#[cfg(feature = "parquet")]
+pub use parquet_sink::{ArrowElement, ParquetSink};
+
+/// Outputs of a batch, copied out of the runtime
+pub type BatchOutputs<TOut> = Vec<ArrayD<TOut>>;
+
+/// Iterator of pre-batched inputs to run through one or more sessions
+///
+/// Each item is a batch: the inputs of one call to
+/// [`Session::run()`](../session/struct.Session.html#method.run).
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{dataset::Dataset, environment::Environment};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let mut sessions = (0..4)
+///     .map(|_| {
+///         environment
+///             .new_session_builder()?
+///             .with_number_threads(1)?
+///             .with_model_from_file("squeezenet.onnx")
+///     })
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let batches = (0..1000).map(|_| [ndarray::Array::<f32, _>::zeros((32, 3, 224, 224))]);
+/// Dataset::new(batches).run::<f32, _, _>(&mut sessions, |outputs| {
+///     for outputs in outputs {
+///         let outputs = outputs?;
+///         // Write `outputs[0]` somewhere
+///     }
+///     Ok::<_, onnxruntime::OrtError>(())
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Dataset<B> {
+    batches: B,
+    prefetch: usize,
+}
+
+impl<B> Dataset<B>
+where
+    B: Iterator,
+{
+    /// Dataset of `batches`, prefetching two batches ahead of the sessions
+    pub fn new<I>(batches: I) -> Dataset<B>
+    where
+        I: IntoIterator<IntoIter = B>,
+    {
+        Dataset {
+            batches: batches.into_iter(),
+            prefetch: 2,
+        }
+    }
+
+    /// Number of batches pulled ahead of the sessions, waiting for one to be free
+    ///
+    /// Bounds the memory held by batches read but not run yet; zero only pulls a batch once a
+    /// session asks for it.
+    pub fn with_prefetch(mut self, prefetch: usize) -> Dataset<B> {
+        self.prefetch = prefetch;
+        self
+    }
+
+    /// Run the batches through `sessions`, calling `f` with the iterator of their outputs
+    ///
+    /// Each session runs on a thread of its own, taking the next batch whenever it is done with
+    /// the previous one; the outputs are yielded in the order of the batches all the same. A
+    /// single session gives pipelining only. The sessions should usually be created with
+    /// [`with_number_threads()`](../session/struct.SessionBuilder.html#method.with_number_threads)
+    /// so that together they do not oversubscribe the machine's cores.
+    ///
+    /// A failed batch is yielded as an error, and the following batches still run. If `f`
+    /// returns before the end of the iterator, the remaining batches are not run (apart from
+    /// the ones already in flight).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sessions` is empty.
+    pub fn run<'a, TOut, F, R>(self, sessions: &mut [Session<'a>], f: F) -> R
+    where
+        B: Send,
+        B::Item: IntoIterator + Send,
+        <B::Item as IntoIterator>::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone + Send,
+        F: for<'r> FnOnce(BatchIterator<'r, TOut>) -> R,
+    {
+        assert!(!sessions.is_empty(), "No session to run the dataset on");
+
+        let (batch_sender, batch_receiver) = mpsc::sync_channel(self.prefetch);
+        // Shared by the sessions' threads, and dropped with the last of them so that the
+        // thread pulling the batches stops too
+        let batch_receiver = Arc::new(Mutex::new(batch_receiver));
+        let (output_sender, output_receiver) = mpsc::sync_channel(sessions.len());
+        let batches = self.batches;
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for batch in batches.enumerate() {
+                    if batch_sender.send(batch).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            for session in sessions.iter_mut() {
+                let batch_receiver = Arc::clone(&batch_receiver);
+                let output_sender = output_sender.clone();
+                scope.spawn(move || loop {
+                    let next = batch_receiver
+                        .lock()
+                        .expect("Failed to acquire lock: another thread panicked?")
+                        .recv();
+                    let (index, batch) = match next {
+                        Ok(next) => next,
+                        Err(_) => break,
+                    };
+                    if output_sender
+                        .send((index, run_batch(session, batch)))
+                        .is_err()
+                    {
+                        break;
+                    }
+                });
+            }
+            drop(batch_receiver);
+            drop(output_sender);
+
+            // The iterator is dropped when `f` returns, so that the sessions' threads stop
+            // before the scope waits for them
+            f(BatchIterator {
+                outputs: output_receiver,
+                next_index: 0,
+                pending: BTreeMap::new(),
+                scope: PhantomData,
+            })
+        })
+    }
+}
+
+fn run_batch<I, TOut>(session: &mut Session<'_>, batch: I) -> Result<BatchOutputs<TOut>>
+where
+    I: IntoIterator,
+    I::Item: IntoInput,
+    TOut: TypeToTensorElementDataType + Debug + Clone,
+{
+    let outputs: Vec<OrtOwnedTensor<TOut, _>> = session.run(batch)?;
+    Ok(outputs
+        .iter()
+        .map(|output| output.view().to_owned())
+        .collect())
+}
+
+/// Iterator of the outputs of a [`Dataset`](struct.Dataset.html)'s batches, in order
+///
+/// Only lives for the duration of [`Dataset::run()`](struct.Dataset.html#method.run).
+#[derive(Debug)]
+pub struct BatchIterator<'r, TOut> {
+    outputs: mpsc::Receiver<(usize, Result<BatchOutputs<TOut>>)>,
+    next_index: usize,
+    /// Outputs of batches done before the ones preceding them
+    pending: BTreeMap<usize, Result<BatchOutputs<TOut>>>,
+    scope: PhantomData<&'r ()>,
+}
+
+impl<'r, TOut> BatchIterator<'r, TOut> {
+    /// Index of the batch the next call to `next()` yields the outputs of
+    pub fn next_index(&self) -> usize {
+        self.next_index
+    }
+}
+
+impl<'r, TOut> Iterator for BatchIterator<'r, TOut> {
+    type Item = Result<BatchOutputs<TOut>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(outputs) = self.pending.remove(&self.next_index) {
+                self.next_index += 1;
+                return Some(outputs);
+            }
+            match self.outputs.recv() {
+                Ok((index, outputs)) => {
+                    self.pending.insert(index, outputs);
+                }
+                Err(_) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn batch_iterator_in_order() {
+        let (sender, receiver) = mpsc::sync_channel(4);
+        for index in [2, 0, 3, 1] {
+            sender
+                .send((index, Ok(vec![ArrayD::from_elem(vec![1], index as f32)])))
+                .unwrap();
+        }
+        drop(sender);
+
+        let outputs = BatchIterator {
+            outputs: receiver,
+            next_index: 0,
+            pending: BTreeMap::new(),
+            scope: PhantomData,
+        };
+        let firsts: Vec<f32> = outputs.map(|outputs| outputs.unwrap()[0][0]).collect();
+        assert_eq!(firsts, vec![0.0, 1.0, 2.0, 3.0]);
+    }
+}
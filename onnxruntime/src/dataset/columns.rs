@@ -0,0 +1,148 @@
+//! Module laying out a model's outputs as table columns, for the file sinks
+
+use ndarray::{ArrayD, Axis};
+
+use crate::{
+    error::{JobError, Result},
+    session::Output,
+};
+
+/// Values of an output for one row, that is one item of the batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ColumnShape {
+    /// A single value
+    Scalar,
+    /// A fixed number of values, known from the model's output metadata
+    Fixed(usize),
+    /// A number of values only known once the model ran
+    Dynamic,
+}
+
+/// Column holding one of the model's outputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Column {
+    pub(super) name: String,
+    pub(super) shape: ColumnShape,
+}
+
+/// One column per output, the first dimension of each output being the batch
+pub(super) fn columns(outputs: &[Output]) -> Vec<Column> {
+    outputs
+        .iter()
+        .map(|output| {
+            let row_dimensions = output.dimensions.iter().skip(1);
+            let shape = match row_dimensions
+                .map(|dimension| dimension.map(|dimension| dimension as usize))
+                .product::<Option<usize>>()
+            {
+                Some(1) => ColumnShape::Scalar,
+                Some(count) => ColumnShape::Fixed(count),
+                None => ColumnShape::Dynamic,
+            };
+            Column {
+                name: output.name.clone(),
+                shape,
+            }
+        })
+        .collect()
+}
+
+/// Number of rows of a batch's outputs, checking they fit the columns
+pub(super) fn batch_rows<TOut>(columns: &[Column], outputs: &[ArrayD<TOut>]) -> Result<usize> {
+    if outputs.len() != columns.len() {
+        return Err(JobError::UnexpectedOutputs(format!(
+            "expected {} outputs, got {}",
+            columns.len(),
+            outputs.len()
+        ))
+        .into());
+    }
+
+    let rows = outputs.first().map_or(0, output_rows);
+    for (column, output) in columns.iter().zip(outputs) {
+        if output_rows(output) != rows {
+            return Err(JobError::UnexpectedOutputs(format!(
+                "output {:?} has {} rows, expected {}",
+                column.name,
+                output_rows(output),
+                rows
+            ))
+            .into());
+        }
+        let row_len = output.len().checked_div(rows).unwrap_or(0);
+        let expected_len = match column.shape {
+            ColumnShape::Scalar => 1,
+            ColumnShape::Fixed(count) => count,
+            ColumnShape::Dynamic => row_len,
+        };
+        if rows > 0 && row_len != expected_len {
+            return Err(JobError::UnexpectedOutputs(format!(
+                "output {:?} has {} values per row, expected {}",
+                column.name, row_len, expected_len
+            ))
+            .into());
+        }
+    }
+    Ok(rows)
+}
+
+/// Values of `output` for row `row`
+pub(super) fn row_values<TOut>(output: &ArrayD<TOut>, row: usize) -> impl Iterator<Item = &TOut> {
+    let row = if output.ndim() == 0 {
+        output.view()
+    } else {
+        output.index_axis(Axis(0), row)
+    };
+    row.into_iter()
+}
+
+/// A scalar output is a single row
+fn output_rows<TOut>(output: &ArrayD<TOut>) -> usize {
+    output.shape().first().copied().unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TensorElementDataType;
+    use test_log::test;
+
+    #[test]
+    fn output_columns() {
+        let output = |name: &str, dimensions: Vec<Option<u32>>| Output {
+            name: name.to_owned(),
+            output_type: TensorElementDataType::Float,
+            dimensions,
+            dynamic_rank: false,
+        };
+        let columns = columns(&[
+            output("score", vec![None, Some(1)]),
+            output("embedding", vec![None, Some(4), Some(2)]),
+            output("tokens", vec![None, None]),
+        ]);
+        let shapes: Vec<_> = columns.iter().map(|column| column.shape).collect();
+        assert_eq!(
+            shapes,
+            vec![
+                ColumnShape::Scalar,
+                ColumnShape::Fixed(8),
+                ColumnShape::Dynamic
+            ]
+        );
+
+        let outputs = vec![
+            ArrayD::<f32>::zeros(vec![3, 1]),
+            ArrayD::<f32>::zeros(vec![3, 4, 2]),
+            ArrayD::<f32>::zeros(vec![3, 5]),
+        ];
+        assert_eq!(batch_rows(&columns, &outputs).unwrap(), 3);
+        assert_eq!(row_values(&outputs[1], 2).count(), 8);
+        assert!(batch_rows(&columns, &outputs[..2]).is_err());
+        let outputs = vec![
+            ArrayD::<f32>::zeros(vec![3, 1]),
+            ArrayD::<f32>::zeros(vec![3, 4, 3]),
+            ArrayD::<f32>::zeros(vec![3, 5]),
+        ];
+        assert!(batch_rows(&columns, &outputs).is_err());
+    }
+}
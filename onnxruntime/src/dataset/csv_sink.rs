@@ -0,0 +1,193 @@
+//! Module containing the CSV output sink
+
+use std::{
+    fmt::Display,
+    fs::{File, OpenOptions},
+    io::{Seek, SeekFrom},
+    path::Path,
+};
+
+use ndarray::ArrayD;
+
+use crate::{
+    error::{JobError, Result},
+    session::Output,
+};
+
+use super::{
+    columns::{self, Column, ColumnShape},
+    OutputSink,
+};
+
+/// Output sink writing one CSV row per item of each batch
+///
+/// The columns are derived from the model's output metadata (see
+/// [`Session::outputs`](../session/struct.Session.html#structfield.outputs)): an output with a
+/// single value per item is written to a column named after it, an output with a fixed number
+/// of values `n` to the columns `<name>_0` to `<name>_<n-1>`, and an output whose number of
+/// values is only known once the model ran to a single column, its values separated by spaces.
+///
+/// The sink's offset is the file's length in bytes.
+#[derive(Debug)]
+pub struct CsvSink {
+    writer: csv::Writer<File>,
+    header: Vec<String>,
+    columns: Vec<Column>,
+}
+
+impl CsvSink {
+    /// Sink writing to `path`, laid out for the model's `outputs`
+    ///
+    /// An existing file is kept as is, so that a [`Job`](struct.Job.html) can resume writing
+    /// to it; the job truncates it before starting afresh. The header is written to new (and
+    /// truncated) files.
+    pub fn create<P>(path: P, outputs: &[Output]) -> Result<CsvSink>
+    where
+        P: AsRef<Path>,
+    {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(JobError::IoError)?;
+        let columns = columns::columns(outputs);
+        let header = columns
+            .iter()
+            .flat_map(|column| match column.shape {
+                ColumnShape::Fixed(count) => (0..count)
+                    .map(|index| format!("{}_{}", column.name, index))
+                    .collect(),
+                ColumnShape::Scalar | ColumnShape::Dynamic => vec![column.name.clone()],
+            })
+            .collect();
+
+        let mut sink = CsvSink {
+            writer: csv::Writer::from_writer(file),
+            header,
+            columns,
+        };
+        let len = sink.file().metadata().map_err(JobError::IoError)?.len();
+        sink.truncate_to(len)?;
+        Ok(sink)
+    }
+
+    fn file(&self) -> &File {
+        self.writer.get_ref()
+    }
+
+    /// Drop what follows `offset`, writing the header again to an emptied file
+    fn truncate_to(&mut self, offset: u64) -> Result<()> {
+        self.writer.flush().map_err(JobError::IoError)?;
+        let mut file = self.file();
+        file.set_len(offset).map_err(JobError::IoError)?;
+        file.seek(SeekFrom::Start(offset))
+            .map_err(JobError::IoError)?;
+        if offset == 0 {
+            self.writer
+                .write_record(&self.header)
+                .map_err(|err| JobError::IoError(err.into()))?;
+        }
+        Ok(())
+    }
+}
+
+impl<TOut> OutputSink<TOut> for CsvSink
+where
+    TOut: Display,
+{
+    fn write(&mut self, outputs: &[ArrayD<TOut>]) -> Result<()> {
+        let rows = columns::batch_rows(&self.columns, outputs)?;
+        let mut record = Vec::with_capacity(self.header.len());
+        for row in 0..rows {
+            record.clear();
+            for (column, output) in self.columns.iter().zip(outputs) {
+                let values = columns::row_values(output, row).map(ToString::to_string);
+                match column.shape {
+                    ColumnShape::Scalar | ColumnShape::Fixed(_) => record.extend(values),
+                    ColumnShape::Dynamic => record.push(values.collect::<Vec<_>>().join(" ")),
+                }
+            }
+            self.writer
+                .write_record(&record)
+                .map_err(|err| JobError::IoError(err.into()))?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<u64> {
+        self.writer.flush().map_err(JobError::IoError)?;
+        let mut file = self.file();
+        file.sync_data().map_err(JobError::IoError)?;
+        Ok(file.stream_position().map_err(JobError::IoError)?)
+    }
+
+    fn truncate(&mut self, offset: u64) -> Result<()> {
+        self.writer.flush().map_err(JobError::IoError)?;
+        let len = self.file().metadata().map_err(JobError::IoError)?.len();
+        if offset > len {
+            return Err(JobError::SinkResume {
+                offset,
+                message: format!("file is only {} bytes long", len),
+            }
+            .into());
+        }
+        self.truncate_to(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TensorElementDataType;
+    use test_log::test;
+
+    #[test]
+    fn csv_sink() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("outputs.csv");
+        let outputs = [
+            Output {
+                name: "label".to_owned(),
+                output_type: TensorElementDataType::Int64,
+                dimensions: vec![None],
+                dynamic_rank: false,
+            },
+            Output {
+                name: "box".to_owned(),
+                output_type: TensorElementDataType::Int64,
+                dimensions: vec![None, Some(2)],
+                dynamic_rank: false,
+            },
+        ];
+
+        let mut sink = CsvSink::create(&path, &outputs).unwrap();
+        let batch = |first: i64| {
+            vec![
+                ArrayD::from_shape_vec(vec![2], vec![first, first + 1]).unwrap(),
+                ArrayD::from_shape_vec(vec![2, 2], vec![1, 2, 3, 4]).unwrap(),
+            ]
+        };
+        sink.write(&batch(0)).unwrap();
+        let offset = OutputSink::<i64>::flush(&mut sink).unwrap();
+        sink.write(&batch(2)).unwrap();
+        OutputSink::<i64>::flush(&mut sink).unwrap();
+        drop(sink);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "label,box_0,box_1\n0,1,2\n1,3,4\n2,1,2\n3,3,4\n"
+        );
+
+        // Resuming after the first batch
+        let mut sink = CsvSink::create(&path, &outputs).unwrap();
+        OutputSink::<i64>::truncate(&mut sink, offset).unwrap();
+        sink.write(&batch(4)).unwrap();
+        OutputSink::<i64>::flush(&mut sink).unwrap();
+        drop(sink);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "label,box_0,box_1\n0,1,2\n1,3,4\n4,1,2\n5,3,4\n"
+        );
+    }
+}
@@ -0,0 +1,381 @@
+//! Module containing bulk inference jobs resuming from checkpoints
+
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use ndarray::ArrayD;
+use tracing::info;
+
+use crate::{
+    error::{JobError, Result},
+    session::Session,
+    tensor::IntoInput,
+    TypeToTensorElementDataType,
+};
+
+use super::{BatchOutputs, Dataset};
+
+/// Destination of a job's outputs, written batch after batch
+///
+/// Sinks are append-only, with an offset (for example a number of bytes or rows) recording
+/// how much of them is durable: [`Job`](struct.Job.html) stores the offset returned by
+/// [`flush()`](#tymethod.flush) in its checkpoints, and hands it back to
+/// [`truncate()`](#tymethod.truncate) when resuming, to drop what was written after it.
+pub trait OutputSink<TOut> {
+    /// Append the outputs of the next batch
+    fn write(&mut self, outputs: &[ArrayD<TOut>]) -> Result<()>;
+
+    /// Make the outputs written so far durable, returning the offset they end at
+    fn flush(&mut self) -> Result<u64>;
+
+    /// Drop the outputs written after `offset`, before resuming from a checkpoint
+    ///
+    /// Also called with an offset of zero before starting afresh, to drop all outputs.
+    fn truncate(&mut self, offset: u64) -> Result<()>;
+}
+
+/// Sink keeping the outputs in memory, mostly for testing; the offset is the number of batches
+impl<TOut> OutputSink<TOut> for Vec<BatchOutputs<TOut>>
+where
+    TOut: Clone,
+{
+    fn write(&mut self, outputs: &[ArrayD<TOut>]) -> Result<()> {
+        self.push(outputs.to_vec());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<u64> {
+        Ok(self.len() as u64)
+    }
+
+    fn truncate(&mut self, offset: u64) -> Result<()> {
+        if offset > self.len() as u64 {
+            return Err(JobError::SinkResume {
+                offset,
+                message: format!("only {} batches were written", self.len()),
+            }
+            .into());
+        }
+        Vec::truncate(self, offset as usize);
+        Ok(())
+    }
+}
+
+/// Progress of a job, saved to its checkpoint file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Index of the first batch not written to the sink yet
+    pub next_batch: u64,
+    /// Offset of the sink after the last batch written
+    pub sink_offset: u64,
+}
+
+impl Checkpoint {
+    /// Read the checkpoint saved to `path`, if any
+    pub fn load<P>(path: P) -> Result<Option<Checkpoint>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(JobError::IoError(err).into()),
+        };
+        let invalid = |message: String| JobError::InvalidCheckpoint {
+            path: path.to_owned(),
+            message,
+        };
+
+        let mut next_batch = None;
+        let mut sink_offset = None;
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected `key=value`, got {:?}", line)))?;
+            let value = value
+                .trim()
+                .parse::<u64>()
+                .map_err(|err| invalid(format!("invalid value for {:?}: {}", key, err)))?;
+            match key.trim() {
+                "next_batch" => next_batch = Some(value),
+                "sink_offset" => sink_offset = Some(value),
+                key => return Err(invalid(format!("unknown key {:?}", key)).into()),
+            }
+        }
+
+        match (next_batch, sink_offset) {
+            (Some(next_batch), Some(sink_offset)) => Ok(Some(Checkpoint {
+                next_batch,
+                sink_offset,
+            })),
+            _ => Err(invalid("missing `next_batch` or `sink_offset`".to_owned()).into()),
+        }
+    }
+
+    /// Save the checkpoint to `path`
+    ///
+    /// The checkpoint is written next to `path` then renamed over it, so that a crash leaves
+    /// either the previous checkpoint or this one.
+    pub fn save<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let mut part_path = path.as_os_str().to_owned();
+        part_path.push(".part");
+        fs::write(
+            &part_path,
+            format!(
+                "next_batch={}\nsink_offset={}\n",
+                self.next_batch, self.sink_offset
+            ),
+        )
+        .map_err(JobError::IoError)?;
+        fs::rename(&part_path, path).map_err(JobError::IoError)?;
+        Ok(())
+    }
+}
+
+/// Progress of a run of a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobMetrics {
+    /// Index of the batch the run resumed from, zero when starting afresh
+    pub resumed_from: u64,
+    /// Number of batches run and written by this run
+    pub batches: u64,
+    /// Time spent by this run
+    pub elapsed: Duration,
+}
+
+impl JobMetrics {
+    /// Batches run per second by this run
+    pub fn batches_per_second(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            secs if secs > 0.0 => self.batches as f64 / secs,
+            _ => 0.0,
+        }
+    }
+
+    /// Emit the metrics as a `tracing` event, at the `INFO` level
+    pub fn emit(&self) {
+        info!(
+            resumed_from = self.resumed_from,
+            batches = self.batches,
+            elapsed_ms = self.elapsed.as_millis() as u64,
+            batches_per_second = self.batches_per_second(),
+            "Bulk inference job progress."
+        );
+    }
+}
+
+/// Bulk inference job, resuming after a crash from where it left off
+///
+/// Runs a [`Dataset`](struct.Dataset.html), writing the outputs to an
+/// [`OutputSink`](trait.OutputSink.html) and saving a [`Checkpoint`](struct.Checkpoint.html)
+/// every few batches. Running the job again with the same dataset reads the checkpoint, drops
+/// the outputs written after it, skips the batches already done and carries on. Without a
+/// checkpoint, the job starts afresh and drops anything already in the sink.
+///
+/// Skipped batches are still pulled from the dataset's iterator, though not run; datasets whose
+/// batches are expensive to produce can start from
+/// [`Job::checkpoint()`](#method.checkpoint)'s `next_batch` themselves instead, with
+/// [`Job::with_skip_done(false)`](#method.with_skip_done).
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{dataset::{Dataset, Job}, environment::Environment};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let mut session = environment
+///     .new_session_builder()?
+///     .with_model_from_file("squeezenet.onnx")?;
+/// let batches = (0..1000).map(|_| [ndarray::Array::<f32, _>::zeros((32, 3, 224, 224))]);
+///
+/// let mut outputs = Vec::new();
+/// let metrics = Job::new("scoring.checkpoint").run::<_, f32, _>(
+///     Dataset::new(batches),
+///     std::slice::from_mut(&mut session),
+///     &mut outputs,
+/// )?;
+/// metrics.emit();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Job {
+    checkpoint_path: PathBuf,
+    checkpoint_every: u64,
+    skip_done: bool,
+}
+
+impl Job {
+    /// Job saving its progress to `checkpoint_path`, every 100 batches
+    pub fn new<P>(checkpoint_path: P) -> Job
+    where
+        P: Into<PathBuf>,
+    {
+        Job {
+            checkpoint_path: checkpoint_path.into(),
+            checkpoint_every: 100,
+            skip_done: true,
+        }
+    }
+
+    /// Save a checkpoint every `batches` batches (at least one)
+    ///
+    /// Each checkpoint flushes the sink; more frequent checkpoints redo less work after a crash.
+    pub fn with_checkpoint_every(mut self, batches: u64) -> Job {
+        self.checkpoint_every = batches.max(1);
+        self
+    }
+
+    /// Whether to skip the batches done by previous runs when resuming (the default)
+    ///
+    /// Disable when the dataset given to [`run()`](#method.run) already starts at the
+    /// checkpoint's `next_batch`.
+    pub fn with_skip_done(mut self, skip_done: bool) -> Job {
+        self.skip_done = skip_done;
+        self
+    }
+
+    /// Checkpoint saved by a previous run, if any
+    pub fn checkpoint(&self) -> Result<Option<Checkpoint>> {
+        Checkpoint::load(&self.checkpoint_path)
+    }
+
+    /// Run the dataset's batches through `sessions` (see
+    /// [`Dataset::run()`](struct.Dataset.html#method.run)), writing the outputs to `sink`
+    ///
+    /// The job stops at the first failed batch, with its progress saved up to that batch: running
+    /// it again retries from there. A checkpoint is also saved when the dataset is exhausted, so
+    /// running a completed job again does nothing.
+    pub fn run<B, TOut, S>(
+        &self,
+        dataset: Dataset<B>,
+        sessions: &mut [Session<'_>],
+        sink: &mut S,
+    ) -> Result<JobMetrics>
+    where
+        B: Iterator + Send,
+        B::Item: IntoIterator + Send,
+        <B::Item as IntoIterator>::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone + Send,
+        S: OutputSink<TOut>,
+    {
+        let start = Instant::now();
+        let resume = self.checkpoint()?.unwrap_or_default();
+        sink.truncate(resume.sink_offset)?;
+        if resume != Checkpoint::default() {
+            info!(
+                next_batch = resume.next_batch,
+                sink_offset = resume.sink_offset,
+                "Resuming bulk inference job."
+            );
+        }
+
+        let skip = if self.skip_done {
+            resume.next_batch as usize
+        } else {
+            0
+        };
+        let dataset = Dataset {
+            batches: dataset.batches.skip(skip),
+            prefetch: dataset.prefetch,
+        };
+
+        let mut metrics = JobMetrics {
+            resumed_from: resume.next_batch,
+            batches: 0,
+            elapsed: Duration::default(),
+        };
+        dataset.run::<TOut, _, _>(sessions, |outputs| {
+            let mut since_checkpoint = 0;
+            for outputs in outputs {
+                let outputs = match outputs {
+                    Ok(outputs) => outputs,
+                    Err(err) => {
+                        self.save_progress(sink, &mut metrics, start)?;
+                        return Err(err);
+                    }
+                };
+                sink.write(&outputs)?;
+                metrics.batches += 1;
+                since_checkpoint += 1;
+                if since_checkpoint == self.checkpoint_every {
+                    since_checkpoint = 0;
+                    self.save_progress(sink, &mut metrics, start)?;
+                }
+            }
+            self.save_progress(sink, &mut metrics, start)
+        })?;
+
+        Ok(metrics)
+    }
+
+    /// Flush the sink and save a checkpoint after the batches counted by `metrics`
+    fn save_progress<TOut, S>(
+        &self,
+        sink: &mut S,
+        metrics: &mut JobMetrics,
+        start: Instant,
+    ) -> Result<()>
+    where
+        S: OutputSink<TOut>,
+    {
+        let checkpoint = Checkpoint {
+            next_batch: metrics.resumed_from + metrics.batches,
+            sink_offset: sink.flush()?,
+        };
+        checkpoint.save(&self.checkpoint_path)?;
+        metrics.elapsed = start.elapsed();
+        metrics.emit();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OrtError;
+    use test_log::test;
+
+    #[test]
+    fn checkpoint_save_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("job.checkpoint");
+        assert_eq!(Checkpoint::load(&path).unwrap(), None);
+
+        let checkpoint = Checkpoint {
+            next_batch: 12,
+            sink_offset: 3456,
+        };
+        checkpoint.save(&path).unwrap();
+        assert_eq!(Checkpoint::load(&path).unwrap(), Some(checkpoint));
+
+        fs::write(&path, "next_batch=12\n").unwrap();
+        assert!(matches!(
+            Checkpoint::load(&path),
+            Err(OrtError::Job(JobError::InvalidCheckpoint { .. }))
+        ));
+    }
+
+    #[test]
+    fn vec_sink_truncate() {
+        let mut sink: Vec<BatchOutputs<f32>> = Vec::new();
+        for value in 0..3 {
+            sink.write(&[ArrayD::from_elem(vec![2], value as f32)])
+                .unwrap();
+        }
+        assert_eq!(sink.flush().unwrap(), 3);
+        OutputSink::<f32>::truncate(&mut sink, 1).unwrap();
+        assert_eq!(sink.len(), 1);
+        assert!(OutputSink::<f32>::truncate(&mut sink, 2).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+//! Module containing the Parquet output sink
+
+use std::{
+    fmt,
+    fs::{self, File},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use arrow_array::{
+    types::{
+        ArrowPrimitiveType, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+        UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+    ArrayRef, FixedSizeListArray, ListArray, PrimitiveArray, RecordBatch,
+};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaRef};
+use ndarray::ArrayD;
+use parquet::arrow::ArrowWriter;
+
+use crate::{
+    error::{JobError, Result},
+    session::Output,
+};
+
+use super::{
+    columns::{self, Column, ColumnShape},
+    OutputSink,
+};
+
+/// Element type of the outputs a [`ParquetSink`](struct.ParquetSink.html) can write
+pub trait ArrowElement: Copy {
+    /// Arrow type of the values
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+macro_rules! impl_arrow_element {
+    ($type_:ty, $arrow_type:ty) => {
+        impl ArrowElement for $type_ {
+            type ArrowType = $arrow_type;
+        }
+    };
+}
+
+impl_arrow_element!(f32, Float32Type);
+impl_arrow_element!(f64, Float64Type);
+impl_arrow_element!(i8, Int8Type);
+impl_arrow_element!(i16, Int16Type);
+impl_arrow_element!(i32, Int32Type);
+impl_arrow_element!(i64, Int64Type);
+impl_arrow_element!(u8, UInt8Type);
+impl_arrow_element!(u16, UInt16Type);
+impl_arrow_element!(u32, UInt32Type);
+impl_arrow_element!(u64, UInt64Type);
+
+/// Output sink writing the items of each batch as rows of Parquet files
+///
+/// The schema is derived from the model's output metadata (see
+/// [`Session::outputs`](../session/struct.Session.html#structfield.outputs)), with a column
+/// per output: a primitive column for outputs with a single value per item, a fixed size list
+/// column for outputs with a fixed number of values, and a list column for outputs whose number
+/// of values is only known once the model ran.
+///
+/// Parquet files cannot be appended to once closed, so the sink writes a directory of files,
+/// `part-00000.parquet`, `part-00001.parquet` and so on: each [`flush()`](#method.flush) closes
+/// the current file. The sink's offset is the number of closed files.
+pub struct ParquetSink<TOut> {
+    dir: PathBuf,
+    columns: Vec<Column>,
+    schema: SchemaRef,
+    writer: Option<ArrowWriter<File>>,
+    parts: u64,
+    element: PhantomData<TOut>,
+}
+
+impl<TOut> ParquetSink<TOut>
+where
+    TOut: ArrowElement,
+{
+    /// Sink writing to the directory `dir`, laid out for the model's `outputs`
+    ///
+    /// The directory is created if needed. Existing files are kept, so that a
+    /// [`Job`](struct.Job.html) can resume writing to it; the job removes them before starting
+    /// afresh.
+    pub fn create<P>(dir: P, outputs: &[Output]) -> Result<ParquetSink<TOut>>
+    where
+        P: Into<PathBuf>,
+    {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(JobError::IoError)?;
+        let parts = part_indices(&dir)?.len() as u64;
+
+        let columns = columns::columns(outputs);
+        let fields: Vec<Field> = columns
+            .iter()
+            .map(|column| {
+                let data_type = match column.shape {
+                    ColumnShape::Scalar => TOut::ArrowType::DATA_TYPE,
+                    ColumnShape::Fixed(count) => {
+                        DataType::FixedSizeList(item_field::<TOut>(), count as i32)
+                    }
+                    ColumnShape::Dynamic => DataType::List(item_field::<TOut>()),
+                };
+                Field::new(column.name.clone(), data_type, false)
+            })
+            .collect();
+
+        Ok(ParquetSink {
+            dir,
+            columns,
+            schema: Arc::new(Schema::new(fields)),
+            writer: None,
+            parts,
+            element: PhantomData,
+        })
+    }
+
+    /// Arrow schema of the files
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn array(&self, column: &Column, output: &ArrayD<TOut>, rows: usize) -> Result<ArrayRef> {
+        let values: PrimitiveArray<TOut::ArrowType> = PrimitiveArray::from_iter_values(
+            (0..rows).flat_map(|row| columns::row_values(output, row).copied()),
+        );
+        let array: ArrayRef = match column.shape {
+            ColumnShape::Scalar => Arc::new(values),
+            ColumnShape::Fixed(count) => Arc::new(
+                FixedSizeListArray::try_new(
+                    item_field::<TOut>(),
+                    count as i32,
+                    Arc::new(values),
+                    None,
+                )
+                .map_err(parquet_error)?,
+            ),
+            ColumnShape::Dynamic => {
+                let row_len = output.len().checked_div(rows).unwrap_or(0);
+                Arc::new(
+                    ListArray::try_new(
+                        item_field::<TOut>(),
+                        OffsetBuffer::from_lengths(vec![row_len; rows]),
+                        Arc::new(values),
+                        None,
+                    )
+                    .map_err(parquet_error)?,
+                )
+            }
+        };
+        Ok(array)
+    }
+}
+
+impl<TOut> OutputSink<TOut> for ParquetSink<TOut>
+where
+    TOut: ArrowElement,
+{
+    fn write(&mut self, outputs: &[ArrayD<TOut>]) -> Result<()> {
+        let rows = columns::batch_rows(&self.columns, outputs)?;
+        let arrays = self
+            .columns
+            .iter()
+            .zip(outputs)
+            .map(|(column, output)| self.array(column, output, rows))
+            .collect::<Result<Vec<_>>>()?;
+        let batch = RecordBatch::try_new(self.schema(), arrays).map_err(parquet_error)?;
+
+        let writer = match self.writer.take() {
+            Some(writer) => writer,
+            None => {
+                let file =
+                    File::create(part_path(&self.dir, self.parts)).map_err(JobError::IoError)?;
+                ArrowWriter::try_new(file, self.schema(), None).map_err(JobError::Parquet)?
+            }
+        };
+        let writer = self.writer.insert(writer);
+        writer.write(&batch).map_err(JobError::Parquet)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<u64> {
+        if let Some(writer) = self.writer.take() {
+            let file = writer.into_inner().map_err(JobError::Parquet)?;
+            file.sync_all().map_err(JobError::IoError)?;
+            self.parts += 1;
+        }
+        Ok(self.parts)
+    }
+
+    fn truncate(&mut self, offset: u64) -> Result<()> {
+        // The current file is not closed, so its rows are dropped with it
+        self.writer = None;
+        let indices = part_indices(&self.dir)?;
+        if (0..offset).any(|index| !indices.contains(&index)) {
+            return Err(JobError::SinkResume {
+                offset,
+                message: format!("missing files in {:?}", self.dir),
+            }
+            .into());
+        }
+        for index in indices.into_iter().filter(|index| *index >= offset) {
+            fs::remove_file(part_path(&self.dir, index)).map_err(JobError::IoError)?;
+        }
+        self.parts = offset;
+        Ok(())
+    }
+}
+
+impl<TOut> fmt::Debug for ParquetSink<TOut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParquetSink")
+            .field("dir", &self.dir)
+            .field("schema", &self.schema)
+            .field("parts", &self.parts)
+            .finish()
+    }
+}
+
+fn item_field<TOut>() -> FieldRef
+where
+    TOut: ArrowElement,
+{
+    Arc::new(Field::new("item", TOut::ArrowType::DATA_TYPE, false))
+}
+
+fn parquet_error(err: ArrowError) -> JobError {
+    JobError::Parquet(err.into())
+}
+
+fn part_path(dir: &Path, index: u64) -> PathBuf {
+    dir.join(format!("part-{:05}.parquet", index))
+}
+
+/// Indices of the files already in `dir`
+fn part_indices(dir: &Path) -> Result<Vec<u64>> {
+    let mut indices = Vec::new();
+    for entry in fs::read_dir(dir).map_err(JobError::IoError)? {
+        let entry = entry.map_err(JobError::IoError)?;
+        let name = entry.file_name();
+        let index = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("part-"))
+            .and_then(|name| name.strip_suffix(".parquet"))
+            .and_then(|index| index.parse().ok());
+        if let Some(index) = index {
+            indices.push(index);
+        }
+    }
+    indices.sort_unstable();
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TensorElementDataType;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use test_log::test;
+
+    #[test]
+    fn parquet_sink() {
+        let dir = tempfile::tempdir().unwrap();
+        let outputs = [
+            Output {
+                name: "score".to_owned(),
+                output_type: TensorElementDataType::Float,
+                dimensions: vec![None, Some(1)],
+                dynamic_rank: false,
+            },
+            Output {
+                name: "embedding".to_owned(),
+                output_type: TensorElementDataType::Float,
+                dimensions: vec![None, Some(3)],
+                dynamic_rank: false,
+            },
+            Output {
+                name: "tokens".to_owned(),
+                output_type: TensorElementDataType::Float,
+                dimensions: vec![None, None],
+                dynamic_rank: false,
+            },
+        ];
+        let batch = |rows: usize| {
+            vec![
+                ArrayD::<f32>::zeros(vec![rows, 1]),
+                ArrayD::<f32>::ones(vec![rows, 3]),
+                ArrayD::<f32>::zeros(vec![rows, 5]),
+            ]
+        };
+
+        let mut sink = ParquetSink::<f32>::create(dir.path(), &outputs).unwrap();
+        sink.write(&batch(2)).unwrap();
+        sink.write(&batch(3)).unwrap();
+        assert_eq!(sink.flush().unwrap(), 1);
+        sink.write(&batch(4)).unwrap();
+        assert_eq!(sink.flush().unwrap(), 2);
+        sink.write(&batch(1)).unwrap();
+        drop(sink);
+
+        let mut sink = ParquetSink::<f32>::create(dir.path(), &outputs).unwrap();
+        sink.truncate(1).unwrap();
+        assert_eq!(part_indices(dir.path()).unwrap(), vec![0]);
+        assert!(sink.truncate(2).is_err());
+
+        let file = File::open(part_path(dir.path(), 0)).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 5);
+        assert_eq!(batches[0].schema(), sink.schema());
+    }
+}
@@ -0,0 +1,441 @@
+//! Module containing the post-processing of object-detection models
+//!
+//! Detection heads output many candidate boxes per image, encoded in a format of their own. The
+//! decoders of this module turn the outputs of the common ones into
+//! [`Detection`](struct.Detection.html)s, which [`Nms`](struct.Nms.html) (non-max suppression)
+//! then reduces to one per object.
+//!
+//! ```no_run
+//! # use std::error::Error;
+//! # use onnxruntime::{
+//! #     detection::{decode_yolo, Nms, YoloFormat},
+//! #     environment::Environment,
+//! #     tensor::OrtOwnedTensor,
+//! # };
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # let environment = Environment::builder().build()?;
+//! # let session = environment.new_session_builder()?.with_model_from_file("yolov8n.onnx")?;
+//! # let image = ndarray::Array::<f32, _>::zeros((1, 3, 640, 640));
+//! let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run(vec![image])?;
+//! let candidates = decode_yolo(outputs[0].view(), YoloFormat::V8, 0.25)?;
+//! let detections = Nms::new(0.45).with_max_detections(100).apply(candidates);
+//! for detection in detections {
+//!     println!("{} {:.2} {:?}", detection.class, detection.score, detection.bbox);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use ndarray::{ArrayView2, ArrayViewD, Axis, Ix3};
+
+use crate::error::{OrtError, Result};
+
+/// Axis-aligned box, in the coordinates of the model's input (pixels or normalized)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    /// Left edge
+    pub x_min: f32,
+    /// Top edge
+    pub y_min: f32,
+    /// Right edge
+    pub x_max: f32,
+    /// Bottom edge
+    pub y_max: f32,
+}
+
+impl BoundingBox {
+    /// Box of the given center and size
+    pub fn from_center(center_x: f32, center_y: f32, width: f32, height: f32) -> BoundingBox {
+        BoundingBox {
+            x_min: center_x - width / 2.0,
+            y_min: center_y - height / 2.0,
+            x_max: center_x + width / 2.0,
+            y_max: center_y + height / 2.0,
+        }
+    }
+
+    /// Area of the box, 0 for degenerate boxes
+    pub fn area(&self) -> f32 {
+        (self.x_max - self.x_min).max(0.0) * (self.y_max - self.y_min).max(0.0)
+    }
+
+    /// Intersection over union of the two boxes, between 0 (disjoint) and 1 (identical)
+    pub fn iou(&self, other: &BoundingBox) -> f32 {
+        let intersection = BoundingBox {
+            x_min: self.x_min.max(other.x_min),
+            y_min: self.y_min.max(other.y_min),
+            x_max: self.x_max.min(other.x_max),
+            y_max: self.y_max.min(other.y_max),
+        }
+        .area();
+        let union = self.area() + other.area() - intersection;
+        if union > 0.0 {
+            intersection / union
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Object detected in an image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detection {
+    /// Box around the object
+    pub bbox: BoundingBox,
+    /// Confidence of the detection, usually between 0 and 1
+    pub score: f32,
+    /// Index of the object's class
+    pub class: usize,
+}
+
+/// Layout of the output of a YOLO detection head
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YoloFormat {
+    /// `[batch, boxes, 5 + classes]`: center x, center y, width, height, objectness, then the
+    /// score of each class (YOLOv5, YOLOv7)
+    V5,
+    /// `[batch, 4 + classes, boxes]`: center x, center y, width, height, then the score of each
+    /// class, without objectness (YOLOv8 and later)
+    V8,
+}
+
+/// Candidate detections of the first image of the output of a YOLO detection head
+///
+/// Each box is given its best scoring class; boxes scoring below `score_threshold` are left out.
+/// The boxes are in the coordinates of the model's input, before any letterboxing is undone.
+pub fn decode_yolo(
+    output: ArrayViewD<'_, f32>,
+    format: YoloFormat,
+    score_threshold: f32,
+) -> Result<Vec<Detection>> {
+    let output = first_image(output, "YOLO")?;
+    // One row per box
+    let (rows, header) = match format {
+        YoloFormat::V5 => (output, 5),
+        YoloFormat::V8 => (output.reversed_axes(), 4),
+    };
+    if rows.len_of(Axis(1)) <= header {
+        return Err(unexpected_shape("YOLO", rows.shape()));
+    }
+
+    let detections = rows
+        .outer_iter()
+        .filter_map(|row| {
+            let objectness = match format {
+                YoloFormat::V5 => row[4],
+                YoloFormat::V8 => 1.0,
+            };
+            let (class, class_score) = best_class(row.iter().skip(header).copied())?;
+            let score = objectness * class_score;
+            if score < score_threshold {
+                return None;
+            }
+            Some(Detection {
+                bbox: BoundingBox::from_center(row[0], row[1], row[2], row[3]),
+                score,
+                class,
+            })
+        })
+        .collect();
+    Ok(detections)
+}
+
+/// Prior box of an SSD detection head, in normalized coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    /// Center x
+    pub center_x: f32,
+    /// Center y
+    pub center_y: f32,
+    /// Width
+    pub width: f32,
+    /// Height
+    pub height: f32,
+}
+
+/// Decoder of the outputs of SSD detection heads, predicting offsets from anchors
+///
+/// The heads output, per anchor, the offsets `(dx, dy, dw, dh)` of the box and the score of each
+/// class. Boxes are decoded as `center = anchor.center + d * center_variance * anchor.size` and
+/// `size = anchor.size * exp(d * size_variance)`, the variances defaulting to 0.1 and 0.2.
+#[derive(Debug, Clone)]
+pub struct SsdDecoder {
+    anchors: Vec<Anchor>,
+    center_variance: f32,
+    size_variance: f32,
+    background_class: Option<usize>,
+}
+
+impl SsdDecoder {
+    /// Decoder of the heads predicting offsets from `anchors`, class 0 being the background
+    pub fn new(anchors: Vec<Anchor>) -> SsdDecoder {
+        SsdDecoder {
+            anchors,
+            center_variance: 0.1,
+            size_variance: 0.2,
+            background_class: Some(0),
+        }
+    }
+
+    /// Scale the predicted offsets of the centers and sizes by the given variances
+    pub fn with_variances(mut self, center_variance: f32, size_variance: f32) -> SsdDecoder {
+        self.center_variance = center_variance;
+        self.size_variance = size_variance;
+        self
+    }
+
+    /// Class standing for the background, never detected (0 by default, `None` for heads without
+    /// one)
+    pub fn with_background_class(mut self, background_class: Option<usize>) -> SsdDecoder {
+        self.background_class = background_class;
+        self
+    }
+
+    /// Candidate detections of the first image of the outputs `offsets` (`[batch, anchors, 4]`)
+    /// and `scores` (`[batch, anchors, classes]`)
+    ///
+    /// Each box is given its best scoring class; boxes scoring below `score_threshold` are left
+    /// out.
+    pub fn decode(
+        &self,
+        offsets: ArrayViewD<'_, f32>,
+        scores: ArrayViewD<'_, f32>,
+        score_threshold: f32,
+    ) -> Result<Vec<Detection>> {
+        let offsets = first_image(offsets, "SSD offsets")?;
+        let scores = first_image(scores, "SSD scores")?;
+        if offsets.shape() != [self.anchors.len(), 4] {
+            return Err(unexpected_shape("SSD offsets", offsets.shape()));
+        }
+        if scores.len_of(Axis(0)) != self.anchors.len() {
+            return Err(unexpected_shape("SSD scores", scores.shape()));
+        }
+
+        let detections = self
+            .anchors
+            .iter()
+            .zip(offsets.outer_iter())
+            .zip(scores.outer_iter())
+            .filter_map(|((anchor, offsets), scores)| {
+                let classes = scores
+                    .iter()
+                    .enumerate()
+                    .filter(|(class, _)| Some(*class) != self.background_class)
+                    .map(|(class, &score)| (class, score));
+                let (class, score) = classes.max_by(|a, b| a.1.total_cmp(&b.1))?;
+                if score < score_threshold {
+                    return None;
+                }
+                let bbox = BoundingBox::from_center(
+                    anchor.center_x + offsets[0] * self.center_variance * anchor.width,
+                    anchor.center_y + offsets[1] * self.center_variance * anchor.height,
+                    anchor.width * (offsets[2] * self.size_variance).exp(),
+                    anchor.height * (offsets[3] * self.size_variance).exp(),
+                );
+                Some(Detection { bbox, score, class })
+            })
+            .collect();
+        Ok(detections)
+    }
+}
+
+/// Non-max suppression, keeping the best scoring of overlapping detections
+///
+/// Detections are kept by decreasing score, unless they overlap a detection already kept by more
+/// than the IoU threshold. Only detections of the same class suppress each other, unless
+/// suppression is [class agnostic](#method.with_class_agnostic).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Nms {
+    iou_threshold: f32,
+    score_threshold: f32,
+    max_detections: Option<usize>,
+    class_agnostic: bool,
+}
+
+impl Nms {
+    /// Suppression of the detections overlapping a better one by more than `iou_threshold`
+    pub fn new(iou_threshold: f32) -> Nms {
+        Nms {
+            iou_threshold,
+            score_threshold: f32::NEG_INFINITY,
+            max_detections: None,
+            class_agnostic: false,
+        }
+    }
+
+    /// Leave out the detections scoring below `score_threshold`
+    pub fn with_score_threshold(mut self, score_threshold: f32) -> Nms {
+        self.score_threshold = score_threshold;
+        self
+    }
+
+    /// Keep at most the `max_detections` best scoring detections
+    pub fn with_max_detections(mut self, max_detections: usize) -> Nms {
+        self.max_detections = Some(max_detections);
+        self
+    }
+
+    /// Whether detections of different classes suppress each other
+    pub fn with_class_agnostic(mut self, class_agnostic: bool) -> Nms {
+        self.class_agnostic = class_agnostic;
+        self
+    }
+
+    /// Detections kept out of `detections`, by decreasing score
+    pub fn apply(&self, mut detections: Vec<Detection>) -> Vec<Detection> {
+        detections.retain(|detection| detection.score >= self.score_threshold);
+        detections.sort_by(|a, b| b.score.total_cmp(&a.score));
+        let max_detections = self.max_detections.unwrap_or(usize::MAX);
+
+        let mut kept: Vec<Detection> = Vec::new();
+        for detection in detections {
+            if kept.len() >= max_detections {
+                break;
+            }
+            let suppressed = kept.iter().any(|other| {
+                (self.class_agnostic || other.class == detection.class)
+                    && other.bbox.iou(&detection.bbox) > self.iou_threshold
+            });
+            if !suppressed {
+                kept.push(detection);
+            }
+        }
+        kept
+    }
+}
+
+/// Output of the first image of a batch, of shape `[batch, rows, columns]`
+fn first_image<'a>(output: ArrayViewD<'a, f32>, name: &str) -> Result<ArrayView2<'a, f32>> {
+    let shape = output.shape().to_vec();
+    match output.into_dimensionality::<Ix3>() {
+        Ok(output) if output.len_of(Axis(0)) > 0 => Ok(output.index_axis_move(Axis(0), 0)),
+        _ => Err(unexpected_shape(name, &shape)),
+    }
+}
+
+/// Index and score of the best scoring class
+fn best_class(scores: impl Iterator<Item = f32>) -> Option<(usize, f32)> {
+    scores.enumerate().max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+fn unexpected_shape(name: &str, shape: &[usize]) -> OrtError {
+    OrtError::Postprocessing(format!("{} output has unexpected shape {:?}", name, shape).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn detection(x_min: f32, y_min: f32, size: f32, score: f32, class: usize) -> Detection {
+        Detection {
+            bbox: BoundingBox {
+                x_min,
+                y_min,
+                x_max: x_min + size,
+                y_max: y_min + size,
+            },
+            score,
+            class,
+        }
+    }
+
+    #[test]
+    fn iou_of_boxes() {
+        let a = detection(0.0, 0.0, 2.0, 1.0, 0).bbox;
+        let b = detection(1.0, 0.0, 2.0, 1.0, 0).bbox;
+        assert_eq!(a.iou(&a), 1.0);
+        assert_eq!(a.iou(&b), 2.0 / 6.0);
+        assert_eq!(a.iou(&detection(5.0, 5.0, 1.0, 1.0, 0).bbox), 0.0);
+        assert_eq!(
+            BoundingBox::from_center(1.0, 1.0, 2.0, 2.0),
+            detection(0.0, 0.0, 2.0, 1.0, 0).bbox
+        );
+    }
+
+    #[test]
+    fn nms_keeps_best_of_overlapping_detections() {
+        let detections = vec![
+            detection(0.0, 0.0, 10.0, 0.8, 0),
+            detection(1.0, 1.0, 10.0, 0.9, 0),
+            // Same place, other class
+            detection(1.0, 1.0, 10.0, 0.7, 1),
+            detection(50.0, 50.0, 10.0, 0.6, 0),
+            detection(80.0, 80.0, 10.0, 0.1, 0),
+        ];
+        let nms = Nms::new(0.5).with_score_threshold(0.2);
+        let kept = nms.apply(detections.clone());
+        assert_eq!(
+            kept,
+            vec![detections[1], detections[2], detections[3]],
+            "sorted by score, the other class is kept"
+        );
+
+        let kept = nms.with_class_agnostic(true).apply(detections.clone());
+        assert_eq!(kept, vec![detections[1], detections[3]]);
+
+        let kept = nms.with_max_detections(1).apply(detections.clone());
+        assert_eq!(kept, vec![detections[1]]);
+    }
+
+    #[test]
+    fn yolo_outputs_are_decoded() {
+        // Two boxes, two classes
+        let v5 = ndarray::arr3(&[[
+            [10.0_f32, 20.0, 4.0, 6.0, 0.9, 0.2, 0.8],
+            [30.0, 30.0, 2.0, 2.0, 0.1, 0.9, 0.1],
+        ]])
+        .into_dyn();
+        let detections = decode_yolo(v5.view(), YoloFormat::V5, 0.5).unwrap();
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].class, 1);
+        assert!((detections[0].score - 0.72).abs() < 1e-6);
+        assert_eq!(
+            detections[0].bbox,
+            BoundingBox::from_center(10.0, 20.0, 4.0, 6.0)
+        );
+
+        // Same boxes without objectness, one column per box
+        let v8 = ndarray::arr3(&[[
+            [10.0_f32, 30.0],
+            [20.0, 30.0],
+            [4.0, 2.0],
+            [6.0, 2.0],
+            [0.2, 0.9],
+            [0.8, 0.1],
+        ]])
+        .into_dyn();
+        let detections = decode_yolo(v8.view(), YoloFormat::V8, 0.5).unwrap();
+        assert_eq!(detections.len(), 2);
+        assert_eq!((detections[0].class, detections[0].score), (1, 0.8));
+        assert_eq!((detections[1].class, detections[1].score), (0, 0.9));
+
+        let flat = ndarray::arr1(&[1.0_f32]).into_dyn();
+        assert!(matches!(
+            decode_yolo(flat.view(), YoloFormat::V8, 0.5),
+            Err(OrtError::Postprocessing(_))
+        ));
+    }
+
+    #[test]
+    fn ssd_offsets_are_decoded_from_anchors() {
+        let anchor = Anchor {
+            center_x: 0.5,
+            center_y: 0.5,
+            width: 0.2,
+            height: 0.4,
+        };
+        let decoder = SsdDecoder::new(vec![anchor; 2]);
+        let offsets =
+            ndarray::arr3(&[[[1.0_f32, -1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0]]]).into_dyn();
+        // The second anchor only scores as background
+        let scores = ndarray::arr3(&[[[0.1_f32, 0.2, 0.7], [0.9, 0.05, 0.05]]]).into_dyn();
+        let detections = decoder.decode(offsets.view(), scores.view(), 0.5).unwrap();
+        assert_eq!(detections.len(), 1);
+        assert_eq!((detections[0].class, detections[0].score), (2, 0.7));
+        let expected = BoundingBox::from_center(0.52, 0.46, 0.2, 0.4);
+        let bbox = detections[0].bbox;
+        assert!((bbox.x_min - expected.x_min).abs() < 1e-6);
+        assert!((bbox.y_max - expected.y_max).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,292 @@
+//! Module containing a report of the runtime and hardware, for bug reports and startup logs
+//!
+//! [`report()`](fn.report.html) gathers the versions of the crate and of the runtime, the
+//! execution providers the runtime was built with, the CPU's features relevant to its kernels
+//! and, where the driver exposes them, the GPUs:
+//!
+//! ```no_run
+//! let report = onnxruntime::diagnostics::report();
+//! println!("{}", report);
+//! ```
+//!
+//! Nothing in the report fails: information that cannot be read is left out.
+
+use std::{ffi::CStr, fmt};
+
+use crate::{
+    char_p_to_string,
+    error::{status_to_result, OrtError, Result},
+    OrtVersion,
+};
+
+/// Versions, providers, CPU and GPUs of the running process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticsReport {
+    /// Version of this crate
+    pub crate_version: &'static str,
+    /// Version of the runtime library, as it reports it
+    pub runtime_version: Option<String>,
+    /// Version of the C API the bindings were generated for
+    pub api_version: u32,
+    /// Build information of the runtime library (git commit, build type...)
+    pub build_info: Option<String>,
+    /// Execution providers the runtime library was built with, for example `CUDAExecutionProvider`
+    pub available_providers: Vec<String>,
+    /// Operating system, as in `std::env::consts::OS`
+    pub os: &'static str,
+    /// The CPU and its features
+    pub cpu: CpuInfo,
+    /// GPUs reported by the driver, empty if none could be found
+    pub gpus: Vec<GpuInfo>,
+}
+
+/// CPU of the machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuInfo {
+    /// Architecture, as in `std::env::consts::ARCH`
+    pub arch: &'static str,
+    /// Model name, for example `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+    pub model_name: Option<String>,
+    /// Number of logical cores available to the process
+    pub logical_cores: Option<usize>,
+    /// Instruction set extensions detected at run time, for example `avx2` or `avx512f`
+    pub features: Vec<&'static str>,
+}
+
+/// GPU of the machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuInfo {
+    /// Model name, for example `NVIDIA A100-SXM4-40GB`
+    pub name: String,
+    /// Bus location of the device, for example `0000:00:04.0`
+    pub bus_location: String,
+}
+
+/// Gather the report; cheap enough to log at startup
+pub fn report() -> DiagnosticsReport {
+    let version = OrtVersion::get();
+    DiagnosticsReport {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        runtime_version: version.runtime_library_version,
+        api_version: version.api_version,
+        build_info: build_info(),
+        available_providers: available_providers().unwrap_or_default(),
+        os: std::env::consts::OS,
+        cpu: cpu_info(),
+        gpus: gpu_info(),
+    }
+}
+
+/// Execution providers the runtime library was built with
+///
+/// A provider being built in does not mean it can be used: its device or libraries (for
+/// example CUDA and cuDNN) may still be missing.
+pub fn available_providers() -> Result<Vec<String>> {
+    let mut providers_ptr: *mut *mut std::os::raw::c_char = std::ptr::null_mut();
+    let mut count: std::os::raw::c_int = 0;
+    let status = unsafe { ort_api!(GetAvailableProviders)?(&mut providers_ptr, &mut count) };
+    status_to_result(status).map_err(OrtError::AvailableProviders)?;
+
+    let providers = (0..count as usize)
+        .map(|i| char_p_to_string(unsafe { *providers_ptr.add(i) }))
+        .collect();
+    let status = unsafe { ort_api!(ReleaseAvailableProviders)?(providers_ptr, count) };
+    status_to_result(status).map_err(OrtError::AvailableProviders)?;
+    providers
+}
+
+fn build_info() -> Option<String> {
+    let build_info = ort_api!(GetBuildInfoString).ok()?;
+    let build_info = unsafe { build_info() };
+    if build_info.is_null() {
+        return None;
+    }
+    // Owned by the library
+    let build_info = unsafe { CStr::from_ptr(build_info) };
+    Some(build_info.to_string_lossy().trim().to_owned())
+}
+
+fn cpu_info() -> CpuInfo {
+    CpuInfo {
+        arch: std::env::consts::ARCH,
+        model_name: cpu_model_name(),
+        logical_cores: std::thread::available_parallelism().map(usize::from).ok(),
+        features: cpu_features(),
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpu_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    macro_rules! detect {
+        ($($feature:tt),*) => {
+            $(if is_x86_feature_detected!($feature) {
+                features.push($feature);
+            })*
+        };
+    }
+    detect!(
+        "sse4.1",
+        "sse4.2",
+        "avx",
+        "avx2",
+        "fma",
+        "f16c",
+        "avx512f",
+        "avx512bw",
+        "avx512vl",
+        "avx512vnni",
+        "avx512bf16"
+    );
+    features
+}
+
+#[cfg(target_arch = "aarch64")]
+fn cpu_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    macro_rules! detect {
+        ($($feature:tt),*) => {
+            $(if std::arch::is_aarch64_feature_detected!($feature) {
+                features.push($feature);
+            })*
+        };
+    }
+    detect!("neon", "dotprod", "fp16", "i8mm", "bf16", "sve");
+    features
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn cpu_features() -> Vec<&'static str> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+fn cpu_model_name() -> Option<String> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == "model name")
+        .map(|(_, value)| value.trim().to_owned())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn cpu_model_name() -> Option<String> {
+    None
+}
+
+/// GPUs listed by the NVIDIA driver, in `/proc/driver/nvidia/gpus/<bus location>/information`
+#[cfg(target_os = "linux")]
+fn gpu_info() -> Vec<GpuInfo> {
+    let entries = match std::fs::read_dir("/proc/driver/nvidia/gpus") {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    let mut gpus: Vec<GpuInfo> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let information = std::fs::read_to_string(entry.path().join("information")).ok()?;
+            let name = information
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(key, _)| key.trim() == "Model")
+                .map(|(_, value)| value.trim().to_owned())?;
+            Some(GpuInfo {
+                name,
+                bus_location: entry.file_name().to_string_lossy().into_owned(),
+            })
+        })
+        .collect();
+    gpus.sort_by(|a, b| a.bus_location.cmp(&b.bus_location));
+    gpus
+}
+
+#[cfg(not(target_os = "linux"))]
+fn gpu_info() -> Vec<GpuInfo> {
+    Vec::new()
+}
+
+impl fmt::Display for DiagnosticsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "onnxruntime-rs: {}", self.crate_version)?;
+        writeln!(
+            f,
+            "runtime: {} (API {})",
+            self.runtime_version.as_deref().unwrap_or("unknown"),
+            self.api_version
+        )?;
+        if let Some(build_info) = &self.build_info {
+            writeln!(f, "build: {}", build_info)?;
+        }
+        writeln!(f, "providers: {}", self.available_providers.join(", "))?;
+        writeln!(f, "os: {}", self.os)?;
+        write!(f, "cpu: {}", self.cpu.arch)?;
+        if let Some(model_name) = &self.cpu.model_name {
+            write!(f, " {}", model_name)?;
+        }
+        if let Some(logical_cores) = self.cpu.logical_cores {
+            write!(f, ", {} logical cores", logical_cores)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "cpu features: {}", self.cpu.features.join(" "))?;
+        if self.gpus.is_empty() {
+            write!(f, "gpus: none found")
+        } else {
+            write!(f, "gpus:")?;
+            for gpu in &self.gpus {
+                write!(f, "\n  {} ({})", gpu.name, gpu.bus_location)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn cpu_info_is_detected() {
+        let cpu = cpu_info();
+        assert_eq!(cpu.arch, std::env::consts::ARCH);
+        assert!(cpu.logical_cores.unwrap_or(1) >= 1);
+        #[cfg(target_arch = "x86_64")]
+        assert!(cpu.features.contains(&"sse4.1") || !is_x86_feature_detected!("sse4.1"));
+    }
+
+    #[test]
+    fn report_display() {
+        let report = DiagnosticsReport {
+            crate_version: "0.0.14",
+            runtime_version: Some("1.15.1".to_owned()),
+            api_version: 15,
+            build_info: None,
+            available_providers: vec![
+                "CUDAExecutionProvider".to_owned(),
+                "CPUExecutionProvider".to_owned(),
+            ],
+            os: "linux",
+            cpu: CpuInfo {
+                arch: "x86_64",
+                model_name: None,
+                logical_cores: Some(8),
+                features: vec!["avx", "avx2"],
+            },
+            gpus: vec![GpuInfo {
+                name: "NVIDIA A100-SXM4-40GB".to_owned(),
+                bus_location: "0000:00:04.0".to_owned(),
+            }],
+        };
+        assert_eq!(
+            report.to_string(),
+            "onnxruntime-rs: 0.0.14\n\
+             runtime: 1.15.1 (API 15)\n\
+             providers: CUDAExecutionProvider, CPUExecutionProvider\n\
+             os: linux\n\
+             cpu: x86_64, 8 logical cores\n\
+             cpu features: avx avx2\n\
+             gpus:\n  NVIDIA A100-SXM4-40GB (0000:00:04.0)"
+        );
+    }
+}
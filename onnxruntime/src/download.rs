@@ -9,6 +9,10 @@
 //!
 //! See [`AvailableOnnxModel`](enum.AvailableOnnxModel.html) for the different models available
 //! to download.
+//!
+//! Models stored elsewhere (a web server, an S3 or GCS bucket) are loaded with
+//! [`with_model_from_source()`](../session/struct.SessionBuilder.html#method.with_model_from_source),
+//! see the [`source`](source/index.html) module.
 
 #[cfg(feature = "model-fetching")]
 use std::{
@@ -24,6 +28,7 @@ use crate::error::{OrtDownloadError, Result};
 use tracing::info;
 
 pub mod language;
+pub mod source;
 pub mod vision;
 
 /// Available pre-trained models to download from [ONNX Model Zoo](https://github.com/onnx/models).
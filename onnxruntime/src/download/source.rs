@@ -0,0 +1,360 @@
+//! Module containing the places models can be fetched from
+//!
+//! A [`ModelSource`](trait.ModelSource.html) hands a model to
+//! [`SessionBuilder::with_model_from_source()`](../../session/struct.SessionBuilder.html#method.with_model_from_source),
+//! either as a local file or as bytes in memory. Sources are provided for local paths,
+//! HTTP(S) URLs (`model-fetching` feature), Amazon S3 (`s3` feature) and Google Cloud Storage
+//! (`gcs` feature); other storage can be supported by implementing the trait.
+//!
+//! Remote sources keep the model in memory, unless given a cache directory where the model is
+//! downloaded once and reused afterwards.
+
+use std::{fmt::Debug, path::PathBuf};
+
+#[cfg(feature = "model-fetching")]
+use std::{
+    fmt, fs,
+    io::{self, Read, Write},
+    path::Path,
+    time::Duration,
+};
+
+#[cfg(feature = "model-fetching")]
+use tracing::info;
+
+use crate::error::{OrtError, Result};
+#[cfg(feature = "model-fetching")]
+use crate::{error::OrtDownloadError, model_hash::ModelHash};
+
+#[cfg(feature = "gcs")]
+mod gcs;
+#[cfg(feature = "s3")]
+mod s3;
+
+#[cfg(feature = "gcs")]
+pub use gcs::GcsSource;
+#[cfg(feature = "s3")]
+pub use s3::{S3Credentials, S3Source};
+
+/// Timeout of remote sources, unless changed
+#[cfg(feature = "model-fetching")]
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(180); // 3 minutes
+
+/// Place a model can be fetched from
+pub trait ModelSource: Debug {
+    /// Fetch the model, downloading it if needed
+    fn fetch(&self) -> Result<FetchedModel>;
+}
+
+impl<S> ModelSource for &S
+where
+    S: ModelSource + ?Sized,
+{
+    fn fetch(&self) -> Result<FetchedModel> {
+        (**self).fetch()
+    }
+}
+
+impl<S> ModelSource for Box<S>
+where
+    S: ModelSource + ?Sized,
+{
+    fn fetch(&self) -> Result<FetchedModel> {
+        (**self).fetch()
+    }
+}
+
+/// Model returned by a [`ModelSource`](trait.ModelSource.html)
+#[derive(Debug)]
+pub enum FetchedModel {
+    /// Model stored in a local file
+    File(PathBuf),
+    /// Model held in memory
+    Bytes(Vec<u8>),
+}
+
+/// Model stored in a local file
+#[derive(Debug, Clone)]
+pub struct LocalSource {
+    path: PathBuf,
+}
+
+impl LocalSource {
+    /// Source reading the model at `path`
+    pub fn new<P>(path: P) -> LocalSource
+    where
+        P: Into<PathBuf>,
+    {
+        LocalSource { path: path.into() }
+    }
+}
+
+impl ModelSource for LocalSource {
+    fn fetch(&self) -> Result<FetchedModel> {
+        if self.path.exists() {
+            Ok(FetchedModel::File(self.path.clone()))
+        } else {
+            Err(OrtError::FileDoesNotExists {
+                filename: self.path.clone(),
+            })
+        }
+    }
+}
+
+/// Model downloaded with an HTTP(S) `GET` request
+#[cfg(feature = "model-fetching")]
+#[derive(Clone)]
+pub struct HttpSource {
+    url: String,
+    headers: Vec<(String, String)>,
+    timeout: Duration,
+    cache_dir: Option<PathBuf>,
+}
+
+#[cfg(feature = "model-fetching")]
+impl HttpSource {
+    /// Source downloading the model at `url`
+    pub fn new<S>(url: S) -> HttpSource
+    where
+        S: Into<String>,
+    {
+        HttpSource {
+            url: url.into(),
+            headers: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+            cache_dir: None,
+        }
+    }
+
+    /// Add a header to the request, for example `Authorization`
+    pub fn with_header<N, V>(mut self, name: N, value: V) -> HttpSource
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Fail downloads taking longer than `timeout` (3 minutes by default)
+    pub fn with_timeout(mut self, timeout: Duration) -> HttpSource {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Download the model to `cache_dir`, named after a hash of the URL and its last path segment
+    ///
+    /// A model already present there is used without downloading it again. URLs differing only
+    /// by their query (such as presigned URLs) are cached separately. Fetching fails with
+    /// [`OrtError::InvalidConfiguration`](../../error/enum.OrtError.html#variant.InvalidConfiguration)
+    /// if the URL's path ends with a `/`.
+    pub fn with_cache_dir<P>(mut self, cache_dir: P) -> HttpSource
+    where
+        P: Into<PathBuf>,
+    {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+}
+
+/// Header values are left out, since they typically hold credentials
+#[cfg(feature = "model-fetching")]
+impl fmt::Debug for HttpSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header_names: Vec<&str> = self.headers.iter().map(|(name, _)| name.as_str()).collect();
+        f.debug_struct("HttpSource")
+            .field("url", &self.url)
+            .field("header_names", &header_names)
+            .field("timeout", &self.timeout)
+            .field("cache_dir", &self.cache_dir)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "model-fetching")]
+impl ModelSource for HttpSource {
+    fn fetch(&self) -> Result<FetchedModel> {
+        let path = self.url.split(['?', '#']).next().unwrap();
+        let cache_file = cache_file(self.cache_dir.as_deref(), &self.url, path)?;
+
+        let mut request = ureq::get(&self.url).timeout(self.timeout);
+        for (name, value) in &self.headers {
+            request = request.set(name, value);
+        }
+        download(request, cache_file)
+    }
+}
+
+#[cfg(feature = "model-fetching")]
+impl ModelSource for super::AvailableOnnxModel {
+    /// Download the model to the current directory, unless already there
+    fn fetch(&self) -> Result<FetchedModel> {
+        let download_dir = std::env::current_dir().map_err(OrtDownloadError::IoError)?;
+        self.download_to(download_dir).map(FetchedModel::File)
+    }
+}
+
+/// Path in `cache_dir` of the model downloaded from `url`, whose path is `path`
+///
+/// The file is named after a hash of the whole URL, so that models of the same name at different
+/// URLs don't overwrite each other, followed by the last segment of `path`, which must not be
+/// empty.
+#[cfg(feature = "model-fetching")]
+fn cache_file(cache_dir: Option<&Path>, url: &str, path: &str) -> Result<Option<PathBuf>> {
+    let cache_dir = match cache_dir {
+        Some(cache_dir) => cache_dir,
+        None => return Ok(None),
+    };
+    let name = path.rsplit('/').next().unwrap();
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(OrtError::InvalidConfiguration(format!(
+            "cannot name a cached model after {:?}: its path has no file name",
+            url
+        )));
+    }
+    let hash = ModelHash::from_bytes(url.as_bytes()).to_hex();
+    Ok(Some(cache_dir.join(format!("{}-{}", &hash[..16], name))))
+}
+
+/// Send `request`, writing the response to `cache_file` if any or to memory otherwise
+///
+/// The model is downloaded to a `.part` file renamed once complete, so that an interrupted
+/// download is never mistaken for a cached model.
+#[cfg(feature = "model-fetching")]
+fn download(request: ureq::Request, cache_file: Option<PathBuf>) -> Result<FetchedModel> {
+    if let Some(cache_file) = &cache_file {
+        if cache_file.exists() {
+            info!(
+                model_filepath = format!("{}", cache_file.display()).as_str(),
+                "File already exists, not re-downloading.",
+            );
+            return Ok(FetchedModel::File(cache_file.clone()));
+        }
+    }
+
+    info!(url = request.url(), "Downloading model, please wait....");
+    let response = request
+        .call()
+        .map_err(Box::new)
+        .map_err(OrtDownloadError::UreqError)?;
+    let expected = response
+        .header("Content-Length")
+        .and_then(|s| s.parse::<u64>().ok());
+    let mut reader = response.into_reader();
+
+    let check_length = |io: u64| match expected {
+        Some(expected) if expected != io => Err(OrtDownloadError::CopyError { expected, io }),
+        _ => Ok(()),
+    };
+
+    match cache_file {
+        Some(cache_file) => {
+            let mut partial_name = cache_file.file_name().unwrap_or_default().to_owned();
+            partial_name.push(".part");
+            let partial_file = cache_file.with_file_name(partial_name);
+
+            let file = fs::File::create(&partial_file).map_err(OrtDownloadError::IoError)?;
+            let mut writer = io::BufWriter::new(file);
+            let io = io::copy(&mut reader, &mut writer).map_err(OrtDownloadError::IoError)?;
+            writer.flush().map_err(OrtDownloadError::IoError)?;
+            check_length(io)?;
+            fs::rename(&partial_file, &cache_file).map_err(OrtDownloadError::IoError)?;
+            Ok(FetchedModel::File(cache_file))
+        }
+        None => {
+            let mut bytes = Vec::with_capacity(expected.unwrap_or(0) as usize);
+            let io = reader
+                .read_to_end(&mut bytes)
+                .map_err(OrtDownloadError::IoError)?;
+            check_length(io as u64)?;
+            Ok(FetchedModel::Bytes(bytes))
+        }
+    }
+}
+
+/// Percent-encode `value` as a URL path, keeping `/` separators if `keep_slashes`
+#[cfg(any(feature = "s3", feature = "gcs"))]
+fn percent_encode(value: &str, keep_slashes: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if keep_slashes => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn local_source() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        match LocalSource::new(file.path()).fetch().unwrap() {
+            FetchedModel::File(path) => assert_eq!(path, file.path()),
+            FetchedModel::Bytes(_) => panic!("local models are not read to memory"),
+        }
+
+        let missing = Box::new(LocalSource::new("does/not/exist.onnx")) as Box<dyn ModelSource>;
+        assert!(matches!(
+            missing.fetch(),
+            Err(OrtError::FileDoesNotExists { .. })
+        ));
+    }
+
+    #[cfg(feature = "model-fetching")]
+    #[test]
+    fn http_source() {
+        let source = HttpSource::new("https://example.com/model.onnx")
+            .with_header("Authorization", "Bearer secret-token");
+        let debug = format!("{:?}", source);
+        assert!(debug.contains("Authorization"));
+        assert!(!debug.contains("secret-token"));
+
+        let cache_dir = Path::new("cache");
+        let v1 = cache_file(
+            Some(cache_dir),
+            "https://a.com/v1/model.onnx",
+            "https://a.com/v1/model.onnx",
+        )
+        .unwrap();
+        let v2 = cache_file(
+            Some(cache_dir),
+            "https://a.com/v2/model.onnx",
+            "https://a.com/v2/model.onnx",
+        )
+        .unwrap();
+        assert_ne!(v1, v2);
+        let v1 = v1.unwrap();
+        assert_eq!(v1.parent(), Some(cache_dir));
+        assert!(v1.to_str().unwrap().ends_with("-model.onnx"));
+
+        assert!(cache_file(None, "https://a.com/", "https://a.com/")
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            cache_file(
+                Some(cache_dir),
+                "https://a.com/models/",
+                "https://a.com/models/"
+            ),
+            Err(OrtError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[cfg(any(feature = "s3", feature = "gcs"))]
+    #[test]
+    fn percent_encoding() {
+        assert_eq!(
+            percent_encode("models/résumé v1.onnx", true),
+            "models/r%C3%A9sum%C3%A9%20v1.onnx"
+        );
+        assert_eq!(percent_encode("a/b~c", false), "a%2Fb~c");
+    }
+}
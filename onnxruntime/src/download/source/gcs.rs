@@ -0,0 +1,136 @@
+//! Module containing models stored in Google Cloud Storage
+
+use std::{env, fmt, path::PathBuf, time::Duration};
+
+use crate::error::Result;
+
+use super::{cache_file, download, percent_encode, FetchedModel, ModelSource, DEFAULT_TIMEOUT};
+
+/// Model stored as an object of a Google Cloud Storage bucket
+///
+/// Requests are authorized with the OAuth 2.0 access token given with
+/// [`with_access_token()`](#method.with_access_token), or else taken from the
+/// `GOOGLE_OAUTH_ACCESS_TOKEN` environment variable (for example set to the output of
+/// `gcloud auth print-access-token`). Without a token, requests are sent anonymously, which only
+/// works for public objects. Service account keys and the metadata server are not queried.
+#[derive(Clone)]
+pub struct GcsSource {
+    bucket: String,
+    object: String,
+    endpoint: String,
+    access_token: Option<String>,
+    timeout: Duration,
+    cache_dir: Option<PathBuf>,
+}
+
+impl GcsSource {
+    /// Source downloading `object` of `bucket`
+    pub fn new<B, O>(bucket: B, object: O) -> GcsSource
+    where
+        B: Into<String>,
+        O: Into<String>,
+    {
+        GcsSource {
+            bucket: bucket.into(),
+            object: object.into(),
+            endpoint: "https://storage.googleapis.com".to_string(),
+            access_token: None,
+            timeout: DEFAULT_TIMEOUT,
+            cache_dir: None,
+        }
+    }
+
+    /// Send requests to `endpoint` rather than `https://storage.googleapis.com`, for example to
+    /// an emulator
+    pub fn with_endpoint<E>(mut self, endpoint: E) -> GcsSource
+    where
+        E: Into<String>,
+    {
+        self.endpoint = endpoint.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Authorize requests with `access_token` rather than with the one from the environment
+    pub fn with_access_token<T>(mut self, access_token: T) -> GcsSource
+    where
+        T: Into<String>,
+    {
+        self.access_token = Some(access_token.into());
+        self
+    }
+
+    /// Fail downloads taking longer than `timeout` (3 minutes by default)
+    pub fn with_timeout(mut self, timeout: Duration) -> GcsSource {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Download the model to `cache_dir`, named after a hash of the object's URL and its last
+    /// path segment
+    ///
+    /// A model already present there is used without downloading it again.
+    pub fn with_cache_dir<P>(mut self, cache_dir: P) -> GcsSource
+    where
+        P: Into<PathBuf>,
+    {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    fn url(&self) -> String {
+        // Object names are a single path segment of the JSON API: their slashes are encoded too
+        format!(
+            "{}/storage/v1/b/{}/o/{}?alt=media",
+            self.endpoint,
+            percent_encode(&self.bucket, false),
+            percent_encode(&self.object, false)
+        )
+    }
+}
+
+impl fmt::Debug for GcsSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcsSource")
+            .field("bucket", &self.bucket)
+            .field("object", &self.object)
+            .field("endpoint", &self.endpoint)
+            .field("timeout", &self.timeout)
+            .field("cache_dir", &self.cache_dir)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ModelSource for GcsSource {
+    fn fetch(&self) -> Result<FetchedModel> {
+        let url = self.url();
+        let cache_file = cache_file(self.cache_dir.as_deref(), &url, &self.object)?;
+        let mut request = ureq::get(&url).timeout(self.timeout);
+        let access_token = self
+            .access_token
+            .clone()
+            .or_else(|| env::var("GOOGLE_OAUTH_ACCESS_TOKEN").ok());
+        if let Some(access_token) = access_token {
+            request = request.set("Authorization", &format!("Bearer {}", access_token));
+        }
+        download(request, cache_file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn gcs_url() {
+        let source = GcsSource::new("my-models", "resnet/v2 final.onnx");
+        assert_eq!(
+            source.url(),
+            "https://storage.googleapis.com/storage/v1/b/my-models/o/resnet%2Fv2%20final.onnx?alt=media"
+        );
+        let source = source.with_endpoint("http://localhost:4443/");
+        assert!(source
+            .url()
+            .starts_with("http://localhost:4443/storage/v1/b/"));
+    }
+}
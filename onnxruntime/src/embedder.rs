@@ -0,0 +1,426 @@
+//! Module containing a convenience API for sentence-embedding models
+//!
+//! An [`Embedder`](struct.Embedder.html) turns strings into embeddings in a single call: it
+//! tokenizes them, runs the model on batches of them, pools the token embeddings into one vector
+//! per string and normalizes it. Tokenization is left to the caller's
+//! [`Tokenizer`](trait.Tokenizer.html), typically wrapping the `tokenizers` crate with the
+//! tokenizer the model was exported with.
+//!
+//! ```no_run
+//! # use std::error::Error;
+//! # use onnxruntime::{
+//! #     embedder::{Embedder, Encoding, Pooling},
+//! #     environment::Environment,
+//! #     model::ProcessingResult,
+//! # };
+//! # fn tokenize(text: &str) -> Vec<i64> { Vec::new() }
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # let environment = Environment::builder().build()?;
+//! let session = environment
+//!     .new_session_builder()?
+//!     .with_model_from_file("all-MiniLM-L6-v2.onnx")?;
+//! let tokenizer = |text: &str| -> ProcessingResult<Encoding> { Ok(Encoding::new(tokenize(text))) };
+//! let embedder = Embedder::new(session, tokenizer)
+//!     .with_pooling(Pooling::Mean)
+//!     .with_max_length(256);
+//! let embeddings = embedder.embed(&["The cat sits outside", "A man is playing guitar"])?;
+//! assert_eq!(embeddings[0].len(), 384);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use ndarray::{Array2, ArrayViewD, Axis, Ix2, Ix3};
+
+use crate::{
+    error::{OrtError, Result},
+    model::ProcessingResult,
+    session::Session,
+    tensor::OrtOwnedTensor,
+};
+
+/// Default number of strings run by the model at once
+const DEFAULT_BATCH_SIZE: usize = 32;
+
+/// Token ids of a string, as expected by the model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encoding {
+    /// Ids of the tokens, special tokens (such as `[CLS]` and `[SEP]`) included
+    pub ids: Vec<i64>,
+    /// 1 for the tokens to attend to, 0 for padding
+    pub attention_mask: Vec<i64>,
+    /// Segment of each token, for models taking `token_type_ids`
+    pub type_ids: Vec<i64>,
+}
+
+impl Encoding {
+    /// Encoding of the tokens `ids`, all attended to and in the first segment
+    pub fn new(ids: Vec<i64>) -> Encoding {
+        Encoding {
+            attention_mask: vec![1; ids.len()],
+            type_ids: vec![0; ids.len()],
+            ids,
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.ids.truncate(len);
+        self.attention_mask.truncate(len);
+        self.type_ids.truncate(len);
+    }
+}
+
+/// Conversion of a string into the token ids of an embedding model
+pub trait Tokenizer {
+    /// Tokens of `text`, special tokens included
+    fn encode(&self, text: &str) -> ProcessingResult<Encoding>;
+}
+
+impl<F> Tokenizer for F
+where
+    F: Fn(&str) -> ProcessingResult<Encoding>,
+{
+    fn encode(&self, text: &str) -> ProcessingResult<Encoding> {
+        self(text)
+    }
+}
+
+/// Reduction of the embeddings of the tokens of a string into a single embedding
+///
+/// Models whose output already holds one embedding per string (of shape `[batch, hidden]`) are
+/// not pooled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pooling {
+    /// Average of the embeddings of the attended tokens, used by most sentence-transformers
+    Mean,
+    /// Embedding of the first token (`[CLS]`)
+    Cls,
+    /// Element-wise maximum of the embeddings of the attended tokens
+    Max,
+}
+
+/// Session of a sentence-embedding model bundled with its tokenizer, embedding strings
+///
+/// The model takes `input_ids`, `attention_mask` and optionally `token_type_ids` as `i64`
+/// tensors of shape `[batch, sequence]`, and outputs `f32` token embeddings of shape
+/// `[batch, sequence, hidden]` (or already pooled ones of shape `[batch, hidden]`). Strings are
+/// run in batches, padded to the longest string of the batch.
+pub struct Embedder<'a, T> {
+    session: Session<'a>,
+    tokenizer: T,
+    pooling: Pooling,
+    normalize: bool,
+    batch_size: usize,
+    max_length: Option<usize>,
+    pad_id: i64,
+    output: Option<String>,
+}
+
+impl<'a, T> Embedder<'a, T>
+where
+    T: Tokenizer,
+{
+    /// Embedder running `session`, mean pooling and normalizing the embeddings of batches of 32
+    /// strings
+    pub fn new(session: Session<'a>, tokenizer: T) -> Embedder<'a, T> {
+        Embedder {
+            session,
+            tokenizer,
+            pooling: Pooling::Mean,
+            normalize: true,
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_length: None,
+            pad_id: 0,
+            output: None,
+        }
+    }
+
+    /// Pool the token embeddings with `pooling`
+    pub fn with_pooling(mut self, pooling: Pooling) -> Embedder<'a, T> {
+        self.pooling = pooling;
+        self
+    }
+
+    /// Whether to scale the embeddings to a unit L2 norm, so that their dot product is their
+    /// cosine similarity (the default)
+    pub fn with_normalization(mut self, normalize: bool) -> Embedder<'a, T> {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Run the model on up to `batch_size` strings at once
+    pub fn with_batch_size(mut self, batch_size: usize) -> Embedder<'a, T> {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Truncate strings to their first `max_length` tokens, such as the model's maximum sequence
+    /// length
+    pub fn with_max_length(mut self, max_length: usize) -> Embedder<'a, T> {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Pad shorter strings of a batch with the token `pad_id` (0 by default)
+    pub fn with_pad_id(mut self, pad_id: i64) -> Embedder<'a, T> {
+        self.pad_id = pad_id;
+        self
+    }
+
+    /// Take the embeddings from the output `name` rather than from the model's first output
+    pub fn with_output(mut self, name: &str) -> Embedder<'a, T> {
+        self.output = Some(name.to_owned());
+        self
+    }
+
+    /// Embedding of each of `texts`, in order
+    ///
+    /// Failures of the tokenizer are reported as
+    /// [`OrtError::Preprocessing`](../error/enum.OrtError.html#variant.Preprocessing), outputs of
+    /// an unexpected shape as
+    /// [`OrtError::Postprocessing`](../error/enum.OrtError.html#variant.Postprocessing).
+    pub fn embed<S>(&self, texts: &[S]) -> Result<Vec<Vec<f32>>>
+    where
+        S: AsRef<str>,
+    {
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for texts in texts.chunks(self.batch_size) {
+            let encodings = texts
+                .iter()
+                .map(|text| {
+                    let mut encoding = self.tokenizer.encode(text.as_ref())?;
+                    if let Some(max_length) = self.max_length {
+                        encoding.truncate(max_length);
+                    }
+                    Ok(encoding)
+                })
+                .collect::<ProcessingResult<Vec<_>>>()
+                .map_err(OrtError::Preprocessing)?;
+            embeddings.extend(self.embed_batch(&encodings)?);
+        }
+        Ok(embeddings)
+    }
+
+    /// Embedding of `text`
+    pub fn embed_one(&self, text: &str) -> Result<Vec<f32>> {
+        let mut embeddings = self.embed(&[text])?;
+        Ok(embeddings.remove(0))
+    }
+
+    /// Underlying session
+    pub fn session(&self) -> &Session<'a> {
+        &self.session
+    }
+
+    /// Tokenizer of the strings
+    pub fn tokenizer(&self) -> &T {
+        &self.tokenizer
+    }
+
+    fn embed_batch(&self, encodings: &[Encoding]) -> Result<Vec<Vec<f32>>> {
+        let batch = Batch::pad(encodings, self.pad_id);
+        let inputs = self
+            .session
+            .inputs
+            .iter()
+            .map(|input| match input.name.as_str() {
+                "input_ids" => Ok(batch.ids.clone()),
+                "attention_mask" => Ok(batch.attention_mask.clone()),
+                "token_type_ids" => Ok(batch.type_ids.clone()),
+                name => Err(OrtError::InvalidConfiguration(format!(
+                    "Embedding model input {} is not one of input_ids, attention_mask and token_type_ids",
+                    name
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let output = match &self.output {
+            Some(output) => output.as_str(),
+            None => self
+                .session
+                .outputs
+                .first()
+                .map(|output| output.name.as_str())
+                .ok_or_else(|| {
+                    OrtError::InvalidConfiguration("Embedding model has no outputs".to_owned())
+                })?,
+        };
+        let outputs: Vec<OrtOwnedTensor<f32, _>> =
+            self.session.run_with_outputs(inputs, &[output])?;
+
+        let mut embeddings = pool(outputs[0].view(), &batch.attention_mask, self.pooling)?;
+        if self.normalize {
+            embeddings
+                .iter_mut()
+                .for_each(|embedding| normalize(embedding));
+        }
+        Ok(embeddings)
+    }
+}
+
+impl<'a, T> fmt::Debug for Embedder<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Embedder")
+            .field("session", &self.session)
+            .field("pooling", &self.pooling)
+            .field("normalize", &self.normalize)
+            .field("batch_size", &self.batch_size)
+            .field("max_length", &self.max_length)
+            .field("pad_id", &self.pad_id)
+            .field("output", &self.output)
+            .finish()
+    }
+}
+
+/// Inputs of the model for a batch of strings, padded to the longest one
+#[derive(Debug)]
+struct Batch {
+    ids: Array2<i64>,
+    attention_mask: Array2<i64>,
+    type_ids: Array2<i64>,
+}
+
+impl Batch {
+    fn pad(encodings: &[Encoding], pad_id: i64) -> Batch {
+        let len = encodings
+            .iter()
+            .map(|encoding| encoding.ids.len())
+            .max()
+            .unwrap_or(0);
+        let shape = (encodings.len(), len);
+        let mut batch = Batch {
+            ids: Array2::from_elem(shape, pad_id),
+            attention_mask: Array2::zeros(shape),
+            type_ids: Array2::zeros(shape),
+        };
+        for (row, encoding) in encodings.iter().enumerate() {
+            for (column, &id) in encoding.ids.iter().enumerate() {
+                batch.ids[[row, column]] = id;
+                batch.attention_mask[[row, column]] = encoding.attention_mask[column];
+                batch.type_ids[[row, column]] = encoding.type_ids[column];
+            }
+        }
+        batch
+    }
+}
+
+/// One embedding per string of the model's output `hidden`, pooling token embeddings
+fn pool(
+    hidden: ArrayViewD<'_, f32>,
+    attention_mask: &Array2<i64>,
+    pooling: Pooling,
+) -> Result<Vec<Vec<f32>>> {
+    let unexpected_shape = || {
+        OrtError::Postprocessing(
+            format!(
+                "Embedding model output has shape {:?}, expected [batch, sequence, hidden] or [batch, hidden]",
+                hidden.shape()
+            )
+            .into(),
+        )
+    };
+    if let Ok(pooled) = hidden.view().into_dimensionality::<Ix2>() {
+        return Ok(pooled.outer_iter().map(|row| row.to_vec()).collect());
+    }
+    let hidden = hidden
+        .view()
+        .into_dimensionality::<Ix3>()
+        .map_err(|_| unexpected_shape())?;
+    if hidden.shape()[..2] != attention_mask.shape()[..] {
+        return Err(unexpected_shape());
+    }
+
+    let embeddings = hidden
+        .outer_iter()
+        .zip(attention_mask.outer_iter())
+        .map(|(tokens, mask)| {
+            let attended = || {
+                tokens
+                    .outer_iter()
+                    .zip(mask.iter())
+                    .filter(|(_, &mask)| mask != 0)
+                    .map(|(token, _)| token)
+            };
+            let mut embedding = vec![0.0; tokens.len_of(Axis(1))];
+            match pooling {
+                Pooling::Cls => {
+                    if let Some(first) = tokens.outer_iter().next() {
+                        embedding = first.to_vec();
+                    }
+                }
+                Pooling::Mean => {
+                    let mut count = 0;
+                    for token in attended() {
+                        embedding
+                            .iter_mut()
+                            .zip(token)
+                            .for_each(|(sum, x)| *sum += x);
+                        count += 1;
+                    }
+                    if count > 0 {
+                        embedding.iter_mut().for_each(|x| *x /= count as f32);
+                    }
+                }
+                Pooling::Max => {
+                    for (index, token) in attended().enumerate() {
+                        for (max, &x) in embedding.iter_mut().zip(token) {
+                            if index == 0 || x > *max {
+                                *max = x;
+                            }
+                        }
+                    }
+                }
+            }
+            embedding
+        })
+        .collect();
+    Ok(embeddings)
+}
+
+/// Scale `embedding` to a unit L2 norm, leaving null embeddings alone
+fn normalize(embedding: &mut [f32]) {
+    let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        embedding.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn batches_are_padded_to_longest_string() {
+        let encodings = [
+            Encoding::new(vec![101, 7, 102]),
+            Encoding::new(vec![101, 102]),
+        ];
+        let batch = Batch::pad(&encodings, -1);
+        assert_eq!(batch.ids, ndarray::arr2(&[[101, 7, 102], [101, 102, -1]]));
+        assert_eq!(batch.attention_mask, ndarray::arr2(&[[1, 1, 1], [1, 1, 0]]));
+        assert_eq!(batch.type_ids, Array2::<i64>::zeros((2, 3)));
+    }
+
+    #[test]
+    fn token_embeddings_are_pooled_over_attended_tokens() {
+        // One string of two tokens, padded with a third
+        let hidden = ndarray::arr3(&[[[1.0_f32, 4.0], [3.0, 2.0], [100.0, 100.0]]]).into_dyn();
+        let mask = ndarray::arr2(&[[1, 1, 0]]);
+        let pooled = |pooling| pool(hidden.view(), &mask, pooling).unwrap();
+        assert_eq!(pooled(Pooling::Mean), vec![vec![2.0, 3.0]]);
+        assert_eq!(pooled(Pooling::Cls), vec![vec![1.0, 4.0]]);
+        assert_eq!(pooled(Pooling::Max), vec![vec![3.0, 4.0]]);
+
+        let sentence = ndarray::arr2(&[[3.0_f32, 4.0]]).into_dyn();
+        let mut embeddings = pool(sentence.view(), &mask, Pooling::Mean).unwrap();
+        normalize(&mut embeddings[0]);
+        assert_eq!(embeddings, vec![vec![0.6, 0.8]]);
+
+        let scalar = ndarray::arr1(&[1.0_f32]).into_dyn();
+        assert!(matches!(
+            pool(scalar.view(), &mask, Pooling::Mean),
+            Err(OrtError::Postprocessing(_))
+        ));
+    }
+}
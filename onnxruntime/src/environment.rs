@@ -12,7 +12,7 @@ use onnxruntime_sys as sys;
 
 use crate::{
     error::{status_to_result, OrtError, Result},
-    g_ort,
+    fork_guard,
     onnxruntime::custom_logger,
     session::SessionBuilder,
     LoggingLevel,
@@ -23,13 +23,18 @@ lazy_static! {
         Arc::new(Mutex::new(EnvironmentSingleton {
             name: String::from("uninitialized"),
             env_ptr: AtomicPtr::new(std::ptr::null_mut()),
+            global_thread_pools: false,
+            random_seed: None,
         }));
+    static ref G_SHARED_ENV: Mutex<Option<(Arc<Environment>, EnvBuilder)>> = Mutex::new(None);
 }
 
 #[derive(Debug)]
 struct EnvironmentSingleton {
     name: String,
     env_ptr: AtomicPtr<sys::OrtEnv>,
+    global_thread_pools: bool,
+    random_seed: Option<u32>,
 }
 
 /// An [`Environment`](session/struct.Environment.html) is the main entry point of the ONNX Runtime.
@@ -69,6 +74,8 @@ impl Environment {
         EnvBuilder {
             name: "default".into(),
             log_level: LoggingLevel::Warning,
+            global_thread_pools: None,
+            random_seed: None,
         }
     }
 
@@ -81,8 +88,41 @@ impl Environment {
         *self.env.lock().unwrap().env_ptr.get_mut()
     }
 
+    /// Process-wide environment created by [`init()`](fn.init.html), if any
+    ///
+    /// Libraries should use this environment when there is one, rather than building their own
+    /// with a configuration that would be ignored anyway.
+    pub fn global() -> Option<Arc<Environment>> {
+        G_SHARED_ENV
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .as_ref()
+            .map(|(environment, _)| environment.clone())
+    }
+
+    /// Whether sessions of this environment share global thread pools
+    ///
+    /// See [`EnvBuilder::with_global_thread_pools()`](struct.EnvBuilder.html#method.with_global_thread_pools).
+    pub fn has_global_thread_pools(&self) -> bool {
+        self.env.lock().unwrap().global_thread_pools
+    }
+
+    /// Seed of the random operators of the models loaded in this environment, if any
+    ///
+    /// See [`EnvBuilder::with_random_seed()`](struct.EnvBuilder.html#method.with_random_seed).
+    pub fn random_seed(&self) -> Option<u32> {
+        self.env.lock().unwrap().random_seed
+    }
+
     #[tracing::instrument]
-    fn new(name: String, log_level: LoggingLevel) -> Result<Environment> {
+    fn new(
+        name: String,
+        log_level: LoggingLevel,
+        global_thread_pools: Option<&ThreadingOptions>,
+        random_seed: Option<u32>,
+    ) -> Result<Environment> {
+        fork_guard::check()?;
+
         // NOTE: Because 'G_ENV' is a lazy_static, locking it will, initially, create
         //      a new Arc<Mutex<EnvironmentSingleton>> with a strong count of 1.
         //      Cloning it to embed it inside the 'Environment' to return
@@ -102,20 +142,37 @@ impl Environment {
 
             let cname = CString::new(name.clone()).unwrap();
 
-            let create_env_with_custom_logger = g_ort().CreateEnvWithCustomLogger.unwrap();
-            let status = {
-                unsafe {
-                    create_env_with_custom_logger(
-                        logging_function,
-                        logger_param,
-                        log_level.into(),
-                        cname.as_ptr(),
-                        &mut env_ptr,
-                    )
+            let status = match global_thread_pools {
+                None => {
+                    let create_env_with_custom_logger = ort_api!(CreateEnvWithCustomLogger)?;
+                    unsafe {
+                        create_env_with_custom_logger(
+                            logging_function,
+                            logger_param,
+                            log_level.into(),
+                            cname.as_ptr(),
+                            &mut env_ptr,
+                        )
+                    }
+                }
+                Some(threading_options) => {
+                    let tp_options = OrtThreadingOptions::new(threading_options)?;
+                    let create_env = ort_api!(CreateEnvWithCustomLoggerAndGlobalThreadPools)?;
+                    unsafe {
+                        create_env(
+                            logging_function,
+                            logger_param,
+                            log_level.into(),
+                            cname.as_ptr(),
+                            tp_options.ptr,
+                            &mut env_ptr,
+                        )
+                    }
                 }
             };
 
             status_to_result(status).map_err(OrtError::Environment)?;
+            fork_guard::register();
 
             debug!(
                 env_ptr = format!("{:?}", env_ptr).as_str(),
@@ -124,6 +181,8 @@ impl Environment {
 
             *g_env_ptr = env_ptr;
             environment_guard.name = name;
+            environment_guard.global_thread_pools = global_thread_pools.is_some();
+            environment_guard.random_seed = random_seed;
 
             // NOTE: Cloning the lazy_static 'G_ENV' will increase its strong count by one.
             //       If this 'Environment' is the only one in the process, the strong count
@@ -173,7 +232,6 @@ impl Drop for Environment {
         //       If there is no other environment, the strong count should be two and we
         //       can properly free the sys::OrtEnv pointer.
         if Arc::strong_count(&G_ENV) == 2 {
-            let release_env = g_ort().ReleaseEnv.unwrap();
             let env_ptr: *mut sys::OrtEnv = *environment_guard.env_ptr.get_mut();
 
             debug!(
@@ -184,12 +242,16 @@ impl Drop for Environment {
             assert_ne!(env_ptr, std::ptr::null_mut());
             if env_ptr.is_null() {
                 error!("Environment pointer is null, not dropping!");
-            } else {
+            } else if fork_guard::is_forked() {
+                warn!("Process forked after creating the Environment, leaking it.");
+            } else if let Ok(release_env) = ort_api!(ReleaseEnv) {
                 unsafe { release_env(env_ptr) };
             }
 
             environment_guard.env_ptr = AtomicPtr::new(std::ptr::null_mut());
             environment_guard.name = String::from("uninitialized");
+            environment_guard.global_thread_pools = false;
+            environment_guard.random_seed = None;
         }
     }
 }
@@ -202,9 +264,12 @@ impl Drop for Environment {
 ///
 /// **NOTE**: If the same configuration method (for example [`with_name()`](struct.EnvBuilder.html#method.with_name))
 /// is called multiple times, the last value will have precedence.
+#[derive(Debug, Clone, PartialEq)]
 pub struct EnvBuilder {
     name: String,
     log_level: LoggingLevel,
+    global_thread_pools: Option<ThreadingOptions>,
+    random_seed: Option<u32>,
 }
 
 impl EnvBuilder {
@@ -233,14 +298,238 @@ impl EnvBuilder {
         self
     }
 
+    /// Share global thread pools between all sessions, configured with `threading_options`
+    ///
+    /// By default each session creates its own intra-op and inter-op thread pools; processes
+    /// running many sessions end up with many more threads than cores. With global thread
+    /// pools, sessions use the environment's pools instead, and their own thread settings
+    /// (such as [`SessionBuilder::with_number_threads()`](../session/struct.SessionBuilder.html#method.with_number_threads))
+    /// have no effect.
+    ///
+    /// **NOTE**: Since ONNX can only define one environment per process,
+    /// creating multiple environments using multiple `EnvBuilder` will
+    /// end up re-using the same environment internally; a new one will _not_
+    /// be created. New parameters will be ignored.
+    pub fn with_global_thread_pools(mut self, threading_options: ThreadingOptions) -> EnvBuilder {
+        self.global_thread_pools = Some(threading_options);
+        self
+    }
+
+    /// Seed the random operators of the models loaded in the environment with `seed`, so that
+    /// runs are reproducible
+    ///
+    /// The C API has no global random seed: operators drawing random values (`RandomNormal`,
+    /// `RandomUniform`, their `*Like` variants, `Multinomial`, `Bernoulli`, and `Dropout` run in
+    /// training mode) use their `seed` attribute, or the clock when the model leaves it out. With
+    /// a seed, sessions add the attribute to the operators without one when loading the model,
+    /// the n-th of them being seeded with `seed + n`; seeds set by the model are kept.
+    ///
+    /// Models loaded from a file are read once to look for such operators. The ones having some
+    /// are loaded from memory once seeded, their external data files still being looked for in
+    /// the model's directory; the others are loaded from their file as usual.
+    ///
+    /// **NOTE**: Since ONNX can only define one environment per process,
+    /// creating multiple environments using multiple `EnvBuilder` will
+    /// end up re-using the same environment internally; a new one will _not_
+    /// be created. New parameters will be ignored.
+    pub fn with_random_seed(mut self, seed: u32) -> EnvBuilder {
+        self.random_seed = Some(seed);
+        self
+    }
+
     /// Commit the configuration to a new [`Environment`](environment/struct.Environment.html)
     pub fn build(self) -> Result<Environment> {
-        Environment::new(self.name, self.log_level)
+        Environment::new(
+            self.name,
+            self.log_level,
+            self.global_thread_pools.as_ref(),
+            self.random_seed,
+        )
+    }
+}
+
+/// Create the process-wide environment, shared by all the crates of the binary
+///
+/// The first call builds the environment from `options`; later calls, from any thread, return
+/// the same handle and only log a warning if their options differ, since the runtime allows a
+/// single environment per process. The environment stays alive until the process exits.
+///
+/// Applications call `init()` early, before any library creates sessions; libraries call
+/// [`Environment::global()`](crate::environment::Environment::global) and fall back to
+/// `init()` with their own defaults if the application did not.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, LoggingLevel};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // In the application
+/// onnxruntime::init(Environment::builder().with_log_level(LoggingLevel::Info))?;
+///
+/// // In a library
+/// let environment = match Environment::global() {
+///     Some(environment) => environment,
+///     None => onnxruntime::init(Environment::builder().with_name("my-library"))?,
+/// };
+/// let session_builder = environment.new_session_builder()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn init(options: EnvBuilder) -> Result<Arc<Environment>> {
+    let mut shared = G_SHARED_ENV
+        .lock()
+        .expect("Failed to acquire lock: another thread panicked?");
+    match &*shared {
+        Some((environment, initial_options)) => {
+            if *initial_options != options {
+                warn!(
+                    initial = format!("{:?}", initial_options).as_str(),
+                    ignored = format!("{:?}", options).as_str(),
+                    "Environment already initialized with other options, ignoring them.",
+                );
+            }
+            Ok(environment.clone())
+        }
+        None => {
+            let environment = Arc::new(options.clone().build()?);
+            *shared = Some((environment.clone(), options));
+            Ok(environment)
+        }
+    }
+}
+
+/// Configuration of the global thread pools shared by an environment's sessions
+///
+/// Passed to [`EnvBuilder::with_global_thread_pools()`](struct.EnvBuilder.html#method.with_global_thread_pools).
+/// Settings left out keep the runtime's defaults.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::environment::{Environment, ThreadingOptions};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let environment = Environment::builder()
+///     .with_global_thread_pools(
+///         ThreadingOptions::default()
+///             .with_intra_op_threads(8)
+///             .with_spinning(false)
+///             .with_denormal_as_zero(true),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadingOptions {
+    intra_op_threads: Option<usize>,
+    inter_op_threads: Option<usize>,
+    spinning: Option<bool>,
+    denormal_as_zero: bool,
+    intra_op_thread_affinity: Option<String>,
+}
+
+impl ThreadingOptions {
+    /// Number of threads of the intra-op pool, which parallelizes the computation of a node
+    ///
+    /// Defaults to the number of physical cores.
+    pub fn with_intra_op_threads(mut self, threads: usize) -> ThreadingOptions {
+        self.intra_op_threads = Some(threads);
+        self
+    }
+
+    /// Number of threads of the inter-op pool, which runs independent nodes in parallel
+    ///
+    /// Only used by sessions executing their graph in parallel mode.
+    pub fn with_inter_op_threads(mut self, threads: usize) -> ThreadingOptions {
+        self.inter_op_threads = Some(threads);
+        self
+    }
+
+    /// Whether idle pool threads spin for a while before sleeping
+    ///
+    /// Spinning lowers latency at the cost of CPU usage; disable it when sharing the machine
+    /// with other work. Defaults to `true`.
+    pub fn with_spinning(mut self, spinning: bool) -> ThreadingOptions {
+        self.spinning = Some(spinning);
+        self
+    }
+
+    /// Flush denormal floats to zero (FTZ and DAZ) on the pool threads
+    ///
+    /// Denormal arithmetic is very slow on most CPUs; flushing it trades a tiny bit of
+    /// precision for predictable performance. Defaults to `false`.
+    pub fn with_denormal_as_zero(mut self, denormal_as_zero: bool) -> ThreadingOptions {
+        self.denormal_as_zero = denormal_as_zero;
+        self
+    }
+
+    /// Pin the intra-op threads to logical processors
+    ///
+    /// Uses the runtime's syntax: one entry per thread other than the calling one, separated
+    /// by `;`, each being a comma separated list or a `first-last` range of 1-based processor
+    /// ids, for example `"1,2;3-4"`.
+    pub fn with_intra_op_thread_affinity<S>(mut self, affinity: S) -> ThreadingOptions
+    where
+        S: Into<String>,
+    {
+        self.intra_op_thread_affinity = Some(affinity.into());
+        self
+    }
+}
+
+/// Runtime's threading options, released once the environment is created
+#[derive(Debug)]
+struct OrtThreadingOptions {
+    ptr: *mut sys::OrtThreadingOptions,
+}
+
+impl OrtThreadingOptions {
+    fn new(options: &ThreadingOptions) -> Result<OrtThreadingOptions> {
+        let mut ptr: *mut sys::OrtThreadingOptions = std::ptr::null_mut();
+        let status = unsafe { ort_api!(CreateThreadingOptions)?(&mut ptr) };
+        status_to_result(status).map_err(OrtError::ThreadingOptions)?;
+        let tp_options = OrtThreadingOptions { ptr };
+
+        let status = |status| status_to_result(status).map_err(OrtError::ThreadingOptions);
+        if let Some(threads) = options.intra_op_threads {
+            status(unsafe {
+                ort_api!(SetGlobalIntraOpNumThreads)?(ptr, threads as std::os::raw::c_int)
+            })?;
+        }
+        if let Some(threads) = options.inter_op_threads {
+            status(unsafe {
+                ort_api!(SetGlobalInterOpNumThreads)?(ptr, threads as std::os::raw::c_int)
+            })?;
+        }
+        if let Some(spinning) = options.spinning {
+            status(unsafe {
+                ort_api!(SetGlobalSpinControl)?(ptr, spinning as std::os::raw::c_int)
+            })?;
+        }
+        if options.denormal_as_zero {
+            status(unsafe { ort_api!(SetGlobalDenormalAsZero)?(ptr) })?;
+        }
+        if let Some(affinity) = &options.intra_op_thread_affinity {
+            let affinity = CString::new(affinity.as_str())?;
+            status(unsafe { ort_api!(SetGlobalIntraOpThreadAffinity)?(ptr, affinity.as_ptr()) })?;
+        }
+
+        Ok(tp_options)
+    }
+}
+
+impl Drop for OrtThreadingOptions {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseThreadingOptions) {
+            unsafe { release(self.ptr) };
+        }
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use std::sync::{RwLock, RwLockWriteGuard};
     use test_log::test;
@@ -259,12 +548,13 @@ mod tests {
         }
     }
 
-    struct ConcurrentTestRun {
+    /// Lock of the tests creating an environment, which is a singleton
+    pub(crate) struct ConcurrentTestRun {
         lock: Arc<RwLock<()>>,
     }
 
     lazy_static! {
-        static ref CONCURRENT_TEST_RUN: ConcurrentTestRun = ConcurrentTestRun {
+        pub(crate) static ref CONCURRENT_TEST_RUN: ConcurrentTestRun = ConcurrentTestRun {
             lock: Arc::new(RwLock::new(()))
         };
     }
@@ -273,7 +563,7 @@ mod tests {
         // fn run(&self) -> std::sync::RwLockReadGuard<()> {
         //     self.lock.read().unwrap()
         // }
-        fn single_test_run(&self) -> RwLockWriteGuard<()> {
+        pub(crate) fn single_test_run(&self) -> RwLockWriteGuard<()> {
             self.lock.write().unwrap()
         }
     }
@@ -325,7 +615,8 @@ mod tests {
         let _concurrent_run_lock_guard = CONCURRENT_TEST_RUN.single_test_run();
 
         let initial_name = String::from("concurrent_environment_creation");
-        let main_env = Environment::new(initial_name.clone(), LoggingLevel::Warning).unwrap();
+        let main_env =
+            Environment::new(initial_name.clone(), LoggingLevel::Warning, None, None).unwrap();
         let main_env_ptr = main_env.env_ptr() as usize;
 
         let children = (0..10).map(|t| {
@@ -6,7 +6,7 @@ use thiserror::Error;
 
 use onnxruntime_sys as sys;
 
-use crate::{char_p_to_string, g_ort};
+use crate::{char_p_to_string, TensorElementDataType};
 
 /// Type alias for the `Result`
 pub type Result<T> = std::result::Result<T, OrtError>;
@@ -23,12 +23,18 @@ pub enum OrtError {
     /// An error occurred when creating an ONNX environment
     #[error("Failed to create environment: {0}")]
     Environment(OrtApiError),
+    /// Error occurred when configuring the environment's global thread pools
+    #[error("Failed to configure global thread pools: {0}")]
+    ThreadingOptions(OrtApiError),
     /// Error occurred when creating an ONNX session options
     #[error("Failed to create session options: {0}")]
     SessionOptions(OrtApiError),
     /// Error occurred when creating an ONNX session
     #[error("Failed to create session: {0}")]
     Session(OrtApiError),
+    /// The session builder's settings conflict with each other
+    #[error("Invalid session configuration: {0}")]
+    InvalidConfiguration(String),
     /// Error occurred when creating an ONNX allocator
     #[error("Failed to get allocator: {0}")]
     Allocator(OrtApiError),
@@ -38,12 +44,38 @@ pub enum OrtError {
     /// Error occurred when getting ONNX input name
     #[error("Failed to get input name: {0}")]
     InputName(OrtApiError),
+    /// The runtime is older than the bindings and lacks a function of its C API
+    #[error("{function} is not supported by the runtime: it requires onnxruntime {min_version} or later")]
+    UnsupportedInRuntime {
+        /// Name of the function in the C API
+        function: &'static str,
+        /// First version of the runtime providing the function, for example `1.15`
+        min_version: String,
+    },
+    /// A callback invoked by the runtime panicked; the panic was stopped before unwinding into
+    /// the runtime, which is undefined behavior
+    #[error("Callback {callback} panicked: {message}")]
+    CallbackPanicked {
+        /// Callback which panicked, for example `logger`
+        callback: &'static str,
+        /// Panic message, if it was a string
+        message: String,
+    },
+    /// Error occurred when listing the execution providers the runtime was built with
+    #[error("Failed to get available providers: {0}")]
+    AvailableProviders(OrtApiError),
     /// Error occurred when getting ONNX type information
     #[error("Failed to get type info: {0}")]
     GetTypeInfo(OrtApiError),
     /// Error occurred when casting ONNX type information to tensor information
     #[error("Failed to cast type info to tensor info: {0}")]
     CastTypeInfoToTensorInfo(OrtApiError),
+    /// Error occurred when getting the kind of ONNX type (tensor, sequence, map...)
+    #[error("Failed to get ONNX type: {0}")]
+    GetOnnxType(OrtApiError),
+    /// Error occurred when reading the type information of a sequence, map or optional
+    #[error("Failed to get contained type info: {0}")]
+    ContainedTypeInfo(OrtApiError),
     /// Error occurred when getting tensor elements type
     #[error("Failed to get tensor element type: {0}")]
     TensorElementType(OrtApiError),
@@ -53,15 +85,30 @@ pub enum OrtError {
     /// Error occurred when getting ONNX dimensions
     #[error("Failed to get dimensions: {0}")]
     GetDimensions(OrtApiError),
+    /// Error occurred when getting the number of elements of a tensor
+    #[error("Failed to get tensor element count: {0}")]
+    GetTensorShapeElementCount(OrtApiError),
     /// Error occurred when creating CPU memory information
     #[error("Failed to get dimensions: {0}")]
     CreateCpuMemoryInfo(OrtApiError),
+    /// Error occurred when creating memory information for a named allocator
+    #[error("Failed to create memory information: {0}")]
+    CreateMemoryInfo(OrtApiError),
+    /// Error occurred when querying memory information
+    #[error("Failed to get memory information: {0}")]
+    GetMemoryInfo(OrtApiError),
     /// Error occurred when creating ONNX tensor
     #[error("Failed to create tensor: {0}")]
     CreateTensor(OrtApiError),
     /// Error occurred when creating ONNX tensor with specific data
     #[error("Failed to create tensor with data: {0}")]
     CreateTensorWithData(OrtApiError),
+    /// Error occurred when creating a sequence or a map
+    #[error("Failed to create sequence or map: {0}")]
+    CreateValue(OrtApiError),
+    /// Error occurred when reading the elements of a sequence or map
+    #[error("Failed to get the elements of a sequence or map: {0}")]
+    GetValue(OrtApiError),
     /// Error occurred when filling a tensor with string data
     #[error("Failed to fill string tensor: {0}")]
     FillStringTensor(OrtApiError),
@@ -74,6 +121,56 @@ pub enum OrtError {
     /// Error occurred when ONNX inference operation was called
     #[error("Failed to run: {0}")]
     Run(OrtApiError),
+    /// Error occurred when binding inputs or outputs to a session
+    #[error("Failed to bind inputs or outputs: {0}")]
+    IoBinding(OrtApiError),
+    /// A run failed, for a session built with
+    /// [`SessionBuilder::with_input_summary_on_error()`](../session/struct.SessionBuilder.html#method.with_input_summary_on_error)
+    #[error("{source} (inputs: {})", inputs.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    RunWithInputs {
+        /// Error of the run
+        source: Box<OrtError>,
+        /// Inputs given to the run
+        inputs: Vec<crate::session::InputSummary>,
+    },
+    /// A run whose logs were captured failed (see
+    /// [`RunOptions::with_log_capture()`](../session/struct.RunOptions.html#method.with_log_capture))
+    #[error("{source} ({} log messages captured)", logs.len())]
+    RunWithLogs {
+        /// Error of the run
+        source: Box<OrtError>,
+        /// Messages logged by the runtime during the run
+        logs: Vec<crate::session::LogMessage>,
+    },
+    /// Error occurred when creating or configuring run options
+    #[error("Failed to create or configure run options: {0}")]
+    RunOptions(OrtApiError),
+    /// An output holds `NaN` or infinite values, detected by a
+    /// [`NonFiniteCheck`](../session/struct.NonFiniteCheck.html)
+    #[error("Output {output:?} holds {nan_count} NaN and {infinite_count} infinite values")]
+    NonFiniteOutput {
+        /// Name of the model output
+        output: String,
+        /// Number of `NaN` values
+        nan_count: usize,
+        /// Number of infinite values
+        infinite_count: usize,
+    },
+    /// The run was terminated because its deadline passed
+    #[error("Deadline exceeded")]
+    DeadlineExceeded,
+    /// The blocking task running inference was cancelled, because its runtime is shutting down
+    #[error("Inference task cancelled")]
+    BlockingTaskCancelled,
+    /// A run is estimated to need more GPU memory than the session's budget (see
+    /// [`SessionBuilder::with_gpu_memory_budget()`](../session/struct.SessionBuilder.html#method.with_gpu_memory_budget))
+    #[error("Run needs an estimated {estimated_bytes} bytes of GPU memory, over the session's budget of {budget_bytes} bytes")]
+    GpuMemoryBudgetExceeded {
+        /// Estimated memory needed by the run, weights included
+        estimated_bytes: usize,
+        /// Budget of the session
+        budget_bytes: usize,
+    },
     /// Error occurred when extracting data from an ONNX tensor into an C array to be used as an `ndarray::ArrayView`
     #[error("Failed to get tensor data: {0}")]
     GetTensorMutableData(OrtApiError),
@@ -115,6 +212,144 @@ pub enum OrtError {
     /// Error occurred when checking if ONNX tensor was properly initialized
     #[error("Failed to check if tensor")]
     IsTensorCheck,
+    /// The element type requested when extracting a tensor differs from the tensor's actual type
+    #[error("Requested tensor element type {requested:?} but tensor contains {actual:?}")]
+    NonMatchingTensorElementType {
+        /// Element type requested by the caller
+        requested: sys::ONNXTensorElementDataType,
+        /// Element type of the tensor returned by the runtime
+        actual: sys::ONNXTensorElementDataType,
+    },
+    /// The value is not of the type requested when reading it (a tensor, a sequence or a map)
+    #[error("Requested a {requested} but the value is a {actual:?}")]
+    NonMatchingValueType {
+        /// Type requested by the caller
+        requested: &'static str,
+        /// Type of the value
+        actual: sys::ONNXType,
+    },
+    /// The tensor's data is not contiguous in row-major order, so it cannot be viewed as a slice
+    #[error("Tensor data is not contiguous")]
+    NonContiguousTensor,
+    /// String tensors cannot point to borrowed data; they must be copied into the runtime
+    #[error("String tensors cannot be created over borrowed data")]
+    BorrowedStringTensor,
+    /// String tensors cannot be extracted as raw tensors or from dynamically typed outputs
+    #[error("String tensors cannot be extracted as raw tensors or from dynamically typed outputs")]
+    RawStringTensor,
+    /// Error occurred when creating a tensor over a memory-mapped file
+    #[cfg(feature = "mmap")]
+    #[error("Failed to create memory-mapped tensor: {0}")]
+    MmapTensor(#[from] MmapTensorError),
+    /// The process was forked after the environment was created, and the runtime cannot be used
+    /// in the child (see the `fork-guard` feature)
+    #[error("The runtime cannot be used in a process forked after creating the environment: create the environment in the child process instead")]
+    ForkedProcess,
+    /// The model has no output with the given name
+    #[error("Model has no output named {name:?}")]
+    UnknownOutput {
+        /// Name of the requested output
+        name: String,
+    },
+    /// A session option or a named input refers to an input the model does not have
+    #[error("Model has no input named {name:?}")]
+    UnknownInput {
+        /// Name given to the session builder or the run
+        name: String,
+    },
+    /// Named inputs of a run lack one of the model's inputs
+    #[error("Model input {name:?} was not given")]
+    MissingInput {
+        /// Name of the model's input
+        name: String,
+    },
+    /// Named inputs of a run give the same input twice
+    #[error("Model input {name:?} was given more than once")]
+    DuplicateInput {
+        /// Name of the model's input
+        name: String,
+    },
+    /// Error occurred when appending an execution provider to the session options
+    #[error("Failed to append execution provider: {0}")]
+    ExecutionProvider(OrtApiError),
+    /// Error occurred when getting the profiling start time
+    #[error("Failed to get profiling start time: {0}")]
+    ProfilingStartTime(OrtApiError),
+    /// Error occurred when ending profiling
+    #[error("Failed to end profiling: {0}")]
+    EndProfiling(OrtApiError),
+    /// Profiling was ended while the session was not profiling
+    #[error("Profiling is not enabled for this session")]
+    ProfilingNotEnabled,
+    /// Error occurred when summarizing a profiling trace
+    #[cfg(feature = "profiling")]
+    #[error("Failed to summarize profiling trace: {0}")]
+    Profile(#[from] ProfileError),
+    /// The runtime rejected the model for a reason it was possible to identify
+    ///
+    /// Other session creation failures are reported as [`OrtError::Session`](#variant.Session).
+    #[error("Failed to load model: {0}")]
+    ModelLoad(ModelLoadError),
+    /// Fixed-shape tensors do not fit the model or the data they are built from
+    #[error("Fixed shape mismatch: {0}")]
+    FixedShape(#[from] FixedShapeError),
+    /// An inference input could not be converted to the element type expected by the model
+    #[error("Failed to convert input: {0}")]
+    InputCoercion(#[from] InputCoercionError),
+    /// An inference input breaks one of the sanity rules configured for it
+    #[error("Invalid input: {0}")]
+    InputGuard(#[from] InputGuardError),
+    /// The pre-processing step of a [`Model`](../model/struct.Model.html) failed
+    #[cfg(feature = "ndarray")]
+    #[error("Pre-processing failed: {0}")]
+    Preprocessing(crate::model::ProcessingError),
+    /// The post-processing step of a [`Model`](../model/struct.Model.html) failed
+    #[cfg(feature = "ndarray")]
+    #[error("Post-processing failed: {0}")]
+    Postprocessing(crate::model::ProcessingError),
+    /// The float32 and float16 versions of a model cannot be compared
+    #[error("Cannot compare float32 and float16 models: {0}")]
+    MixedPrecisionModel(String),
+    /// The outputs of two sessions cannot be compared
+    #[error("Cannot compare the sessions' outputs: {0}")]
+    OutputComparison(String),
+    /// Error occurred when reading a model file, to hash or verify it
+    #[error("Failed to read model: {0}")]
+    ReadModel(#[source] io::Error),
+    /// The model cannot be parsed to relocate its external data
+    #[error("Failed to relocate external data: {0}")]
+    ExternalData(String),
+    /// Model refused by the verifier given to
+    /// [`SessionBuilder::with_model_verification()`](../session/struct.SessionBuilder.html#method.with_model_verification)
+    #[error("Model verification failed: {0}")]
+    ModelVerification(crate::verification::VerificationError),
+    /// Error occurred when reading or decompressing a compressed model
+    #[cfg(any(feature = "gzip", feature = "zstd"))]
+    #[error("Failed to decompress model: {0}")]
+    DecompressModel(#[source] io::Error),
+    /// Error occurred when saving or loading a tensor in the NumPy formats
+    #[cfg(feature = "npy")]
+    #[error("Failed to save or load NumPy array: {0}")]
+    Npy(#[from] NpyError),
+    /// Error occurred when saving a job's progress or writing its outputs
+    #[error("Bulk inference job failed: {0}")]
+    Job(#[from] JobError),
+    /// Error occurred when scanning a [`ModelRepository`](../model_repository/struct.ModelRepository.html)
+    /// or looking up one of its models
+    #[error("Model repository error: {0}")]
+    Repository(#[from] RepositoryError),
+    /// Error occurred when reading the metadata of an ORT format model
+    #[cfg(feature = "ort-format")]
+    #[error("Failed to read ORT format model: {0}")]
+    OrtFormat(#[from] OrtFormatError),
+    /// Error occurred when encoding or decoding KServe v2 requests and responses
+    #[cfg(feature = "kserve")]
+    #[error("Failed to encode or decode KServe v2 tensors: {0}")]
+    KServe(#[from] KServeError),
+    /// Error occurred when serving an inference request
+    #[cfg(feature = "serving")]
+    #[error("Failed to serve inference request: {0}")]
+    Serving(#[from] ServingError),
 }
 
 /// Error used when dimensions of input (from model and from inference call)
@@ -134,6 +369,14 @@ pub enum NonMatchingDimensionsError {
         /// Input dimensions defined in model
         model_input: Vec<Vec<Option<u32>>>,
     },
+    /// Number of output buffers given does not match the number of outputs of the model
+    #[error("Non-matching number of outputs: {given} buffers given vs {model} outputs for model")]
+    OutputsCount {
+        /// Number of output buffers given to the inference call
+        given: usize,
+        /// Number of outputs defined in model
+        model: usize,
+    },
     /// Inputs length from model does not match the expected input from inference call
     #[error("Different input lengths: Expected Input: {model_input:?} vs Received Input: {inference_input:?}")]
     InputsLength {
@@ -142,6 +385,417 @@ pub enum NonMatchingDimensionsError {
         /// Input dimensions defined in model
         model_input: Vec<Vec<Option<u32>>>,
     },
+    /// The batch dimension of an input exceeds the session's maximum batch size
+    #[error("Batch of {batch_size} exceeds the maximum batch size of {max_batch_size}")]
+    BatchTooLarge {
+        /// First dimension of the input
+        batch_size: usize,
+        /// Maximum set with [`SessionBuilder::with_max_batch_size()`](../session/struct.SessionBuilder.html#method.with_max_batch_size)
+        max_batch_size: usize,
+    },
+    /// Inputs split into batches do not have the same batch (first) dimension
+    #[error("Inputs have differing batch sizes: {batch_sizes:?}")]
+    BatchSizes {
+        /// First dimension of each input
+        batch_sizes: Vec<usize>,
+    },
+    /// An output tensor does not have the shape it was expected to have when extracted
+    #[error("Output tensor has shape {actual:?} instead of {expected:?}")]
+    OutputShape {
+        /// Shape the output was extracted with
+        expected: Vec<usize>,
+        /// Shape reported by the runtime
+        actual: Vec<usize>,
+    },
+}
+
+/// Error used when a shape known at compile time does not fit
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum FixedShapeError {
+    /// The model does not have as many inputs as the fixed-shape session
+    #[error("Model has {model} inputs, fixed-shape session expects {expected}")]
+    InputsCount {
+        /// Number of inputs of the model
+        model: usize,
+        /// Number of inputs of the fixed-shape session
+        expected: usize,
+    },
+    /// A model input or output is not compatible with the static element type and shape
+    #[error("Model input or output {name:?} ({element_type:?}, {dimensions:?}) does not fit {expected_type:?} tensors of shape {expected_shape:?}")]
+    Model {
+        /// Name of the model input or output
+        name: String,
+        /// Element type declared by the model
+        element_type: TensorElementDataType,
+        /// Dimensions declared by the model (`None` for dynamic ones)
+        dimensions: Vec<Option<u32>>,
+        /// Static element type
+        expected_type: TensorElementDataType,
+        /// Static shape
+        expected_shape: Vec<usize>,
+    },
+    /// Data (or a model output) does not have the static shape
+    #[error("Shape {shape:?} does not match static shape {expected_shape:?}")]
+    Shape {
+        /// Actual shape
+        shape: Vec<usize>,
+        /// Static shape
+        expected_shape: Vec<usize>,
+    },
+}
+
+/// Error used when an input's element type differs from the model's and cannot be converted
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum InputCoercionError {
+    /// No conversion exists between the two element types
+    #[error("Input {input:?} cannot be converted from {from:?} to {to:?}")]
+    Unsupported {
+        /// Name of the model input
+        input: String,
+        /// Element type of the inference call's array
+        from: TensorElementDataType,
+        /// Element type expected by the model
+        to: TensorElementDataType,
+    },
+    /// A value would not be represented exactly and lossy conversions were not allowed
+    #[error("Input {input:?} cannot be converted from {from:?} to {to:?} without loss (element {index})")]
+    Lossy {
+        /// Name of the model input
+        input: String,
+        /// Element type of the inference call's array
+        from: TensorElementDataType,
+        /// Element type expected by the model
+        to: TensorElementDataType,
+        /// Position of the first offending element, in row-major order
+        index: usize,
+    },
+}
+
+/// Error used when an input breaks one of the rules of its
+/// [`InputGuard`](../session/struct.InputGuard.html)
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum InputGuardError {
+    /// A value is `NaN` or infinite
+    #[error("Input {input:?} holds the non-finite value {value} (element {index})")]
+    NonFinite {
+        /// Name of the model input
+        input: String,
+        /// Position of the first offending element, in row-major order
+        index: usize,
+        /// Offending value
+        value: f64,
+    },
+    /// A value is outside of the allowed range
+    #[error(
+        "Input {input:?} holds the value {value}, outside of [{min}, {max}] (element {index})"
+    )]
+    OutOfRange {
+        /// Name of the model input
+        input: String,
+        /// Position of the first offending element, in row-major order
+        index: usize,
+        /// Offending value
+        value: f64,
+        /// Smallest allowed value
+        min: f64,
+        /// Largest allowed value
+        max: f64,
+    },
+    /// The input has no elements
+    #[error("Input {input:?} is empty")]
+    Empty {
+        /// Name of the model input
+        input: String,
+    },
+}
+
+/// Reason why the runtime could not load a model, parsed from its error message
+///
+/// Every variant keeps the runtime's full message, since the parsed fields only cover the
+/// parts of it that could be identified.
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum ModelLoadError {
+    /// The model uses an opset version the runtime does not support
+    #[error("Unsupported opset: {message}")]
+    UnsupportedOpset {
+        /// Opset domain (`ai.onnx` for the default domain), if present in the message
+        domain: Option<String>,
+        /// Opset version used by the model, if present in the message
+        version: Option<i64>,
+        /// Message returned by the runtime
+        message: String,
+    },
+    /// An operator of the model is neither built in nor provided by a registered custom op library
+    #[error("Missing operator {op_type:?}: {message}")]
+    MissingOperator {
+        /// Type of the operator, for example `GPT2Tokenizer`
+        op_type: String,
+        /// Domain of the operator, if present in the message (empty for the default domain)
+        domain: Option<String>,
+        /// Message returned by the runtime
+        message: String,
+    },
+    /// The model file is not a valid ONNX protobuf (corrupted, truncated or not a model at all)
+    #[error("Invalid model protobuf: {message}")]
+    InvalidProtobuf {
+        /// Message returned by the runtime
+        message: String,
+    },
+}
+
+impl ModelLoadError {
+    /// Identify the failure described by a `CreateSession()` error message
+    fn parse(message: &str) -> Option<ModelLoadError> {
+        let owned = || message.to_owned();
+
+        if message.to_lowercase().contains("protobuf parsing failed") {
+            return Some(ModelLoadError::InvalidProtobuf { message: owned() });
+        }
+
+        // "Fatal error: com.example:MyOp(-1) is not a registered function/op"
+        if let Some(op) = text_between(message, "Fatal error: ", " is not a registered function/op")
+        {
+            let op = op.split('(').next().unwrap_or(op);
+            let (domain, op_type) = match op.rfind(':') {
+                Some(colon) => (Some(op[..colon].to_owned()), &op[colon + 1..]),
+                None => (Some(String::new()), op),
+            };
+            return Some(ModelLoadError::MissingOperator {
+                op_type: op_type.to_owned(),
+                domain,
+                message: owned(),
+            });
+        }
+        // "Could not find an implementation for MyOp(13) node with name 'node'"
+        // "No Op registered for MyOp with domain_version of 13"
+        if let Some(op) = text_between(message, "Could not find an implementation for ", "(")
+            .or_else(|| text_between(message, "No Op registered for ", " with domain_version"))
+        {
+            return Some(ModelLoadError::MissingOperator {
+                op_type: op.to_owned(),
+                domain: None,
+                message: owned(),
+            });
+        }
+
+        // "Opset 21 is under development [...] Current official support for domain ai.onnx is till opset 20."
+        if let Some(version) = text_between(message, "Opset ", " is under development") {
+            return Some(ModelLoadError::UnsupportedOpset {
+                domain: text_between(message, "support for domain ", " is till").map(str::to_owned),
+                version: version.parse().ok(),
+                message: owned(),
+            });
+        }
+        // "[...] stamped with opset version 7 or above for opset domain 'ai.onnx'. [...] this opset 6 model [...]"
+        if let Some(domain) = text_between(message, "or above for opset domain '", "'") {
+            return Some(ModelLoadError::UnsupportedOpset {
+                domain: Some(domain.to_owned()),
+                version: text_between(message, "this opset ", " model")
+                    .and_then(|v| v.parse().ok()),
+                message: owned(),
+            });
+        }
+
+        None
+    }
+}
+
+/// Text found between the first occurrence of `start` and the following occurrence of `end`
+pub(crate) fn text_between<'m>(message: &'m str, start: &str, end: &str) -> Option<&'m str> {
+    let from = message.find(start)? + start.len();
+    let len = message[from..].find(end)?;
+    Some(&message[from..from + len])
+}
+
+/// Error from creating a tensor over a memory-mapped file region
+#[cfg(feature = "mmap")]
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum MmapTensorError {
+    /// Generic input/output error while opening or mapping the file
+    #[error("Error mapping file: {0}")]
+    IoError(#[from] io::Error),
+    /// The mapped region is too small to hold the requested shape
+    #[error("Mapped region of {available} bytes (after offset) cannot hold {required} bytes")]
+    RegionTooSmall {
+        /// Number of bytes needed by the tensor's shape and element type
+        required: usize,
+        /// Number of bytes available in the mapping after the offset
+        available: usize,
+    },
+    /// The tensor data does not start at an address suitably aligned for its element type
+    #[error("Tensor data at offset {offset} is not aligned to {alignment} bytes")]
+    Misaligned {
+        /// Offset of the tensor data inside the mapping
+        offset: usize,
+        /// Alignment required by the element type
+        alignment: usize,
+    },
+}
+
+/// Error from saving or loading tensors in NumPy's `.npy` and `.npz` formats
+#[cfg(feature = "npy")]
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum NpyError {
+    /// Generic input/output error while reading or writing the array
+    #[error("Error reading or writing array: {0}")]
+    IoError(#[from] io::Error),
+    /// Error from the `.npz` zip archive
+    #[error("Error in npz archive: {0}")]
+    ZipError(#[from] zip::result::ZipError),
+    /// The `.npy` header is malformed or uses an unsupported version
+    #[error("Invalid npy header: {0}")]
+    InvalidHeader(String),
+    /// The array's shape does not fit in a version 1.0 header
+    #[error("Array shape is too large for an npy header")]
+    HeaderTooLarge,
+    /// The stored element type is not the requested one
+    #[error("Array of type {descr:?} cannot be read as {requested:?}")]
+    NonMatchingElementType {
+        /// NumPy type string found in the file
+        descr: String,
+        /// NumPy type string of the requested element type
+        requested: &'static str,
+    },
+}
+
+/// Error from reading a profiling trace
+#[cfg(feature = "profiling")]
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum ProfileError {
+    /// Generic input/output error while reading the trace
+    #[error("Error reading trace: {0}")]
+    IoError(#[from] io::Error),
+    /// The trace is not valid JSON
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+    /// The trace is valid JSON, but not in the expected format
+    #[error("Unexpected trace format: {0}")]
+    UnexpectedFormat(&'static str),
+}
+
+/// Error from a bulk inference [`Job`](../dataset/struct.Job.html)
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum JobError {
+    /// Generic input/output error while writing outputs or a checkpoint
+    #[error("Error writing outputs or checkpoint: {0}")]
+    IoError(#[from] io::Error),
+    /// The checkpoint file exists but cannot be parsed
+    #[error("Invalid checkpoint {path:?}: {message}")]
+    InvalidCheckpoint {
+        /// Path of the checkpoint file
+        path: PathBuf,
+        /// Description of the problem
+        message: String,
+    },
+    /// Error writing a Parquet file
+    #[cfg(feature = "parquet")]
+    #[error("Error writing Parquet file: {0}")]
+    Parquet(#[from] parquet::errors::ParquetError),
+    /// The outputs do not match the ones the sink was created for
+    #[error("Unexpected outputs: {0}")]
+    UnexpectedOutputs(String),
+    /// The output sink cannot resume from the checkpoint's offset
+    #[error("Output sink cannot resume from offset {offset}: {message}")]
+    SinkResume {
+        /// Offset recorded by the checkpoint
+        offset: u64,
+        /// Description of the problem
+        message: String,
+    },
+}
+
+/// Error from a [`ModelRepository`](../model_repository/struct.ModelRepository.html)
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum RepositoryError {
+    /// Error reading a directory or file of the repository
+    #[error("Error reading {path:?}: {source}")]
+    IoError {
+        /// Path of the directory or file
+        path: PathBuf,
+        /// Underlying error
+        source: io::Error,
+    },
+    /// A model's configuration file cannot be parsed
+    #[error("Invalid model configuration {path:?}: {message}")]
+    InvalidConfig {
+        /// Path of the configuration file
+        path: PathBuf,
+        /// Description of the problem
+        message: String,
+    },
+    /// No model is loaded with the requested name and version
+    #[error("Unknown model {name:?}{}", version.map(|v| format!(" version {}", v)).unwrap_or_default())]
+    UnknownModel {
+        /// Name of the model
+        name: String,
+        /// Version of the model, if one was requested
+        version: Option<u64>,
+    },
+}
+
+/// Error from the [`ort_format`](../ort_format/index.html) module
+#[cfg(feature = "ort-format")]
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum OrtFormatError {
+    /// The bytes do not have the `ORTM` file identifier of ORT format models
+    #[error("Not an ORT format model")]
+    NotOrtFormat,
+    /// The flatbuffer does not match the ORT format schema (corrupted or truncated file)
+    #[error("Invalid flatbuffer: {0}")]
+    InvalidFlatbuffer(flatbuffers::InvalidFlatbuffer),
+    /// The file has no model, only session state
+    #[error("No model in the file")]
+    MissingModel,
+}
+
+/// Error from the [`kserve`](../kserve/index.html) module
+#[cfg(feature = "kserve")]
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum KServeError {
+    /// The JSON document is not valid JSON, or not a valid request or response
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The element type has no equivalent in the protocol, or is not a number
+    #[error("Unsupported datatype {0}")]
+    UnsupportedDatatype(String),
+    /// The elements of a tensor do not match its type or its shape
+    #[error("Invalid tensor: {0}")]
+    InvalidTensor(String),
+    /// The binary data of the body does not match the sizes declared by its tensors
+    #[error("Invalid binary data: {0}")]
+    BinaryData(String),
+}
+
+/// Error from the [`serving`](../serving/index.html) module
+#[cfg(feature = "serving")]
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum ServingError {
+    /// The request does not match the model
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    /// The model cannot be served
+    #[error("Unsupported model: {0}")]
+    UnsupportedModel(String),
+    /// No model is served with the requested name and version
+    #[error("Unknown model {name:?}{}", version.as_ref().map(|v| format!(" version {:?}", v)).unwrap_or_default())]
+    UnknownModel {
+        /// Name of the model
+        name: String,
+        /// Version of the model, if one was requested
+        version: Option<String>,
+    },
 }
 
 /// Error details when ONNX C API fail
@@ -208,7 +862,11 @@ impl From<OrtStatusWrapper> for std::result::Result<(), OrtApiError> {
         if status.0.is_null() {
             Ok(())
         } else {
-            let raw: *const i8 = unsafe { g_ort().GetErrorMessage.unwrap()(status.0) };
+            let get_error_message = match ort_api!(GetErrorMessage) {
+                Ok(get_error_message) => get_error_message,
+                Err(err) => return Err(OrtApiError::Msg(err.to_string())),
+            };
+            let raw: *const i8 = unsafe { get_error_message(status.0) };
             match char_p_to_string(raw) {
                 Ok(msg) => Err(OrtApiError::Msg(msg)),
                 Err(err) => match err {
@@ -229,10 +887,106 @@ pub(crate) fn status_to_result(
     status_wrapper.into()
 }
 
-/// A wrapper around a function on OrtApi that maps the status code into [OrtApiError]
-pub(crate) unsafe fn call_ort<F>(mut f: F) -> std::result::Result<(), OrtApiError>
+/// Run `callback`, called by the runtime through a C function pointer, stopping its panics
+///
+/// Panics must not unwind across the C boundary: they would abort the process (or worse, with
+/// older compilers). They are converted to [`OrtError::CallbackPanicked`] instead, for the
+/// caller to report to the runtime or log.
+pub(crate) fn catch_callback_panic<R, F>(name: &'static str, callback: F) -> Result<R>
 where
-    F: FnMut(sys::OrtApi) -> *const sys::OrtStatus,
+    F: FnOnce() -> R,
 {
-    status_to_result(f(g_ort()))
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)).map_err(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            (*message).to_owned()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "<non-string panic payload>".to_owned()
+        };
+        OrtError::CallbackPanicked {
+            callback: name,
+            message,
+        }
+    })
+}
+
+/// Convert a `CreateSession()` failure to an [`OrtError`], identifying model loading errors when possible
+pub(crate) fn session_creation_error(error: OrtApiError) -> OrtError {
+    match &error {
+        OrtApiError::Msg(message) => match ModelLoadError::parse(message) {
+            Some(model_load_error) => OrtError::ModelLoad(model_load_error),
+            None => OrtError::Session(error),
+        },
+        OrtApiError::IntoStringError(_) => OrtError::Session(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn callback_panics_are_caught() {
+        assert_eq!(catch_callback_panic("test", || 42).unwrap(), 42);
+        match catch_callback_panic("test", || panic!("boom {}", 1)) {
+            Err(OrtError::CallbackPanicked { callback, message }) => {
+                assert_eq!(callback, "test");
+                assert_eq!(message, "boom 1");
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_protobuf() {
+        let error =
+            ModelLoadError::parse("Load model from model.onnx failed:Protobuf parsing failed.");
+        assert!(matches!(
+            error,
+            Some(ModelLoadError::InvalidProtobuf { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_missing_custom_op() {
+        let error = ModelLoadError::parse("Load model from model.onnx failed:Fatal error: ai.onnx.contrib:GPT2Tokenizer(-1) is not a registered function/op");
+        match error {
+            Some(ModelLoadError::MissingOperator {
+                op_type, domain, ..
+            }) => {
+                assert_eq!(op_type, "GPT2Tokenizer");
+                assert_eq!(domain.as_deref(), Some("ai.onnx.contrib"));
+            }
+            other => panic!("Unexpected {:?}", other),
+        }
+
+        let error = ModelLoadError::parse(
+            "Could not find an implementation for MyOp(13) node with name 'my_node'",
+        );
+        assert!(matches!(
+            error,
+            Some(ModelLoadError::MissingOperator { op_type, domain: None, .. }) if op_type == "MyOp"
+        ));
+    }
+
+    #[test]
+    fn parse_unsupported_opset() {
+        let error = ModelLoadError::parse("Load model from model.onnx failed:Opset 21 is under development and support for this is limited. Current official support for domain ai.onnx is till opset 19.");
+        match error {
+            Some(ModelLoadError::UnsupportedOpset {
+                domain, version, ..
+            }) => {
+                assert_eq!(domain.as_deref(), Some("ai.onnx"));
+                assert_eq!(version, Some(21));
+            }
+            other => panic!("Unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_unknown_message() {
+        assert!(ModelLoadError::parse("Something else went wrong").is_none());
+    }
 }
@@ -0,0 +1,804 @@
+//! Module containing the execution providers a session can run on
+//!
+//! Pass them to [`SessionBuilder::with_execution_provider()`](../session/struct.SessionBuilder.html#method.with_execution_provider).
+//! The runtime library must have been built with the corresponding provider, otherwise
+//! appending it fails.
+//!
+//! GPU providers accept the queue or stream the application already uses, so inference
+//! is ordered with the application's own work on the device without extra synchronization.
+//!
+//! When the target machines vary, an [`ExecutionProviderChain`](struct.ExecutionProviderChain.html)
+//! tries several configurations in turn until one of them works.
+//!
+//! Out-of-tree providers shipped as shared libraries cannot be registered yet: the runtime
+//! registers such plugins on the environment (`RegisterExecutionProviderLibrary()`) from
+//! version 1.22 of its API, while these bindings target version 1.15.
+
+use std::{ffi::CString, fmt, os::raw::c_void};
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{status_to_result, OrtError, Result},
+    session::Session,
+};
+
+/// Execution provider that can be appended to a session's options
+///
+/// This trait is sealed: it is implemented for the providers of this module only.
+pub trait ExecutionProvider: private::Sealed {
+    /// Name of the provider, as used by the runtime (for example `CUDAExecutionProvider`)
+    fn name(&self) -> &'static str;
+
+    /// Options the provider was configured with, as provider option names and values
+    ///
+    /// Recorded in [`SessionConfig`](../session/struct.SessionConfig.html). Pointers, such as a
+    /// user compute stream, are only recorded as being set.
+    fn options(&self) -> Vec<(&'static str, String)>;
+
+    #[doc(hidden)]
+    fn append_to(&self, session_options: private::SessionOptions) -> Result<()>;
+
+    /// Same provider, limited to `budget` bytes of device memory unless it was already limited
+    ///
+    /// `None` for providers without such a limit.
+    #[doc(hidden)]
+    fn with_memory_budget(&self, _budget: usize) -> Option<Box<dyn ExecutionProvider>> {
+        None
+    }
+}
+
+pub(crate) mod private {
+    use onnxruntime_sys as sys;
+
+    pub trait Sealed {}
+
+    /// Options the provider is appended to, only obtainable within the crate
+    #[derive(Debug, Clone, Copy)]
+    pub struct SessionOptions(pub(crate) *mut sys::OrtSessionOptions);
+}
+
+/// NVIDIA CUDA execution provider
+#[derive(Debug, Clone)]
+pub struct CudaExecutionProvider {
+    device_id: i32,
+    user_compute_stream: *mut c_void,
+    conv_algo_search: CudnnConvAlgoSearch,
+    use_tf32: Option<bool>,
+    gpu_mem_limit: Option<usize>,
+    arena_extend_strategy: Option<ArenaExtendStrategy>,
+}
+
+/// How a device memory arena grows when it runs out of memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArenaExtendStrategy {
+    /// Allocate a chunk twice as large as the previous one (the runtime's default), which may
+    /// reserve much more memory than needed
+    NextPowerOfTwo,
+    /// Allocate exactly the memory requested
+    SameAsRequested,
+}
+
+impl ArenaExtendStrategy {
+    /// Value of the `arena_extend_strategy` provider option
+    fn option_value(self) -> &'static str {
+        match self {
+            ArenaExtendStrategy::NextPowerOfTwo => "kNextPowerOfTwo",
+            ArenaExtendStrategy::SameAsRequested => "kSameAsRequested",
+        }
+    }
+}
+
+impl From<ArenaExtendStrategy> for std::os::raw::c_int {
+    fn from(val: ArenaExtendStrategy) -> Self {
+        match val {
+            ArenaExtendStrategy::NextPowerOfTwo => 0,
+            ArenaExtendStrategy::SameAsRequested => 1,
+        }
+    }
+}
+
+/// How the CUDA provider picks the cuDNN algorithm of convolutions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CudnnConvAlgoSearch {
+    /// Benchmark the available algorithms on the first run of each input shape, keeping the
+    /// fastest one
+    ///
+    /// The algorithm picked may differ between processes and devices, and so may the outputs.
+    Exhaustive,
+    /// Use cuDNN's heuristics (`cudnnGetConvolutionForwardAlgorithm_v7`), without benchmarking
+    Heuristic,
+    /// Always use `CUDNN_CONVOLUTION_FWD_ALGO_IMPLICIT_PRECOMP_GEMM`, for reproducible outputs
+    Default,
+}
+
+impl CudnnConvAlgoSearch {
+    /// Value of the `cudnn_conv_algo_search` provider option
+    fn option_value(self) -> &'static str {
+        match self {
+            CudnnConvAlgoSearch::Exhaustive => "EXHAUSTIVE",
+            CudnnConvAlgoSearch::Heuristic => "HEURISTIC",
+            CudnnConvAlgoSearch::Default => "DEFAULT",
+        }
+    }
+}
+
+impl From<CudnnConvAlgoSearch> for sys::OrtCudnnConvAlgoSearch {
+    fn from(val: CudnnConvAlgoSearch) -> Self {
+        match val {
+            CudnnConvAlgoSearch::Exhaustive => {
+                sys::OrtCudnnConvAlgoSearch::OrtCudnnConvAlgoSearchExhaustive
+            }
+            CudnnConvAlgoSearch::Heuristic => {
+                sys::OrtCudnnConvAlgoSearch::OrtCudnnConvAlgoSearchHeuristic
+            }
+            CudnnConvAlgoSearch::Default => {
+                sys::OrtCudnnConvAlgoSearch::OrtCudnnConvAlgoSearchDefault
+            }
+        }
+    }
+}
+
+impl CudaExecutionProvider {
+    /// Run on the CUDA device with the given id
+    pub fn new(device_id: i32) -> CudaExecutionProvider {
+        CudaExecutionProvider {
+            device_id,
+            user_compute_stream: std::ptr::null_mut(),
+            conv_algo_search: CudnnConvAlgoSearch::Exhaustive,
+            use_tf32: None,
+            gpu_mem_limit: None,
+            arena_extend_strategy: None,
+        }
+    }
+
+    /// Limit the size of the provider's memory arena to `bytes` (the `gpu_mem_limit` provider option)
+    ///
+    /// Runs needing more memory fail instead of taking the whole device. Weights count towards
+    /// the limit, as well as activations.
+    pub fn with_gpu_mem_limit(mut self, bytes: usize) -> CudaExecutionProvider {
+        self.gpu_mem_limit = Some(bytes);
+        self
+    }
+
+    /// Set how the provider's memory arena grows
+    ///
+    /// Defaults to [`ArenaExtendStrategy::NextPowerOfTwo`](enum.ArenaExtendStrategy.html#variant.NextPowerOfTwo).
+    pub fn with_arena_extend_strategy(
+        mut self,
+        strategy: ArenaExtendStrategy,
+    ) -> CudaExecutionProvider {
+        self.arena_extend_strategy = Some(strategy);
+        self
+    }
+
+    /// Allow or forbid TF32 tensor core kernels (the `use_tf32` provider option)
+    ///
+    /// On Ampere and later GPUs, the runtime lets cuBLAS and cuDNN run float32 matrix
+    /// multiplications and convolutions on tensor cores, rounding their operands to 10 bits of
+    /// mantissa. This is much faster, but outputs then differ from a float32 CPU baseline well
+    /// beyond the usual float32 rounding errors. Use
+    /// [`compare_sessions()`](../mixed_precision/fn.compare_sessions.html) to measure the
+    /// differences on representative inputs before choosing.
+    ///
+    /// The option is only known to runtimes from version 1.18: with older ones, appending the
+    /// provider fails. For those, the `NVIDIA_TF32_OVERRIDE=0` environment variable, set before
+    /// the process starts, disables TF32 kernels globally.
+    ///
+    /// Defaults to the runtime's default, TF32 kernels being allowed.
+    pub fn with_tf32(mut self, use_tf32: bool) -> CudaExecutionProvider {
+        self.use_tf32 = Some(use_tf32);
+        self
+    }
+
+    /// Append the provider with string options, as needed for the options missing from
+    /// `OrtCUDAProviderOptions`
+    fn append_v2(&self, session_options: private::SessionOptions, use_tf32: bool) -> Result<()> {
+        let mut entries = vec![
+            ("device_id", self.device_id.to_string()),
+            (
+                "cudnn_conv_algo_search",
+                self.conv_algo_search.option_value().to_owned(),
+            ),
+            ("do_copy_in_default_stream", "1".to_owned()),
+            ("use_tf32", (use_tf32 as i32).to_string()),
+        ];
+        if let Some(gpu_mem_limit) = self.gpu_mem_limit {
+            entries.push(("gpu_mem_limit", gpu_mem_limit.to_string()));
+        }
+        if let Some(strategy) = self.arena_extend_strategy {
+            entries.push(("arena_extend_strategy", strategy.option_value().to_owned()));
+        }
+        if !self.user_compute_stream.is_null() {
+            entries.push(("has_user_compute_stream", "1".to_owned()));
+            entries.push((
+                "user_compute_stream",
+                (self.user_compute_stream as usize).to_string(),
+            ));
+        }
+        let keys = entries
+            .iter()
+            .map(|(key, _)| CString::new(*key))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let values = entries
+            .iter()
+            .map(|(_, value)| CString::new(value.as_str()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let key_ptrs: Vec<_> = keys.iter().map(|key| key.as_ptr()).collect();
+        let value_ptrs: Vec<_> = values.iter().map(|value| value.as_ptr()).collect();
+
+        let mut options_ptr: *mut sys::OrtCUDAProviderOptionsV2 = std::ptr::null_mut();
+        let status = unsafe { ort_api!(CreateCUDAProviderOptions)?(&mut options_ptr) };
+        status_to_result(status).map_err(OrtError::ExecutionProvider)?;
+        let options = CudaProviderOptionsV2(options_ptr);
+
+        let status = unsafe {
+            ort_api!(UpdateCUDAProviderOptions)?(
+                options.0,
+                key_ptrs.as_ptr(),
+                value_ptrs.as_ptr(),
+                key_ptrs.len(),
+            )
+        };
+        status_to_result(status).map_err(OrtError::ExecutionProvider)?;
+        let status = unsafe {
+            ort_api!(SessionOptionsAppendExecutionProvider_CUDA_V2)?(session_options.0, options.0)
+        };
+        status_to_result(status).map_err(OrtError::ExecutionProvider)
+    }
+
+    /// Set how the algorithm of convolutions is picked
+    ///
+    /// Defaults to [`CudnnConvAlgoSearch::Exhaustive`](enum.CudnnConvAlgoSearch.html#variant.Exhaustive).
+    pub fn with_conv_algo_search(mut self, search: CudnnConvAlgoSearch) -> CudaExecutionProvider {
+        self.conv_algo_search = search;
+        self
+    }
+
+    /// Run on an existing CUDA stream (`cudaStream_t`) instead of one created by the runtime
+    ///
+    /// # Safety
+    ///
+    /// `stream` must be a valid stream of the selected device, and outlive the session.
+    pub unsafe fn with_user_compute_stream(mut self, stream: *mut c_void) -> CudaExecutionProvider {
+        self.user_compute_stream = stream;
+        self
+    }
+}
+
+impl private::Sealed for CudaExecutionProvider {}
+
+impl ExecutionProvider for CudaExecutionProvider {
+    fn name(&self) -> &'static str {
+        "CUDAExecutionProvider"
+    }
+
+    fn options(&self) -> Vec<(&'static str, String)> {
+        let mut options = vec![
+            ("device_id", self.device_id.to_string()),
+            (
+                "cudnn_conv_algo_search",
+                self.conv_algo_search.option_value().to_owned(),
+            ),
+        ];
+        if let Some(use_tf32) = self.use_tf32 {
+            options.push(("use_tf32", (use_tf32 as i32).to_string()));
+        }
+        if let Some(gpu_mem_limit) = self.gpu_mem_limit {
+            options.push(("gpu_mem_limit", gpu_mem_limit.to_string()));
+        }
+        if let Some(strategy) = self.arena_extend_strategy {
+            options.push(("arena_extend_strategy", strategy.option_value().to_owned()));
+        }
+        if !self.user_compute_stream.is_null() {
+            options.push(("has_user_compute_stream", "1".to_owned()));
+        }
+        options
+    }
+
+    fn append_to(&self, session_options: private::SessionOptions) -> Result<()> {
+        if let Some(use_tf32) = self.use_tf32 {
+            return self.append_v2(session_options, use_tf32);
+        }
+
+        let options = sys::OrtCUDAProviderOptions {
+            device_id: self.device_id,
+            cudnn_conv_algo_search: self.conv_algo_search.into(),
+            gpu_mem_limit: self.gpu_mem_limit.unwrap_or(usize::MAX),
+            arena_extend_strategy: self
+                .arena_extend_strategy
+                .unwrap_or(ArenaExtendStrategy::NextPowerOfTwo)
+                .into(),
+            do_copy_in_default_stream: 1,
+            has_user_compute_stream: !self.user_compute_stream.is_null() as i32,
+            user_compute_stream: self.user_compute_stream,
+            default_memory_arena_cfg: std::ptr::null_mut(),
+            tunable_op_enable: 0,
+            tunable_op_tuning_enable: 0,
+        };
+        let status = unsafe {
+            ort_api!(SessionOptionsAppendExecutionProvider_CUDA)?(session_options.0, &options)
+        };
+        status_to_result(status).map_err(OrtError::ExecutionProvider)
+    }
+
+    fn with_memory_budget(&self, budget: usize) -> Option<Box<dyn ExecutionProvider>> {
+        if self.gpu_mem_limit.is_some() {
+            return None;
+        }
+        let mut provider = self.clone();
+        provider.gpu_mem_limit = Some(budget);
+        // Growing by powers of two could overshoot the budget by half of it
+        provider.arena_extend_strategy = provider
+            .arena_extend_strategy
+            .or(Some(ArenaExtendStrategy::SameAsRequested));
+        Some(Box::new(provider))
+    }
+}
+
+/// Options of the CUDA provider, released when dropped
+struct CudaProviderOptionsV2(*mut sys::OrtCUDAProviderOptionsV2);
+
+impl Drop for CudaProviderOptionsV2 {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseCUDAProviderOptions) {
+            unsafe { release(self.0) };
+        }
+    }
+}
+
+/// AMD ROCm execution provider
+#[derive(Debug, Clone)]
+pub struct RocmExecutionProvider {
+    device_id: i32,
+    user_compute_stream: *mut c_void,
+}
+
+impl RocmExecutionProvider {
+    /// Run on the ROCm device with the given id
+    pub fn new(device_id: i32) -> RocmExecutionProvider {
+        RocmExecutionProvider {
+            device_id,
+            user_compute_stream: std::ptr::null_mut(),
+        }
+    }
+
+    /// Run on an existing HIP stream (`hipStream_t`) instead of one created by the runtime
+    ///
+    /// # Safety
+    ///
+    /// `stream` must be a valid stream of the selected device, and outlive the session.
+    pub unsafe fn with_user_compute_stream(mut self, stream: *mut c_void) -> RocmExecutionProvider {
+        self.user_compute_stream = stream;
+        self
+    }
+}
+
+impl private::Sealed for RocmExecutionProvider {}
+
+impl ExecutionProvider for RocmExecutionProvider {
+    fn name(&self) -> &'static str {
+        "ROCMExecutionProvider"
+    }
+
+    fn options(&self) -> Vec<(&'static str, String)> {
+        let mut options = vec![("device_id", self.device_id.to_string())];
+        if !self.user_compute_stream.is_null() {
+            options.push(("has_user_compute_stream", "1".to_owned()));
+        }
+        options
+    }
+
+    fn append_to(&self, session_options: private::SessionOptions) -> Result<()> {
+        let options = sys::OrtROCMProviderOptions {
+            device_id: self.device_id,
+            miopen_conv_exhaustive_search: 0,
+            gpu_mem_limit: usize::MAX,
+            arena_extend_strategy: 0,
+            do_copy_in_default_stream: 1,
+            has_user_compute_stream: !self.user_compute_stream.is_null() as i32,
+            user_compute_stream: self.user_compute_stream,
+            default_memory_arena_cfg: std::ptr::null_mut(),
+            tunable_op_enable: 0,
+            tunable_op_tuning_enable: 0,
+        };
+        let status = unsafe {
+            ort_api!(SessionOptionsAppendExecutionProvider_ROCM)?(session_options.0, &options)
+        };
+        status_to_result(status).map_err(OrtError::ExecutionProvider)
+    }
+}
+
+/// Microsoft DirectML execution provider (Windows only)
+///
+/// The DirectML provider does not support memory pattern optimizations nor parallel execution:
+/// the session must be built with
+/// [`with_memory_pattern(false)`](../session/struct.SessionBuilder.html#method.with_memory_pattern)
+/// and sequential execution, otherwise committing it fails with
+/// [`OrtError::InvalidConfiguration`](../error/enum.OrtError.html#variant.InvalidConfiguration).
+#[derive(Debug, Clone)]
+pub struct DirectMLExecutionProvider {
+    device: DirectMLDevice,
+}
+
+#[derive(Debug, Clone)]
+enum DirectMLDevice {
+    Id(i32),
+    CommandQueue {
+        dml_device: *mut c_void,
+        command_queue: *mut c_void,
+    },
+}
+
+impl DirectMLExecutionProvider {
+    /// Run on the adapter with the given id, with a device and queue created by the runtime
+    pub fn new(device_id: i32) -> DirectMLExecutionProvider {
+        DirectMLExecutionProvider {
+            device: DirectMLDevice::Id(device_id),
+        }
+    }
+
+    /// Run on an existing DirectML device (`IDMLDevice`) and command queue (`ID3D12CommandQueue`)
+    ///
+    /// # Safety
+    ///
+    /// Both must be valid COM pointers, the queue being a direct or compute queue of the device
+    /// the DirectML device was created from. The runtime adds its own references to them.
+    pub unsafe fn with_command_queue(
+        dml_device: *mut c_void,
+        command_queue: *mut c_void,
+    ) -> DirectMLExecutionProvider {
+        DirectMLExecutionProvider {
+            device: DirectMLDevice::CommandQueue {
+                dml_device,
+                command_queue,
+            },
+        }
+    }
+}
+
+/// First members of `OrtDmlApi`, from `dml_provider_factory.h`
+#[repr(C)]
+struct OrtDmlApi {
+    session_options_append_execution_provider_dml: Option<
+        extern_system_fn! { unsafe fn(*mut sys::OrtSessionOptions, i32) -> sys::OrtStatusPtr },
+    >,
+    session_options_append_execution_provider_dml1: Option<
+        extern_system_fn! { unsafe fn(*mut sys::OrtSessionOptions, *mut c_void, *mut c_void) -> sys::OrtStatusPtr },
+    >,
+}
+
+impl private::Sealed for DirectMLExecutionProvider {}
+
+impl ExecutionProvider for DirectMLExecutionProvider {
+    fn name(&self) -> &'static str {
+        "DmlExecutionProvider"
+    }
+
+    fn options(&self) -> Vec<(&'static str, String)> {
+        match self.device {
+            DirectMLDevice::Id(device_id) => vec![("device_id", device_id.to_string())],
+            DirectMLDevice::CommandQueue { .. } => vec![("command_queue", "1".to_owned())],
+        }
+    }
+
+    fn append_to(&self, session_options: private::SessionOptions) -> Result<()> {
+        let provider_name = CString::new("DML")?;
+        let mut dml_api_ptr: *const c_void = std::ptr::null();
+        let status = unsafe {
+            ort_api!(GetExecutionProviderApi)?(
+                provider_name.as_ptr(),
+                sys::ORT_API_VERSION,
+                &mut dml_api_ptr,
+            )
+        };
+        status_to_result(status).map_err(OrtError::ExecutionProvider)?;
+        let dml_api = unsafe { (dml_api_ptr as *const OrtDmlApi).as_ref() }
+            .ok_or_else(|| OrtError::PointerShouldNotBeNull("OrtDmlApi".to_owned()))?;
+
+        let status = match self.device {
+            DirectMLDevice::Id(device_id) => unsafe {
+                dml_api
+                    .session_options_append_execution_provider_dml
+                    .unwrap()(session_options.0, device_id)
+            },
+            DirectMLDevice::CommandQueue {
+                dml_device,
+                command_queue,
+            } => unsafe {
+                dml_api
+                    .session_options_append_execution_provider_dml1
+                    .unwrap()(session_options.0, dml_device, command_queue)
+            },
+        };
+        status_to_result(status).map_err(OrtError::ExecutionProvider)
+    }
+}
+
+/// How a provider of an [`ExecutionProviderChain`](struct.ExecutionProviderChain.html) is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderPriority {
+    /// Appended in every attempt; creating the session fails if it cannot be used
+    Required,
+    /// Appended in the first attempts, dropped (in order) when the session cannot be created
+    Preferred,
+    /// Only appended once every preferred provider was dropped
+    Fallback,
+}
+
+/// Session health check, run on every session created by an [`ExecutionProviderChain`](struct.ExecutionProviderChain.html)
+pub type HealthCheck = Box<dyn Fn(&mut Session) -> Result<()> + Send + Sync>;
+
+/// Execution providers tried progressively until a session can be created
+///
+/// Passed to [`SessionBuilder::with_execution_provider_chain()`](../session/struct.SessionBuilder.html#method.with_execution_provider_chain).
+/// Committing the session tries, in turn:
+///
+/// * all the preferred providers, then without the first one, then without the first two, ...
+/// * then all the fallback providers, then without the first one, ...
+/// * and finally none of them, running on the CPU only.
+///
+/// Required providers are appended in every attempt. An attempt fails when a provider cannot be
+/// appended (for example when the runtime was built without it), when the session cannot be
+/// created with it, or when the optional health check fails. Failed attempts are logged; the
+/// providers of the successful one are available from
+/// [`Session::execution_providers()`](../session/struct.Session.html#method.execution_providers).
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, execution_providers::*};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// // Try CUDA on the second GPU, then on the first one, then the CPU
+/// let chain = ExecutionProviderChain::new()
+///     .preferred(CudaExecutionProvider::new(1))
+///     .fallback(CudaExecutionProvider::new(0));
+/// let session = environment
+///     .new_session_builder()?
+///     .with_execution_provider_chain(chain)?
+///     .with_model_from_file("model.onnx")?;
+/// println!("Running on {:?}", session.execution_providers());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ExecutionProviderChain {
+    providers: Vec<(ProviderPriority, Box<dyn ExecutionProvider>)>,
+    health_check: Option<HealthCheck>,
+}
+
+impl fmt::Debug for ExecutionProviderChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let providers: Vec<_> = self
+            .providers
+            .iter()
+            .map(|(priority, provider)| (priority, provider.name()))
+            .collect();
+        f.debug_struct("ExecutionProviderChain")
+            .field("providers", &providers)
+            .field("health_check", &self.health_check.is_some())
+            .finish()
+    }
+}
+
+impl ExecutionProviderChain {
+    /// Empty chain, running on the CPU only
+    pub fn new() -> ExecutionProviderChain {
+        ExecutionProviderChain::default()
+    }
+
+    /// Add a provider with the given priority
+    pub fn with_provider<E>(mut self, priority: ProviderPriority, provider: E) -> Self
+    where
+        E: ExecutionProvider + 'static,
+    {
+        self.providers.push((priority, Box::new(provider)));
+        self
+    }
+
+    /// Add a [required](enum.ProviderPriority.html#variant.Required) provider
+    pub fn required<E>(self, provider: E) -> Self
+    where
+        E: ExecutionProvider + 'static,
+    {
+        self.with_provider(ProviderPriority::Required, provider)
+    }
+
+    /// Add a [preferred](enum.ProviderPriority.html#variant.Preferred) provider
+    pub fn preferred<E>(self, provider: E) -> Self
+    where
+        E: ExecutionProvider + 'static,
+    {
+        self.with_provider(ProviderPriority::Preferred, provider)
+    }
+
+    /// Add a [fallback](enum.ProviderPriority.html#variant.Fallback) provider
+    pub fn fallback<E>(self, provider: E) -> Self
+    where
+        E: ExecutionProvider + 'static,
+    {
+        self.with_provider(ProviderPriority::Fallback, provider)
+    }
+
+    /// Check every created session, for example with a warm-up run, before accepting it
+    pub fn with_health_check<F>(mut self, health_check: F) -> Self
+    where
+        F: Fn(&mut Session) -> Result<()> + Send + Sync + 'static,
+    {
+        self.health_check = Some(Box::new(health_check));
+        self
+    }
+
+    /// Names of all the providers of the chain, whatever their priority
+    pub(crate) fn provider_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.providers.iter().map(|(_, provider)| provider.name())
+    }
+
+    pub(crate) fn health_check(&self) -> Option<&HealthCheck> {
+        self.health_check.as_ref()
+    }
+
+    /// Providers of each attempt, in order, with whether they are required
+    pub(crate) fn attempts(&self) -> Vec<Vec<(bool, &dyn ExecutionProvider)>> {
+        let preferred = self.with_priority(ProviderPriority::Preferred);
+        let fallback = self.with_priority(ProviderPriority::Fallback);
+
+        (0..preferred.len())
+            .map(|skipped| self.attempt(&preferred[skipped..]))
+            .chain((0..=fallback.len()).map(|skipped| self.attempt(&fallback[skipped..])))
+            .collect()
+    }
+
+    /// Indices of the providers with the given priority
+    fn with_priority(&self, priority: ProviderPriority) -> Vec<usize> {
+        (0..self.providers.len())
+            .filter(|&index| self.providers[index].0 == priority)
+            .collect()
+    }
+
+    /// Required providers and the `optional` ones, in the order they were added
+    fn attempt(&self, optional: &[usize]) -> Vec<(bool, &dyn ExecutionProvider)> {
+        self.providers
+            .iter()
+            .enumerate()
+            .filter(|(index, (priority, _))| {
+                *priority == ProviderPriority::Required || optional.contains(index)
+            })
+            .map(|(_, (priority, provider))| {
+                (*priority == ProviderPriority::Required, provider.as_ref())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        environment::{tests::CONCURRENT_TEST_RUN, Environment},
+        session::SharedInitializers,
+        LoggingLevel,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use test_log::test;
+
+    /// Provider appending nothing to the options, so that every attempt creates a session
+    struct NoopProvider(&'static str);
+
+    impl private::Sealed for NoopProvider {}
+
+    impl ExecutionProvider for NoopProvider {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn options(&self) -> Vec<(&'static str, String)> {
+            Vec::new()
+        }
+
+        fn append_to(&self, _session_options: private::SessionOptions) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn names(attempts: Vec<Vec<(bool, &dyn ExecutionProvider)>>) -> Vec<Vec<String>> {
+        attempts
+            .into_iter()
+            .map(|attempt| {
+                attempt
+                    .into_iter()
+                    .map(|(required, provider)| {
+                        format!("{}{}", provider.name(), if required { "!" } else { "" })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chain_attempts() {
+        let chain = ExecutionProviderChain::new()
+            .preferred(RocmExecutionProvider::new(0))
+            .required(DirectMLExecutionProvider::new(0))
+            .preferred(CudaExecutionProvider::new(0))
+            .fallback(CudaExecutionProvider::new(1));
+        assert_eq!(
+            names(chain.attempts()),
+            vec![
+                vec![
+                    "ROCMExecutionProvider",
+                    "DmlExecutionProvider!",
+                    "CUDAExecutionProvider"
+                ],
+                vec!["DmlExecutionProvider!", "CUDAExecutionProvider"],
+                vec!["DmlExecutionProvider!", "CUDAExecutionProvider"],
+                vec!["DmlExecutionProvider!"],
+            ]
+        );
+
+        let chain = ExecutionProviderChain::new();
+        assert_eq!(names(chain.attempts()), vec![Vec::<String>::new()]);
+    }
+
+    #[test]
+    fn health_check_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync + ?Sized>(_: &T) {}
+
+        let health_check: HealthCheck = Box::new(|_| Ok(()));
+        assert_send_sync(&health_check);
+    }
+
+    #[test]
+    fn fallback_after_failed_health_check_keeps_shared_initializers() {
+        let _run_lock = CONCURRENT_TEST_RUN.single_test_run();
+        let environment = Environment::builder()
+            .with_name("fallback_after_failed_health_check")
+            .with_log_level(LoggingLevel::Warning)
+            .build()
+            .unwrap();
+
+        let checks = Arc::new(AtomicUsize::new(0));
+        let chain = ExecutionProviderChain::new()
+            .preferred(NoopProvider("Preferred"))
+            .fallback(NoopProvider("Fallback"))
+            .with_health_check({
+                let checks = Arc::clone(&checks);
+                move |_session| match checks.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(OrtError::InvalidConfiguration("unhealthy".to_owned())),
+                    _ => Ok(()),
+                }
+            });
+        let shared = SharedInitializers::new().with_min_bytes(0);
+        let session = environment
+            .new_session_builder()
+            .unwrap()
+            .with_shared_initializers(shared.clone())
+            .unwrap()
+            .with_execution_provider_chain(chain)
+            .unwrap()
+            .with_model_from_file(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests")
+                    .join("data")
+                    .join("upsample.onnx"),
+            )
+            .unwrap();
+
+        assert_eq!(checks.load(Ordering::SeqCst), 2);
+        assert_eq!(session.execution_providers(), ["Fallback"]);
+        // The values handed to the first session are still held for the second one
+        assert!(shared.usage().0 > 0);
+
+        #[cfg(feature = "ndarray")]
+        {
+            let input = ndarray::Array4::<f32>::zeros((1, 2, 2, 3));
+            let outputs: Vec<crate::tensor::OrtOwnedTensor<f32, _>> = session.run([input]).unwrap();
+            assert_eq!(outputs[0].shape(), [1, 4, 4, 3]);
+        }
+    }
+}
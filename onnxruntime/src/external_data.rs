@@ -0,0 +1,267 @@
+//! Module relocating the external data files referenced by a model
+//!
+//! Initializers stored outside of the model (`onnx.save(..., save_as_external_data=True)`)
+//! reference their file with a `location` relative to the model's directory. To load the model
+//! from elsewhere, the locations are rewritten to point to the directory given to
+//! `SessionBuilder::with_external_data_dir()`, copying the rest of the protobuf as is, so no
+//! protobuf library is required.
+
+use std::path::Path;
+
+use crate::error::{OrtError, Result};
+
+/// Messages leading to `TensorProto`s, the ones holding external data references
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Model,
+    Graph,
+    Node,
+    Attribute,
+    SparseTensor,
+    Tensor,
+    /// `StringStringEntryProto`, a key and value pair of `TensorProto.external_data`
+    ExternalDataEntry,
+}
+
+impl Message {
+    /// Type of the message in field `field`, if it can hold tensors
+    fn child(self, field: u64) -> Option<Message> {
+        use Message::*;
+        match (self, field) {
+            // ModelProto.graph
+            (Model, 7) => Some(Graph),
+            // GraphProto.node, initializer, sparse_initializer
+            (Graph, 1) => Some(Node),
+            (Graph, 5) => Some(Tensor),
+            (Graph, 15) => Some(SparseTensor),
+            // NodeProto.attribute
+            (Node, 5) => Some(Attribute),
+            // AttributeProto.t, g, tensors, graphs, sparse_tensor, sparse_tensors
+            (Attribute, 5) | (Attribute, 10) => Some(Tensor),
+            (Attribute, 6) | (Attribute, 11) => Some(Graph),
+            (Attribute, 22) | (Attribute, 23) => Some(SparseTensor),
+            // SparseTensorProto.values, indices
+            (SparseTensor, 1) | (SparseTensor, 2) => Some(Tensor),
+            // TensorProto.external_data
+            (Tensor, 13) => Some(ExternalDataEntry),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) const WIRE_VARINT: u64 = 0;
+pub(crate) const WIRE_FIXED64: u64 = 1;
+pub(crate) const WIRE_LENGTH_DELIMITED: u64 = 2;
+pub(crate) const WIRE_FIXED32: u64 = 5;
+
+/// Serialized model, with the external data locations joined to `dir`
+pub(crate) fn relocate(model: &[u8], dir: &Path) -> Result<Vec<u8>> {
+    let mut relocated = Vec::with_capacity(model.len());
+    relocate_message(model, Message::Model, dir, &mut relocated)?;
+    Ok(relocated)
+}
+
+fn relocate_message(bytes: &[u8], message: Message, dir: &Path, out: &mut Vec<u8>) -> Result<()> {
+    if message == Message::ExternalDataEntry {
+        return relocate_entry(bytes, dir, out);
+    }
+
+    let mut reader = Reader::new(bytes);
+    while !reader.is_empty() {
+        let start = reader.position;
+        let (field, wire_type) = reader.tag()?;
+        match wire_type {
+            WIRE_VARINT => {
+                reader.varint()?;
+            }
+            WIRE_FIXED64 => {
+                reader.take(8)?;
+            }
+            WIRE_FIXED32 => {
+                reader.take(4)?;
+            }
+            WIRE_LENGTH_DELIMITED => {
+                let payload = reader.length_delimited()?;
+                if let Some(child) = message.child(field) {
+                    let mut relocated = Vec::with_capacity(payload.len());
+                    relocate_message(payload, child, dir, &mut relocated)?;
+                    write_length_delimited(out, field, &relocated);
+                    continue;
+                }
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "unsupported wire type {} at byte {}",
+                    wire_type, start
+                )))
+            }
+        }
+        out.extend_from_slice(&bytes[start..reader.position]);
+    }
+    Ok(())
+}
+
+fn relocate_entry(bytes: &[u8], dir: &Path, out: &mut Vec<u8>) -> Result<()> {
+    let mut key = None;
+    let mut value = None;
+    let mut reader = Reader::new(bytes);
+    while !reader.is_empty() {
+        let (field, wire_type) = reader.tag()?;
+        if wire_type != WIRE_LENGTH_DELIMITED {
+            return Err(invalid(
+                "external data entry with a non-string field".to_owned(),
+            ));
+        }
+        let payload = reader.length_delimited()?;
+        match field {
+            1 => key = Some(payload),
+            2 => value = Some(payload),
+            _ => {}
+        }
+    }
+
+    match (key, value) {
+        (Some(b"location"), Some(location)) => {
+            let location = std::str::from_utf8(location)
+                .map_err(|_| invalid("external data location is not UTF-8".to_owned()))?;
+            let relocated = dir.join(location);
+            let relocated = relocated.to_str().ok_or_else(|| OrtError::NonUtf8Path {
+                path: relocated.clone(),
+            })?;
+            write_length_delimited(out, 1, b"location");
+            write_length_delimited(out, 2, relocated.as_bytes());
+        }
+        _ => out.extend_from_slice(bytes),
+    }
+    Ok(())
+}
+
+pub(crate) fn invalid(message: String) -> OrtError {
+    OrtError::ExternalData(message)
+}
+
+pub(crate) fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+pub(crate) fn write_length_delimited(out: &mut Vec<u8>, field: u64, payload: &[u8]) {
+    write_varint(out, (field << 3) | WIRE_LENGTH_DELIMITED);
+    write_varint(out, payload.len() as u64);
+    out.extend_from_slice(payload);
+}
+
+/// Reader of the fields of a serialized protobuf message
+pub(crate) struct Reader<'b> {
+    bytes: &'b [u8],
+    pub(crate) position: usize,
+}
+
+impl<'b> Reader<'b> {
+    pub(crate) fn new(bytes: &'b [u8]) -> Reader<'b> {
+        Reader { bytes, position: 0 }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    pub(crate) fn varint(&mut self) -> Result<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .bytes
+                .get(self.position)
+                .ok_or_else(|| invalid("truncated varint".to_owned()))?;
+            self.position += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(invalid("varint longer than 10 bytes".to_owned()))
+    }
+
+    pub(crate) fn tag(&mut self) -> Result<(u64, u64)> {
+        let tag = self.varint()?;
+        Ok((tag >> 3, tag & 0x7))
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'b [u8]> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| invalid(format!("truncated field at byte {}", self.position)))?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    pub(crate) fn length_delimited(&mut self) -> Result<&'b [u8]> {
+        let len = self.varint()?;
+        self.take(len as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn message(fields: &[(u64, Vec<u8>)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for (field, payload) in fields {
+            write_length_delimited(&mut out, *field, payload);
+        }
+        out
+    }
+
+    fn tensor(location: &str) -> Vec<u8> {
+        message(&[
+            (8, b"weight".to_vec()),
+            (
+                13,
+                message(&[(1, b"location".to_vec()), (2, location.as_bytes().to_vec())]),
+            ),
+            (
+                13,
+                message(&[(1, b"offset".to_vec()), (2, b"128".to_vec())]),
+            ),
+        ])
+    }
+
+    #[cfg(not(target_family = "windows"))]
+    #[test]
+    fn relocate_external_data() {
+        // A graph with an initializer, and an `If` node whose branch has one too
+        let branch = message(&[(5, tensor("branch.bin"))]);
+        let node = message(&[(5, message(&[(1, b"then_branch".to_vec()), (6, branch)]))]);
+        let graph = message(&[(1, node), (5, tensor("weights.bin"))]);
+        let mut model = vec![0x08, 0x08]; // ir_version: 8
+        model.extend(message(&[(7, graph)]));
+
+        let relocated = relocate(&model, Path::new("/models/weights")).unwrap();
+
+        let expected_branch = message(&[(5, tensor("/models/weights/branch.bin"))]);
+        let expected_node = message(&[(
+            5,
+            message(&[(1, b"then_branch".to_vec()), (6, expected_branch)]),
+        )]);
+        let expected_graph = message(&[
+            (1, expected_node),
+            (5, tensor("/models/weights/weights.bin")),
+        ]);
+        let mut expected = vec![0x08, 0x08];
+        expected.extend(message(&[(7, expected_graph)]));
+        assert_eq!(relocated, expected);
+
+        assert!(matches!(
+            relocate(&model[..model.len() - 1], Path::new("/models")),
+            Err(OrtError::ExternalData(_))
+        ));
+    }
+}
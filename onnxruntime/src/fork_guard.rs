@@ -0,0 +1,84 @@
+//! Module detecting use of the runtime in a process forked after the environment was created
+//!
+//! The runtime is not fork-safe: its thread pools do not survive `fork()`, so a child process
+//! using sessions inherited from its parent hangs waiting for threads which no longer exist.
+//! With the `fork-guard` feature, on Unix, a `pthread_atfork()` handler registered when the
+//! environment is created marks the child as forked; the bindings then fail with
+//! [`OrtError::ForkedProcess`](../error/enum.OrtError.html#variant.ForkedProcess) instead of
+//! calling into the runtime.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::{OrtError, Result};
+
+/// Set in the child process of a `fork()` following the creation of the environment
+static FORKED: AtomicBool = AtomicBool::new(false);
+
+/// Register the handler marking child processes as forked, once per process
+#[cfg(all(unix, feature = "fork-guard"))]
+pub(crate) fn register() {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+
+    REGISTER.call_once(|| {
+        let status = unsafe { libc::pthread_atfork(None, None, Some(mark_forked)) };
+        if status != 0 {
+            tracing::warn!(status, "Failed to register the fork handler.");
+        }
+    });
+}
+
+/// Register the handler marking child processes as forked (no-op without the `fork-guard`
+/// feature, or outside of Unix)
+#[cfg(not(all(unix, feature = "fork-guard")))]
+pub(crate) fn register() {}
+
+/// Handler run in the child process after `fork()`
+///
+/// Only async-signal-safe operations are allowed there: an atomic store is.
+#[cfg(all(unix, feature = "fork-guard"))]
+extern "C" fn mark_forked() {
+    FORKED.store(true, Ordering::SeqCst);
+}
+
+/// Whether this process was forked after the environment was created
+pub(crate) fn is_forked() -> bool {
+    FORKED.load(Ordering::SeqCst)
+}
+
+/// Fail if this process was forked after the environment was created
+pub(crate) fn check() -> Result<()> {
+    if is_forked() {
+        return Err(OrtError::ForkedProcess);
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix, feature = "fork-guard"))]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn child_is_poisoned() {
+        register();
+
+        match unsafe { libc::fork() } {
+            -1 => panic!("fork() failed"),
+            0 => {
+                // Only async-signal-safe calls in the child of a multi-threaded process
+                let code = match check() {
+                    Err(OrtError::ForkedProcess) => 0,
+                    _ => 1,
+                };
+                unsafe { libc::_exit(code) };
+            }
+            child => {
+                let mut status = 0;
+                assert_eq!(unsafe { libc::waitpid(child, &mut status, 0) }, child);
+                assert!(libc::WIFEXITED(status));
+                assert_eq!(libc::WEXITSTATUS(status), 0);
+                assert!(check().is_ok());
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Module reading the initializers (weights) of a serialized model
+//!
+//! Only the initializers of the main graph holding their data in `raw_data` are read, which is
+//! how exporters store weights; the data is borrowed from the model's bytes.
+
+use std::convert::TryFrom;
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::Result,
+    external_data::{
+        invalid, Reader, WIRE_FIXED32, WIRE_FIXED64, WIRE_LENGTH_DELIMITED, WIRE_VARINT,
+    },
+    TensorElementDataType,
+};
+
+/// Initializer of a model, with its data in `raw_data`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Initializer<'m> {
+    pub(crate) name: &'m str,
+    pub(crate) element_type: TensorElementDataType,
+    pub(crate) dims: Vec<i64>,
+    /// Elements, in little-endian order
+    pub(crate) raw_data: &'m [u8],
+}
+
+/// Element types of the initializers read, strings excepted
+const ELEMENT_TYPES: [TensorElementDataType; 12] = [
+    TensorElementDataType::Float,
+    TensorElementDataType::Uint8,
+    TensorElementDataType::Int8,
+    TensorElementDataType::Uint16,
+    TensorElementDataType::Int16,
+    TensorElementDataType::Int32,
+    TensorElementDataType::Int64,
+    TensorElementDataType::Float16,
+    TensorElementDataType::Double,
+    TensorElementDataType::Uint32,
+    TensorElementDataType::Uint64,
+    TensorElementDataType::Bfloat16,
+];
+
+/// Initializers of the main graph of `model` holding their data in `raw_data`
+pub(crate) fn raw_initializers(model: &[u8]) -> Result<Vec<Initializer<'_>>> {
+    let mut initializers = Vec::new();
+    // ModelProto.graph
+    for graph in length_delimited_fields(model, 7)? {
+        // GraphProto.initializer
+        for tensor in length_delimited_fields(graph, 5)? {
+            initializers.extend(raw_initializer(tensor)?);
+        }
+    }
+    Ok(initializers)
+}
+
+/// Payloads of the length-delimited fields `field` of `message`, skipping the others
+fn length_delimited_fields(message: &[u8], field: u64) -> Result<Vec<&[u8]>> {
+    let mut payloads = Vec::new();
+    let mut reader = Reader::new(message);
+    while !reader.is_empty() {
+        let (number, wire_type) = reader.tag()?;
+        match wire_type {
+            WIRE_LENGTH_DELIMITED => {
+                let payload = reader.length_delimited()?;
+                if number == field {
+                    payloads.push(payload);
+                }
+            }
+            _ => skip(&mut reader, wire_type)?,
+        }
+    }
+    Ok(payloads)
+}
+
+/// Initializer of a `TensorProto`, if its data is in `raw_data` and matches its type and dimensions
+fn raw_initializer(tensor: &[u8]) -> Result<Option<Initializer<'_>>> {
+    let mut name = None;
+    let mut data_type = None;
+    let mut dims = Vec::new();
+    let mut raw_data = None;
+    let mut reader = Reader::new(tensor);
+    while !reader.is_empty() {
+        let (field, wire_type) = reader.tag()?;
+        match (field, wire_type) {
+            // TensorProto.dims, unpacked or packed
+            (1, WIRE_VARINT) => dims.push(reader.varint()? as i64),
+            (1, WIRE_LENGTH_DELIMITED) => {
+                let mut packed = Reader::new(reader.length_delimited()?);
+                while !packed.is_empty() {
+                    dims.push(packed.varint()? as i64);
+                }
+            }
+            // TensorProto.data_type
+            (2, WIRE_VARINT) => data_type = Some(reader.varint()?),
+            // TensorProto.name
+            (8, WIRE_LENGTH_DELIMITED) => {
+                name = std::str::from_utf8(reader.length_delimited()?).ok();
+            }
+            // TensorProto.raw_data
+            (9, WIRE_LENGTH_DELIMITED) => raw_data = Some(reader.length_delimited()?),
+            (_, WIRE_LENGTH_DELIMITED) => {
+                reader.length_delimited()?;
+            }
+            _ => skip(&mut reader, wire_type)?,
+        }
+    }
+
+    let element_type = data_type.and_then(|data_type| {
+        ELEMENT_TYPES.iter().copied().find(|element_type| {
+            sys::ONNXTensorElementDataType::from(*element_type) as u64 == data_type
+        })
+    });
+    let (name, element_type, raw_data) = match (name, element_type, raw_data) {
+        (Some(name), Some(element_type), Some(raw_data)) => (name, element_type, raw_data),
+        _ => return Ok(None),
+    };
+    let element_count = dims.iter().try_fold(1_usize, |count, &dim| {
+        count.checked_mul(usize::try_from(dim).ok()?)
+    });
+    let expected_len = element_count.zip(element_type.size_in_bytes());
+    if expected_len.map(|(count, size)| count * size) != Some(raw_data.len()) {
+        return Ok(None);
+    }
+
+    Ok(Some(Initializer {
+        name,
+        element_type,
+        dims,
+        raw_data,
+    }))
+}
+
+fn skip(reader: &mut Reader, wire_type: u64) -> Result<()> {
+    match wire_type {
+        WIRE_VARINT => {
+            reader.varint()?;
+        }
+        WIRE_FIXED64 => {
+            reader.take(8)?;
+        }
+        WIRE_FIXED32 => {
+            reader.take(4)?;
+        }
+        _ => {
+            return Err(invalid(format!(
+                "unsupported wire type {} at byte {}",
+                wire_type, reader.position
+            )))
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_data::{write_length_delimited, write_varint};
+    use test_log::test;
+
+    fn tensor(name: &str, data_type: u64, dims: &[u64], raw_data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut packed_dims = Vec::new();
+        for &dim in dims {
+            write_varint(&mut packed_dims, dim);
+        }
+        write_length_delimited(&mut out, 1, &packed_dims);
+        write_varint(&mut out, 2 << 3);
+        write_varint(&mut out, data_type);
+        write_length_delimited(&mut out, 8, name.as_bytes());
+        write_length_delimited(&mut out, 9, raw_data);
+        out
+    }
+
+    #[test]
+    fn read_raw_initializers() {
+        let weight = [0_u8; 24];
+        let mut graph = Vec::new();
+        write_length_delimited(&mut graph, 5, &tensor("weight", 1, &[2, 3], &weight));
+        // Data not matching the dimensions
+        write_length_delimited(&mut graph, 5, &tensor("bad", 1, &[4], &[0; 3]));
+        write_length_delimited(
+            &mut graph,
+            5,
+            &tensor("ids", 7, &[1], &[1, 0, 0, 0, 0, 0, 0, 0]),
+        );
+        let mut model = vec![0x08, 0x08]; // ir_version: 8
+        write_length_delimited(&mut model, 7, &graph);
+
+        let initializers = raw_initializers(&model).unwrap();
+        assert_eq!(
+            initializers,
+            vec![
+                Initializer {
+                    name: "weight",
+                    element_type: TensorElementDataType::Float,
+                    dims: vec![2, 3],
+                    raw_data: &weight,
+                },
+                Initializer {
+                    name: "ids",
+                    element_type: TensorElementDataType::Int64,
+                    dims: vec![1],
+                    raw_data: &[1, 0, 0, 0, 0, 0, 0, 0],
+                }
+            ]
+        );
+
+        assert!(raw_initializers(&model[..model.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,629 @@
+//! Module containing the tensor wire format of the KServe v2 inference protocol (requires the `kserve` feature)
+//!
+//! The [KServe v2 inference protocol](https://kserve.github.io/website/latest/modelserving/data_plane/v2_protocol/)
+//! is spoken by Triton, KServe, Seldon MLServer and others. Its requests and responses are JSON
+//! documents describing tensors, whose elements are either inlined as JSON arrays or, with the
+//! [binary tensor data extension](https://github.com/triton-inference-server/server/blob/main/docs/protocol/extension_binary_data.md),
+//! appended as raw little-endian bytes after the JSON document. The length of the JSON document is
+//! then sent in the `Inference-Header-Content-Length` HTTP header.
+//!
+//! The types of this module are those documents, serialized with `serde`, and
+//! [`TensorData`](enum.TensorData.html) converts tensors from and to either encoding. They do not
+//! depend on any HTTP library, so they can be used by clients as well as servers (see the
+//! [`serving`](../serving/index.html) module for the latter).
+//!
+//! # Example
+//!
+//! ```
+//! # use onnxruntime::kserve::{InferRequest, InferTensor, TensorData};
+//! # fn main() -> onnxruntime::Result<()> {
+//! let image = TensorData::from(ndarray::ArrayD::<f32>::zeros(vec![1, 3, 2, 2]));
+//! let request = InferRequest::new(vec![InferTensor::binary("image", &image)]);
+//! let (body, header_length) = request.to_bytes()?;
+//!
+//! // On the server
+//! let request = InferRequest::from_bytes(&body, header_length)?;
+//! assert_eq!(request.inputs[0].tensor()?, image);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::convert::{TryFrom, TryInto};
+
+use ndarray::ArrayD;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::{KServeError, Result},
+    TensorElementDataType,
+};
+
+/// Name of the HTTP header holding the length of the JSON document of binary bodies
+pub const HEADER_CONTENT_LENGTH: &str = "Inference-Header-Content-Length";
+
+/// Free-form parameters of requests, responses and tensors
+pub type Parameters = Map<String, Value>;
+
+/// Parameter of tensors holding the length of their binary data
+const BINARY_DATA_SIZE: &str = "binary_data_size";
+
+/// Parameter of requested outputs asking for binary data
+const BINARY_DATA: &str = "binary_data";
+
+/// Body of an inference request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferRequest {
+    /// Identifier of the request, repeated in the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Parameters of the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Parameters>,
+    /// Input tensors
+    pub inputs: Vec<RequestInput>,
+    /// Outputs to return; all of them when `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<Vec<RequestOutput>>,
+}
+
+impl InferRequest {
+    /// Request of `inputs`, for all outputs
+    pub fn new(inputs: Vec<RequestInput>) -> InferRequest {
+        InferRequest {
+            id: None,
+            parameters: None,
+            inputs,
+            outputs: None,
+        }
+    }
+
+    /// Decode a request body, of which the first `header_length` bytes are JSON (all of them if `None`)
+    pub fn from_bytes(body: &[u8], header_length: Option<usize>) -> Result<InferRequest> {
+        let (mut request, mut binary): (InferRequest, _) = split_body(body, header_length)?;
+        for input in &mut request.inputs {
+            input.read_binary_data(&mut binary)?;
+        }
+        check_consumed(binary)?;
+        Ok(request)
+    }
+
+    /// Encode the request as a body and, if some inputs have binary data, the length of its JSON part
+    pub fn to_bytes(&self) -> Result<(Vec<u8>, Option<usize>)> {
+        join_body(self, &self.inputs)
+    }
+}
+
+/// Output requested by an [`InferRequest`](struct.InferRequest.html)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestOutput {
+    /// Name of the model's output
+    pub name: String,
+    /// Parameters of the output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Parameters>,
+}
+
+impl RequestOutput {
+    /// Request output `name` as JSON data
+    pub fn new<S>(name: S) -> RequestOutput
+    where
+        S: Into<String>,
+    {
+        RequestOutput {
+            name: name.into(),
+            parameters: None,
+        }
+    }
+
+    /// Request output `name` as binary data
+    pub fn binary<S>(name: S) -> RequestOutput
+    where
+        S: Into<String>,
+    {
+        let mut parameters = Parameters::new();
+        parameters.insert(BINARY_DATA.to_owned(), Value::Bool(true));
+        RequestOutput {
+            name: name.into(),
+            parameters: Some(parameters),
+        }
+    }
+
+    /// Whether the output is requested as binary data
+    pub fn is_binary(&self) -> bool {
+        self.parameters
+            .as_ref()
+            .and_then(|parameters| parameters.get(BINARY_DATA))
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+    }
+}
+
+/// Body of an inference response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferResponse {
+    /// Name of the model
+    pub model_name: String,
+    /// Version of the model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_version: Option<String>,
+    /// Identifier of the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Parameters of the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Parameters>,
+    /// Output tensors
+    pub outputs: Vec<ResponseOutput>,
+}
+
+impl InferResponse {
+    /// Decode a response body, of which the first `header_length` bytes are JSON (all of them if `None`)
+    pub fn from_bytes(body: &[u8], header_length: Option<usize>) -> Result<InferResponse> {
+        let (mut response, mut binary): (InferResponse, _) = split_body(body, header_length)?;
+        for output in &mut response.outputs {
+            output.read_binary_data(&mut binary)?;
+        }
+        check_consumed(binary)?;
+        Ok(response)
+    }
+
+    /// Encode the response as a body and, if some outputs have binary data, the length of its JSON part
+    pub fn to_bytes(&self) -> Result<(Vec<u8>, Option<usize>)> {
+        join_body(self, &self.outputs)
+    }
+}
+
+/// Input tensor of an [`InferRequest`](struct.InferRequest.html)
+pub type RequestInput = InferTensor;
+
+/// Output tensor of an [`InferResponse`](struct.InferResponse.html)
+pub type ResponseOutput = InferTensor;
+
+/// Named tensor of a request or a response, with its elements as JSON or binary data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferTensor {
+    /// Name of the model's input or output
+    pub name: String,
+    /// Shape of the tensor
+    pub shape: Vec<usize>,
+    /// Element type of the tensor, for example `FP32` (see [`datatype()`](fn.datatype.html))
+    pub datatype: String,
+    /// Parameters of the tensor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Parameters>,
+    /// Elements of the tensor in row-major order, either flat or nested; `null` with binary data
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub data: Value,
+    /// Elements of the tensor as little-endian bytes, sent after the JSON document
+    #[serde(skip)]
+    pub binary_data: Option<Vec<u8>>,
+}
+
+impl InferTensor {
+    /// Tensor `name` with the elements of `tensor` as JSON data
+    pub fn new<S>(name: S, tensor: &TensorData) -> InferTensor
+    where
+        S: Into<String>,
+    {
+        InferTensor {
+            name: name.into(),
+            shape: tensor.shape().to_vec(),
+            datatype: datatype(tensor.element_type()).to_owned(),
+            parameters: None,
+            data: tensor.to_json(),
+            binary_data: None,
+        }
+    }
+
+    /// Tensor `name` with the elements of `tensor` as binary data
+    pub fn binary<S>(name: S, tensor: &TensorData) -> InferTensor
+    where
+        S: Into<String>,
+    {
+        let binary_data = tensor.to_bytes();
+        let mut parameters = Parameters::new();
+        parameters.insert(BINARY_DATA_SIZE.to_owned(), Value::from(binary_data.len()));
+        InferTensor {
+            name: name.into(),
+            shape: tensor.shape().to_vec(),
+            datatype: datatype(tensor.element_type()).to_owned(),
+            parameters: Some(parameters),
+            data: Value::Null,
+            binary_data: Some(binary_data),
+        }
+    }
+
+    /// Element type of the tensor
+    pub fn element_type(&self) -> Result<TensorElementDataType> {
+        element_type(&self.datatype)
+    }
+
+    /// Decode the elements of the tensor, from its binary data if any, or else from its JSON data
+    pub fn tensor(&self) -> Result<TensorData> {
+        let element_type = self.element_type()?;
+        match &self.binary_data {
+            Some(bytes) => TensorData::from_bytes(element_type, &self.shape, bytes),
+            None => TensorData::from_json(element_type, &self.shape, &self.data),
+        }
+    }
+
+    fn binary_data_size(&self) -> Result<Option<usize>> {
+        match self
+            .parameters
+            .as_ref()
+            .and_then(|parameters| parameters.get(BINARY_DATA_SIZE))
+        {
+            None => Ok(None),
+            Some(size) => size
+                .as_u64()
+                .and_then(|size| usize::try_from(size).ok())
+                .map(Some)
+                .ok_or_else(|| {
+                    KServeError::BinaryData(format!(
+                        "invalid {} {} of tensor {:?}",
+                        BINARY_DATA_SIZE, size, self.name
+                    ))
+                    .into()
+                }),
+        }
+    }
+
+    fn read_binary_data(&mut self, binary: &mut &[u8]) -> Result<()> {
+        if let Some(size) = self.binary_data_size()? {
+            if size > binary.len() {
+                return Err(KServeError::BinaryData(format!(
+                    "tensor {:?} has {} bytes of binary data, only {} remain",
+                    self.name,
+                    size,
+                    binary.len()
+                ))
+                .into());
+            }
+            let (data, rest) = binary.split_at(size);
+            self.binary_data = Some(data.to_vec());
+            *binary = rest;
+        }
+        Ok(())
+    }
+}
+
+/// Split `body` into its JSON document, decoded, and its binary data
+fn split_body<T>(body: &[u8], header_length: Option<usize>) -> Result<(T, &[u8])>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let header_length = header_length.unwrap_or(body.len());
+    if header_length > body.len() {
+        return Err(KServeError::BinaryData(format!(
+            "header length {} exceeds body length {}",
+            header_length,
+            body.len()
+        ))
+        .into());
+    }
+    let (header, binary) = body.split_at(header_length);
+    let document = serde_json::from_slice(header).map_err(KServeError::Json)?;
+    Ok((document, binary))
+}
+
+fn check_consumed(binary: &[u8]) -> Result<()> {
+    if binary.is_empty() {
+        Ok(())
+    } else {
+        Err(KServeError::BinaryData(format!(
+            "{} bytes of binary data belong to no tensor",
+            binary.len()
+        ))
+        .into())
+    }
+}
+
+/// Encode `document` as JSON followed by the binary data of `tensors`
+fn join_body<T>(document: &T, tensors: &[InferTensor]) -> Result<(Vec<u8>, Option<usize>)>
+where
+    T: Serialize,
+{
+    let mut body = serde_json::to_vec(document).map_err(KServeError::Json)?;
+    let header_length = body.len();
+    let mut has_binary_data = false;
+    for tensor in tensors {
+        if let Some(data) = &tensor.binary_data {
+            if tensor.binary_data_size()? != Some(data.len()) {
+                return Err(KServeError::BinaryData(format!(
+                    "{} of tensor {:?} does not match its {} bytes of binary data",
+                    BINARY_DATA_SIZE,
+                    tensor.name,
+                    data.len()
+                ))
+                .into());
+            }
+            body.extend_from_slice(data);
+            has_binary_data = true;
+        }
+    }
+    Ok((body, Some(header_length).filter(|_| has_binary_data)))
+}
+
+/// Protocol name of an element type, for example `FP32` for
+/// [`TensorElementDataType::Float`](../enum.TensorElementDataType.html#variant.Float)
+pub fn datatype(element_type: TensorElementDataType) -> &'static str {
+    use TensorElementDataType::*;
+    match element_type {
+        Float => "FP32",
+        Uint8 => "UINT8",
+        Int8 => "INT8",
+        Uint16 => "UINT16",
+        Int16 => "INT16",
+        Int32 => "INT32",
+        Int64 => "INT64",
+        String => "BYTES",
+        Float16 => "FP16",
+        Double => "FP64",
+        Uint32 => "UINT32",
+        Uint64 => "UINT64",
+        Bfloat16 => "BF16",
+    }
+}
+
+/// Element type of a protocol name, the reverse of [`datatype()`](fn.datatype.html)
+///
+/// `BOOL`, the only protocol type without an ONNX Runtime equivalent in this crate, is rejected.
+pub fn element_type(datatype: &str) -> Result<TensorElementDataType> {
+    use TensorElementDataType::*;
+    Ok(match datatype {
+        "FP32" => Float,
+        "UINT8" => Uint8,
+        "INT8" => Int8,
+        "UINT16" => Uint16,
+        "INT16" => Int16,
+        "INT32" => Int32,
+        "INT64" => Int64,
+        "BYTES" => String,
+        "FP16" => Float16,
+        "FP64" => Double,
+        "UINT32" => Uint32,
+        "UINT64" => Uint64,
+        "BF16" => Bfloat16,
+        _ => return Err(KServeError::UnsupportedDatatype(datatype.to_owned()).into()),
+    })
+}
+
+macro_rules! tensor_data {
+    ($($variant:ident($type_:ty) from $from_json:expr),* $(,)?) => {
+        /// Tensor of any of the numeric element types of the protocol
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum TensorData {
+            $(
+                #[allow(missing_docs)]
+                $variant(ArrayD<$type_>),
+            )*
+        }
+
+        impl TensorData {
+            /// Element type of the tensor
+            pub fn element_type(&self) -> TensorElementDataType {
+                match self {
+                    $(TensorData::$variant(_) => TensorElementDataType::$variant,)*
+                }
+            }
+
+            /// Shape of the tensor
+            pub fn shape(&self) -> &[usize] {
+                match self {
+                    $(TensorData::$variant(array) => array.shape(),)*
+                }
+            }
+
+            /// Tensor of type `element_type` and shape `shape` from JSON, flat or nested, data
+            pub fn from_json(
+                element_type: TensorElementDataType,
+                shape: &[usize],
+                data: &Value,
+            ) -> Result<TensorData> {
+                let mut values = Vec::new();
+                flatten(data, &mut values);
+                match element_type {
+                    $(
+                        TensorElementDataType::$variant => {
+                            let from_json: fn(&Value) -> Option<$type_> = $from_json;
+                            let values = values
+                                .into_iter()
+                                .map(|value| {
+                                    from_json(value).ok_or_else(|| {
+                                        KServeError::InvalidTensor(format!(
+                                            "{} is not a valid {}",
+                                            value,
+                                            datatype(element_type)
+                                        ))
+                                        .into()
+                                    })
+                                })
+                                .collect::<Result<Vec<_>>>()?;
+                            Ok(TensorData::$variant(from_shape_vec(shape, values)?))
+                        }
+                    )*
+                    _ => Err(KServeError::UnsupportedDatatype(datatype(element_type).to_owned()).into()),
+                }
+            }
+
+            /// Elements of the tensor as a flat JSON array
+            pub fn to_json(&self) -> Value {
+                match self {
+                    $(TensorData::$variant(array) => array.iter().map(|&value| Value::from(value)).collect(),)*
+                }
+            }
+
+            /// Tensor of type `element_type` and shape `shape` from little-endian bytes
+            pub fn from_bytes(
+                element_type: TensorElementDataType,
+                shape: &[usize],
+                bytes: &[u8],
+            ) -> Result<TensorData> {
+                match element_type {
+                    $(
+                        TensorElementDataType::$variant => {
+                            let chunks = bytes.chunks_exact(std::mem::size_of::<$type_>());
+                            if !chunks.remainder().is_empty() {
+                                return Err(KServeError::InvalidTensor(format!(
+                                    "{} bytes are not a whole number of {}",
+                                    bytes.len(),
+                                    datatype(element_type)
+                                ))
+                                .into());
+                            }
+                            let values = chunks
+                                .map(|chunk| <$type_>::from_le_bytes(chunk.try_into().expect("Chunks have the size of the type")))
+                                .collect();
+                            Ok(TensorData::$variant(from_shape_vec(shape, values)?))
+                        }
+                    )*
+                    _ => Err(KServeError::UnsupportedDatatype(datatype(element_type).to_owned()).into()),
+                }
+            }
+
+            /// Elements of the tensor in row-major order as little-endian bytes
+            pub fn to_bytes(&self) -> Vec<u8> {
+                match self {
+                    $(TensorData::$variant(array) => array.iter().flat_map(|value| value.to_le_bytes()).collect(),)*
+                }
+            }
+        }
+
+        $(
+            impl From<ArrayD<$type_>> for TensorData {
+                fn from(array: ArrayD<$type_>) -> TensorData {
+                    TensorData::$variant(array)
+                }
+            }
+        )*
+    };
+}
+
+tensor_data! {
+    Float(f32) from |value| value.as_f64().map(|value| value as f32),
+    Double(f64) from Value::as_f64,
+    Int8(i8) from |value| value.as_i64().and_then(|value| i8::try_from(value).ok()),
+    Int16(i16) from |value| value.as_i64().and_then(|value| i16::try_from(value).ok()),
+    Int32(i32) from |value| value.as_i64().and_then(|value| i32::try_from(value).ok()),
+    Int64(i64) from Value::as_i64,
+    Uint8(u8) from |value| value.as_u64().and_then(|value| u8::try_from(value).ok()),
+    Uint16(u16) from |value| value.as_u64().and_then(|value| u16::try_from(value).ok()),
+    Uint32(u32) from |value| value.as_u64().and_then(|value| u32::try_from(value).ok()),
+    Uint64(u64) from Value::as_u64,
+}
+
+fn from_shape_vec<T>(shape: &[usize], values: Vec<T>) -> Result<ArrayD<T>> {
+    let len = values.len();
+    ArrayD::from_shape_vec(shape, values).map_err(|_| {
+        KServeError::InvalidTensor(format!("{} elements do not match shape {:?}", len, shape))
+            .into()
+    })
+}
+
+/// Leaves of nested JSON arrays, in order
+fn flatten<'v>(data: &'v Value, values: &mut Vec<&'v Value>) {
+    match data {
+        Value::Array(items) => items.iter().for_each(|item| flatten(item, values)),
+        value => values.push(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::OrtError;
+    use test_log::test;
+
+    #[test]
+    fn tensor_data_json() {
+        let data = serde_json::json!([[1, 2, 3], [4, 5, 6]]);
+        let tensor = TensorData::from_json(TensorElementDataType::Int32, &[2, 3], &data).unwrap();
+        assert_eq!(tensor.shape(), &[2, 3]);
+        assert_eq!(tensor.to_json(), serde_json::json!([1, 2, 3, 4, 5, 6]));
+
+        assert!(TensorData::from_json(
+            TensorElementDataType::Uint8,
+            &[1],
+            &serde_json::json!([256])
+        )
+        .is_err());
+        assert!(TensorData::from_json(TensorElementDataType::Float, &[4], &data).is_err());
+        assert!(matches!(
+            TensorData::from_json(
+                TensorElementDataType::String,
+                &[1],
+                &serde_json::json!(["a"])
+            ),
+            Err(OrtError::KServe(KServeError::UnsupportedDatatype(_)))
+        ));
+    }
+
+    #[test]
+    fn tensor_data_bytes() {
+        let tensor = TensorData::from(
+            ArrayD::from_shape_vec(vec![2, 2], vec![1.5_f32, -2.0, 0.0, 3.25]).unwrap(),
+        );
+        let bytes = tensor.to_bytes();
+        assert_eq!(&bytes[..4], &1.5_f32.to_le_bytes());
+        assert_eq!(
+            TensorData::from_bytes(TensorElementDataType::Float, &[2, 2], &bytes).unwrap(),
+            tensor
+        );
+        assert!(
+            TensorData::from_bytes(TensorElementDataType::Float, &[2, 2], &bytes[1..]).is_err()
+        );
+        assert!(TensorData::from_bytes(TensorElementDataType::Float, &[3], &bytes).is_err());
+    }
+
+    #[test]
+    fn binary_request_body() {
+        let ids = TensorData::from(ArrayD::from_shape_vec(vec![3], vec![7_i64, 8, 9]).unwrap());
+        let mask = TensorData::from(ArrayD::from_shape_vec(vec![3], vec![1_u8, 1, 0]).unwrap());
+        let mut request = InferRequest::new(vec![
+            InferTensor::binary("ids", &ids),
+            InferTensor::new("mask", &mask),
+        ]);
+        request.outputs = Some(vec![RequestOutput::binary("logits")]);
+
+        let (body, header_length) = request.to_bytes().unwrap();
+        let header_length = header_length.unwrap();
+        assert_eq!(body.len(), header_length + 3 * 8);
+        let header: Value = serde_json::from_slice(&body[..header_length]).unwrap();
+        assert_eq!(
+            header["inputs"][0]["parameters"][BINARY_DATA_SIZE],
+            serde_json::json!(24)
+        );
+        assert!(header["inputs"][0].get("data").is_none());
+
+        let decoded = InferRequest::from_bytes(&body, Some(header_length)).unwrap();
+        assert_eq!(decoded, request);
+        assert_eq!(decoded.inputs[0].tensor().unwrap(), ids);
+        assert_eq!(decoded.inputs[1].tensor().unwrap(), mask);
+        assert!(decoded.outputs.unwrap()[0].is_binary());
+
+        assert!(InferRequest::from_bytes(&body[..body.len() - 1], Some(header_length)).is_err());
+        assert!(
+            InferRequest::from_bytes(&[body.as_slice(), &[0]].concat(), Some(header_length))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn json_response_body() {
+        let response: InferResponse = serde_json::from_str(
+            r#"{
+                "model_name": "add",
+                "outputs": [{"name": "sum", "shape": [2], "datatype": "FP64", "data": [0.5, 1.5]}]
+            }"#,
+        )
+        .unwrap();
+        let (body, header_length) = response.to_bytes().unwrap();
+        assert_eq!(header_length, None);
+        let decoded = InferResponse::from_bytes(&body, None).unwrap();
+        assert_eq!(
+            decoded.outputs[0].tensor().unwrap(),
+            TensorData::from(ArrayD::from_shape_vec(vec![2], vec![0.5_f64, 1.5]).unwrap())
+        );
+    }
+}
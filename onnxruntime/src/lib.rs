@@ -86,7 +86,8 @@ to download.
 "##
 )]
 //!
-//! Inference will be run on data passed as an [`ndarray::Array`](https://docs.rs/ndarray/latest/ndarray/type.Array.html).
+//! Inference will be run on data passed as an [`ndarray::Array`](https://docs.rs/ndarray/latest/ndarray/type.Array.html)
+//! (with the `ndarray` feature, enabled by default).
 //!
 //! ```no_run
 //! # use std::error::Error;
@@ -116,7 +117,7 @@ to download.
 //! example for more details.
 
 use std::ffi::CStr;
-use std::sync::{atomic::AtomicPtr, Arc, Mutex};
+use std::fmt;
 
 use lazy_static::lazy_static;
 
@@ -142,18 +143,75 @@ macro_rules! extern_system_fn {
     ($(#[$meta:meta])* $vis:vis unsafe fn $($tt:tt)*) => ($(#[$meta])* $vis unsafe extern "C" fn $($tt)*);
 }
 
+/// Function `$function` of the runtime's C API
+///
+/// Fails with [`OrtError::UnsupportedInRuntime`](error/enum.OrtError.html#variant.UnsupportedInRuntime)
+/// when the runtime is older than the function, instead of panicking.
+macro_rules! ort_api {
+    ($function:ident) => {{
+        // Only the function's own field is read: older runtimes have a shorter `OrtApi`
+        #[allow(unused_unsafe)]
+        let read = |api: *const onnxruntime_sys::OrtApi| unsafe {
+            std::ptr::addr_of!((*api).$function).read()
+        };
+        crate::api_function(
+            stringify!($function),
+            std::mem::offset_of!(onnxruntime_sys::OrtApi, $function),
+            read,
+        )
+    }};
+}
+
+#[cfg(any(feature = "gzip", feature = "zstd"))]
+pub mod compression;
+#[cfg(feature = "ndarray")]
+pub mod dataset;
+#[cfg(feature = "ndarray")]
+pub mod detection;
+pub mod diagnostics;
 pub mod download;
+#[cfg(feature = "ndarray")]
+pub mod embedder;
 pub mod environment;
 pub mod error;
+pub mod execution_providers;
+mod external_data;
+mod fork_guard;
+mod initializers;
+#[cfg(feature = "kserve")]
+pub mod kserve;
+mod log_capture;
 mod memory;
+pub mod memory_report;
+#[cfg(feature = "ndarray")]
+pub mod mixed_precision;
+#[cfg(feature = "ndarray")]
+pub mod model;
+pub mod model_hash;
+pub mod model_repository;
+mod numa;
+#[cfg(feature = "ort-format")]
+pub mod ort_format;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+mod random_seed;
+#[cfg(feature = "ndarray")]
+pub mod segmentation;
+#[cfg(feature = "serving")]
+pub mod serving;
 pub mod session;
 pub mod tensor;
+pub mod type_info;
+pub mod verification;
 
 // Re-export
+pub use environment::init;
 pub use error::{OrtApiError, OrtError, Result};
+pub use memory::MemoryInfo;
 use sys::OnnxEnumInt;
 
 // Re-export ndarray as it's part of the public API anyway
+#[cfg(feature = "ndarray")]
 pub use ndarray;
 
 /// Represents the version of the ONNX runtime
@@ -197,30 +255,86 @@ impl OrtVersion {
 }
 
 lazy_static! {
-    // static ref G_ORT: Arc<Mutex<AtomicPtr<sys::OrtApi>>> =
-    //     Arc::new(Mutex::new(AtomicPtr::new(unsafe {
-    //         sys::OrtGetApiBase().as_ref().unwrap().GetApi.unwrap()(sys::ORT_API_VERSION)
-    //     } as *mut sys::OrtApi)));
-    static ref G_ORT_API: Arc<Mutex<AtomicPtr<sys::OrtApi>>> = {
+    static ref G_ORT_API: OrtApiHandle = {
         let base: *const sys::OrtApiBase = unsafe { sys::OrtGetApiBase() };
         assert_ne!(base, std::ptr::null());
         let get_api: extern_system_fn!{ unsafe fn(u32) -> *const onnxruntime_sys::OrtApi } =
             unsafe { (*base).GetApi.unwrap() };
-        let api: *const sys::OrtApi = unsafe { get_api(sys::ORT_API_VERSION) };
-        Arc::new(Mutex::new(AtomicPtr::new(api as *mut sys::OrtApi)))
+        // A runtime older than the bindings does not provide their version of the API: fall back
+        // to the most recent version it provides, without the functions added since.
+        (1..=sys::ORT_API_VERSION)
+            .rev()
+            .find_map(|version| {
+                let api: *const sys::OrtApi = unsafe { get_api(version) };
+                if api.is_null() {
+                    None
+                } else {
+                    Some(OrtApiHandle { api, version })
+                }
+            })
+            .expect("The runtime does not provide any version of the C API")
     };
 }
 
-fn g_ort() -> sys::OrtApi {
-    let mut api_ref = G_ORT_API
-        .lock()
-        .expect("Failed to acquire lock: another thread panicked?");
-    let api_ref_mut: &mut *mut sys::OrtApi = api_ref.get_mut();
-    let api_ptr_mut: *mut sys::OrtApi = *api_ref_mut;
+/// The runtime's C API, at the most recent version both the runtime and the bindings know
+struct OrtApiHandle {
+    api: *const sys::OrtApi,
+    version: u32,
+}
 
-    assert_ne!(api_ptr_mut, std::ptr::null_mut());
+// The API is an immutable table of function pointers
+unsafe impl Send for OrtApiHandle {}
+unsafe impl Sync for OrtApiHandle {}
 
-    unsafe { *api_ptr_mut }
+/// Index in `OrtApi` of the last function of each version of the C API
+///
+/// The API only grows by appending functions, version 1.x of the runtime providing version x.
+/// Version 7 added no function.
+const API_VERSIONS_LAST_FUNCTION: [(u32, usize); 15] = [
+    (1, 101),
+    (2, 118),
+    (3, 126),
+    (4, 149),
+    (5, 157),
+    (6, 161),
+    (7, 161),
+    (8, 169),
+    (9, 191),
+    (10, 203),
+    (11, 209),
+    (12, 218),
+    (13, 224),
+    (14, 238),
+    (15, 254),
+];
+
+/// Version of the C API the function at byte `offset` in `OrtApi` was added in
+fn api_version_of(offset: usize) -> u32 {
+    let index = offset / std::mem::size_of::<*const ()>();
+    API_VERSIONS_LAST_FUNCTION
+        .iter()
+        .find(|(_, last)| index <= *last)
+        .map(|(version, _)| *version)
+        .unwrap_or(sys::ORT_API_VERSION)
+}
+
+/// Function of the runtime's C API at byte `offset` in `OrtApi`, read with `read`
+///
+/// Use the `ort_api!()` macro instead.
+fn api_function<F>(
+    function: &'static str,
+    offset: usize,
+    read: impl FnOnce(*const sys::OrtApi) -> Option<F>,
+) -> Result<F> {
+    let min_version = api_version_of(offset);
+    let unsupported = || OrtError::UnsupportedInRuntime {
+        function,
+        min_version: format!("1.{}", min_version),
+    };
+    if G_ORT_API.version < min_version {
+        return Err(unsupported());
+    }
+    read(G_ORT_API.api).ok_or_else(unsupported)
 }
 
 fn char_p_to_string(raw: *const i8) -> Result<String> {
@@ -273,6 +387,9 @@ mod onnxruntime {
 
     extern_system_fn! {
         /// Callback from C that will handle the logging, forwarding the runtime's logs to the tracing crate.
+        ///
+        /// Panics (for example from a `tracing` subscriber) are stopped here and reported on
+        /// stderr, since the logging may be what panicked.
         pub(crate) fn custom_logger(
             _params: *mut std::ffi::c_void,
             severity: sys::OrtLoggingLevel,
@@ -281,53 +398,77 @@ mod onnxruntime {
             code_location: *const i8,
             message: *const i8,
         ) {
-            let log_level = match severity {
-                sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_VERBOSE => Level::TRACE,
-                sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_INFO => Level::DEBUG,
-                sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_WARNING => Level::INFO,
-                sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_ERROR => Level::WARN,
-                sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_FATAL => Level::ERROR,
-            };
-
-            assert_ne!(category, std::ptr::null());
-            let category = unsafe { CStr::from_ptr(category) };
-            assert_ne!(code_location, std::ptr::null());
-            let code_location = unsafe { CStr::from_ptr(code_location) }
-                .to_str()
-                .unwrap_or("unknown");
-            assert_ne!(message, std::ptr::null());
-            let message = unsafe { CStr::from_ptr(message) };
-
-            assert_ne!(logid, std::ptr::null());
-            let logid = unsafe { CStr::from_ptr(logid) };
-
-            // Parse the code location
-            let code_location: CodeLocation = code_location.into();
-
-            let span = span!(
-                Level::TRACE,
-                "onnxruntime",
-                category = category.to_str().unwrap_or("<unknown>"),
-                file = code_location.file,
-                line_number = code_location.line_number,
-                function = code_location.function,
-                logid = logid.to_str().unwrap_or("<unknown>"),
-            );
-            let _enter = span.enter();
-
-            match log_level {
-                Level::TRACE => trace!("{:?}", message),
-                Level::DEBUG => debug!("{:?}", message),
-                Level::INFO => info!("{:?}", message),
-                Level::WARN => warn!("{:?}", message),
-                Level::ERROR => error!("{:?}", message),
+            let logged = crate::error::catch_callback_panic("logger", || {
+                log(severity, category, logid, code_location, message)
+            });
+            if let Err(err) = logged {
+                use std::io::Write;
+                let _ = writeln!(std::io::stderr(), "onnxruntime: {}", err);
             }
         }
     }
+
+    /// Forward a message of the runtime to the tracing crate and to the log captures
+    fn log(
+        severity: sys::OrtLoggingLevel,
+        category: *const i8,
+        logid: *const i8,
+        code_location: *const i8,
+        message: *const i8,
+    ) {
+        let log_level = match severity {
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_VERBOSE => Level::TRACE,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_INFO => Level::DEBUG,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_WARNING => Level::INFO,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_ERROR => Level::WARN,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_FATAL => Level::ERROR,
+        };
+
+        assert_ne!(category, std::ptr::null());
+        let category = unsafe { CStr::from_ptr(category) };
+        assert_ne!(code_location, std::ptr::null());
+        let code_location = unsafe { CStr::from_ptr(code_location) }
+            .to_str()
+            .unwrap_or("unknown");
+        assert_ne!(message, std::ptr::null());
+        let message = unsafe { CStr::from_ptr(message) };
+
+        assert_ne!(logid, std::ptr::null());
+        let logid = unsafe { CStr::from_ptr(logid) };
+
+        crate::log_capture::capture(
+            logid.to_str().unwrap_or("<unknown>"),
+            severity.into(),
+            category.to_str().unwrap_or("<unknown>"),
+            message.to_str().unwrap_or("<unknown>"),
+        );
+
+        // Parse the code location
+        let code_location: CodeLocation = code_location.into();
+
+        let span = span!(
+            Level::TRACE,
+            "onnxruntime",
+            category = category.to_str().unwrap_or("<unknown>"),
+            file = code_location.file,
+            line_number = code_location.line_number,
+            function = code_location.function,
+            logid = logid.to_str().unwrap_or("<unknown>"),
+        );
+        let _enter = span.enter();
+
+        match log_level {
+            Level::TRACE => trace!("{:?}", message),
+            Level::DEBUG => debug!("{:?}", message),
+            Level::INFO => info!("{:?}", message),
+            Level::WARN => warn!("{:?}", message),
+            Level::ERROR => error!("{:?}", message),
+        }
+    }
 }
 
 /// Logging level of the ONNX Runtime C API
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(not(windows), repr(u32))]
 #[cfg_attr(windows, repr(i32))]
 pub enum LoggingLevel {
@@ -343,6 +484,18 @@ pub enum LoggingLevel {
     Fatal = sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_FATAL as OnnxEnumInt,
 }
 
+impl From<sys::OrtLoggingLevel> for LoggingLevel {
+    fn from(val: sys::OrtLoggingLevel) -> Self {
+        match val {
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_VERBOSE => LoggingLevel::Verbose,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_INFO => LoggingLevel::Info,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_WARNING => LoggingLevel::Warning,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_ERROR => LoggingLevel::Error,
+            sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_FATAL => LoggingLevel::Fatal,
+        }
+    }
+}
+
 impl From<LoggingLevel> for sys::OrtLoggingLevel {
     fn from(val: LoggingLevel) -> Self {
         match val {
@@ -359,7 +512,7 @@ impl From<LoggingLevel> for sys::OrtLoggingLevel {
 ///
 /// See the [official documentation](https://github.com/microsoft/onnxruntime/blob/master/docs/ONNX_Runtime_Graph_Optimizations.md)
 /// for more information on the different optimization levels.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(not(windows), repr(u32))]
 #[cfg_attr(windows, repr(i32))]
 pub enum GraphOptimizationLevel {
@@ -385,10 +538,53 @@ impl From<GraphOptimizationLevel> for sys::GraphOptimizationLevel {
     }
 }
 
+impl fmt::Display for GraphOptimizationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use GraphOptimizationLevel::*;
+        f.write_str(match self {
+            DisableAll => "disable_all",
+            Basic => "basic",
+            Extended => "extended",
+            All => "all",
+        })
+    }
+}
+
+/// Execution mode of the session's graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(not(windows), repr(u32))]
+#[cfg_attr(windows, repr(i32))]
+pub enum ExecutionMode {
+    /// Run the nodes one after the other
+    Sequential = sys::ExecutionMode::ORT_SEQUENTIAL as OnnxEnumInt,
+    /// Run independent nodes in parallel, on the inter-op thread pool
+    Parallel = sys::ExecutionMode::ORT_PARALLEL as OnnxEnumInt,
+}
+
+impl From<ExecutionMode> for sys::ExecutionMode {
+    fn from(val: ExecutionMode) -> Self {
+        match val {
+            ExecutionMode::Sequential => sys::ExecutionMode::ORT_SEQUENTIAL,
+            ExecutionMode::Parallel => sys::ExecutionMode::ORT_PARALLEL,
+        }
+    }
+}
+
+impl fmt::Display for ExecutionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExecutionMode::Sequential => "sequential",
+            ExecutionMode::Parallel => "parallel",
+        })
+    }
+}
+
 // FIXME: Use https://docs.rs/bindgen/0.54.1/bindgen/struct.Builder.html#method.rustified_enum
 // FIXME: Add tests to cover the commented out types
 /// Enum mapping ONNX Runtime's supported tensor types
-#[derive(Debug)]
+///
+/// Displayed as in ONNX type strings, for example `float` for `tensor(float)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(not(windows), repr(u32))]
 #[cfg_attr(windows, repr(i32))]
 pub enum TensorElementDataType {
@@ -410,8 +606,8 @@ pub enum TensorElementDataType {
     String = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING as OnnxEnumInt,
     // /// Boolean, equivalent to Rust's `bool`
     // Bool = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL as OnnxEnumInt,
-    // /// 16-bit floating point, equivalent to Rust's `f16`
-    // Float16 = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16 as OnnxEnumInt,
+    /// 16-bit floating point (IEEE 754 half precision)
+    Float16 = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16 as OnnxEnumInt,
     /// 64-bit floating point, equivalent to Rust's `f64`
     Double = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE as OnnxEnumInt,
     /// Unsigned 32-bit int, equivalent to Rust's `u32`
@@ -422,8 +618,9 @@ pub enum TensorElementDataType {
     // Complex64 = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64 as OnnxEnumInt,
     // /// Complex 128-bit floating point, equivalent to Rust's `???`
     // Complex128 = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128 as OnnxEnumInt,
-    // /// Brain 16-bit floating point
-    // Bfloat16 = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 as OnnxEnumInt,
+    /// Brain 16-bit floating point
+    Bfloat16 =
+        sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 as OnnxEnumInt,
 }
 
 impl From<TensorElementDataType> for sys::ONNXTensorElementDataType {
@@ -441,9 +638,7 @@ impl From<TensorElementDataType> for sys::ONNXTensorElementDataType {
             // Bool => {
             //     sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL
             // }
-            // Float16 => {
-            //     sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16
-            // }
+            Float16 => sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16,
             Double => sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE,
             Uint32 => sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32,
             Uint64 => sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64,
@@ -453,13 +648,75 @@ impl From<TensorElementDataType> for sys::ONNXTensorElementDataType {
             // Complex128 => {
             //     sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128
             // }
-            // Bfloat16 => {
-            //     sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16
-            // }
+            Bfloat16 => sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16,
         }
     }
 }
 
+impl TensorElementDataType {
+    /// Element type read from the runtime, or `None` for the types this crate does not support
+    /// (booleans, complex numbers, undefined)
+    pub(crate) fn from_sys(element_type: sys::ONNXTensorElementDataType) -> Option<Self> {
+        use sys::ONNXTensorElementDataType::*;
+        use TensorElementDataType::*;
+        Some(match element_type {
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT => Float,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8 => Uint8,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8 => Int8,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16 => Uint16,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16 => Int16,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32 => Int32,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64 => Int64,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING => String,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16 => Float16,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE => Double,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32 => Uint32,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64 => Uint64,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 => Bfloat16,
+            _ => return None,
+        })
+    }
+
+    /// Size of an element in bytes, or `None` for strings, whose size varies
+    pub fn size_in_bytes(self) -> Option<usize> {
+        use TensorElementDataType::*;
+        match self {
+            Uint8 | Int8 => Some(1),
+            Uint16 | Int16 | Float16 | Bfloat16 => Some(2),
+            Float | Int32 | Uint32 => Some(4),
+            Double | Int64 | Uint64 => Some(8),
+            String => None,
+        }
+    }
+
+    /// Whether the elements are floating point numbers, of any precision
+    pub fn is_float(self) -> bool {
+        use TensorElementDataType::*;
+        matches!(self, Float | Float16 | Double | Bfloat16)
+    }
+}
+
+impl fmt::Display for TensorElementDataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TensorElementDataType::*;
+        f.write_str(match self {
+            Float => "float",
+            Uint8 => "uint8",
+            Int8 => "int8",
+            Uint16 => "uint16",
+            Int16 => "int16",
+            Int32 => "int32",
+            Int64 => "int64",
+            String => "string",
+            Float16 => "float16",
+            Double => "double",
+            Uint32 => "uint32",
+            Uint64 => "uint64",
+            Bfloat16 => "bfloat16",
+        })
+    }
+}
+
 /// Trait used to map Rust types (for example `f32`) to ONNX types (for example `Float`)
 pub trait TypeToTensorElementDataType {
     /// Return the ONNX type for a Rust type
@@ -534,7 +791,7 @@ impl<T: Utf8Data> TypeToTensorElementDataType for T {
 }
 
 /// Allocator type
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum AllocatorType {
     // Invalid = sys::OrtAllocatorType::Invalid as i32,
@@ -555,16 +812,27 @@ impl From<AllocatorType> for sys::OrtAllocatorType {
     }
 }
 
+impl fmt::Display for AllocatorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AllocatorType::*;
+        f.write_str(match self {
+            Device => "device",
+            Arena => "arena",
+        })
+    }
+}
+
 /// Memory type
 ///
-/// Only support ONNX's default type for now.
-#[derive(Debug, Clone)]
+/// The C API's `OrtMemTypeCPU` is the same as `OrtMemTypeCPUOutput`, i.e.
+/// [`MemType::CpuOutput`](#variant.CpuOutput).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum MemType {
-    // FIXME: C API's `OrtMemType_OrtMemTypeCPU` defines it equal to `OrtMemType_OrtMemTypeCPUOutput`. How to handle this??
-    // CPUInput = sys::OrtMemType::OrtMemTypeCPUInput as i32,
-    // CPUOutput = sys::OrtMemType::OrtMemTypeCPUOutput as i32,
-    // CPU = sys::OrtMemType::OrtMemTypeCPU as i32,
+    /// CPU memory used by a non-CPU execution provider
+    CpuInput = sys::OrtMemType::OrtMemTypeCPUInput as i32,
+    /// CPU accessible memory output by a non-CPU execution provider, such as CUDA pinned memory
+    CpuOutput = sys::OrtMemType::OrtMemTypeCPUOutput as i32,
     /// Default memory type
     Default = sys::OrtMemType::OrtMemTypeDefault as i32,
 }
@@ -573,14 +841,34 @@ impl From<MemType> for sys::OrtMemType {
     fn from(val: MemType) -> Self {
         use MemType::*;
         match val {
-            // CPUInput => sys::OrtMemType::OrtMemTypeCPUInput,
-            // CPUOutput => sys::OrtMemType::OrtMemTypeCPUOutput,
-            // CPU => sys::OrtMemType::OrtMemTypeCPU,
+            CpuInput => sys::OrtMemType::OrtMemTypeCPUInput,
+            CpuOutput => sys::OrtMemType::OrtMemTypeCPUOutput,
             Default => sys::OrtMemType::OrtMemTypeDefault,
         }
     }
 }
 
+impl From<sys::OrtMemType> for MemType {
+    fn from(val: sys::OrtMemType) -> Self {
+        match val {
+            sys::OrtMemType::OrtMemTypeCPUInput => MemType::CpuInput,
+            sys::OrtMemType::OrtMemTypeCPUOutput => MemType::CpuOutput,
+            sys::OrtMemType::OrtMemTypeDefault => MemType::Default,
+        }
+    }
+}
+
+impl fmt::Display for MemType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use MemType::*;
+        f.write_str(match self {
+            CpuInput => "cpu_input",
+            CpuOutput => "cpu_output",
+            Default => "default",
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -591,4 +879,48 @@ mod test {
         let ptr = s.as_c_str().as_ptr();
         assert_eq!("foo", char_p_to_string(ptr).unwrap());
     }
+
+    #[test]
+    fn tensor_element_data_type_helpers() {
+        assert_eq!(TensorElementDataType::Float.size_in_bytes(), Some(4));
+        assert_eq!(TensorElementDataType::Bfloat16.size_in_bytes(), Some(2));
+        assert_eq!(TensorElementDataType::String.size_in_bytes(), None);
+        assert!(TensorElementDataType::Float16.is_float());
+        assert!(!TensorElementDataType::Int64.is_float());
+        assert_eq!(TensorElementDataType::Uint8.to_string(), "uint8");
+        assert_eq!(
+            GraphOptimizationLevel::DisableAll.to_string(),
+            "disable_all"
+        );
+    }
+
+    #[test]
+    fn api_versions_of_functions() {
+        use std::mem::offset_of;
+
+        assert_eq!(api_version_of(offset_of!(sys::OrtApi, CreateStatus)), 1);
+        assert_eq!(
+            api_version_of(offset_of!(sys::OrtApi, ReleaseCustomOpDomain)),
+            1
+        );
+        assert_eq!(
+            api_version_of(offset_of!(sys::OrtApi, GetAvailableProviders)),
+            3
+        );
+        assert_eq!(
+            api_version_of(offset_of!(sys::OrtApi, AddSessionConfigEntry)),
+            4
+        );
+        assert_eq!(
+            api_version_of(offset_of!(
+                sys::OrtApi,
+                CreateSessionFromArrayWithPrepackedWeightsContainer
+            )),
+            8
+        );
+        assert_eq!(
+            api_version_of(offset_of!(sys::OrtApi, GetBuildInfoString)),
+            15
+        );
+    }
 }
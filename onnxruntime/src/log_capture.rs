@@ -0,0 +1,310 @@
+//! Module keeping some of the runtime's log messages, per session
+//!
+//! Each session is given a log id of its own; the runtime's logging callback hands the messages
+//! of registered log ids to [`capture()`](fn.capture.html), in addition to forwarding them to
+//! `tracing`. Warnings are kept while the session is being created, and memory related messages
+//! for sessions built with a memory report, including the ones of the environment's default
+//! logger, which the runtime's arenas log through. Runs whose [`RunOptions`](../session/struct.RunOptions.html)
+//! capture their logs get a log id of their own too, for which all messages are kept.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use lazy_static::lazy_static;
+
+use crate::{memory_report, LoggingLevel};
+
+/// Maximum number of messages kept per session, so long-lived sessions don't grow unbounded
+const MAX_CAPTURED_MESSAGES: usize = 10_000;
+
+lazy_static! {
+    static ref CAPTURES: Mutex<HashMap<String, Arc<CapturedMessages>>> = Mutex::new(HashMap::new());
+}
+
+/// Number of registered captures, to skip locking in the logging callback when there are none
+static ACTIVE_CAPTURES: AtomicUsize = AtomicUsize::new(0);
+static NEXT_CAPTURE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Message logged by the runtime at the warning level or above
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// Severity of the message: `Warning`, `Error` or `Fatal`
+    pub level: LoggingLevel,
+    /// Category of the message, for example `onnxruntime`
+    pub category: String,
+    /// The message itself
+    pub message: String,
+}
+
+/// Message logged by the runtime during a run whose logs were captured
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogMessage {
+    /// Severity of the message
+    pub level: LoggingLevel,
+    /// Category of the message, for example `onnxruntime`
+    pub category: String,
+    /// The message itself
+    pub message: String,
+}
+
+#[derive(Debug, Default)]
+struct CapturedMessages {
+    keep_warnings: AtomicBool,
+    keep_memory_messages: AtomicBool,
+    keep_all_messages: AtomicBool,
+    warnings: Mutex<Vec<Warning>>,
+    memory_messages: Mutex<Vec<String>>,
+    all_messages: Mutex<Vec<LogMessage>>,
+}
+
+/// Registration of a session's log id, keeping its warnings until told otherwise
+///
+/// Unregisters itself when dropped.
+#[derive(Debug)]
+pub(crate) struct LogCapture {
+    logid: String,
+    messages: Arc<CapturedMessages>,
+}
+
+impl LogCapture {
+    pub(crate) fn new() -> LogCapture {
+        let capture = LogCapture::register("session");
+        capture
+            .messages
+            .keep_warnings
+            .store(true, Ordering::Relaxed);
+        capture
+    }
+
+    /// Registration of a run's log id, keeping all its messages
+    pub(crate) fn for_run() -> LogCapture {
+        let capture = LogCapture::register("run");
+        capture
+            .messages
+            .keep_all_messages
+            .store(true, Ordering::Relaxed);
+        capture
+    }
+
+    fn register(kind: &str) -> LogCapture {
+        let logid = format!(
+            "onnxruntime-rs-{}-{}",
+            kind,
+            NEXT_CAPTURE_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let messages = Arc::new(CapturedMessages::default());
+        CAPTURES
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .insert(logid.clone(), messages.clone());
+        ACTIVE_CAPTURES.fetch_add(1, Ordering::Release);
+
+        LogCapture { logid, messages }
+    }
+
+    /// Log id the session must use for its messages to be captured
+    pub(crate) fn logid(&self) -> &str {
+        &self.logid
+    }
+
+    /// Keep memory related messages too, from now on
+    pub(crate) fn keep_memory_messages(&self) {
+        self.messages
+            .keep_memory_messages
+            .store(true, Ordering::Relaxed);
+    }
+
+    /// Memory related messages kept so far, `None` unless asked to keep them
+    pub(crate) fn memory_messages(&self) -> Option<Vec<String>> {
+        if !self.messages.keep_memory_messages.load(Ordering::Relaxed) {
+            return None;
+        }
+        Some(
+            self.messages
+                .memory_messages
+                .lock()
+                .expect("Failed to acquire lock: another thread panicked?")
+                .clone(),
+        )
+    }
+
+    /// Stop keeping warnings, returning the ones kept so far
+    pub(crate) fn finish_warnings(&self) -> Vec<Warning> {
+        self.messages.keep_warnings.store(false, Ordering::Relaxed);
+        std::mem::take(
+            &mut *self
+                .messages
+                .warnings
+                .lock()
+                .expect("Failed to acquire lock: another thread panicked?"),
+        )
+    }
+
+    /// All messages kept since the last call, if asked to keep them
+    pub(crate) fn take_messages(&self) -> Vec<LogMessage> {
+        std::mem::take(
+            &mut *self
+                .messages
+                .all_messages
+                .lock()
+                .expect("Failed to acquire lock: another thread panicked?"),
+        )
+    }
+
+    /// Keep warnings again, for example for another attempt at creating a session
+    pub(crate) fn resume_warnings(&self) {
+        self.messages.keep_warnings.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for LogCapture {
+    fn drop(&mut self) {
+        if let Ok(mut captures) = CAPTURES.lock() {
+            captures.remove(&self.logid);
+        }
+        ACTIVE_CAPTURES.fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// Keep `message` if `logid` is being captured and the message is of interest
+///
+/// Called from the runtime's logging callback.
+pub(crate) fn capture(logid: &str, level: LoggingLevel, category: &str, message: &str) {
+    if ACTIVE_CAPTURES.load(Ordering::Acquire) == 0 {
+        return;
+    }
+
+    let messages = match CAPTURES.lock() {
+        Ok(captures) => match captures.get(logid) {
+            Some(messages) => messages.clone(),
+            // Not a session's log id but the environment's default logger, which the arenas log
+            // through without naming their session: keep their messages for all memory reports
+            None => {
+                if memory_report::is_memory_related(message) {
+                    for messages in captures.values() {
+                        keep_memory_message(messages, message);
+                    }
+                }
+                return;
+            }
+        },
+        Err(_) => return,
+    };
+
+    let is_warning = matches!(
+        level,
+        LoggingLevel::Warning | LoggingLevel::Error | LoggingLevel::Fatal
+    );
+    if is_warning && messages.keep_warnings.load(Ordering::Relaxed) {
+        if let Ok(mut warnings) = messages.warnings.lock() {
+            if warnings.len() < MAX_CAPTURED_MESSAGES {
+                warnings.push(Warning {
+                    level,
+                    category: category.to_owned(),
+                    message: message.to_owned(),
+                });
+            }
+        }
+    }
+
+    if messages.keep_all_messages.load(Ordering::Relaxed) {
+        if let Ok(mut all_messages) = messages.all_messages.lock() {
+            if all_messages.len() < MAX_CAPTURED_MESSAGES {
+                all_messages.push(LogMessage {
+                    level,
+                    category: category.to_owned(),
+                    message: message.to_owned(),
+                });
+            }
+        }
+    }
+
+    if memory_report::is_memory_related(message) {
+        keep_memory_message(&messages, message);
+    }
+}
+
+fn keep_memory_message(messages: &CapturedMessages, message: &str) {
+    if messages.keep_memory_messages.load(Ordering::Relaxed) {
+        if let Ok(mut memory_messages) = messages.memory_messages.lock() {
+            if memory_messages.len() < MAX_CAPTURED_MESSAGES {
+                memory_messages.push(message.to_owned());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn log_capture() {
+        let log_capture = LogCapture::new();
+        let logid = log_capture.logid().to_owned();
+        log_capture.keep_memory_messages();
+        capture(
+            &logid,
+            LoggingLevel::Info,
+            "onnxruntime",
+            "Total allocated bytes: 42",
+        );
+        capture(
+            &logid,
+            LoggingLevel::Verbose,
+            "onnxruntime",
+            "Begin execution",
+        );
+        capture(
+            &logid,
+            LoggingLevel::Warning,
+            "onnxruntime",
+            "Some nodes were not assigned",
+        );
+        capture(
+            "other",
+            LoggingLevel::Warning,
+            "onnxruntime",
+            "Some other nodes were not assigned",
+        );
+
+        let warnings = log_capture.finish_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level, LoggingLevel::Warning);
+        assert_eq!(warnings[0].message, "Some nodes were not assigned");
+        capture(&logid, LoggingLevel::Error, "onnxruntime", "Late error");
+        assert!(log_capture.finish_warnings().is_empty());
+
+        assert_eq!(
+            log_capture.memory_messages(),
+            Some(vec!["Total allocated bytes: 42".to_owned()])
+        );
+        assert!(log_capture.take_messages().is_empty());
+    }
+
+    #[test]
+    fn run_log_capture() {
+        let log_capture = LogCapture::for_run();
+        let logid = log_capture.logid().to_owned();
+        capture(&logid, LoggingLevel::Verbose, "VLOG1", "Begin execution");
+        capture(
+            &logid,
+            LoggingLevel::Error,
+            "onnxruntime",
+            "Non-zero status",
+        );
+
+        let messages = log_capture.take_messages();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].level, LoggingLevel::Verbose);
+        assert_eq!(messages[1].message, "Non-zero status");
+        assert!(log_capture.take_messages().is_empty());
+        assert!(log_capture.finish_warnings().is_empty());
+    }
+}
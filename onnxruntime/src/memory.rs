@@ -1,3 +1,7 @@
+//! Module containing the description of the memory of allocators
+
+use std::ffi::CString;
+
 use tracing::debug;
 
 use onnxruntime_sys as sys;
@@ -5,22 +9,37 @@ use onnxruntime_sys as sys;
 use tracing::error;
 
 use crate::{
+    char_p_to_string,
     error::{assert_not_null_pointer, status_to_result, OrtError, Result},
-    g_ort, AllocatorType, MemType,
+    AllocatorType, MemType,
 };
 
+/// Description of the memory of an allocator: its name, device and memory type
+///
+/// Created for the CPU with [`new()`](#method.new), or for any allocator of the runtime or
+/// registered at the environment by name with [`with_name()`](#method.with_name), for example to
+/// bind outputs to pinned host memory with
+/// [`IoBinding::bind_output_to()`](../session/struct.IoBinding.html#method.bind_output_to).
 #[derive(Debug)]
-pub(crate) struct MemoryInfo {
-    pub ptr: *mut sys::OrtMemoryInfo,
+pub struct MemoryInfo {
+    pub(crate) ptr: *mut sys::OrtMemoryInfo,
 }
 
 impl MemoryInfo {
+    /// Name of the allocator of CPU memory
+    pub const CPU: &'static str = "Cpu";
+    /// Name of the CUDA execution provider's allocator of device memory
+    pub const CUDA: &'static str = "Cuda";
+    /// Name of the CUDA execution provider's allocator of pinned (page-locked) host memory
+    pub const CUDA_PINNED: &'static str = "CudaPinned";
+
+    /// Memory information of the CPU allocator
     #[tracing::instrument]
     pub fn new(allocator: AllocatorType, memory_type: MemType) -> Result<Self> {
         debug!("Creating new memory info.");
         let mut memory_info_ptr: *mut sys::OrtMemoryInfo = std::ptr::null_mut();
         let status = unsafe {
-            g_ort().CreateCpuMemoryInfo.unwrap()(
+            ort_api!(CreateCpuMemoryInfo)?(
                 allocator.into(),
                 memory_type.into(),
                 &mut memory_info_ptr,
@@ -33,6 +52,64 @@ impl MemoryInfo {
             ptr: memory_info_ptr,
         })
     }
+
+    /// Memory information of the allocator of the given name, on the device of the given id
+    ///
+    /// `name` is the name of an execution provider's allocator, such as
+    /// [`MemoryInfo::CUDA_PINNED`](#associatedconstant.CUDA_PINNED), or of a custom allocator
+    /// registered at the environment.
+    #[tracing::instrument]
+    pub fn with_name(
+        name: &str,
+        allocator: AllocatorType,
+        device_id: i32,
+        memory_type: MemType,
+    ) -> Result<Self> {
+        debug!("Creating new named memory info.");
+        let name = CString::new(name)?;
+        let mut memory_info_ptr: *mut sys::OrtMemoryInfo = std::ptr::null_mut();
+        let status = unsafe {
+            ort_api!(CreateMemoryInfo)?(
+                name.as_ptr(),
+                allocator.into(),
+                device_id,
+                memory_type.into(),
+                &mut memory_info_ptr,
+            )
+        };
+        status_to_result(status).map_err(OrtError::CreateMemoryInfo)?;
+        assert_not_null_pointer(memory_info_ptr, "MemoryInfo")?;
+
+        Ok(Self {
+            ptr: memory_info_ptr,
+        })
+    }
+
+    /// Name of the allocator
+    pub fn name(&self) -> Result<String> {
+        let mut name: *const i8 = std::ptr::null();
+        let status = unsafe { ort_api!(MemoryInfoGetName)?(self.ptr, &mut name) };
+        status_to_result(status).map_err(OrtError::GetMemoryInfo)?;
+        assert_not_null_pointer(name, "MemoryInfoName")?;
+        // The name is owned by the memory information
+        char_p_to_string(name)
+    }
+
+    /// Id of the device of the allocator
+    pub fn device_id(&self) -> Result<i32> {
+        let mut device_id = 0;
+        let status = unsafe { ort_api!(MemoryInfoGetId)?(self.ptr, &mut device_id) };
+        status_to_result(status).map_err(OrtError::GetMemoryInfo)?;
+        Ok(device_id)
+    }
+
+    /// Memory type of the allocator
+    pub fn memory_type(&self) -> Result<MemType> {
+        let mut memory_type = sys::OrtMemType::OrtMemTypeDefault;
+        let status = unsafe { ort_api!(MemoryInfoGetMemType)?(self.ptr, &mut memory_type) };
+        status_to_result(status).map_err(OrtError::GetMemoryInfo)?;
+        Ok(memory_type.into())
+    }
 }
 
 impl Drop for MemoryInfo {
@@ -42,7 +119,9 @@ impl Drop for MemoryInfo {
             error!("MemoryInfo pointer is null, not dropping.");
         } else {
             debug!("Dropping the memory information.");
-            unsafe { g_ort().ReleaseMemoryInfo.unwrap()(self.ptr) };
+            if let Ok(release) = ort_api!(ReleaseMemoryInfo) {
+                unsafe { release(self.ptr) };
+            }
         }
 
         self.ptr = std::ptr::null_mut();
@@ -59,4 +138,18 @@ mod tests {
         let memory_info = MemoryInfo::new(AllocatorType::Arena, MemType::Default).unwrap();
         std::mem::drop(memory_info);
     }
+
+    #[test]
+    fn named_memory_info_constructor_destructor() {
+        let memory_info = MemoryInfo::with_name(
+            MemoryInfo::CUDA_PINNED,
+            AllocatorType::Device,
+            0,
+            MemType::CpuOutput,
+        )
+        .unwrap();
+        assert_eq!(memory_info.name().unwrap(), MemoryInfo::CUDA_PINNED);
+        assert_eq!(memory_info.memory_type().unwrap(), MemType::CpuOutput);
+        std::mem::drop(memory_info);
+    }
 }
@@ -0,0 +1,154 @@
+//! Module containing the memory report of a session
+//!
+//! When enabled with [`SessionBuilder::with_memory_report()`](../session/struct.SessionBuilder.html#method.with_memory_report),
+//! the session logs verbosely under its own log id. The runtime's memory related messages
+//! for that log id are kept (in addition to being forwarded to `tracing`) and parsed into a
+//! [`MemoryReport`](struct.MemoryReport.html).
+//!
+//! The arenas don't log under the session's log id but through the environment's default logger,
+//! which filters messages at the environment's log level: it must be
+//! [`LoggingLevel::Info`](../enum.LoggingLevel.html#variant.Info) or more verbose (see
+//! [`EnvBuilder::with_log_level()`](../environment/struct.EnvBuilder.html#method.with_log_level))
+//! for the report to list arena allocations. Since those messages don't say which session they
+//! come from, they are kept for every session with a memory report: build only one at a time
+//! for the report to be accurate.
+//!
+//! Release builds of the runtime log arena reservations and growth, which is what the report
+//! is made of. Per-node buffer plans are only logged by runtimes built with memory profiling;
+//! their messages are still available in [`MemoryReport::messages`](struct.MemoryReport.html#structfield.messages).
+
+use crate::error::text_between;
+
+/// Memory usage reported by the runtime for a session
+///
+/// Arenas grow while running inference, so the report reflects the runs performed so far:
+/// get it after running representative inputs (for example the largest batch size considered).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryReport {
+    /// Memory reserved up front in arenas, mostly for initializers (weights)
+    pub arena_reservations: Vec<ArenaAllocation>,
+    /// Each time an arena had to grow, mostly for activations
+    pub arena_extensions: Vec<ArenaAllocation>,
+    /// Largest total of bytes allocated by an arena, as reported after it grew
+    pub peak_allocated_bytes: Option<usize>,
+    /// Memory related messages logged by the runtime for the session
+    pub messages: Vec<String>,
+}
+
+/// Memory allocated in one of the runtime's arenas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaAllocation {
+    /// Name of the allocator owning the arena, for example `Cpu` or `Cuda`
+    pub allocator: String,
+    /// Number of bytes allocated
+    pub bytes: usize,
+}
+
+impl MemoryReport {
+    pub(crate) fn from_messages(messages: Vec<String>) -> MemoryReport {
+        let mut report = MemoryReport::default();
+        for message in &messages {
+            // "Reserving memory in BFCArena for Cuda size: 33554432"
+            if let Some((allocator, bytes)) =
+                text_between(message, "Reserving memory in BFCArena for ", " size: ")
+                    .zip(number_after(message, " size: "))
+            {
+                report.arena_reservations.push(ArenaAllocation {
+                    allocator: allocator.to_owned(),
+                    bytes,
+                });
+            // "Extending BFCArena for Cpu. bin_num:20 (requested) num_bytes: 1048576 (actual) rounded_bytes:1048576"
+            } else if let Some((allocator, bytes)) =
+                text_between(message, "Extending BFCArena for ", ".")
+                    .zip(number_after(message, "rounded_bytes:"))
+            {
+                report.arena_extensions.push(ArenaAllocation {
+                    allocator: allocator.to_owned(),
+                    bytes,
+                });
+            // "Total allocated bytes: 1048576"
+            } else if let Some(bytes) = number_after(message, "Total allocated bytes: ") {
+                report.peak_allocated_bytes = report.peak_allocated_bytes.max(Some(bytes));
+            }
+        }
+        report.messages = messages;
+        report
+    }
+}
+
+fn number_after(message: &str, start: &str) -> Option<usize> {
+    let from = message.find(start)? + start.len();
+    let digits = message[from..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(&message[from..], |len| &message[from..from + len]);
+    digits.parse().ok()
+}
+
+/// Whether `message` is about memory, and kept for the report
+pub(crate) fn is_memory_related(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("arena") || message.contains("memory") || message.contains("allocat")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn memory_report_from_messages() {
+        let report = MemoryReport::from_messages(vec![
+            "Reserving memory in BFCArena for Cuda size: 33554432".to_owned(),
+            "Extending BFCArena for Cpu. bin_num:20 (requested) num_bytes: 1000000 (actual) rounded_bytes:1048576".to_owned(),
+            "Extended allocation by 1048576 bytes.".to_owned(),
+            "Total allocated bytes: 1048576".to_owned(),
+            "Extending BFCArena for Cpu. bin_num:21 (requested) num_bytes: 2000000 (actual) rounded_bytes:2097152".to_owned(),
+            "Total allocated bytes: 3145728".to_owned(),
+        ]);
+        assert_eq!(
+            report.arena_reservations,
+            vec![ArenaAllocation {
+                allocator: "Cuda".to_owned(),
+                bytes: 33554432
+            }]
+        );
+        assert_eq!(
+            report
+                .arena_extensions
+                .iter()
+                .map(|extension| extension.bytes)
+                .collect::<Vec<_>>(),
+            vec![1048576, 2097152]
+        );
+        assert_eq!(report.peak_allocated_bytes, Some(3145728));
+        assert_eq!(report.messages.len(), 6);
+    }
+
+    #[test]
+    fn memory_log_capture() {
+        use crate::{log_capture, LoggingLevel};
+
+        let capture = log_capture::LogCapture::new();
+        capture.keep_memory_messages();
+        let logid = capture.logid();
+        log_capture::capture(logid, LoggingLevel::Info, "", "Total allocated bytes: 42");
+        log_capture::capture(logid, LoggingLevel::Info, "", "Begin execution");
+        // Arenas log through the environment's default logger, under its own log id
+        log_capture::capture(
+            "default",
+            LoggingLevel::Info,
+            "",
+            "Extending BFCArena for Cpu. bin_num:20 (requested) num_bytes: 1000000 (actual) rounded_bytes:1048576",
+        );
+        let report = MemoryReport::from_messages(capture.memory_messages().unwrap());
+        assert!(report.peak_allocated_bytes >= Some(42));
+        assert!(report.arena_extensions.contains(&ArenaAllocation {
+            allocator: "Cpu".to_owned(),
+            bytes: 1048576
+        }));
+        assert!(!report
+            .messages
+            .iter()
+            .any(|message| message == "Begin execution"));
+    }
+}
@@ -0,0 +1,303 @@
+//! Module containing helpers to evaluate half precision versions of models
+//!
+//! Running a model in float16 roughly halves its memory and bandwidth needs, and is much faster
+//! on GPUs with tensor cores, at the cost of some accuracy. Whether that cost is acceptable
+//! depends on the model: [`load_mixed_precision()`](fn.load_mixed_precision.html) loads both
+//! versions and compares their outputs on sample inputs, so the choice can be made with evidence.
+//!
+//! ONNX Runtime has no graph transformer converting a model to float16 at load time; the half
+//! precision version must be converted beforehand, keeping float32 inputs and outputs, for
+//! example with:
+//!
+//! ```text
+//! python -c "import onnx; from onnxconverter_common import float16; \
+//!     onnx.save(float16.convert_float_to_float16(onnx.load('model.onnx'), keep_io_types=True), \
+//!     'model_fp16.onnx')"
+//! ```
+//!
+//! Other sources of numerical differences, such as the TF32 kernels of the CUDA provider (see
+//! [`CudaExecutionProvider::with_tf32()`](../execution_providers/struct.CudaExecutionProvider.html#method.with_tf32)),
+//! can be evaluated the same way with [`compare_sessions()`](fn.compare_sessions.html).
+
+use std::path::Path;
+
+use ndarray::ArrayD;
+
+use crate::{
+    environment::Environment,
+    error::{OrtError, Result},
+    session::{Session, SessionBuilder},
+    tensor::OrtOwnedTensor,
+    TensorElementDataType,
+};
+
+/// Differences between the outputs of a reference session (such as the float32 version of a
+/// model) and a candidate session (such as its float16 version) for one output
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputComparison {
+    /// Name of the output
+    pub name: String,
+    /// Largest absolute difference over all elements of all samples
+    pub max_abs_error: f32,
+    /// Mean absolute difference over all elements of all samples
+    pub mean_abs_error: f32,
+    /// Largest difference relative to the reference value, ignoring reference values near zero
+    pub max_rel_error: f32,
+}
+
+/// Accuracy of a candidate session (such as the float16 version of a model) compared to a
+/// reference one
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PrecisionReport {
+    /// Number of samples compared
+    pub samples: usize,
+    /// Differences per output, in the order of the model's outputs
+    pub outputs: Vec<OutputComparison>,
+}
+
+impl PrecisionReport {
+    /// Largest absolute difference over all outputs
+    pub fn max_abs_error(&self) -> f32 {
+        self.outputs
+            .iter()
+            .map(|output| output.max_abs_error)
+            .fold(0.0, f32::max)
+    }
+
+    /// Whether all absolute differences are within `tolerance`
+    pub fn is_within(&self, tolerance: f32) -> bool {
+        self.max_abs_error() <= tolerance
+    }
+}
+
+/// Float32 and float16 sessions of a model, with the accuracy of the latter
+#[derive(Debug)]
+pub struct MixedPrecisionSessions<'a> {
+    /// Session of the float32 model
+    pub fp32: Session<'a>,
+    /// Session of the float16 model, returning float32 outputs
+    pub fp16: Session<'a>,
+    /// Comparison of both sessions' outputs on the samples
+    pub report: PrecisionReport,
+}
+
+/// Float32 values below this magnitude are ignored for relative errors
+const RELATIVE_ERROR_FLOOR: f32 = 1e-6;
+
+/// Load the float32 and float16 versions of a model, and compare their outputs on `samples`
+///
+/// Both sessions are built from builders passed through `configure` (for example to append an
+/// execution provider). The float16 session is built with
+/// [`with_half_precision_upcast()`](../session/struct.SessionBuilder.html#method.with_half_precision_upcast),
+/// so that models whose outputs were converted too are still compared as float32.
+///
+/// Each sample holds one array per model input. Fails if the models' inputs or outputs do not
+/// match, or if an input of the float16 model is not float32 (see the [module](index.html)
+/// documentation).
+pub fn load_mixed_precision<'a, F, P, Q, I>(
+    environment: &'a Environment,
+    configure: F,
+    fp32_model: P,
+    fp16_model: Q,
+    samples: I,
+) -> Result<MixedPrecisionSessions<'a>>
+where
+    F: Fn(SessionBuilder<'a>) -> Result<SessionBuilder<'a>>,
+    P: AsRef<Path> + 'a,
+    Q: AsRef<Path> + 'a,
+    I: IntoIterator<Item = Vec<ArrayD<f32>>>,
+{
+    let mut fp32 =
+        configure(environment.new_session_builder()?)?.with_model_from_file(fp32_model)?;
+    let mut fp16 = configure(environment.new_session_builder()?)?
+        .with_half_precision_upcast(true)?
+        .with_model_from_file(fp16_model)?;
+
+    for input in &fp16.inputs {
+        if input.input_type != TensorElementDataType::Float {
+            return Err(OrtError::MixedPrecisionModel(format!(
+                "input {:?} of the float16 model is {:?}, not Float",
+                input.name, input.input_type
+            )));
+        }
+    }
+    let report = compare(&mut fp32, &mut fp16, samples, OrtError::MixedPrecisionModel)?;
+
+    Ok(MixedPrecisionSessions { fp32, fp16, report })
+}
+
+/// Compare the float32 outputs of `candidate` to the ones of `reference` on `samples`
+///
+/// The sessions are typically built from the same model with different settings, for example
+/// with the CUDA provider's TF32 kernels enabled for the candidate and disabled for the reference:
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, execution_providers::CudaExecutionProvider};
+/// # use onnxruntime::mixed_precision::compare_sessions;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let samples: Vec<Vec<ndarray::ArrayD<f32>>> = Vec::new();
+/// let mut fp32 = environment
+///     .new_session_builder()?
+///     .with_execution_provider(CudaExecutionProvider::new(0).with_tf32(false))?
+///     .with_model_from_file("model.onnx")?;
+/// let mut tf32 = environment
+///     .new_session_builder()?
+///     .with_execution_provider(CudaExecutionProvider::new(0).with_tf32(true))?
+///     .with_model_from_file("model.onnx")?;
+/// let report = compare_sessions(&mut fp32, &mut tf32, samples)?;
+/// println!("TF32 max error: {}", report.max_abs_error());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Each sample holds one array per model input. Fails with
+/// [`OrtError::OutputComparison`](../error/enum.OrtError.html#variant.OutputComparison) if the
+/// sessions' outputs do not match.
+pub fn compare_sessions<I>(
+    reference: &mut Session,
+    candidate: &mut Session,
+    samples: I,
+) -> Result<PrecisionReport>
+where
+    I: IntoIterator<Item = Vec<ArrayD<f32>>>,
+{
+    compare(reference, candidate, samples, OrtError::OutputComparison)
+}
+
+fn compare<I>(
+    reference: &mut Session,
+    candidate: &mut Session,
+    samples: I,
+    error: fn(String) -> OrtError,
+) -> Result<PrecisionReport>
+where
+    I: IntoIterator<Item = Vec<ArrayD<f32>>>,
+{
+    let names = |session: &Session| -> Vec<String> {
+        session
+            .outputs
+            .iter()
+            .map(|output| output.name.clone())
+            .collect()
+    };
+    if names(reference) != names(candidate) {
+        return Err(error(format!(
+            "outputs differ: {:?} and {:?}",
+            names(reference),
+            names(candidate)
+        )));
+    }
+
+    let mut comparisons: Vec<Accumulator> = reference
+        .outputs
+        .iter()
+        .map(|output| Accumulator::new(&output.name))
+        .collect();
+    let mut sample_count = 0;
+    for sample in samples {
+        let expected: Vec<OrtOwnedTensor<f32, _>> = reference.run(sample.iter())?;
+        let actual: Vec<OrtOwnedTensor<f32, _>> = candidate.run(sample.iter())?;
+        for ((comparison, expected), actual) in comparisons.iter_mut().zip(&expected).zip(&actual) {
+            if expected.shape() != actual.shape() {
+                return Err(error(format!(
+                    "output {:?} has shape {:?} and {:?}",
+                    comparison.name,
+                    expected.shape(),
+                    actual.shape()
+                )));
+            }
+            comparison.add(expected.iter().copied().zip(actual.iter().copied()));
+        }
+        sample_count += 1;
+    }
+
+    Ok(PrecisionReport {
+        samples: sample_count,
+        outputs: comparisons.into_iter().map(Accumulator::finish).collect(),
+    })
+}
+
+/// Running differences of one output
+struct Accumulator {
+    name: String,
+    max_abs_error: f32,
+    abs_error_sum: f64,
+    elements: usize,
+    max_rel_error: f32,
+}
+
+impl Accumulator {
+    fn new(name: &str) -> Accumulator {
+        Accumulator {
+            name: name.to_owned(),
+            max_abs_error: 0.0,
+            abs_error_sum: 0.0,
+            elements: 0,
+            max_rel_error: 0.0,
+        }
+    }
+
+    fn add<I>(&mut self, values: I)
+    where
+        I: Iterator<Item = (f32, f32)>,
+    {
+        for (expected, actual) in values {
+            // `max` ignores NaN: count NaN differences as infinite so they are not hidden
+            let abs_error = match (expected - actual).abs() {
+                error if error.is_nan() => f32::INFINITY,
+                error => error,
+            };
+            self.max_abs_error = self.max_abs_error.max(abs_error);
+            self.abs_error_sum += f64::from(abs_error);
+            self.elements += 1;
+            if expected.abs() > RELATIVE_ERROR_FLOOR {
+                self.max_rel_error = self.max_rel_error.max(abs_error / expected.abs());
+            }
+        }
+    }
+
+    fn finish(self) -> OutputComparison {
+        OutputComparison {
+            name: self.name,
+            max_abs_error: self.max_abs_error,
+            mean_abs_error: match self.elements {
+                0 => 0.0,
+                elements => (self.abs_error_sum / elements as f64) as f32,
+            },
+            max_rel_error: self.max_rel_error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn accumulator_errors() {
+        let mut accumulator = Accumulator::new("output");
+        accumulator.add(vec![(1.0, 1.5), (-2.0, -2.0), (0.0, 0.25)].into_iter());
+        accumulator.add(vec![(4.0, 3.0)].into_iter());
+        let comparison = accumulator.finish();
+        assert_eq!(comparison.max_abs_error, 1.0);
+        assert_eq!(comparison.mean_abs_error, 1.75 / 4.0);
+        assert_eq!(comparison.max_rel_error, 0.5);
+
+        let report = PrecisionReport {
+            samples: 2,
+            outputs: vec![comparison],
+        };
+        assert!(report.is_within(1.0));
+        assert!(!report.is_within(0.5));
+    }
+
+    #[test]
+    fn accumulator_nan() {
+        let mut accumulator = Accumulator::new("output");
+        accumulator.add(vec![(1.0, f32::NAN)].into_iter());
+        assert_eq!(accumulator.finish().max_abs_error, f32::INFINITY);
+    }
+}
@@ -0,0 +1,198 @@
+//! Module containing a facade bundling a session with its pre- and post-processing
+//!
+//! A [`Model`](struct.Model.html) turns raw inputs (for example encoded image bytes) into typed
+//! results (for example labels) in a single call, so that complete pipelines can be passed
+//! around, pooled and benchmarked like any other value.
+//!
+//! ```no_run
+//! # use std::error::Error;
+//! # use ndarray::{Array, IxDyn};
+//! # use onnxruntime::{
+//! #     environment::Environment,
+//! #     model::{Model, Postprocessor, Preprocessor, ProcessingResult},
+//! #     tensor::OrtOwnedTensor,
+//! # };
+//! struct Normalize;
+//!
+//! impl Preprocessor for Normalize {
+//!     type Input = Vec<u8>;
+//!     type Elem = f32;
+//!     type Dim = IxDyn;
+//!
+//!     fn preprocess(&self, pixels: Vec<u8>) -> ProcessingResult<Vec<Array<f32, IxDyn>>> {
+//!         let pixels = pixels.into_iter().map(|p| f32::from(p) / 255.0).collect();
+//!         Ok(vec![Array::from_shape_vec(IxDyn(&[1, 1, 28, 28]), pixels)?])
+//!     }
+//! }
+//!
+//! struct ArgMax;
+//!
+//! impl Postprocessor for ArgMax {
+//!     type Elem = f32;
+//!     type Output = usize;
+//!
+//!     fn postprocess(&self, outputs: Vec<OrtOwnedTensor<f32, IxDyn>>) -> ProcessingResult<usize> {
+//!         let scores = outputs[0].iter().enumerate();
+//!         let best = scores.max_by(|a, b| a.1.total_cmp(b.1)).ok_or("No scores")?;
+//!         Ok(best.0)
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # let environment = Environment::builder().build()?;
+//! let session = environment
+//!     .new_session_builder()?
+//!     .with_model_from_file("mnist.onnx")?;
+//! let mut model = Model::new(session, Normalize, ArgMax);
+//! let digit = model.run(vec![0; 28 * 28])?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt::Debug,
+    time::{Duration, Instant},
+};
+
+use ndarray::{Array, Dimension, IxDyn};
+
+use crate::{
+    error::{OrtError, Result},
+    session::Session,
+    tensor::OrtOwnedTensor,
+    TypeToTensorElementDataType,
+};
+
+/// Error returned by user supplied processing steps
+pub type ProcessingError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Result of user supplied processing steps
+pub type ProcessingResult<T> = std::result::Result<T, ProcessingError>;
+
+/// Conversion of a model's raw input into the arrays it runs on
+pub trait Preprocessor {
+    /// Raw input, for example encoded image bytes
+    type Input;
+    /// Element type of the model's inputs
+    type Elem: TypeToTensorElementDataType + Debug + Clone;
+    /// Dimensionality of the model's inputs
+    type Dim: Dimension;
+
+    /// Build the model's inputs, in the order of [`Session::inputs`](../session/struct.Session.html#structfield.inputs)
+    fn preprocess(&self, input: Self::Input)
+        -> ProcessingResult<Vec<Array<Self::Elem, Self::Dim>>>;
+}
+
+/// Conversion of a model's outputs into a typed result
+pub trait Postprocessor {
+    /// Element type of the model's outputs
+    type Elem: TypeToTensorElementDataType + Debug + Clone;
+    /// Typed result, for example a list of detected objects
+    type Output;
+
+    /// Build the result from the model's outputs, in the order of [`Session::outputs`](../session/struct.Session.html#structfield.outputs)
+    fn postprocess(
+        &self,
+        outputs: Vec<OrtOwnedTensor<Self::Elem, IxDyn>>,
+    ) -> ProcessingResult<Self::Output>;
+}
+
+/// Time spent in each step of a [`Model::run_timed()`](struct.Model.html#method.run_timed) call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModelTimings {
+    /// Time spent in the [`Preprocessor`](trait.Preprocessor.html)
+    pub preprocess: Duration,
+    /// Time spent running the session
+    pub inference: Duration,
+    /// Time spent in the [`Postprocessor`](trait.Postprocessor.html)
+    pub postprocess: Duration,
+}
+
+impl ModelTimings {
+    /// Time spent in all steps
+    pub fn total(&self) -> Duration {
+        self.preprocess + self.inference + self.postprocess
+    }
+}
+
+/// Session bundled with its pre- and post-processing, running raw inputs to typed results
+#[derive(Debug)]
+pub struct Model<'a, TPre, TPost> {
+    session: Session<'a>,
+    preprocessor: TPre,
+    postprocessor: TPost,
+}
+
+impl<'a, TPre, TPost> Model<'a, TPre, TPost>
+where
+    TPre: Preprocessor,
+    TPost: Postprocessor,
+{
+    /// Bundle a session with its pre- and post-processing
+    pub fn new(session: Session<'a>, preprocessor: TPre, postprocessor: TPost) -> Self {
+        Model {
+            session,
+            preprocessor,
+            postprocessor,
+        }
+    }
+
+    /// Run a raw input through pre-processing, the model and post-processing
+    ///
+    /// Failures of the processing steps are reported as
+    /// [`OrtError::Preprocessing`](../error/enum.OrtError.html#variant.Preprocessing) and
+    /// [`OrtError::Postprocessing`](../error/enum.OrtError.html#variant.Postprocessing).
+    pub fn run(&mut self, input: TPre::Input) -> Result<TPost::Output> {
+        self.run_timed(input).map(|(output, _)| output)
+    }
+
+    /// Same as [`run()`](#method.run), also returning the time spent in each step
+    pub fn run_timed(&mut self, input: TPre::Input) -> Result<(TPost::Output, ModelTimings)> {
+        let start = Instant::now();
+        let inputs = self
+            .preprocessor
+            .preprocess(input)
+            .map_err(OrtError::Preprocessing)?;
+        let preprocessed = Instant::now();
+
+        let outputs = self.session.run(inputs)?;
+        let inferred = Instant::now();
+
+        let output = self
+            .postprocessor
+            .postprocess(outputs)
+            .map_err(OrtError::Postprocessing)?;
+
+        let timings = ModelTimings {
+            preprocess: preprocessed - start,
+            inference: inferred - preprocessed,
+            postprocess: inferred.elapsed(),
+        };
+        Ok((output, timings))
+    }
+
+    /// Underlying session
+    pub fn session(&self) -> &Session<'a> {
+        &self.session
+    }
+
+    /// Underlying session, for example to end profiling
+    pub fn session_mut(&mut self) -> &mut Session<'a> {
+        &mut self.session
+    }
+
+    /// Pre-processing step
+    pub fn preprocessor(&self) -> &TPre {
+        &self.preprocessor
+    }
+
+    /// Post-processing step
+    pub fn postprocessor(&self) -> &TPost {
+        &self.postprocessor
+    }
+
+    /// Split the model back into its session and processing steps
+    pub fn into_parts(self) -> (Session<'a>, TPre, TPost) {
+        (self.session, self.preprocessor, self.postprocessor)
+    }
+}
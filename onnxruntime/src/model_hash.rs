@@ -0,0 +1,104 @@
+//! Module containing content hashes identifying models
+//!
+//! A [`ModelHash`](struct.ModelHash.html) is the SHA-256 digest of a model's bytes: it is the same
+//! for the same model whatever its file name, location or the way it was loaded, and changes
+//! whenever the model does. Use it as a cache key (for example for engine or warm-up caches) or
+//! to log exactly which model served a request.
+
+use std::{fmt, fs::File, io::Read, path::Path};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::{OrtError, Result};
+
+/// SHA-256 digest of a model's bytes
+///
+/// Displayed as 64 lowercase hexadecimal digits, like `sha256sum` prints it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModelHash([u8; 32]);
+
+impl ModelHash {
+    /// Hash of a model in memory
+    pub fn from_bytes(model_bytes: &[u8]) -> ModelHash {
+        ModelHash(Sha256::digest(model_bytes).into())
+    }
+
+    /// Hash of a model file, read in chunks
+    pub fn from_file<P>(model_filepath: P) -> Result<ModelHash>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::open(model_filepath).map_err(OrtError::ReadModel)?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0; 1 << 16];
+        loop {
+            match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => hasher.update(&buffer[..read]),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(OrtError::ReadModel(err)),
+            }
+        }
+        Ok(ModelHash(hasher.finalize().into()))
+    }
+
+    /// Raw digest
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Lowercase hexadecimal digest, for example to name a cache directory
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for ModelHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&to_hex(&self.0))
+    }
+}
+
+/// Lowercase hexadecimal representation of `bytes`
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use test_log::test;
+
+    #[test]
+    fn model_hash_test_vectors() {
+        assert_eq!(
+            ModelHash::from_bytes(b"").to_hex(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            ModelHash::from_bytes(b"abc").to_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            ModelHash::from_bytes(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+                .to_hex(),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        assert_eq!(
+            ModelHash::from_bytes(&vec![b'a'; 1_000_000]).to_hex(),
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+    }
+
+    #[test]
+    fn model_hash_from_file_matches_bytes() {
+        let bytes: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&bytes).unwrap();
+        assert_eq!(
+            ModelHash::from_file(file.path()).unwrap(),
+            ModelHash::from_bytes(&bytes)
+        );
+    }
+}
@@ -0,0 +1,744 @@
+//! Module containing a loader of model repositories laid out as for Triton
+//!
+//! A model repository is a directory with one sub-directory per model, itself holding one
+//! sub-directory per version, named with a positive integer, and optionally a `config.pbtxt`
+//! configuration file:
+//!
+//! ```text
+//! models/
+//! ├── resnet/
+//! │   ├── config.pbtxt
+//! │   ├── 1/
+//! │   │   └── model.onnx
+//! │   └── 2/
+//! │       └── model.onnx
+//! └── bert/
+//!     └── 1/
+//!         └── model.onnx
+//! ```
+//!
+//! A [`ModelRepository`](struct.ModelRepository.html) loads a session for each version of each
+//! model, and [`refresh()`](struct.ModelRepository.html#method.refresh) loads the versions added
+//! since, unloads the removed ones and reloads the ones whose model or configuration changed,
+//! without interrupting the runs of the others.
+//!
+//! Models sharing a machine or a GPU can be given a [`ModelQuota`](struct.ModelQuota.html), so
+//! that one busy model cannot starve the others.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, RwLock},
+    time::SystemTime,
+};
+
+use tracing::{info, warn};
+
+use crate::{
+    environment::Environment,
+    error::{OrtError, RepositoryError, Result},
+    execution_providers::CudaExecutionProvider,
+    session::{
+        ConcurrencyLimit, ConcurrencyMetrics, SessionBuilder, SharedInitializers, SwappableSession,
+    },
+};
+
+/// Name of the optional configuration file of each model
+pub const CONFIG_FILENAME: &str = "config.pbtxt";
+
+/// Name of the model file in each version directory, unless configured otherwise
+pub const DEFAULT_MODEL_FILENAME: &str = "model.onnx";
+
+/// Configuration of a model, read from its `config.pbtxt`
+///
+/// Only the top-level scalar fields of the protobuf text format are read, for example `name`,
+/// `platform`, `max_batch_size` or `default_model_filename`; nested messages such as `input`,
+/// `output` or `instance_group` are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelConfig {
+    entries: Vec<(String, String)>,
+}
+
+impl ModelConfig {
+    /// Parse the top-level scalar fields of a configuration in the protobuf text format
+    pub fn parse(text: &str) -> std::result::Result<ModelConfig, String> {
+        let tokens = tokenize(text)?;
+        let mut entries = Vec::new();
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            let key = match token {
+                Token::Word(key) => key,
+                Token::Punct(',') | Token::Punct(';') => continue,
+                other => return Err(format!("expected a field name, found {:?}", other)),
+            };
+            if tokens.peek() == Some(&Token::Punct(':')) {
+                tokens.next();
+            }
+            match tokens.next() {
+                Some(Token::Punct(open @ '{')) | Some(Token::Punct(open @ '[')) => {
+                    skip_block(&mut tokens, open)?
+                }
+                Some(Token::Word(value)) | Some(Token::Str(value)) => entries.push((key, value)),
+                other => return Err(format!("expected a value for {:?}, found {:?}", key, other)),
+            }
+        }
+        Ok(ModelConfig { entries })
+    }
+
+    /// Value of the top-level field `key`, if set
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(entry, _)| entry == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Top-level fields and their values, in order
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.entries
+    }
+
+    /// Maximum batch size the model supports, if it batches (`max_batch_size` above 0)
+    pub fn max_batch_size(&self) -> Option<usize> {
+        self.get("max_batch_size")
+            .and_then(|value| value.parse().ok())
+            .filter(|size| *size > 0)
+    }
+
+    /// Name of the model file in each version directory
+    pub fn model_filename(&self) -> &str {
+        self.get("default_model_filename")
+            .unwrap_or(DEFAULT_MODEL_FILENAME)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Punct(char),
+}
+
+fn tokenize(text: &str) -> std::result::Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' | '\'' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(end) if end == c => break,
+                        Some('\\') => value.extend(chars.next()),
+                        Some(other) => value.push(other),
+                        None => return Err("unterminated string".to_owned()),
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            '{' | '}' | '[' | ']' | '<' | '>' | ':' | ',' | ';' => tokens.push(Token::Punct(c)),
+            c if c.is_whitespace() => {}
+            c => {
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || "{}[]<>:,;#\"'".contains(next) {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn skip_block<I>(tokens: &mut I, open: char) -> std::result::Result<(), String>
+where
+    I: Iterator<Item = Token>,
+{
+    let mut depth = 1;
+    for token in tokens {
+        match token {
+            Token::Punct('{') | Token::Punct('[') => depth += 1,
+            Token::Punct('}') | Token::Punct(']') => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return Ok(());
+        }
+    }
+    Err(format!("unterminated {:?}", open))
+}
+
+/// Limits on the resources used by the sessions of a model
+///
+/// Limits left to `None` are not enforced, except the maximum batch size which defaults to the
+/// `max_batch_size` of the model's configuration, if above 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModelQuota {
+    /// Maximum number of runs at the same time, over all versions of the model
+    ///
+    /// Runs over the limit wait for a slot (see
+    /// [`ConcurrencyLimit`](../session/struct.ConcurrencyLimit.html)).
+    pub max_concurrent_runs: Option<usize>,
+    /// Maximum batch (first) dimension of the inputs of a run
+    ///
+    /// See [`SessionBuilder::with_max_batch_size()`](../session/struct.SessionBuilder.html#method.with_max_batch_size).
+    pub max_batch_size: Option<usize>,
+    /// CUDA device to run the model on
+    ///
+    /// The CUDA execution provider is appended to the session builder for this device before
+    /// the builder is configured with the function given to
+    /// [`ModelRepository::with_session_builder()`](struct.ModelRepository.html#method.with_session_builder),
+    /// so it takes precedence over providers appended there.
+    pub device_id: Option<i32>,
+}
+
+/// Identifier of a version of a model in a repository
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModelVersion {
+    /// Name of the model, the name of its directory
+    pub name: String,
+    /// Version of the model, the name of its version directory
+    pub version: u64,
+}
+
+/// Changes applied by [`ModelRepository::refresh()`](struct.ModelRepository.html#method.refresh)
+#[derive(Debug, Default)]
+pub struct RepositoryChanges {
+    /// Versions loaded for the first time
+    pub loaded: Vec<ModelVersion>,
+    /// Versions whose model or configuration changed, and were reloaded
+    pub reloaded: Vec<ModelVersion>,
+    /// Versions removed from the repository, and unloaded
+    pub unloaded: Vec<ModelVersion>,
+    /// Versions that failed to load or reload; reloaded versions keep serving their previous session
+    pub failed: Vec<(ModelVersion, OrtError)>,
+}
+
+impl RepositoryChanges {
+    /// Whether the refresh changed nothing
+    pub fn is_empty(&self) -> bool {
+        self.loaded.is_empty()
+            && self.reloaded.is_empty()
+            && self.unloaded.is_empty()
+            && self.failed.is_empty()
+    }
+}
+
+/// Last modification time and length of a model file, and its model's configuration, to detect changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fingerprint {
+    modified: Option<SystemTime>,
+    len: u64,
+    config: ModelConfig,
+}
+
+#[derive(Debug)]
+struct LoadedVersion<'a> {
+    session: Arc<SwappableSession<'a>>,
+    fingerprint: Fingerprint,
+}
+
+#[derive(Debug)]
+struct LoadedModel<'a> {
+    config: ModelConfig,
+    versions: BTreeMap<u64, LoadedVersion<'a>>,
+}
+
+/// Version directory found by a scan of the repository
+#[derive(Debug)]
+struct ScannedVersion {
+    model: ModelVersion,
+    path: PathBuf,
+    fingerprint: Fingerprint,
+}
+
+type Configure<'a> =
+    Box<dyn Fn(SessionBuilder<'a>, &ModelConfig) -> Result<SessionBuilder<'a>> + Send + Sync + 'a>;
+
+/// Sessions of the models of a repository directory, by name and version
+///
+/// Lookups return [`SwappableSession`s](../session/struct.SwappableSession.html), which keep
+/// serving their current model while a refresh reloads it. Lookups and runs can happen from any
+/// thread while another one refreshes the repository, for example periodically.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, model_repository::ModelRepository, GraphOptimizationLevel};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let repository = ModelRepository::new(&environment, "models").with_session_builder(
+///     |builder, _config| builder.with_optimization_level(GraphOptimizationLevel::All),
+/// );
+/// let changes = repository.refresh()?;
+/// assert!(changes.failed.is_empty());
+///
+/// let latest = repository.session("resnet", None)?;
+/// let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224));
+/// let outputs = latest.run::<_, f32>([input])?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ModelRepository<'a> {
+    environment: &'a Environment,
+    root: PathBuf,
+    configure: Configure<'a>,
+    quotas: HashMap<String, ModelQuota>,
+    default_quota: ModelQuota,
+    concurrency_limits: Mutex<HashMap<String, ConcurrencyLimit>>,
+    shared_initializers: Option<SharedInitializers>,
+    models: RwLock<BTreeMap<String, LoadedModel<'a>>>,
+    refreshing: Mutex<()>,
+}
+
+impl<'a> std::fmt::Debug for ModelRepository<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModelRepository")
+            .field("root", &self.root)
+            .field("quotas", &self.quotas)
+            .field("default_quota", &self.default_quota)
+            .field("models", &self.models)
+            .finish()
+    }
+}
+
+impl<'a> ModelRepository<'a> {
+    /// Repository of the models in directory `root`, none of which is loaded until
+    /// [`refresh()`](#method.refresh) is called
+    pub fn new<P>(environment: &'a Environment, root: P) -> ModelRepository<'a>
+    where
+        P: Into<PathBuf>,
+    {
+        ModelRepository {
+            environment,
+            root: root.into(),
+            configure: Box::new(|builder, _| Ok(builder)),
+            quotas: HashMap::new(),
+            default_quota: ModelQuota::default(),
+            concurrency_limits: Mutex::new(HashMap::new()),
+            shared_initializers: None,
+            models: RwLock::new(BTreeMap::new()),
+            refreshing: Mutex::new(()),
+        }
+    }
+
+    /// Configure the session builder of each version with `configure`, given the model's configuration
+    pub fn with_session_builder<F>(mut self, configure: F) -> ModelRepository<'a>
+    where
+        F: Fn(SessionBuilder<'a>, &ModelConfig) -> Result<SessionBuilder<'a>> + Send + Sync + 'a,
+    {
+        self.configure = Box::new(configure);
+        self
+    }
+
+    /// Limit the resources used by model `name`, from the next time its versions are loaded
+    pub fn with_quota<S>(mut self, name: S, quota: ModelQuota) -> ModelRepository<'a>
+    where
+        S: Into<String>,
+    {
+        self.quotas.insert(name.into(), quota);
+        self
+    }
+
+    /// Limit the resources used by each model without a quota of its own
+    pub fn with_default_quota(mut self, quota: ModelQuota) -> ModelRepository<'a> {
+        self.default_quota = quota;
+        self
+    }
+
+    /// Share identical initializers (weights) between the sessions of all versions of all models,
+    /// from the next time they are loaded
+    ///
+    /// Versions differing in a few tensors then hold the others in memory once, including while
+    /// a reload loads a new model next to the one it replaces (see
+    /// [`SharedInitializers`](../session/struct.SharedInitializers.html)).
+    pub fn with_shared_initializers(mut self, shared: SharedInitializers) -> ModelRepository<'a> {
+        self.shared_initializers = Some(shared);
+        self
+    }
+
+    /// Quota of model `name`
+    pub fn quota(&self, name: &str) -> ModelQuota {
+        self.quotas.get(name).copied().unwrap_or(self.default_quota)
+    }
+
+    /// Wait times of the runs of model `name`, if its quota limits concurrent runs and it was loaded
+    pub fn concurrency_metrics(&self, name: &str) -> Option<ConcurrencyMetrics> {
+        self.concurrency_limits
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .get(name)
+            .map(ConcurrencyLimit::metrics)
+    }
+
+    /// Directory of the repository
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Scan the repository, loading, reloading and unloading versions to match its content
+    ///
+    /// Fails only if the repository directory cannot be read; errors loading a given version
+    /// are reported in the returned changes, and other versions are still loaded.
+    pub fn refresh(&self) -> Result<RepositoryChanges> {
+        let _refreshing = self
+            .refreshing
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?");
+        let scanned = scan(&self.root)?;
+        let mut changes = RepositoryChanges::default();
+
+        // Load outside of the models' lock, so that lookups are not blocked meanwhile
+        let mut loaded = Vec::new();
+        for version in scanned {
+            let existing = self.loaded(&version.model);
+            match existing {
+                Some((_, fingerprint)) if fingerprint == version.fingerprint => {
+                    loaded.push((version, None));
+                }
+                Some((session, _)) => {
+                    match session.reload(|| self.load(&version), |_| Ok(())) {
+                        Ok(_) => {
+                            info!(
+                                model = version.model.name.as_str(),
+                                version = version.model.version,
+                                "Model reloaded."
+                            );
+                            changes.reloaded.push(version.model.clone());
+                            loaded.push((version, Some(session)));
+                        }
+                        Err(err) => {
+                            warn!(
+                                model = version.model.name.as_str(),
+                                version = version.model.version,
+                                "Failed to reload model: {}",
+                                err
+                            );
+                            changes.failed.push((version.model.clone(), err));
+                            // Keep the previous fingerprint, to retry at the next refresh
+                            loaded.push((version, None));
+                        }
+                    }
+                }
+                None => match self.load(&version) {
+                    Ok(session) => {
+                        info!(
+                            model = version.model.name.as_str(),
+                            version = version.model.version,
+                            "Model loaded."
+                        );
+                        changes.loaded.push(version.model.clone());
+                        loaded.push((version, Some(Arc::new(SwappableSession::new(session)))));
+                    }
+                    Err(err) => {
+                        warn!(
+                            model = version.model.name.as_str(),
+                            version = version.model.version,
+                            "Failed to load model: {}",
+                            err
+                        );
+                        changes.failed.push((version.model, err));
+                    }
+                },
+            }
+        }
+
+        let mut models = self
+            .models
+            .write()
+            .expect("Failed to acquire lock: another thread panicked?");
+        let mut previous = std::mem::take(&mut *models);
+        for (version, session) in loaded {
+            let config = version.fingerprint.config.clone();
+            let previous_version = previous
+                .get_mut(&version.model.name)
+                .and_then(|model| model.versions.remove(&version.model.version));
+            let loaded_version = match (session, previous_version) {
+                (Some(session), _) => LoadedVersion {
+                    session,
+                    fingerprint: version.fingerprint,
+                },
+                (None, Some(previous_version)) => previous_version,
+                (None, None) => continue,
+            };
+            let model = models
+                .entry(version.model.name)
+                .or_insert_with(|| LoadedModel {
+                    config: config.clone(),
+                    versions: BTreeMap::new(),
+                });
+            model.config = config;
+            model.versions.insert(version.model.version, loaded_version);
+        }
+        // Versions that failed to load for the first time are not in `previous` either
+        for (name, model) in previous {
+            for version in model.versions.into_keys() {
+                let model = ModelVersion {
+                    name: name.clone(),
+                    version,
+                };
+                info!(
+                    model = model.name.as_str(),
+                    version = model.version,
+                    "Model unloaded."
+                );
+                changes.unloaded.push(model);
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Session of version `version` of model `name`, or of its latest version if `None`
+    pub fn session(&self, name: &str, version: Option<u64>) -> Result<Arc<SwappableSession<'a>>> {
+        let models = self
+            .models
+            .read()
+            .expect("Failed to acquire lock: another thread panicked?");
+        let versions = models.get(name).map(|model| &model.versions);
+        let loaded = match version {
+            Some(version) => versions.and_then(|versions| versions.get(&version)),
+            None => versions.and_then(|versions| versions.values().next_back()),
+        };
+        loaded
+            .map(|loaded| Arc::clone(&loaded.session))
+            .ok_or_else(|| {
+                RepositoryError::UnknownModel {
+                    name: name.to_owned(),
+                    version,
+                }
+                .into()
+            })
+    }
+
+    /// Names of the models loaded, in alphabetical order
+    pub fn models(&self) -> Vec<String> {
+        self.models
+            .read()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Versions of model `name` loaded, in increasing order
+    pub fn versions(&self, name: &str) -> Vec<u64> {
+        self.models
+            .read()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .get(name)
+            .map(|model| model.versions.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Configuration of model `name`, if loaded
+    pub fn config(&self, name: &str) -> Option<ModelConfig> {
+        self.models
+            .read()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .get(name)
+            .map(|model| model.config.clone())
+    }
+
+    fn loaded(&self, model: &ModelVersion) -> Option<(Arc<SwappableSession<'a>>, Fingerprint)> {
+        self.models
+            .read()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .get(&model.name)
+            .and_then(|loaded| loaded.versions.get(&model.version))
+            .map(|loaded| (Arc::clone(&loaded.session), loaded.fingerprint.clone()))
+    }
+
+    fn load(&self, version: &ScannedVersion) -> Result<crate::session::Session<'a>> {
+        let config = &version.fingerprint.config;
+        let quota = self.quota(&version.model.name);
+        let mut builder = self.environment.new_session_builder()?;
+        if let Some(device_id) = quota.device_id {
+            builder = builder.with_execution_provider(CudaExecutionProvider::new(device_id))?;
+        }
+        if let Some(max_concurrent_runs) = quota.max_concurrent_runs {
+            // Shared by all versions of the model, and kept across reloads
+            let limit = self
+                .concurrency_limits
+                .lock()
+                .expect("Failed to acquire lock: another thread panicked?")
+                .entry(version.model.name.clone())
+                .or_insert_with(|| ConcurrencyLimit::new(max_concurrent_runs))
+                .clone();
+            builder = builder.with_concurrency_limit(limit)?;
+        }
+        if let Some(max_batch_size) = quota.max_batch_size.or_else(|| config.max_batch_size()) {
+            builder = builder.with_max_batch_size(max_batch_size)?;
+        }
+        if let Some(shared) = &self.shared_initializers {
+            builder = builder.with_shared_initializers(shared.clone())?;
+        }
+        (self.configure)(builder, config)?.with_model_from_file(version.path.clone())
+    }
+}
+
+/// Versions of the models in `root` with a model file
+fn scan(root: &Path) -> Result<Vec<ScannedVersion>> {
+    let mut scanned = Vec::new();
+    for model_dir in read_dir(root)? {
+        let name = match model_dir.file_name().and_then(|name| name.to_str()) {
+            Some(name) if model_dir.is_dir() && !name.starts_with('.') => name.to_owned(),
+            _ => continue,
+        };
+        let config_path = model_dir.join(CONFIG_FILENAME);
+        let config = match fs::read_to_string(&config_path) {
+            Ok(text) => {
+                ModelConfig::parse(&text).map_err(|message| RepositoryError::InvalidConfig {
+                    path: config_path.clone(),
+                    message,
+                })?
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => ModelConfig::default(),
+            Err(source) => {
+                return Err(RepositoryError::IoError {
+                    path: config_path,
+                    source,
+                }
+                .into())
+            }
+        };
+        for version_dir in read_dir(&model_dir)? {
+            let version = match version_dir
+                .file_name()
+                .and_then(|version| version.to_str())
+                .and_then(|version| version.parse::<u64>().ok())
+            {
+                Some(version) if version > 0 && version_dir.is_dir() => version,
+                _ => continue,
+            };
+            let path = version_dir.join(config.model_filename());
+            let metadata = match fs::metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(source) => return Err(RepositoryError::IoError { path, source }.into()),
+            };
+            scanned.push(ScannedVersion {
+                model: ModelVersion {
+                    name: name.clone(),
+                    version,
+                },
+                path,
+                fingerprint: Fingerprint {
+                    modified: metadata.modified().ok(),
+                    len: metadata.len(),
+                    config: config.clone(),
+                },
+            });
+        }
+    }
+    scanned.sort_by(|a, b| a.model.cmp(&b.model));
+    Ok(scanned)
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    let io_error = |source| RepositoryError::IoError {
+        path: dir.to_owned(),
+        source,
+    };
+    fs::read_dir(dir)
+        .map_err(io_error)?
+        .map(|entry| entry.map(|entry| entry.path()).map_err(io_error))
+        .collect::<std::result::Result<_, _>>()
+        .map_err(OrtError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn model_config_parse() {
+        let config = ModelConfig::parse(
+            r#"
+            name: "resnet"  # Triton ignores the name of the directory if set
+            platform: "onnxruntime_onnx"
+            max_batch_size: 8
+            input [
+              { name: "input" data_type: TYPE_FP32 dims: [ 3, 224, 224 ] }
+            ]
+            instance_group { count: 2 kind: KIND_GPU }
+            default_model_filename: 'resnet50.onnx'
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.get("name"), Some("resnet"));
+        assert_eq!(config.get("data_type"), None);
+        assert_eq!(config.max_batch_size(), Some(8));
+        assert_eq!(config.model_filename(), "resnet50.onnx");
+        assert_eq!(config.entries().len(), 4);
+
+        assert_eq!(
+            ModelConfig::default().model_filename(),
+            DEFAULT_MODEL_FILENAME
+        );
+        assert!(ModelConfig::parse("input [ { name: \"x\" }").is_err());
+        assert!(ModelConfig::parse("name: \"x").is_err());
+    }
+
+    #[test]
+    fn scan_repository() {
+        let root = tempfile::tempdir().unwrap();
+        let create = |path: &str| {
+            let path = root.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, b"onnx").unwrap();
+        };
+        create("resnet/1/model.onnx");
+        create("resnet/3/model.onnx");
+        create("resnet/latest/model.onnx");
+        create("resnet/0/model.onnx");
+        create("bert/2/bert.onnx");
+        create("bert/4/model.onnx");
+        fs::write(
+            root.path().join("bert").join(CONFIG_FILENAME),
+            "default_model_filename: \"bert.onnx\"",
+        )
+        .unwrap();
+        create("empty/1/README");
+        create(".git/1/model.onnx");
+
+        let scanned: Vec<_> = scan(root.path())
+            .unwrap()
+            .into_iter()
+            .map(|version| (version.model.name, version.model.version))
+            .collect();
+        assert_eq!(
+            scanned,
+            [
+                ("bert".to_owned(), 2),
+                ("resnet".to_owned(), 1),
+                ("resnet".to_owned(), 3)
+            ]
+        );
+
+        fs::write(
+            root.path().join("bert").join(CONFIG_FILENAME),
+            "max_batch_size {",
+        )
+        .unwrap();
+        assert!(matches!(
+            scan(root.path()),
+            Err(OrtError::Repository(RepositoryError::InvalidConfig { .. }))
+        ));
+        assert!(scan(&root.path().join("missing")).is_err());
+    }
+}
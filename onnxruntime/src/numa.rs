@@ -0,0 +1,107 @@
+//! Module placing output buffers on a given NUMA node
+//!
+//! On multi-socket machines, memory is faster to access from the socket it is attached to. The
+//! runtime allocates outputs wherever its threads run; copies of the outputs can instead be
+//! placed on the node of the thread consuming them (see
+//! [`SessionBuilder::with_output_numa_node()`](../session/struct.SessionBuilder.html#method.with_output_numa_node)).
+//! Placement requires the `numa` feature and Linux; elsewhere buffers are allocated as usual.
+
+/// Number of NUMA nodes the placement supports (that of the kernel's default configuration)
+#[cfg_attr(not(feature = "numa"), allow(dead_code))]
+pub(crate) const MAX_NUMA_NODES: u32 = 1024;
+
+/// Empty vector with room for `capacity` elements, whose memory is preferably placed on `node`
+///
+/// The placement applies to the pages of the buffer not yet touched, so to large buffers (which
+/// the allocator maps afresh) rather than small ones. Writing the elements places the pages.
+pub(crate) fn vec_on_node<T>(capacity: usize, node: Option<u32>) -> Vec<T> {
+    let vec = Vec::with_capacity(capacity);
+    if let Some(node) = node {
+        place_on_node(
+            vec.as_ptr() as usize,
+            capacity * std::mem::size_of::<T>(),
+            node,
+        );
+    }
+    vec
+}
+
+/// Copy of `data`, preferably placed on `node`
+pub(crate) fn copy_to_node<T>(data: &[T], node: Option<u32>) -> Vec<T>
+where
+    T: Clone,
+{
+    let mut vec = vec_on_node(data.len(), node);
+    vec.extend_from_slice(data);
+    vec
+}
+
+#[cfg(all(target_os = "linux", feature = "numa"))]
+fn place_on_node(start: usize, len: usize, node: u32) {
+    use tracing::warn;
+
+    /// `MPOL_PREFERRED` memory policy: allocate on the node, or elsewhere if it is full
+    const MPOL_PREFERRED: libc::c_long = 1;
+
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+    let (first, pages_len) = match whole_pages(start, len, page_size) {
+        Some(range) => range,
+        None => return,
+    };
+
+    let mut node_mask = [0_u64; (MAX_NUMA_NODES / 64) as usize];
+    node_mask[(node / 64) as usize] |= 1 << (node % 64);
+    let status = unsafe {
+        libc::syscall(
+            libc::SYS_mbind,
+            first as *mut libc::c_void,
+            pages_len,
+            MPOL_PREFERRED,
+            node_mask.as_ptr(),
+            // The kernel expects the number of bits of the mask plus one
+            MAX_NUMA_NODES as libc::c_ulong + 1,
+            0,
+        )
+    };
+    if status != 0 {
+        warn!(
+            node,
+            error = std::io::Error::last_os_error().to_string().as_str(),
+            "Failed to place output buffer on NUMA node."
+        );
+    }
+}
+
+#[cfg(not(all(target_os = "linux", feature = "numa")))]
+fn place_on_node(_start: usize, _len: usize, _node: u32) {}
+
+/// Start and length of the whole pages within `len` bytes from `start`, if any
+///
+/// The pages the buffer shares with other allocations are left alone.
+#[cfg_attr(not(all(target_os = "linux", feature = "numa")), allow(dead_code))]
+fn whole_pages(start: usize, len: usize, page_size: usize) -> Option<(usize, usize)> {
+    let first = start.div_ceil(page_size) * page_size;
+    let end = (start + len) / page_size * page_size;
+    (end > first).then(|| (first, end - first))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn only_whole_pages_are_placed() {
+        assert_eq!(whole_pages(4096, 8192, 4096), Some((4096, 8192)));
+        assert_eq!(whole_pages(4112, 3 * 4096, 4096), Some((8192, 8192)));
+        assert_eq!(whole_pages(4112, 4096, 4096), None);
+        assert_eq!(whole_pages(4096, 0, 4096), None);
+    }
+
+    #[test]
+    fn copy_keeps_elements() {
+        let data: Vec<f32> = (0..100_000).map(|i| i as f32).collect();
+        assert_eq!(copy_to_node(&data, Some(0)), data);
+        assert_eq!(copy_to_node(&data, None), data);
+    }
+}
@@ -0,0 +1,590 @@
+//! Module reading the metadata of ORT format (`.ort`) models
+//!
+//! Minimal builds of the runtime only load models converted to the ORT format, a flatbuffers
+//! serialization of the optimized graph, which the protobuf based tools cannot read. An
+//! [`OrtFormatModel`](struct.OrtFormatModel.html) describes such a model (runtime version it
+//! was converted with, opsets, inputs and outputs) without creating a session.
+//!
+//! Only the parts of the schema (`onnxruntime/core/flatbuffers/schema/ort.fbs`) needed for this
+//! description are read; the buffer is verified before anything is read from it.
+//!
+//! ```no_run
+//! # use std::error::Error;
+//! # use onnxruntime::ort_format::OrtFormatModel;
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let model = OrtFormatModel::from_file("model.ort")?;
+//! for input in &model.inputs {
+//!     println!("{}: {:?}", input.name, input.type_info);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{convert::TryFrom, path::Path};
+
+use crate::{
+    error::{OrtError, OrtFormatError, Result},
+    type_info::{TensorInfo, TypeInfo},
+    TensorElementDataType,
+};
+
+/// File identifier of ORT format models, at bytes 4 to 8
+pub const FILE_IDENTIFIER: &str = "ORTM";
+
+/// Metadata of an ORT format model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrtFormatModel {
+    /// Version of the runtime the model was converted with
+    pub ort_version: String,
+    /// Version of the ONNX IR of the model
+    pub ir_version: i64,
+    /// Opsets imported by the model
+    pub opsets: Vec<Opset>,
+    /// Name of the tool that produced the model
+    pub producer_name: String,
+    /// Version of the tool that produced the model
+    pub producer_version: String,
+    /// Domain of the model
+    pub domain: String,
+    /// Version of the model
+    pub model_version: i64,
+    /// Documentation of the model
+    pub doc_string: String,
+    /// Inputs of the graph, in order
+    pub inputs: Vec<ValueInfo>,
+    /// Outputs of the graph, in order
+    pub outputs: Vec<ValueInfo>,
+    /// Metadata properties, as keys and values
+    pub metadata: Vec<(String, String)>,
+}
+
+/// Opset imported by a model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opset {
+    /// Domain of the opset, empty for the default ONNX domain
+    pub domain: String,
+    /// Version of the opset
+    pub version: i64,
+}
+
+/// Input or output of an ORT format model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueInfo {
+    /// Name of the input or output
+    pub name: String,
+    /// Type of the input or output, if stored in the model
+    ///
+    /// Dimensions given by name (symbolic dimensions) are dynamic, `None`.
+    pub type_info: Option<TypeInfo>,
+}
+
+impl OrtFormatModel {
+    /// Whether `bytes` start like an ORT format model, as opposed to an ONNX protobuf
+    pub fn is_ort_format(bytes: &[u8]) -> bool {
+        bytes.len() >= 8 && flatbuffers::buffer_has_identifier(bytes, FILE_IDENTIFIER, false)
+    }
+
+    /// Read the metadata of an ORT format model in memory
+    pub fn from_bytes(bytes: &[u8]) -> Result<OrtFormatModel> {
+        if !OrtFormatModel::is_ort_format(bytes) {
+            return Err(OrtFormatError::NotOrtFormat.into());
+        }
+        let session = flatbuffers::root::<fbs::InferenceSession>(bytes)
+            .map_err(OrtFormatError::InvalidFlatbuffer)?;
+        let model = session.model().ok_or(OrtFormatError::MissingModel)?;
+
+        let node_args: Vec<fbs::ValueInfo<'_>> = model
+            .graph()
+            .and_then(|graph| graph.node_args())
+            .map(|node_args| node_args.iter().collect())
+            .unwrap_or_default();
+        let graph = model.graph();
+
+        Ok(OrtFormatModel {
+            ort_version: session.ort_version().unwrap_or_default().to_owned(),
+            ir_version: model.ir_version(),
+            opsets: model
+                .opset_import()
+                .iter()
+                .flat_map(|opsets| opsets.iter())
+                .map(|opset| Opset {
+                    domain: opset.domain().unwrap_or_default().to_owned(),
+                    version: opset.version(),
+                })
+                .collect(),
+            producer_name: model.producer_name().unwrap_or_default().to_owned(),
+            producer_version: model.producer_version().unwrap_or_default().to_owned(),
+            domain: model.domain().unwrap_or_default().to_owned(),
+            model_version: model.model_version(),
+            doc_string: model.doc_string().unwrap_or_default().to_owned(),
+            inputs: value_infos(graph.and_then(|graph| graph.inputs()), &node_args),
+            outputs: value_infos(graph.and_then(|graph| graph.outputs()), &node_args),
+            metadata: model
+                .metadata_props()
+                .iter()
+                .flat_map(|props| props.iter())
+                .map(|entry| {
+                    (
+                        entry.key().unwrap_or_default().to_owned(),
+                        entry.value().unwrap_or_default().to_owned(),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    /// Read the metadata of an ORT format model file
+    pub fn from_file<P>(model_filepath: P) -> Result<OrtFormatModel>
+    where
+        P: AsRef<Path>,
+    {
+        let bytes = std::fs::read(model_filepath).map_err(OrtError::ReadModel)?;
+        OrtFormatModel::from_bytes(&bytes)
+    }
+}
+
+/// Inputs or outputs named `names`, with their type found among the graph's `node_args`
+fn value_infos(
+    names: Option<fbs::Strings<'_>>,
+    node_args: &[fbs::ValueInfo<'_>],
+) -> Vec<ValueInfo> {
+    names
+        .iter()
+        .flat_map(|names| names.iter())
+        .map(|name| ValueInfo {
+            name: name.to_owned(),
+            type_info: node_args
+                .iter()
+                .find(|node_arg| node_arg.name() == Some(name))
+                .and_then(|node_arg| node_arg.type_info())
+                .and_then(type_info),
+        })
+        .collect()
+}
+
+/// Type of a value, `None` if the model does not describe it
+fn type_info(value: fbs::TypeInfo<'_>) -> Option<TypeInfo> {
+    match value.value_type() {
+        fbs::TYPE_INFO_VALUE_TENSOR_TYPE => {
+            let tensor = value.value_as::<fbs::TensorTypeAndShape>()?;
+            let dimensions = tensor
+                .shape()
+                .and_then(|shape| shape.dim())
+                .iter()
+                .flat_map(|dims| dims.iter())
+                .map(|dim| {
+                    dim.value()
+                        .filter(|dim| dim.dim_type() == fbs::DIMENSION_VALUE_TYPE_VALUE)
+                        .and_then(|dim| u32::try_from(dim.dim_value()).ok())
+                })
+                .collect();
+            Some(TypeInfo::Tensor(TensorInfo {
+                element_type: element_type(tensor.elem_type()),
+                dimensions,
+            }))
+        }
+        fbs::TYPE_INFO_VALUE_SEQUENCE_TYPE => {
+            let sequence = value.value_as::<fbs::SequenceType>()?;
+            Some(TypeInfo::Sequence(Box::new(
+                sequence.elem_type().and_then(type_info)?,
+            )))
+        }
+        fbs::TYPE_INFO_VALUE_MAP_TYPE => {
+            let map = value.value_as::<fbs::MapType>()?;
+            Some(TypeInfo::Map {
+                key: element_type(map.key_type())?,
+                value: Box::new(map.value_type().and_then(type_info)?),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Element type from its `TensorProto.DataType` value, which the runtime's enum shares
+fn element_type(data_type: i32) -> Option<TensorElementDataType> {
+    use TensorElementDataType::*;
+    Some(match data_type {
+        1 => Float,
+        2 => Uint8,
+        3 => Int8,
+        4 => Uint16,
+        5 => Int16,
+        6 => Int32,
+        7 => Int64,
+        8 => String,
+        10 => Float16,
+        11 => Double,
+        12 => Uint32,
+        13 => Uint64,
+        16 => Bfloat16,
+        _ => return None,
+    })
+}
+
+/// Accessors of the tables of `ort.fbs` read by this module, as `flatc` would generate them
+mod fbs {
+    use flatbuffers::{ForwardsUOffset, Table, VOffsetT, Vector, Verifiable, Verifier};
+
+    pub(super) const TYPE_INFO_VALUE_TENSOR_TYPE: u8 = 1;
+    pub(super) const TYPE_INFO_VALUE_SEQUENCE_TYPE: u8 = 2;
+    pub(super) const TYPE_INFO_VALUE_MAP_TYPE: u8 = 3;
+    pub(super) const DIMENSION_VALUE_TYPE_VALUE: i8 = 1;
+
+    /// Offset in the vtable of the field with the given index in the schema
+    const fn field(index: VOffsetT) -> VOffsetT {
+        4 + 2 * index
+    }
+
+    pub(super) type Strings<'a> = Vector<'a, ForwardsUOffset<&'a str>>;
+    type Tables<'a, T> = Vector<'a, ForwardsUOffset<T>>;
+
+    macro_rules! table {
+        ($name:ident {
+            $($field:ident: $ty:ty = $index:literal),* $(,)?
+        } $(union $union:ident = $union_index:literal { $($key:literal => $variant:ident),* })?) => {
+            #[derive(Clone, Copy)]
+            pub(super) struct $name<'a> {
+                table: Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for $name<'a> {
+                type Inner = $name<'a>;
+
+                unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    $name {
+                        table: Table::new(buf, loc),
+                    }
+                }
+            }
+
+            impl Verifiable for $name<'_> {
+                fn run_verifier(
+                    v: &mut Verifier<'_, '_>,
+                    pos: usize,
+                ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+                    let table = v.visit_table(pos)?;
+                    $(let table = table.visit_field::<$ty>(
+                        stringify!($field),
+                        field($index),
+                        false,
+                    )?;)*
+                    $(let table = table.visit_union::<u8, _>(
+                        concat!(stringify!($union), "_type"),
+                        field($union_index - 1),
+                        stringify!($union),
+                        field($union_index),
+                        false,
+                        |key, v, pos| match key {
+                            $($key => v.verify_union_variant::<ForwardsUOffset<$variant>>(
+                                stringify!($variant),
+                                pos,
+                            ),)*
+                            _ => Ok(()),
+                        },
+                    )?;)?
+                    table.finish();
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    macro_rules! accessor {
+        ($field:ident: $ty:ty = $index:literal) => {
+            pub(super) fn $field(&self) -> Option<<$ty as flatbuffers::Follow<'a>>::Inner> {
+                // Safety: the field was checked to have this type when verifying the buffer
+                unsafe { self.table.get::<$ty>(field($index), None) }
+            }
+        };
+        ($field:ident: $ty:ty = $index:literal, default $default:expr) => {
+            pub(super) fn $field(&self) -> $ty {
+                // Safety: the field was checked to have this type when verifying the buffer
+                unsafe { self.table.get::<$ty>(field($index), Some($default)) }.unwrap()
+            }
+        };
+    }
+
+    table!(InferenceSession {
+        ort_version: ForwardsUOffset<&str> = 0,
+        model: ForwardsUOffset<Model> = 1,
+    });
+
+    impl<'a> InferenceSession<'a> {
+        accessor!(ort_version: ForwardsUOffset<&'a str> = 0);
+        accessor!(model: ForwardsUOffset<Model<'a>> = 1);
+    }
+
+    table!(Model {
+        ir_version: i64 = 0,
+        opset_import: ForwardsUOffset<Tables<'_, OperatorSetId>> = 1,
+        producer_name: ForwardsUOffset<&str> = 2,
+        producer_version: ForwardsUOffset<&str> = 3,
+        domain: ForwardsUOffset<&str> = 4,
+        model_version: i64 = 5,
+        doc_string: ForwardsUOffset<&str> = 6,
+        graph: ForwardsUOffset<Graph> = 7,
+        metadata_props: ForwardsUOffset<Tables<'_, StringStringEntry>> = 9,
+    });
+
+    impl<'a> Model<'a> {
+        accessor!(ir_version: i64 = 0, default 0);
+        accessor!(opset_import: ForwardsUOffset<Tables<'a, OperatorSetId<'a>>> = 1);
+        accessor!(producer_name: ForwardsUOffset<&'a str> = 2);
+        accessor!(producer_version: ForwardsUOffset<&'a str> = 3);
+        accessor!(domain: ForwardsUOffset<&'a str> = 4);
+        accessor!(model_version: i64 = 5, default 0);
+        accessor!(doc_string: ForwardsUOffset<&'a str> = 6);
+        accessor!(graph: ForwardsUOffset<Graph<'a>> = 7);
+        accessor!(metadata_props: ForwardsUOffset<Tables<'a, StringStringEntry<'a>>> = 9);
+    }
+
+    table!(OperatorSetId {
+        domain: ForwardsUOffset<&str> = 0,
+        version: i64 = 1,
+    });
+
+    impl<'a> OperatorSetId<'a> {
+        accessor!(domain: ForwardsUOffset<&'a str> = 0);
+        accessor!(version: i64 = 1, default 0);
+    }
+
+    table!(StringStringEntry {
+        key: ForwardsUOffset<&str> = 0,
+        value: ForwardsUOffset<&str> = 1,
+    });
+
+    impl<'a> StringStringEntry<'a> {
+        accessor!(key: ForwardsUOffset<&'a str> = 0);
+        accessor!(value: ForwardsUOffset<&'a str> = 1);
+    }
+
+    table!(Graph {
+        node_args: ForwardsUOffset<Tables<'_, ValueInfo>> = 1,
+        inputs: ForwardsUOffset<Strings<'_>> = 5,
+        outputs: ForwardsUOffset<Strings<'_>> = 6,
+    });
+
+    impl<'a> Graph<'a> {
+        accessor!(node_args: ForwardsUOffset<Tables<'a, ValueInfo<'a>>> = 1);
+        accessor!(inputs: ForwardsUOffset<Strings<'a>> = 5);
+        accessor!(outputs: ForwardsUOffset<Strings<'a>> = 6);
+    }
+
+    table!(ValueInfo {
+        name: ForwardsUOffset<&str> = 0,
+        type_info: ForwardsUOffset<TypeInfo> = 2,
+    });
+
+    impl<'a> ValueInfo<'a> {
+        accessor!(name: ForwardsUOffset<&'a str> = 0);
+        accessor!(type_info: ForwardsUOffset<TypeInfo<'a>> = 2);
+    }
+
+    table!(TypeInfo {} union value = 2 {
+        1 => TensorTypeAndShape,
+        2 => SequenceType,
+        3 => MapType
+    });
+
+    impl<'a> TypeInfo<'a> {
+        accessor!(value_type: u8 = 1, default 0);
+
+        /// Value of the union, which is a `T` as told by `value_type()`
+        pub(super) fn value_as<T>(&self) -> Option<T>
+        where
+            T: flatbuffers::Follow<'a, Inner = T> + 'a,
+        {
+            // Safety: the union was verified according to its type
+            unsafe { self.table.get::<ForwardsUOffset<T>>(field(2), None) }
+        }
+    }
+
+    table!(TensorTypeAndShape {
+        elem_type: i32 = 0,
+        shape: ForwardsUOffset<Shape> = 1,
+    });
+
+    impl<'a> TensorTypeAndShape<'a> {
+        accessor!(elem_type: i32 = 0, default 0);
+        accessor!(shape: ForwardsUOffset<Shape<'a>> = 1);
+    }
+
+    table!(Shape {
+        dim: ForwardsUOffset<Tables<'_, Dimension>> = 0,
+    });
+
+    impl<'a> Shape<'a> {
+        accessor!(dim: ForwardsUOffset<Tables<'a, Dimension<'a>>> = 0);
+    }
+
+    table!(Dimension {
+        value: ForwardsUOffset<DimensionValue> = 0,
+    });
+
+    impl<'a> Dimension<'a> {
+        accessor!(value: ForwardsUOffset<DimensionValue<'a>> = 0);
+    }
+
+    table!(DimensionValue {
+        dim_type: i8 = 0,
+        dim_value: i64 = 1,
+    });
+
+    impl<'a> DimensionValue<'a> {
+        accessor!(dim_type: i8 = 0, default 0);
+        accessor!(dim_value: i64 = 1, default 0);
+    }
+
+    table!(SequenceType {
+        elem_type: ForwardsUOffset<TypeInfo> = 0,
+    });
+
+    impl<'a> SequenceType<'a> {
+        accessor!(elem_type: ForwardsUOffset<TypeInfo<'a>> = 0);
+    }
+
+    table!(MapType {
+        key_type: i32 = 0,
+        value_type: ForwardsUOffset<TypeInfo> = 1,
+    });
+
+    impl<'a> MapType<'a> {
+        accessor!(key_type: i32 = 0, default 0);
+        accessor!(value_type: ForwardsUOffset<TypeInfo<'a>> = 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flatbuffers::{FlatBufferBuilder, WIPOffset};
+    use test_log::test;
+
+    /// Serialize a model with a `input: float[batch, 3]` input and an untyped `output`
+    fn ort_model() -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+
+        let batch = fbb.create_string("batch");
+        let start = fbb.start_table();
+        fbb.push_slot::<i8>(4, 2, 0);
+        fbb.push_slot_always(8, batch);
+        let batch = fbb.end_table(start);
+        let start = fbb.start_table();
+        fbb.push_slot::<i8>(4, 1, 0);
+        fbb.push_slot::<i64>(6, 3, 0);
+        let three = fbb.end_table(start);
+        let dims: Vec<_> = [batch, three]
+            .iter()
+            .map(|&value| {
+                let start = fbb.start_table();
+                fbb.push_slot_always(4, value);
+                fbb.end_table(start)
+            })
+            .collect();
+        let dims = fbb.create_vector(&dims);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, dims);
+        let shape = fbb.end_table(start);
+        let start = fbb.start_table();
+        fbb.push_slot::<i32>(4, 1, 0);
+        fbb.push_slot_always(6, shape);
+        let tensor = fbb.end_table(start);
+        let start = fbb.start_table();
+        fbb.push_slot::<u8>(6, fbs::TYPE_INFO_VALUE_TENSOR_TYPE, 0);
+        fbb.push_slot_always(8, tensor);
+        let input_type = fbb.end_table(start);
+
+        let input_name = fbb.create_string("input");
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, input_name);
+        fbb.push_slot_always(8, input_type);
+        let input = fbb.end_table(start);
+        let output_name = fbb.create_string("output");
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, output_name);
+        let output = fbb.end_table(start);
+        let node_args = fbb.create_vector(&[input, output]);
+        let inputs = [fbb.create_string("input")];
+        let inputs = fbb.create_vector(&inputs);
+        let outputs = [fbb.create_string("output")];
+        let outputs = fbb.create_vector(&outputs);
+        let start = fbb.start_table();
+        fbb.push_slot_always(6, node_args);
+        fbb.push_slot_always(14, inputs);
+        fbb.push_slot_always(16, outputs);
+        let graph = fbb.end_table(start);
+
+        let domain = fbb.create_string("");
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, domain);
+        fbb.push_slot::<i64>(6, 13, 0);
+        let opset: WIPOffset<_> = fbb.end_table(start);
+        let opsets = fbb.create_vector(&[opset]);
+        let producer = fbb.create_string("pytorch");
+        let start = fbb.start_table();
+        fbb.push_slot::<i64>(4, 8, 0);
+        fbb.push_slot_always(6, opsets);
+        fbb.push_slot_always(8, producer);
+        fbb.push_slot_always(18, graph);
+        let model = fbb.end_table(start);
+
+        let ort_version = fbb.create_string("1.15.1");
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, ort_version);
+        fbb.push_slot_always(6, model);
+        let session = fbb.end_table(start);
+        fbb.finish(session, Some(FILE_IDENTIFIER));
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    fn read_ort_format_model() {
+        let bytes = ort_model();
+        assert!(OrtFormatModel::is_ort_format(&bytes));
+
+        let model = OrtFormatModel::from_bytes(&bytes).unwrap();
+        assert_eq!(model.ort_version, "1.15.1");
+        assert_eq!(model.ir_version, 8);
+        assert_eq!(model.producer_name, "pytorch");
+        assert_eq!(
+            model.opsets,
+            vec![Opset {
+                domain: String::new(),
+                version: 13
+            }]
+        );
+        assert_eq!(
+            model.inputs,
+            vec![ValueInfo {
+                name: "input".to_owned(),
+                type_info: Some(TypeInfo::Tensor(TensorInfo {
+                    element_type: Some(TensorElementDataType::Float),
+                    dimensions: vec![None, Some(3)],
+                })),
+            }]
+        );
+        assert_eq!(
+            model.outputs,
+            vec![ValueInfo {
+                name: "output".to_owned(),
+                type_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn reject_other_formats() {
+        // Start of an ONNX protobuf: `ir_version` field, then `producer_name`
+        let onnx = b"\x08\x07\x12\x07pytorch";
+        assert!(matches!(
+            OrtFormatModel::from_bytes(onnx),
+            Err(OrtError::OrtFormat(OrtFormatError::NotOrtFormat))
+        ));
+
+        let bytes = ort_model();
+        assert!(matches!(
+            OrtFormatModel::from_bytes(&bytes[..bytes.len() / 2]),
+            Err(OrtError::OrtFormat(OrtFormatError::InvalidFlatbuffer(_)))
+        ));
+    }
+}
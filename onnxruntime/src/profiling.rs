@@ -0,0 +1,188 @@
+//! Module containing summaries of the runtime's profiling traces
+//!
+//! Profiling traces (see [`SessionBuilder::with_profiling()`](../session/struct.SessionBuilder.html#method.with_profiling))
+//! are JSON files in the Chrome tracing format, with one event per executed node. A
+//! [`ProfileSummary`](struct.ProfileSummary.html) aggregates them per operator type, which is
+//! usually what's needed to find where the time goes, and can emit the aggregates as `tracing`
+//! events so they reach the process' logs or metrics pipeline.
+//!
+//! **NOTE**: The runtime keeps profiling events in memory and only writes the trace when
+//! profiling ends; there is no in-progress trace to read. Summaries thus cover the window
+//! between the session's creation and the end of profiling, see
+//! [`SessionBuilder::with_profiling_summary()`](../session/struct.SessionBuilder.html#method.with_profiling_summary).
+
+use std::{collections::HashMap, path::Path, time::Duration};
+
+use serde_json::Value;
+use tracing::info;
+
+use crate::error::{ProfileError, Result};
+
+/// Time spent running the nodes of one operator type on one execution provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpTiming {
+    /// Operator type, for example `Conv`
+    pub op_type: String,
+    /// Execution provider the nodes ran on, for example `CPUExecutionProvider`
+    pub provider: String,
+    /// Number of node executions
+    pub calls: u64,
+    /// Total time spent in the nodes' kernels
+    pub total: Duration,
+}
+
+impl OpTiming {
+    /// Mean time of a node execution
+    pub fn mean(&self) -> Duration {
+        match self.calls {
+            0 => Duration::default(),
+            calls => Duration::from_nanos((self.total.as_nanos() / u128::from(calls)) as u64),
+        }
+    }
+}
+
+/// Per operator type aggregates of a profiling trace
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfileSummary {
+    /// Number of inference runs in the trace
+    pub runs: u64,
+    /// Total time of the inference runs
+    pub run_total: Duration,
+    /// Timings per operator type, the most expensive first
+    pub ops: Vec<OpTiming>,
+}
+
+impl ProfileSummary {
+    /// Summarize the trace written to `path`
+    pub fn from_file<P>(path: P) -> Result<ProfileSummary>
+    where
+        P: AsRef<Path>,
+    {
+        let trace = std::fs::read_to_string(path).map_err(ProfileError::IoError)?;
+        ProfileSummary::parse(&trace)
+    }
+
+    /// Summarize a trace
+    pub fn parse(trace: &str) -> Result<ProfileSummary> {
+        let events = match serde_json::from_str(trace).map_err(ProfileError::InvalidJson)? {
+            Value::Array(events) => events,
+            _ => return Err(ProfileError::UnexpectedFormat("trace is not an array").into()),
+        };
+
+        let mut summary = ProfileSummary::default();
+        let mut ops: HashMap<(String, String), OpTiming> = HashMap::new();
+        for event in &events {
+            let duration =
+                Duration::from_micros(event.get("dur").and_then(Value::as_u64).unwrap_or(0));
+            let name = event.get("name").and_then(Value::as_str).unwrap_or("");
+            match event.get("cat").and_then(Value::as_str) {
+                Some("Session") if name == "model_run" => {
+                    summary.runs += 1;
+                    summary.run_total += duration;
+                }
+                // Nodes have `_fence_before`, `_kernel_time` and `_fence_after` events
+                Some("Node") if name.ends_with("_kernel_time") => {
+                    let args = event.get("args");
+                    let field = |key| {
+                        args.and_then(|args| args.get(key))
+                            .and_then(Value::as_str)
+                            .unwrap_or("unknown")
+                            .to_owned()
+                    };
+                    let (op_type, provider) = (field("op_name"), field("provider"));
+                    let timing = ops
+                        .entry((op_type.clone(), provider.clone()))
+                        .or_insert_with(|| OpTiming {
+                            op_type,
+                            provider,
+                            calls: 0,
+                            total: Duration::default(),
+                        });
+                    timing.calls += 1;
+                    timing.total += duration;
+                }
+                _ => {}
+            }
+        }
+
+        summary.ops = ops.into_values().collect();
+        summary.ops.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then_with(|| a.op_type.cmp(&b.op_type))
+        });
+        Ok(summary)
+    }
+
+    /// Emit one `INFO` event per operator type, and one for the runs, on the `onnxruntime::profiling` target
+    pub fn emit(&self) {
+        info!(
+            target: "onnxruntime::profiling",
+            runs = self.runs,
+            total_us = self.run_total.as_micros() as u64,
+            "Inference runs"
+        );
+        for op in &self.ops {
+            info!(
+                target: "onnxruntime::profiling",
+                op_type = op.op_type.as_str(),
+                provider = op.provider.as_str(),
+                calls = op.calls,
+                total_us = op.total.as_micros() as u64,
+                mean_us = op.mean().as_micros() as u64,
+                "Operator timings"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    const TRACE: &str = r#"[
+{"cat" : "Session","pid" :1,"tid" :1,"dur" :120,"ts" :1,"ph" : "X","name" :"model_loading_uri","args" : {}},
+{"cat" : "Node","pid" :1,"tid" :1,"dur" :3,"ts" :10,"ph" : "X","name" :"conv1_fence_before","args" : {"op_name" : "Conv"}},
+{"cat" : "Node","pid" :1,"tid" :1,"dur" :40,"ts" :11,"ph" : "X","name" :"conv1_kernel_time","args" : {"op_name" : "Conv","provider" : "CPUExecutionProvider","output_type_shape" : [{"float":[1,8,28,28]}]}},
+{"cat" : "Node","pid" :1,"tid" :1,"dur" :25,"ts" :60,"ph" : "X","name" :"conv2_kernel_time","args" : {"op_name" : "Conv","provider" : "CPUExecutionProvider"}},
+{"cat" : "Node","pid" :1,"tid" :1,"dur" :5,"ts" :90,"ph" : "X","name" :"relu_kernel_time","args" : {"op_name" : "Relu","provider" : "CPUExecutionProvider"}},
+{"cat" : "Session","pid" :1,"tid" :1,"dur" :80,"ts" :9,"ph" : "X","name" :"model_run","args" : {}}
+]
+"#;
+
+    #[test]
+    fn profile_summary_aggregates_per_op_type() {
+        let summary = ProfileSummary::parse(TRACE).unwrap();
+        assert_eq!(summary.runs, 1);
+        assert_eq!(summary.run_total, Duration::from_micros(80));
+        assert_eq!(
+            summary.ops,
+            vec![
+                OpTiming {
+                    op_type: "Conv".to_owned(),
+                    provider: "CPUExecutionProvider".to_owned(),
+                    calls: 2,
+                    total: Duration::from_micros(65),
+                },
+                OpTiming {
+                    op_type: "Relu".to_owned(),
+                    provider: "CPUExecutionProvider".to_owned(),
+                    calls: 1,
+                    total: Duration::from_micros(5),
+                },
+            ]
+        );
+        assert_eq!(summary.ops[0].mean(), Duration::from_nanos(32_500));
+    }
+
+    #[test]
+    fn profile_summary_invalid_json() {
+        assert!(ProfileSummary::parse("[{\"cat\": }]").is_err());
+        assert!(ProfileSummary::parse("{}").is_err());
+        assert_eq!(
+            ProfileSummary::parse("[]").unwrap(),
+            ProfileSummary::default()
+        );
+    }
+}
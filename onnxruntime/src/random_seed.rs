@@ -0,0 +1,264 @@
+//! Module seeding the random operators of a model
+//!
+//! ONNX Runtime has no global random seed in its C API: operators drawing random values
+//! (`RandomNormal`, `RandomUniform`, `Multinomial`, `Bernoulli`, `Dropout` in training mode...)
+//! seed their generator from their `seed` attribute, or from the clock when the model leaves it
+//! out. To make them reproducible, a `seed` attribute is added to the nodes without one, copying
+//! the rest of the protobuf as is, like the relocation of external data.
+
+use crate::{
+    error::Result,
+    external_data::{
+        invalid, write_length_delimited, write_varint, Reader, WIRE_FIXED32, WIRE_FIXED64,
+        WIRE_LENGTH_DELIMITED, WIRE_VARINT,
+    },
+};
+
+/// Operators of the default domain with a `seed` attribute, and whether the attribute is an
+/// integer rather than a float
+const SEEDED_OPERATORS: &[(&str, bool)] = &[
+    ("Bernoulli", false),
+    ("Dropout", true),
+    ("Multinomial", false),
+    ("RandomNormal", false),
+    ("RandomNormalLike", false),
+    ("RandomUniform", false),
+    ("RandomUniformLike", false),
+];
+
+/// `AttributeProto.AttributeType` values
+const ATTRIBUTE_FLOAT: u64 = 1;
+const ATTRIBUTE_INT: u64 = 2;
+
+/// Messages leading to the nodes, including the ones of subgraphs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Model,
+    Graph,
+    Node,
+    Attribute,
+}
+
+impl Message {
+    /// Type of the message in field `field`, if it can hold nodes
+    fn child(self, field: u64) -> Option<Message> {
+        use Message::*;
+        match (self, field) {
+            // ModelProto.graph
+            (Model, 7) => Some(Graph),
+            // GraphProto.node
+            (Graph, 1) => Some(Node),
+            // NodeProto.attribute
+            (Node, 5) => Some(Attribute),
+            // AttributeProto.g, graphs
+            (Attribute, 6) | (Attribute, 11) => Some(Graph),
+            _ => None,
+        }
+    }
+}
+
+/// Serialized model, with the random operators lacking a `seed` attribute seeded from `seed`, or
+/// `None` if there are no such operators
+///
+/// The n-th operator seeded (in the order of the protobuf, from 0) is given `seed + n`, so that
+/// operators of the same shape do not draw the same values.
+pub(crate) fn seed_random_operators(model: &[u8], seed: u32) -> Result<Option<Vec<u8>>> {
+    let mut seeder = Seeder { seed, seeded: 0 };
+    let mut seeded = Vec::with_capacity(model.len());
+    seeder.message(model, Message::Model, &mut seeded)?;
+    if seeder.seeded > 0 {
+        Ok(Some(seeded))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Whether `model` has random operators lacking a `seed` attribute
+pub(crate) fn has_unseeded_random_operators(model: &[u8]) -> Result<bool> {
+    Ok(seed_random_operators(model, 0)?.is_some())
+}
+
+struct Seeder {
+    seed: u32,
+    /// Number of operators seeded so far
+    seeded: u32,
+}
+
+impl Seeder {
+    fn message(&mut self, bytes: &[u8], message: Message, out: &mut Vec<u8>) -> Result<()> {
+        let mut op_type: &[u8] = b"";
+        let mut domain: &[u8] = b"";
+        let mut has_seed = false;
+
+        let mut reader = Reader::new(bytes);
+        while !reader.is_empty() {
+            let start = reader.position;
+            let (field, wire_type) = reader.tag()?;
+            match wire_type {
+                WIRE_VARINT => {
+                    reader.varint()?;
+                }
+                WIRE_FIXED64 => {
+                    reader.take(8)?;
+                }
+                WIRE_FIXED32 => {
+                    reader.take(4)?;
+                }
+                WIRE_LENGTH_DELIMITED => {
+                    let payload = reader.length_delimited()?;
+                    match (message, field) {
+                        // NodeProto.op_type, domain
+                        (Message::Node, 4) => op_type = payload,
+                        (Message::Node, 7) => domain = payload,
+                        (Message::Node, 5) => has_seed |= attribute_name(payload)? == b"seed",
+                        _ => {}
+                    }
+                    if let Some(child) = message.child(field) {
+                        let mut seeded = Vec::with_capacity(payload.len());
+                        self.message(payload, child, &mut seeded)?;
+                        write_length_delimited(out, field, &seeded);
+                        continue;
+                    }
+                }
+                _ => {
+                    return Err(invalid(format!(
+                        "unsupported wire type {} at byte {}",
+                        wire_type, start
+                    )))
+                }
+            }
+            out.extend_from_slice(&bytes[start..reader.position]);
+        }
+
+        if message == Message::Node && !has_seed && (domain == b"" || domain == b"ai.onnx") {
+            let operator = SEEDED_OPERATORS
+                .iter()
+                .find(|(name, _)| name.as_bytes() == op_type);
+            if let Some((_, integer)) = operator {
+                let seed = self.seed.wrapping_add(self.seeded);
+                self.seeded += 1;
+                write_length_delimited(out, 5, &seed_attribute(seed, *integer));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `AttributeProto.name` of a serialized attribute
+fn attribute_name(attribute: &[u8]) -> Result<&[u8]> {
+    let mut reader = Reader::new(attribute);
+    while !reader.is_empty() {
+        let (field, wire_type) = reader.tag()?;
+        match wire_type {
+            WIRE_LENGTH_DELIMITED => {
+                let payload = reader.length_delimited()?;
+                if field == 1 {
+                    return Ok(payload);
+                }
+            }
+            WIRE_VARINT => {
+                reader.varint()?;
+            }
+            WIRE_FIXED64 => {
+                reader.take(8)?;
+            }
+            WIRE_FIXED32 => {
+                reader.take(4)?;
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "unsupported wire type {} at byte {}",
+                    wire_type, reader.position
+                )))
+            }
+        }
+    }
+    Ok(b"")
+}
+
+/// Serialized `seed` attribute, an `int` or a `float`
+fn seed_attribute(seed: u32, integer: bool) -> Vec<u8> {
+    let mut attribute = Vec::new();
+    write_length_delimited(&mut attribute, 1, b"seed");
+    if integer {
+        // AttributeProto.i
+        write_varint(&mut attribute, 3 << 3 | WIRE_VARINT);
+        write_varint(&mut attribute, u64::from(seed));
+        write_varint(&mut attribute, 20 << 3 | WIRE_VARINT);
+        write_varint(&mut attribute, ATTRIBUTE_INT);
+    } else {
+        // AttributeProto.f
+        write_varint(&mut attribute, 2 << 3 | WIRE_FIXED32);
+        attribute.extend_from_slice(&(seed as f32).to_le_bytes());
+        write_varint(&mut attribute, 20 << 3 | WIRE_VARINT);
+        write_varint(&mut attribute, ATTRIBUTE_FLOAT);
+    }
+    attribute
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn message(fields: &[(u64, Vec<u8>)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for (field, payload) in fields {
+            write_length_delimited(&mut out, *field, payload);
+        }
+        out
+    }
+
+    fn node(op_type: &str, attributes: Vec<Vec<u8>>) -> Vec<u8> {
+        let mut fields = vec![(4, op_type.as_bytes().to_vec())];
+        fields.extend(attributes.into_iter().map(|attribute| (5, attribute)));
+        message(&fields)
+    }
+
+    #[test]
+    fn random_operators_are_seeded() {
+        let seeded_node = node("RandomUniform", vec![seed_attribute(7, false)]);
+        let branch = message(&[(1, node("Dropout", vec![]))]);
+        let graph = message(&[
+            (1, node("RandomNormal", vec![])),
+            (1, node("Relu", vec![])),
+            (1, seeded_node.clone()),
+            (
+                1,
+                node(
+                    "If",
+                    vec![message(&[(1, b"then_branch".to_vec()), (6, branch)])],
+                ),
+            ),
+        ]);
+        let mut model = vec![0x08, 0x08]; // ir_version: 8
+        model.extend(message(&[(7, graph)]));
+
+        let seeded = seed_random_operators(&model, 42).unwrap().unwrap();
+
+        let expected_branch = message(&[(1, node("Dropout", vec![seed_attribute(43, true)]))]);
+        let expected_graph = message(&[
+            (1, node("RandomNormal", vec![seed_attribute(42, false)])),
+            (1, node("Relu", vec![])),
+            (1, seeded_node),
+            (
+                1,
+                node(
+                    "If",
+                    vec![message(&[
+                        (1, b"then_branch".to_vec()),
+                        (6, expected_branch),
+                    ])],
+                ),
+            ),
+        ]);
+        let mut expected = vec![0x08, 0x08];
+        expected.extend(message(&[(7, expected_graph)]));
+        assert_eq!(seeded, expected);
+
+        assert!(has_unseeded_random_operators(&model).unwrap());
+        // Nothing left to seed
+        assert!(!has_unseeded_random_operators(&seeded).unwrap());
+        assert_eq!(seed_random_operators(&seeded, 42).unwrap(), None);
+    }
+}
@@ -0,0 +1,183 @@
+//! Module containing the post-processing of semantic segmentation models
+//!
+//! Segmentation models output the logits (or probabilities) of each class for each pixel. The
+//! helpers here reduce them to a mask of labels, the best scoring class of each pixel, and color
+//! the mask for display, for example as an overlay blended over the input image.
+//!
+//! ```no_run
+//! # use std::error::Error;
+//! # use onnxruntime::{
+//! #     environment::Environment,
+//! #     segmentation::{argmax_labels, ColorMap},
+//! #     tensor::{layout::Layout, OrtOwnedTensor},
+//! # };
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # let environment = Environment::builder().build()?;
+//! # let session = environment.new_session_builder()?.with_model_from_file("deeplabv3.onnx")?;
+//! # let image = ndarray::Array::<f32, _>::zeros((1, 3, 520, 520));
+//! let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run(vec![image])?;
+//! let labels = argmax_labels(outputs[0].view(), Layout::Nchw)?;
+//! let overlay = ColorMap::pascal_voc().with_alpha(128).colorize(&labels);
+//! assert_eq!(overlay.shape(), &[520, 520, 4]);
+//! # Ok(())
+//! # }
+//! ```
+
+use ndarray::{Array2, Array3, ArrayViewD, Axis, Ix4};
+
+use crate::{
+    error::{OrtError, Result},
+    tensor::layout::Layout,
+};
+
+/// Label of the best scoring class of each pixel of the first image of `logits`
+///
+/// `logits` holds the score of each class for each pixel of a batch of images, in `layout`
+/// (`[batch, classes, height, width]` for [`Layout::Nchw`](../tensor/layout/enum.Layout.html)).
+/// The returned mask is of shape `[height, width]`.
+pub fn argmax_labels(logits: ArrayViewD<'_, f32>, layout: Layout) -> Result<Array2<u32>> {
+    let shape = logits.shape().to_vec();
+    let logits = match logits.into_dimensionality::<Ix4>() {
+        Ok(logits) if logits.len_of(Axis(0)) > 0 => logits.index_axis_move(Axis(0), 0),
+        _ => {
+            return Err(OrtError::Postprocessing(
+                format!(
+                    "Segmentation output has shape {:?}, expected 4 dimensions",
+                    shape
+                )
+                .into(),
+            ))
+        }
+    };
+    // Classes last, so that the scores of a pixel are next to each other
+    let logits = match layout {
+        Layout::Nchw => logits.permuted_axes([1, 2, 0]),
+        Layout::Nhwc => logits,
+    };
+
+    let (height, width, _) = logits.dim();
+    let mut labels = Array2::zeros((height, width));
+    for ((row, column), label) in labels.indexed_iter_mut() {
+        let scores = logits.slice(ndarray::s![row, column, ..]);
+        *label = scores
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map_or(0, |(class, _)| class as u32);
+    }
+    Ok(labels)
+}
+
+/// Colors of the labels of a mask, as RGBA
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorMap {
+    colors: Vec<[u8; 4]>,
+}
+
+impl ColorMap {
+    /// Color map giving label `i` the color `colors[i]`, and labels past the end transparent black
+    pub fn new(colors: Vec<[u8; 4]>) -> ColorMap {
+        ColorMap { colors }
+    }
+
+    /// Color map of the PASCAL VOC dataset, telling apart 256 labels, label 0 (background) being
+    /// black
+    pub fn pascal_voc() -> ColorMap {
+        let colors = (0..256_u32)
+            .map(|label| {
+                let mut color = [0, 0, 0, 255];
+                let mut label = label;
+                for bit in (0..8).rev() {
+                    for (channel, value) in color.iter_mut().take(3).enumerate() {
+                        *value |= (((label >> channel) & 1) as u8) << bit;
+                    }
+                    label >>= 3;
+                }
+                color
+            })
+            .collect();
+        ColorMap { colors }
+    }
+
+    /// Set the opacity of all colors, from 0 (transparent) to 255 (opaque)
+    pub fn with_alpha(mut self, alpha: u8) -> ColorMap {
+        self.colors.iter_mut().for_each(|color| color[3] = alpha);
+        self
+    }
+
+    /// Color of `label`
+    pub fn color(&self, label: u32) -> [u8; 4] {
+        self.colors
+            .get(label as usize)
+            .copied()
+            .unwrap_or([0, 0, 0, 0])
+    }
+
+    /// Color each label of `labels`, returning an RGBA image of shape `[height, width, 4]`
+    pub fn colorize(&self, labels: &Array2<u32>) -> Array3<u8> {
+        let (height, width) = labels.dim();
+        let mut pixels = Vec::with_capacity(height * width * 4);
+        for &label in labels.iter() {
+            pixels.extend_from_slice(&self.color(label));
+        }
+        Array3::from_shape_vec((height, width, 4), pixels).expect("One color per label")
+    }
+
+    /// Color each label of `labels` into an image of the `image` crate (requires the `image`
+    /// feature)
+    ///
+    /// Blend it over the input image with `image::imageops::overlay()`.
+    #[cfg(feature = "image")]
+    pub fn to_image(&self, labels: &Array2<u32>) -> image::RgbaImage {
+        let (height, width) = labels.dim();
+        let pixels = self.colorize(labels).into_raw_vec();
+        image::RgbaImage::from_raw(width as u32, height as u32, pixels)
+            .expect("One color per label")
+    }
+}
+
+impl Default for ColorMap {
+    fn default() -> Self {
+        ColorMap::pascal_voc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn labels_are_best_scoring_classes() {
+        // Two classes, one row of three pixels
+        let nchw = ndarray::arr3(&[[[0.1_f32, 0.9, 0.4]], [[0.8, 0.2, 0.6]]])
+            .insert_axis(Axis(0))
+            .into_dyn();
+        let labels = argmax_labels(nchw.view(), Layout::Nchw).unwrap();
+        assert_eq!(labels, ndarray::arr2(&[[1, 0, 1]]));
+
+        let nhwc = nchw.view().permuted_axes(vec![0, 2, 3, 1]);
+        assert_eq!(argmax_labels(nhwc, Layout::Nhwc).unwrap(), labels);
+
+        let flat = ndarray::arr1(&[1.0_f32]).into_dyn();
+        assert!(matches!(
+            argmax_labels(flat.view(), Layout::Nchw),
+            Err(OrtError::Postprocessing(_))
+        ));
+    }
+
+    #[test]
+    fn labels_are_colored() {
+        let color_map = ColorMap::pascal_voc();
+        assert_eq!(color_map.color(0), [0, 0, 0, 255]);
+        assert_eq!(color_map.color(1), [128, 0, 0, 255]);
+        assert_eq!(color_map.color(15), [192, 128, 128, 255]);
+        assert_eq!(color_map.color(300), [0, 0, 0, 0]);
+
+        let overlay = color_map
+            .with_alpha(100)
+            .colorize(&ndarray::arr2(&[[0, 1]]));
+        assert_eq!(overlay.shape(), &[1, 2, 4]);
+        assert_eq!(overlay.into_raw_vec(), vec![0, 0, 0, 100, 128, 0, 0, 100]);
+    }
+}
@@ -0,0 +1,544 @@
+//! Module containing building blocks of inference servers (requires the `serving` feature)
+//!
+//! An [`InferenceServer`](struct.InferenceServer.html) serves sessions over the HTTP routes of
+//! the [KServe v2 inference protocol](https://kserve.github.io/website/latest/modelserving/data_plane/v2_protocol/)
+//! (also implemented by Triton and other servers), with requests and responses encoded by the
+//! [`kserve`](../kserve/index.html) module. It does not depend on any HTTP library: forward each
+//! request's method, path, `Inference-Header-Content-Length` header and body to
+//! [`InferenceServer::handle()`](struct.InferenceServer.html#method.handle), and reply with the
+//! returned [`HttpResponse`](struct.HttpResponse.html). For example with `axum`:
+//!
+//! ```ignore
+//! let server = Arc::new(
+//!     InferenceServer::new("my-server")
+//!         .with_service(InferenceService::new("resnet", session)?),
+//! );
+//! let app = axum::Router::new().fallback(
+//!     move |method: Method, uri: Uri, headers: HeaderMap, body: Bytes| {
+//!         let server = Arc::clone(&server);
+//!         let header_length = headers
+//!             .get(kserve::HEADER_CONTENT_LENGTH)
+//!             .and_then(|value| value.to_str().ok()?.parse().ok());
+//!         async move {
+//!             let response = tokio::task::spawn_blocking(move || {
+//!                 server.handle(method.as_str(), uri.path(), header_length, &body)
+//!             })
+//!             .await
+//!             .unwrap();
+//!             let mut headers = HeaderMap::new();
+//!             headers.insert(CONTENT_TYPE, HeaderValue::from_static(response.content_type));
+//!             if let Some(length) = response.header_length {
+//!                 headers.insert(kserve::HEADER_CONTENT_LENGTH, length.into());
+//!             }
+//!             (StatusCode::from_u16(response.status).unwrap(), headers, response.body)
+//!         }
+//!     },
+//! );
+//! ```
+//!
+//! Models are supported if their inputs all have the same numeric element type, and their
+//! outputs too.
+
+use ndarray::ArrayD;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{KServeError, OrtError, Result, ServingError},
+    kserve::{datatype, InferRequest, InferResponse, InferTensor, TensorData},
+    session::Session,
+    TensorElementDataType,
+};
+
+/// Name, element type and shape of a model's input or output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TensorMetadata {
+    /// Name of the input or output
+    pub name: String,
+    /// Element type
+    pub datatype: String,
+    /// Shape, with `-1` for dynamic dimensions
+    pub shape: Vec<i64>,
+}
+
+/// Body of a model metadata response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelMetadata {
+    /// Name of the model
+    pub name: String,
+    /// Versions of the model available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<String>,
+    /// Framework of the model
+    pub platform: String,
+    /// Inputs of the model
+    pub inputs: Vec<TensorMetadata>,
+    /// Outputs of the model
+    pub outputs: Vec<TensorMetadata>,
+}
+
+/// Body of a server metadata response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerMetadata {
+    /// Name of the server
+    pub name: String,
+    /// Version of the server
+    pub version: String,
+    /// Protocol extensions supported by the server
+    pub extensions: Vec<String>,
+}
+
+/// Body of error responses
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    /// Description of the error
+    pub error: String,
+}
+
+macro_rules! run_dispatch {
+    ($($variant:ident($type_:ty)),* $(,)?) => {
+        /// Run `session` on `inputs`, all of the same element type
+        fn run(
+            session: &Session,
+            inputs: Vec<TensorData>,
+            output_type: TensorElementDataType,
+        ) -> Result<Vec<TensorData>> {
+            let input_type = match inputs.first() {
+                Some(input) => input.element_type(),
+                None => return run_typed(session, Vec::<ArrayD<f32>>::new(), output_type),
+            };
+            match input_type {
+                $(
+                    TensorElementDataType::$variant => {
+                        let arrays = inputs
+                            .into_iter()
+                            .map(|input| match input {
+                                TensorData::$variant(array) => Ok(array),
+                                other => Err(invalid(format!(
+                                    "inputs have different datatypes {} and {}",
+                                    datatype(input_type),
+                                    datatype(other.element_type())
+                                ))),
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        run_typed(session, arrays, output_type)
+                    }
+                )*
+                _ => unreachable!("Tensor data only holds supported element types"),
+            }
+        }
+
+        fn run_typed<TIn>(
+            session: &Session,
+            inputs: Vec<ArrayD<TIn>>,
+            output_type: TensorElementDataType,
+        ) -> Result<Vec<TensorData>>
+        where
+            TIn: crate::TypeToTensorElementDataType + std::fmt::Debug + Clone,
+        {
+            match output_type {
+                $(
+                    TensorElementDataType::$variant => Ok(session
+                        .run::<_, $type_>(inputs)?
+                        .iter()
+                        .map(|output| TensorData::$variant(output.view().to_owned()))
+                        .collect()),
+                )*
+                _ => Err(KServeError::UnsupportedDatatype(datatype(output_type).to_owned()).into()),
+            }
+        }
+
+        fn is_supported(element_type: TensorElementDataType) -> bool {
+            matches!(element_type, $(TensorElementDataType::$variant)|*)
+        }
+    };
+}
+
+run_dispatch! {
+    Float(f32),
+    Double(f64),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Uint8(u8),
+    Uint16(u16),
+    Uint32(u32),
+    Uint64(u64),
+}
+
+fn invalid(message: String) -> OrtError {
+    ServingError::InvalidRequest(message).into()
+}
+
+/// Session served under a model name (and optionally a version)
+#[derive(Debug)]
+pub struct InferenceService<'a> {
+    session: Session<'a>,
+    metadata: ModelMetadata,
+    version: Option<String>,
+    output_type: TensorElementDataType,
+}
+
+impl<'a> InferenceService<'a> {
+    /// Serve `session` as the model `name`
+    ///
+    /// Fails with [`ServingError::UnsupportedModel`](../error/enum.ServingError.html#variant.UnsupportedModel)
+    /// if the model's inputs (or its outputs) have different element types, or types other than
+    /// numbers of 8 to 64 bits.
+    pub fn new<S>(name: S, session: Session<'a>) -> Result<InferenceService<'a>>
+    where
+        S: Into<String>,
+    {
+        let single_type = |types: Vec<TensorElementDataType>, kind: &str| {
+            let first = types
+                .first()
+                .copied()
+                .unwrap_or(TensorElementDataType::Float);
+            if types.iter().any(|element_type| *element_type != first) {
+                return Err(ServingError::UnsupportedModel(format!(
+                    "{}s have different element types {:?}",
+                    kind, types
+                )));
+            }
+            if !is_supported(first) {
+                return Err(ServingError::UnsupportedModel(format!(
+                    "{}s have unsupported element type {}",
+                    kind, first
+                )));
+            }
+            Ok(first)
+        };
+        single_type(
+            session
+                .inputs
+                .iter()
+                .map(|input| input.input_type)
+                .collect(),
+            "input",
+        )?;
+        let output_type = single_type(
+            session
+                .outputs
+                .iter()
+                .map(|output| output.output_type)
+                .collect(),
+            "output",
+        )?;
+
+        let shape = |dimensions: &[Option<u32>]| {
+            dimensions
+                .iter()
+                .map(|dimension| dimension.map_or(-1, i64::from))
+                .collect()
+        };
+        let metadata = ModelMetadata {
+            name: name.into(),
+            versions: Vec::new(),
+            platform: "onnxruntime_onnx".to_owned(),
+            inputs: session
+                .inputs
+                .iter()
+                .map(|input| TensorMetadata {
+                    name: input.name.clone(),
+                    datatype: datatype(input.input_type).to_owned(),
+                    shape: shape(&input.dimensions),
+                })
+                .collect(),
+            outputs: session
+                .outputs
+                .iter()
+                .map(|output| TensorMetadata {
+                    name: output.name.clone(),
+                    datatype: datatype(output.output_type).to_owned(),
+                    shape: shape(&output.dimensions),
+                })
+                .collect(),
+        };
+
+        Ok(InferenceService {
+            session,
+            metadata,
+            version: None,
+            output_type,
+        })
+    }
+
+    /// Serve the model as version `version`
+    pub fn with_version<S>(mut self, version: S) -> InferenceService<'a>
+    where
+        S: Into<String>,
+    {
+        let version = version.into();
+        self.metadata.versions = vec![version.clone()];
+        self.version = Some(version);
+        self
+    }
+
+    /// Name of the model
+    pub fn name(&self) -> &str {
+        &self.metadata.name
+    }
+
+    /// Version of the model, if any
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Metadata of the model
+    pub fn metadata(&self) -> &ModelMetadata {
+        &self.metadata
+    }
+
+    /// Run an inference request
+    ///
+    /// The request's inputs are matched to the model's inputs by name. Requests from
+    /// several threads run concurrently on the same session.
+    pub fn infer(&self, request: InferRequest) -> Result<InferResponse> {
+        let mut inputs = Vec::with_capacity(self.metadata.inputs.len());
+        for model_input in &self.metadata.inputs {
+            let input = request
+                .inputs
+                .iter()
+                .find(|input| input.name == model_input.name)
+                .ok_or_else(|| invalid(format!("missing input {:?}", model_input.name)))?;
+            if input.datatype != model_input.datatype {
+                return Err(invalid(format!(
+                    "input {:?} is {}, not {}",
+                    input.name, input.datatype, model_input.datatype
+                )));
+            }
+            inputs.push(input.tensor()?);
+        }
+        if let Some(unknown) = request.inputs.iter().find(|input| {
+            !self
+                .metadata
+                .inputs
+                .iter()
+                .any(|model_input| model_input.name == input.name)
+        }) {
+            return Err(invalid(format!("unknown input {:?}", unknown.name)));
+        }
+
+        if let Some(unknown) = request.outputs.iter().flatten().find(|requested| {
+            !self
+                .metadata
+                .outputs
+                .iter()
+                .any(|output| output.name == requested.name)
+        }) {
+            return Err(invalid(format!("unknown output {:?}", unknown.name)));
+        }
+
+        let outputs = run(&self.session, inputs, self.output_type)?;
+
+        let outputs = self
+            .metadata
+            .outputs
+            .iter()
+            .zip(outputs)
+            .filter_map(|(metadata, output)| match &request.outputs {
+                Some(requested) => requested
+                    .iter()
+                    .find(|requested| requested.name == metadata.name)
+                    .map(|requested| match requested.is_binary() {
+                        true => InferTensor::binary(metadata.name.as_str(), &output),
+                        false => InferTensor::new(metadata.name.as_str(), &output),
+                    }),
+                None => Some(InferTensor::new(metadata.name.as_str(), &output)),
+            })
+            .collect();
+
+        Ok(InferResponse {
+            model_name: self.metadata.name.clone(),
+            model_version: self.version.clone(),
+            id: request.id,
+            parameters: None,
+            outputs,
+        })
+    }
+}
+
+/// Response of [`InferenceServer::handle()`](struct.InferenceServer.html#method.handle)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Value of the `Content-Type` header
+    pub content_type: &'static str,
+    /// Value of the `Inference-Header-Content-Length` header, to set if not `None`
+    pub header_length: Option<usize>,
+    /// Body of the response
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    fn json<T>(status: u16, body: &T) -> HttpResponse
+    where
+        T: Serialize,
+    {
+        HttpResponse {
+            status,
+            content_type: "application/json",
+            header_length: None,
+            body: serde_json::to_vec(body).expect("Protocol types serialize to JSON"),
+        }
+    }
+
+    fn infer(response: &InferResponse) -> Result<HttpResponse> {
+        let (body, header_length) = response.to_bytes()?;
+        Ok(HttpResponse {
+            status: 200,
+            content_type: match header_length {
+                Some(_) => "application/octet-stream",
+                None => "application/json",
+            },
+            header_length,
+            body,
+        })
+    }
+
+    fn empty(status: u16) -> HttpResponse {
+        HttpResponse {
+            status,
+            content_type: "text/plain",
+            header_length: None,
+            body: Vec::new(),
+        }
+    }
+
+    fn error(err: &OrtError) -> HttpResponse {
+        let status = match err {
+            OrtError::Serving(ServingError::UnknownModel { .. }) => 404,
+            OrtError::Serving(_) | OrtError::KServe(_) => 400,
+            _ => 500,
+        };
+        HttpResponse::json(
+            status,
+            &ErrorResponse {
+                error: err.to_string(),
+            },
+        )
+    }
+}
+
+/// Models served over the HTTP routes of the KServe v2 protocol
+#[derive(Debug)]
+pub struct InferenceServer<'a> {
+    metadata: ServerMetadata,
+    services: Vec<InferenceService<'a>>,
+}
+
+impl<'a> InferenceServer<'a> {
+    /// Server named `name`, serving no models yet
+    pub fn new<S>(name: S) -> InferenceServer<'a>
+    where
+        S: Into<String>,
+    {
+        InferenceServer {
+            metadata: ServerMetadata {
+                name: name.into(),
+                version: env!("CARGO_PKG_VERSION").to_owned(),
+                extensions: vec!["binary_tensor_data".to_owned()],
+            },
+            services: Vec::new(),
+        }
+    }
+
+    /// Serve a model
+    pub fn with_service(mut self, service: InferenceService<'a>) -> InferenceServer<'a> {
+        self.services.push(service);
+        self
+    }
+
+    /// Service of model `name`, with version `version` or of any version
+    pub fn service(&self, name: &str, version: Option<&str>) -> Result<&InferenceService<'a>> {
+        self.services
+            .iter()
+            .find(|service| {
+                service.name() == name && (version.is_none() || service.version() == version)
+            })
+            .ok_or_else(|| {
+                ServingError::UnknownModel {
+                    name: name.to_owned(),
+                    version: version.map(str::to_owned),
+                }
+                .into()
+            })
+    }
+
+    /// Handle an HTTP request of the protocol
+    ///
+    /// Serves the health (`/v2/health/live`, `/v2/health/ready`), metadata (`/v2`,
+    /// `/v2/models/{name}[/versions/{version}]`), readiness
+    /// (`/v2/models/{name}[/versions/{version}]/ready`) and inference
+    /// (`POST /v2/models/{name}[/versions/{version}]/infer`) routes. Inference runs on the
+    /// calling thread: call this from a blocking task in asynchronous servers.
+    ///
+    /// `header_length` is the value of the request's `Inference-Header-Content-Length` header, if
+    /// any, when its body holds [binary tensor data](../kserve/index.html).
+    pub fn handle(
+        &self,
+        method: &str,
+        path: &str,
+        header_length: Option<usize>,
+        body: &[u8],
+    ) -> HttpResponse {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let (model, rest) = match (method, segments.as_slice()) {
+            ("GET", ["v2"]) => return HttpResponse::json(200, &self.metadata),
+            ("GET", ["v2", "health", "live"]) | ("GET", ["v2", "health", "ready"]) => {
+                return HttpResponse::empty(200)
+            }
+            (_, ["v2", "models", name, "versions", version, rest @ ..]) => {
+                ((*name, Some(*version)), rest)
+            }
+            (_, ["v2", "models", name, rest @ ..]) => ((*name, None), rest),
+            _ => return HttpResponse::empty(404),
+        };
+
+        let service = match self.service(model.0, model.1) {
+            Ok(service) => service,
+            Err(err) => return HttpResponse::error(&err),
+        };
+        match (method, rest) {
+            ("GET", []) => HttpResponse::json(200, service.metadata()),
+            ("GET", ["ready"]) => HttpResponse::empty(200),
+            ("POST", ["infer"]) => {
+                let response = InferRequest::from_bytes(body, header_length)
+                    .and_then(|request| service.infer(request))
+                    .and_then(|response| HttpResponse::infer(&response));
+                match response {
+                    Ok(response) => response,
+                    Err(err) => HttpResponse::error(&err),
+                }
+            }
+            _ => HttpResponse::empty(404),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn server_routes() {
+        let server = InferenceServer::new("test");
+        let response = server.handle("GET", "/v2", None, &[]);
+        assert_eq!(response.status, 200);
+        let metadata: ServerMetadata = serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(metadata.name, "test");
+        assert_eq!(
+            server.handle("GET", "/v2/health/ready", None, &[]).status,
+            200
+        );
+        assert_eq!(
+            server
+                .handle("POST", "/v2/models/missing/infer", None, b"{}")
+                .status,
+            404
+        );
+        assert_eq!(server.handle("GET", "/metrics", None, &[]).status, 404);
+    }
+}
@@ -1,39 +1,147 @@
 //! Module containing session types
 
-use std::{ffi::CString, fmt::Debug, marker::PhantomData, path::Path};
+use std::{
+    ffi::CString,
+    fmt::{self, Debug},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 
 #[cfg(not(target_family = "windows"))]
 use std::os::unix::ffi::OsStrExt;
 #[cfg(target_family = "windows")]
 use std::os::windows::ffi::OsStrExt;
 
-#[cfg(feature = "model-fetching")]
-use std::env;
-
-use ndarray::Array;
-use tracing::{debug, error};
+#[cfg(feature = "ndarray")]
+use ndarray::ArrayD;
+#[cfg(feature = "ndarray")]
+use smallvec::SmallVec;
+use tracing::{debug, error, info, warn};
 
 use onnxruntime_sys as sys;
 
 use crate::{
     char_p_to_string,
+    download::source::{FetchedModel, ModelSource},
     environment::Environment,
     error::{
-        assert_not_null_pointer, assert_null_pointer, status_to_result, NonMatchingDimensionsError,
-        OrtApiError, OrtError, Result,
+        assert_not_null_pointer, assert_null_pointer, session_creation_error, status_to_result,
+        NonMatchingDimensionsError, OrtApiError, OrtError, Result,
+    },
+    execution_providers::{
+        private::SessionOptions, ExecutionProvider, ExecutionProviderChain, HealthCheck,
     },
-    g_ort,
+    external_data, fork_guard,
+    log_capture::LogCapture,
     memory::MemoryInfo,
+    memory_report::MemoryReport,
+    model_hash::ModelHash,
+    random_seed,
     tensor::{
-        ort_owned_tensor::{OrtOwnedTensor, OrtOwnedTensorExtractor},
-        OrtTensor,
+        ort_tensor::OrtTensorRef,
+        raw::{self, RawTensor},
     },
-    AllocatorType, GraphOptimizationLevel, MemType, TensorElementDataType,
+    type_info::{TensorInfo, TypeInfo},
+    verification::{ModelVerifier, Verifier},
+    AllocatorType, ExecutionMode, GraphOptimizationLevel, MemType, TensorElementDataType,
     TypeToTensorElementDataType,
 };
 
+#[cfg(any(feature = "gzip", feature = "zstd"))]
+use crate::compression::Compression;
+#[cfg(feature = "mmap")]
+use crate::tensor::MmapTensor;
+#[cfg(feature = "ndarray")]
+use crate::tensor::{
+    ort_owned_tensor::{OrtOwnedTensor, OrtOwnedTensorExtractor},
+    DynOrtTensor, DynOrtValue, InputArray, InputValue, IntoInput, OrtTensor,
+};
+
+#[cfg(feature = "ndarray")]
+mod batched;
+#[cfg(feature = "tokio")]
+mod blocking;
+#[cfg(feature = "ndarray")]
+mod coercion;
+mod concurrency;
+mod config;
+#[cfg(feature = "ndarray")]
+mod deadline;
+mod debug;
+#[cfg(feature = "ndarray")]
+mod fixed;
+#[cfg(feature = "ndarray")]
+mod guard;
+#[cfg(feature = "ndarray")]
+mod io_binding;
+#[cfg(feature = "ndarray")]
+mod layer;
+mod memory_budget;
+#[cfg(feature = "ndarray")]
+mod mock;
+#[cfg(feature = "ndarray")]
+mod named;
+mod non_finite;
+#[cfg(feature = "ndarray")]
+mod outputs;
+#[cfg(feature = "ndarray")]
+mod pinned;
+#[cfg(feature = "ndarray")]
+mod result_cache;
+mod retry;
+#[cfg(feature = "ndarray")]
+mod router;
+mod run_options;
+mod shared_initializers;
+#[cfg(feature = "ndarray")]
+mod streaming;
+mod swappable;
+mod timings;
+mod trace_tags;
+
 #[cfg(feature = "model-fetching")]
-use crate::{download::AvailableOnnxModel, error::OrtDownloadError};
+use crate::download::AvailableOnnxModel;
+pub use crate::log_capture::{LogMessage, Warning};
+#[cfg(feature = "tokio")]
+pub use blocking::{run_async, run_blocking_on, spawn_run};
+#[cfg(feature = "ndarray")]
+pub use coercion::InputCoercion;
+#[cfg(feature = "ndarray")]
+use coercion::{AsOrtValue, InputTensor};
+pub use concurrency::{ConcurrencyLimit, ConcurrencyMetrics};
+pub use config::{ProviderConfig, SessionConfig};
+pub use debug::DebugOptions;
+#[cfg(feature = "ndarray")]
+pub use fixed::FixedShapeSession;
+#[cfg(feature = "ndarray")]
+pub use guard::InputGuard;
+#[cfg(feature = "ndarray")]
+pub use io_binding::IoBinding;
+#[cfg(feature = "ndarray")]
+pub use layer::{Layered, Next, RunStats, SessionLayer};
+use memory_budget::GpuMemoryBudget;
+#[cfg(feature = "ndarray")]
+pub use mock::{MockInput, MockOutput, MockSession};
+pub use non_finite::{NonFiniteCheck, NonFiniteOutput};
+#[cfg(feature = "ndarray")]
+pub use outputs::Outputs;
+#[cfg(feature = "ndarray")]
+use pinned::PinnedStaging;
+#[cfg(feature = "ndarray")]
+pub use result_cache::ResultCache;
+pub use retry::{is_transient, RetryAttempt, RetryPolicy};
+#[cfg(feature = "ndarray")]
+pub use router::{AbRouter, NumericElement, OutputSummary, Variant, VariantStats};
+pub use run_options::RunOptions;
+pub use shared_initializers::SharedInitializers;
+use shared_initializers::SharedValue;
+#[cfg(feature = "ndarray")]
+pub use streaming::{StreamState, StreamingSession};
+pub use swappable::SwappableSession;
+pub use timings::RunTimings;
+use timings::{Phase, RunTimer};
+pub use trace_tags::TraceTags;
 
 /// Type used to create a session using the _builder pattern_
 ///
@@ -46,6 +154,10 @@ use crate::{download::AvailableOnnxModel, error::OrtDownloadError};
 /// Once configured, use the [`SessionBuilder::with_model_from_file()`](../session/struct.SessionBuilder.html#method.with_model_from_file)
 /// method to "commit" the builder configuration into a [`Session`](../session/struct.Session.html).
 ///
+/// Committing blocks until the runtime has loaded the model and cannot be cancelled: the
+/// runtime's load cancellation flag (`SetLoadCancellationFlag()`) is more recent than version
+/// 1.15 of its API, which these bindings target.
+///
 /// # Example
 ///
 /// ```no_run
@@ -71,6 +183,42 @@ pub struct SessionBuilder<'a> {
 
     allocator: AllocatorType,
     memory_type: MemType,
+    upcast_half_outputs: bool,
+    #[cfg(feature = "ndarray")]
+    input_coercions: Vec<(String, InputCoercion)>,
+    #[cfg(feature = "ndarray")]
+    input_guards: Vec<(String, InputGuard)>,
+    log_capture: Option<LogCapture>,
+    concurrency_limit: Option<ConcurrencyLimit>,
+    max_batch_size: Option<usize>,
+    gpu_memory_budget: Option<usize>,
+    activation_bytes_per_item: Option<usize>,
+    model_size: usize,
+    retry_policy: Option<RetryPolicy>,
+    non_finite_check: Option<NonFiniteCheck>,
+    summarize_inputs_on_error: bool,
+    run_timings: bool,
+    trace_tags: Option<TraceTags>,
+    output_numa_node: Option<u32>,
+    #[cfg(feature = "ndarray")]
+    pinned_staging_min_bytes: Option<usize>,
+    shared_initializers: Option<SharedInitializers>,
+    shared_initializer_values: Vec<Arc<SharedValue>>,
+    #[cfg(feature = "profiling")]
+    profiling_summary: bool,
+    execution_providers: Vec<&'static str>,
+    provider_configs: Vec<ProviderConfig>,
+    execution_provider_chain: Option<ExecutionProviderChain>,
+    compute_model_hash: bool,
+    model_hash: Option<ModelHash>,
+    verifier: Option<ModelVerifier>,
+    external_data_dir: Option<PathBuf>,
+    disabled_optimizers: Vec<String>,
+    number_threads: Option<i16>,
+    memory_pattern: bool,
+    execution_mode: ExecutionMode,
+    optimization_level: GraphOptimizationLevel,
+    config_entries: Vec<(String, String)>,
 }
 
 impl<'a> Drop for SessionBuilder<'a> {
@@ -80,41 +228,124 @@ impl<'a> Drop for SessionBuilder<'a> {
             error!("Session options pointer is null, not dropping");
         } else {
             debug!("Dropping the session options.");
-            unsafe { g_ort().ReleaseSessionOptions.unwrap()(self.session_options_ptr) };
+            if let Ok(release) = ort_api!(ReleaseSessionOptions) {
+                unsafe { release(self.session_options_ptr) };
+            }
         }
     }
 }
 
 impl<'a> SessionBuilder<'a> {
     pub(crate) fn new(env: &'a Environment) -> Result<SessionBuilder<'a>> {
+        fork_guard::check()?;
+
         let mut session_options_ptr: *mut sys::OrtSessionOptions = std::ptr::null_mut();
-        let status = unsafe { g_ort().CreateSessionOptions.unwrap()(&mut session_options_ptr) };
+        let status = unsafe { ort_api!(CreateSessionOptions)?(&mut session_options_ptr) };
 
         status_to_result(status).map_err(OrtError::SessionOptions)?;
         assert_null_pointer(status, "SessionStatus")?;
         assert_not_null_pointer(session_options_ptr, "SessionOptions")?;
 
-        Ok(SessionBuilder {
+        let builder = SessionBuilder {
             env,
             session_options_ptr,
             allocator: AllocatorType::Arena,
             memory_type: MemType::Default,
-        })
+            upcast_half_outputs: false,
+            #[cfg(feature = "ndarray")]
+            input_coercions: Vec::new(),
+            #[cfg(feature = "ndarray")]
+            input_guards: Vec::new(),
+            log_capture: Some(LogCapture::new()),
+            concurrency_limit: None,
+            max_batch_size: None,
+            gpu_memory_budget: None,
+            activation_bytes_per_item: None,
+            model_size: 0,
+            retry_policy: None,
+            non_finite_check: None,
+            summarize_inputs_on_error: false,
+            run_timings: false,
+            trace_tags: None,
+            output_numa_node: None,
+            #[cfg(feature = "ndarray")]
+            pinned_staging_min_bytes: None,
+            shared_initializers: None,
+            shared_initializer_values: Vec::new(),
+            #[cfg(feature = "profiling")]
+            profiling_summary: false,
+            execution_providers: Vec::new(),
+            provider_configs: Vec::new(),
+            execution_provider_chain: None,
+            compute_model_hash: false,
+            model_hash: None,
+            verifier: None,
+            external_data_dir: None,
+            disabled_optimizers: Vec::new(),
+            number_threads: None,
+            memory_pattern: true,
+            execution_mode: ExecutionMode::Sequential,
+            optimization_level: GraphOptimizationLevel::All,
+            config_entries: Vec::new(),
+        };
+
+        // Each session logs under its own id, so that its messages can be told apart
+        let logid = CString::new(builder.log_capture.as_ref().unwrap().logid())?;
+        let status = unsafe { ort_api!(SetSessionLogId)?(session_options_ptr, logid.as_ptr()) };
+        status_to_result(status).map_err(OrtError::SessionOptions)?;
+
+        if env.has_global_thread_pools() {
+            let status = unsafe { ort_api!(DisablePerSessionThreads)?(session_options_ptr) };
+            status_to_result(status).map_err(OrtError::SessionOptions)?;
+        }
+
+        Ok(builder)
     }
 
     /// Configure the session to use a number of threads
-    pub fn with_number_threads(self, num_threads: i16) -> Result<SessionBuilder<'a>> {
+    pub fn with_number_threads(mut self, num_threads: i16) -> Result<SessionBuilder<'a>> {
         // FIXME: Pre-built binaries use OpenMP, set env variable instead
 
+        self.number_threads = Some(num_threads);
         // We use a u16 in the builder to cover the 16-bits positive values of a i32.
         let num_threads = num_threads as i32;
         let status =
-            unsafe { g_ort().SetIntraOpNumThreads.unwrap()(self.session_options_ptr, num_threads) };
+            unsafe { ort_api!(SetIntraOpNumThreads)?(self.session_options_ptr, num_threads) };
         status_to_result(status).map_err(OrtError::SessionOptions)?;
         assert_null_pointer(status, "SessionStatus")?;
         Ok(self)
     }
 
+    /// Enable or disable memory pattern optimization
+    ///
+    /// The runtime records the allocations of the first run of each input shape, and allocates
+    /// a single block for later runs with the same shapes. Not supported by the DirectML
+    /// provider, which requires disabling it.
+    ///
+    /// Defaults to `true`.
+    pub fn with_memory_pattern(mut self, enabled: bool) -> Result<SessionBuilder<'a>> {
+        let status = unsafe {
+            match enabled {
+                true => ort_api!(EnableMemPattern)?(self.session_options_ptr),
+                false => ort_api!(DisableMemPattern)?(self.session_options_ptr),
+            }
+        };
+        status_to_result(status).map_err(OrtError::SessionOptions)?;
+        self.memory_pattern = enabled;
+        Ok(self)
+    }
+
+    /// Set whether the graph's nodes run one after the other or in parallel
+    ///
+    /// Defaults to [`ExecutionMode::Sequential`](../enum.ExecutionMode.html#variant.Sequential).
+    pub fn with_execution_mode(mut self, mode: ExecutionMode) -> Result<SessionBuilder<'a>> {
+        let status =
+            unsafe { ort_api!(SetSessionExecutionMode)?(self.session_options_ptr, mode.into()) };
+        status_to_result(status).map_err(OrtError::SessionOptions)?;
+        self.execution_mode = mode;
+        Ok(self)
+    }
+
     /// Call an EP loading function of the form `Fn(*mut OrtSessionOptions) -> OrtStatusPtr`
     ///
     /// This function may do anything with the provided `OrtSessionOptions` points, but the
@@ -131,18 +362,139 @@ impl<'a> SessionBuilder<'a> {
         Ok(self)
     }
 
+    /// Append an execution provider, from the [`execution_providers`](../execution_providers/index.html) module
+    ///
+    /// Providers are tried in the order they were appended, nodes not supported by any of them
+    /// falling back to the CPU.
+    pub fn with_execution_provider<E>(mut self, provider: E) -> Result<SessionBuilder<'a>>
+    where
+        E: ExecutionProvider,
+    {
+        let budgeted = self
+            .gpu_memory_budget
+            .and_then(|budget| provider.with_memory_budget(budget));
+        let provider: &dyn ExecutionProvider = budgeted.as_deref().unwrap_or(&provider);
+        provider.append_to(SessionOptions(self.session_options_ptr))?;
+        self.execution_providers.push(provider.name());
+        self.provider_configs.push(ProviderConfig::of(provider));
+        Ok(self)
+    }
+
+    /// Try several execution provider configurations in turn when committing the session
+    ///
+    /// See [`ExecutionProviderChain`](../execution_providers/struct.ExecutionProviderChain.html).
+    /// Its providers come after the ones appended with
+    /// [`with_execution_provider()`](#method.with_execution_provider).
+    pub fn with_execution_provider_chain(
+        mut self,
+        chain: ExecutionProviderChain,
+    ) -> Result<SessionBuilder<'a>> {
+        self.execution_provider_chain = Some(chain);
+        Ok(self)
+    }
+
     /// Set the session's optimization level
     pub fn with_optimization_level(
-        self,
+        mut self,
         opt_level: GraphOptimizationLevel,
     ) -> Result<SessionBuilder<'a>> {
         // Sets graph optimization level
         unsafe {
-            g_ort().SetSessionGraphOptimizationLevel.unwrap()(
-                self.session_options_ptr,
-                opt_level.into(),
-            )
+            ort_api!(SetSessionGraphOptimizationLevel)?(self.session_options_ptr, opt_level.into())
         };
+        self.optimization_level = opt_level;
+        Ok(self)
+    }
+
+    /// Enable or disable the NCHWc layout transformer of the CPU provider
+    ///
+    /// With [`GraphOptimizationLevel::All`](../enum.GraphOptimizationLevel.html#variant.All),
+    /// convolutions and the operators around them are converted to the blocked NCHWc layout of
+    /// the CPU kernels. This is faster, but accumulates in a different order than the reference
+    /// kernels, so outputs differ in their last bits; disabling it trades speed for outputs
+    /// matching the unoptimized graph.
+    ///
+    /// Defaults to `true`.
+    pub fn with_nchwc_transformer(self, enabled: bool) -> Result<SessionBuilder<'a>> {
+        match enabled {
+            true => Ok(self),
+            false => self.with_disabled_optimizers(["NchwcTransformer"]),
+        }
+    }
+
+    /// Disable graph optimizers by name (for example `ConstantFolding` or `GeluFusion`)
+    ///
+    /// The names are the ones of the runtime's graph transformers and rewrite rules, as listed in
+    /// its verbose logs. Unknown names are ignored by the runtime.
+    pub fn with_disabled_optimizers<I, S>(mut self, optimizers: I) -> Result<SessionBuilder<'a>>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for optimizer in optimizers {
+            let optimizer = optimizer.as_ref();
+            if !self
+                .disabled_optimizers
+                .iter()
+                .any(|name| name == optimizer)
+            {
+                self.disabled_optimizers.push(optimizer.to_owned());
+            }
+        }
+        Ok(self)
+    }
+
+    /// Flush denormal floating point values to zero on the session's threads
+    ///
+    /// Computations on denormals are much slower on most CPUs; flushing them trades the
+    /// precision of values close to zero for speed. Only the session's own thread pools are
+    /// affected: with an environment's global thread pools, this has no effect.
+    ///
+    /// Defaults to `false`.
+    pub fn with_denormals_as_zero(mut self, enabled: bool) -> Result<SessionBuilder<'a>> {
+        self.add_config_entry("session.set_denormal_as_zero", config_flag(enabled))?;
+        Ok(self)
+    }
+
+    /// Enable or disable fusing `QuantizeLinear`/`DequantizeLinear` pairs into integer kernels
+    ///
+    /// In quantized models, the operators between such pairs are replaced by integer kernels,
+    /// which are faster but round intermediate values. Disabling the fusion runs the operators
+    /// in floating point, as the model describes them.
+    ///
+    /// Defaults to `true`.
+    pub fn with_qdq_fusion(mut self, enabled: bool) -> Result<SessionBuilder<'a>> {
+        self.add_config_entry("session.disable_quant_qdq", config_flag(!enabled))?;
+        Ok(self)
+    }
+
+    /// Pass a parameter to a custom operator, as the `custom_op.<op_name>.<key>` config entry
+    ///
+    /// Custom operators read such entries when their kernel is created, so that they can be
+    /// tuned without being recompiled. `value` is formatted with `Display`: booleans are set as
+    /// `true` or `false`, numbers in their usual decimal form.
+    ///
+    /// Fails with [`OrtError::InvalidConfiguration`](../error/enum.OrtError.html#variant.InvalidConfiguration)
+    /// if `op_name` or `key` is empty, or if `op_name` contains a `.`.
+    pub fn with_custom_op_config<V>(
+        mut self,
+        op_name: &str,
+        key: &str,
+        value: V,
+    ) -> Result<SessionBuilder<'a>>
+    where
+        V: fmt::Display,
+    {
+        if op_name.is_empty() || op_name.contains('.') || key.is_empty() {
+            return Err(OrtError::InvalidConfiguration(format!(
+                "invalid custom op config key `custom_op.{}.{}`",
+                op_name, key
+            )));
+        }
+        self.add_config_entry(
+            &format!("custom_op.{}.{}", op_name, key),
+            &value.to_string(),
+        )?;
         Ok(self)
     }
 
@@ -162,6 +514,361 @@ impl<'a> SessionBuilder<'a> {
         Ok(self)
     }
 
+    /// Allow extracting `float16` and `bfloat16` outputs as `f32`
+    ///
+    /// When enabled, requesting `f32` outputs from a model producing half precision data
+    /// converts the values while copying them out of the runtime, so callers don't need
+    /// a half precision type. Without it, such a request fails with
+    /// [`OrtError::NonMatchingTensorElementType`](../error/enum.OrtError.html#variant.NonMatchingTensorElementType).
+    ///
+    /// Defaults to `false`.
+    pub fn with_half_precision_upcast(mut self, upcast: bool) -> Result<SessionBuilder<'a>> {
+        self.upcast_half_outputs = upcast;
+        Ok(self)
+    }
+
+    /// Convert the input named `input_name` to the model's element type when they differ
+    ///
+    /// By default arrays are handed to the runtime as is, which rejects inputs of the wrong
+    /// element type. With a coercion configured, [`Session::run()`](struct.Session.html#method.run)
+    /// converts such an input first, failing with
+    /// [`OrtError::InputCoercion`](../error/enum.OrtError.html#variant.InputCoercion) if a
+    /// value cannot be represented exactly, unless [`InputCoercion::AllowLossy`](enum.InputCoercion.html#variant.AllowLossy)
+    /// is used.
+    ///
+    /// Committing the session fails if the model has no input with that name.
+    #[cfg(feature = "ndarray")]
+    pub fn with_input_coercion<S>(
+        mut self,
+        input_name: S,
+        coercion: InputCoercion,
+    ) -> Result<SessionBuilder<'a>>
+    where
+        S: Into<String>,
+    {
+        self.input_coercions.push((input_name.into(), coercion));
+        Ok(self)
+    }
+
+    /// Check the input named `input_name` against the rules of `guard` before each run
+    ///
+    /// See [`InputGuard`](struct.InputGuard.html). Inputs are checked as given, before any
+    /// [coercion](#method.with_input_coercion); runs on raw or memory-mapped data are not checked.
+    ///
+    /// Committing the session fails if the model has no input with that name.
+    #[cfg(feature = "ndarray")]
+    pub fn with_input_guard<S>(
+        mut self,
+        input_name: S,
+        guard: InputGuard,
+    ) -> Result<SessionBuilder<'a>>
+    where
+        S: Into<String>,
+    {
+        self.input_guards.push((input_name.into(), guard));
+        Ok(self)
+    }
+
+    /// Apply debugging oriented settings, see [`DebugOptions`](struct.DebugOptions.html)
+    pub fn with_debug_options(mut self, options: DebugOptions) -> Result<SessionBuilder<'a>> {
+        for (key, value) in options.config_entries() {
+            self.add_config_entry(key, value)?;
+        }
+        if let Some(path) = &options.optimized_model_path {
+            let path = path_to_ort_string(path);
+            let status = unsafe {
+                ort_api!(SetOptimizedModelFilePath)?(self.session_options_ptr, path.as_ptr())
+            };
+            status_to_result(status).map_err(OrtError::SessionOptions)?;
+        }
+        Ok(self)
+    }
+
+    /// Enable profiling, writing a trace to a file named after `profile_file_prefix`
+    ///
+    /// The runtime appends a timestamp and the `.json` extension to the prefix. The trace
+    /// is written when [`Session::end_profiling()`](struct.Session.html#method.end_profiling)
+    /// is called or the session is dropped, and can be opened in `chrome://tracing`.
+    pub fn with_profiling<P>(self, profile_file_prefix: P) -> Result<SessionBuilder<'a>>
+    where
+        P: AsRef<Path>,
+    {
+        let profile_file_prefix = path_to_ort_string(profile_file_prefix.as_ref());
+        let status = unsafe {
+            ort_api!(EnableProfiling)?(self.session_options_ptr, profile_file_prefix.as_ptr())
+        };
+        status_to_result(status).map_err(OrtError::SessionOptions)?;
+        assert_null_pointer(status, "SessionStatus")?;
+        Ok(self)
+    }
+
+    /// Enable profiling, emitting per operator type timings as `tracing` events when it ends
+    ///
+    /// Instead of leaving a trace file behind, the trace is summarized (see
+    /// [`ProfileSummary`](../profiling/struct.ProfileSummary.html)), emitted on the
+    /// `onnxruntime::profiling` target and deleted, either by
+    /// [`Session::end_profiling_summary()`](struct.Session.html#method.end_profiling_summary)
+    /// or when the session is dropped.
+    ///
+    /// The runtime only writes its trace when profiling ends, so timings cannot be emitted
+    /// periodically; for continuous visibility in a long-lived process, end profiling after a
+    /// representative number of runs.
+    #[cfg(feature = "profiling")]
+    pub fn with_profiling_summary<P>(mut self, profile_file_prefix: P) -> Result<SessionBuilder<'a>>
+    where
+        P: AsRef<Path>,
+    {
+        self = self.with_profiling(profile_file_prefix)?;
+        self.profiling_summary = true;
+        Ok(self)
+    }
+
+    /// Keep the runtime's memory related messages, to build a [`MemoryReport`](../memory_report/struct.MemoryReport.html)
+    ///
+    /// This sets the session's log severity to verbose: all of the session's messages are
+    /// forwarded to `tracing` (at the `TRACE` level), and the memory related ones are also kept
+    /// for [`Session::memory_report()`](struct.Session.html#method.memory_report).
+    ///
+    /// The arenas log through the environment's logger rather than the session's: the environment
+    /// must be built with [`LoggingLevel::Info`](../enum.LoggingLevel.html#variant.Info) or more
+    /// verbose for their allocations to be reported, and they are reported to every session with a
+    /// memory report. See the [`memory_report`](../memory_report/index.html) module.
+    ///
+    /// Meant for debugging, for example to pick a batch size fitting a GPU memory budget.
+    pub fn with_memory_report(self) -> Result<SessionBuilder<'a>> {
+        let status = unsafe { ort_api!(SetSessionLogSeverityLevel)?(self.session_options_ptr, 0) };
+        status_to_result(status).map_err(OrtError::SessionOptions)?;
+
+        if let Some(log_capture) = &self.log_capture {
+            log_capture.keep_memory_messages();
+        }
+        Ok(self)
+    }
+
+    /// Allow at most `max_concurrent_runs` inference runs of this session at the same time
+    ///
+    /// Runs over the limit wait for a slot. Wait times are available from
+    /// [`Session::concurrency_metrics()`](struct.Session.html#method.concurrency_metrics).
+    pub fn with_max_concurrent_runs(
+        self,
+        max_concurrent_runs: usize,
+    ) -> Result<SessionBuilder<'a>> {
+        self.with_concurrency_limit(ConcurrencyLimit::new(max_concurrent_runs))
+    }
+
+    /// Share a limit on concurrent inference runs with other sessions
+    ///
+    /// See [`ConcurrencyLimit`](struct.ConcurrencyLimit.html).
+    pub fn with_concurrency_limit(mut self, limit: ConcurrencyLimit) -> Result<SessionBuilder<'a>> {
+        self.concurrency_limit = Some(limit);
+        Ok(self)
+    }
+
+    /// Reject runs whose inputs have a batch (first) dimension larger than `max_batch_size`
+    ///
+    /// Such runs fail with [`NonMatchingDimensionsError::BatchTooLarge`](../error/enum.NonMatchingDimensionsError.html#variant.BatchTooLarge)
+    /// before reaching the runtime, so that a single oversized request cannot take all of a
+    /// device's memory.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Result<SessionBuilder<'a>> {
+        self.max_batch_size = Some(max_batch_size);
+        Ok(self)
+    }
+
+    /// Keep the session within `bytes` of GPU memory, failing runs estimated not to fit
+    ///
+    /// GPU execution providers appended afterwards (including those of an
+    /// [`ExecutionProviderChain`](../execution_providers/struct.ExecutionProviderChain.html)) get
+    /// `bytes` as their memory limit, unless they have one already, and an arena growing by
+    /// exactly the memory requested. Runs then fail with
+    /// [`OrtError::GpuMemoryBudgetExceeded`](../error/enum.OrtError.html#variant.GpuMemoryBudgetExceeded)
+    /// before reaching the runtime when their estimated memory exceeds the budget, instead of
+    /// running out of memory halfway through.
+    ///
+    /// The estimate is the size of the model (external data excluded), plus the inputs, plus the
+    /// activations set with
+    /// [`with_activation_memory_estimate()`](#method.with_activation_memory_estimate): without
+    /// it, only oversized inputs are caught before running.
+    ///
+    /// Fails with [`OrtError::InvalidConfiguration`](../error/enum.OrtError.html#variant.InvalidConfiguration)
+    /// if execution providers were already appended.
+    pub fn with_gpu_memory_budget(mut self, bytes: usize) -> Result<SessionBuilder<'a>> {
+        if !self.provider_configs.is_empty() {
+            return Err(OrtError::InvalidConfiguration(
+                "`with_gpu_memory_budget()` must be called before appending execution providers"
+                    .to_owned(),
+            ));
+        }
+        self.gpu_memory_budget = Some(bytes);
+        Ok(self)
+    }
+
+    /// Estimate the activations of a run at `bytes_per_item` bytes of GPU memory per item of the
+    /// batch (first dimension of the inputs)
+    ///
+    /// Used by [`with_gpu_memory_budget()`](#method.with_gpu_memory_budget). Measure it by
+    /// comparing the device memory used by runs of two batch sizes, for example with
+    /// [`memory_report()`](struct.Session.html#method.memory_report).
+    pub fn with_activation_memory_estimate(
+        mut self,
+        bytes_per_item: usize,
+    ) -> Result<SessionBuilder<'a>> {
+        self.activation_bytes_per_item = Some(bytes_per_item);
+        Ok(self)
+    }
+
+    /// Retry runs failing with transient errors, such as a GPU running out of memory
+    ///
+    /// See [`RetryPolicy`](struct.RetryPolicy.html). Retries happen within the call to
+    /// [`Session::run()`](struct.Session.html#method.run) (or its variants), which thus takes
+    /// longer to fail.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Result<SessionBuilder<'a>> {
+        self.retry_policy = Some(policy);
+        Ok(self)
+    }
+
+    /// Scan the outputs of runs for `NaN` and infinite values, failing the runs or reporting them
+    ///
+    /// See [`NonFiniteCheck`](struct.NonFiniteCheck.html). Outputs of runs with an
+    /// [`IoBinding`](struct.IoBinding.html) are not checked.
+    pub fn with_non_finite_check(mut self, check: NonFiniteCheck) -> Result<SessionBuilder<'a>> {
+        self.non_finite_check = Some(check);
+        Ok(self)
+    }
+
+    /// Attach the names, element types and shapes of the inputs to the errors of failed runs
+    ///
+    /// Failed runs then return an [`OrtError::RunWithInputs`](../error/enum.OrtError.html#variant.RunWithInputs)
+    /// wrapping the runtime's error. Disabled by default, since shapes can reveal the size of
+    /// the data being processed (for example the length of a user's text) once errors are logged.
+    pub fn with_input_summary_on_error(mut self, enabled: bool) -> Result<SessionBuilder<'a>> {
+        self.summarize_inputs_on_error = enabled;
+        Ok(self)
+    }
+
+    /// Time the phases of each run, to tell the time spent in the bindings from the time spent
+    /// executing the model
+    ///
+    /// The timings of the last run are returned by
+    /// [`Session::last_run_timings()`](struct.Session.html#method.last_run_timings). Disabled by
+    /// default; when enabled, each run reads the clock a few more times and takes a lock.
+    pub fn with_run_timings(mut self, enabled: bool) -> Result<SessionBuilder<'a>> {
+        self.run_timings = enabled;
+        Ok(self)
+    }
+
+    /// Tag runs with the trace context of their caller, such as the current `tracing` span
+    ///
+    /// See [`TraceTags`](struct.TraceTags.html): the runtime's messages about a run are then
+    /// logged with the tag as their log id. Runs with [`RunOptions`](struct.RunOptions.html) of
+    /// their own are left alone; tag them with
+    /// [`RunOptions::with_run_tag()`](struct.RunOptions.html#method.with_run_tag) instead.
+    pub fn with_trace_tags(mut self, trace_tags: TraceTags) -> Result<SessionBuilder<'a>> {
+        self.trace_tags = Some(trace_tags);
+        Ok(self)
+    }
+
+    /// Copy inputs of at least `min_input_bytes` to pinned (page-locked) host memory before the
+    /// runtime uploads them to the device
+    ///
+    /// Uploads from pinned memory are substantially faster than from pageable memory, which the
+    /// driver copies through a pinned buffer of its own: large inputs such as image batches
+    /// benefit the most. The pinned buffers are kept per input and reused by the next runs while
+    /// the input keeps its element type and shape. Applies to the inputs of
+    /// [`Session::run()`](struct.Session.html#method.run) and the methods built on it, except
+    /// inputs converted by an [`InputCoercion`](enum.InputCoercion.html).
+    ///
+    /// The session must run on the CUDA execution provider, which allocates the pinned memory:
+    /// committing fails with [`OrtError::Allocator`](../error/enum.OrtError.html#variant.Allocator)
+    /// otherwise.
+    #[cfg(feature = "ndarray")]
+    pub fn with_pinned_input_staging(
+        mut self,
+        min_input_bytes: usize,
+    ) -> Result<SessionBuilder<'a>> {
+        self.pinned_staging_min_bytes = Some(min_input_bytes);
+        Ok(self)
+    }
+
+    /// Share the initializers (weights) of the model with other sessions using the same cache
+    ///
+    /// See [`SharedInitializers`](struct.SharedInitializers.html): identical initializers of
+    /// sessions built with clones of `shared` are held in memory once. The model is read into
+    /// memory to look its initializers up; the external data files of a model loaded from a file
+    /// are still looked for in its directory, but initializers stored there are not shared.
+    pub fn with_shared_initializers(
+        mut self,
+        shared: SharedInitializers,
+    ) -> Result<SessionBuilder<'a>> {
+        self.shared_initializers = Some(shared);
+        Ok(self)
+    }
+
+    /// Place the copies of outputs returned by runs on the given NUMA node
+    ///
+    /// On multi-socket machines, pass the node of the thread consuming the outputs to avoid
+    /// cross-socket traffic. The copied arrays (of [`SwappableSession::run()`](struct.SwappableSession.html#method.run),
+    /// [`Session::run_raw()`](struct.Session.html#method.run_raw), ...) have their pages allocated
+    /// there and touched by the copy; the runtime's own output buffers, and the views of
+    /// [`Session::run()`](struct.Session.html#method.run), are not placed. Placement is a
+    /// preference: pages go to another node when this one is full. Only buffers spanning whole
+    /// pages are placed, and only on Linux; elsewhere this has no effect.
+    ///
+    /// Fails with [`OrtError::InvalidConfiguration`](../error/enum.OrtError.html#variant.InvalidConfiguration)
+    /// if the node is beyond the 1024 supported.
+    #[cfg(feature = "numa")]
+    pub fn with_output_numa_node(mut self, node: u32) -> Result<SessionBuilder<'a>> {
+        if node >= crate::numa::MAX_NUMA_NODES {
+            return Err(OrtError::InvalidConfiguration(format!(
+                "NUMA node {} is beyond the {} supported",
+                node,
+                crate::numa::MAX_NUMA_NODES
+            )));
+        }
+        self.output_numa_node = Some(node);
+        Ok(self)
+    }
+
+    /// Compute the [`ModelHash`](../model_hash/struct.ModelHash.html) of the model when committing the session
+    ///
+    /// The hash is then available from [`Session::model_hash()`](struct.Session.html#method.model_hash).
+    /// Hashing reads the whole model once more, which takes a noticeable time for large models;
+    /// it is thus disabled by default.
+    pub fn with_model_hash(mut self, compute_model_hash: bool) -> Result<SessionBuilder<'a>> {
+        self.compute_model_hash = compute_model_hash;
+        Ok(self)
+    }
+
+    /// Check the model with `verifier` before creating the session
+    ///
+    /// Loading fails with [`OrtError::ModelVerification`](../error/enum.OrtError.html#variant.ModelVerification)
+    /// if the verifier refuses the model. The verifier sees the bytes actually loaded: models from
+    /// files are read to memory first (see the [`verification`](../verification/index.html)
+    /// module), compressed models are verified once decompressed.
+    pub fn with_model_verification<V>(mut self, verifier: V) -> Result<SessionBuilder<'a>>
+    where
+        V: Verifier + 'static,
+    {
+        self.verifier = Some(ModelVerifier::new(verifier));
+        Ok(self)
+    }
+
+    /// Look for the files of initializers stored as external data in `dir`
+    ///
+    /// Such initializers reference their file with a path relative to the model's directory, or
+    /// to the current directory for models loaded from memory. With this option, the paths are
+    /// taken relative to `dir` instead, so that a model and its weights can be stored apart, or
+    /// relocated, without editing the model.
+    ///
+    /// The model is read to memory and its references rewritten before creating the session, as
+    /// with [`with_model_from_memory()`](#method.with_model_from_memory).
+    pub fn with_external_data_dir<P>(mut self, dir: P) -> Result<SessionBuilder<'a>>
+    where
+        P: Into<PathBuf>,
+    {
+        self.external_data_dir = Some(dir.into());
+        Ok(self)
+    }
+
     /// Download an ONNX pre-trained model from the [ONNX Model Zoo](https://github.com/onnx/models) and commit the session
     #[cfg(feature = "model-fetching")]
     pub fn with_model_downloaded<M>(self, model: M) -> Result<Session<'a>>
@@ -173,21 +880,101 @@ impl<'a> SessionBuilder<'a> {
 
     #[cfg(feature = "model-fetching")]
     fn with_model_downloaded_monomorphized(self, model: AvailableOnnxModel) -> Result<Session<'a>> {
-        let download_dir = env::current_dir().map_err(OrtDownloadError::IoError)?;
-        let downloaded_path = model.download_to(download_dir)?;
-        self.with_model_from_file(downloaded_path)
+        self.with_model_from_source(model)
+    }
+
+    /// Fetch an ONNX graph from a [`ModelSource`](../download/source/trait.ModelSource.html) and commit the session
+    ///
+    /// Models fetched to a file are loaded with [`with_model_from_file()`](#method.with_model_from_file),
+    /// others with [`with_model_from_memory()`](#method.with_model_from_memory).
+    pub fn with_model_from_source<S>(self, source: S) -> Result<Session<'a>>
+    where
+        S: ModelSource,
+    {
+        match source.fetch()? {
+            FetchedModel::File(model_filepath) => self.with_model_from_file(model_filepath),
+            FetchedModel::Bytes(model_bytes) => self.with_model_from_memory(model_bytes),
+        }
     }
 
     // TODO: Add all functions changing the options.
     //       See all OrtApi methods taking a `options: *mut OrtSessionOptions`.
 
+    /// Check settings the runtime would reject, or silently ignore, with a clearer error
+    fn validate_configuration(&self) -> Result<()> {
+        let mut providers = self.execution_providers.iter().copied().chain(
+            self.execution_provider_chain
+                .iter()
+                .flat_map(ExecutionProviderChain::provider_names),
+        );
+        if providers.any(|name| name == "DmlExecutionProvider") {
+            if self.memory_pattern {
+                return Err(OrtError::InvalidConfiguration(
+                    "the DirectML provider does not support memory patterns, \
+                     disable them with `with_memory_pattern(false)`"
+                        .to_owned(),
+                ));
+            }
+            if self.execution_mode == ExecutionMode::Parallel {
+                return Err(OrtError::InvalidConfiguration(
+                    "the DirectML provider does not support parallel execution, \
+                     use `ExecutionMode::Sequential`"
+                        .to_owned(),
+                ));
+            }
+        }
+        if let Some(threads) = self.number_threads {
+            if self.env.has_global_thread_pools() {
+                return Err(OrtError::InvalidConfiguration(format!(
+                    "`with_number_threads({})` has no effect: the environment has global thread \
+                     pools, configure them with `ThreadingOptions` instead",
+                    threads
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Set a session configuration entry, as listed in `onnxruntime_session_options_config_keys.h`
+    fn add_config_entry(&mut self, key: &str, value: &str) -> Result<()> {
+        let key_c = CString::new(key)?;
+        let value_c = CString::new(value)?;
+        let status = unsafe {
+            ort_api!(AddSessionConfigEntry)?(
+                self.session_options_ptr,
+                key_c.as_ptr(),
+                value_c.as_ptr(),
+            )
+        };
+        status_to_result(status).map_err(OrtError::SessionOptions)?;
+
+        // Setting an entry again replaces its value
+        self.config_entries.retain(|(existing, _)| existing != key);
+        self.config_entries.push((key.to_owned(), value.to_owned()));
+        Ok(())
+    }
+
+    /// Configuration of a session created with this builder's settings and the given providers
+    fn effective_config(&self) -> SessionConfig {
+        SessionConfig {
+            optimization_level: self.optimization_level,
+            number_threads: self.number_threads,
+            execution_mode: self.execution_mode,
+            memory_pattern: self.memory_pattern,
+            allocator: self.allocator,
+            memory_type: self.memory_type,
+            global_thread_pools: self.env.has_global_thread_pools(),
+            execution_providers: self.provider_configs.clone(),
+            config_entries: self.config_entries.clone(),
+        }
+    }
+
     /// Load an ONNX graph from a file and commit the session
-    pub fn with_model_from_file<P>(self, model_filepath_ref: P) -> Result<Session<'a>>
+    pub fn with_model_from_file<P>(mut self, model_filepath_ref: P) -> Result<Session<'a>>
     where
         P: AsRef<Path> + 'a,
     {
         let model_filepath = model_filepath_ref.as_ref();
-        let mut session_ptr: *mut sys::OrtSession = std::ptr::null_mut();
 
         if !model_filepath.exists() {
             return Err(OrtError::FileDoesNotExists {
@@ -195,60 +982,57 @@ impl<'a> SessionBuilder<'a> {
             });
         }
 
-        // Build an OsString than a vector of bytes to pass to C
-        let model_path = std::ffi::OsString::from(model_filepath);
-        #[cfg(target_family = "windows")]
-        let model_path: Vec<u16> = model_path
-            .encode_wide()
-            .chain(std::iter::once(0)) // Make sure we have a null terminated string
-            .collect();
-        #[cfg(not(target_family = "windows"))]
-        let model_path: Vec<std::os::raw::c_char> = model_path
-            .as_bytes()
-            .iter()
-            .chain(std::iter::once(&b'\0')) // Make sure we have a null terminated string
-            .map(|b| *b as std::os::raw::c_char)
-            .collect();
-
-        let env_ptr: *const sys::OrtEnv = self.env.env_ptr();
+        let read_to_memory = self.verifier.is_some()
+            || self.external_data_dir.is_some()
+            || self.shared_initializers.is_some();
+        if read_to_memory || self.env.random_seed().is_some() {
+            let model_bytes = std::fs::read(model_filepath).map_err(OrtError::ReadModel)?;
+            // Without random operators to seed, the model is loaded from its file as usual
+            if !read_to_memory && !random_seed::has_unseeded_random_operators(&model_bytes)? {
+                return self.with_model_from_path(model_filepath);
+            }
+            if let Some(verifier) = self.verifier.take() {
+                verifier.verify(&model_bytes, Some(model_filepath))?;
+            }
+            // A model loaded from memory has no directory of its own: keep resolving its external
+            // data against the file's, as when loading it from its path
+            if self.external_data_dir.is_none() {
+                self.external_data_dir = model_filepath
+                    .parent()
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .map(Path::to_path_buf);
+            }
+            return self.with_model_from_memory_monomorphized(&model_bytes);
+        }
 
-        let status = unsafe {
-            g_ort().CreateSession.unwrap()(
-                env_ptr,
-                model_path.as_ptr(),
-                self.session_options_ptr,
-                &mut session_ptr,
-            )
-        };
-        status_to_result(status).map_err(OrtError::Session)?;
-        assert_null_pointer(status, "SessionStatus")?;
-        assert_not_null_pointer(session_ptr, "Session")?;
+        self.with_model_from_path(model_filepath)
+    }
 
-        let mut allocator_ptr: *mut sys::OrtAllocator = std::ptr::null_mut();
-        let status = unsafe { g_ort().GetAllocatorWithDefaultOptions.unwrap()(&mut allocator_ptr) };
-        status_to_result(status).map_err(OrtError::Allocator)?;
-        assert_null_pointer(status, "SessionStatus")?;
-        assert_not_null_pointer(allocator_ptr, "Allocator")?;
+    /// Have the runtime load the model from its file
+    fn with_model_from_path(mut self, model_filepath: &Path) -> Result<Session<'a>> {
+        if self.compute_model_hash {
+            self.model_hash = Some(ModelHash::from_file(model_filepath)?);
+        }
+        self.model_size = std::fs::metadata(model_filepath).map_or(0, |m| m.len() as usize);
 
-        let memory_info = MemoryInfo::new(AllocatorType::Arena, MemType::Default)?;
+        let model_path = path_to_ort_string(model_filepath);
 
-        // Extract input and output properties
-        let num_input_nodes = dangerous::extract_inputs_count(session_ptr)?;
-        let num_output_nodes = dangerous::extract_outputs_count(session_ptr)?;
-        let inputs = (0..num_input_nodes)
-            .map(|i| dangerous::extract_input(session_ptr, allocator_ptr, i))
-            .collect::<Result<Vec<Input>>>()?;
-        let outputs = (0..num_output_nodes)
-            .map(|i| dangerous::extract_output(session_ptr, allocator_ptr, i))
-            .collect::<Result<Vec<Output>>>()?;
+        let env_ptr: *const sys::OrtEnv = self.env.env_ptr();
 
-        Ok(Session {
-            env: PhantomData,
-            session_ptr,
-            allocator_ptr,
-            memory_info,
-            inputs,
-            outputs,
+        self.commit(|session_options_ptr| {
+            let mut session_ptr: *mut sys::OrtSession = std::ptr::null_mut();
+            let status = unsafe {
+                ort_api!(CreateSession)?(
+                    env_ptr,
+                    model_path.as_ptr(),
+                    session_options_ptr,
+                    &mut session_ptr,
+                )
+            };
+            status_to_result(status).map_err(session_creation_error)?;
+            assert_null_pointer(status, "SessionStatus")?;
+            assert_not_null_pointer(session_ptr, "Session")?;
+            Ok(session_ptr)
         })
     }
 
@@ -260,28 +1044,518 @@ impl<'a> SessionBuilder<'a> {
         self.with_model_from_memory_monomorphized(model_bytes.as_ref())
     }
 
-    fn with_model_from_memory_monomorphized(self, model_bytes: &[u8]) -> Result<Session<'a>> {
-        let mut session_ptr: *mut sys::OrtSession = std::ptr::null_mut();
+    /// Decompress an ONNX graph from memory and commit the session
+    ///
+    /// The model is decompressed to memory, then loaded as with
+    /// [`with_model_from_memory()`](#method.with_model_from_memory).
+    #[cfg(any(feature = "gzip", feature = "zstd"))]
+    pub fn with_model_from_compressed<B>(
+        self,
+        compressed_model: B,
+        compression: Compression,
+    ) -> Result<Session<'a>>
+    where
+        B: AsRef<[u8]>,
+    {
+        let compressed_model = compressed_model.as_ref();
+        let model_bytes = compression.decompress(compressed_model, compressed_model.len())?;
+        self.with_model_from_memory(model_bytes)
+    }
+
+    /// Decompress an ONNX graph from a file and commit the session
+    ///
+    /// The file is decompressed to memory while being read, without any temporary file; use
+    /// [`Compression::from_path()`](../compression/enum.Compression.html#method.from_path) to pick
+    /// the compression from the file's extension.
+    #[cfg(any(feature = "gzip", feature = "zstd"))]
+    pub fn with_model_from_compressed_file<P>(
+        self,
+        model_filepath_ref: P,
+        compression: Compression,
+    ) -> Result<Session<'a>>
+    where
+        P: AsRef<Path>,
+    {
+        let model_filepath = model_filepath_ref.as_ref();
+        if !model_filepath.exists() {
+            return Err(OrtError::FileDoesNotExists {
+                filename: model_filepath.to_path_buf(),
+            });
+        }
+
+        let file = std::fs::File::open(model_filepath).map_err(OrtError::ReadModel)?;
+        let compressed_len = file
+            .metadata()
+            .map_or(0, |metadata| metadata.len() as usize);
+        let model_bytes = compression.decompress(std::io::BufReader::new(file), compressed_len)?;
+        self.with_model_from_memory(model_bytes)
+    }
 
+    fn with_model_from_memory_monomorphized(mut self, model_bytes: &[u8]) -> Result<Session<'a>> {
         let env_ptr: *const sys::OrtEnv = self.env.env_ptr();
 
-        let status = unsafe {
-            let model_data = model_bytes.as_ptr() as *const std::ffi::c_void;
-            let model_data_length = model_bytes.len();
-            g_ort().CreateSessionFromArray.unwrap()(
-                env_ptr,
-                model_data,
-                model_data_length,
-                self.session_options_ptr,
-                &mut session_ptr,
-            )
+        if let Some(verifier) = self.verifier.take() {
+            verifier.verify(model_bytes, None)?;
+        }
+
+        if self.compute_model_hash {
+            self.model_hash = Some(ModelHash::from_bytes(model_bytes));
+        }
+        self.model_size = model_bytes.len();
+
+        let relocated_model;
+        let model_bytes = match &self.external_data_dir {
+            Some(dir) => {
+                relocated_model = external_data::relocate(model_bytes, dir)?;
+                &relocated_model[..]
+            }
+            None => model_bytes,
         };
-        status_to_result(status).map_err(OrtError::Session)?;
-        assert_null_pointer(status, "SessionStatus")?;
-        assert_not_null_pointer(session_ptr, "Session")?;
 
+        let seeded_model = match self.env.random_seed() {
+            Some(seed) => random_seed::seed_random_operators(model_bytes, seed)?,
+            None => None,
+        };
+        let model_bytes = seeded_model.as_deref().unwrap_or(model_bytes);
+
+        if let Some(shared) = &self.shared_initializers {
+            self.shared_initializer_values =
+                shared.add_to(self.session_options_ptr, model_bytes)?;
+        }
+
+        self.commit(|session_options_ptr| {
+            let mut session_ptr: *mut sys::OrtSession = std::ptr::null_mut();
+            let status = unsafe {
+                let model_data = model_bytes.as_ptr() as *const std::ffi::c_void;
+                let model_data_length = model_bytes.len();
+                ort_api!(CreateSessionFromArray)?(
+                    env_ptr,
+                    model_data,
+                    model_data_length,
+                    session_options_ptr,
+                    &mut session_ptr,
+                )
+            };
+            status_to_result(status).map_err(session_creation_error)?;
+            assert_null_pointer(status, "SessionStatus")?;
+            assert_not_null_pointer(session_ptr, "Session")?;
+            Ok(session_ptr)
+        })
+    }
+
+    /// Create the session with `create_session`, trying the execution provider chain if any
+    fn commit<F>(mut self, create_session: F) -> Result<Session<'a>>
+    where
+        F: Fn(*mut sys::OrtSessionOptions) -> Result<*mut sys::OrtSession>,
+    {
+        fork_guard::check()?;
+        self.validate_configuration()?;
+
+        // The entry holds the whole list, so it is only set once all optimizers are known
+        if !self.disabled_optimizers.is_empty() {
+            let disabled_optimizers = self.disabled_optimizers.join(",");
+            self.add_config_entry(
+                "optimization.disable_specified_optimizers",
+                &disabled_optimizers,
+            )?;
+        }
+
+        let chain = match self.execution_provider_chain.take() {
+            Some(chain) => chain,
+            None => {
+                let session_ptr = create_session(self.session_options_ptr)?;
+                return Session::from_session_ptr(session_ptr, &mut self);
+            }
+        };
+
+        let attempts = chain.attempts();
+        let mut last_error = None;
+        for providers in attempts {
+            let names: Vec<&'static str> = providers.iter().map(|(_, p)| p.name()).collect();
+            match self.try_execution_providers(&create_session, &providers, chain.health_check()) {
+                Ok(session) => {
+                    info!(
+                        execution_providers = format!("{:?}", session.execution_providers).as_str(),
+                        "Session created."
+                    );
+                    return Ok(session);
+                }
+                Err(ProvidersAttemptError::Required(err)) => return Err(err),
+                Err(ProvidersAttemptError::Failed(err)) => {
+                    warn!(
+                        execution_providers = format!("{:?}", names).as_str(),
+                        "Failed to create session, trying the next execution providers: {}", err
+                    );
+                    last_error = Some(err);
+                }
+            }
+        }
+        Err(last_error.expect("Execution provider chains have at least one attempt"))
+    }
+
+    fn try_execution_providers<F>(
+        &mut self,
+        create_session: &F,
+        providers: &[(bool, &dyn ExecutionProvider)],
+        health_check: Option<&HealthCheck>,
+    ) -> std::result::Result<Session<'a>, ProvidersAttemptError>
+    where
+        F: Fn(*mut sys::OrtSessionOptions) -> Result<*mut sys::OrtSession>,
+    {
+        let budgeted: Vec<Option<Box<dyn ExecutionProvider>>> = providers
+            .iter()
+            .map(|(_, provider)| {
+                self.gpu_memory_budget
+                    .and_then(|budget| provider.with_memory_budget(budget))
+            })
+            .collect();
+        let providers: Vec<(bool, &dyn ExecutionProvider)> = providers
+            .iter()
+            .zip(&budgeted)
+            .map(|(&(required, provider), budgeted)| {
+                (required, budgeted.as_deref().unwrap_or(provider))
+            })
+            .collect();
+
+        let session_options = ClonedSessionOptions::new(self.session_options_ptr)
+            .map_err(ProvidersAttemptError::Required)?;
+        for (required, provider) in &providers {
+            provider
+                .append_to(SessionOptions(session_options.ptr))
+                .map_err(|err| match required {
+                    true => ProvidersAttemptError::Required(err),
+                    false => ProvidersAttemptError::Failed(err),
+                })?;
+        }
+
+        let session_ptr =
+            create_session(session_options.ptr).map_err(ProvidersAttemptError::Failed)?;
+        let builder_providers = self.execution_providers.len();
+        self.execution_providers
+            .extend(providers.iter().map(|(_, provider)| provider.name()));
+        self.provider_configs.extend(
+            providers
+                .iter()
+                .map(|(_, provider)| ProviderConfig::of(*provider)),
+        );
+        let session = Session::from_session_ptr(session_ptr, self);
+        self.execution_providers.truncate(builder_providers);
+        self.provider_configs.truncate(builder_providers);
+        let mut session = session.map_err(ProvidersAttemptError::Required)?;
+
+        match health_check.map_or(Ok(()), |health_check| health_check(&mut session)) {
+            Ok(()) => Ok(session),
+            Err(err) => {
+                // Hand what the session took from the builder back for the next attempt
+                self.log_capture = session.log_capture.take();
+                if let Some(log_capture) = &self.log_capture {
+                    log_capture.resume_warnings();
+                }
+                self.concurrency_limit = session.concurrency_limit.take();
+                Err(ProvidersAttemptError::Failed(err))
+            }
+        }
+    }
+}
+
+/// Value of a boolean session configuration entry
+fn config_flag(enabled: bool) -> &'static str {
+    match enabled {
+        true => "1",
+        false => "0",
+    }
+}
+
+/// Failure of an attempt of an execution provider chain
+enum ProvidersAttemptError {
+    /// Failure ending the chain: a required provider could not be used
+    Required(OrtError),
+    /// Failure moving on to the next attempt
+    Failed(OrtError),
+}
+
+/// Copy of a builder's session options, to append providers to
+struct ClonedSessionOptions {
+    ptr: *mut sys::OrtSessionOptions,
+}
+
+impl ClonedSessionOptions {
+    fn new(session_options_ptr: *const sys::OrtSessionOptions) -> Result<ClonedSessionOptions> {
+        let mut ptr: *mut sys::OrtSessionOptions = std::ptr::null_mut();
+        let status = unsafe { ort_api!(CloneSessionOptions)?(session_options_ptr, &mut ptr) };
+        status_to_result(status).map_err(OrtError::SessionOptions)?;
+        assert_not_null_pointer(ptr, "SessionOptions")?;
+        Ok(ClonedSessionOptions { ptr })
+    }
+}
+
+impl Drop for ClonedSessionOptions {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseSessionOptions) {
+            unsafe { release(self.ptr) };
+        }
+    }
+}
+
+/// Type storing the session information, built from an [`Environment`](environment/struct.Environment.html)
+///
+/// Runs take `&self`: the runtime supports concurrent runs of a session, so threads can share one
+/// as a `&Session` or an `Arc<Session>`, without a mutex.
+#[derive(Debug)]
+pub struct Session<'a> {
+    env: PhantomData<&'a Environment>,
+    session_ptr: *mut sys::OrtSession,
+    allocator_ptr: *mut sys::OrtAllocator,
+    memory_info: MemoryInfo,
+    /// Information about the ONNX's inputs as stored in loaded file
+    pub inputs: Vec<Input>,
+    /// Information about the ONNX's outputs as stored in loaded file
+    pub outputs: Vec<Output>,
+    input_names: Vec<CString>,
+    output_names: Vec<CString>,
+    scratch_pool: Mutex<Vec<RunScratch>>,
+    upcast_half_outputs: bool,
+    #[cfg(feature = "ndarray")]
+    input_coercions: Vec<Option<InputCoercion>>,
+    #[cfg(feature = "ndarray")]
+    input_guards: Vec<Option<InputGuard>>,
+    log_capture: Option<LogCapture>,
+    creation_warnings: Vec<Warning>,
+    concurrency_limit: Option<ConcurrencyLimit>,
+    max_batch_size: Option<usize>,
+    gpu_memory_budget: Option<GpuMemoryBudget>,
+    retry_policy: Option<RetryPolicy>,
+    non_finite_check: Option<NonFiniteCheck>,
+    summarize_inputs_on_error: bool,
+    run_timer: Option<RunTimer>,
+    trace_tags: Option<TraceTags>,
+    output_numa_node: Option<u32>,
+    #[cfg(feature = "ndarray")]
+    pinned_staging: Option<PinnedStaging>,
+    /// Initializers handed to the runtime, which must outlive the session
+    shared_initializer_values: Vec<Arc<SharedValue>>,
+    #[cfg(feature = "profiling")]
+    profiling_summary: bool,
+    execution_providers: Vec<&'static str>,
+    config: SessionConfig,
+    model_hash: Option<ModelHash>,
+}
+
+/// Tensor handed to the runtime for an input of type `I`
+#[cfg(feature = "ndarray")]
+type InputTensorOf<'t, I> = InputTensor<'t, <I as IntoInput>::Elem, <I as IntoInput>::Dim>;
+
+/// Inputs of a run, or their tensors, kept inline for models of up to 4 inputs so that
+/// preparing them doesn't allocate
+#[cfg(feature = "ndarray")]
+type RunInputs<T> = SmallVec<[T; 4]>;
+
+/// Buffers reused across calls to [`Session::run()`](struct.Session.html#method.run)
+///
+/// Their capacity grows to fit the model on the first call; later calls only clear and refill
+/// them, so steady-state inference does not allocate for the `Run()` arguments. Each run takes a
+/// set from the session's pool for its duration, so that concurrent runs get one each.
+#[derive(Debug, Default)]
+struct RunScratch {
+    input_names_ptr: Vec<*const std::os::raw::c_char>,
+    output_names_ptr: Vec<*const std::os::raw::c_char>,
+    input_ort_values: Vec<*const sys::OrtValue>,
+    output_ort_values: Vec<*mut sys::OrtValue>,
+    dims: Vec<i64>,
+    shape: Vec<usize>,
+}
+
+/// Scratch buffers taken from a session's pool, returned to it when dropped
+struct PooledScratch<'s> {
+    pool: &'s Mutex<Vec<RunScratch>>,
+    scratch: RunScratch,
+}
+
+impl<'s> PooledScratch<'s> {
+    fn take(pool: &'s Mutex<Vec<RunScratch>>) -> PooledScratch<'s> {
+        let scratch = pool
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .pop()
+            .unwrap_or_default();
+        PooledScratch { pool, scratch }
+    }
+}
+
+impl<'s> std::ops::Deref for PooledScratch<'s> {
+    type Target = RunScratch;
+
+    fn deref(&self) -> &RunScratch {
+        &self.scratch
+    }
+}
+
+impl<'s> std::ops::DerefMut for PooledScratch<'s> {
+    fn deref_mut(&mut self) -> &mut RunScratch {
+        &mut self.scratch
+    }
+}
+
+impl<'s> Drop for PooledScratch<'s> {
+    fn drop(&mut self) {
+        let scratch = std::mem::take(&mut self.scratch);
+        self.pool
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .push(scratch);
+    }
+}
+
+/// Information about an ONNX's input as stored in loaded file
+#[derive(Debug)]
+pub struct Input {
+    /// Name of the input layer
+    pub name: String,
+    /// Type of the input layer's elements
+    pub input_type: TensorElementDataType,
+    /// Shape of the input layer
+    ///
+    /// C API uses a i64 for the dimensions. We use an unsigned of the same range of the positive values.
+    pub dimensions: Vec<Option<u32>>,
+    /// Type of the input when it is a sequence or a map rather than a tensor
+    ///
+    /// `input_type` is then the element type of the innermost tensors (or of the map's values),
+    /// and `dimensions` is empty.
+    pub container: Option<TypeInfo>,
+}
+
+/// Information about an ONNX's output as stored in loaded file
+#[derive(Debug)]
+pub struct Output {
+    /// Name of the output layer
+    pub name: String,
+    /// Type of the output layer's elements
+    pub output_type: TensorElementDataType,
+    /// Shape of the output layer
+    ///
+    /// C API uses a i64 for the dimensions. We use an unsigned of the same range of the positive values.
+    pub dimensions: Vec<Option<u32>>,
+    /// Whether the rank of the output is only known once the model has run, `dimensions` being
+    /// empty
+    ///
+    /// The model declares no shape for such outputs (or declares them as scalars, which the
+    /// runtime does not tell apart). They are extracted with the shape of each run's result.
+    pub dynamic_rank: bool,
+}
+
+impl Input {
+    /// Type of the input layer, as a [`TypeInfo`](../type_info/enum.TypeInfo.html)
+    pub fn type_info(&self) -> TypeInfo {
+        if let Some(container) = &self.container {
+            return container.clone();
+        }
+        TypeInfo::Tensor(TensorInfo {
+            element_type: Some(self.input_type),
+            dimensions: self.dimensions.clone(),
+        })
+    }
+
+    /// Return an iterator over the shape elements of the input layer
+    ///
+    /// Note: The member [`Input::dimensions`](struct.Input.html#structfield.dimensions)
+    /// stores `u32` (since ONNX uses `i64` but which cannot be negative) so the
+    /// iterator converts to `usize`.
+    pub fn dimensions(&self) -> impl Iterator<Item = Option<usize>> + '_ {
+        self.dimensions.iter().map(|d| d.map(|d2| d2 as usize))
+    }
+}
+
+impl Output {
+    /// Type of the output layer, as a [`TypeInfo`](../type_info/enum.TypeInfo.html)
+    pub fn type_info(&self) -> TypeInfo {
+        TypeInfo::Tensor(TensorInfo {
+            element_type: Some(self.output_type),
+            dimensions: self.dimensions.clone(),
+        })
+    }
+
+    /// Number of dimensions of the output, `None` if only known once the model has run
+    pub fn rank(&self) -> Option<usize> {
+        (!self.dynamic_rank).then(|| self.dimensions.len())
+    }
+
+    /// Return an iterator over the shape elements of the output layer
+    ///
+    /// Note: The member [`Output::dimensions`](struct.Output.html#structfield.dimensions)
+    /// stores `u32` (since ONNX uses `i64` but which cannot be negative) so the
+    /// iterator converts to `usize`.
+    pub fn dimensions(&self) -> impl Iterator<Item = Option<usize>> + '_ {
+        self.dimensions.iter().map(|d| d.map(|d2| d2 as usize))
+    }
+}
+
+/// Name, element type and shape of an input given to a run, attached to the run's error
+///
+/// See [`SessionBuilder::with_input_summary_on_error()`](struct.SessionBuilder.html#method.with_input_summary_on_error).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSummary {
+    /// Name of the model's input
+    pub name: String,
+    /// Type of the tensor's elements, if the runtime could report it
+    pub element_type: Option<TensorElementDataType>,
+    /// Shape of the tensor, empty if the runtime could not report it
+    pub shape: Vec<i64>,
+}
+
+impl fmt::Display for InputSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.element_type {
+            Some(element_type) => write!(f, "{}: {}{:?}", self.name, element_type, self.shape),
+            None => write!(f, "{}: unknown{:?}", self.name, self.shape),
+        }
+    }
+}
+
+unsafe impl<'a> Send for Session<'a> {}
+unsafe impl<'a> Sync for Session<'a> {}
+
+impl<'a> Drop for Session<'a> {
+    #[tracing::instrument]
+    fn drop(&mut self) {
+        debug!("Dropping the session.");
+        #[cfg(feature = "profiling")]
+        if self.profiling_summary {
+            match self.end_profiling_summary() {
+                Ok(_) | Err(OrtError::ProfilingNotEnabled) => {}
+                Err(err) => error!("Failed to summarize profiling trace: {}", err),
+            }
+        }
+        if self.session_ptr.is_null() {
+            error!("Session pointer is null, not dropping.");
+        } else if fork_guard::is_forked() {
+            // Releasing waits for the session's threads, which did not survive the fork
+            warn!("Process forked after creating the session, leaking it.");
+        } else {
+            // The pinned buffers come from an allocator of the session
+            #[cfg(feature = "ndarray")]
+            {
+                self.pinned_staging = None;
+            }
+            if let Ok(release) = ort_api!(ReleaseSession) {
+                unsafe { release(self.session_ptr) };
+            }
+            // Only once the session using them is released
+            self.shared_initializer_values.clear();
+        }
+        // FIXME: There is no C function to release the allocator?
+
+        self.session_ptr = std::ptr::null_mut();
+        self.allocator_ptr = std::ptr::null_mut();
+    }
+}
+
+impl<'a> Session<'a> {
+    fn from_session_ptr(
+        session_ptr: *mut sys::OrtSession,
+        builder: &mut SessionBuilder<'a>,
+    ) -> Result<Session<'a>> {
         let mut allocator_ptr: *mut sys::OrtAllocator = std::ptr::null_mut();
-        let status = unsafe { g_ort().GetAllocatorWithDefaultOptions.unwrap()(&mut allocator_ptr) };
+        let status = unsafe { ort_api!(GetAllocatorWithDefaultOptions)?(&mut allocator_ptr) };
         status_to_result(status).map_err(OrtError::Allocator)?;
         assert_null_pointer(status, "SessionStatus")?;
         assert_not_null_pointer(allocator_ptr, "Allocator")?;
@@ -298,200 +1572,1372 @@ impl<'a> SessionBuilder<'a> {
             .map(|i| dangerous::extract_output(session_ptr, allocator_ptr, i))
             .collect::<Result<Vec<Output>>>()?;
 
-        Ok(Session {
-            env: PhantomData,
-            session_ptr,
-            allocator_ptr,
-            memory_info,
-            inputs,
-            outputs,
+        // Names are passed to every `Run()` call; convert them to C strings only once.
+        let input_names = inputs
+            .iter()
+            .map(|input| CString::new(input.name.clone()))
+            .collect::<std::result::Result<Vec<CString>, _>>()?;
+        let output_names = outputs
+            .iter()
+            .map(|output| CString::new(output.name.clone()))
+            .collect::<std::result::Result<Vec<CString>, _>>()?;
+
+        #[cfg(feature = "ndarray")]
+        let mut input_coercions = vec![None; inputs.len()];
+        #[cfg(feature = "ndarray")]
+        for (name, coercion) in &builder.input_coercions {
+            let index = inputs
+                .iter()
+                .position(|input| &input.name == name)
+                .ok_or_else(|| OrtError::UnknownInput { name: name.clone() })?;
+            input_coercions[index] = Some(*coercion);
+        }
+        #[cfg(feature = "ndarray")]
+        let mut input_guards = vec![None; inputs.len()];
+        #[cfg(feature = "ndarray")]
+        for (name, guard) in &builder.input_guards {
+            let index = inputs
+                .iter()
+                .position(|input| &input.name == name)
+                .ok_or_else(|| OrtError::UnknownInput { name: name.clone() })?;
+            input_guards[index] = Some(*guard);
+        }
+
+        let config = builder.effective_config();
+        debug!(
+            config = config.to_string().as_str(),
+            "Session configuration."
+        );
+
+        let log_capture = builder.log_capture.take();
+        let creation_warnings = log_capture
+            .as_ref()
+            .map_or_else(Vec::new, LogCapture::finish_warnings);
+
+        #[cfg_attr(not(feature = "ndarray"), allow(unused_mut))]
+        let mut session = Session {
+            env: PhantomData,
+            session_ptr,
+            allocator_ptr,
+            memory_info,
+            inputs,
+            outputs,
+            input_names,
+            output_names,
+            scratch_pool: Mutex::new(Vec::new()),
+            upcast_half_outputs: builder.upcast_half_outputs,
+            #[cfg(feature = "ndarray")]
+            input_coercions,
+            #[cfg(feature = "ndarray")]
+            input_guards,
+            log_capture,
+            creation_warnings,
+            concurrency_limit: builder.concurrency_limit.take(),
+            max_batch_size: builder.max_batch_size,
+            gpu_memory_budget: builder
+                .gpu_memory_budget
+                .map(|budget_bytes| GpuMemoryBudget {
+                    budget_bytes,
+                    weights_bytes: builder.model_size,
+                    activation_bytes_per_item: builder.activation_bytes_per_item,
+                }),
+            retry_policy: builder.retry_policy.clone(),
+            non_finite_check: builder.non_finite_check.clone(),
+            summarize_inputs_on_error: builder.summarize_inputs_on_error,
+            run_timer: builder.run_timings.then(RunTimer::default),
+            trace_tags: builder.trace_tags.clone(),
+            output_numa_node: builder.output_numa_node,
+            #[cfg(feature = "ndarray")]
+            pinned_staging: None,
+            // Cloned, not taken: the builder's session options point at the values until it is
+            // dropped, and a later attempt of an execution provider chain may use them again
+            shared_initializer_values: builder.shared_initializer_values.clone(),
+            #[cfg(feature = "profiling")]
+            profiling_summary: builder.profiling_summary,
+            execution_providers: builder.execution_providers.clone(),
+            config,
+            model_hash: builder.model_hash,
+        };
+
+        // Created last, so that the session is released properly if this fails
+        #[cfg(feature = "ndarray")]
+        if let Some(min_input_bytes) = builder.pinned_staging_min_bytes {
+            session.pinned_staging = Some(PinnedStaging::new(
+                session.session_ptr,
+                session.inputs.len(),
+                min_input_bytes,
+            )?);
+        }
+
+        Ok(session)
+    }
+
+    /// Run the input data through the ONNX graph, performing inference.
+    ///
+    /// Note that ONNX models can have multiple inputs; any iterable of inputs (see
+    /// [`IntoInput`](../tensor/into_input/trait.IntoInput.html)) is thus accepted here, matched
+    /// to the model's inputs by position: a `Vec<_>`, a slice iterator, or an array such as
+    /// `[input]` for single-input models. The number and shapes of the inputs are checked before
+    /// any of them is converted, and inputs in the standard layout, owned or borrowed, are handed
+    /// to the runtime in place.
+    #[cfg(feature = "ndarray")]
+    pub fn run<'s, 't, 'm, I, TOut>(
+        &'s self,
+        inputs: I,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let inputs: RunInputs<_> = inputs.into_iter().collect();
+        let input_ort_tensors = self.prepare_inputs(&inputs)?;
+        self.run_ort_values(None, input_ort_tensors.iter().map(|tensor| tensor.c_ptr()))
+    }
+
+    /// Same as [`run()`](#method.run), for inputs of differing element types
+    ///
+    /// Inputs are [`InputArray`](../tensor/enum.InputArray.html)s, or arrays converted to them,
+    /// of the element type of the model's input unless an
+    /// [input coercion](struct.SessionBuilder.html#method.with_input_coercion) is set for it.
+    /// Models taking sequences or maps are given
+    /// [`OrtSequence`](../tensor/container/struct.OrtSequence.html)s and
+    /// [`OrtMap`](../tensor/container/struct.OrtMap.html)s, as
+    /// [`InputValue`](../tensor/enum.InputValue.html)s:
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::{environment::Environment, ndarray::{Array2, Array4}};
+    /// # use onnxruntime::tensor::{InputArray, OrtOwnedTensor};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let images = Array4::<f32>::zeros((1, 3, 224, 224));
+    /// let token_ids = Array2::<i64>::zeros((1, 16));
+    /// let inputs: Vec<InputArray> = vec![images.into(), token_ids.into()];
+    /// let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run_mixed(inputs)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_mixed<'s, 't, 'm, I, TOut>(
+        &'s self,
+        inputs: I,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator,
+        I::Item: Into<InputValue>,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let input_ort_tensors = self.prepare_mixed_inputs(inputs)?;
+        self.run_ort_values(None, input_ort_tensors.iter().map(|tensor| tensor.c_ptr()))
+    }
+
+    /// Same as [`run()`](#method.run), with optional inputs omitted by passing `None`
+    ///
+    /// Inputs are matched to the model's inputs by position. Only inputs the model can do
+    /// without may be omitted: inputs of an `optional` type, or inputs overriding an initializer,
+    /// such as the initial hidden state of a recurrent network. Omitting another input fails the
+    /// run with [`OrtError::Run`](../error/enum.OrtError.html#variant.Run).
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::{environment::Environment, ndarray::Array3, tensor::OrtOwnedTensor};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let mut session = environment.new_session_builder()?.with_model_from_file("rnn.onnx")?;
+    /// let features = Array3::<f32>::zeros((1, 100, 40));
+    /// // No initial hidden state: the model starts from zeros
+    /// let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run_optional([Some(features), None])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_optional<'s, 't, 'm, I, X, TOut>(
+        &'s self,
+        inputs: I,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator<Item = Option<X>>,
+        X: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let inputs: RunInputs<Option<X>> = inputs.into_iter().collect();
+        let views: RunInputs<_> = inputs
+            .iter()
+            .map(|input| input.as_ref().map(IntoInput::input_view))
+            .collect();
+        self.validate_optional_input_shapes(
+            views
+                .iter()
+                .map(|view| view.as_ref().map(|view| view.shape())),
+        )?;
+
+        let input_ort_tensors: RunInputs<Option<InputTensorOf<X>>> =
+            self.timed(Phase::InputConversion, || {
+                views
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, view)| {
+                        view.map(|view| self.prepare_borrowed_input(index, view))
+                            .transpose()
+                    })
+                    .collect::<Result<_>>()
+            })?;
+        self.run_ort_values(
+            None,
+            input_ort_tensors.iter().map(|tensor| {
+                tensor
+                    .as_ref()
+                    .map_or(std::ptr::null_mut(), InputTensor::c_ptr)
+            }),
+        )
+    }
+
+    /// Run a model without inputs, such as a generator-style graph computing its outputs from
+    /// initializers only
+    ///
+    /// Fails with [`OrtError::NonMatchingDimensions`](../error/enum.OrtError.html#variant.NonMatchingDimensions)
+    /// if the model has inputs.
+    #[cfg(feature = "ndarray")]
+    pub fn run_without_inputs<'s, 't, 'm, TOut>(
+        &'s self,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        self.validate_input_shapes(std::iter::empty())?;
+        self.run_ort_values(None, std::iter::empty())
+    }
+
+    /// Same as [`run_mixed()`](#method.run_mixed), for outputs of differing element types
+    ///
+    /// Each output is extracted with its own type, using
+    /// [`DynOrtTensor::try_extract()`](../tensor/dyn_ort_tensor/struct.DynOrtTensor.html#method.try_extract):
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::{environment::Environment, ndarray::Array4};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let images = Array4::<f32>::zeros((1, 3, 640, 640));
+    /// let outputs = session.run_untyped(vec![images])?;
+    /// let boxes = outputs[0].try_extract::<f32>()?;
+    /// let labels = outputs[1].try_extract::<i64>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_untyped<'s, 'm, I>(&'s self, inputs: I) -> Result<Vec<DynOrtTensor<'m>>>
+    where
+        I: IntoIterator,
+        I::Item: Into<InputValue>,
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let input_ort_tensors = self.prepare_mixed_inputs(inputs)?;
+        let memory_info_ref = &self.memory_info;
+        let upcast_half_outputs = self.upcast_half_outputs;
+        self.run_and_extract(
+            None,
+            input_ort_tensors.iter().map(|tensor| tensor.c_ptr()),
+            |ptr, shape| DynOrtTensor::new(memory_info_ref, ptr, shape, upcast_half_outputs),
+        )
+    }
+
+    /// Same as [`run_untyped()`](#method.run_untyped), for outputs of any type
+    ///
+    /// Outputs are [`DynOrtValue`](../tensor/dyn_ort_value/struct.DynOrtValue.html)s: tensors of
+    /// any element type, sequences or maps, as output by traditional machine learning models
+    /// converted to ONNX. Inputs may be values too.
+    #[cfg(feature = "ndarray")]
+    pub fn run_values<'s, 'm, I>(&'s self, inputs: I) -> Result<Vec<DynOrtValue<'m>>>
+    where
+        I: IntoIterator,
+        I::Item: Into<InputValue>,
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let input_ort_values = self.prepare_mixed_inputs(inputs)?;
+        let memory_info_ref = &self.memory_info;
+        let upcast_half_outputs = self.upcast_half_outputs;
+        self.run_and_extract(
+            None,
+            input_ort_values.iter().map(|value| value.c_ptr()),
+            |ptr, _shape| DynOrtValue::new(memory_info_ref, ptr, upcast_half_outputs),
+        )
+    }
+
+    /// Same as [`run()`](#method.run), fetching only the outputs named in `output_names`, in that
+    /// order
+    ///
+    /// The runtime skips the parts of the graph only needed by the other outputs, and does not
+    /// allocate them: large auxiliary outputs (attention maps, debug heads) are not materialized.
+    /// Fails with [`OrtError::UnknownOutput`](../error/enum.OrtError.html#variant.UnknownOutput)
+    /// if the model has no output of one of the names.
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::{environment::Environment, ndarray::Array2, tensor::OrtOwnedTensor};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let input_ids = Array2::<i64>::zeros((1, 8));
+    /// let outputs: Vec<OrtOwnedTensor<f32, _>> =
+    ///     session.run_with_outputs([input_ids], &["logits"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_with_outputs<'s, 't, 'm, I, N, TOut>(
+        &'s self,
+        inputs: I,
+        output_names: &[N],
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        N: AsRef<str>,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let output_indices = output_names
+            .iter()
+            .map(|name| self.output_index(name.as_ref()))
+            .collect::<Result<Vec<usize>>>()?;
+        let inputs: RunInputs<_> = inputs.into_iter().collect();
+        let input_ort_tensors = self.prepare_inputs(&inputs)?;
+        self.run_ort_values_for_outputs(
+            None,
+            Some(&output_indices),
+            input_ort_tensors.iter().map(|tensor| tensor.c_ptr()),
+        )
+    }
+
+    /// Same as [`run()`](#method.run), with inputs matched to the model's inputs by name
+    ///
+    /// Accepts any iterable of `(name, input)` pairs, such as a `HashMap<&str, Array<T, D>>`.
+    /// Every input of the model must be given exactly once: the run fails with
+    /// [`OrtError::UnknownInput`](../error/enum.OrtError.html#variant.UnknownInput),
+    /// [`OrtError::MissingInput`](../error/enum.OrtError.html#variant.MissingInput) or
+    /// [`OrtError::DuplicateInput`](../error/enum.OrtError.html#variant.DuplicateInput) otherwise.
+    ///
+    /// ```no_run
+    /// # use std::{collections::HashMap, error::Error};
+    /// # use onnxruntime::{environment::Environment, ndarray::Array2, tensor::OrtOwnedTensor};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let mut inputs = HashMap::new();
+    /// inputs.insert("input_ids", Array2::<i64>::zeros((1, 8)));
+    /// inputs.insert("attention_mask", Array2::<i64>::ones((1, 8)));
+    /// let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run_with_names(inputs)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_with_names<'s, 't, 'm, I, N, X, TOut>(
+        &'s self,
+        inputs: I,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator<Item = (N, X)>,
+        N: AsRef<str>,
+        X: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let inputs = named::order_inputs(&self.inputs, inputs)?;
+        self.run(inputs)
+    }
+
+    /// Same as [`run()`](#method.run), with the outputs looked up by name rather than position
+    ///
+    /// See [`Outputs`](struct.Outputs.html).
+    #[cfg(feature = "ndarray")]
+    pub fn run_named_outputs<'s, 't, 'm, I, TOut>(
+        &'s self,
+        inputs: I,
+    ) -> Result<Outputs<'s, OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let outputs = self.run(inputs)?;
+        let names = self
+            .outputs
+            .iter()
+            .map(|output| output.name.as_str())
+            .collect();
+        Ok(Outputs::new(names, outputs))
+    }
+
+    /// Same as [`run()`](#method.run), with the given [`RunOptions`](struct.RunOptions.html)
+    ///
+    /// If the options capture the run's logs and the run fails, the error is an
+    /// [`OrtError::RunWithLogs`](../error/enum.OrtError.html#variant.RunWithLogs) holding them.
+    #[cfg(feature = "ndarray")]
+    pub fn run_with_options<'s, 't, 'm, I, TOut>(
+        &'s self,
+        inputs: I,
+        options: &RunOptions,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let inputs: RunInputs<_> = inputs.into_iter().collect();
+        let input_ort_tensors = self.prepare_inputs(&inputs)?;
+        let outputs = self.run_ort_values(
+            Some(options),
+            input_ort_tensors.iter().map(|tensor| tensor.c_ptr()),
+        );
+        match outputs {
+            Err(err) if options.captures_logs() => {
+                let logs = options.take_logs();
+                debug!(
+                    messages = logs.len(),
+                    "Run failed, attaching captured messages."
+                );
+                Err(OrtError::RunWithLogs {
+                    source: Box::new(err),
+                    logs,
+                })
+            }
+            outputs => outputs,
+        }
+    }
+
+    /// Bind inputs and outputs once, to run the session on them repeatedly
+    ///
+    /// See [`IoBinding`](struct.IoBinding.html).
+    #[cfg(feature = "ndarray")]
+    pub fn io_binding(&self) -> Result<IoBinding<'_, 'a>> {
+        IoBinding::new(self)
+    }
+
+    /// Check the shapes of inputs, then convert them to tensors handed to the runtime
+    ///
+    /// Inputs in the standard layout are handed to the runtime in place, borrowed from `inputs`.
+    #[cfg(feature = "ndarray")]
+    fn prepare_inputs<'t, X>(&'t self, inputs: &'t [X]) -> Result<RunInputs<InputTensorOf<'t, X>>>
+    where
+        X: IntoInput,
+        X::Elem: 't,
+        X::Dim: 't,
+    {
+        // Checked before converting (and possibly copying) any input
+        let views: RunInputs<_> = inputs.iter().map(IntoInput::input_view).collect();
+        self.validate_input_shapes(views.iter().map(|view| view.shape()))?;
+
+        // The C API expects pointers for the arrays (pointers to C-arrays)
+        self.timed(Phase::InputConversion, || {
+            views
+                .into_iter()
+                .enumerate()
+                .map(|(index, view)| self.prepare_borrowed_input(index, view))
+                .collect()
+        })
+    }
+
+    /// Check the shapes and element types of inputs of any element type, sequences and maps,
+    /// then convert them to values handed to the runtime
+    #[cfg(feature = "ndarray")]
+    fn prepare_mixed_inputs<'t, I>(&'t self, inputs: I) -> Result<Vec<Box<dyn AsOrtValue + 't>>>
+    where
+        I: IntoIterator,
+        I::Item: Into<InputValue>,
+    {
+        let inputs: Vec<InputValue> = inputs.into_iter().map(Into::into).collect();
+
+        // Checked before converting (and copying) any input
+        self.validate_input_shapes(inputs.iter().map(InputValue::shape))?;
+        for (index, (input, model_input)) in inputs.iter().zip(&self.inputs).enumerate() {
+            let element_type = match input {
+                InputValue::Array(array) => array.element_type(),
+                _ => continue,
+            };
+            let coerced = matches!(self.input_coercions.get(index), Some(Some(_)));
+            if !coerced && element_type != model_input.input_type {
+                return Err(crate::error::InputCoercionError::Unsupported {
+                    input: model_input.name.clone(),
+                    from: element_type,
+                    to: model_input.input_type,
+                }
+                .into());
+            }
+        }
+
+        macro_rules! prepare {
+            ($index:expr, $array:expr) => {
+                Box::new(self.prepare_input($index, $array.into_input())?)
+            };
+        }
+
+        let input_ort_tensors: Vec<Box<dyn AsOrtValue>> =
+            self.timed(Phase::InputConversion, || {
+                inputs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, input)| -> Result<Box<dyn AsOrtValue>> {
+                        let array = match input {
+                            InputValue::Array(array) => array,
+                            InputValue::Sequence(sequence) => return Ok(Box::new(sequence)),
+                            InputValue::Map(map) => return Ok(Box::new(map)),
+                            InputValue::Value(value) => return Ok(Box::new(value)),
+                        };
+                        Ok(match array {
+                            InputArray::Float(array) => prepare!(index, array),
+                            InputArray::Uint8(array) => prepare!(index, array),
+                            InputArray::Int8(array) => prepare!(index, array),
+                            InputArray::Uint16(array) => prepare!(index, array),
+                            InputArray::Int16(array) => prepare!(index, array),
+                            InputArray::Int32(array) => prepare!(index, array),
+                            InputArray::Int64(array) => prepare!(index, array),
+                            InputArray::Double(array) => prepare!(index, array),
+                            InputArray::Uint32(array) => prepare!(index, array),
+                            InputArray::Uint64(array) => prepare!(index, array),
+                            InputArray::String(array) => prepare!(index, array),
+                        })
+                    })
+                    .collect::<Result<_>>()
+            })?;
+
+        Ok(input_ort_tensors)
+    }
+
+    /// Convert the input of the given index to a tensor handed to the runtime, converting it
+    /// to the model's element type or copying it to pinned memory if the session does so
+    #[cfg(feature = "ndarray")]
+    fn prepare_input<'t, T, D>(
+        &'t self,
+        index: usize,
+        input_array: ndarray::Array<T, D>,
+    ) -> Result<InputTensor<'t, T, D>>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone + 't,
+        D: ndarray::Dimension + 't,
+    {
+        if let Some(input_tensor) = self.prepare_checked_input(index, &input_array)? {
+            return Ok(input_tensor);
+        }
+        OrtTensor::from_array(&self.memory_info, self.allocator_ptr, input_array)
+            .map(InputTensor::Direct)
+    }
+
+    /// Same as [`prepare_input()`](#method.prepare_input), handing a borrowed input already in
+    /// the standard layout to the runtime in place rather than copying it
+    #[cfg(feature = "ndarray")]
+    fn prepare_borrowed_input<'t, T, D>(
+        &'t self,
+        index: usize,
+        input_array: ndarray::ArrayView<'t, T, D>,
+    ) -> Result<InputTensor<'t, T, D>>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone + 't,
+        D: ndarray::Dimension + 't,
+    {
+        if let Some(input_tensor) = self.prepare_checked_input(index, &input_array)? {
+            return Ok(input_tensor);
+        }
+        match input_array.to_slice() {
+            // String tensors are always copied to the runtime's memory
+            Some(data) if T::tensor_element_data_type() != TensorElementDataType::String => {
+                OrtTensorRef::from_slice(&self.memory_info, data, input_array.shape())
+                    .map(|tensor| InputTensor::Borrowed(tensor, input_array.raw_dim()))
+            }
+            _ => OrtTensor::from_array(
+                &self.memory_info,
+                self.allocator_ptr,
+                input_array.as_standard_layout().into_owned(),
+            )
+            .map(InputTensor::Direct),
+        }
+    }
+
+    /// Check the input of the given index with its guard, then convert it to the model's
+    /// element type or copy it to pinned memory if the session does so
+    ///
+    /// Returns `None` if the input is to be handed to the runtime as is.
+    #[cfg(feature = "ndarray")]
+    fn prepare_checked_input<'t, S, T, D>(
+        &'t self,
+        index: usize,
+        input_array: &ndarray::ArrayBase<S, D>,
+    ) -> Result<Option<InputTensor<'t, T, D>>>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: TypeToTensorElementDataType + Debug + Clone + 't,
+        D: ndarray::Dimension + 't,
+    {
+        let model_input = self.inputs.get(index);
+        if let (Some(model_input), Some(Some(guard))) = (model_input, self.input_guards.get(index))
+        {
+            guard.check(&model_input.name, input_array.view())?;
+        }
+        match (model_input, self.input_coercions.get(index)) {
+            (Some(model_input), Some(Some(coercion)))
+                if model_input.input_type != T::tensor_element_data_type() =>
+            {
+                coercion::coerce_input(
+                    &self.memory_info,
+                    self.allocator_ptr,
+                    model_input,
+                    input_array,
+                    *coercion,
+                )
+                .map(|tensor| Some(InputTensor::Coerced(tensor)))
+            }
+            _ => match &self.pinned_staging {
+                Some(staging) => Ok(staging.stage(index, input_array)?.map(InputTensor::Staged)),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Run inference on tensors backed by memory-mapped files
+    ///
+    /// The tensors' data is handed to the runtime in place: nothing is read into
+    /// anonymous memory beforehand. Inputs are matched to the model's inputs by position,
+    /// as for [`run()`](#method.run).
+    #[cfg(feature = "mmap")]
+    pub fn run_mmap<'s, 't, 'm, TIn, TOut>(
+        &'s self,
+        input_tensors: &[MmapTensor<TIn>],
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        TIn: TypeToTensorElementDataType + Debug + Clone,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        self.validate_input_shapes(input_tensors.iter().map(|tensor| tensor.shape()))?;
+
+        let input_ort_tensors = self.timed(Phase::InputConversion, || {
+            input_tensors
+                .iter()
+                .map(|tensor| {
+                    OrtTensorRef::from_slice(&self.memory_info, tensor.data(), tensor.shape())
+                })
+                .collect::<Result<Vec<OrtTensorRef>>>()
+        })?;
+
+        self.run_ort_values(None, input_ort_tensors.iter().map(|tensor| tensor.c_ptr))
+    }
+
+    /// Run inference on borrowed arrays, always handing their data to the runtime in place
+    ///
+    /// Like [`run()`](#method.run), inputs are matched to the model's inputs by position and
+    /// views in the standard layout are not copied (others are copied once). Unlike it, input
+    /// coercions and pinned staging do not apply, so the runtime always reads the arrays' memory
+    /// directly, and string tensors are not supported. Flat buffers can be run in place with
+    /// [`run_raw()`](#method.run_raw).
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::{environment::Environment, tensor::OrtOwnedTensor};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let images = ndarray::Array::<f32, _>::zeros((64, 3, 224, 224));
+    /// for batch in images.axis_chunks_iter(ndarray::Axis(0), 8) {
+    ///     let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run_borrowed(&[batch])?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_borrowed<'s, 't, 'm, TIn, D, TOut>(
+        &'s self,
+        inputs: &[ndarray::ArrayView<'_, TIn, D>],
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        TIn: TypeToTensorElementDataType + Debug + Clone,
+        D: ndarray::Dimension,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        for ((input, model_input), guard) in inputs.iter().zip(&self.inputs).zip(&self.input_guards)
+        {
+            if let Some(guard) = guard {
+                guard.check(&model_input.name, input.view())?;
+            }
+        }
+
+        // Borrows the arrays already in the standard layout, copies the others
+        let inputs: Vec<ndarray::CowArray<TIn, D>> = inputs
+            .iter()
+            .map(|input| input.as_standard_layout())
+            .collect();
+        self.validate_input_shapes(inputs.iter().map(|input| input.shape()))?;
+
+        let input_ort_tensors = self.timed(Phase::InputConversion, || {
+            inputs
+                .iter()
+                .map(|input| {
+                    let data = input
+                        .as_slice()
+                        .expect("Arrays in the standard layout are contiguous");
+                    OrtTensorRef::from_slice(&self.memory_info, data, input.shape())
+                })
+                .collect::<Result<Vec<OrtTensorRef>>>()
+        })?;
+
+        self.run_ort_values(None, input_ort_tensors.iter().map(|tensor| tensor.c_ptr))
+    }
+
+    /// Run inference on inputs of any batch size, splitting them along their batch (first) axis
+    /// into batches the model accepts and concatenating the outputs of the batches
+    ///
+    /// The batches are of the model's batch size if its first input has a fixed one (as models
+    /// exported with a batch of 1), or else of the session's maximum batch size (see
+    /// [`SessionBuilder::with_max_batch_size()`](struct.SessionBuilder.html#method.with_max_batch_size));
+    /// without either, the inputs run at once. A last batch smaller than a fixed batch size is
+    /// padded by repeating its last item, and the outputs of the padding are dropped.
+    ///
+    /// All inputs must have the same batch size, and all outputs a batch axis. Outputs are copied
+    /// out of the runtime, as the batches are run as for [`run_borrowed()`](#method.run_borrowed).
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::environment::Environment;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// // Exported with input `image` of shape [1, 3, 224, 224]
+    /// let session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let images = ndarray::Array::<f32, _>::zeros((64, 3, 224, 224));
+    /// let scores = session.run_batched::<_, _, f32>(&[images.view()])?;
+    /// assert_eq!(scores[0].shape()[0], 64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_batched<TIn, D, TOut>(
+        &self,
+        inputs: &[ndarray::ArrayView<'_, TIn, D>],
+    ) -> Result<Vec<ArrayD<TOut>>>
+    where
+        TIn: TypeToTensorElementDataType + Debug + Clone,
+        D: ndarray::RemoveAxis,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+    {
+        let fixed_batch_size = self
+            .inputs
+            .first()
+            .and_then(|input| batched::fixed_batch_size(&input.dimensions));
+        let batch_size = fixed_batch_size
+            .or(self.max_batch_size)
+            .or_else(|| {
+                inputs
+                    .first()
+                    .and_then(|input| input.shape().first().copied())
+            })
+            .unwrap_or(1);
+        batched::run_in_batches(inputs, batch_size, fixed_batch_size.is_some(), |batch| {
+            let outputs: Vec<OrtOwnedTensor<TOut, ndarray::IxDyn>> = self.run_borrowed(batch)?;
+            Ok(self.copy_outputs(&outputs))
+        })
+    }
+
+    /// Run inference, writing the outputs to the caller's `outputs` rather than allocating them
+    ///
+    /// `outputs` are matched to the model's outputs by position, and must be of their element
+    /// type and shape (the run fails otherwise) and in the standard layout. Reusing the same
+    /// buffers across runs, steady-state inference allocates no outputs, which suits low-latency
+    /// loops over audio or video frames. Inputs are handed to the runtime as for
+    /// [`run()`](#method.run).
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::environment::Environment;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let mut frame = ndarray::Array::<f32, _>::zeros((1, 480));
+    /// let mut denoised = ndarray::Array::<f32, _>::zeros((1, 480));
+    /// loop {
+    ///     // ... fill `frame` with the next samples
+    ///     session.run_into(vec![frame.view()], &mut [denoised.view_mut()])?;
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn run_into<I, TOut, D>(
+        &self,
+        inputs: I,
+        outputs: &mut [ndarray::ArrayViewMut<'_, TOut, D>],
+    ) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        D: ndarray::Dimension,
+    {
+        if outputs.len() != self.outputs.len() {
+            return Err(OrtError::NonMatchingDimensions(
+                NonMatchingDimensionsError::OutputsCount {
+                    given: outputs.len(),
+                    model: self.outputs.len(),
+                },
+            ));
+        }
+        let output_ort_tensors = outputs
+            .iter_mut()
+            .map(|output| {
+                let shape = output.shape().to_vec();
+                let data = output.as_slice_mut().ok_or(OrtError::NonContiguousTensor)?;
+                OrtTensorRef::from_mut_slice(&self.memory_info, data, &shape)
+            })
+            .collect::<Result<Vec<OrtTensorRef>>>()?;
+        let output_ort_values: Vec<*mut sys::OrtValue> = output_ort_tensors
+            .iter()
+            .map(|tensor| tensor.c_ptr)
+            .collect();
+
+        let inputs: RunInputs<_> = inputs.into_iter().collect();
+        let input_ort_tensors = self.prepare_inputs(&inputs)?;
+        self.run_values_for_outputs(
+            None,
+            None,
+            Some(&output_ort_values),
+            input_ort_tensors.iter().map(|tensor| tensor.c_ptr()),
+            // The outputs are the caller's, released with `output_ort_tensors`
+            |_, _| Ok(()),
+        )?;
+        Ok(())
+    }
+
+    /// Run inference on tensors given as flat buffers and shapes, without `ndarray`
+    ///
+    /// Inputs are matched to the model's inputs by position, as for [`run()`](#method.run), and
+    /// handed to the runtime in place: nothing is copied. The outputs are copied out of the
+    /// runtime's memory. Input coercions do not apply and string tensors are not supported.
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::{environment::Environment, tensor::RawTensor};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// let input = RawTensor::new(vec![0.0_f32; 3 * 224 * 224], vec![1, 3, 224, 224])?;
+    /// let outputs: Vec<RawTensor<f32>> = session.run_raw(&[input])?;
+    /// println!("{:?}: {:?}", outputs[0].shape(), outputs[0].data());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_raw<TIn, TOut>(&self, inputs: &[RawTensor<TIn>]) -> Result<Vec<RawTensor<TOut>>>
+    where
+        TIn: TypeToTensorElementDataType + Debug + Clone,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+    {
+        self.validate_input_shapes(inputs.iter().map(RawTensor::shape))?;
+
+        let input_ort_tensors = self.timed(Phase::InputConversion, || {
+            inputs
+                .iter()
+                .map(|tensor| {
+                    OrtTensorRef::from_slice(&self.memory_info, tensor.data(), tensor.shape())
+                })
+                .collect::<Result<Vec<OrtTensorRef>>>()
+        })?;
+
+        let upcast_half_outputs = self.upcast_half_outputs;
+        let output_numa_node = self.output_numa_node;
+        self.run_and_extract(
+            None,
+            input_ort_tensors.iter().map(|tensor| tensor.c_ptr),
+            |ptr, shape| raw::extract(ptr, shape, upcast_half_outputs, output_numa_node),
+        )
+    }
+
+    /// Call `Run()` on already created input values, in the order of the model's inputs,
+    /// extracting the outputs as [`OrtOwnedTensor`](../tensor/ort_owned_tensor/struct.OrtOwnedTensor.html)s
+    ///
+    /// The caller keeps ownership of the input values and must keep them alive until this returns.
+    #[cfg(feature = "ndarray")]
+    fn run_ort_values<'s, 't, 'm, TOut>(
+        &'s self,
+        run_options: Option<&RunOptions>,
+        input_ort_values: impl Iterator<Item = *mut sys::OrtValue>,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        self.run_ort_values_for_outputs(run_options, None, input_ort_values)
+    }
+
+    /// Same as [`run_ort_values()`](#method.run_ort_values), fetching only the outputs of the
+    /// given indices (all of them if `None`), in that order
+    #[cfg(feature = "ndarray")]
+    fn run_ort_values_for_outputs<'s, 't, 'm, TOut>(
+        &'s self,
+        run_options: Option<&RunOptions>,
+        output_indices: Option<&[usize]>,
+        input_ort_values: impl Iterator<Item = *mut sys::OrtValue>,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        let memory_info_ref = &self.memory_info;
+        let extract = |ptr, shape: &[usize]| {
+            let mut output_tensor_extractor =
+                OrtOwnedTensorExtractor::new(memory_info_ref, ndarray::IxDyn(shape));
+            output_tensor_extractor.tensor_ptr = ptr;
+            output_tensor_extractor.upcast_half_to_f32 = self.upcast_half_outputs;
+            output_tensor_extractor.extract::<TOut>()
+        };
+        self.run_values_for_outputs(run_options, output_indices, None, input_ort_values, extract)
+    }
+
+    /// Call `Run()` on already created input values, in the order of the model's inputs,
+    /// extracting each output value with `extract`, given the value and its shape (empty for
+    /// outputs other than tensors)
+    ///
+    /// The caller keeps ownership of the input values and must keep them alive until this returns;
+    /// `extract` takes ownership of the output values.
+    fn run_and_extract<O>(
+        &self,
+        run_options: Option<&RunOptions>,
+        input_ort_values: impl Iterator<Item = *mut sys::OrtValue>,
+        extract: impl FnMut(*mut sys::OrtValue, &[usize]) -> Result<O>,
+    ) -> Result<Vec<O>> {
+        self.run_values_for_outputs(run_options, None, None, input_ort_values, extract)
+    }
+
+    /// Same as [`run_and_extract()`](#method.run_and_extract), fetching only the outputs of the
+    /// given indices (all of them if `None`), in that order
+    ///
+    /// Given `preallocated_outputs`, one per fetched output, the runtime writes the outputs to
+    /// them instead of allocating its own; the caller keeps ownership of them.
+    fn run_values_for_outputs<O>(
+        &self,
+        run_options: Option<&RunOptions>,
+        output_indices: Option<&[usize]>,
+        preallocated_outputs: Option<&[*mut sys::OrtValue]>,
+        input_ort_values: impl Iterator<Item = *mut sys::OrtValue>,
+        mut extract: impl FnMut(*mut sys::OrtValue, &[usize]) -> Result<O>,
+    ) -> Result<Vec<O>> {
+        fork_guard::check()?;
+
+        let _permit = self
+            .concurrency_limit
+            .as_ref()
+            .map(ConcurrencyLimit::acquire);
+
+        // Build arguments to Run(), reusing scratch buffers of the session
+
+        let mut scratch_guard = PooledScratch::take(&self.scratch_pool);
+        let scratch = &mut *scratch_guard;
+
+        // Inputs without a value (null) are omitted, for the model to do without them
+        scratch.input_names_ptr.clear();
+        scratch.input_ort_values.clear();
+        for (name, ptr) in self.input_names.iter().zip(input_ort_values) {
+            if !ptr.is_null() {
+                scratch.input_names_ptr.push(name.as_ptr());
+                scratch.input_ort_values.push(ptr as *const sys::OrtValue);
+            }
+        }
+
+        scratch.output_names_ptr.clear();
+        match output_indices {
+            Some(output_indices) => scratch.output_names_ptr.extend(
+                output_indices
+                    .iter()
+                    .map(|&index| self.output_names[index].as_ptr()),
+            ),
+            None => scratch
+                .output_names_ptr
+                .extend(self.output_names.iter().map(|n| n.as_ptr())),
+        }
+
+        scratch.output_ort_values.clear();
+        scratch
+            .output_ort_values
+            .resize(scratch.output_names_ptr.len(), std::ptr::null_mut());
+
+        let mut run_once = |run_options: Option<&RunOptions>| {
+            let run_options_ptr = run_options.map_or(std::ptr::null(), RunOptions::ptr);
+            for (index, output) in scratch.output_ort_values.iter_mut().enumerate() {
+                *output = preallocated_outputs.map_or(std::ptr::null_mut(), |values| values[index]);
+            }
+            let status = unsafe {
+                ort_api!(Run)?(
+                    self.session_ptr,
+                    run_options_ptr,
+                    scratch.input_names_ptr.as_ptr(),
+                    scratch.input_ort_values.as_ptr(),
+                    scratch.input_ort_values.len(),
+                    scratch.output_names_ptr.as_ptr(),
+                    scratch.output_names_ptr.len(),
+                    scratch.output_ort_values.as_mut_ptr(),
+                )
+            };
+            status_to_result(status).map_err(OrtError::Run)
+        };
+        // Runs without options of their own carry the tag of their caller's trace context
+        let run_tag = match (run_options, &self.trace_tags) {
+            (None, Some(trace_tags)) => trace_tags.tag(),
+            _ => None,
+        };
+        if let Some(run_tag) = run_options
+            .and_then(RunOptions::run_tag)
+            .or(run_tag.as_deref())
+        {
+            debug!(run_tag, "Starting tagged run.");
+        }
+        let result = self.timed(Phase::Run, || match &self.retry_policy {
+            Some(policy) => policy.retry(run_options, run_tag.as_deref(), run_once),
+            None => {
+                let tagged_options = run_tag.as_deref().map(RunOptions::tagged).transpose()?;
+                run_once(run_options.or(tagged_options.as_ref()))
+            }
+        });
+        if let Err(err) = result {
+            if !self.summarize_inputs_on_error {
+                return Err(err);
+            }
+            return Err(OrtError::RunWithInputs {
+                source: Box::new(err),
+                inputs: self.summarize_inputs(&scratch.input_names_ptr, &scratch.input_ort_values),
+            });
+        }
+
+        if let Some(check) = &self.non_finite_check {
+            let checked =
+                scratch
+                    .output_ort_values
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(position, &ptr)| {
+                        let index = output_indices.map_or(position, |indices| indices[position]);
+                        check.check(&self.outputs[index].name, ptr)
+                    });
+            if let Err(err) = checked {
+                // The outputs are dropped with the failed run, unless they belong to the caller
+                if preallocated_outputs.is_none() {
+                    if let Ok(release) = ort_api!(ReleaseValue) {
+                        for &ptr in &scratch.output_ort_values {
+                            unsafe { release(ptr) };
+                        }
+                    }
+                }
+                return Err(err);
+            }
+        }
+
+        let dims_scratch = &mut scratch.dims;
+        let shape_scratch = &mut scratch.shape;
+        let output_ort_values = &scratch.output_ort_values;
+        self.timed(Phase::OutputExtraction, || {
+            output_ort_values
+                .iter()
+                .map(|&ptr| {
+                    shape_scratch.clear();
+                    let mut is_tensor = 0;
+                    let status = unsafe { ort_api!(IsTensor)?(ptr, &mut is_tensor) };
+                    status_to_result(status).map_err(OrtError::IsTensor)?;
+                    if is_tensor == 0 {
+                        return extract(ptr, shape_scratch);
+                    }
+
+                    let mut tensor_info_ptr: *mut sys::OrtTensorTypeAndShapeInfo =
+                        std::ptr::null_mut();
+                    let status =
+                        unsafe { ort_api!(GetTensorTypeAndShape)?(ptr, &mut tensor_info_ptr as _) };
+                    status_to_result(status).map_err(OrtError::GetTensorTypeAndShape)?;
+                    let dims = unsafe { get_tensor_dimensions_into(tensor_info_ptr, dims_scratch) };
+                    unsafe { ort_api!(ReleaseTensorTypeAndShapeInfo)?(tensor_info_ptr) };
+                    dims?;
+                    shape_scratch.extend(dims_scratch.iter().map(|&n| n as usize));
+
+                    extract(ptr, shape_scratch)
+                })
+                .collect()
+        })
+    }
+
+    /// Copy outputs to owned arrays, on the NUMA node of the session if any
+    #[cfg(feature = "ndarray")]
+    fn copy_outputs<TOut>(
+        &self,
+        outputs: &[OrtOwnedTensor<TOut, ndarray::IxDyn>],
+    ) -> Vec<ArrayD<TOut>>
+    where
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+    {
+        self.timed(Phase::OutputCopy, || {
+            outputs
+                .iter()
+                .map(|output| copy_output(output, self.output_numa_node))
+                .collect()
         })
     }
-}
 
-/// Type storing the session information, built from an [`Environment`](environment/struct.Environment.html)
-#[derive(Debug)]
-pub struct Session<'a> {
-    env: PhantomData<&'a Environment>,
-    session_ptr: *mut sys::OrtSession,
-    allocator_ptr: *mut sys::OrtAllocator,
-    memory_info: MemoryInfo,
-    /// Information about the ONNX's inputs as stored in loaded file
-    pub inputs: Vec<Input>,
-    /// Information about the ONNX's outputs as stored in loaded file
-    pub outputs: Vec<Output>,
-}
+    /// Call `f`, timing it as the given phase of the run if the session records timings
+    fn timed<R>(&self, phase: Phase, f: impl FnOnce() -> R) -> R {
+        match &self.run_timer {
+            Some(timer) => timer.time(phase, f),
+            None => f(),
+        }
+    }
 
-/// Information about an ONNX's input as stored in loaded file
-#[derive(Debug)]
-pub struct Input {
-    /// Name of the input layer
-    pub name: String,
-    /// Type of the input layer's elements
-    pub input_type: TensorElementDataType,
-    /// Shape of the input layer
+    /// Time spent in each phase of the last run
     ///
-    /// C API uses a i64 for the dimensions. We use an unsigned of the same range of the positive values.
-    pub dimensions: Vec<Option<u32>>,
-}
+    /// `None` unless the session was built with
+    /// [`SessionBuilder::with_run_timings()`](struct.SessionBuilder.html#method.with_run_timings).
+    /// Retries (see [`RetryPolicy`](struct.RetryPolicy.html)) count towards the `Run()` phase.
+    /// With concurrent runs, the phases may come from different runs.
+    pub fn last_run_timings(&self) -> Option<RunTimings> {
+        self.run_timer.as_ref().map(RunTimer::last)
+    }
 
-/// Information about an ONNX's output as stored in loaded file
-#[derive(Debug)]
-pub struct Output {
-    /// Name of the output layer
-    pub name: String,
-    /// Type of the output layer's elements
-    pub output_type: TensorElementDataType,
-    /// Shape of the output layer
-    ///
-    /// C API uses a i64 for the dimensions. We use an unsigned of the same range of the positive values.
-    pub dimensions: Vec<Option<u32>>,
-}
+    /// Summaries of the input values of a run, in the order of the model's inputs
+    fn summarize_inputs(
+        &self,
+        input_names_ptr: &[*const std::os::raw::c_char],
+        input_ort_values: &[*const sys::OrtValue],
+    ) -> Vec<InputSummary> {
+        input_names_ptr
+            .iter()
+            .zip(input_ort_values)
+            .filter_map(|(&name_ptr, &value)| {
+                let index = self
+                    .input_names
+                    .iter()
+                    .position(|name| name.as_ptr() == name_ptr)?;
+                Some((&self.inputs[index], value))
+            })
+            .map(|(input, value)| {
+                let mut summary = InputSummary {
+                    name: input.name.clone(),
+                    element_type: None,
+                    shape: Vec::new(),
+                };
+                if let Ok(TypeInfo::Tensor(tensor)) = unsafe { TypeInfo::from_value(value) } {
+                    summary.element_type = tensor.element_type;
+                    summary.shape = tensor
+                        .dimensions
+                        .into_iter()
+                        .map(|d| d.map_or(-1, i64::from))
+                        .collect();
+                }
+                summary
+            })
+            .collect()
+    }
 
-impl Input {
-    /// Return an iterator over the shape elements of the input layer
+    /// Type of the elements of the output named `name`
     ///
-    /// Note: The member [`Input::dimensions`](struct.Input.html#structfield.dimensions)
-    /// stores `u32` (since ONNX uses `i64` but which cannot be negative) so the
-    /// iterator converts to `usize`.
-    pub fn dimensions(&self) -> impl Iterator<Item = Option<usize>> + '_ {
-        self.dimensions.iter().map(|d| d.map(|d2| d2 as usize))
+    /// Fails with [`OrtError::UnknownOutput`](../error/enum.OrtError.html#variant.UnknownOutput)
+    /// if the model has no such output.
+    pub fn output_dtype(&self, name: &str) -> Result<TensorElementDataType> {
+        self.output(name).map(|output| output.output_type)
     }
-}
 
-impl Output {
-    /// Return an iterator over the shape elements of the output layer
+    /// Number of dimensions of the output named `name`, or `None` if it is only known once the
+    /// model has run
     ///
-    /// Note: The member [`Output::dimensions`](struct.Output.html#structfield.dimensions)
-    /// stores `u32` (since ONNX uses `i64` but which cannot be negative) so the
-    /// iterator converts to `usize`.
-    pub fn dimensions(&self) -> impl Iterator<Item = Option<usize>> + '_ {
-        self.dimensions.iter().map(|d| d.map(|d2| d2 as usize))
+    /// See [`Output::rank()`](struct.Output.html#method.rank). Fails with
+    /// [`OrtError::UnknownOutput`](../error/enum.OrtError.html#variant.UnknownOutput) if the model
+    /// has no such output.
+    pub fn output_rank(&self, name: &str) -> Result<Option<usize>> {
+        self.output(name).map(Output::rank)
     }
-}
-
-unsafe impl<'a> Send for Session<'a> {}
-unsafe impl<'a> Sync for Session<'a> {}
 
-impl<'a> Drop for Session<'a> {
-    #[tracing::instrument]
-    fn drop(&mut self) {
-        debug!("Dropping the session.");
-        if self.session_ptr.is_null() {
-            error!("Session pointer is null, not dropping.");
-        } else {
-            unsafe { g_ort().ReleaseSession.unwrap()(self.session_ptr) };
+    /// Check that all outputs can be extracted as `TOut`, without running the model
+    ///
+    /// [`run()`](#method.run) only finds out about a type mismatch once the inference is done;
+    /// calling this once after loading the model catches it early. Half precision outputs can be
+    /// extracted as `f32` when the session was built with
+    /// [`with_half_precision_upcast()`](struct.SessionBuilder.html#method.with_half_precision_upcast).
+    ///
+    /// Fails with [`OrtError::NonMatchingTensorElementType`](../error/enum.OrtError.html#variant.NonMatchingTensorElementType)
+    /// for the first output that cannot.
+    pub fn check_output_types<TOut>(&self) -> Result<()>
+    where
+        TOut: TypeToTensorElementDataType,
+    {
+        let requested = TOut::tensor_element_data_type();
+        for output in &self.outputs {
+            let upcast = self.upcast_half_outputs
+                && requested == TensorElementDataType::Float
+                && matches!(
+                    output.output_type,
+                    TensorElementDataType::Float16 | TensorElementDataType::Bfloat16
+                );
+            if output.output_type != requested && !upcast {
+                return Err(OrtError::NonMatchingTensorElementType {
+                    requested: requested.into(),
+                    actual: output.output_type.into(),
+                });
+            }
         }
-        // FIXME: There is no C function to release the allocator?
+        Ok(())
+    }
 
-        self.session_ptr = std::ptr::null_mut();
-        self.allocator_ptr = std::ptr::null_mut();
+    fn output(&self, name: &str) -> Result<&Output> {
+        self.output_index(name).map(|index| &self.outputs[index])
     }
-}
 
-impl<'a> Session<'a> {
-    /// Run the input data through the ONNX graph, performing inference.
+    fn output_index(&self, name: &str) -> Result<usize> {
+        self.outputs
+            .iter()
+            .position(|output| output.name == name)
+            .ok_or_else(|| OrtError::UnknownOutput {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Content hash of the model, identifying it for caches and audit logs
     ///
-    /// Note that ONNX models can have multiple inputs; a `Vec<_>` is thus
-    /// used for the input data here.
-    pub fn run<'s, 't, 'm, TIn, TOut, D>(
-        &'s mut self,
-        input_arrays: Vec<Array<TIn, D>>,
-    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
-    where
-        TIn: TypeToTensorElementDataType + Debug + Clone,
-        TOut: TypeToTensorElementDataType + Debug + Clone,
-        D: ndarray::Dimension,
-        'm: 't, // 'm outlives 't (memory info outlives tensor)
-        's: 'm, // 's outlives 'm (session outlives memory info)
-    {
-        self.validate_input_shapes(&input_arrays)?;
+    /// Returns `None` unless the session was built with
+    /// [`SessionBuilder::with_model_hash()`](struct.SessionBuilder.html#method.with_model_hash).
+    pub fn model_hash(&self) -> Option<ModelHash> {
+        self.model_hash
+    }
 
-        // Build arguments to Run()
+    /// Everything the session was configured with, to reproduce it elsewhere
+    ///
+    /// See [`SessionConfig`](struct.SessionConfig.html).
+    pub fn effective_config(&self) -> SessionConfig {
+        self.config.clone()
+    }
 
-        let input_names_ptr: Vec<*const i8> = self
-            .inputs
-            .iter()
-            .map(|input| input.name.clone())
-            .map(|n| CString::new(n).unwrap())
-            .map(|n| n.into_raw() as *const i8)
-            .collect();
+    /// Names of the execution providers appended to the session, in priority order
+    ///
+    /// Nodes none of them supports run on the CPU, which is not listed. With an
+    /// [`ExecutionProviderChain`](../execution_providers/struct.ExecutionProviderChain.html),
+    /// these are the providers of the attempt which succeeded.
+    pub fn execution_providers(&self) -> &[&'static str] {
+        &self.execution_providers
+    }
 
-        let output_names_cstring: Vec<CString> = self
-            .outputs
-            .iter()
-            .map(|output| output.name.clone())
-            .map(|n| CString::new(n).unwrap())
-            .collect();
-        let output_names_ptr: Vec<*const i8> = output_names_cstring
-            .iter()
-            .map(|n| n.as_ptr() as *const i8)
-            .collect();
+    /// Wait times of runs held back by the session's concurrency limit
+    ///
+    /// Returns `None` unless the session was built with
+    /// [`SessionBuilder::with_max_concurrent_runs()`](struct.SessionBuilder.html#method.with_max_concurrent_runs)
+    /// or [`SessionBuilder::with_concurrency_limit()`](struct.SessionBuilder.html#method.with_concurrency_limit).
+    /// A limit shared with other sessions reports the runs of all of them.
+    pub fn concurrency_metrics(&self) -> Option<ConcurrencyMetrics> {
+        self.concurrency_limit
+            .as_ref()
+            .map(ConcurrencyLimit::metrics)
+    }
 
-        let mut output_tensor_extractors_ptrs: Vec<*mut sys::OrtValue> =
-            vec![std::ptr::null_mut(); self.outputs.len()];
+    /// Memory usage reported by the runtime so far
+    ///
+    /// Returns `None` unless the session was built with
+    /// [`SessionBuilder::with_memory_report()`](struct.SessionBuilder.html#method.with_memory_report).
+    pub fn memory_report(&self) -> Option<MemoryReport> {
+        self.log_capture
+            .as_ref()
+            .and_then(LogCapture::memory_messages)
+            .map(MemoryReport::from_messages)
+    }
 
-        // The C API expects pointers for the arrays (pointers to C-arrays)
-        let input_ort_tensors: Vec<OrtTensor<TIn, D>> = input_arrays
-            .into_iter()
-            .map(|input_array| {
-                OrtTensor::from_array(&self.memory_info, self.allocator_ptr, input_array)
-            })
-            .collect::<Result<Vec<OrtTensor<TIn, D>>>>()?;
-        let input_ort_values: Vec<*const sys::OrtValue> = input_ort_tensors
-            .iter()
-            .map(|input_array_ort| input_array_ort.c_ptr as *const sys::OrtValue)
-            .collect();
+    /// Warnings and errors logged by the runtime while creating the session
+    ///
+    /// For example "Some nodes were not assigned to the preferred execution providers", which
+    /// would otherwise only reach the logs. Messages below the environment's log level (see
+    /// [`EnvBuilder::with_log_level()`](../environment/struct.EnvBuilder.html#method.with_log_level))
+    /// are not seen, and neither are the ones logged by the environment rather than the session.
+    /// With an [`ExecutionProviderChain`](../execution_providers/struct.ExecutionProviderChain.html),
+    /// only the messages of the attempt that created the session are kept.
+    pub fn creation_warnings(&self) -> Vec<Warning> {
+        self.creation_warnings.clone()
+    }
 
-        let run_options_ptr: *const sys::OrtRunOptions = std::ptr::null();
+    /// Time at which profiling started, in nanoseconds since the Unix epoch
+    ///
+    /// Profiler events are timestamped relative to this instant, which allows aligning a
+    /// session's trace with other traces of the process.
+    pub fn profiling_start_time_ns(&self) -> Result<u64> {
+        let mut start_time_ns: u64 = 0;
+        let status = unsafe {
+            ort_api!(SessionGetProfilingStartTimeNs)?(self.session_ptr, &mut start_time_ns)
+        };
+        status_to_result(status).map_err(OrtError::ProfilingStartTime)?;
+        Ok(start_time_ns)
+    }
 
+    /// Stop profiling and write the trace, returning the path of the written file
+    ///
+    /// Use it to scope a trace to a phase of a long-lived process (for example warm-up),
+    /// instead of waiting for the session to be dropped. The C API cannot start profiling
+    /// again on the same session: to trace a later window, build a new session with
+    /// [`SessionBuilder::with_profiling()`](struct.SessionBuilder.html#method.with_profiling).
+    ///
+    /// Fails with [`OrtError::ProfilingNotEnabled`](../error/enum.OrtError.html#variant.ProfilingNotEnabled)
+    /// if the session was not profiling, including when profiling was already ended.
+    pub fn end_profiling(&mut self) -> Result<PathBuf> {
+        let mut profile_file_bytes: *mut std::os::raw::c_char = std::ptr::null_mut();
         let status = unsafe {
-            g_ort().Run.unwrap()(
+            ort_api!(SessionEndProfiling)?(
                 self.session_ptr,
-                run_options_ptr,
-                input_names_ptr.as_ptr(),
-                input_ort_values.as_ptr(),
-                input_ort_values.len(),
-                output_names_ptr.as_ptr(),
-                output_names_ptr.len(),
-                output_tensor_extractors_ptrs.as_mut_ptr(),
+                self.allocator_ptr,
+                &mut profile_file_bytes,
             )
         };
-        status_to_result(status).map_err(OrtError::Run)?;
+        status_to_result(status).map_err(OrtError::EndProfiling)?;
+        assert_not_null_pointer(profile_file_bytes, "ProfileFile")?;
 
-        let memory_info_ref = &self.memory_info;
-        let outputs: Result<Vec<OrtOwnedTensor<TOut, ndarray::Dim<ndarray::IxDynImpl>>>> =
-            output_tensor_extractors_ptrs
-                .into_iter()
-                .map(|ptr| {
-                    let mut tensor_info_ptr: *mut sys::OrtTensorTypeAndShapeInfo =
-                        std::ptr::null_mut();
-                    let status = unsafe {
-                        g_ort().GetTensorTypeAndShape.unwrap()(ptr, &mut tensor_info_ptr as _)
-                    };
-                    status_to_result(status).map_err(OrtError::GetTensorTypeAndShape)?;
-                    let dims = unsafe { get_tensor_dimensions(tensor_info_ptr) };
-                    unsafe { g_ort().ReleaseTensorTypeAndShapeInfo.unwrap()(tensor_info_ptr) };
-                    let dims: Vec<_> = dims?.iter().map(|&n| n as usize).collect();
-
-                    let mut output_tensor_extractor =
-                        OrtOwnedTensorExtractor::new(memory_info_ref, ndarray::IxDyn(&dims));
-                    output_tensor_extractor.tensor_ptr = ptr;
-                    output_tensor_extractor.extract::<TOut>()
-                })
-                .collect();
-
-        // Reconvert to CString so drop impl is called and memory is freed
-        let cstrings: Result<Vec<CString>> = input_names_ptr
-            .into_iter()
-            .map(|p| {
-                assert_not_null_pointer(p, "i8 for CString")?;
-                unsafe { Ok(CString::from_raw(p as *mut i8)) }
-            })
-            .collect();
-        cstrings?;
+        let profile_file = char_p_to_string(profile_file_bytes);
+        let status = unsafe {
+            ort_api!(AllocatorFree)?(
+                self.allocator_ptr,
+                profile_file_bytes as *mut std::ffi::c_void,
+            )
+        };
+        status_to_result(status).map_err(OrtError::Allocator)?;
+
+        // The runtime returns an empty name when the profiler is disabled
+        match profile_file? {
+            profile_file if profile_file.is_empty() => Err(OrtError::ProfilingNotEnabled),
+            profile_file => Ok(PathBuf::from(profile_file)),
+        }
+    }
 
-        outputs
+    /// Stop profiling, emit the trace's per operator type timings as `tracing` events and delete it
+    ///
+    /// See [`SessionBuilder::with_profiling_summary()`](struct.SessionBuilder.html#method.with_profiling_summary);
+    /// this also works for sessions built with
+    /// [`SessionBuilder::with_profiling()`](struct.SessionBuilder.html#method.with_profiling).
+    #[cfg(feature = "profiling")]
+    pub fn end_profiling_summary(&mut self) -> Result<crate::profiling::ProfileSummary> {
+        let profile_file = self.end_profiling()?;
+        let summary = crate::profiling::ProfileSummary::from_file(&profile_file);
+        if let Err(err) = std::fs::remove_file(&profile_file) {
+            warn!(
+                "Failed to delete profiling trace {:?}: {}",
+                profile_file, err
+            );
+        }
+        let summary = summary?;
+        summary.emit();
+        Ok(summary)
     }
 
     // pub fn tensor_from_array<'a, 'b, T, D>(&'a self, array: Array<T, D>) -> Tensor<'b, T, D>
@@ -501,69 +2947,78 @@ impl<'a> Session<'a> {
     //     Tensor::from_array(self, array)
     // }
 
-    fn validate_input_shapes<TIn, D>(&mut self, input_arrays: &[Array<TIn, D>]) -> Result<()>
+    fn validate_input_shapes<'i, I>(&self, input_shapes: I) -> Result<()>
     where
-        TIn: TypeToTensorElementDataType + Debug + Clone,
-        D: ndarray::Dimension,
+        I: Iterator<Item = &'i [usize]> + Clone,
+    {
+        self.validate_optional_input_shapes(input_shapes.map(Some))
+    }
+
+    /// Same as [`validate_input_shapes()`](#method.validate_input_shapes), with omitted inputs
+    /// (`None`) left unchecked
+    fn validate_optional_input_shapes<'i, I>(&self, input_shapes: I) -> Result<()>
+    where
+        I: Iterator<Item = Option<&'i [usize]>> + Clone,
     {
         // ******************************************************************
         // FIXME: Properly handle errors here
         // Make sure all dimensions match (except dynamic ones)
 
+        let inference_input = || -> Vec<Vec<usize>> {
+            input_shapes
+                .clone()
+                .map(|shape| shape.map_or_else(Vec::new, <[usize]>::to_vec))
+                .collect()
+        };
+        let model_input = || -> Vec<Vec<Option<u32>>> {
+            self.inputs
+                .iter()
+                .map(|input| input.dimensions.clone())
+                .collect()
+        };
+
         // Verify length of inputs
-        if input_arrays.len() != self.inputs.len() {
+        let inference_input_count = input_shapes.clone().count();
+        if inference_input_count != self.inputs.len() {
             error!(
                 "Non-matching number of inputs: {} (inference) vs {} (model)",
-                input_arrays.len(),
+                inference_input_count,
                 self.inputs.len()
             );
             return Err(OrtError::NonMatchingDimensions(
                 NonMatchingDimensionsError::InputsCount {
                     inference_input_count: 0,
                     model_input_count: 0,
-                    inference_input: input_arrays
-                        .iter()
-                        .map(|input_array| input_array.shape().to_vec())
-                        .collect(),
-                    model_input: self
-                        .inputs
-                        .iter()
-                        .map(|input| input.dimensions.clone())
-                        .collect(),
+                    inference_input: inference_input(),
+                    model_input: model_input(),
                 },
             ));
         }
 
         // Verify length of each individual inputs
-        let inputs_different_length = input_arrays
-            .iter()
+        let inputs_different_length = input_shapes
+            .clone()
             .zip(self.inputs.iter())
-            .any(|(l, r)| l.shape().len() != r.dimensions.len());
+            .any(|(l, r)| matches!(l, Some(l) if l.len() != r.dimensions.len()));
         if inputs_different_length {
             error!(
                 "Different input lengths: {:?} vs {:?}",
-                self.inputs, input_arrays
+                self.inputs,
+                inference_input()
             );
             return Err(OrtError::NonMatchingDimensions(
                 NonMatchingDimensionsError::InputsLength {
-                    inference_input: input_arrays
-                        .iter()
-                        .map(|input_array| input_array.shape().to_vec())
-                        .collect(),
-                    model_input: self
-                        .inputs
-                        .iter()
-                        .map(|input| input.dimensions.clone())
-                        .collect(),
+                    inference_input: inference_input(),
+                    model_input: model_input(),
                 },
             ));
         }
 
         // Verify shape of each individual inputs
-        let inputs_different_shape = input_arrays.iter().zip(self.inputs.iter()).any(|(l, r)| {
-            let l_shape = l.shape();
+        let inputs_different_shape = input_shapes.clone().zip(self.inputs.iter()).any(|(l, r)| {
+            let l = l.unwrap_or(&[]);
             let r_shape = r.dimensions.as_slice();
-            l_shape.iter().zip(r_shape.iter()).any(|(l2, r2)| match r2 {
+            l.iter().zip(r_shape.iter()).any(|(l2, r2)| match r2 {
                 Some(r3) => *r3 as usize != *l2,
                 None => false, // None means dynamic size; in that case shape always match
             })
@@ -571,45 +3026,268 @@ impl<'a> Session<'a> {
         if inputs_different_shape {
             error!(
                 "Different input lengths: {:?} vs {:?}",
-                self.inputs, input_arrays
+                self.inputs,
+                inference_input()
             );
             return Err(OrtError::NonMatchingDimensions(
                 NonMatchingDimensionsError::InputsLength {
-                    inference_input: input_arrays
-                        .iter()
-                        .map(|input_array| input_array.shape().to_vec())
-                        .collect(),
-                    model_input: self
-                        .inputs
-                        .iter()
-                        .map(|input| input.dimensions.clone())
-                        .collect(),
+                    inference_input: inference_input(),
+                    model_input: model_input(),
                 },
             ));
         }
 
+        if let Some(max_batch_size) = self.max_batch_size {
+            if let Some(batch_size) = input_shapes
+                .clone()
+                .filter_map(|shape| shape?.first().copied())
+                .find(|batch_size| *batch_size > max_batch_size)
+            {
+                return Err(OrtError::NonMatchingDimensions(
+                    NonMatchingDimensionsError::BatchTooLarge {
+                        batch_size,
+                        max_batch_size,
+                    },
+                ));
+            }
+        }
+
+        if let Some(budget) = &self.gpu_memory_budget {
+            budget.check(&self.inputs, input_shapes)?;
+        }
+
         Ok(())
     }
 }
 
-unsafe fn get_tensor_dimensions(
+/// Copy an output to an owned array, on the given NUMA node if any
+#[cfg(feature = "ndarray")]
+fn copy_output<TOut>(
+    output: &OrtOwnedTensor<TOut, ndarray::IxDyn>,
+    numa_node: Option<u32>,
+) -> ArrayD<TOut>
+where
+    TOut: TypeToTensorElementDataType + Debug + Clone,
+{
+    let view = output.view();
+    let data = match view.as_slice() {
+        Some(data) => crate::numa::copy_to_node(data, numa_node),
+        None => {
+            let mut data = crate::numa::vec_on_node(view.len(), numa_node);
+            data.extend(view.iter().cloned());
+            data
+        }
+    };
+    ArrayD::from_shape_vec(view.raw_dim(), data).expect("Shape matches the number of elements")
+}
+
+/// Something running inference, returning outputs copied out of the runtime
+///
+/// Implemented by [`Session`](struct.Session.html) and
+/// [`SwappableSession`](struct.SwappableSession.html), and by
+/// [`MockSession`](struct.MockSession.html) so that code generic over `Run` can be unit-tested
+/// without model files or the runtime. Runs take `&self`, so that shared sessions (for example
+/// an `Arc<Session>`) can be used through the trait; implementations keeping mutable state use
+/// interior mutability.
+///
+/// # Example
+///
+/// ```
+/// # use onnxruntime::session::{MockOutput, MockSession, Run};
+/// fn score<S: Run>(session: &S, features: ndarray::Array2<f32>) -> onnxruntime::Result<f32> {
+///     let outputs = session.run::<_, f32>(vec![features])?;
+///     Ok(outputs[0].sum())
+/// }
+///
+/// let session = MockSession::with_outputs(vec![MockOutput::new(ndarray::arr1(&[0.25_f32, 0.5]))]);
+/// assert_eq!(score(&session, ndarray::Array2::zeros((1, 4))).unwrap(), 0.75);
+/// ```
+#[cfg(feature = "ndarray")]
+pub trait Run {
+    /// Run the inputs through the model
+    ///
+    /// See [`Session::run()`](struct.Session.html#method.run); the outputs are owned arrays
+    /// rather than tensors borrowing from the session.
+    fn run<I, TOut>(&self, inputs: I) -> Result<Vec<ArrayD<TOut>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        <I::Item as IntoInput>::Elem: 'static,
+        TOut: TypeToTensorElementDataType + Debug + Clone + 'static;
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a> Run for Session<'a> {
+    fn run<I, TOut>(&self, inputs: I) -> Result<Vec<ArrayD<TOut>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        <I::Item as IntoInput>::Elem: 'static,
+        TOut: TypeToTensorElementDataType + Debug + Clone + 'static,
+    {
+        let inputs: RunInputs<_> = inputs.into_iter().collect();
+        let input_ort_tensors = self.prepare_inputs(&inputs)?;
+        let outputs: Vec<OrtOwnedTensor<TOut, _>> =
+            self.run_ort_values(None, input_ort_tensors.iter().map(|tensor| tensor.c_ptr()))?;
+        Ok(self.copy_outputs(&outputs))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a> Run for SwappableSession<'a> {
+    fn run<I, TOut>(&self, inputs: I) -> Result<Vec<ArrayD<TOut>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        <I::Item as IntoInput>::Elem: 'static,
+        TOut: TypeToTensorElementDataType + Debug + Clone + 'static,
+    {
+        SwappableSession::run(self, inputs)
+    }
+}
+
+/// Object-safe interface of something running inference, for code holding runners as trait objects
+///
+/// Unlike [`Run`](trait.Run.html), the element types of the inputs (`TIn`) and outputs (`TOut`)
+/// are fixed by the trait, and runs take `&self` so that a runner can be shared, for example as an
+/// `Arc<dyn InferenceRunner<f32>>` handed to request handlers.
+///
+/// Implemented by [`Session`](struct.Session.html) (the runtime supports concurrent runs of a
+/// session), [`SwappableSession`](struct.SwappableSession.html),
+/// [`AbRouter`](struct.AbRouter.html) and [`MockSession`](struct.MockSession.html).
+///
+/// # Example
+///
+/// ```
+/// # use onnxruntime::session::{InferenceRunner, MockOutput, MockSession};
+/// fn classify(runner: &dyn InferenceRunner<f32>, image: ndarray::ArrayD<f32>) -> onnxruntime::Result<usize> {
+///     let outputs = runner.run_dyn(vec![image])?;
+///     let (class, _) = outputs[0]
+///         .iter()
+///         .enumerate()
+///         .fold((0, f32::MIN), |best, (class, &score)| if score > best.1 { (class, score) } else { best });
+///     Ok(class)
+/// }
+///
+/// let runner = MockSession::with_outputs(vec![MockOutput::new(ndarray::arr1(&[0.1_f32, 0.7, 0.2]))]);
+/// assert_eq!(classify(&runner, ndarray::ArrayD::zeros(vec![1, 3, 224, 224])).unwrap(), 1);
+/// ```
+#[cfg(feature = "ndarray")]
+pub trait InferenceRunner<TIn, TOut = TIn> {
+    /// Run the inputs through the model, matched to the model's inputs by position
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>>;
+}
+
+#[cfg(feature = "ndarray")]
+impl<R, TIn, TOut> InferenceRunner<TIn, TOut> for &R
+where
+    R: InferenceRunner<TIn, TOut> + ?Sized,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        (**self).run_dyn(inputs)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<R, TIn, TOut> InferenceRunner<TIn, TOut> for Box<R>
+where
+    R: InferenceRunner<TIn, TOut> + ?Sized,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        (**self).run_dyn(inputs)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<R, TIn, TOut> InferenceRunner<TIn, TOut> for std::sync::Arc<R>
+where
+    R: InferenceRunner<TIn, TOut> + ?Sized,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        (**self).run_dyn(inputs)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a, TIn, TOut> InferenceRunner<TIn, TOut> for Session<'a>
+where
+    TIn: TypeToTensorElementDataType + Debug + Clone,
+    TOut: TypeToTensorElementDataType + Debug + Clone,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        let input_ort_tensors = self.prepare_inputs(&inputs)?;
+        let outputs: Vec<OrtOwnedTensor<TOut, _>> =
+            self.run_ort_values(None, input_ort_tensors.iter().map(|tensor| tensor.c_ptr()))?;
+        Ok(self.copy_outputs(&outputs))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a, TIn, TOut> InferenceRunner<TIn, TOut> for SwappableSession<'a>
+where
+    TIn: TypeToTensorElementDataType + Debug + Clone,
+    TOut: TypeToTensorElementDataType + Debug + Clone,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        self.run(inputs)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a, TIn, TOut> InferenceRunner<TIn, TOut> for AbRouter<'a>
+where
+    TIn: TypeToTensorElementDataType + Debug + Clone,
+    TOut: NumericElement,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        self.run(inputs).map(|(_, outputs)| outputs)
+    }
+}
+
+/// Convert a path to the null terminated string type used by the C API for paths
+fn path_to_ort_string(path: &Path) -> Vec<OrtChar> {
+    // Build an OsString than a vector of bytes to pass to C
+    let path = std::ffi::OsString::from(path);
+    #[cfg(target_family = "windows")]
+    let path: Vec<u16> = path
+        .encode_wide()
+        .chain(std::iter::once(0)) // Make sure we have a null terminated string
+        .collect();
+    #[cfg(not(target_family = "windows"))]
+    let path: Vec<std::os::raw::c_char> = path
+        .as_bytes()
+        .iter()
+        .chain(std::iter::once(&b'\0')) // Make sure we have a null terminated string
+        .map(|b| *b as std::os::raw::c_char)
+        .collect();
+    path
+}
+
+#[cfg(target_family = "windows")]
+type OrtChar = u16;
+#[cfg(not(target_family = "windows"))]
+type OrtChar = std::os::raw::c_char;
+
+/// Read the dimensions of a tensor into `node_dims`, reusing its allocation
+///
+/// Scalars have no dimensions.
+pub(crate) unsafe fn get_tensor_dimensions_into(
     tensor_info_ptr: *const sys::OrtTensorTypeAndShapeInfo,
-) -> Result<Vec<i64>> {
+    node_dims: &mut Vec<i64>,
+) -> Result<()> {
     let mut num_dims = 0;
-    let status = g_ort().GetDimensionsCount.unwrap()(tensor_info_ptr, &mut num_dims);
+    let status = ort_api!(GetDimensionsCount)?(tensor_info_ptr, &mut num_dims);
     status_to_result(status).map_err(OrtError::GetDimensionsCount)?;
-    (num_dims != 0)
-        .then(|| ())
-        .ok_or(OrtError::InvalidDimensions)?;
 
-    let mut node_dims: Vec<i64> = vec![0; num_dims as usize];
-    let status = g_ort().GetDimensions.unwrap()(
+    node_dims.clear();
+    node_dims.resize(num_dims as usize, 0);
+    let status = ort_api!(GetDimensions)?(
         tensor_info_ptr,
         node_dims.as_mut_ptr(), // FIXME: UB?
         num_dims,
     );
     status_to_result(status).map_err(OrtError::GetDimensions)?;
-    Ok(node_dims)
+    Ok(())
 }
 
 /// This module contains dangerous functions working on raw pointers.
@@ -618,14 +3296,20 @@ unsafe fn get_tensor_dimensions(
 mod dangerous {
     use super::*;
 
+    /// Number of inputs of the model, which may be zero for generator-style graphs computing
+    /// their outputs from initializers only
     pub(super) fn extract_inputs_count(session_ptr: *mut sys::OrtSession) -> Result<usize> {
-        let f = g_ort().SessionGetInputCount.unwrap();
+        let f = ort_api!(SessionGetInputCount)?;
         extract_io_count(f, session_ptr)
     }
 
     pub(super) fn extract_outputs_count(session_ptr: *mut sys::OrtSession) -> Result<usize> {
-        let f = g_ort().SessionGetOutputCount.unwrap();
-        extract_io_count(f, session_ptr)
+        let f = ort_api!(SessionGetOutputCount)?;
+        let num_nodes = extract_io_count(f, session_ptr)?;
+        (num_nodes != 0).then(|| ()).ok_or_else(|| {
+            OrtError::InOutCount(OrtApiError::Msg("No outputs in model".to_owned()))
+        })?;
+        Ok(num_nodes)
     }
 
     fn extract_io_count(
@@ -636,9 +3320,6 @@ mod dangerous {
         let status = unsafe { f(session_ptr, &mut num_nodes) };
         status_to_result(status).map_err(OrtError::InOutCount)?;
         assert_null_pointer(status, "SessionStatus")?;
-        (num_nodes != 0).then(|| ()).ok_or_else(|| {
-            OrtError::InOutCount(OrtApiError::Msg("No nodes in model".to_owned()))
-        })?;
         Ok(num_nodes)
     }
 
@@ -647,7 +3328,7 @@ mod dangerous {
         allocator_ptr: *mut sys::OrtAllocator,
         i: usize,
     ) -> Result<String> {
-        let f = g_ort().SessionGetInputName.unwrap();
+        let f = ort_api!(SessionGetInputName)?;
         extract_io_name(f, session_ptr, allocator_ptr, i)
     }
 
@@ -656,7 +3337,7 @@ mod dangerous {
         allocator_ptr: *mut sys::OrtAllocator,
         i: usize,
     ) -> Result<String> {
-        let f = g_ort().SessionGetOutputName.unwrap();
+        let f = ort_api!(SessionGetOutputName)?;
         extract_io_name(f, session_ptr, allocator_ptr, i)
     }
 
@@ -689,12 +3370,22 @@ mod dangerous {
         i: usize,
     ) -> Result<Input> {
         let input_name = extract_input_name(session_ptr, allocator_ptr, i)?;
-        let f = g_ort().SessionGetInputTypeInfo.unwrap();
-        let (input_type, dimensions) = extract_io(f, session_ptr, i)?;
+        let f = ort_api!(SessionGetInputTypeInfo)?;
+        let type_info = unsafe { TypeInfo::from_session(f, session_ptr, i)? };
+        if let TypeInfo::Sequence(_) | TypeInfo::Map { .. } = type_info {
+            return Ok(Input {
+                name: input_name,
+                input_type: innermost_element_type(&type_info)?,
+                dimensions: Vec::new(),
+                container: Some(type_info),
+            });
+        }
+        let (input_type, dimensions) = extract_tensor_io(type_info, false)?;
         Ok(Input {
             name: input_name,
             input_type,
             dimensions,
+            container: None,
         })
     }
 
@@ -704,63 +3395,60 @@ mod dangerous {
         i: usize,
     ) -> Result<Output> {
         let output_name = extract_output_name(session_ptr, allocator_ptr, i)?;
-        let f = g_ort().SessionGetOutputTypeInfo.unwrap();
-        let (output_type, dimensions) = extract_io(f, session_ptr, i)?;
+        let f = ort_api!(SessionGetOutputTypeInfo)?;
+        let type_info = unsafe { TypeInfo::from_session(f, session_ptr, i)? };
+        let dynamic_rank =
+            matches!(type_info.tensor(), Some(tensor) if tensor.dimensions.is_empty());
+        if dynamic_rank {
+            debug!(
+                output = output_name.as_str(),
+                "Output rank only known once the model has run."
+            );
+        }
+        let (output_type, dimensions) = extract_tensor_io(type_info, dynamic_rank)?;
         Ok(Output {
             name: output_name,
             output_type,
             dimensions,
+            dynamic_rank,
         })
     }
 
-    fn extract_io(
-        f: extern_system_fn! { unsafe fn(
-            *const sys::OrtSession,
-            usize,
-            *mut *mut sys::OrtTypeInfo,
-        ) -> *mut sys::OrtStatus },
-        session_ptr: *mut sys::OrtSession,
-        i: usize,
-    ) -> Result<(TensorElementDataType, Vec<Option<u32>>)> {
-        let mut typeinfo_ptr: *mut sys::OrtTypeInfo = std::ptr::null_mut();
-
-        let status = unsafe { f(session_ptr, i, &mut typeinfo_ptr) };
-        status_to_result(status).map_err(OrtError::GetTypeInfo)?;
-        assert_not_null_pointer(typeinfo_ptr, "TypeInfo")?;
+    /// Element type of the innermost tensors of a sequence, or of the values of a map
+    fn innermost_element_type(type_info: &TypeInfo) -> Result<TensorElementDataType> {
+        match type_info {
+            TypeInfo::Tensor(tensor) => tensor
+                .element_type
+                .ok_or(OrtError::UndefinedTensorElementType),
+            TypeInfo::Sequence(element) | TypeInfo::Map { value: element, .. } => {
+                innermost_element_type(element)
+            }
+            other => Err(OrtError::CastTypeInfoToTensorInfo(OrtApiError::Msg(
+                format!("Unsupported type {}", other),
+            ))),
+        }
+    }
 
-        let mut tensor_info_ptr: *const sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
-        let status = unsafe {
-            g_ort().CastTypeInfoToTensorInfo.unwrap()(typeinfo_ptr, &mut tensor_info_ptr)
+    /// Inputs are supported as tensors of known rank, sequences and maps; outputs only as tensors,
+    /// of unknown rank with `dynamic_rank`
+    fn extract_tensor_io(
+        type_info: TypeInfo,
+        dynamic_rank: bool,
+    ) -> Result<(TensorElementDataType, Vec<Option<u32>>)> {
+        let tensor = match type_info {
+            TypeInfo::Tensor(tensor) => tensor,
+            other => {
+                return Err(OrtError::CastTypeInfoToTensorInfo(OrtApiError::Msg(
+                    format!("Unsupported type {}", other),
+                )))
+            }
         };
-        status_to_result(status).map_err(OrtError::CastTypeInfoToTensorInfo)?;
-        assert_not_null_pointer(tensor_info_ptr, "TensorInfo")?;
-
-        let mut type_sys = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
-        let status =
-            unsafe { g_ort().GetTensorElementType.unwrap()(tensor_info_ptr, &mut type_sys) };
-        status_to_result(status).map_err(OrtError::TensorElementType)?;
-        (type_sys != sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED)
-            .then(|| ())
+        let io_type = tensor
+            .element_type
             .ok_or(OrtError::UndefinedTensorElementType)?;
-        // This transmute should be safe since its value is read from GetTensorElementType which we must trust.
-        let io_type: TensorElementDataType = unsafe { std::mem::transmute(type_sys) };
-
-        // info!("{} : type={}", i, type_);
-
-        let node_dims = unsafe { get_tensor_dimensions(tensor_info_ptr)? };
-
-        // for j in 0..num_dims {
-        //     info!("{} : dim {}={}", i, j, node_dims[j as usize]);
-        // }
-
-        unsafe { g_ort().ReleaseTypeInfo.unwrap()(typeinfo_ptr) };
-
-        Ok((
-            io_type,
-            node_dims
-                .into_iter()
-                .map(|d| if d == -1 { None } else { Some(d as u32) })
-                .collect(),
-        ))
+        (dynamic_rank || !tensor.dimensions.is_empty())
+            .then(|| ())
+            .ok_or(OrtError::InvalidDimensions)?;
+        Ok((io_type, tensor.dimensions))
     }
 }
@@ -0,0 +1,153 @@
+//! Module containing the splitting of inputs into batches a model accepts
+
+use ndarray::{ArrayD, ArrayView, Axis, RemoveAxis, Slice};
+
+use crate::error::{NonMatchingDimensionsError, OrtError, Result};
+
+/// Run `inputs` in batches of `batch_size` items along their first axis with `run`, concatenating
+/// the outputs of the batches
+///
+/// With `pad`, a last batch smaller than `batch_size` is padded to it by repeating its last item,
+/// and the outputs of the padding are dropped. Inputs of no items run once, as they are.
+pub(super) fn run_in_batches<TIn, D, TOut, F>(
+    inputs: &[ArrayView<'_, TIn, D>],
+    batch_size: usize,
+    pad: bool,
+    mut run: F,
+) -> Result<Vec<ArrayD<TOut>>>
+where
+    TIn: Clone,
+    D: RemoveAxis,
+    TOut: Clone,
+    F: FnMut(&[ArrayView<'_, TIn, D>]) -> Result<Vec<ArrayD<TOut>>>,
+{
+    let item_counts = inputs
+        .iter()
+        .map(|input| input.shape().first().copied())
+        .collect::<Option<Vec<usize>>>()
+        .ok_or(OrtError::InvalidDimensions)?;
+    let item_count = item_counts.first().copied().unwrap_or(0);
+    if item_counts.iter().any(|&count| count != item_count) {
+        return Err(OrtError::NonMatchingDimensions(
+            NonMatchingDimensionsError::BatchSizes {
+                batch_sizes: item_counts,
+            },
+        ));
+    }
+    let batch_size = batch_size.max(1);
+
+    let mut batches: Vec<Vec<ArrayD<TOut>>> = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + batch_size).min(item_count);
+        let batch: Vec<ArrayView<TIn, D>> = inputs
+            .iter()
+            .map(|input| input.slice_axis(Axis(0), Slice::from(start..end)))
+            .collect();
+        let padding = if pad && end > start {
+            batch_size - (end - start)
+        } else {
+            0
+        };
+
+        let outputs = if padding == 0 {
+            run(&batch)?
+        } else {
+            let padded = batch
+                .iter()
+                .map(|items| {
+                    let last = items.slice_axis(Axis(0), Slice::from(end - start - 1..));
+                    let mut views = vec![items.view()];
+                    views.extend((0..padding).map(|_| last.view()));
+                    ndarray::concatenate(Axis(0), &views).map_err(|_| OrtError::InvalidDimensions)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let padded: Vec<ArrayView<TIn, D>> = padded.iter().map(|items| items.view()).collect();
+            let mut outputs = run(&padded)?;
+            for output in &mut outputs {
+                let len = output.shape().first().ok_or(OrtError::InvalidDimensions)?;
+                let len = len.saturating_sub(padding);
+                output.slice_axis_inplace(Axis(0), Slice::from(..len));
+            }
+            outputs
+        };
+        batches.push(outputs);
+
+        start = end;
+        if start >= item_count {
+            break;
+        }
+    }
+
+    if batches.len() == 1 {
+        return Ok(batches.pop().expect("One batch"));
+    }
+    let output_count = batches[0].len();
+    (0..output_count)
+        .map(|index| {
+            let views = batches
+                .iter()
+                .map(|outputs| outputs.get(index).map(ArrayD::view))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(OrtError::InvalidDimensions)?;
+            ndarray::concatenate(Axis(0), &views).map_err(|_| OrtError::InvalidDimensions)
+        })
+        .collect()
+}
+
+/// Batch size of the model's first input, if fixed
+pub(super) fn fixed_batch_size(dimensions: &[Option<u32>]) -> Option<usize> {
+    match dimensions.first() {
+        Some(Some(size)) if *size > 0 => Some(*size as usize),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    /// Model doubling its input, recording the batch sizes it is run on
+    fn double<'b>(
+        batch_sizes: &'b mut Vec<usize>,
+    ) -> impl FnMut(&[ArrayView<'_, f32, ndarray::Ix2>]) -> Result<Vec<ArrayD<f32>>> + 'b {
+        move |inputs| {
+            batch_sizes.push(inputs[0].len_of(Axis(0)));
+            Ok(vec![inputs[0].mapv(|x| x * 2.0).into_dyn()])
+        }
+    }
+
+    #[test]
+    fn inputs_are_split_and_outputs_concatenated() {
+        let input = ndarray::arr2(&[[1.0_f32], [2.0], [3.0], [4.0], [5.0]]);
+        let expected = ndarray::arr2(&[[2.0_f32], [4.0], [6.0], [8.0], [10.0]]).into_dyn();
+
+        let mut batch_sizes = Vec::new();
+        let outputs = run_in_batches(&[input.view()], 2, false, double(&mut batch_sizes)).unwrap();
+        assert_eq!(outputs, vec![expected.clone()]);
+        assert_eq!(batch_sizes, vec![2, 2, 1]);
+
+        // Fixed batch size: the last batch is padded, and its padding dropped
+        let mut batch_sizes = Vec::new();
+        let outputs = run_in_batches(&[input.view()], 2, true, double(&mut batch_sizes)).unwrap();
+        assert_eq!(outputs, vec![expected]);
+        assert_eq!(batch_sizes, vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn inputs_must_have_the_same_batch_size() {
+        let inputs = [
+            ndarray::Array2::<f32>::zeros((2, 1)),
+            ndarray::Array2::<f32>::zeros((3, 1)),
+        ];
+        let views: Vec<_> = inputs.iter().map(|input| input.view()).collect();
+        let result = run_in_batches(&views, 1, false, double(&mut Vec::new()));
+        assert!(matches!(
+            result,
+            Err(OrtError::NonMatchingDimensions(
+                NonMatchingDimensionsError::BatchSizes { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! Module containing helpers running inference from asynchronous code (requires the `tokio` feature)
+
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use ndarray::ArrayD;
+use tokio::{runtime::Handle, task::JoinHandle};
+
+use crate::{
+    error::{OrtError, Result},
+    TypeToTensorElementDataType,
+};
+
+use super::{InferenceRunner, Run};
+
+/// Run `inputs` through `session` on the blocking thread pool of the current Tokio runtime
+///
+/// Inference is blocking and CPU bound: running it on an asynchronous task stalls the other
+/// tasks of its worker thread. The inputs are moved to the blocking thread and the outputs,
+/// owned arrays, moved back, so nothing borrowed crosses threads. Concurrent calls on the same
+/// session wait for each other.
+///
+/// Panics if called outside of a Tokio runtime, and resumes the panic of the run if it panicked.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::{error::Error, sync::{Arc, Mutex}};
+/// # use onnxruntime::{environment::Environment, session::spawn_run};
+/// # async fn infer(session: Arc<Mutex<onnxruntime::session::Session<'static>>>) -> Result<(), Box<dyn Error>> {
+/// let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224)).into_dyn();
+/// let outputs = spawn_run::<_, _, f32>(&session, vec![input]).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn spawn_run<S, TIn, TOut>(
+    session: &Arc<Mutex<S>>,
+    inputs: Vec<ArrayD<TIn>>,
+) -> Result<Vec<ArrayD<TOut>>>
+where
+    S: Run + Send + 'static,
+    TIn: TypeToTensorElementDataType + Debug + Clone + Send + 'static,
+    TOut: TypeToTensorElementDataType + Debug + Clone + Send + 'static,
+{
+    run_blocking_on(&Handle::current(), session, inputs).await
+}
+
+/// Same as [`spawn_run()`](fn.spawn_run.html), on the blocking thread pool of the runtime of
+/// `handle`
+///
+/// Useful when the application runs inference on a runtime of its own, apart from the one
+/// serving requests.
+pub async fn run_blocking_on<S, TIn, TOut>(
+    handle: &Handle,
+    session: &Arc<Mutex<S>>,
+    inputs: Vec<ArrayD<TIn>>,
+) -> Result<Vec<ArrayD<TOut>>>
+where
+    S: Run + Send + 'static,
+    TIn: TypeToTensorElementDataType + Debug + Clone + Send + 'static,
+    TOut: TypeToTensorElementDataType + Debug + Clone + Send + 'static,
+{
+    let session = Arc::clone(session);
+    join(handle.spawn_blocking(move || {
+        session
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .run(inputs)
+    }))
+    .await
+}
+
+/// Run `inputs` through a shared `runner`, such as an `Arc<Session>`, on the blocking thread pool
+/// of the current Tokio runtime
+///
+/// Unlike [`spawn_run()`](fn.spawn_run.html), concurrent calls on the same runner run at the same
+/// time, sessions running through a shared reference. The executor's threads never block: only
+/// the blocking thread running the model waits for it. (The C API's own `RunAsync()`, which calls
+/// back from the runtime's thread pool, is not available in ONNX Runtime 1.15.)
+///
+/// Panics if called outside of a Tokio runtime, and resumes the panic of the run if it panicked.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::{error::Error, sync::Arc};
+/// # use onnxruntime::session::{run_async, Session};
+/// # async fn infer(session: Arc<Session<'static>>) -> Result<(), Box<dyn Error>> {
+/// let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224)).into_dyn();
+/// let outputs = run_async::<_, _, f32>(&session, vec![input]).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn run_async<R, TIn, TOut>(
+    runner: &Arc<R>,
+    inputs: Vec<ArrayD<TIn>>,
+) -> Result<Vec<ArrayD<TOut>>>
+where
+    R: InferenceRunner<TIn, TOut> + Send + Sync + 'static,
+    TIn: Send + 'static,
+    TOut: Send + 'static,
+{
+    let runner = Arc::clone(runner);
+    join(Handle::current().spawn_blocking(move || runner.run_dyn(inputs))).await
+}
+
+/// Result of the blocking `task`
+async fn join<T>(task: JoinHandle<Result<T>>) -> Result<T> {
+    match task.await {
+        Ok(result) => result,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(_) => Err(OrtError::BlockingTaskCancelled),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{MockOutput, MockSession};
+    use test_log::test;
+
+    #[test]
+    fn spawn_run_on_blocking_pool() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let session = Arc::new(Mutex::new(MockSession::with_outputs(vec![
+            MockOutput::new(ndarray::arr1(&[1.0_f32, 2.0])),
+        ])));
+
+        let input = ndarray::Array2::<f32>::zeros((1, 4)).into_dyn();
+        let outputs = runtime
+            .block_on(spawn_run::<_, _, f32>(&session, vec![input]))
+            .unwrap();
+        assert_eq!(outputs[0], ndarray::arr1(&[1.0_f32, 2.0]).into_dyn());
+    }
+
+    #[test]
+    fn run_async_on_shared_runner() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let session = Arc::new(MockSession::with_outputs(vec![MockOutput::new(
+            ndarray::arr1(&[3.0_f32]),
+        )]));
+
+        let input = ndarray::Array2::<f32>::zeros((1, 4)).into_dyn();
+        let outputs: Vec<ArrayD<f32>> = runtime.block_on(run_async(&session, vec![input])).unwrap();
+        assert_eq!(outputs[0], ndarray::arr1(&[3.0_f32]).into_dyn());
+        assert_eq!(session.runs(), 1);
+    }
+}
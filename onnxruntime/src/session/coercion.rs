@@ -0,0 +1,426 @@
+//! Module containing conversions of inference inputs to the element type expected by the model
+
+use std::{convert::TryFrom, fmt::Debug};
+
+use ndarray::{Array, ArrayBase, ArrayView};
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::InputCoercionError,
+    memory::MemoryInfo,
+    tensor::{ort_tensor::OrtTensorRef, DynOrtValue, OrtMap, OrtSequence, OrtTensor},
+    Result, TensorElementDataType, TypeToTensorElementDataType,
+};
+
+use super::{pinned::StagedInput, Input};
+
+/// Conversion applied to an input whose element type differs from the model's
+///
+/// Configured per input with
+/// [`SessionBuilder::with_input_coercion()`](struct.SessionBuilder.html#method.with_input_coercion);
+/// typical uses are feeding `i32` token ids to a model expecting `i64`, or `f64` features to a
+/// model expecting `f32`. Only numeric types can be converted: strings and half precision
+/// types are not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputCoercion {
+    /// Convert only if every value is represented exactly in the model's type, fail otherwise
+    Lossless,
+    /// Always convert: floats are rounded to the nearest representable value (or infinity), floats
+    /// converted to integers are truncated and out of range values saturate
+    AllowLossy,
+}
+
+/// Input tensor handed to the runtime, either built from the caller's array as is, converted to
+/// the model's element type or copied to pinned memory
+pub(super) enum InputTensor<'t, T, D>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    Direct(OrtTensor<'t, T, D>),
+    /// Pointing to the caller's array, already in the standard layout, with its dimensions
+    Borrowed(OrtTensorRef<'t>, D),
+    Coerced(Box<dyn AsOrtValue + 't>),
+    Staged(StagedInput<'t>),
+}
+
+impl<'t, T, D> InputTensor<'t, T, D>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    pub(super) fn c_ptr(&self) -> *mut sys::OrtValue {
+        match self {
+            InputTensor::Direct(tensor) => tensor.c_ptr,
+            InputTensor::Borrowed(tensor, _) => tensor.c_ptr,
+            InputTensor::Coerced(tensor) => tensor.c_ptr(),
+            InputTensor::Staged(tensor) => tensor.c_ptr(),
+        }
+    }
+}
+
+impl<'t, T, D> AsOrtValue for InputTensor<'t, T, D>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    fn c_ptr(&self) -> *mut sys::OrtValue {
+        InputTensor::c_ptr(self)
+    }
+}
+
+/// Tensor of any element type, sequence or map
+pub(super) trait AsOrtValue {
+    fn c_ptr(&self) -> *mut sys::OrtValue;
+}
+
+impl<'t, T, D> AsOrtValue for OrtTensor<'t, T, D>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    fn c_ptr(&self) -> *mut sys::OrtValue {
+        self.c_ptr
+    }
+}
+
+impl AsOrtValue for OrtSequence {
+    fn c_ptr(&self) -> *mut sys::OrtValue {
+        self.c_ptr
+    }
+}
+
+impl<'m> AsOrtValue for DynOrtValue<'m> {
+    fn c_ptr(&self) -> *mut sys::OrtValue {
+        DynOrtValue::c_ptr(self)
+    }
+}
+
+impl AsOrtValue for OrtMap {
+    fn c_ptr(&self) -> *mut sys::OrtValue {
+        self.c_ptr
+    }
+}
+
+/// Convert `array` to the element type of `input` and copy it to the runtime
+pub(super) fn coerce_input<'t, 'm, S, TIn, D>(
+    memory_info: &'m MemoryInfo,
+    allocator_ptr: *mut sys::OrtAllocator,
+    input: &Input,
+    array: &ArrayBase<S, D>,
+    coercion: InputCoercion,
+) -> Result<Box<dyn AsOrtValue + 't>>
+where
+    S: ndarray::Data<Elem = TIn>,
+    TIn: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension + 't,
+    'm: 't,
+{
+    macro_rules! coerce_to {
+        ($type_:ty) => {
+            Box::new(OrtTensor::from_array(
+                memory_info,
+                allocator_ptr,
+                convert_array::<TIn, $type_, D>(input, array.view(), coercion)?,
+            )?)
+        };
+    }
+
+    Ok(match input.input_type {
+        TensorElementDataType::Float => coerce_to!(f32),
+        TensorElementDataType::Uint8 => coerce_to!(u8),
+        TensorElementDataType::Int8 => coerce_to!(i8),
+        TensorElementDataType::Uint16 => coerce_to!(u16),
+        TensorElementDataType::Int16 => coerce_to!(i16),
+        TensorElementDataType::Int32 => coerce_to!(i32),
+        TensorElementDataType::Int64 => coerce_to!(i64),
+        TensorElementDataType::Double => coerce_to!(f64),
+        TensorElementDataType::Uint32 => coerce_to!(u32),
+        TensorElementDataType::Uint64 => coerce_to!(u64),
+        TensorElementDataType::String
+        | TensorElementDataType::Float16
+        | TensorElementDataType::Bfloat16 => {
+            return Err(unsupported::<TIn>(input).into());
+        }
+    })
+}
+
+fn convert_array<TIn, TOut, D>(
+    input: &Input,
+    array: ArrayView<'_, TIn, D>,
+    coercion: InputCoercion,
+) -> Result<Array<TOut, D>>
+where
+    TIn: TypeToTensorElementDataType,
+    TOut: FromNumber,
+    D: ndarray::Dimension,
+{
+    if !is_numeric(TIn::tensor_element_data_type()) {
+        return Err(unsupported::<TIn>(input).into());
+    }
+
+    let allow_lossy = coercion == InputCoercion::AllowLossy;
+    let data = array
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            to_number(value)
+                .and_then(|number| TOut::from_number(number, allow_lossy))
+                .ok_or_else(|| InputCoercionError::Lossy {
+                    input: input.name.clone(),
+                    from: TIn::tensor_element_data_type(),
+                    to: input.input_type,
+                    index,
+                })
+        })
+        .collect::<std::result::Result<Vec<TOut>, _>>()?;
+
+    Ok(Array::from_shape_vec(array.raw_dim(), data)
+        .expect("Elements were collected in logical order from an array of the same shape"))
+}
+
+fn unsupported<TIn>(input: &Input) -> InputCoercionError
+where
+    TIn: TypeToTensorElementDataType,
+{
+    InputCoercionError::Unsupported {
+        input: input.name.clone(),
+        from: TIn::tensor_element_data_type(),
+        to: input.input_type,
+    }
+}
+
+fn is_numeric(element_type: TensorElementDataType) -> bool {
+    !matches!(
+        element_type,
+        TensorElementDataType::String
+            | TensorElementDataType::Float16
+            | TensorElementDataType::Bfloat16
+    )
+}
+
+/// Value of a numeric tensor element, in a type holding any of them exactly
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+/// Read a tensor element as a number, `None` for non-numeric element types
+pub(super) fn to_number<T>(value: &T) -> Option<Number>
+where
+    T: TypeToTensorElementDataType,
+{
+    let ptr = value as *const T;
+    macro_rules! read {
+        ($type_:ty) => {{
+            // The element type reported by the trait determines the in-memory representation
+            assert_eq!(std::mem::size_of::<T>(), std::mem::size_of::<$type_>());
+            unsafe { *(ptr as *const $type_) }
+        }};
+    }
+
+    Some(match T::tensor_element_data_type() {
+        TensorElementDataType::Float => Number::Float(read!(f32) as f64),
+        TensorElementDataType::Double => Number::Float(read!(f64)),
+        TensorElementDataType::Uint8 => Number::Int(read!(u8) as i128),
+        TensorElementDataType::Int8 => Number::Int(read!(i8) as i128),
+        TensorElementDataType::Uint16 => Number::Int(read!(u16) as i128),
+        TensorElementDataType::Int16 => Number::Int(read!(i16) as i128),
+        TensorElementDataType::Int32 => Number::Int(read!(i32) as i128),
+        TensorElementDataType::Int64 => Number::Int(read!(i64) as i128),
+        TensorElementDataType::Uint32 => Number::Int(read!(u32) as i128),
+        TensorElementDataType::Uint64 => Number::Int(read!(u64) as i128),
+        TensorElementDataType::String
+        | TensorElementDataType::Float16
+        | TensorElementDataType::Bfloat16 => return None,
+    })
+}
+
+/// Element types inputs can be converted to
+trait FromNumber: TypeToTensorElementDataType + Debug + Clone {
+    /// Convert `number`, or `None` if it is not represented exactly and `allow_lossy` is false
+    fn from_number(number: Number, allow_lossy: bool) -> Option<Self>;
+}
+
+macro_rules! impl_from_number_int {
+    ($type_:ty) => {
+        impl FromNumber for $type_ {
+            fn from_number(number: Number, allow_lossy: bool) -> Option<Self> {
+                let exact = match number {
+                    Number::Int(value) => <$type_>::try_from(value).ok(),
+                    // Integral floats are exact in i128 up to its range, well beyond any target's
+                    Number::Float(value) if value.fract() == 0.0 => {
+                        <$type_>::try_from(value as i128).ok()
+                    }
+                    Number::Float(_) => None,
+                };
+                exact.or_else(|| {
+                    if !allow_lossy {
+                        return None;
+                    }
+                    // `as` from a float truncates and saturates (NaN becomes 0)
+                    Some(match number {
+                        Number::Int(value) => {
+                            value.clamp(<$type_>::MIN as i128, <$type_>::MAX as i128) as $type_
+                        }
+                        Number::Float(value) => value as $type_,
+                    })
+                })
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_number_float {
+    ($type_:ty) => {
+        impl FromNumber for $type_ {
+            #[allow(clippy::float_cmp)]
+            fn from_number(number: Number, allow_lossy: bool) -> Option<Self> {
+                let (converted, exact) = match number {
+                    Number::Int(value) => {
+                        let converted = value as $type_;
+                        (converted, converted as i128 == value)
+                    }
+                    Number::Float(value) => {
+                        let converted = value as $type_;
+                        (converted, converted as f64 == value || value.is_nan())
+                    }
+                };
+                if exact || allow_lossy {
+                    Some(converted)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_from_number_int!(u8);
+impl_from_number_int!(i8);
+impl_from_number_int!(u16);
+impl_from_number_int!(i16);
+impl_from_number_int!(i32);
+impl_from_number_int!(i64);
+impl_from_number_int!(u32);
+impl_from_number_int!(u64);
+impl_from_number_float!(f32);
+impl_from_number_float!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OrtError;
+    use ndarray::arr2;
+    use test_log::test;
+
+    fn input(input_type: TensorElementDataType) -> Input {
+        Input {
+            name: "input".to_string(),
+            input_type,
+            dimensions: vec![None, None],
+            container: None,
+        }
+    }
+
+    #[test]
+    fn coerce_i32_to_i64() {
+        let array = arr2(&[[1_i32, -2], [i32::MAX, i32::MIN]]);
+        let converted = convert_array::<i32, i64, _>(
+            &input(TensorElementDataType::Int64),
+            array.view(),
+            InputCoercion::Lossless,
+        )
+        .unwrap();
+        assert_eq!(converted, array.mapv(i64::from));
+    }
+
+    #[test]
+    fn coerce_f64_to_f32() {
+        let exact = arr2(&[[0.5_f64, -2.0], [1e10, f64::INFINITY]]);
+        let converted = convert_array::<f64, f32, _>(
+            &input(TensorElementDataType::Float),
+            exact.view(),
+            InputCoercion::Lossless,
+        )
+        .unwrap();
+        assert_eq!(converted, arr2(&[[0.5_f32, -2.0], [1e10, f32::INFINITY]]));
+
+        let inexact = arr2(&[[0.5_f64, 0.1]]);
+        let lossless = convert_array::<f64, f32, _>(
+            &input(TensorElementDataType::Float),
+            inexact.view(),
+            InputCoercion::Lossless,
+        );
+        assert!(matches!(
+            lossless,
+            Err(OrtError::InputCoercion(InputCoercionError::Lossy {
+                index: 1,
+                ..
+            }))
+        ));
+        let lossy = convert_array::<f64, f32, _>(
+            &input(TensorElementDataType::Float),
+            inexact.view(),
+            InputCoercion::AllowLossy,
+        )
+        .unwrap();
+        assert_eq!(lossy, arr2(&[[0.5_f32, 0.1]]));
+    }
+
+    #[test]
+    fn coerce_out_of_range_integers() {
+        let array = arr2(&[[1_i64, 1 << 40]]);
+        let lossless = convert_array::<i64, i32, _>(
+            &input(TensorElementDataType::Int32),
+            array.view(),
+            InputCoercion::Lossless,
+        );
+        assert!(matches!(
+            lossless,
+            Err(OrtError::InputCoercion(InputCoercionError::Lossy {
+                index: 1,
+                ..
+            }))
+        ));
+        let lossy = convert_array::<i64, i32, _>(
+            &input(TensorElementDataType::Int32),
+            array.view(),
+            InputCoercion::AllowLossy,
+        )
+        .unwrap();
+        assert_eq!(lossy, arr2(&[[1_i32, i32::MAX]]));
+    }
+
+    #[test]
+    fn coerce_float_to_integer() {
+        assert_eq!(i64::from_number(Number::Float(3.0), false), Some(3));
+        assert_eq!(i64::from_number(Number::Float(3.7), false), None);
+        assert_eq!(i64::from_number(Number::Float(3.7), true), Some(3));
+        assert_eq!(u8::from_number(Number::Float(-1.0), true), Some(0));
+        assert_eq!(i64::from_number(Number::Float(f64::NAN), false), None);
+        assert_eq!(
+            f32::from_number(Number::Int(1 << 24), false),
+            Some(16777216.0)
+        );
+        assert_eq!(f32::from_number(Number::Int((1 << 24) + 1), false), None);
+    }
+
+    #[test]
+    fn coerce_string_unsupported() {
+        let array = arr2(&[["a".to_string()]]);
+        let converted = convert_array::<String, i64, _>(
+            &input(TensorElementDataType::Int64),
+            array.view(),
+            InputCoercion::AllowLossy,
+        );
+        assert!(matches!(
+            converted,
+            Err(OrtError::InputCoercion(
+                InputCoercionError::Unsupported { .. }
+            ))
+        ));
+    }
+}
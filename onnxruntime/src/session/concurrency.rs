@@ -0,0 +1,197 @@
+//! Module containing the limit on concurrent inference runs
+
+use std::{
+    sync::{Arc, Condvar, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Limit on the number of inference runs executing at the same time
+///
+/// Every run uses the runtime's intra-op thread pool; too many concurrent runs oversubscribe
+/// the machine's cores and latency collapses. Runs over the limit wait for a slot instead.
+///
+/// The limit is a cheap handle: cloning it and passing the clones to several sessions (with
+/// [`SessionBuilder::with_concurrency_limit()`](struct.SessionBuilder.html#method.with_concurrency_limit))
+/// caps their runs together, for example for a server running one session per worker thread.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimit {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    max_concurrent_runs: usize,
+    state: Mutex<State>,
+    slot_freed: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    running: usize,
+    runs: u64,
+    waited_runs: u64,
+    total_wait: Duration,
+    max_wait: Duration,
+}
+
+/// Wait times of runs going through a [`ConcurrencyLimit`](struct.ConcurrencyLimit.html)
+///
+/// Meant for tuning the limit: runs rarely waiting with idle cores mean it can be raised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcurrencyMetrics {
+    /// The limit itself
+    pub max_concurrent_runs: usize,
+    /// Number of runs executing right now
+    pub running: usize,
+    /// Number of runs started so far
+    pub runs: u64,
+    /// Number of runs which had to wait for a slot
+    pub waited_runs: u64,
+    /// Total time spent waiting for a slot, over all runs
+    pub total_wait: Duration,
+    /// Longest time a run waited for a slot
+    pub max_wait: Duration,
+}
+
+impl ConcurrencyMetrics {
+    /// Mean time runs waited for a slot, counting the ones which did not wait
+    pub fn mean_wait(&self) -> Duration {
+        match self.runs {
+            0 => Duration::default(),
+            runs => Duration::from_secs_f64(self.total_wait.as_secs_f64() / runs as f64),
+        }
+    }
+}
+
+impl ConcurrencyLimit {
+    /// Allow at most `max_concurrent_runs` runs at the same time (a limit of zero is treated as one)
+    pub fn new(max_concurrent_runs: usize) -> ConcurrencyLimit {
+        ConcurrencyLimit {
+            inner: Arc::new(Inner {
+                max_concurrent_runs: max_concurrent_runs.max(1),
+                state: Mutex::new(State::default()),
+                slot_freed: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Wait times so far
+    pub fn metrics(&self) -> ConcurrencyMetrics {
+        let state = self.lock();
+        ConcurrencyMetrics {
+            max_concurrent_runs: self.inner.max_concurrent_runs,
+            running: state.running,
+            runs: state.runs,
+            waited_runs: state.waited_runs,
+            total_wait: state.total_wait,
+            max_wait: state.max_wait,
+        }
+    }
+
+    /// Wait for a slot, held until the returned permit is dropped
+    pub(super) fn acquire(&self) -> RunPermit<'_> {
+        let mut state = self.lock();
+        let waited = if state.running >= self.inner.max_concurrent_runs {
+            let start = Instant::now();
+            while state.running >= self.inner.max_concurrent_runs {
+                state = self
+                    .inner
+                    .slot_freed
+                    .wait(state)
+                    .expect("Failed to acquire lock: another thread panicked?");
+            }
+            Some(start.elapsed())
+        } else {
+            None
+        };
+
+        state.running += 1;
+        state.runs += 1;
+        if let Some(waited) = waited {
+            state.waited_runs += 1;
+            state.total_wait += waited;
+            state.max_wait = state.max_wait.max(waited);
+        }
+
+        RunPermit { limit: self }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.inner
+            .state
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+    }
+}
+
+/// Slot of a [`ConcurrencyLimit`](struct.ConcurrencyLimit.html), released on drop
+#[derive(Debug)]
+pub(super) struct RunPermit<'l> {
+    limit: &'l ConcurrencyLimit,
+}
+
+impl<'l> Drop for RunPermit<'l> {
+    fn drop(&mut self) {
+        // Permits are dropped while unwinding from panicking runs: free the slot regardless
+        let mut state = self
+            .limit
+            .inner
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        state.running -= 1;
+        drop(state);
+        self.limit.inner.slot_freed.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use test_log::test;
+
+    #[test]
+    fn concurrency_limit_is_respected() {
+        let limit = ConcurrencyLimit::new(2);
+        let peak = Arc::new(Mutex::new((0, 0)));
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let limit = limit.clone();
+                let peak = Arc::clone(&peak);
+                thread::spawn(move || {
+                    let _permit = limit.acquire();
+                    {
+                        let mut peak = peak.lock().unwrap();
+                        peak.0 += 1;
+                        peak.1 = peak.1.max(peak.0);
+                    }
+                    thread::sleep(Duration::from_millis(5));
+                    peak.lock().unwrap().0 -= 1;
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(peak.lock().unwrap().1, 2);
+        let metrics = limit.metrics();
+        assert_eq!(metrics.runs, 8);
+        assert_eq!(metrics.running, 0);
+        assert!(metrics.waited_runs >= 6);
+        assert!(metrics.max_wait >= Duration::from_millis(5));
+        assert!(metrics.mean_wait() <= metrics.max_wait);
+    }
+
+    #[test]
+    fn concurrency_limit_zero_allows_one_run() {
+        let limit = ConcurrencyLimit::new(0);
+        let permit = limit.acquire();
+        assert_eq!(limit.metrics().running, 1);
+        drop(permit);
+        assert_eq!(limit.metrics().running, 0);
+        assert_eq!(limit.metrics().waited_runs, 0);
+    }
+}
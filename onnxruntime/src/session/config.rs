@@ -0,0 +1,153 @@
+//! Module containing the record of a session's configuration
+
+use std::fmt;
+
+use crate::{
+    execution_providers::ExecutionProvider, AllocatorType, ExecutionMode, GraphOptimizationLevel,
+    MemType,
+};
+
+/// Execution provider of a [`SessionConfig`](struct.SessionConfig.html), with its options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderConfig {
+    /// Name of the provider, as used by the runtime (for example `CUDAExecutionProvider`)
+    pub name: String,
+    /// Provider option names and values
+    pub options: Vec<(String, String)>,
+}
+
+impl ProviderConfig {
+    pub(crate) fn of(provider: &dyn ExecutionProvider) -> ProviderConfig {
+        ProviderConfig {
+            name: provider.name().to_owned(),
+            options: provider
+                .options()
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        }
+    }
+}
+
+/// Everything a session was configured with through its [`SessionBuilder`](struct.SessionBuilder.html)
+///
+/// Returned by [`Session::effective_config()`](struct.Session.html#method.effective_config), and
+/// logged at the `DEBUG` level when the session is created, so that the configuration of a
+/// session in production can be reproduced exactly.
+///
+/// Displayed on a single line of space separated `key=value` pairs, for example:
+///
+/// ```text
+/// optimization_level=all number_threads=4 execution_mode=sequential memory_pattern=true
+/// allocator=arena memory_type=default global_thread_pools=false
+/// execution_providers=[CUDAExecutionProvider{device_id=0,cudnn_conv_algo_search=EXHAUSTIVE}]
+/// config_entries={session.set_denormal_as_zero=1}
+/// ```
+///
+/// Settings the runtime was left to pick have their default value; the intra-op thread count
+/// is then `default`. Functions passed to
+/// [`with_ep_loader()`](struct.SessionBuilder.html#method.with_ep_loader) are not recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionConfig {
+    /// Graph optimization level
+    pub optimization_level: GraphOptimizationLevel,
+    /// Number of intra-op threads, if set
+    pub number_threads: Option<i16>,
+    /// Execution mode of the graph
+    pub execution_mode: ExecutionMode,
+    /// Whether memory pattern optimization is enabled
+    pub memory_pattern: bool,
+    /// Allocator of the session
+    pub allocator: AllocatorType,
+    /// Memory type of the session
+    pub memory_type: MemType,
+    /// Whether the session uses the environment's global thread pools
+    pub global_thread_pools: bool,
+    /// Execution providers actually used, in priority order
+    pub execution_providers: Vec<ProviderConfig>,
+    /// Session configuration entries, as keys and values
+    pub config_entries: Vec<(String, String)>,
+}
+
+impl fmt::Display for SessionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "optimization_level={}", self.optimization_level)?;
+        match self.number_threads {
+            Some(threads) => write!(f, " number_threads={}", threads)?,
+            None => write!(f, " number_threads=default")?,
+        }
+        write!(
+            f,
+            " execution_mode={} memory_pattern={} allocator={} memory_type={} global_thread_pools={}",
+            self.execution_mode,
+            self.memory_pattern,
+            self.allocator,
+            self.memory_type,
+            self.global_thread_pools
+        )?;
+
+        write!(f, " execution_providers=[")?;
+        for (index, provider) in self.execution_providers.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}{{", provider.name)?;
+            write_pairs(f, &provider.options)?;
+            write!(f, "}}")?;
+        }
+        write!(f, "] config_entries={{")?;
+        write_pairs(f, &self.config_entries)?;
+        write!(f, "}}")
+    }
+}
+
+fn write_pairs(f: &mut fmt::Formatter<'_>, pairs: &[(String, String)]) -> fmt::Result {
+    for (index, (key, value)) in pairs.iter().enumerate() {
+        if index > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{}={}", key, value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn session_config_display() {
+        let config = SessionConfig {
+            optimization_level: GraphOptimizationLevel::Extended,
+            number_threads: Some(4),
+            execution_mode: ExecutionMode::Sequential,
+            memory_pattern: false,
+            allocator: AllocatorType::Arena,
+            memory_type: MemType::Default,
+            global_thread_pools: false,
+            execution_providers: vec![ProviderConfig {
+                name: "CUDAExecutionProvider".to_owned(),
+                options: vec![
+                    ("device_id".to_owned(), "1".to_owned()),
+                    ("use_tf32".to_owned(), "0".to_owned()),
+                ],
+            }],
+            config_entries: vec![
+                ("session.set_denormal_as_zero".to_owned(), "1".to_owned()),
+                (
+                    "optimization.disable_specified_optimizers".to_owned(),
+                    "NchwcTransformer".to_owned(),
+                ),
+            ],
+        };
+        assert_eq!(
+            config.to_string(),
+            "optimization_level=extended number_threads=4 execution_mode=sequential \
+             memory_pattern=false allocator=arena memory_type=default global_thread_pools=false \
+             execution_providers=[CUDAExecutionProvider{device_id=1,use_tf32=0}] \
+             config_entries={session.set_denormal_as_zero=1,\
+             optimization.disable_specified_optimizers=NchwcTransformer}"
+        );
+    }
+}
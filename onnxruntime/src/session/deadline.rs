@@ -0,0 +1,109 @@
+//! Module containing inference runs cancelled when a deadline passes
+
+use std::{
+    fmt::Debug,
+    sync::{Condvar, Mutex},
+    thread,
+    time::Instant,
+};
+
+use tracing::{debug, warn};
+
+use crate::{
+    error::{OrtError, Result},
+    tensor::{IntoInput, OrtOwnedTensor},
+    TypeToTensorElementDataType,
+};
+
+use super::{RunInputs, RunOptions, Session};
+
+impl<'a> Session<'a> {
+    /// Same as [`run()`](#method.run), terminating the run if it is still executing at `deadline`
+    ///
+    /// Fails with [`OrtError::DeadlineExceeded`](../error/enum.OrtError.html#variant.DeadlineExceeded)
+    /// if the deadline has already passed (without running anything) or if the run was terminated.
+    /// A watchdog thread lives for the duration of the call; the runtime checks for termination
+    /// between nodes, so a run can overshoot its deadline by the duration of a single node.
+    pub fn run_with_deadline<'s, 't, 'm, I, TOut>(
+        &'s mut self,
+        inputs: I,
+        deadline: Instant,
+    ) -> Result<Vec<OrtOwnedTensor<'t, 'm, TOut, ndarray::IxDyn>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        'm: 't, // 'm outlives 't (memory info outlives tensor)
+        's: 'm, // 's outlives 'm (session outlives memory info)
+    {
+        if Instant::now() >= deadline {
+            return Err(OrtError::DeadlineExceeded);
+        }
+
+        let inputs: RunInputs<_> = inputs.into_iter().collect();
+        let input_ort_tensors = self.prepare_inputs(&inputs)?;
+        let run_options = RunOptions::new()?;
+        let watchdog = Watchdog::default();
+
+        let session: &'s Session<'a> = self;
+        let (outputs, terminated) = thread::scope(|scope| {
+            let watcher = scope.spawn(|| watchdog.watch(deadline, &run_options));
+            let outputs = session.run_ort_values(
+                Some(&run_options),
+                input_ort_tensors.iter().map(|tensor| tensor.c_ptr()),
+            );
+            watchdog.disarm();
+            let terminated = watcher.join().expect("Deadline watchdog panicked");
+            (outputs, terminated)
+        });
+
+        match outputs {
+            Err(OrtError::Run(_)) | Err(OrtError::RunWithInputs { .. }) if terminated => {
+                Err(OrtError::DeadlineExceeded)
+            }
+            outputs => outputs,
+        }
+    }
+}
+
+/// Terminates a run at its deadline, unless disarmed before
+#[derive(Debug, Default)]
+struct Watchdog {
+    finished: Mutex<bool>,
+    run_finished: Condvar,
+}
+
+impl Watchdog {
+    /// Wait until the run finishes or the deadline passes, returning whether the run was terminated
+    fn watch(&self, deadline: Instant, run_options: &RunOptions) -> bool {
+        let mut finished = self.lock();
+        while !*finished {
+            let now = Instant::now();
+            if now >= deadline {
+                debug!("Deadline passed, terminating the run.");
+                if let Err(err) = run_options.terminate() {
+                    warn!("Failed to terminate run past its deadline: {}", err);
+                    return false;
+                }
+                return true;
+            }
+            finished = self
+                .run_finished
+                .wait_timeout(finished, deadline - now)
+                .expect("Failed to acquire lock: another thread panicked?")
+                .0;
+        }
+        false
+    }
+
+    fn disarm(&self) {
+        *self.lock() = true;
+        self.run_finished.notify_one();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, bool> {
+        self.finished
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+    }
+}
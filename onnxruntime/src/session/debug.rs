@@ -0,0 +1,129 @@
+//! Module containing the debugging oriented settings of sessions
+
+use std::path::{Path, PathBuf};
+
+/// Debugging oriented settings of a session, to triage issues of converted models
+///
+/// Set with [`SessionBuilder::with_debug_options()`](struct.SessionBuilder.html#method.with_debug_options).
+/// None of them are meant for production: they make session creation stricter or slower, or
+/// leave files behind.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, session::DebugOptions};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let session = environment
+///     .new_session_builder()?
+///     .with_debug_options(
+///         DebugOptions::new()
+///             .with_strict_shape_type_inference(true)
+///             .with_optimized_model_path("converted.optimized.onnx"),
+///     )?
+///     .with_model_from_file("converted.onnx")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugOptions {
+    strict_shape_type_inference: bool,
+    layout_transformation_dumps: bool,
+    prepacking: bool,
+    pub(super) optimized_model_path: Option<PathBuf>,
+}
+
+impl Default for DebugOptions {
+    fn default() -> DebugOptions {
+        DebugOptions {
+            strict_shape_type_inference: false,
+            layout_transformation_dumps: false,
+            prepacking: true,
+            optimized_model_path: None,
+        }
+    }
+}
+
+impl DebugOptions {
+    /// Settings of the runtime's defaults, until changed
+    pub fn new() -> DebugOptions {
+        DebugOptions::default()
+    }
+
+    /// Fail session creation on shape or type inference mismatches, rather than warning
+    ///
+    /// Shapes and types declared by a model that contradict the ones inferred from its operators
+    /// are normally logged and ignored; a strict inference points at the node at fault.
+    pub fn with_strict_shape_type_inference(mut self, strict: bool) -> DebugOptions {
+        self.strict_shape_type_inference = strict;
+        self
+    }
+
+    /// Save the model after each step of the layout transformation (NCHW to NHWC) of
+    /// execution providers preferring channels last, in the working directory
+    ///
+    /// Only effective with a debug build of the runtime.
+    pub fn with_layout_transformation_dumps(mut self, enabled: bool) -> DebugOptions {
+        self.layout_transformation_dumps = enabled;
+        self
+    }
+
+    /// Enable or disable the pre-packing of constant weights into kernel specific layouts
+    ///
+    /// Disabling it keeps initializers as the model stores them, to rule pre-packing out when
+    /// outputs differ from another runtime.
+    pub fn with_prepacking(mut self, enabled: bool) -> DebugOptions {
+        self.prepacking = enabled;
+        self
+    }
+
+    /// Save the graph as optimized by the runtime to `path`, once the session is created
+    ///
+    /// The saved graph shows which nodes were fused, and into which operators, to debug graph
+    /// fusions changing a model's outputs. A path ending with `.ort` saves it in the ORT format.
+    pub fn with_optimized_model_path<P>(mut self, path: P) -> DebugOptions
+    where
+        P: AsRef<Path>,
+    {
+        self.optimized_model_path = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Session config entries of the settings differing from the runtime's defaults
+    pub(super) fn config_entries(&self) -> Vec<(&'static str, &'static str)> {
+        let mut entries = Vec::new();
+        if self.strict_shape_type_inference {
+            entries.push(("session.strict_shape_type_inference", "1"));
+        }
+        if self.layout_transformation_dumps {
+            entries.push(("session.debug_layout_transformation", "1"));
+        }
+        if !self.prepacking {
+            entries.push(("session.disable_prepacking", "1"));
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn only_changed_settings_are_set() {
+        assert!(DebugOptions::new().config_entries().is_empty());
+
+        let options = DebugOptions::new()
+            .with_strict_shape_type_inference(true)
+            .with_prepacking(false);
+        assert_eq!(
+            options.config_entries(),
+            vec![
+                ("session.strict_shape_type_inference", "1"),
+                ("session.disable_prepacking", "1"),
+            ]
+        );
+    }
+}
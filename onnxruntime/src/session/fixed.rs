@@ -0,0 +1,144 @@
+//! Module containing sessions running tensors whose shape is known at compile time
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{
+    error::FixedShapeError,
+    tensor::{
+        fixed::{FixedShape, FixedTensor},
+        OrtOwnedTensor, OrtTensor,
+    },
+    Result, TensorElementDataType, TypeToTensorElementDataType,
+};
+
+use super::Session;
+
+/// Session whose inputs and outputs have shapes known at compile time
+///
+/// Created with [`Session::with_fixed_shapes()`](struct.Session.html#method.with_fixed_shapes),
+/// which checks the model's declared element types and dimensions against the static ones,
+/// so that [`run()`](#method.run) does not validate its inputs.
+///
+/// The model must have `N` inputs of element type `TIn` and shape `SIn`, and all of its outputs
+/// must be of element type `TOut` and shape `SOut`.
+#[derive(Debug)]
+pub struct FixedShapeSession<'s, 'a, TIn, SIn, TOut, SOut, const N: usize> {
+    session: &'s mut Session<'a>,
+    types: PhantomData<(TIn, SIn, TOut, SOut)>,
+}
+
+impl<'a> Session<'a> {
+    /// Check the model against static shapes, to run fixed-shape tensors without further validation
+    ///
+    /// Dynamic dimensions of the model accept any static size.
+    pub fn with_fixed_shapes<TIn, SIn, TOut, SOut, const N: usize>(
+        &mut self,
+    ) -> Result<FixedShapeSession<'_, 'a, TIn, SIn, TOut, SOut, N>>
+    where
+        TIn: TypeToTensorElementDataType + Debug + Clone,
+        SIn: FixedShape,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+        SOut: FixedShape,
+    {
+        if self.inputs.len() != N {
+            return Err(FixedShapeError::InputsCount {
+                model: self.inputs.len(),
+                expected: N,
+            }
+            .into());
+        }
+
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| (&input.name, input.input_type, &input.dimensions));
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| (&output.name, output.output_type, &output.dimensions));
+        for (name, element_type, dimensions) in inputs {
+            check_fixed_shape::<TIn, SIn>(name, element_type, dimensions)?;
+        }
+        for (name, element_type, dimensions) in outputs {
+            check_fixed_shape::<TOut, SOut>(name, element_type, dimensions)?;
+        }
+
+        Ok(FixedShapeSession {
+            session: self,
+            types: PhantomData,
+        })
+    }
+}
+
+fn check_fixed_shape<T, S>(
+    name: &str,
+    element_type: TensorElementDataType,
+    dimensions: &[Option<u32>],
+) -> Result<()>
+where
+    T: TypeToTensorElementDataType,
+    S: FixedShape,
+{
+    let fits = element_type == T::tensor_element_data_type()
+        && dimensions.len() == S::SHAPE.len()
+        && dimensions
+            .iter()
+            .zip(S::SHAPE)
+            .all(|(dimension, &size)| match dimension {
+                Some(d) => *d as usize == size,
+                None => true,
+            });
+    if fits {
+        Ok(())
+    } else {
+        Err(FixedShapeError::Model {
+            name: name.to_owned(),
+            element_type,
+            dimensions: dimensions.to_vec(),
+            expected_type: T::tensor_element_data_type(),
+            expected_shape: S::SHAPE.to_vec(),
+        }
+        .into())
+    }
+}
+
+impl<'s, 'a, TIn, SIn, TOut, SOut, const N: usize>
+    FixedShapeSession<'s, 'a, TIn, SIn, TOut, SOut, N>
+where
+    TIn: TypeToTensorElementDataType + Debug + Clone,
+    SIn: FixedShape,
+    TOut: TypeToTensorElementDataType + Debug + Clone,
+    SOut: FixedShape,
+{
+    /// Run the inputs through the ONNX graph, performing inference
+    ///
+    /// Outputs are copied out of the runtime; their shape is still checked, since the model
+    /// may not declare all of its output dimensions.
+    pub fn run(
+        &mut self,
+        inputs: [FixedTensor<TIn, SIn>; N],
+    ) -> Result<Vec<FixedTensor<TOut, SOut>>> {
+        let session = &*self.session;
+        let input_ort_tensors = IntoIterator::into_iter(inputs)
+            .map(|input| {
+                OrtTensor::from_array(
+                    &session.memory_info,
+                    session.allocator_ptr,
+                    input.into_array(),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let outputs: Vec<OrtOwnedTensor<TOut, _>> =
+            session.run_ort_values(None, input_ort_tensors.iter().map(|tensor| tensor.c_ptr))?;
+        outputs
+            .iter()
+            .map(|output| FixedTensor::from_view(output.view()))
+            .collect()
+    }
+
+    /// Underlying session
+    pub fn session(&self) -> &Session<'a> {
+        self.session
+    }
+}
@@ -0,0 +1,154 @@
+//! Module containing sanity checks of inference inputs, run before handing them to the runtime
+
+use ndarray::ArrayView;
+
+use crate::{error::InputGuardError, TypeToTensorElementDataType};
+
+use super::coercion::{to_number, Number};
+
+/// Numerical sanity rules an input must follow, checked before running the model
+///
+/// Configured per input with
+/// [`SessionBuilder::with_input_guard()`](struct.SessionBuilder.html#method.with_input_guard).
+/// Runs whose input breaks a rule fail with
+/// [`OrtError::InputGuard`](../error/enum.OrtError.html#variant.InputGuard), naming the input and
+/// the first offending element, rather than propagating a `NaN` from a broken pre-processing step
+/// into garbage outputs. The value rules only apply to numeric inputs.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, session::InputGuard};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let session = environment
+///     .new_session_builder()?
+///     .with_input_guard(
+///         "image",
+///         InputGuard::new()
+///             .with_finite_only(true)
+///             .with_value_range(0.0, 1.0),
+///     )?
+///     .with_model_from_file("model.onnx")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct InputGuard {
+    finite_only: bool,
+    value_range: Option<(f64, f64)>,
+    non_empty: bool,
+}
+
+impl InputGuard {
+    /// Guard checking nothing, until rules are added
+    pub fn new() -> InputGuard {
+        InputGuard::default()
+    }
+
+    /// Reject `NaN` and infinite values
+    pub fn with_finite_only(mut self, finite_only: bool) -> InputGuard {
+        self.finite_only = finite_only;
+        self
+    }
+
+    /// Reject values below `min` or above `max` (`NaN` is rejected too)
+    pub fn with_value_range(mut self, min: f64, max: f64) -> InputGuard {
+        self.value_range = Some((min, max));
+        self
+    }
+
+    /// Reject inputs without any element (one of their dimensions is zero)
+    pub fn with_non_empty(mut self, non_empty: bool) -> InputGuard {
+        self.non_empty = non_empty;
+        self
+    }
+
+    /// Check the input named `input`, holding `array`
+    pub(super) fn check<T, D>(
+        &self,
+        input: &str,
+        array: ArrayView<'_, T, D>,
+    ) -> std::result::Result<(), InputGuardError>
+    where
+        T: TypeToTensorElementDataType,
+        D: ndarray::Dimension,
+    {
+        if self.non_empty && array.is_empty() {
+            return Err(InputGuardError::Empty {
+                input: input.to_owned(),
+            });
+        }
+        if !self.finite_only && self.value_range.is_none() {
+            return Ok(());
+        }
+
+        for (index, value) in array.iter().enumerate() {
+            let value = match to_number(value) {
+                Some(Number::Float(value)) => value,
+                Some(Number::Int(value)) => value as f64,
+                // Strings and half precision values are not checked
+                None => return Ok(()),
+            };
+            if self.finite_only && !value.is_finite() {
+                return Err(InputGuardError::NonFinite {
+                    input: input.to_owned(),
+                    index,
+                    value,
+                });
+            }
+            if let Some((min, max)) = self.value_range {
+                if !(min..=max).contains(&value) {
+                    return Err(InputGuardError::OutOfRange {
+                        input: input.to_owned(),
+                        index,
+                        value,
+                        min,
+                        max,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn values_are_checked() {
+        let guard = InputGuard::new()
+            .with_finite_only(true)
+            .with_value_range(0.0, 1.0);
+        let valid = ndarray::arr2(&[[0.0_f32, 0.5], [1.0, 0.25]]);
+        assert!(guard.check("image", valid.view()).is_ok());
+
+        let nan = ndarray::arr2(&[[0.0_f32, 0.5], [f32::NAN, 0.25]]);
+        assert!(matches!(
+            guard.check("image", nan.view()),
+            Err(InputGuardError::NonFinite { index: 2, .. })
+        ));
+
+        let out_of_range = ndarray::arr1(&[0_i64, 3]);
+        assert!(matches!(
+            guard.check("ids", out_of_range.view()),
+            Err(InputGuardError::OutOfRange { index: 1, value, .. }) if value == 3.0
+        ));
+    }
+
+    #[test]
+    fn empty_inputs_are_rejected_on_request() {
+        let empty = ndarray::Array2::<f32>::zeros((0, 3));
+        assert!(InputGuard::new().check("image", empty.view()).is_ok());
+        assert!(matches!(
+            InputGuard::new()
+                .with_non_empty(true)
+                .check("image", empty.view()),
+            Err(InputGuardError::Empty { .. })
+        ));
+    }
+}
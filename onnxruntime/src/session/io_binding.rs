@@ -0,0 +1,266 @@
+//! Module containing inputs and outputs bound to a session once and reused across runs
+
+use std::{collections::HashMap, ffi::CString, fmt, fmt::Debug};
+
+use tracing::debug;
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{assert_not_null_pointer, status_to_result, OrtError, Result},
+    fork_guard,
+    memory::MemoryInfo,
+    tensor::{
+        ort_owned_tensor::{OrtOwnedTensor, OrtOwnedTensorExtractor},
+        IntoInput,
+    },
+    TypeToTensorElementDataType,
+};
+
+use super::{coercion::AsOrtValue, get_tensor_dimensions_into, RunOptions, Session};
+
+/// Inputs and outputs bound to a session, run any number of times without binding them again
+///
+/// Created by [`Session::io_binding()`](struct.Session.html#method.io_binding). Inputs are copied
+/// to the runtime once when bound, rather than on every run: with a session running on a device,
+/// the runtime uploads them once too. Outputs are bound by name and allocated by the runtime on
+/// each run.
+///
+/// Inputs not bound are left out of the runs, which fail unless the model gives them a default.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, tensor::OrtOwnedTensor};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+/// let mut binding = session.io_binding()?;
+/// binding.bind_input("image", ndarray::Array::<f32, _>::zeros((1, 3, 224, 224)))?;
+/// binding.bind_output("scores")?;
+/// for _ in 0..10 {
+///     let outputs: Vec<OrtOwnedTensor<f32, _>> = binding.run()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct IoBinding<'s, 'a> {
+    ptr: *mut sys::OrtIoBinding,
+    session: &'s Session<'a>,
+    /// Values of the bound inputs, by input index, which the runtime reads on every run
+    inputs: HashMap<usize, Box<dyn AsOrtValue + 's>>,
+    output_count: usize,
+}
+
+impl<'s, 'a> IoBinding<'s, 'a> {
+    pub(super) fn new(session: &'s Session<'a>) -> Result<IoBinding<'s, 'a>> {
+        let mut ptr: *mut sys::OrtIoBinding = std::ptr::null_mut();
+        let status = unsafe { ort_api!(CreateIoBinding)?(session.session_ptr, &mut ptr) };
+        status_to_result(status).map_err(OrtError::IoBinding)?;
+        assert_not_null_pointer(ptr, "IoBinding")?;
+        Ok(IoBinding {
+            ptr,
+            session,
+            inputs: HashMap::new(),
+            output_count: 0,
+        })
+    }
+
+    /// Bind `input` to the model's input `name`, replacing the value bound to it, if any
+    ///
+    /// The input is converted like the inputs of [`Session::run()`](struct.Session.html#method.run).
+    pub fn bind_input<X>(&mut self, name: &str, input: X) -> Result<()>
+    where
+        X: IntoInput,
+        X::Elem: 's,
+        X::Dim: 's,
+    {
+        let index = self
+            .session
+            .inputs
+            .iter()
+            .position(|input| input.name == name)
+            .ok_or_else(|| OrtError::UnknownInput {
+                name: name.to_owned(),
+            })?;
+        let value = self.session.prepare_input(index, input.into_input())?;
+        let c_name = CString::new(name)?;
+        let status = unsafe { ort_api!(BindInput)?(self.ptr, c_name.as_ptr(), value.c_ptr()) };
+        status_to_result(status).map_err(OrtError::IoBinding)?;
+        // The value previously bound, if any, is no longer read by the runtime
+        self.inputs.insert(index, Box::new(value));
+        Ok(())
+    }
+
+    /// Bind the model's output `name`, allocated by the runtime with the session's allocator
+    ///
+    /// [`run()`](#method.run) returns the outputs in the order they were bound.
+    pub fn bind_output(&mut self, name: &str) -> Result<()> {
+        self.session.output_index(name)?;
+        let c_name = CString::new(name)?;
+        let status = unsafe {
+            ort_api!(BindOutputToDevice)?(self.ptr, c_name.as_ptr(), self.session.memory_info.ptr)
+        };
+        status_to_result(status).map_err(OrtError::IoBinding)?;
+        self.output_count += 1;
+        Ok(())
+    }
+
+    /// Bind the model's output `name`, allocated by the runtime with the allocator described by
+    /// `memory_info`
+    ///
+    /// For example, binding outputs to [`MemoryInfo::CUDA_PINNED`](../struct.MemoryInfo.html#associatedconstant.CUDA_PINNED)
+    /// memory speeds up their download from a CUDA device. The outputs must be CPU accessible to
+    /// be extracted by [`run()`](#method.run).
+    pub fn bind_output_to(&mut self, name: &str, memory_info: &MemoryInfo) -> Result<()> {
+        self.session.output_index(name)?;
+        let c_name = CString::new(name)?;
+        let status =
+            unsafe { ort_api!(BindOutputToDevice)?(self.ptr, c_name.as_ptr(), memory_info.ptr) };
+        status_to_result(status).map_err(OrtError::IoBinding)?;
+        self.output_count += 1;
+        Ok(())
+    }
+
+    /// Bind all the model's outputs, in the order of [`Session::outputs`](struct.Session.html#structfield.outputs)
+    pub fn bind_all_outputs(&mut self) -> Result<()> {
+        let session = self.session;
+        for output in &session.outputs {
+            self.bind_output(&output.name)?;
+        }
+        Ok(())
+    }
+
+    /// Unbind all inputs
+    pub fn clear_inputs(&mut self) -> Result<()> {
+        unsafe { ort_api!(ClearBoundInputs)?(self.ptr) };
+        self.inputs.clear();
+        Ok(())
+    }
+
+    /// Unbind all outputs
+    pub fn clear_outputs(&mut self) -> Result<()> {
+        unsafe { ort_api!(ClearBoundOutputs)?(self.ptr) };
+        self.output_count = 0;
+        Ok(())
+    }
+
+    /// Wait for the copies of the bound inputs to the device to complete
+    ///
+    /// Runs synchronize the inputs themselves; this lets the caller overlap the copies with other
+    /// work.
+    pub fn synchronize_inputs(&self) -> Result<()> {
+        let status = unsafe { ort_api!(SynchronizeBoundInputs)?(self.ptr) };
+        status_to_result(status).map_err(OrtError::IoBinding)
+    }
+
+    /// Wait for the outputs of the last run to be written to their device
+    pub fn synchronize_outputs(&self) -> Result<()> {
+        let status = unsafe { ort_api!(SynchronizeBoundOutputs)?(self.ptr) };
+        status_to_result(status).map_err(OrtError::IoBinding)
+    }
+
+    /// Run the session on the bound inputs, returning the bound outputs in the order they were
+    /// bound
+    pub fn run<TOut>(&self) -> Result<Vec<OrtOwnedTensor<'s, 's, TOut, ndarray::IxDyn>>>
+    where
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+    {
+        self.run_with(None)
+    }
+
+    /// Same as [`run()`](#method.run), with the given [`RunOptions`](struct.RunOptions.html)
+    pub fn run_with_options<TOut>(
+        &self,
+        options: &RunOptions,
+    ) -> Result<Vec<OrtOwnedTensor<'s, 's, TOut, ndarray::IxDyn>>>
+    where
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+    {
+        self.run_with(Some(options))
+    }
+
+    fn run_with<TOut>(
+        &self,
+        run_options: Option<&RunOptions>,
+    ) -> Result<Vec<OrtOwnedTensor<'s, 's, TOut, ndarray::IxDyn>>>
+    where
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+    {
+        fork_guard::check()?;
+
+        debug!(
+            inputs = self.inputs.len(),
+            outputs = self.output_count,
+            "Running with bound inputs and outputs."
+        );
+        let run_options_ptr = run_options.map_or(std::ptr::null(), RunOptions::ptr);
+        let status = unsafe {
+            ort_api!(RunWithBinding)?(self.session.session_ptr, run_options_ptr, self.ptr)
+        };
+        status_to_result(status).map_err(OrtError::Run)?;
+
+        let mut values_ptr: *mut *mut sys::OrtValue = std::ptr::null_mut();
+        let mut count = 0;
+        let status = unsafe {
+            ort_api!(GetBoundOutputValues)?(
+                self.ptr,
+                self.session.allocator_ptr,
+                &mut values_ptr,
+                &mut count,
+            )
+        };
+        status_to_result(status).map_err(OrtError::IoBinding)?;
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        assert_not_null_pointer(values_ptr, "BoundOutputValues")?;
+        let values = unsafe { std::slice::from_raw_parts(values_ptr, count) }.to_vec();
+        let status = unsafe {
+            ort_api!(AllocatorFree)?(
+                self.session.allocator_ptr,
+                values_ptr as *mut std::ffi::c_void,
+            )
+        };
+        status_to_result(status).map_err(OrtError::Allocator)?;
+
+        let memory_info = &self.session.memory_info;
+        let mut dims = Vec::new();
+        values
+            .into_iter()
+            .map(|ptr| {
+                let mut tensor_info_ptr: *mut sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+                let status = unsafe { ort_api!(GetTensorTypeAndShape)?(ptr, &mut tensor_info_ptr) };
+                status_to_result(status).map_err(OrtError::GetTensorTypeAndShape)?;
+                let result = unsafe { get_tensor_dimensions_into(tensor_info_ptr, &mut dims) };
+                unsafe { ort_api!(ReleaseTensorTypeAndShapeInfo)?(tensor_info_ptr) };
+                result?;
+                let shape: Vec<usize> = dims.iter().map(|&n| n as usize).collect();
+
+                let mut extractor =
+                    OrtOwnedTensorExtractor::new(memory_info, ndarray::IxDyn(&shape));
+                extractor.tensor_ptr = ptr;
+                extractor.upcast_half_to_f32 = self.session.upcast_half_outputs;
+                extractor.extract::<TOut>()
+            })
+            .collect()
+    }
+}
+
+impl<'s, 'a> Drop for IoBinding<'s, 'a> {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseIoBinding) {
+            unsafe { release(self.ptr) };
+        }
+    }
+}
+
+impl<'s, 'a> fmt::Debug for IoBinding<'s, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IoBinding")
+            .field("inputs", &self.inputs.len())
+            .field("outputs", &self.output_count)
+            .finish()
+    }
+}
@@ -0,0 +1,279 @@
+//! Module containing layers running code around the runs of a session
+
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use ndarray::ArrayD;
+
+use crate::error::Result;
+
+use super::InferenceRunner;
+
+/// Statistics of a run, handed to [`SessionLayer::after_run()`](trait.SessionLayer.html#method.after_run)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RunStats {
+    /// Time spent in the layers below and the model
+    pub duration: Duration,
+}
+
+/// Code run around the runs of a session, such as logging, input sanitization or rate limiting
+///
+/// Layers are stacked around a runner with [`Layered`](struct.Layered.html). Most layers only
+/// implement [`before_run()`](#method.before_run) and [`after_run()`](#method.after_run); layers
+/// that need to wrap the rest of the stack (to answer runs from a cache, or hold a permit for the
+/// duration of the run) implement [`run()`](#method.run) instead.
+pub trait SessionLayer<TIn, TOut = TIn>: Send + Sync {
+    /// Called with the inputs before the run; an error fails the run without going further
+    fn before_run(&self, _inputs: &[ArrayD<TIn>]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the outputs after a successful run
+    fn after_run(&self, _outputs: &[ArrayD<TOut>], _stats: &RunStats) {}
+
+    /// Run the inputs through the rest of the stack, `next`
+    ///
+    /// Calls [`before_run()`](#method.before_run), `next` and [`after_run()`](#method.after_run)
+    /// by default.
+    fn run(
+        &self,
+        inputs: Vec<ArrayD<TIn>>,
+        next: Next<'_, TIn, TOut>,
+    ) -> Result<Vec<ArrayD<TOut>>> {
+        self.before_run(&inputs)?;
+        let start = Instant::now();
+        let outputs = next.run(inputs)?;
+        let stats = RunStats {
+            duration: start.elapsed(),
+        };
+        self.after_run(&outputs, &stats);
+        Ok(outputs)
+    }
+}
+
+/// Shared layer, so that the caller can keep using it (to read its statistics for example)
+impl<L, TIn, TOut> SessionLayer<TIn, TOut> for Arc<L>
+where
+    L: SessionLayer<TIn, TOut> + ?Sized,
+{
+    fn before_run(&self, inputs: &[ArrayD<TIn>]) -> Result<()> {
+        (**self).before_run(inputs)
+    }
+
+    fn after_run(&self, outputs: &[ArrayD<TOut>], stats: &RunStats) {
+        (**self).after_run(outputs, stats)
+    }
+
+    fn run(
+        &self,
+        inputs: Vec<ArrayD<TIn>>,
+        next: Next<'_, TIn, TOut>,
+    ) -> Result<Vec<ArrayD<TOut>>> {
+        (**self).run(inputs, next)
+    }
+}
+
+/// Rest of a stack of layers: the layers below the current one, then the runner
+pub struct Next<'l, TIn, TOut> {
+    layers: &'l [Box<dyn SessionLayer<TIn, TOut>>],
+    runner: &'l dyn InferenceRunner<TIn, TOut>,
+}
+
+impl<'l, TIn, TOut> Next<'l, TIn, TOut> {
+    /// Run the inputs through the rest of the stack
+    pub fn run(self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        match self.layers.split_first() {
+            Some((layer, layers)) => layer.run(
+                inputs,
+                Next {
+                    layers,
+                    runner: self.runner,
+                },
+            ),
+            None => self.runner.run_dyn(inputs),
+        }
+    }
+}
+
+impl<'l, TIn, TOut> fmt::Debug for Next<'l, TIn, TOut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Next")
+            .field("layers", &self.layers.len())
+            .finish()
+    }
+}
+
+/// Runner with layers stacked around it, itself an [`InferenceRunner`](trait.InferenceRunner.html)
+///
+/// The first layer added is the outermost one: its `before_run()` is called first and its
+/// `after_run()` last.
+///
+/// # Example
+///
+/// ```
+/// # use onnxruntime::session::{InferenceRunner, Layered, MockOutput, MockSession, RunStats, SessionLayer};
+/// struct LogDuration;
+///
+/// impl SessionLayer<f32> for LogDuration {
+///     fn after_run(&self, _outputs: &[ndarray::ArrayD<f32>], stats: &RunStats) {
+///         println!("Run took {:?}", stats.duration);
+///     }
+/// }
+///
+/// let session = MockSession::with_outputs(vec![MockOutput::new(ndarray::arr1(&[0.5_f32]))]);
+/// let runner = Layered::new(session).with_layer(LogDuration);
+/// let outputs = runner.run_dyn(vec![ndarray::ArrayD::zeros(vec![1, 4])]).unwrap();
+/// ```
+pub struct Layered<R, TIn, TOut = TIn> {
+    runner: R,
+    layers: Vec<Box<dyn SessionLayer<TIn, TOut>>>,
+}
+
+impl<R, TIn, TOut> Layered<R, TIn, TOut>
+where
+    R: InferenceRunner<TIn, TOut>,
+{
+    /// `runner`, without layers yet
+    pub fn new(runner: R) -> Layered<R, TIn, TOut> {
+        Layered {
+            runner,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Add `layer` below the layers already added
+    pub fn with_layer<L>(mut self, layer: L) -> Layered<R, TIn, TOut>
+    where
+        L: SessionLayer<TIn, TOut> + 'static,
+    {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Runner the layers are stacked around
+    pub fn runner(&self) -> &R {
+        &self.runner
+    }
+}
+
+impl<R, TIn, TOut> InferenceRunner<TIn, TOut> for Layered<R, TIn, TOut>
+where
+    R: InferenceRunner<TIn, TOut>,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        Next {
+            layers: &self.layers,
+            runner: &self.runner,
+        }
+        .run(inputs)
+    }
+}
+
+impl<R, TIn, TOut> fmt::Debug for Layered<R, TIn, TOut>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Layered")
+            .field("runner", &self.runner)
+            .field("layers", &self.layers.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        session::{MockOutput, MockSession},
+        OrtError,
+    };
+    use std::sync::{Arc, Mutex};
+    use test_log::test;
+
+    struct Record {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl SessionLayer<f32> for Record {
+        fn before_run(&self, inputs: &[ArrayD<f32>]) -> Result<()> {
+            if inputs[0].iter().any(|value| value.is_nan()) {
+                return Err(OrtError::InvalidConfiguration("NaN input".to_owned()));
+            }
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("before {}", self.name));
+            Ok(())
+        }
+
+        fn after_run(&self, outputs: &[ArrayD<f32>], _stats: &RunStats) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("after {} {}", self.name, outputs.len()));
+        }
+    }
+
+    struct Constant;
+
+    impl SessionLayer<f32> for Constant {
+        fn run(
+            &self,
+            _inputs: Vec<ArrayD<f32>>,
+            _next: Next<'_, f32, f32>,
+        ) -> Result<Vec<ArrayD<f32>>> {
+            Ok(vec![ndarray::arr1(&[1.0_f32]).into_dyn()])
+        }
+    }
+
+    #[test]
+    fn layers_run_in_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let layer = |name| Record {
+            name,
+            calls: Arc::clone(&calls),
+        };
+        let session = MockSession::with_outputs(vec![MockOutput::new(ndarray::arr1(&[0.5_f32]))]);
+        let runner = Layered::new(session)
+            .with_layer(layer("outer"))
+            .with_layer(layer("inner"));
+
+        let outputs = runner
+            .run_dyn(vec![ndarray::ArrayD::zeros(vec![2])])
+            .unwrap();
+        assert_eq!(outputs[0], ndarray::arr1(&[0.5]).into_dyn());
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "before outer",
+                "before inner",
+                "after inner 1",
+                "after outer 1"
+            ]
+        );
+
+        calls.lock().unwrap().clear();
+        assert!(runner
+            .run_dyn(vec![ndarray::arr1(&[f32::NAN]).into_dyn()])
+            .is_err());
+        assert!(calls.lock().unwrap().is_empty());
+        assert_eq!(runner.runner().runs(), 1);
+    }
+
+    #[test]
+    fn layer_can_answer_runs() {
+        let session = MockSession::with_outputs(Vec::new());
+        let runner = Layered::new(session).with_layer(Constant);
+        let outputs = runner
+            .run_dyn(vec![ndarray::ArrayD::zeros(vec![2])])
+            .unwrap();
+        assert_eq!(outputs[0], ndarray::arr1(&[1.0]).into_dyn());
+        assert_eq!(runner.runner().runs(), 0);
+    }
+}
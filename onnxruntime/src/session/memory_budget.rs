@@ -0,0 +1,105 @@
+//! Module containing the check of runs against a session's GPU memory budget
+
+use crate::error::{OrtError, Result};
+
+use super::Input;
+
+/// Device memory a session may use, set with
+/// [`SessionBuilder::with_gpu_memory_budget()`](struct.SessionBuilder.html#method.with_gpu_memory_budget)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct GpuMemoryBudget {
+    /// Budget, in bytes
+    pub(super) budget_bytes: usize,
+    /// Size of the model, as an estimate of the memory taken by its weights
+    pub(super) weights_bytes: usize,
+    /// Memory taken by the activations of each item of a batch, if known
+    pub(super) activation_bytes_per_item: Option<usize>,
+}
+
+impl GpuMemoryBudget {
+    /// Memory a run on inputs of the given shapes is estimated to need
+    ///
+    /// Weights, plus the inputs themselves, plus the activations of each item of the batch.
+    /// Omitted inputs (`None`) take no memory.
+    pub(super) fn estimate<'i, I>(&self, inputs: &[Input], input_shapes: I) -> usize
+    where
+        I: Iterator<Item = Option<&'i [usize]>>,
+    {
+        let mut batch_size = 0;
+        let mut input_bytes = 0usize;
+        for (input, shape) in inputs.iter().zip(input_shapes) {
+            let shape = match shape {
+                Some(shape) => shape,
+                None => continue,
+            };
+            batch_size = batch_size.max(shape.first().copied().unwrap_or(1));
+            let element_bytes = input.input_type.size_in_bytes().unwrap_or(0);
+            input_bytes =
+                input_bytes.saturating_add(shape.iter().product::<usize>() * element_bytes);
+        }
+        let activation_bytes = self
+            .activation_bytes_per_item
+            .map_or(0, |bytes| bytes.saturating_mul(batch_size));
+        self.weights_bytes
+            .saturating_add(input_bytes)
+            .saturating_add(activation_bytes)
+    }
+
+    /// Fail if a run on inputs of the given shapes is estimated not to fit in the budget
+    pub(super) fn check<'i, I>(&self, inputs: &[Input], input_shapes: I) -> Result<()>
+    where
+        I: Iterator<Item = Option<&'i [usize]>>,
+    {
+        let estimated_bytes = self.estimate(inputs, input_shapes);
+        if estimated_bytes > self.budget_bytes {
+            return Err(OrtError::GpuMemoryBudgetExceeded {
+                estimated_bytes,
+                budget_bytes: self.budget_bytes,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TensorElementDataType;
+    use test_log::test;
+
+    #[test]
+    fn estimate_scales_with_batch_size() {
+        let inputs = vec![Input {
+            name: "images".to_owned(),
+            input_type: TensorElementDataType::Float,
+            dimensions: vec![None, Some(3), Some(224), Some(224)],
+            container: None,
+        }];
+        let budget = GpuMemoryBudget {
+            budget_bytes: 1 << 30,
+            weights_bytes: 100 << 20,
+            activation_bytes_per_item: Some(50 << 20),
+        };
+
+        let image_bytes = 3 * 224 * 224 * 4;
+        assert_eq!(
+            budget.estimate(&inputs, std::iter::once(Some(&[2, 3, 224, 224][..]))),
+            (100 << 20) + 2 * image_bytes + 2 * (50 << 20)
+        );
+        // Omitted inputs take no memory
+        assert_eq!(budget.estimate(&inputs, std::iter::once(None)), 100 << 20);
+        assert!(budget
+            .check(&inputs, std::iter::once(Some(&[16, 3, 224, 224][..])))
+            .is_ok());
+        match budget.check(&inputs, std::iter::once(Some(&[32, 3, 224, 224][..]))) {
+            Err(OrtError::GpuMemoryBudgetExceeded {
+                estimated_bytes,
+                budget_bytes,
+            }) => {
+                assert!(estimated_bytes > budget_bytes);
+                assert_eq!(budget_bytes, 1 << 30);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
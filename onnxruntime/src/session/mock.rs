@@ -0,0 +1,309 @@
+//! Module containing a fake session, for unit tests of code using sessions
+
+use std::{
+    any::Any,
+    fmt,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use ndarray::{Array, ArrayD, ArrayViewD, Dimension};
+
+use crate::{
+    error::{OrtError, Result},
+    tensor::IntoInput,
+    TensorElementDataType, TypeToTensorElementDataType,
+};
+
+use super::{InferenceRunner, Run};
+
+/// Input handed to a [`MockSession`](struct.MockSession.html)'s closure
+pub struct MockInput {
+    element_type: TensorElementDataType,
+    shape: Vec<usize>,
+    array: Box<dyn Any>,
+}
+
+impl MockInput {
+    fn new<T, D>(array: Array<T, D>) -> MockInput
+    where
+        T: TypeToTensorElementDataType + 'static,
+        D: Dimension,
+    {
+        MockInput {
+            element_type: T::tensor_element_data_type(),
+            shape: array.shape().to_vec(),
+            array: Box::new(array.into_dyn()),
+        }
+    }
+
+    /// Type of the input's elements
+    pub fn element_type(&self) -> TensorElementDataType {
+        self.element_type
+    }
+
+    /// Shape of the input
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// View of the input, if its elements are of type `T`
+    pub fn view<T>(&self) -> Result<ArrayViewD<'_, T>>
+    where
+        T: TypeToTensorElementDataType + 'static,
+    {
+        self.array
+            .downcast_ref::<ArrayD<T>>()
+            .map(ArrayD::view)
+            .ok_or_else(|| non_matching_type::<T>(self.element_type))
+    }
+}
+
+impl Debug for MockInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockInput")
+            .field("element_type", &self.element_type)
+            .field("shape", &self.shape)
+            .finish()
+    }
+}
+
+type AnyArray = Box<dyn Any + Send + Sync>;
+
+/// Output returned by a [`MockSession`](struct.MockSession.html)
+pub struct MockOutput {
+    element_type: TensorElementDataType,
+    array: AnyArray,
+    clone_array: fn(&AnyArray) -> AnyArray,
+}
+
+impl MockOutput {
+    /// Output holding `array`
+    pub fn new<T, D>(array: Array<T, D>) -> MockOutput
+    where
+        T: TypeToTensorElementDataType + Clone + Send + Sync + 'static,
+        D: Dimension,
+    {
+        MockOutput {
+            element_type: T::tensor_element_data_type(),
+            array: Box::new(array.into_dyn()),
+            clone_array: |array| {
+                Box::new(
+                    array
+                        .downcast_ref::<ArrayD<T>>()
+                        .expect("Mock output holds an array of its element type")
+                        .clone(),
+                )
+            },
+        }
+    }
+
+    /// Type of the output's elements
+    pub fn element_type(&self) -> TensorElementDataType {
+        self.element_type
+    }
+
+    fn to_array<T>(&self) -> Result<ArrayD<T>>
+    where
+        T: TypeToTensorElementDataType + Clone + 'static,
+    {
+        self.array
+            .downcast_ref::<ArrayD<T>>()
+            .cloned()
+            .ok_or_else(|| non_matching_type::<T>(self.element_type))
+    }
+}
+
+impl Clone for MockOutput {
+    fn clone(&self) -> MockOutput {
+        MockOutput {
+            element_type: self.element_type,
+            array: (self.clone_array)(&self.array),
+            clone_array: self.clone_array,
+        }
+    }
+}
+
+impl Debug for MockOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockOutput")
+            .field("element_type", &self.element_type)
+            .finish()
+    }
+}
+
+type Responder = Box<dyn FnMut(&[MockInput]) -> Result<Vec<MockOutput>> + Send>;
+
+/// Fake session, implementing [`Run`](trait.Run.html) and
+/// [`InferenceRunner`](trait.InferenceRunner.html) without a model or the runtime
+///
+/// Returns the same canned outputs for every run, or the outputs computed by a closure from the
+/// inputs, so that code using sessions can be unit-tested deterministically. Concurrent runs call
+/// the closure one at a time.
+///
+/// Requesting outputs of another element type than the ones returned fails, as with a real
+/// session.
+///
+/// # Example
+///
+/// ```
+/// # use onnxruntime::session::{MockOutput, MockSession, Run};
+/// let session = MockSession::with_closure(|inputs| {
+///     let doubled = inputs[0].view::<f32>()?.mapv(|value| value * 2.0);
+///     Ok(vec![MockOutput::new(doubled)])
+/// });
+/// let outputs = session.run::<_, f32>(vec![ndarray::arr1(&[1.0_f32, 2.0])]).unwrap();
+/// assert_eq!(outputs[0], ndarray::arr1(&[2.0, 4.0]).into_dyn());
+/// assert_eq!(session.runs(), 1);
+/// ```
+pub struct MockSession {
+    respond: Mutex<Responder>,
+    runs: AtomicUsize,
+}
+
+impl MockSession {
+    /// Mock returning `outputs` for every run, whatever the inputs
+    pub fn with_outputs(outputs: Vec<MockOutput>) -> MockSession {
+        MockSession::with_closure(move |_| Ok(outputs.clone()))
+    }
+
+    /// Mock returning the outputs computed by `respond` from the inputs
+    ///
+    /// Errors returned by `respond` are returned by the run, for example to test error handling.
+    pub fn with_closure<F>(respond: F) -> MockSession
+    where
+        F: FnMut(&[MockInput]) -> Result<Vec<MockOutput>> + Send + 'static,
+    {
+        MockSession {
+            respond: Mutex::new(Box::new(respond)),
+            runs: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of runs so far, including failed ones
+    pub fn runs(&self) -> usize {
+        self.runs.load(Ordering::SeqCst)
+    }
+
+    fn run_inputs<TOut>(&self, inputs: Vec<MockInput>) -> Result<Vec<ArrayD<TOut>>>
+    where
+        TOut: TypeToTensorElementDataType + Clone + 'static,
+    {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        let mut respond = self
+            .respond
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?");
+        respond(&inputs)?.iter().map(MockOutput::to_array).collect()
+    }
+}
+
+impl Debug for MockSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockSession")
+            .field("runs", &self.runs())
+            .finish()
+    }
+}
+
+impl Run for MockSession {
+    fn run<I, TOut>(&self, inputs: I) -> Result<Vec<ArrayD<TOut>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        <I::Item as IntoInput>::Elem: 'static,
+        TOut: TypeToTensorElementDataType + Debug + Clone + 'static,
+    {
+        self.run_inputs(
+            inputs
+                .into_iter()
+                .map(|input| MockInput::new(input.into_input()))
+                .collect(),
+        )
+    }
+}
+
+impl<TIn, TOut> InferenceRunner<TIn, TOut> for MockSession
+where
+    TIn: TypeToTensorElementDataType + 'static,
+    TOut: TypeToTensorElementDataType + Clone + 'static,
+{
+    fn run_dyn(&self, inputs: Vec<ArrayD<TIn>>) -> Result<Vec<ArrayD<TOut>>> {
+        self.run_inputs(inputs.into_iter().map(MockInput::new).collect())
+    }
+}
+
+fn non_matching_type<T>(actual: TensorElementDataType) -> OrtError
+where
+    T: TypeToTensorElementDataType,
+{
+    OrtError::NonMatchingTensorElementType {
+        requested: T::tensor_element_data_type().into(),
+        actual: actual.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn mock_session_canned_outputs() {
+        let session = MockSession::with_outputs(vec![
+            MockOutput::new(ndarray::arr2(&[[0.5_f32, 0.5]])),
+            MockOutput::new(ndarray::arr1(&[7.0_f32])),
+        ]);
+        for _ in 0..2 {
+            let outputs = session
+                .run::<_, f32>(vec![ndarray::arr1(&[1_u8, 2, 3])])
+                .unwrap();
+            assert_eq!(outputs[0].shape(), &[1, 2]);
+            assert_eq!(outputs[1], ndarray::arr1(&[7.0]).into_dyn());
+        }
+        assert!(matches!(
+            session.run::<_, i64>(vec![ndarray::arr1(&[1_u8])]),
+            Err(OrtError::NonMatchingTensorElementType { .. })
+        ));
+        assert_eq!(session.runs(), 3);
+    }
+
+    #[test]
+    fn mock_session_closure() {
+        let session = MockSession::with_closure(|inputs| {
+            assert_eq!(inputs[0].element_type(), TensorElementDataType::Int64);
+            assert!(inputs[0].view::<f32>().is_err());
+            let sum = inputs[0].view::<i64>()?.sum();
+            Ok(vec![MockOutput::new(ndarray::arr0(sum))])
+        });
+        let outputs = session
+            .run::<_, i64>(vec![ndarray::arr2(&[[1_i64, 2], [3, 4]])])
+            .unwrap();
+        assert_eq!(outputs[0], ndarray::arr0(10).into_dyn());
+    }
+
+    #[test]
+    fn mock_session_trait_object() {
+        let runner: std::sync::Arc<dyn InferenceRunner<i64, f32> + Send + Sync> =
+            std::sync::Arc::new(MockSession::with_closure(|inputs| {
+                let len = inputs[0].shape()[1];
+                Ok(vec![MockOutput::new(ndarray::Array2::<f32>::ones((
+                    1, len,
+                )))])
+            }));
+        std::thread::scope(|scope| {
+            for len in 1..4 {
+                let runner = &runner;
+                scope.spawn(move || {
+                    let outputs = runner
+                        .run_dyn(vec![ndarray::ArrayD::zeros(vec![1, len])])
+                        .unwrap();
+                    assert_eq!(outputs[0].shape(), &[1, len]);
+                });
+            }
+        });
+    }
+}
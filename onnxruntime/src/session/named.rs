@@ -0,0 +1,75 @@
+//! Module containing the matching of named inputs to the model's inputs
+
+use crate::{OrtError, Result};
+
+use super::Input;
+
+/// Order `named_inputs` as the model's `inputs`, checking that each is given exactly once
+pub(super) fn order_inputs<I, N, X>(inputs: &[Input], named_inputs: I) -> Result<Vec<X>>
+where
+    I: IntoIterator<Item = (N, X)>,
+    N: AsRef<str>,
+{
+    let mut ordered: Vec<Option<X>> = inputs.iter().map(|_| None).collect();
+    for (name, input) in named_inputs {
+        let name = name.as_ref();
+        let index = inputs
+            .iter()
+            .position(|model_input| model_input.name == name)
+            .ok_or_else(|| OrtError::UnknownInput {
+                name: name.to_owned(),
+            })?;
+        if ordered[index].replace(input).is_some() {
+            return Err(OrtError::DuplicateInput {
+                name: name.to_owned(),
+            });
+        }
+    }
+
+    ordered
+        .into_iter()
+        .zip(inputs)
+        .map(|(input, model_input)| {
+            input.ok_or_else(|| OrtError::MissingInput {
+                name: model_input.name.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TensorElementDataType;
+    use test_log::test;
+
+    fn input(name: &str) -> Input {
+        Input {
+            name: name.to_owned(),
+            input_type: TensorElementDataType::Int64,
+            dimensions: vec![None, Some(8)],
+            container: None,
+        }
+    }
+
+    #[test]
+    fn inputs_are_ordered_by_name() {
+        let inputs = [input("input_ids"), input("attention_mask")];
+
+        let ordered = order_inputs(&inputs, vec![("attention_mask", 2), ("input_ids", 1)]).unwrap();
+        assert_eq!(ordered, vec![1, 2]);
+
+        assert!(matches!(
+            order_inputs(&inputs, vec![("input_ids", 1), ("token_type_ids", 3)]),
+            Err(OrtError::UnknownInput { name }) if name == "token_type_ids"
+        ));
+        assert!(matches!(
+            order_inputs(&inputs, vec![("input_ids", 1)]),
+            Err(OrtError::MissingInput { name }) if name == "attention_mask"
+        ));
+        assert!(matches!(
+            order_inputs(&inputs, vec![("input_ids", 1), ("input_ids", 1)]),
+            Err(OrtError::DuplicateInput { name }) if name == "input_ids"
+        ));
+    }
+}
@@ -0,0 +1,184 @@
+//! Module containing the detection of `NaN` and infinite values in the outputs of runs
+
+use std::{fmt, sync::Arc};
+
+use tracing::warn;
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{OrtError, Result},
+    tensor::raw::tensor_data,
+    type_info::TypeInfo,
+    TensorElementDataType,
+};
+
+type NonFiniteHook = Arc<dyn Fn(&NonFiniteOutput<'_>) + Send + Sync>;
+
+/// Check of the outputs of runs for `NaN` and infinite values
+///
+/// Set on a session with
+/// [`SessionBuilder::with_non_finite_check()`](struct.SessionBuilder.html#method.with_non_finite_check).
+/// After each run, the floating point outputs (including `float16` and `bfloat16` ones) are
+/// scanned; useful to catch numerical issues introduced by half precision conversions or
+/// aggressive graph optimizations, at the cost of reading every output once more.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, session::NonFiniteCheck};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let mut session = environment
+///     .new_session_builder()?
+///     .with_non_finite_check(NonFiniteCheck::warn(|output| {
+///         eprintln!("{} NaN in output {}", output.nan_count, output.name)
+///     }))?
+///     .with_model_from_file("model_fp16.onnx")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct NonFiniteCheck {
+    on_non_finite: Option<NonFiniteHook>,
+}
+
+/// Output holding `NaN` or infinite values, passed to the hook of
+/// [`NonFiniteCheck::warn()`](struct.NonFiniteCheck.html#method.warn)
+#[derive(Debug)]
+pub struct NonFiniteOutput<'o> {
+    /// Name of the model output
+    pub name: &'o str,
+    /// Number of `NaN` values
+    pub nan_count: usize,
+    /// Number of infinite values
+    pub infinite_count: usize,
+}
+
+impl NonFiniteCheck {
+    /// Fail runs with an output holding `NaN` or infinite values, with
+    /// [`OrtError::NonFiniteOutput`](../error/enum.OrtError.html#variant.NonFiniteOutput)
+    pub fn fail() -> NonFiniteCheck {
+        NonFiniteCheck {
+            on_non_finite: None,
+        }
+    }
+
+    /// Call `hook` for each output holding `NaN` or infinite values, and return the outputs
+    /// anyway
+    pub fn warn<F>(hook: F) -> NonFiniteCheck
+    where
+        F: Fn(&NonFiniteOutput<'_>) + Send + Sync + 'static,
+    {
+        NonFiniteCheck {
+            on_non_finite: Some(Arc::new(hook)),
+        }
+    }
+
+    /// Check the output `name`, held by the runtime's value `value`
+    pub(super) fn check(&self, name: &str, value: *mut sys::OrtValue) -> Result<()> {
+        let tensor = match unsafe { TypeInfo::from_value(value) }? {
+            TypeInfo::Tensor(tensor) => tensor,
+            _ => return Ok(()),
+        };
+        let shape: Vec<usize> = tensor
+            .dimensions
+            .iter()
+            .map(|d| d.unwrap_or(0) as usize)
+            .collect();
+        let (nan_count, infinite_count) = match tensor.element_type {
+            Some(TensorElementDataType::Float) => {
+                count_floats(tensor_data::<f32>(value, &shape)?.iter().map(|&v| v as f64))
+            }
+            Some(TensorElementDataType::Double) => {
+                count_floats(tensor_data::<f64>(value, &shape)?.iter().copied())
+            }
+            Some(TensorElementDataType::Float16) => {
+                count_half(tensor_data::<u16>(value, &shape)?, F16_EXPONENT)
+            }
+            Some(TensorElementDataType::Bfloat16) => {
+                count_half(tensor_data::<u16>(value, &shape)?, BF16_EXPONENT)
+            }
+            _ => return Ok(()),
+        };
+        if nan_count == 0 && infinite_count == 0 {
+            return Ok(());
+        }
+
+        match &self.on_non_finite {
+            Some(hook) => {
+                warn!(
+                    output = name,
+                    nan_count, infinite_count, "Output holds non-finite values."
+                );
+                hook(&NonFiniteOutput {
+                    name,
+                    nan_count,
+                    infinite_count,
+                });
+                Ok(())
+            }
+            None => Err(OrtError::NonFiniteOutput {
+                output: name.to_owned(),
+                nan_count,
+                infinite_count,
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for NonFiniteCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NonFiniteCheck")
+            .field("on_non_finite", &self.on_non_finite.is_some())
+            .finish()
+    }
+}
+
+/// Exponent bits of `float16` and `bfloat16` values
+const F16_EXPONENT: u16 = 0x7c00;
+const BF16_EXPONENT: u16 = 0x7f80;
+
+/// Numbers of `NaN` and of infinite values of `values`
+fn count_floats(values: impl Iterator<Item = f64>) -> (usize, usize) {
+    values.fold((0, 0), |(nan, infinite), value| {
+        (
+            nan + value.is_nan() as usize,
+            infinite + value.is_infinite() as usize,
+        )
+    })
+}
+
+/// Numbers of `NaN` and of infinite values of half precision `values`, stored as their bits,
+/// given the bits of their exponent
+fn count_half(values: &[u16], exponent: u16) -> (usize, usize) {
+    let mantissa = !(exponent | 0x8000);
+    values.iter().fold((0, 0), |(nan, infinite), &bits| {
+        if bits & exponent != exponent {
+            (nan, infinite)
+        } else if bits & mantissa != 0 {
+            (nan + 1, infinite)
+        } else {
+            (nan, infinite + 1)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn non_finite_values_are_counted() {
+        let values = [1.0, f64::NAN, f64::INFINITY, -f64::INFINITY, f64::NAN];
+        assert_eq!(count_floats(values.iter().copied()), (2, 2));
+        assert_eq!(count_floats([0.0, 1e300].iter().copied()), (0, 0));
+
+        // 1.0, NaN, -inf
+        assert_eq!(count_half(&[0x3c00, 0x7e00, 0xfc00], F16_EXPONENT), (1, 1));
+        // 1.0, inf, NaN
+        assert_eq!(count_half(&[0x3f80, 0x7f80, 0x7fc0], BF16_EXPONENT), (1, 1));
+    }
+}
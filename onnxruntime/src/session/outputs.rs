@@ -0,0 +1,137 @@
+//! Module containing the outputs of a run, keyed by the model's output names
+
+use std::ops::Index;
+
+use crate::{OrtError, Result};
+
+/// Outputs of a run, looked up by the names of the model's outputs
+///
+/// Returned by [`Session::run_named_outputs()`](struct.Session.html#method.run_named_outputs).
+/// Looking outputs up by name keeps working when a re-exported model orders its outputs
+/// differently, where hard-coded indices silently pick the wrong tensor.
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, ndarray::Array2, session::Outputs};
+/// # use onnxruntime::tensor::OrtOwnedTensor;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+/// let input_ids = Array2::<i64>::zeros((1, 8));
+/// let outputs: Outputs<OrtOwnedTensor<f32, _>> = session.run_named_outputs([input_ids])?;
+/// let logits = &outputs["logits"];
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Outputs<'s, T> {
+    names: Vec<&'s str>,
+    values: Vec<T>,
+}
+
+impl<'s, T> Outputs<'s, T> {
+    /// Outputs of the given names, in the same order as `values`
+    pub(crate) fn new(names: Vec<&'s str>, values: Vec<T>) -> Outputs<'s, T> {
+        debug_assert_eq!(names.len(), values.len());
+        Outputs { names, values }
+    }
+
+    /// Output of the given name, if the model has one
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.position(name).map(|index| &self.values[index])
+    }
+
+    /// Same as [`get()`](#method.get), mutably
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.position(name)
+            .map(move |index| &mut self.values[index])
+    }
+
+    /// Take the output of the given name out of the outputs
+    ///
+    /// Fails with [`OrtError::UnknownOutput`](../error/enum.OrtError.html#variant.UnknownOutput)
+    /// if there is no (longer an) output of that name.
+    pub fn remove(&mut self, name: &str) -> Result<T> {
+        let index = self.position(name).ok_or_else(|| OrtError::UnknownOutput {
+            name: name.to_owned(),
+        })?;
+        self.names.remove(index);
+        Ok(self.values.remove(index))
+    }
+
+    /// Names of the outputs, in the model's order
+    pub fn names(&self) -> &[&'s str] {
+        &self.names
+    }
+
+    /// Number of outputs
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether there are no outputs
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterate over the `(name, output)` pairs, in the model's order
+    pub fn iter(&self) -> impl Iterator<Item = (&'s str, &T)> + '_ {
+        self.names.iter().copied().zip(&self.values)
+    }
+
+    /// Outputs in the model's order, dropping their names
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|&output| output == name)
+    }
+}
+
+impl<'s, T> Index<&str> for Outputs<'s, T> {
+    type Output = T;
+
+    /// Output of the given name
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no output of that name.
+    fn index(&self, name: &str) -> &T {
+        self.get(name)
+            .unwrap_or_else(|| panic!("No output named {:?}", name))
+    }
+}
+
+impl<'s, T> IntoIterator for Outputs<'s, T> {
+    type Item = (&'s str, T);
+    type IntoIter = std::iter::Zip<std::vec::IntoIter<&'s str>, std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names.into_iter().zip(self.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn outputs_are_looked_up_by_name() {
+        let mut outputs = Outputs::new(vec!["logits", "hidden_states"], vec![1, 2]);
+        assert_eq!(outputs.get("hidden_states"), Some(&2));
+        assert_eq!(outputs["logits"], 1);
+        assert_eq!(outputs.get("attentions"), None);
+
+        assert_eq!(outputs.remove("logits").unwrap(), 1);
+        assert!(matches!(
+            outputs.remove("logits"),
+            Err(OrtError::UnknownOutput { name }) if name == "logits"
+        ));
+        assert_eq!(
+            outputs.into_iter().collect::<Vec<_>>(),
+            vec![("hidden_states", 2)]
+        );
+    }
+}
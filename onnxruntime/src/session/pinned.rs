@@ -0,0 +1,201 @@
+//! Module containing the staging of inputs through pinned (page-locked) host memory
+
+use std::{fmt::Debug, sync::Mutex};
+
+use ndarray::{ArrayBase, Dimension};
+use tracing::debug;
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{assert_not_null_pointer, status_to_result, OrtError, Result},
+    memory::MemoryInfo,
+    AllocatorType, MemType, TensorElementDataType, TypeToTensorElementDataType,
+};
+
+use super::coercion::AsOrtValue;
+
+/// Pool of pinned host buffers, one per model input, that inputs are copied to before their
+/// upload to the device
+///
+/// Device transfers from pinned memory use DMA directly, instead of going through a pinned
+/// bounce buffer of the driver as transfers from pageable memory do. Buffers are reused from run
+/// to run as long as the input keeps its element type and shape.
+#[derive(Debug)]
+pub(super) struct PinnedStaging {
+    allocator_ptr: *mut sys::OrtAllocator,
+    min_input_bytes: usize,
+    buffers: Mutex<Vec<Option<StagingBuffer>>>,
+}
+
+impl PinnedStaging {
+    /// Staging for the inputs of `session_ptr` of at least `min_input_bytes`
+    ///
+    /// Fails if the session does not run on the CUDA execution provider.
+    pub(super) fn new(
+        session_ptr: *mut sys::OrtSession,
+        input_count: usize,
+        min_input_bytes: usize,
+    ) -> Result<PinnedStaging> {
+        let memory_info = MemoryInfo::with_name(
+            MemoryInfo::CUDA_PINNED,
+            AllocatorType::Device,
+            0,
+            MemType::CpuOutput,
+        )?;
+        let mut allocator_ptr: *mut sys::OrtAllocator = std::ptr::null_mut();
+        let status =
+            unsafe { ort_api!(CreateAllocator)?(session_ptr, memory_info.ptr, &mut allocator_ptr) };
+        status_to_result(status).map_err(OrtError::Allocator)?;
+        assert_not_null_pointer(allocator_ptr, "Allocator")?;
+
+        Ok(PinnedStaging {
+            allocator_ptr,
+            min_input_bytes,
+            buffers: Mutex::new((0..input_count).map(|_| None).collect()),
+        })
+    }
+
+    /// Copy `array`, the input of the given index, to a pinned buffer
+    ///
+    /// `None` if the input is too small to be worth staging, or holds strings.
+    pub(super) fn stage<S, T, D>(
+        &self,
+        index: usize,
+        array: &ArrayBase<S, D>,
+    ) -> Result<Option<StagedInput<'_>>>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: TypeToTensorElementDataType + Debug + Clone,
+        D: Dimension,
+    {
+        let element_type = T::tensor_element_data_type();
+        if array.is_empty()
+            || array.len() * std::mem::size_of::<T>() < self.min_input_bytes
+            || element_type == TensorElementDataType::String
+        {
+            return Ok(None);
+        }
+
+        let reusable = self
+            .buffers
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .get_mut(index)
+            .and_then(Option::take)
+            .filter(|buffer| buffer.element_type == element_type && buffer.shape == array.shape());
+        let buffer = match reusable {
+            Some(buffer) => buffer,
+            None => {
+                debug!(index, shape = ?array.shape(), "Allocating pinned staging buffer.");
+                StagingBuffer::new(self.allocator_ptr, element_type, array.shape())?
+            }
+        };
+
+        let data_ptr = buffer.data_ptr()? as *mut T;
+        for (offset, element) in array.iter().enumerate() {
+            // The buffer holds `array.len()` elements, not initialized before the first run
+            unsafe { data_ptr.add(offset).write(element.clone()) };
+        }
+
+        Ok(Some(StagedInput {
+            staging: self,
+            index,
+            buffer: Some(buffer),
+        }))
+    }
+}
+
+impl Drop for PinnedStaging {
+    fn drop(&mut self) {
+        // The buffers were allocated by the allocator: release them first
+        if let Ok(buffers) = self.buffers.get_mut() {
+            buffers.clear();
+        }
+        if let Ok(release) = ort_api!(ReleaseAllocator) {
+            unsafe { release(self.allocator_ptr) };
+        }
+    }
+}
+
+/// Pinned buffer of an input, as a tensor of the runtime
+#[derive(Debug)]
+struct StagingBuffer {
+    value_ptr: *mut sys::OrtValue,
+    element_type: TensorElementDataType,
+    shape: Vec<usize>,
+}
+
+impl StagingBuffer {
+    fn new(
+        allocator_ptr: *mut sys::OrtAllocator,
+        element_type: TensorElementDataType,
+        shape: &[usize],
+    ) -> Result<StagingBuffer> {
+        let dims: Vec<i64> = shape.iter().map(|&dim| dim as i64).collect();
+        let mut value_ptr: *mut sys::OrtValue = std::ptr::null_mut();
+        let status = unsafe {
+            ort_api!(CreateTensorAsOrtValue)?(
+                allocator_ptr,
+                dims.as_ptr(),
+                dims.len(),
+                element_type.into(),
+                &mut value_ptr,
+            )
+        };
+        status_to_result(status).map_err(OrtError::CreateTensor)?;
+        assert_not_null_pointer(value_ptr, "Value")?;
+
+        Ok(StagingBuffer {
+            value_ptr,
+            element_type,
+            shape: shape.to_vec(),
+        })
+    }
+
+    fn data_ptr(&self) -> Result<*mut std::ffi::c_void> {
+        let mut data_ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let status = unsafe { ort_api!(GetTensorMutableData)?(self.value_ptr, &mut data_ptr) };
+        status_to_result(status).map_err(OrtError::GetTensorMutableData)?;
+        assert_not_null_pointer(data_ptr, "TensorData")?;
+        Ok(data_ptr)
+    }
+}
+
+impl Drop for StagingBuffer {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseValue) {
+            unsafe { release(self.value_ptr) };
+        }
+    }
+}
+
+/// Input copied to a pinned buffer, which returns to the pool once the run is over
+pub(super) struct StagedInput<'s> {
+    staging: &'s PinnedStaging,
+    index: usize,
+    buffer: Option<StagingBuffer>,
+}
+
+impl<'s> AsOrtValue for StagedInput<'s> {
+    fn c_ptr(&self) -> *mut sys::OrtValue {
+        self.buffer
+            .as_ref()
+            .expect("Buffer is only taken back when dropped")
+            .value_ptr
+    }
+}
+
+impl<'s> Drop for StagedInput<'s> {
+    fn drop(&mut self) {
+        let mut buffers = self
+            .staging
+            .buffers
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?");
+        // When concurrent runs staged the same input, only one buffer is kept
+        if let Some(slot @ None) = buffers.get_mut(self.index) {
+            *slot = self.buffer.take();
+        }
+    }
+}
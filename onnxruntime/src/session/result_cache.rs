@@ -0,0 +1,275 @@
+//! Module containing a layer answering repeated runs from a cache of outputs
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use ndarray::ArrayD;
+
+use crate::error::Result;
+
+use super::{Next, SessionLayer};
+
+/// Layer answering runs whose inputs were already seen with the outputs of the earlier run
+///
+/// Inputs are identified by a key computed by the caller's function, typically a hash of the
+/// inputs, or of the content they were built from (the text given to an embedding model for
+/// example). Only suitable for deterministic models: cached outputs are returned as they were.
+///
+/// Once `capacity` entries are held, the least recently used one is evicted; entries older than
+/// the time to live (see [`with_ttl()`](#method.with_ttl)) are not used. Concurrent runs of inputs
+/// not cached yet both run the model. Share the cache with an `Arc` to read its statistics.
+///
+/// # Example
+///
+/// ```
+/// # use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}, time::Duration};
+/// # use onnxruntime::session::{InferenceRunner, Layered, MockOutput, MockSession, ResultCache};
+/// let session = MockSession::with_outputs(vec![MockOutput::new(ndarray::arr1(&[0.5_f32]))]);
+/// let cache = ResultCache::new(10_000, |inputs: &[ndarray::ArrayD<i64>]| {
+///     let mut hasher = DefaultHasher::new();
+///     inputs.hash(&mut hasher);
+///     hasher.finish()
+/// })
+/// .with_ttl(Duration::from_secs(600));
+/// let runner = Layered::new(session).with_layer(cache);
+///
+/// let token_ids = ndarray::arr2(&[[101_i64, 2023, 102]]).into_dyn();
+/// for _ in 0..3 {
+///     let outputs: Vec<ndarray::ArrayD<f32>> = runner.run_dyn(vec![token_ids.clone()]).unwrap();
+/// }
+/// assert_eq!(runner.runner().runs(), 1);
+/// ```
+pub struct ResultCache<K, F, TOut> {
+    key: F,
+    capacity: usize,
+    ttl: Option<Duration>,
+    entries: Mutex<Entries<K, TOut>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Cached outputs, with the order in which they were last used
+struct Entries<K, TOut> {
+    by_key: HashMap<K, Entry<TOut>>,
+    by_use: BTreeMap<u64, K>,
+    next_use: u64,
+}
+
+struct Entry<TOut> {
+    outputs: Vec<ArrayD<TOut>>,
+    inserted: Instant,
+    last_use: u64,
+}
+
+impl<K, F, TOut> ResultCache<K, F, TOut>
+where
+    K: Hash + Eq + Clone,
+{
+    /// Cache of up to `capacity` outputs, keyed by `key` applied to the inputs
+    pub fn new(capacity: usize, key: F) -> ResultCache<K, F, TOut> {
+        ResultCache {
+            key,
+            capacity,
+            ttl: None,
+            entries: Mutex::new(Entries {
+                by_key: HashMap::new(),
+                by_use: BTreeMap::new(),
+                next_use: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Only use outputs cached less than `ttl` ago
+    pub fn with_ttl(mut self, ttl: Duration) -> ResultCache<K, F, TOut> {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Number of runs answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of runs not answered from the cache
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of outputs currently cached, expired ones included until evicted
+    pub fn len(&self) -> usize {
+        self.lock().by_key.len()
+    }
+
+    /// Whether no outputs are cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all cached outputs
+    pub fn clear(&self) {
+        let mut entries = self.lock();
+        entries.by_key.clear();
+        entries.by_use.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries<K, TOut>> {
+        self.entries
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+    }
+
+    /// Outputs cached for `key`, if still valid, marking them as used
+    fn get(&self, key: &K) -> Option<Vec<ArrayD<TOut>>>
+    where
+        TOut: Clone,
+    {
+        let mut entries = self.lock();
+        let entries = &mut *entries;
+        let entry = entries.by_key.get_mut(key)?;
+        if let Some(ttl) = self.ttl {
+            if entry.inserted.elapsed() >= ttl {
+                entries.by_use.remove(&entry.last_use);
+                entries.by_key.remove(key);
+                return None;
+            }
+        }
+        entries.by_use.remove(&entry.last_use);
+        entry.last_use = entries.next_use;
+        entries.by_use.insert(entries.next_use, key.clone());
+        entries.next_use += 1;
+        Some(entry.outputs.clone())
+    }
+
+    /// Cache `outputs` for `key`, evicting the least recently used outputs if full
+    fn insert(&self, key: K, outputs: Vec<ArrayD<TOut>>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.lock();
+        let entries = &mut *entries;
+        if let Some(entry) = entries.by_key.remove(&key) {
+            entries.by_use.remove(&entry.last_use);
+        }
+        while entries.by_key.len() >= self.capacity {
+            let (_, evicted) = entries.by_use.pop_first().expect("Every entry has a use");
+            entries.by_key.remove(&evicted);
+        }
+        let last_use = entries.next_use;
+        entries.next_use += 1;
+        entries.by_use.insert(last_use, key.clone());
+        entries.by_key.insert(
+            key,
+            Entry {
+                outputs,
+                inserted: Instant::now(),
+                last_use,
+            },
+        );
+    }
+}
+
+impl<K, F, TIn, TOut> SessionLayer<TIn, TOut> for ResultCache<K, F, TOut>
+where
+    K: Hash + Eq + Clone + Send,
+    F: Fn(&[ArrayD<TIn>]) -> K + Send + Sync,
+    TOut: Clone + Send,
+{
+    fn run(
+        &self,
+        inputs: Vec<ArrayD<TIn>>,
+        next: Next<'_, TIn, TOut>,
+    ) -> Result<Vec<ArrayD<TOut>>> {
+        let key = (self.key)(&inputs);
+        if let Some(outputs) = self.get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(outputs);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let outputs = next.run(inputs)?;
+        self.insert(key, outputs.clone());
+        Ok(outputs)
+    }
+}
+
+impl<K, F, TOut> fmt::Debug for ResultCache<K, F, TOut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResultCache")
+            .field("capacity", &self.capacity)
+            .field("ttl", &self.ttl)
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{InferenceRunner, Layered, MockOutput, MockSession};
+    use std::sync::Arc;
+    use test_log::test;
+
+    fn first_input(inputs: &[ArrayD<i64>]) -> i64 {
+        inputs[0][[0]]
+    }
+
+    type Cache = ResultCache<i64, fn(&[ArrayD<i64>]) -> i64, f32>;
+
+    fn runner(cache: Arc<Cache>) -> Layered<MockSession, i64, f32> {
+        let session = MockSession::with_closure(|inputs| {
+            let value = inputs[0].view::<i64>()?[[0]] as f32;
+            Ok(vec![MockOutput::new(ndarray::arr1(&[value]))])
+        });
+        Layered::new(session).with_layer(cache)
+    }
+
+    fn run(runner: &Layered<MockSession, i64, f32>, value: i64) -> f32 {
+        let outputs = runner
+            .run_dyn(vec![ndarray::arr1(&[value]).into_dyn()])
+            .unwrap();
+        outputs[0][[0]]
+    }
+
+    #[test]
+    fn repeated_inputs_are_answered_from_cache() {
+        let cache = Arc::new(ResultCache::new(
+            2,
+            first_input as fn(&[ArrayD<i64>]) -> i64,
+        ));
+        let runner = runner(Arc::clone(&cache));
+        assert_eq!(run(&runner, 1), 1.0);
+        assert_eq!(run(&runner, 1), 1.0);
+        assert_eq!(run(&runner, 2), 2.0);
+        assert_eq!(runner.runner().runs(), 2);
+
+        // 1 was used more recently than 2, which is evicted
+        assert_eq!(run(&runner, 1), 1.0);
+        assert_eq!(run(&runner, 3), 3.0);
+        assert_eq!(runner.runner().runs(), 3);
+        assert_eq!(run(&runner, 1), 1.0);
+        assert_eq!(runner.runner().runs(), 3);
+        assert_eq!(run(&runner, 2), 2.0);
+        assert_eq!(runner.runner().runs(), 4);
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (3, 4, 2));
+    }
+
+    #[test]
+    fn expired_outputs_are_not_used() {
+        let cache =
+            ResultCache::new(10, first_input as fn(&[ArrayD<i64>]) -> i64).with_ttl(Duration::ZERO);
+        let runner = runner(Arc::new(cache));
+        run(&runner, 1);
+        run(&runner, 1);
+        assert_eq!(runner.runner().runs(), 2);
+    }
+}
@@ -0,0 +1,285 @@
+//! Module containing the retry policy of inference runs failing transiently
+
+use std::{fmt, sync::Arc, thread, time::Duration};
+
+use tracing::warn;
+
+use crate::error::{OrtError, Result};
+
+use super::RunOptions;
+
+type Classifier = Arc<dyn Fn(&OrtError) -> bool + Send + Sync>;
+type RetryHook = Arc<dyn Fn(&RetryAttempt<'_>) + Send + Sync>;
+
+/// Retries of runs failing with transient errors, such as a GPU running out of memory
+///
+/// Set on a session with [`SessionBuilder::with_retry_policy()`](struct.SessionBuilder.html#method.with_retry_policy).
+/// A failed run is retried, after waiting for an exponentially growing backoff, as long as its
+/// error is classified as transient and the maximum number of attempts is not reached. The
+/// error of the last attempt is returned otherwise.
+///
+/// By default, errors are classified with [`is_transient()`](fn.is_transient.html).
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::{error::Error, time::Duration};
+/// # use onnxruntime::{environment::Environment, session::RetryPolicy};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let policy = RetryPolicy::new(3)
+///     .with_backoff(Duration::from_millis(100), Duration::from_secs(2))
+///     .with_arena_shrinkage("gpu:0")
+///     .on_retry(|attempt| eprintln!("run failed ({}), retrying", attempt.error));
+/// let mut session = environment
+///     .new_session_builder()?
+///     .with_retry_policy(policy)?
+///     .with_model_from_file("model.onnx")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    arena_shrinkage: Option<String>,
+    classifier: Classifier,
+    on_retry: Option<RetryHook>,
+}
+
+/// Failed attempt about to be retried, passed to the hook of
+/// [`RetryPolicy::on_retry()`](struct.RetryPolicy.html#method.on_retry)
+#[derive(Debug)]
+pub struct RetryAttempt<'e> {
+    /// Number of the attempt which failed, starting at 1
+    pub attempt: u32,
+    /// Error of the attempt
+    pub error: &'e OrtError,
+    /// Time waited before the next attempt
+    pub backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Run at most `max_attempts` times (zero is treated as one), waiting 50 ms before the first
+    /// retry and doubling the wait up to 1 s
+    pub fn new(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(1),
+            multiplier: 2.0,
+            arena_shrinkage: None,
+            classifier: Arc::new(is_transient),
+            on_retry: None,
+        }
+    }
+
+    /// Wait `initial` before the first retry, doubling the wait up to `max` for the next ones
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> RetryPolicy {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+
+    /// Multiply the wait by `multiplier` after each retry instead of doubling it
+    pub fn with_backoff_multiplier(mut self, multiplier: f64) -> RetryPolicy {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Retry the errors for which `classifier` returns `true`, instead of the ones recognized
+    /// by [`is_transient()`](fn.is_transient.html)
+    pub fn with_classifier<F>(mut self, classifier: F) -> RetryPolicy
+    where
+        F: Fn(&OrtError) -> bool + Send + Sync + 'static,
+    {
+        self.classifier = Arc::new(classifier);
+        self
+    }
+
+    /// Shrink the memory arenas of `devices` (for example `"gpu:0"` or `"cpu:0;gpu:0"`) at the
+    /// end of the attempts following a failure
+    ///
+    /// The memory the arenas hold but do not use is then returned to the device, instead of
+    /// staying reserved after an out of memory error. Only applies to runs without
+    /// [`RunOptions`](struct.RunOptions.html) of their own.
+    pub fn with_arena_shrinkage<S>(mut self, devices: S) -> RetryPolicy
+    where
+        S: Into<String>,
+    {
+        self.arena_shrinkage = Some(devices.into());
+        self
+    }
+
+    /// Call `hook` before waiting for each retry, for example to release memory held elsewhere
+    /// in the process or to count retries
+    pub fn on_retry<F>(mut self, hook: F) -> RetryPolicy
+    where
+        F: Fn(&RetryAttempt<'_>) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(hook));
+        self
+    }
+
+    /// Call `run` until it succeeds or fails with an error not to be retried
+    ///
+    /// `run` is given the options of the attempt: `run_options` (or, without them, options
+    /// tagging the run with `run_tag`) or, once a retry shrinks the arenas, options doing so.
+    pub(super) fn retry<F>(
+        &self,
+        run_options: Option<&RunOptions>,
+        run_tag: Option<&str>,
+        mut run: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Option<&RunOptions>) -> Result<()>,
+    {
+        let tagged_options = match run_options {
+            None => run_tag.map(RunOptions::tagged).transpose()?,
+            Some(_) => None,
+        };
+        let run_options_or_tagged = run_options.or(tagged_options.as_ref());
+        let mut shrinking_options: Option<RunOptions> = None;
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            let error = match run(shrinking_options.as_ref().or(run_options_or_tagged)) {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            };
+            if attempt >= self.max_attempts || !(self.classifier)(&error) {
+                return Err(error);
+            }
+
+            warn!(
+                attempt,
+                backoff_ms = backoff.as_millis() as u64,
+                error = error.to_string().as_str(),
+                "Run failed with a transient error, retrying."
+            );
+            if let Some(hook) = &self.on_retry {
+                hook(&RetryAttempt {
+                    attempt,
+                    error: &error,
+                    backoff,
+                });
+            }
+            if let (None, None, Some(devices)) =
+                (run_options, &shrinking_options, &self.arena_shrinkage)
+            {
+                let options = match run_tag {
+                    Some(tag) => RunOptions::tagged(tag)?,
+                    None => RunOptions::new()?,
+                };
+                shrinking_options = Some(options.with_arena_shrinkage(devices)?);
+            }
+
+            thread::sleep(backoff);
+            backoff = backoff.mul_f64(self.multiplier).min(self.max_backoff);
+            attempt += 1;
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("multiplier", &self.multiplier)
+            .field("arena_shrinkage", &self.arena_shrinkage)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+/// Whether a run failed in a way worth retrying: the device ran out of memory, or the execution
+/// provider reported a failure of its own
+///
+/// The runtime only reports errors as messages, so they are recognized by their wording; errors
+/// from invalid inputs or a broken model are never transient.
+pub fn is_transient(error: &OrtError) -> bool {
+    const TRANSIENT_MESSAGES: &[&str] = &[
+        "out of memory",
+        "Failed to allocate memory",
+        "cudaErrorMemoryAllocation",
+        "CUBLAS_STATUS_ALLOC_FAILED",
+        "CUDNN_STATUS_ALLOC_FAILED",
+        "EP_FAIL",
+    ];
+
+    match error {
+        OrtError::Run(error) => {
+            let message = error.to_string();
+            TRANSIENT_MESSAGES
+                .iter()
+                .any(|pattern| message.contains(pattern))
+        }
+        OrtError::RunWithInputs { source, .. } | OrtError::RunWithLogs { source, .. } => {
+            is_transient(source)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::OrtApiError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use test_log::test;
+
+    fn run_error(message: &str) -> OrtError {
+        OrtError::Run(OrtApiError::Msg(message.to_owned()))
+    }
+
+    #[test]
+    fn transient_errors() {
+        assert!(is_transient(&run_error(
+            "Non-zero status code returned while running Conv node. Name:'conv1' Status Message: \
+             /onnxruntime_src/onnxruntime/core/framework/bfc_arena.cc:376 void* \
+             onnxruntime::BFCArena::AllocateRawInternal(size_t, bool) Failed to allocate memory \
+             for requested buffer of size 1073741824"
+        )));
+        assert!(is_transient(&run_error(
+            "CUDA failure 2: out of memory ; GPU=0 ; hostname=gpu-1"
+        )));
+        assert!(!is_transient(&run_error(
+            "Got invalid dimensions for input: input for the following indices index: 1 Got: 4 Expected: 3"
+        )));
+        assert!(!is_transient(&OrtError::DeadlineExceeded));
+    }
+
+    #[test]
+    fn retries_transient_errors_only() {
+        let policy = RetryPolicy::new(3).with_backoff(Duration::ZERO, Duration::ZERO);
+
+        let calls = AtomicU32::new(0);
+        let result = policy.retry(None, None, |_| match calls.fetch_add(1, Ordering::SeqCst) {
+            0 => Err(run_error("CUDA failure 2: out of memory")),
+            _ => Ok(()),
+        });
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let calls = AtomicU32::new(0);
+        let result = policy.retry(None, None, |_| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(run_error("Invalid Feed Input Name:x"))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let hook_retries = Arc::clone(&retries);
+        let policy = policy.on_retry(move |attempt| {
+            hook_retries.store(attempt.attempt, Ordering::SeqCst);
+        });
+        let result = policy.retry(None, None, |_| Err(run_error("EP_FAIL")));
+        assert!(is_transient(&result.unwrap_err()));
+        assert_eq!(retries.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,380 @@
+//! Module containing a router splitting runs between two versions of a model
+
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU32, AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use ndarray::ArrayD;
+
+use crate::{error::Result, tensor::IntoInput, TypeToTensorElementDataType};
+
+use super::SwappableSession;
+
+/// Shares are expressed in basis points (hundredths of a percent)
+const FULL_SHARE: u32 = 10_000;
+
+/// Version of the model a run was routed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Version currently in production
+    Control,
+    /// Version being rolled out
+    Candidate,
+}
+
+impl Variant {
+    fn index(self) -> usize {
+        match self {
+            Variant::Control => 0,
+            Variant::Candidate => 1,
+        }
+    }
+}
+
+/// Numeric element types whose values are summarized in [`VariantStats`](struct.VariantStats.html)
+pub trait NumericElement: TypeToTensorElementDataType + Debug + Clone {
+    /// Value as a `f64`, rounded for 64 bits integers beyond 2^53
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_numeric_element {
+    ($($type_:ty),*) => {
+        $(
+            impl NumericElement for $type_ {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_element!(f32, f64, u8, i8, u16, i16, u32, i32, u64, i64);
+
+/// Summary of the values of one output over all runs of a variant
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutputSummary {
+    /// Number of elements
+    pub elements: u64,
+    /// Sum of the elements
+    pub sum: f64,
+    /// Smallest element, ignoring NaN
+    pub min: f64,
+    /// Largest element, ignoring NaN
+    pub max: f64,
+}
+
+impl OutputSummary {
+    fn new() -> OutputSummary {
+        OutputSummary {
+            elements: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Mean of the elements, `None` if there are none
+    pub fn mean(&self) -> Option<f64> {
+        match self.elements {
+            0 => None,
+            elements => Some(self.sum / elements as f64),
+        }
+    }
+}
+
+/// Latencies and outputs of the runs routed to a variant
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VariantStats {
+    /// Number of successful runs
+    pub runs: u64,
+    /// Number of failed runs
+    pub errors: u64,
+    /// Total duration of the successful runs
+    pub total_latency: Duration,
+    /// Longest successful run
+    pub max_latency: Duration,
+    /// Summary of each output, in the order of the model's outputs
+    pub outputs: Vec<OutputSummary>,
+}
+
+impl VariantStats {
+    /// Mean duration of the successful runs, `None` if there are none
+    pub fn mean_latency(&self) -> Option<Duration> {
+        match self.runs {
+            0 => None,
+            runs => Some(Duration::from_nanos(
+                (self.total_latency.as_nanos() / u128::from(runs)) as u64,
+            )),
+        }
+    }
+
+    fn record<T>(&mut self, latency: Duration, outputs: &Result<Vec<ArrayD<T>>>)
+    where
+        T: NumericElement,
+    {
+        let outputs = match outputs {
+            Ok(outputs) => outputs,
+            Err(_) => {
+                self.errors += 1;
+                return;
+            }
+        };
+        self.runs += 1;
+        self.total_latency += latency;
+        self.max_latency = self.max_latency.max(latency);
+        if self.outputs.len() < outputs.len() {
+            self.outputs.resize(outputs.len(), OutputSummary::new());
+        }
+        for (summary, output) in self.outputs.iter_mut().zip(outputs) {
+            for value in output.iter().map(NumericElement::to_f64) {
+                summary.elements += 1;
+                summary.sum += value;
+                summary.min = summary.min.min(value);
+                summary.max = summary.max.max(value);
+            }
+        }
+    }
+}
+
+/// Router splitting runs between a control and a candidate version of a model
+///
+/// Runs are routed to the candidate either by percentage (evenly interleaved, so that 10% means
+/// exactly one run out of ten) or by key, for example a user id, so that a given key always sees
+/// the same version. Latencies and output values are recorded per variant, to compare both
+/// versions before promoting the candidate.
+///
+/// Both variants are [`SwappableSession`](struct.SwappableSession.html)s: a rollout swaps the
+/// candidate in as control once validated, then sets the candidate's share back to 0.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, session::{AbRouter, SwappableSession, Variant}};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let control = environment.new_session_builder()?.with_model_from_file("v1.onnx")?;
+/// let candidate = environment.new_session_builder()?.with_model_from_file("v2.onnx")?;
+/// let router = AbRouter::new(
+///     SwappableSession::new(control),
+///     SwappableSession::new(candidate),
+///     5.0,
+/// );
+///
+/// let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224));
+/// let (variant, outputs) = router.run_keyed::<_, _, f32>("user-42", [input])?;
+///
+/// let candidate_stats = router.stats(Variant::Candidate);
+/// println!("Candidate mean latency: {:?}", candidate_stats.mean_latency());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AbRouter<'a> {
+    sessions: [SwappableSession<'a>; 2],
+    splitter: Splitter,
+    stats: [Mutex<VariantStats>; 2],
+}
+
+impl<'a> AbRouter<'a> {
+    /// Route `candidate_percentage` percent of the runs to `candidate`, the others to `control`
+    pub fn new(
+        control: SwappableSession<'a>,
+        candidate: SwappableSession<'a>,
+        candidate_percentage: f64,
+    ) -> AbRouter<'a> {
+        AbRouter {
+            sessions: [control, candidate],
+            splitter: Splitter::new(candidate_percentage),
+            stats: Default::default(),
+        }
+    }
+
+    /// Percentage of the runs routed to the candidate
+    pub fn candidate_percentage(&self) -> f64 {
+        self.splitter.percentage()
+    }
+
+    /// Change the percentage of the runs routed to the candidate, clamped to 0 to 100
+    pub fn set_candidate_percentage(&self, candidate_percentage: f64) {
+        self.splitter.set_percentage(candidate_percentage);
+    }
+
+    /// Session of `variant`, for example to swap in a new version
+    pub fn session(&self, variant: Variant) -> &SwappableSession<'a> {
+        &self.sessions[variant.index()]
+    }
+
+    /// Run the inputs through the variant picked by percentage
+    pub fn run<I, TOut>(&self, inputs: I) -> Result<(Variant, Vec<ArrayD<TOut>>)>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: NumericElement,
+    {
+        let variant = self.splitter.route();
+        self.run_on(variant, inputs)
+            .map(|outputs| (variant, outputs))
+    }
+
+    /// Run the inputs through the variant picked by hashing `key`
+    ///
+    /// The hash is stable across processes and versions of the crate, so a key keeps the same
+    /// variant as long as the percentage does not change.
+    pub fn run_keyed<K, I, TOut>(&self, key: K, inputs: I) -> Result<(Variant, Vec<ArrayD<TOut>>)>
+    where
+        K: AsRef<[u8]>,
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: NumericElement,
+    {
+        let variant = self.splitter.route_key(key.as_ref());
+        self.run_on(variant, inputs)
+            .map(|outputs| (variant, outputs))
+    }
+
+    /// Run the inputs through `variant`, recording the run in its statistics
+    pub fn run_on<I, TOut>(&self, variant: Variant, inputs: I) -> Result<Vec<ArrayD<TOut>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: NumericElement,
+    {
+        let start = Instant::now();
+        let outputs = self.sessions[variant.index()].run(inputs);
+        let latency = start.elapsed();
+        self.stats[variant.index()]
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .record(latency, &outputs);
+        outputs
+    }
+
+    /// Statistics of the runs routed to `variant`
+    pub fn stats(&self, variant: Variant) -> VariantStats {
+        self.stats[variant.index()]
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .clone()
+    }
+
+    /// Clear the statistics of both variants, for example after swapping a new candidate in
+    pub fn reset_stats(&self) {
+        for stats in &self.stats {
+            *stats
+                .lock()
+                .expect("Failed to acquire lock: another thread panicked?") =
+                VariantStats::default();
+        }
+    }
+}
+
+/// Picks the variant of each run
+#[derive(Debug)]
+struct Splitter {
+    candidate_share: AtomicU32,
+    requests: AtomicU64,
+}
+
+impl Splitter {
+    fn new(percentage: f64) -> Splitter {
+        let splitter = Splitter {
+            candidate_share: AtomicU32::new(0),
+            requests: AtomicU64::new(0),
+        };
+        splitter.set_percentage(percentage);
+        splitter
+    }
+
+    fn percentage(&self) -> f64 {
+        f64::from(self.candidate_share.load(Ordering::Relaxed)) / 100.0
+    }
+
+    fn set_percentage(&self, percentage: f64) {
+        let share = (percentage.clamp(0.0, 100.0) * 100.0).round() as u32;
+        self.candidate_share.store(share, Ordering::Relaxed);
+    }
+
+    /// Route the candidate's share of requests to it, evenly spread
+    fn route(&self) -> Variant {
+        let share = u64::from(self.candidate_share.load(Ordering::Relaxed));
+        let request = self.requests.fetch_add(1, Ordering::Relaxed);
+        // The candidate gets a request each time the count of its due requests goes up
+        let due = |requests: u64| u128::from(requests) * u128::from(share) / u128::from(FULL_SHARE);
+        if due(request + 1) > due(request) {
+            Variant::Candidate
+        } else {
+            Variant::Control
+        }
+    }
+
+    fn route_key(&self, key: &[u8]) -> Variant {
+        // 64 bits FNV-1a
+        let hash = key.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        if hash % u64::from(FULL_SHARE) < u64::from(self.candidate_share.load(Ordering::Relaxed)) {
+            Variant::Candidate
+        } else {
+            Variant::Control
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn splitter_route() {
+        let splitter = Splitter::new(25.0);
+        let routes: Vec<Variant> = (0..8).map(|_| splitter.route()).collect();
+        let candidates =
+            |routes: &[Variant]| routes.iter().filter(|v| **v == Variant::Candidate).count();
+        assert_eq!(candidates(&routes[..4]), 1);
+        assert_eq!(candidates(&routes[4..]), 1);
+
+        splitter.set_percentage(150.0);
+        assert_eq!(splitter.percentage(), 100.0);
+        assert!((0..10).all(|_| splitter.route() == Variant::Candidate));
+        splitter.set_percentage(0.0);
+        assert!((0..10).all(|_| splitter.route() == Variant::Control));
+
+        splitter.set_percentage(50.0);
+        let variant = splitter.route_key(b"user-42");
+        assert!((0..10).all(|_| splitter.route_key(b"user-42") == variant));
+        let candidates = (0..1000)
+            .filter(|i| splitter.route_key(format!("user-{}", i).as_bytes()) == Variant::Candidate)
+            .count();
+        assert!((400..600).contains(&candidates), "{}", candidates);
+    }
+
+    #[test]
+    fn variant_stats_record() {
+        let mut stats = VariantStats::default();
+        let outputs = vec![ndarray::arr1(&[1.0_f32, 3.0]).into_dyn()];
+        stats.record(Duration::from_millis(10), &Ok(outputs));
+        let outputs = vec![ndarray::arr1(&[-4.0_f32]).into_dyn()];
+        stats.record(Duration::from_millis(20), &Ok(outputs));
+        stats.record::<f32>(
+            Duration::from_millis(1),
+            &Err(crate::OrtError::DeadlineExceeded),
+        );
+
+        assert_eq!(stats.runs, 2);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.mean_latency(), Some(Duration::from_millis(15)));
+        assert_eq!(stats.max_latency, Duration::from_millis(20));
+        let summary = stats.outputs[0];
+        assert_eq!(summary.elements, 3);
+        assert_eq!(summary.mean(), Some(0.0));
+        assert_eq!((summary.min, summary.max), (-4.0, 3.0));
+    }
+}
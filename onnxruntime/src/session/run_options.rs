@@ -0,0 +1,248 @@
+//! Module containing options passed to a single inference run
+
+use std::ffi::CString;
+
+use tracing::debug;
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{assert_not_null_pointer, status_to_result, OrtError, Result},
+    log_capture::{LogCapture, LogMessage},
+    LoggingLevel,
+};
+
+/// Run configuration key listing the arenas to shrink when a run ends
+const ARENA_SHRINKAGE_KEY: &str = "memory.enable_memory_arena_shrinkage";
+
+/// Options of a single inference run, passed to
+/// [`Session::run_with_options()`](struct.Session.html#method.run_with_options)
+///
+/// Mostly useful to debug a given request in production: its logging can be made verbose, and
+/// its messages captured, without changing the logging level of the whole environment. Tagging
+/// the run with the id of the request correlates the runtime's messages with it.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, session::RunOptions, tensor::OrtOwnedTensor};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+/// let options = RunOptions::new()?.with_verbose_logging()?;
+/// let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224));
+/// let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run_with_options([input], &options)?;
+/// for message in options.take_logs() {
+///     println!("{:?} {}", message.level, message.message);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RunOptions {
+    ptr: *mut sys::OrtRunOptions,
+    log_capture: Option<LogCapture>,
+    run_tag: Option<String>,
+}
+
+// The runtime reads run options while the run executes; setting the terminate flag from another
+// thread is the documented way of cancelling a run.
+unsafe impl Send for RunOptions {}
+unsafe impl Sync for RunOptions {}
+
+impl RunOptions {
+    /// Default options
+    pub fn new() -> Result<RunOptions> {
+        let mut ptr: *mut sys::OrtRunOptions = std::ptr::null_mut();
+        let status = unsafe { ort_api!(CreateRunOptions)?(&mut ptr) };
+        status_to_result(status).map_err(OrtError::RunOptions)?;
+        assert_not_null_pointer(ptr, "RunOptions")?;
+        Ok(RunOptions {
+            ptr,
+            log_capture: None,
+            run_tag: None,
+        })
+    }
+
+    /// Log the messages of the run from `level` up, whatever the environment's logging level
+    pub fn with_log_severity(self, level: LoggingLevel) -> Result<RunOptions> {
+        let status = unsafe {
+            ort_api!(RunOptionsSetRunLogSeverityLevel)?(
+                self.ptr,
+                sys::OrtLoggingLevel::from(level) as i32,
+            )
+        };
+        status_to_result(status).map_err(OrtError::RunOptions)?;
+        Ok(self)
+    }
+
+    /// Log the verbose messages of the run up to `verbosity` (the higher, the more detailed)
+    ///
+    /// Only has an effect with a [`LoggingLevel::Verbose`](../enum.LoggingLevel.html#variant.Verbose)
+    /// severity, and only with runtimes built with verbose logging, such as debug builds: release
+    /// builds leave most verbose messages out.
+    pub fn with_log_verbosity(self, verbosity: i32) -> Result<RunOptions> {
+        let status = unsafe { ort_api!(RunOptionsSetRunLogVerbosityLevel)?(self.ptr, verbosity) };
+        status_to_result(status).map_err(OrtError::RunOptions)?;
+        Ok(self)
+    }
+
+    /// Tag the run with `tag`, used by the runtime as the log id of the run's messages
+    ///
+    /// Overridden by [`with_log_capture()`](#method.with_log_capture), which tags the run with a
+    /// log id of its own.
+    ///
+    /// ```no_run
+    /// # use onnxruntime::{session::RunOptions, LoggingLevel};
+    /// # fn main() -> onnxruntime::Result<()> {
+    /// let options = RunOptions::new()?
+    ///     .with_log_severity(LoggingLevel::Info)?
+    ///     .with_run_tag("request-42")?;
+    /// assert_eq!(options.run_tag(), Some("request-42"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_run_tag(mut self, tag: &str) -> Result<RunOptions> {
+        self.set_run_tag(tag)?;
+        Ok(self)
+    }
+
+    /// Default options, tagging the run with `tag`
+    pub(crate) fn tagged(tag: &str) -> Result<RunOptions> {
+        RunOptions::new()?.with_run_tag(tag)
+    }
+
+    /// Tag of the run, used as the log id of its messages, if set
+    ///
+    /// The session logs it with its own messages about the run, so that they can be correlated
+    /// with the runtime's.
+    pub fn run_tag(&self) -> Option<&str> {
+        self.run_tag.as_deref()
+    }
+
+    fn set_run_tag(&mut self, tag: &str) -> Result<()> {
+        let c_tag = CString::new(tag)?;
+        let status = unsafe { ort_api!(RunOptionsSetRunTag)?(self.ptr, c_tag.as_ptr()) };
+        status_to_result(status).map_err(OrtError::RunOptions)?;
+        self.run_tag = Some(tag.to_owned());
+        Ok(())
+    }
+
+    /// Keep the messages logged by the runtime during the run
+    ///
+    /// They are returned by [`take_logs()`](#method.take_logs), and attached to the error of a
+    /// failed run (see [`OrtError::RunWithLogs`](../error/enum.OrtError.html#variant.RunWithLogs)).
+    /// They are still forwarded to `tracing` as well. The run is tagged with a log id of its own
+    /// for this purpose.
+    pub fn with_log_capture(mut self) -> Result<RunOptions> {
+        let log_capture = LogCapture::for_run();
+        self.set_run_tag(log_capture.logid())?;
+        self.log_capture = Some(log_capture);
+        Ok(self)
+    }
+
+    /// Log all messages of the run, at the highest verbosity, and capture them
+    ///
+    /// Shorthand for [`with_log_severity(LoggingLevel::Verbose)`](#method.with_log_severity),
+    /// [`with_log_verbosity()`](#method.with_log_verbosity) and
+    /// [`with_log_capture()`](#method.with_log_capture).
+    pub fn with_verbose_logging(self) -> Result<RunOptions> {
+        self.with_log_severity(LoggingLevel::Verbose)?
+            .with_log_verbosity(i32::MAX)?
+            .with_log_capture()
+    }
+
+    /// Shrink the memory arenas of `devices` (for example `"gpu:0"` or `"cpu:0;gpu:0"`) when the
+    /// run ends
+    ///
+    /// The memory the arenas hold but the run no longer uses is returned to the device, at the
+    /// cost of allocating it again on the next run. Useful after an unusually large run.
+    pub fn with_arena_shrinkage(self, devices: &str) -> Result<RunOptions> {
+        self.add_config_entry(ARENA_SHRINKAGE_KEY, devices)?;
+        Ok(self)
+    }
+
+    /// Messages captured since the last call, empty unless built with
+    /// [`with_log_capture()`](#method.with_log_capture)
+    pub fn take_logs(&self) -> Vec<LogMessage> {
+        self.log_capture
+            .as_ref()
+            .map(LogCapture::take_messages)
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "ndarray")]
+    pub(crate) fn captures_logs(&self) -> bool {
+        self.log_capture.is_some()
+    }
+
+    /// Set a configuration entry of the run, as listed in `onnxruntime_run_options_config_keys.h`
+    fn add_config_entry(&self, key: &str, value: &str) -> Result<()> {
+        let key = CString::new(key)?;
+        let value = CString::new(value)?;
+        let status =
+            unsafe { ort_api!(AddRunConfigEntry)?(self.ptr, key.as_ptr(), value.as_ptr()) };
+        status_to_result(status).map_err(OrtError::RunOptions)
+    }
+
+    pub(crate) fn ptr(&self) -> *const sys::OrtRunOptions {
+        self.ptr
+    }
+
+    /// Ask runs using these options to stop as soon as possible, failing with an error
+    #[cfg(feature = "ndarray")]
+    pub(crate) fn terminate(&self) -> Result<()> {
+        let status = unsafe { ort_api!(RunOptionsSetTerminate)?(self.ptr) };
+        status_to_result(status).map_err(OrtError::RunOptions)
+    }
+}
+
+impl Drop for RunOptions {
+    #[tracing::instrument]
+    fn drop(&mut self) {
+        debug!("Dropping the run options.");
+        if let Ok(release) = ort_api!(ReleaseRunOptions) {
+            unsafe { release(self.ptr) };
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
+mod tests {
+    use super::*;
+    use crate::{
+        environment::{tests::CONCURRENT_TEST_RUN, Environment},
+        tensor::OrtOwnedTensor,
+    };
+    use test_log::test;
+
+    #[test]
+    fn run_with_arena_shrinkage() {
+        let _run_lock = CONCURRENT_TEST_RUN.single_test_run();
+        let environment = Environment::builder()
+            .with_name("run_with_arena_shrinkage")
+            .with_log_level(LoggingLevel::Warning)
+            .build()
+            .unwrap();
+        let session = environment
+            .new_session_builder()
+            .unwrap()
+            .with_model_from_file(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests")
+                    .join("data")
+                    .join("upsample.onnx"),
+            )
+            .unwrap();
+
+        let options = RunOptions::new()
+            .unwrap()
+            .with_arena_shrinkage("cpu:0")
+            .unwrap();
+        let input = ndarray::Array4::<f32>::zeros((1, 2, 2, 3));
+        let outputs: Vec<OrtOwnedTensor<f32, _>> =
+            session.run_with_options([input], &options).unwrap();
+        assert_eq!(outputs[0].shape(), [1, 4, 4, 3]);
+    }
+}
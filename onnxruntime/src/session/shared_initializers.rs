@@ -0,0 +1,201 @@
+//! Module containing the sharing of identical initializers between sessions
+
+use std::{
+    collections::HashMap,
+    ffi::CString,
+    sync::{Arc, Mutex, Weak},
+};
+
+use sha2::{Digest as _, Sha256};
+use tracing::debug;
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{assert_not_null_pointer, status_to_result, OrtError, Result},
+    initializers::{raw_initializers, Initializer},
+};
+
+/// Size below which initializers are not shared by default, in bytes
+const DEFAULT_MIN_BYTES: usize = 1024;
+
+type Digest = [u8; 32];
+
+/// Cache of initializers (weights) shared by the sessions of models holding identical ones
+///
+/// Set on session builders with
+/// [`SessionBuilder::with_shared_initializers()`](struct.SessionBuilder.html#method.with_shared_initializers).
+/// When a session is committed, each initializer of its model is looked up by content (element
+/// type, dimensions and data): initializers already held by another session using the same
+/// cache are handed to the runtime in place of the model's copy, the others are copied once and
+/// added to the cache. Versions of a model differing in a few tensors thus share the memory of
+/// the others, for example during blue/green deployments.
+///
+/// Initializers are kept as long as a session uses them. Sharing saves the memory of sessions
+/// running on the CPU; providers running on a device still copy the weights to it. Only
+/// initializers of the main graph, holding their data in the model (`raw_data`), are shared.
+///
+/// Clones share the same cache.
+#[derive(Debug, Clone)]
+pub struct SharedInitializers {
+    values: Arc<Mutex<HashMap<Digest, Weak<SharedValue>>>>,
+    min_bytes: usize,
+}
+
+impl SharedInitializers {
+    /// Empty cache, sharing initializers of at least 1 KiB
+    pub fn new() -> SharedInitializers {
+        SharedInitializers {
+            values: Arc::new(Mutex::new(HashMap::new())),
+            min_bytes: DEFAULT_MIN_BYTES,
+        }
+    }
+
+    /// Only share initializers of at least `min_bytes`, smaller ones being left to each session
+    pub fn with_min_bytes(mut self, min_bytes: usize) -> SharedInitializers {
+        self.min_bytes = min_bytes;
+        self
+    }
+
+    /// Number of initializers currently held, and their total size in bytes
+    pub fn usage(&self) -> (usize, usize) {
+        let values = self
+            .values
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?");
+        values
+            .values()
+            .filter_map(Weak::upgrade)
+            .fold((0, 0), |(count, bytes), value| {
+                (count + 1, bytes + value.len)
+            })
+    }
+
+    /// Add the initializers of `model` to `session_options_ptr`, reusing the cached ones
+    ///
+    /// The returned values must outlive the sessions created with the options.
+    pub(super) fn add_to(
+        &self,
+        session_options_ptr: *mut sys::OrtSessionOptions,
+        model: &[u8],
+    ) -> Result<Vec<Arc<SharedValue>>> {
+        let mut allocator_ptr: *mut sys::OrtAllocator = std::ptr::null_mut();
+        let status = unsafe { ort_api!(GetAllocatorWithDefaultOptions)?(&mut allocator_ptr) };
+        status_to_result(status).map_err(OrtError::Allocator)?;
+        assert_not_null_pointer(allocator_ptr, "Allocator")?;
+
+        let mut values = self
+            .values
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?");
+        values.retain(|_, value| value.strong_count() > 0);
+
+        let mut shared = Vec::new();
+        let mut reused = 0;
+        for initializer in raw_initializers(model)? {
+            if initializer.raw_data.len() < self.min_bytes {
+                continue;
+            }
+            let digest = digest(&initializer);
+            let value = match values.get(&digest).and_then(Weak::upgrade) {
+                Some(value) => {
+                    reused += 1;
+                    value
+                }
+                None => {
+                    let value = Arc::new(SharedValue::new(allocator_ptr, &initializer)?);
+                    values.insert(digest, Arc::downgrade(&value));
+                    value
+                }
+            };
+
+            let name = CString::new(initializer.name)?;
+            let status =
+                unsafe { ort_api!(AddInitializer)?(session_options_ptr, name.as_ptr(), value.ptr) };
+            status_to_result(status).map_err(OrtError::SessionOptions)?;
+            shared.push(value);
+        }
+        debug!(
+            initializers = shared.len(),
+            reused, "Initializers shared with the runtime."
+        );
+        Ok(shared)
+    }
+}
+
+impl Default for SharedInitializers {
+    fn default() -> Self {
+        SharedInitializers::new()
+    }
+}
+
+/// Digest of the content of an initializer, its name excepted
+fn digest(initializer: &Initializer) -> Digest {
+    let mut hasher = Sha256::new();
+    hasher.update((initializer.element_type as u32).to_le_bytes());
+    hasher.update((initializer.dims.len() as u64).to_le_bytes());
+    for dim in &initializer.dims {
+        hasher.update(dim.to_le_bytes());
+    }
+    hasher.update(initializer.raw_data);
+    hasher.finalize().into()
+}
+
+/// Initializer copied to a value of the runtime, shared by sessions
+#[derive(Debug)]
+pub(super) struct SharedValue {
+    ptr: *mut sys::OrtValue,
+    len: usize,
+}
+
+// The value is only read, by the runtime, once created
+unsafe impl Send for SharedValue {}
+unsafe impl Sync for SharedValue {}
+
+impl SharedValue {
+    fn new(
+        allocator_ptr: *mut sys::OrtAllocator,
+        initializer: &Initializer,
+    ) -> Result<SharedValue> {
+        let mut ptr: *mut sys::OrtValue = std::ptr::null_mut();
+        let status = unsafe {
+            ort_api!(CreateTensorAsOrtValue)?(
+                allocator_ptr,
+                initializer.dims.as_ptr(),
+                initializer.dims.len(),
+                initializer.element_type.into(),
+                &mut ptr,
+            )
+        };
+        status_to_result(status).map_err(OrtError::CreateTensor)?;
+        assert_not_null_pointer(ptr, "Value")?;
+        // Released if copying the data fails
+        let value = SharedValue {
+            ptr,
+            len: initializer.raw_data.len(),
+        };
+
+        let mut data_ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let status = unsafe { ort_api!(GetTensorMutableData)?(ptr, &mut data_ptr) };
+        status_to_result(status).map_err(OrtError::GetTensorMutableData)?;
+        if value.len > 0 {
+            assert_not_null_pointer(data_ptr, "TensorData")?;
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    initializer.raw_data.as_ptr(),
+                    data_ptr as *mut u8,
+                    value.len,
+                )
+            };
+        }
+        Ok(value)
+    }
+}
+
+impl Drop for SharedValue {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseValue) {
+            unsafe { release(self.ptr) };
+        }
+    }
+}
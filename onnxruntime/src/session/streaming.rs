@@ -0,0 +1,335 @@
+//! Module containing chunked inference of streaming audio models
+
+use std::fmt;
+
+use ndarray::{ArrayD, Axis, Slice};
+
+use crate::error::{OrtError, Result};
+
+use super::InferenceRunner;
+
+/// State tensor of a streaming model, fed back from one of its outputs to one of its inputs
+/// between chunks (for example the hidden state of an LSTM, or the cache of a conformer encoder)
+#[derive(Debug, Clone)]
+pub struct StreamState {
+    input: usize,
+    output: usize,
+    initial: ArrayD<f32>,
+}
+
+impl StreamState {
+    /// State read from the model's input of index `input` and updated from its output of index
+    /// `output`, starting (and starting again on [`reset()`](struct.StreamingSession.html#method.reset))
+    /// as `initial`
+    pub fn new(input: usize, output: usize, initial: ArrayD<f32>) -> StreamState {
+        StreamState {
+            input,
+            output,
+            initial,
+        }
+    }
+}
+
+/// Runner of a streaming audio model over chunks of samples pushed as they arrive
+///
+/// Samples are buffered until a chunk of `chunk_size` is available; the model then runs on the
+/// chunk preceded by the last `overlap` samples of the previous one (zeros for the first chunk),
+/// as a `[1, overlap + chunk_size]` tensor. State tensors are fed back from one chunk to the next,
+/// and the partial results of the chunks are stitched along an axis, first trimming the frames
+/// computed from the overlap. The model's inputs are the samples and the states, in any order.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, session::{StreamState, StreamingSession}};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let session = environment.new_session_builder()?.with_model_from_file("encoder.onnx")?;
+/// # let microphone: Vec<Vec<f32>> = Vec::new();
+/// // Inputs: samples, hidden state; outputs: logits [1, frames, vocabulary], hidden state
+/// let mut stream = StreamingSession::new(session, 16_000 / 2)
+///     .with_overlap(400)
+///     .with_output_trim(2)
+///     .with_state(StreamState::new(1, 1, ndarray::ArrayD::zeros(vec![2, 1, 256])));
+/// for samples in microphone {
+///     if let Some(logits) = stream.push(&samples)? {
+///         // decode the frames of the new chunks
+///     }
+/// }
+/// let last_logits = stream.flush()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct StreamingSession<R> {
+    runner: R,
+    chunk_size: usize,
+    overlap: usize,
+    audio_input: usize,
+    result_output: usize,
+    output_trim: usize,
+    stitch_axis: usize,
+    states: Vec<StreamState>,
+    current_states: Vec<ArrayD<f32>>,
+    /// Last `overlap` samples of the previous chunk, then the samples not run yet
+    pending: Vec<f32>,
+}
+
+impl<R> StreamingSession<R>
+where
+    R: InferenceRunner<f32>,
+{
+    /// Streaming over chunks of `chunk_size` samples, without overlap, state or trimming, the
+    /// samples being the model's first input and the results its first output, stitched along
+    /// axis 1
+    pub fn new(runner: R, chunk_size: usize) -> StreamingSession<R> {
+        StreamingSession {
+            runner,
+            chunk_size: chunk_size.max(1),
+            overlap: 0,
+            audio_input: 0,
+            result_output: 0,
+            output_trim: 0,
+            stitch_axis: 1,
+            states: Vec::new(),
+            current_states: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Precede each chunk with the last `overlap` samples of the previous one
+    pub fn with_overlap(mut self, overlap: usize) -> StreamingSession<R> {
+        self.overlap = overlap;
+        self.pending = vec![0.0; overlap];
+        self
+    }
+
+    /// Feed the samples to the model's input of index `index`
+    pub fn with_audio_input(mut self, index: usize) -> StreamingSession<R> {
+        self.audio_input = index;
+        self
+    }
+
+    /// Take the results from the model's output of index `index`
+    pub fn with_result_output(mut self, index: usize) -> StreamingSession<R> {
+        self.result_output = index;
+        self
+    }
+
+    /// Drop the first `frames` of the result of each chunk, computed from the overlap
+    pub fn with_output_trim(mut self, frames: usize) -> StreamingSession<R> {
+        self.output_trim = frames;
+        self
+    }
+
+    /// Stitch the results of the chunks along `axis` (the frames axis)
+    pub fn with_stitch_axis(mut self, axis: usize) -> StreamingSession<R> {
+        self.stitch_axis = axis;
+        self
+    }
+
+    /// Feed `state` back between chunks
+    pub fn with_state(mut self, state: StreamState) -> StreamingSession<R> {
+        self.current_states.push(state.initial.clone());
+        self.states.push(state);
+        self
+    }
+
+    /// Buffer `samples`, running the model on the chunks now complete
+    ///
+    /// Returns the stitched results of these chunks, or `None` if no chunk was complete.
+    pub fn push(&mut self, samples: &[f32]) -> Result<Option<ArrayD<f32>>> {
+        self.pending.extend_from_slice(samples);
+        let mut results = Vec::new();
+        while self.pending.len() >= self.overlap + self.chunk_size {
+            results.push(self.run_chunk()?);
+        }
+        self.stitch(results)
+    }
+
+    /// Run the model on the samples still buffered, padded with zeros to a full chunk
+    ///
+    /// Returns `None` if no samples were buffered. The stream can then go on, as if the padding
+    /// was pushed; call [`reset()`](#method.reset) to start a new one.
+    pub fn flush(&mut self) -> Result<Option<ArrayD<f32>>> {
+        if self.pending.len() <= self.overlap {
+            return Ok(None);
+        }
+        self.pending.resize(self.overlap + self.chunk_size, 0.0);
+        let result = self.run_chunk()?;
+        self.stitch(vec![result])
+    }
+
+    /// Start a new stream: drop the buffered samples and restore the initial states
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.pending.resize(self.overlap, 0.0);
+        self.current_states = self
+            .states
+            .iter()
+            .map(|state| state.initial.clone())
+            .collect();
+    }
+
+    /// Runner of the model
+    pub fn runner(&self) -> &R {
+        &self.runner
+    }
+
+    /// Run the model on the first chunk of the buffered samples, updating the states
+    fn run_chunk(&mut self) -> Result<ArrayD<f32>> {
+        let window_len = self.overlap + self.chunk_size;
+        let window =
+            ArrayD::from_shape_vec(vec![1, window_len], self.pending[..window_len].to_vec())
+                .expect("Window matches its shape");
+
+        let input_count = self.states.len() + 1;
+        let mut inputs: Vec<Option<ArrayD<f32>>> = vec![None; input_count];
+        let mut place = |index: usize, array: ArrayD<f32>| match inputs.get_mut(index) {
+            Some(input @ None) => {
+                *input = Some(array);
+                Ok(())
+            }
+            _ => Err(OrtError::InvalidConfiguration(format!(
+                "Streaming input {} is out of range or used twice ({} inputs)",
+                index, input_count
+            ))),
+        };
+        place(self.audio_input, window)?;
+        for (state, current) in self.states.iter().zip(&self.current_states) {
+            place(state.input, current.clone())?;
+        }
+        let inputs = inputs.into_iter().flatten().collect();
+
+        let mut outputs = self.runner.run_dyn(inputs)?;
+        let output = |index: usize| {
+            if index < outputs.len() {
+                Ok(index)
+            } else {
+                Err(OrtError::InvalidConfiguration(format!(
+                    "Streaming output {} is out of range ({} outputs)",
+                    index,
+                    outputs.len()
+                )))
+            }
+        };
+        output(self.result_output)?;
+        for state in &self.states {
+            output(state.output)?;
+        }
+        for (state, current) in self.states.iter().zip(&mut self.current_states) {
+            *current = outputs[state.output].clone();
+        }
+        let mut result = std::mem::replace(&mut outputs[self.result_output], ArrayD::zeros(vec![]));
+        if self.output_trim > 0 {
+            if result.ndim() <= self.stitch_axis {
+                return Err(self.axis_error(&result));
+            }
+            let frames = result.len_of(Axis(self.stitch_axis));
+            result.slice_axis_inplace(
+                Axis(self.stitch_axis),
+                Slice::from(self.output_trim.min(frames)..),
+            );
+        }
+
+        // Keep the last `overlap` samples of the window as the context of the next chunk
+        self.pending.drain(..self.chunk_size);
+        Ok(result)
+    }
+
+    fn stitch(&self, results: Vec<ArrayD<f32>>) -> Result<Option<ArrayD<f32>>> {
+        match results.len() {
+            0 => Ok(None),
+            1 => Ok(results.into_iter().next()),
+            _ => {
+                let views: Vec<_> = results.iter().map(ArrayD::view).collect();
+                ndarray::concatenate(Axis(self.stitch_axis), &views)
+                    .map(Some)
+                    .map_err(|_| self.axis_error(&results[0]))
+            }
+        }
+    }
+
+    fn axis_error(&self, result: &ArrayD<f32>) -> OrtError {
+        OrtError::InvalidConfiguration(format!(
+            "Streaming results of shape {:?} cannot be stitched along axis {}",
+            result.shape(),
+            self.stitch_axis
+        ))
+    }
+}
+
+impl<R> fmt::Debug for StreamingSession<R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingSession")
+            .field("runner", &self.runner)
+            .field("chunk_size", &self.chunk_size)
+            .field("overlap", &self.overlap)
+            .field("states", &self.states.len())
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{MockOutput, MockSession};
+    use test_log::test;
+
+    /// Model outputting, per chunk, one frame per sample of the window (its value plus the number
+    /// of chunks run so far, the state)
+    fn model() -> MockSession {
+        MockSession::with_closure(|inputs| {
+            let samples = inputs[0].view::<f32>()?;
+            let state = inputs[1].view::<f32>()?[[0]];
+            let frames = samples.mapv(|sample| sample + state * 100.0);
+            Ok(vec![
+                MockOutput::new(frames),
+                MockOutput::new(ndarray::arr1(&[state + 1.0])),
+            ])
+        })
+    }
+
+    #[test]
+    fn chunks_are_run_with_overlap_and_state() {
+        let mut stream = StreamingSession::new(model(), 2)
+            .with_overlap(1)
+            .with_output_trim(1)
+            .with_state(StreamState::new(1, 1, ndarray::arr1(&[0.0]).into_dyn()));
+
+        assert_eq!(stream.push(&[1.0]).unwrap(), None);
+        // Two chunks: [0 | 1 2] and [2 | 3 4], the overlap trimmed
+        let result = stream.push(&[2.0, 3.0, 4.0, 5.0]).unwrap().unwrap();
+        assert_eq!(
+            result,
+            ndarray::arr2(&[[1.0, 2.0, 103.0, 104.0]]).into_dyn()
+        );
+        assert_eq!(stream.runner().runs(), 2);
+
+        // [4 | 5 0]
+        let result = stream.flush().unwrap().unwrap();
+        assert_eq!(result, ndarray::arr2(&[[205.0, 200.0]]).into_dyn());
+        assert_eq!(stream.flush().unwrap(), None);
+
+        stream.reset();
+        let result = stream.push(&[7.0, 8.0]).unwrap().unwrap();
+        assert_eq!(result, ndarray::arr2(&[[7.0, 8.0]]).into_dyn());
+    }
+
+    #[test]
+    fn misconfigured_indices_fail() {
+        let mut stream = StreamingSession::new(model(), 1).with_state(StreamState::new(
+            0,
+            1,
+            ndarray::arr1(&[0.0]).into_dyn(),
+        ));
+        assert!(matches!(
+            stream.push(&[1.0]),
+            Err(OrtError::InvalidConfiguration(_))
+        ));
+    }
+}
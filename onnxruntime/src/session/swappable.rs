@@ -0,0 +1,144 @@
+//! Module containing sessions whose model can be replaced while serving
+
+#[cfg(feature = "ndarray")]
+use std::fmt::Debug;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, RwLock,
+};
+
+#[cfg(feature = "ndarray")]
+use ndarray::ArrayD;
+use tracing::info;
+
+use crate::error::Result;
+#[cfg(feature = "ndarray")]
+use crate::{
+    tensor::{IntoInput, OrtOwnedTensor},
+    TypeToTensorElementDataType,
+};
+
+use super::Session;
+
+/// Session whose model can be replaced without interrupting the runs using it
+///
+/// A new version of the model is loaded (and warmed up) while the current one keeps serving,
+/// typically from a background thread, then swapped in: runs started after the swap use the new
+/// session, while runs already in flight complete on the old one, which is dropped once they are
+/// done.
+///
+/// As with [`Session::run()`](struct.Session.html#method.run), which takes `&self`, runs of a
+/// given version from several threads run concurrently.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, session::SwappableSession};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// let session = environment.new_session_builder()?.with_model_from_file("v1.onnx")?;
+/// let swappable = SwappableSession::new(session);
+///
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| {
+///         swappable.reload(
+///             || environment.new_session_builder()?.with_model_from_file("v2.onnx"),
+///             |session| {
+///                 let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224));
+///                 session.run::<_, f32>([input]).map(|_| ())
+///             },
+///         )
+///     });
+///
+///     let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224));
+///     let outputs = swappable.run::<_, f32>([input]);
+/// });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SwappableSession<'a> {
+    current: RwLock<Arc<Session<'a>>>,
+    version: AtomicU64,
+}
+
+impl<'a> SwappableSession<'a> {
+    /// Serve `session`, as version 0
+    pub fn new(session: Session<'a>) -> SwappableSession<'a> {
+        SwappableSession {
+            current: RwLock::new(Arc::new(session)),
+            version: AtomicU64::new(0),
+        }
+    }
+
+    /// Version of the session currently served, incremented by each swap
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::SeqCst)
+    }
+
+    /// Run the inputs through the current session, copying the outputs out of the runtime
+    ///
+    /// See [`Session::run()`](struct.Session.html#method.run). The outputs are copied since the
+    /// session they come from may be swapped out and dropped right after the run.
+    #[cfg(feature = "ndarray")]
+    pub fn run<I, TOut>(&self, inputs: I) -> Result<Vec<ArrayD<TOut>>>
+    where
+        I: IntoIterator,
+        I::Item: IntoInput,
+        TOut: TypeToTensorElementDataType + Debug + Clone,
+    {
+        self.with_session(|session| {
+            let numa_node = session.output_numa_node;
+            let outputs: Vec<OrtOwnedTensor<TOut, _>> = session.run(inputs)?;
+            Ok(outputs
+                .iter()
+                .map(|output| super::copy_output(output, numa_node))
+                .collect())
+        })
+    }
+
+    /// Call `f` with the current session
+    ///
+    /// The session stays alive until `f` returns, even if swapped out in the meantime.
+    pub fn with_session<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce(&Session<'a>) -> T,
+    {
+        f(&self.current())
+    }
+
+    /// Serve `session` for subsequent runs, returning the new version
+    pub fn swap(&self, session: Session<'a>) -> u64 {
+        let mut current = self
+            .current
+            .write()
+            .expect("Failed to acquire lock: another thread panicked?");
+        *current = Arc::new(session);
+        // Incremented while holding the lock so that versions and sessions always match
+        let version = self.version.fetch_add(1, Ordering::SeqCst) + 1;
+        info!(version, "Session swapped.");
+        version
+    }
+
+    /// Load a new session with `load`, warm it up with `warm_up`, then swap it in
+    ///
+    /// The current session keeps serving while the new one loads; if loading or warming up fails,
+    /// it keeps serving and the error is returned. Returns the new version.
+    pub fn reload<L, W>(&self, load: L, warm_up: W) -> Result<u64>
+    where
+        L: FnOnce() -> Result<Session<'a>>,
+        W: FnOnce(&Session<'a>) -> Result<()>,
+    {
+        let session = load()?;
+        warm_up(&session)?;
+        Ok(self.swap(session))
+    }
+
+    fn current(&self) -> Arc<Session<'a>> {
+        self.current
+            .read()
+            .expect("Failed to acquire lock: another thread panicked?")
+            .clone()
+    }
+}
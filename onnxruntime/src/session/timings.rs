@@ -0,0 +1,121 @@
+//! Module containing the timings of the phases of inference runs
+
+use std::{
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Time spent in each phase of a call to [`Session::run()`](struct.Session.html#method.run)
+///
+/// Recorded for sessions built with
+/// [`SessionBuilder::with_run_timings()`](struct.SessionBuilder.html#method.with_run_timings)
+/// and returned by [`Session::last_run_timings()`](struct.Session.html#method.last_run_timings).
+/// Everything but [`run`](#structfield.run) is spent in the bindings rather than in the model:
+/// see [`binding_overhead()`](#method.binding_overhead).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunTimings {
+    /// Converting the inputs to values handed to the runtime (including coercions)
+    pub input_conversion: Duration,
+    /// The runtime's `Run()` call, that is executing the model
+    pub run: Duration,
+    /// Extracting the outputs from the runtime's values
+    pub output_extraction: Duration,
+    /// Copying the outputs to owned arrays, for the methods returning them (such as
+    /// [`Run::run()`](trait.Run.html#tymethod.run)); zero otherwise
+    pub output_copy: Duration,
+}
+
+impl RunTimings {
+    /// Time spent outside of the runtime's `Run()` call
+    pub fn binding_overhead(&self) -> Duration {
+        self.input_conversion + self.output_extraction + self.output_copy
+    }
+
+    /// Total time of the call
+    pub fn total(&self) -> Duration {
+        self.run + self.binding_overhead()
+    }
+}
+
+impl fmt::Display for RunTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "input_conversion={:?} run={:?} output_extraction={:?} output_copy={:?}",
+            self.input_conversion, self.run, self.output_extraction, self.output_copy
+        )
+    }
+}
+
+/// Phase of a run, as timed by a [`RunTimer`](struct.RunTimer.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Phase {
+    InputConversion,
+    Run,
+    OutputExtraction,
+    #[cfg(feature = "ndarray")]
+    OutputCopy,
+}
+
+/// Timings of the last run of a session
+#[derive(Debug, Default)]
+pub(super) struct RunTimer {
+    last: Mutex<RunTimings>,
+}
+
+impl RunTimer {
+    /// Call `f`, recording its duration as the given phase of the current run
+    ///
+    /// Converting the inputs starts a new run: the timings of the other phases are reset.
+    pub(super) fn time<R>(&self, phase: Phase, f: impl FnOnce() -> R) -> R {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        let mut last = self
+            .last
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?");
+        match phase {
+            Phase::InputConversion => {
+                *last = RunTimings {
+                    input_conversion: elapsed,
+                    ..RunTimings::default()
+                }
+            }
+            Phase::Run => last.run = elapsed,
+            Phase::OutputExtraction => last.output_extraction = elapsed,
+            #[cfg(feature = "ndarray")]
+            Phase::OutputCopy => last.output_copy = elapsed,
+        }
+        result
+    }
+
+    pub(super) fn last(&self) -> RunTimings {
+        *self
+            .last
+            .lock()
+            .expect("Failed to acquire lock: another thread panicked?")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn input_conversion_starts_a_new_run() {
+        let timer = RunTimer::default();
+        timer.time(Phase::InputConversion, || ());
+        timer.time(Phase::Run, || std::thread::sleep(Duration::from_millis(2)));
+        timer.time(Phase::OutputExtraction, || ());
+        let first = timer.last();
+        assert!(first.run >= Duration::from_millis(2));
+        assert_eq!(first.total(), first.run + first.binding_overhead());
+
+        timer.time(Phase::InputConversion, || ());
+        assert_eq!(timer.last().run, Duration::ZERO);
+    }
+}
@@ -0,0 +1,106 @@
+//! Module containing the tagging of inference runs with the current trace context
+
+use std::{fmt, sync::Arc};
+
+use tracing::Span;
+
+type TagProvider = Arc<dyn Fn() -> Option<String> + Send + Sync>;
+
+/// Tags runs with the trace context they execute in, linking the runtime's logs to traces
+///
+/// Set on a session with
+/// [`SessionBuilder::with_trace_tags()`](struct.SessionBuilder.html#method.with_trace_tags).
+/// Before each run without [`RunOptions`](struct.RunOptions.html) of its own, the tag is read
+/// from the caller's context and set as the run tag: the runtime logs the messages of the run
+/// with it as their log id (the `logid` field of the events forwarded to `tracing`). A `tracing`
+/// event carrying the tag is also emitted at the start of the run, within the caller's span,
+/// since the runtime's profiling entries do not record run tags.
+///
+/// # Example
+///
+/// With OpenTelemetry, through `tracing-opentelemetry`:
+///
+/// ```ignore
+/// use opentelemetry::trace::TraceContextExt;
+/// use tracing_opentelemetry::OpenTelemetrySpanExt;
+///
+/// let tags = TraceTags::with_provider(|| {
+///     let context = tracing::Span::current().context();
+///     let span = context.span();
+///     let span_context = span.span_context();
+///     span_context.is_valid().then(|| span_context.trace_id().to_string())
+/// });
+/// let session = environment
+///     .new_session_builder()?
+///     .with_trace_tags(tags)?
+///     .with_model_from_file("model.onnx")?;
+/// ```
+#[derive(Clone)]
+pub struct TraceTags {
+    provider: TagProvider,
+}
+
+impl TraceTags {
+    /// Tag runs with the id of the current `tracing` span, in hexadecimal
+    ///
+    /// Runs outside of any span are not tagged.
+    pub fn from_spans() -> TraceTags {
+        TraceTags::with_provider(|| {
+            Span::current()
+                .id()
+                .map(|id| format!("{:016x}", id.into_u64()))
+        })
+    }
+
+    /// Tag runs with the value returned by `provider`, for example the id of the current
+    /// OpenTelemetry trace; runs for which it returns `None` are not tagged
+    pub fn with_provider<F>(provider: F) -> TraceTags
+    where
+        F: Fn() -> Option<String> + Send + Sync + 'static,
+    {
+        TraceTags {
+            provider: Arc::new(provider),
+        }
+    }
+
+    /// Tag of a run starting now, if any
+    pub(super) fn tag(&self) -> Option<String> {
+        // Tags are passed as C strings
+        (self.provider)().filter(|tag| !tag.is_empty() && !tag.contains('\0'))
+    }
+}
+
+impl Default for TraceTags {
+    fn default() -> Self {
+        TraceTags::from_spans()
+    }
+}
+
+impl fmt::Debug for TraceTags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraceTags").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+    use tracing::{info_span, subscriber::with_default};
+    use tracing_subscriber::Registry;
+
+    #[test]
+    fn tags_from_current_span() {
+        let tags = TraceTags::from_spans();
+        with_default(Registry::default(), || {
+            assert_eq!(tags.tag(), None);
+            let span = info_span!("request");
+            let id = span.id().unwrap().into_u64();
+            let _entered = span.enter();
+            assert_eq!(tags.tag(), Some(format!("{:016x}", id)));
+        });
+
+        let tags = TraceTags::with_provider(|| Some("bad\0tag".to_owned()));
+        assert_eq!(tags.tag(), None);
+    }
+}
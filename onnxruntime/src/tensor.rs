@@ -22,10 +22,60 @@
 //! to a [`Tensor`](struct.Tensor.html). After inference, a [`OrtOwnedTensor`](struct.OrtOwnedTensor.html)
 //! will be returned by the method which can be derefed into its internal
 //! [`ndarray::ArrayView`](https://docs.rs/ndarray/latest/ndarray/type.ArrayView.html).
+//!
+//! All of the above requires the `ndarray` feature (enabled by default). Without it, inference
+//! runs on [`RawTensor`](raw/struct.RawTensor.html)s, flat buffers of elements with their shape
+//! (see [`Session::run_raw()`](../session/struct.Session.html#method.run_raw)).
 
+#[cfg(feature = "ndarray")]
+pub mod container;
+#[cfg(feature = "ndarray")]
+pub mod dyn_ort_tensor;
+#[cfg(feature = "ndarray")]
+pub mod dyn_ort_value;
+#[cfg(feature = "ndarray")]
+pub mod fixed;
+#[cfg(feature = "ndarray")]
+pub mod input_array;
+#[cfg(feature = "ndarray")]
+pub mod into_input;
+#[cfg(feature = "ndarray")]
+pub mod layout;
+#[cfg(feature = "mmap")]
+pub mod mmap_tensor;
+#[cfg(feature = "ndarray")]
 pub mod ndarray_tensor;
+#[cfg(feature = "npy")]
+pub mod npy;
+#[cfg(feature = "ndarray")]
 pub mod ort_owned_tensor;
 pub mod ort_tensor;
+#[cfg(feature = "ndarray")]
+pub mod quantization;
+pub mod raw;
+#[cfg(feature = "ndarray")]
+pub mod tensor_proto;
 
+#[cfg(feature = "ndarray")]
+pub use container::{OrtMap, OrtSequence};
+#[cfg(feature = "ndarray")]
+pub use dyn_ort_tensor::DynOrtTensor;
+#[cfg(feature = "ndarray")]
+pub use dyn_ort_value::DynOrtValue;
+#[cfg(feature = "ndarray")]
+pub use fixed::FixedTensor;
+#[cfg(feature = "ndarray")]
+pub use input_array::{InputArray, InputValue};
+#[cfg(feature = "ndarray")]
+pub use into_input::IntoInput;
+#[cfg(feature = "ndarray")]
+pub use layout::Layout;
+#[cfg(feature = "mmap")]
+pub use mmap_tensor::MmapTensor;
+#[cfg(feature = "ndarray")]
 pub use ort_owned_tensor::OrtOwnedTensor;
+#[cfg(feature = "ndarray")]
 pub use ort_tensor::OrtTensor;
+#[cfg(feature = "ndarray")]
+pub use quantization::QuantizationParameters;
+pub use raw::RawTensor;
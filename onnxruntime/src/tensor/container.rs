@@ -0,0 +1,183 @@
+//! Module containing sequences and maps handed to models as inputs
+//!
+//! Traditional machine learning models converted to ONNX (from scikit-learn for example) may take
+//! a `map(string, float)` of named features, or a sequence of such maps, instead of tensors. The
+//! runtime copies the elements when building these values, so they do not borrow their arrays.
+
+use std::fmt::Debug;
+
+use ndarray::{Array, Array1};
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{assert_not_null_pointer, status_to_result},
+    memory::MemoryInfo,
+    tensor::OrtTensor,
+    AllocatorType, MemType, OrtError, Result, TensorElementDataType, TypeToTensorElementDataType,
+};
+
+/// Map from keys to values, handed to a model's `map` input
+///
+/// Keys are `i64` or strings, values `i64`, `f32`, `f64` or strings.
+///
+/// ```no_run
+/// # use std::{collections::HashMap, error::Error};
+/// # use onnxruntime::{environment::Environment, tensor::OrtMap};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+/// let mut features = HashMap::new();
+/// features.insert("age".to_owned(), 42.0_f32);
+/// features.insert("income".to_owned(), 1.5e4);
+/// let (keys, values): (Vec<_>, Vec<_>) = features.into_iter().unzip();
+/// let outputs = session.run_untyped(vec![OrtMap::new(keys, values)?])?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OrtMap {
+    pub(crate) c_ptr: *mut sys::OrtValue,
+    key_type: TensorElementDataType,
+    value_type: TensorElementDataType,
+    len: usize,
+}
+
+impl OrtMap {
+    /// Map from `keys[i]` to `values[i]`
+    ///
+    /// Fails if the lengths differ or if the runtime does not support the key or value type.
+    pub fn new<K, V>(keys: Vec<K>, values: Vec<V>) -> Result<OrtMap>
+    where
+        K: TypeToTensorElementDataType + Debug + Clone,
+        V: TypeToTensorElementDataType + Debug + Clone,
+    {
+        let memory_info = MemoryInfo::new(AllocatorType::Arena, MemType::Default)?;
+        let allocator_ptr = default_allocator()?;
+        let len = keys.len();
+        let keys = OrtTensor::from_array(&memory_info, allocator_ptr, Array1::from(keys))?;
+        let values = OrtTensor::from_array(&memory_info, allocator_ptr, Array1::from(values))?;
+        let c_ptr = create_value(
+            &[keys.c_ptr as *const _, values.c_ptr as *const _],
+            sys::ONNXType::ONNX_TYPE_MAP,
+        )?;
+        Ok(OrtMap {
+            c_ptr,
+            key_type: K::tensor_element_data_type(),
+            value_type: V::tensor_element_data_type(),
+            len,
+        })
+    }
+
+    /// Element type of the keys
+    pub fn key_type(&self) -> TensorElementDataType {
+        self.key_type
+    }
+
+    /// Element type of the values
+    pub fn value_type(&self) -> TensorElementDataType {
+        self.value_type
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Drop for OrtMap {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseValue) {
+            unsafe { release(self.c_ptr) };
+        }
+    }
+}
+
+/// Sequence of tensors or of maps, handed to a model's `sequence` input
+///
+/// Sequences hold at least one element.
+#[derive(Debug)]
+pub struct OrtSequence {
+    pub(crate) c_ptr: *mut sys::OrtValue,
+    len: usize,
+}
+
+impl OrtSequence {
+    /// Sequence of the tensors holding `arrays`, which may differ in shape
+    pub fn from_arrays<T, D>(arrays: Vec<Array<T, D>>) -> Result<OrtSequence>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone,
+        D: ndarray::Dimension,
+    {
+        let memory_info = MemoryInfo::new(AllocatorType::Arena, MemType::Default)?;
+        let allocator_ptr = default_allocator()?;
+        let len = arrays.len();
+        let tensors = arrays
+            .into_iter()
+            .map(|array| OrtTensor::from_array(&memory_info, allocator_ptr, array))
+            .collect::<Result<Vec<_>>>()?;
+        let value_ptrs: Vec<*const sys::OrtValue> = tensors
+            .iter()
+            .map(|tensor| tensor.c_ptr as *const _)
+            .collect();
+        let c_ptr = create_value(&value_ptrs, sys::ONNXType::ONNX_TYPE_SEQUENCE)?;
+        Ok(OrtSequence { c_ptr, len })
+    }
+
+    /// Sequence of `maps`, which must all have the same key and value types
+    pub fn from_maps(maps: Vec<OrtMap>) -> Result<OrtSequence> {
+        let value_ptrs: Vec<*const sys::OrtValue> =
+            maps.iter().map(|map| map.c_ptr as *const _).collect();
+        let c_ptr = create_value(&value_ptrs, sys::ONNXType::ONNX_TYPE_SEQUENCE)?;
+        Ok(OrtSequence {
+            c_ptr,
+            len: maps.len(),
+        })
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the sequence has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Drop for OrtSequence {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseValue) {
+            unsafe { release(self.c_ptr) };
+        }
+    }
+}
+
+/// Sequence or map (`value_type`) of `values`, which the runtime copies
+fn create_value(
+    values: &[*const sys::OrtValue],
+    value_type: sys::ONNXType,
+) -> Result<*mut sys::OrtValue> {
+    let mut value_ptr: *mut sys::OrtValue = std::ptr::null_mut();
+    let status = unsafe {
+        ort_api!(CreateValue)?(values.as_ptr(), values.len(), value_type, &mut value_ptr)
+    };
+    status_to_result(status).map_err(OrtError::CreateValue)?;
+    assert_not_null_pointer(value_ptr, "Value")?;
+    Ok(value_ptr)
+}
+
+/// Allocator of the runtime, used for string tensors
+pub(crate) fn default_allocator() -> Result<*mut sys::OrtAllocator> {
+    let mut allocator_ptr: *mut sys::OrtAllocator = std::ptr::null_mut();
+    let status = unsafe { ort_api!(GetAllocatorWithDefaultOptions)?(&mut allocator_ptr) };
+    status_to_result(status).map_err(OrtError::Allocator)?;
+    assert_not_null_pointer(allocator_ptr, "Allocator")?;
+    Ok(allocator_ptr)
+}
@@ -0,0 +1,241 @@
+//! Module containing outputs whose element type is only known once the model has run
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use ndarray::{ArrayView, CowArray, IxDyn};
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::status_to_result,
+    memory::MemoryInfo,
+    tensor::raw::{tensor_data, upcast_half},
+    OrtError, Result, TensorElementDataType, TypeToTensorElementDataType,
+};
+
+/// Output of a model, owned by the ONNX Runtime, whose elements are extracted with the type
+/// chosen for each output
+///
+/// Returned by [`Session::run_untyped()`](../../session/struct.Session.html#method.run_untyped), for
+/// models whose outputs have differing element types (for example `f32` scores next to `i64`
+/// labels):
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::environment::Environment;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+/// # let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224));
+/// let outputs = session.run_untyped(vec![input])?;
+/// let scores = outputs[0].try_extract::<f32>()?;
+/// let labels = outputs[1].try_extract::<i64>()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct DynOrtTensor<'m> {
+    tensor_ptr: *mut sys::OrtValue,
+    shape: Vec<usize>,
+    element_type: sys::ONNXTensorElementDataType,
+    /// Convert `float16`/`bfloat16` data to `f32` when `f32` is requested
+    upcast_half_to_f32: bool,
+    memory_info: PhantomData<&'m MemoryInfo>,
+}
+
+impl<'m> DynOrtTensor<'m> {
+    /// Take ownership of the runtime's tensor `tensor_ptr`, of the given shape
+    pub(crate) fn new(
+        _memory_info: &'m MemoryInfo,
+        tensor_ptr: *mut sys::OrtValue,
+        shape: &[usize],
+        upcast_half_to_f32: bool,
+    ) -> Result<DynOrtTensor<'m>> {
+        // Released if querying its element type fails
+        let mut tensor = DynOrtTensor {
+            tensor_ptr,
+            shape: shape.to_vec(),
+            element_type: sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED,
+            upcast_half_to_f32,
+            memory_info: PhantomData,
+        };
+
+        let mut tensor_info_ptr: *mut sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+        let status = unsafe { ort_api!(GetTensorTypeAndShape)?(tensor_ptr, &mut tensor_info_ptr) };
+        status_to_result(status).map_err(OrtError::GetTensorTypeAndShape)?;
+        let status =
+            unsafe { ort_api!(GetTensorElementType)?(tensor_info_ptr, &mut tensor.element_type) };
+        unsafe { ort_api!(ReleaseTensorTypeAndShapeInfo)?(tensor_info_ptr) };
+        status_to_result(status).map_err(OrtError::TensorElementType)?;
+
+        Ok(tensor)
+    }
+
+    /// Element type of the output, `None` for types the crate does not support
+    pub fn element_type(&self) -> Option<TensorElementDataType> {
+        TensorElementDataType::from_sys(self.element_type)
+    }
+
+    /// Dimensions of the output, outermost first
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Elements of the output as `T`
+    ///
+    /// The array borrows the runtime's memory, unless `float16`/`bfloat16` data is converted to
+    /// the requested `f32` (see
+    /// [`SessionBuilder::with_half_precision_upcast()`](../../session/struct.SessionBuilder.html#method.with_half_precision_upcast)).
+    /// Fails with
+    /// [`OrtError::NonMatchingTensorElementType`](../../error/enum.OrtError.html#variant.NonMatchingTensorElementType)
+    /// if the output holds other elements, and with
+    /// [`OrtError::RawStringTensor`](../../error/enum.OrtError.html#variant.RawStringTensor) for
+    /// strings.
+    pub fn try_extract<T>(&self) -> Result<CowArray<'_, T, IxDyn>>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone,
+    {
+        extract_tensor(
+            self.tensor_ptr,
+            &self.shape,
+            self.element_type,
+            self.upcast_half_to_f32,
+        )
+    }
+}
+
+impl<'m> Drop for DynOrtTensor<'m> {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseValue) {
+            unsafe { release(self.tensor_ptr) };
+        }
+    }
+}
+
+/// Elements of the runtime's tensor `tensor_ptr`, of the given shape and element type, as `T`
+///
+/// The array borrows the tensor's data unless it is converted, and must not outlive the tensor.
+pub(crate) fn extract_tensor<'v, T>(
+    tensor_ptr: *mut sys::OrtValue,
+    shape: &[usize],
+    element_type: sys::ONNXTensorElementDataType,
+    upcast_half_to_f32: bool,
+) -> Result<CowArray<'v, T, IxDyn>>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+{
+    let conversion = conversion(
+        T::tensor_element_data_type(),
+        element_type,
+        upcast_half_to_f32,
+    )?;
+    Ok(match conversion {
+        Conversion::None => {
+            let data = tensor_data::<T>(tensor_ptr, shape)?;
+            CowArray::from(
+                ArrayView::from_shape(IxDyn(shape), data).expect("Data matches the output's shape"),
+            )
+        }
+        Conversion::UpcastHalf => {
+            let data = upcast_half::<T>(tensor_ptr, shape, element_type, None)?;
+            CowArray::from(
+                ndarray::Array::from_shape_vec(IxDyn(shape), data)
+                    .expect("Data matches the output's shape"),
+            )
+        }
+    })
+}
+
+/// Conversion of the elements of an output when extracting them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conversion {
+    None,
+    UpcastHalf,
+}
+
+/// Conversion needed to extract the elements of an output of the `actual` type as `requested`
+fn conversion(
+    requested: TensorElementDataType,
+    actual: sys::ONNXTensorElementDataType,
+    upcast_half_to_f32: bool,
+) -> Result<Conversion> {
+    // String tensors hold the runtime's own string objects, which cannot be read as elements
+    if requested == TensorElementDataType::String {
+        return Err(OrtError::RawStringTensor);
+    }
+    let requested: sys::ONNXTensorElementDataType = requested.into();
+    match actual {
+        _ if actual == requested => Ok(Conversion::None),
+        sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16
+        | sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16
+            if upcast_half_to_f32
+                && requested
+                    == sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT =>
+        {
+            Ok(Conversion::UpcastHalf)
+        }
+        _ => Err(OrtError::NonMatchingTensorElementType { requested, actual }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use sys::ONNXTensorElementDataType::*;
+
+    #[test]
+    fn conversion_depends_on_actual_type() {
+        assert_eq!(
+            conversion(
+                TensorElementDataType::Int64,
+                ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64,
+                false
+            )
+            .unwrap(),
+            Conversion::None
+        );
+        assert!(matches!(
+            conversion(
+                TensorElementDataType::Float,
+                ONNX_TENSOR_ELEMENT_DATA_TYPE_INT64,
+                false
+            ),
+            Err(OrtError::NonMatchingTensorElementType { .. })
+        ));
+        assert!(matches!(
+            conversion(
+                TensorElementDataType::String,
+                ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING,
+                false
+            ),
+            Err(OrtError::RawStringTensor)
+        ));
+    }
+
+    #[test]
+    fn half_outputs_are_upcast_on_request() {
+        assert!(conversion(
+            TensorElementDataType::Float,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16,
+            false
+        )
+        .is_err());
+        assert_eq!(
+            conversion(
+                TensorElementDataType::Float,
+                ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16,
+                true
+            )
+            .unwrap(),
+            Conversion::UpcastHalf
+        );
+        assert!(conversion(
+            TensorElementDataType::Double,
+            ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16,
+            true
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,348 @@
+//! Module containing values of any type owned by the ONNX Runtime: tensors of any element type,
+//! sequences and maps
+
+use std::{ffi, fmt::Debug, marker::PhantomData};
+
+use ndarray::{ArrayBase, CowArray, IxDyn};
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{assert_not_null_pointer, status_to_result},
+    memory::MemoryInfo,
+    session::get_tensor_dimensions_into,
+    tensor::{
+        container::default_allocator, dyn_ort_tensor::extract_tensor, into_input, raw::RawTensor,
+        OrtMap, OrtSequence,
+    },
+    type_info::TypeInfo,
+    OrtError, Result, TensorElementDataType, TypeToTensorElementDataType,
+};
+
+/// Value of any type owned by the ONNX Runtime: a tensor of any element type, a sequence or a map
+///
+/// Values are handed to models as inputs (see
+/// [`InputValue`](../input_array/enum.InputValue.html)), and returned as outputs by
+/// [`Session::run_values()`](../../session/struct.Session.html#method.run_values), which unlike
+/// [`Session::run_untyped()`](../../session/struct.Session.html#method.run_untyped) supports models
+/// outputting sequences and maps (such as the probabilities of a scikit-learn classifier, a
+/// sequence of maps from labels to probabilities):
+///
+/// ```no_run
+/// # use std::error::Error;
+/// # use onnxruntime::{environment::Environment, tensor::DynOrtValue};
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let environment = Environment::builder().build()?;
+/// # let session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+/// let features = DynOrtValue::from_slice(&[1, 4], &[5.1_f32, 3.5, 1.4, 0.2])?;
+/// let outputs = session.run_values(vec![features])?;
+/// let labels = outputs[0].try_extract::<i64>()?;
+/// for map in outputs[1].sequence()? {
+///     let (classes, probabilities) = map.map()?;
+///     let probabilities = probabilities.try_extract::<f32>()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Values built from Rust data copy it to memory allocated by the runtime.
+#[derive(Debug)]
+pub struct DynOrtValue<'m> {
+    ptr: *mut sys::OrtValue,
+    onnx_type: sys::ONNXType,
+    /// Shape of tensors, empty for other values
+    shape: Vec<usize>,
+    element_type: sys::ONNXTensorElementDataType,
+    /// Convert `float16`/`bfloat16` data to `f32` when `f32` is requested
+    upcast_half_to_f32: bool,
+    memory_info: PhantomData<&'m MemoryInfo>,
+}
+
+impl DynOrtValue<'static> {
+    /// Tensor holding a copy of the elements of `array`
+    pub fn from_array<S, T, D>(array: &ArrayBase<S, D>) -> Result<DynOrtValue<'static>>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: TypeToTensorElementDataType + Debug + Clone,
+        D: ndarray::Dimension,
+    {
+        match array.as_slice() {
+            Some(data) => DynOrtValue::from_slice(array.shape(), data),
+            None => {
+                let array = into_input::into_standard_layout(array.to_owned());
+                DynOrtValue::from_slice(array.shape(), array.as_slice().expect("Standard layout"))
+            }
+        }
+    }
+
+    /// Tensor of the given shape holding a copy of `data`, in row-major order
+    ///
+    /// Fails with [`OrtError::InvalidDimensions`](../../error/enum.OrtError.html#variant.InvalidDimensions)
+    /// if the number of elements does not match the shape.
+    pub fn from_slice<T>(shape: &[usize], data: &[T]) -> Result<DynOrtValue<'static>>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone,
+    {
+        if shape.iter().product::<usize>() != data.len() {
+            return Err(OrtError::InvalidDimensions);
+        }
+
+        let allocator_ptr = default_allocator()?;
+        let dims: Vec<i64> = shape.iter().map(|&d| d as i64).collect();
+        let mut tensor_ptr: *mut sys::OrtValue = std::ptr::null_mut();
+        let status = unsafe {
+            ort_api!(CreateTensorAsOrtValue)?(
+                allocator_ptr,
+                dims.as_ptr(),
+                dims.len(),
+                T::tensor_element_data_type().into(),
+                &mut tensor_ptr,
+            )
+        };
+        status_to_result(status).map_err(OrtError::CreateTensor)?;
+        assert_not_null_pointer(tensor_ptr, "Tensor")?;
+
+        // Released if filling it fails
+        let value = DynOrtValue {
+            ptr: tensor_ptr,
+            onnx_type: sys::ONNXType::ONNX_TYPE_TENSOR,
+            shape: shape.to_vec(),
+            element_type: T::tensor_element_data_type().into(),
+            upcast_half_to_f32: false,
+            memory_info: PhantomData,
+        };
+
+        if T::tensor_element_data_type() == TensorElementDataType::String {
+            // Null-terminated copies of each string, as per `FillStringTensor` docs
+            let null_terminated_copies = data
+                .iter()
+                .map(|elt| {
+                    let bytes = elt
+                        .try_utf8_bytes()
+                        .expect("String data type must provide utf8 bytes");
+                    ffi::CString::new(bytes)
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let string_pointers: Vec<_> = null_terminated_copies
+                .iter()
+                .map(|cstring| cstring.as_ptr())
+                .collect();
+            let status = unsafe {
+                ort_api!(FillStringTensor)?(
+                    tensor_ptr,
+                    string_pointers.as_ptr(),
+                    string_pointers.len(),
+                )
+            };
+            status_to_result(status).map_err(OrtError::FillStringTensor)?;
+        } else if !data.is_empty() {
+            let mut data_ptr: *mut T = std::ptr::null_mut();
+            let status = unsafe {
+                ort_api!(GetTensorMutableData)?(
+                    tensor_ptr,
+                    &mut data_ptr as *mut *mut T as *mut *mut std::ffi::c_void,
+                )
+            };
+            status_to_result(status).map_err(OrtError::GetTensorMutableData)?;
+            assert_not_null_pointer(data_ptr, "TensorData")?;
+            // Elements other than strings are plain numbers, copied bitwise
+            unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), data_ptr, data.len()) };
+        }
+
+        Ok(value)
+    }
+
+    /// Tensor holding a copy of the elements of `tensor`
+    pub fn from_raw<T>(tensor: &RawTensor<T>) -> Result<DynOrtValue<'static>>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone,
+    {
+        DynOrtValue::from_slice(tensor.shape(), tensor.data())
+    }
+}
+
+impl<'m> DynOrtValue<'m> {
+    /// Take ownership of the runtime's value `ptr`
+    pub(crate) fn new(
+        _memory_info: &'m MemoryInfo,
+        ptr: *mut sys::OrtValue,
+        upcast_half_to_f32: bool,
+    ) -> Result<DynOrtValue<'m>> {
+        DynOrtValue::from_ptr(ptr, upcast_half_to_f32)
+    }
+
+    fn from_ptr(ptr: *mut sys::OrtValue, upcast_half_to_f32: bool) -> Result<DynOrtValue<'m>> {
+        // Released if querying its type fails
+        let mut value = DynOrtValue {
+            ptr,
+            onnx_type: sys::ONNXType::ONNX_TYPE_UNKNOWN,
+            shape: Vec::new(),
+            element_type: sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED,
+            upcast_half_to_f32,
+            memory_info: PhantomData,
+        };
+
+        let status = unsafe { ort_api!(GetValueType)?(ptr, &mut value.onnx_type) };
+        status_to_result(status).map_err(OrtError::GetOnnxType)?;
+        if value.onnx_type != sys::ONNXType::ONNX_TYPE_TENSOR {
+            return Ok(value);
+        }
+
+        let mut tensor_info_ptr: *mut sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+        let status = unsafe { ort_api!(GetTensorTypeAndShape)?(ptr, &mut tensor_info_ptr) };
+        status_to_result(status).map_err(OrtError::GetTensorTypeAndShape)?;
+        let mut dims = Vec::new();
+        let result =
+            unsafe { get_tensor_dimensions_into(tensor_info_ptr, &mut dims) }.and_then(|()| {
+                let status = unsafe {
+                    ort_api!(GetTensorElementType)?(tensor_info_ptr, &mut value.element_type)
+                };
+                status_to_result(status).map_err(OrtError::TensorElementType)
+            });
+        unsafe { ort_api!(ReleaseTensorTypeAndShapeInfo)?(tensor_info_ptr) };
+        result?;
+        value.shape = dims.into_iter().map(|d| d as usize).collect();
+        Ok(value)
+    }
+
+    /// Whether the value is a tensor
+    pub fn is_tensor(&self) -> bool {
+        self.onnx_type == sys::ONNXType::ONNX_TYPE_TENSOR
+    }
+
+    /// Type of the value, with the element types and shapes of the tensors it holds
+    pub fn type_info(&self) -> Result<TypeInfo> {
+        unsafe { TypeInfo::from_value(self.ptr) }
+    }
+
+    /// Element type of a tensor, `None` for other values and for types the crate does not support
+    pub fn element_type(&self) -> Option<TensorElementDataType> {
+        TensorElementDataType::from_sys(self.element_type)
+    }
+
+    /// Dimensions of a tensor, outermost first, empty for other values
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Elements of a tensor as `T`
+    ///
+    /// Same as [`DynOrtTensor::try_extract()`](../dyn_ort_tensor/struct.DynOrtTensor.html#method.try_extract),
+    /// failing with
+    /// [`OrtError::NonMatchingValueType`](../../error/enum.OrtError.html#variant.NonMatchingValueType)
+    /// if the value is not a tensor.
+    pub fn try_extract<T>(&self) -> Result<CowArray<'_, T, IxDyn>>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone,
+    {
+        self.expect_type("tensor", sys::ONNXType::ONNX_TYPE_TENSOR)?;
+        extract_tensor(
+            self.ptr,
+            &self.shape,
+            self.element_type,
+            self.upcast_half_to_f32,
+        )
+    }
+
+    /// Elements of a sequence
+    ///
+    /// The runtime copies them out of the sequence. Fails with
+    /// [`OrtError::NonMatchingValueType`](../../error/enum.OrtError.html#variant.NonMatchingValueType)
+    /// if the value is not a sequence.
+    pub fn sequence(&self) -> Result<Vec<DynOrtValue<'m>>> {
+        self.expect_type("sequence", sys::ONNXType::ONNX_TYPE_SEQUENCE)?;
+        let mut count = 0;
+        let status = unsafe { ort_api!(GetValueCount)?(self.ptr, &mut count) };
+        status_to_result(status).map_err(OrtError::GetValue)?;
+        (0..count as i32).map(|index| self.element(index)).collect()
+    }
+
+    /// Keys and values of a map, as two tensors of the same length
+    ///
+    /// The runtime copies them out of the map. Fails with
+    /// [`OrtError::NonMatchingValueType`](../../error/enum.OrtError.html#variant.NonMatchingValueType)
+    /// if the value is not a map.
+    pub fn map(&self) -> Result<(DynOrtValue<'m>, DynOrtValue<'m>)> {
+        self.expect_type("map", sys::ONNXType::ONNX_TYPE_MAP)?;
+        Ok((self.element(0)?, self.element(1)?))
+    }
+
+    /// Element `index` of a sequence, or the keys (0) or values (1) of a map
+    fn element(&self, index: i32) -> Result<DynOrtValue<'m>> {
+        let mut element_ptr: *mut sys::OrtValue = std::ptr::null_mut();
+        let status =
+            unsafe { ort_api!(GetValue)?(self.ptr, index, default_allocator()?, &mut element_ptr) };
+        status_to_result(status).map_err(OrtError::GetValue)?;
+        assert_not_null_pointer(element_ptr, "Value")?;
+        DynOrtValue::from_ptr(element_ptr, self.upcast_half_to_f32)
+    }
+
+    fn expect_type(&self, requested: &'static str, onnx_type: sys::ONNXType) -> Result<()> {
+        if self.onnx_type == onnx_type {
+            Ok(())
+        } else {
+            Err(OrtError::NonMatchingValueType {
+                requested,
+                actual: self.onnx_type,
+            })
+        }
+    }
+
+    pub(crate) fn c_ptr(&self) -> *mut sys::OrtValue {
+        self.ptr
+    }
+}
+
+impl From<OrtSequence> for DynOrtValue<'static> {
+    fn from(sequence: OrtSequence) -> Self {
+        let value = DynOrtValue {
+            ptr: sequence.c_ptr,
+            onnx_type: sys::ONNXType::ONNX_TYPE_SEQUENCE,
+            shape: Vec::new(),
+            element_type: sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED,
+            upcast_half_to_f32: false,
+            memory_info: PhantomData,
+        };
+        // The value now releases the sequence
+        std::mem::forget(sequence);
+        value
+    }
+}
+
+impl From<OrtMap> for DynOrtValue<'static> {
+    fn from(map: OrtMap) -> Self {
+        let value = DynOrtValue {
+            ptr: map.c_ptr,
+            onnx_type: sys::ONNXType::ONNX_TYPE_MAP,
+            shape: Vec::new(),
+            element_type: sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED,
+            upcast_half_to_f32: false,
+            memory_info: PhantomData,
+        };
+        // The value now releases the map
+        std::mem::forget(map);
+        value
+    }
+}
+
+impl<'m> Drop for DynOrtValue<'m> {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseValue) {
+            unsafe { release(self.ptr) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn values_check_their_shape() {
+        assert!(matches!(
+            DynOrtValue::from_slice(&[2, 3], &[1.0_f32, 2.0]),
+            Err(OrtError::InvalidDimensions)
+        ));
+    }
+}
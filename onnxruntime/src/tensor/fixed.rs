@@ -0,0 +1,204 @@
+//! Module containing tensors whose shape is known at compile time
+//!
+//! Pipelines with fully static shapes can describe them in the type system, for example
+//! `FixedTensor<f32, Shape4<1, 3, 224, 224>>`. Such tensors are checked against the model once,
+//! when creating a [`FixedShapeSession`](../../session/struct.FixedShapeSession.html), instead of
+//! on every inference call.
+
+use std::{fmt::Debug, ops::Deref};
+
+use ndarray::{Array, ArrayView, Dimension};
+
+use crate::{error::FixedShapeError, tensor::IntoInput, Result, TypeToTensorElementDataType};
+
+/// Shape known at compile time
+pub trait FixedShape {
+    /// Dimensions, outermost first
+    const SHAPE: &'static [usize];
+    /// Number of elements
+    const LEN: usize;
+
+    /// Matching `ndarray` dimensionality
+    type Dim: Dimension;
+
+    /// Value of the matching `ndarray` dimension
+    fn dim() -> Self::Dim;
+}
+
+macro_rules! impl_fixed_shape {
+    ($(#[$meta:meta])* $name:ident, $dim:ty, $($n:ident),+) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name<$(const $n: usize),+>;
+
+        impl<$(const $n: usize),+> FixedShape for $name<$($n),+> {
+            const SHAPE: &'static [usize] = &[$($n),+];
+            const LEN: usize = 1 $(* $n)+;
+
+            type Dim = $dim;
+
+            fn dim() -> Self::Dim {
+                ndarray::Dim([$($n),+])
+            }
+        }
+    };
+}
+
+impl_fixed_shape!(
+    /// Rank 1 fixed shape
+    Shape1, ndarray::Ix1, A
+);
+impl_fixed_shape!(
+    /// Rank 2 fixed shape
+    Shape2, ndarray::Ix2, A, B
+);
+impl_fixed_shape!(
+    /// Rank 3 fixed shape
+    Shape3, ndarray::Ix3, A, B, C
+);
+impl_fixed_shape!(
+    /// Rank 4 fixed shape, for example `Shape4<N, C, H, W>` for a batch of images
+    Shape4, ndarray::Ix4, A, B, C, D
+);
+impl_fixed_shape!(
+    /// Rank 5 fixed shape
+    Shape5, ndarray::Ix5, A, B, C, D, E
+);
+impl_fixed_shape!(
+    /// Rank 6 fixed shape
+    Shape6, ndarray::Ix6, A, B, C, D, E, F
+);
+
+/// Tensor whose shape is part of its type
+///
+/// Derefs to the underlying [`ndarray::Array`](https://docs.rs/ndarray/latest/ndarray/type.Array.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedTensor<T, S>
+where
+    S: FixedShape,
+{
+    array: Array<T, S::Dim>,
+}
+
+impl<T, S> FixedTensor<T, S>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    S: FixedShape,
+{
+    /// Tensor with every element set to `value`
+    pub fn from_elem(value: T) -> FixedTensor<T, S> {
+        FixedTensor {
+            array: Array::from_elem(S::dim(), value),
+        }
+    }
+
+    /// Tensor from elements in row-major order, failing if there are not exactly `S::LEN` of them
+    pub fn from_vec(data: Vec<T>) -> Result<FixedTensor<T, S>> {
+        let len = data.len();
+        Array::from_shape_vec(S::dim(), data)
+            .map(|array| FixedTensor { array })
+            .map_err(|_| {
+                FixedShapeError::Shape {
+                    shape: vec![len],
+                    expected_shape: S::SHAPE.to_vec(),
+                }
+                .into()
+            })
+    }
+
+    /// Tensor from an array, failing if its shape is not `S::SHAPE`
+    pub fn from_array<D>(array: Array<T, D>) -> Result<FixedTensor<T, S>>
+    where
+        D: Dimension,
+    {
+        let shape = array.shape().to_vec();
+        array
+            .into_dimensionality::<S::Dim>()
+            .ok()
+            .filter(|array| array.shape() == S::SHAPE)
+            .map(|array| FixedTensor { array })
+            .ok_or_else(|| {
+                FixedShapeError::Shape {
+                    shape,
+                    expected_shape: S::SHAPE.to_vec(),
+                }
+                .into()
+            })
+    }
+
+    /// Tensor copied from a view, failing if its shape is not `S::SHAPE`
+    pub(crate) fn from_view<D>(view: ArrayView<T, D>) -> Result<FixedTensor<T, S>>
+    where
+        D: Dimension,
+    {
+        FixedTensor::from_array(view.to_owned())
+    }
+
+    /// Convert into the underlying array
+    pub fn into_array(self) -> Array<T, S::Dim> {
+        self.array
+    }
+}
+
+impl<T, S> Deref for FixedTensor<T, S>
+where
+    S: FixedShape,
+{
+    type Target = Array<T, S::Dim>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.array
+    }
+}
+
+impl<T, S> IntoInput for FixedTensor<T, S>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    S: FixedShape,
+{
+    type Elem = T;
+    type Dim = S::Dim;
+
+    fn into_input(self) -> Array<T, S::Dim> {
+        self.array
+    }
+
+    fn input_view(&self) -> ndarray::ArrayView<'_, T, S::Dim> {
+        self.array.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OrtError;
+    use ndarray::arr2;
+    use test_log::test;
+
+    #[test]
+    fn fixed_shape_constants() {
+        assert_eq!(Shape4::<1, 3, 224, 224>::SHAPE, &[1, 3, 224, 224]);
+        assert_eq!(Shape4::<1, 3, 224, 224>::LEN, 3 * 224 * 224);
+        assert_eq!(Shape2::<2, 5>::dim(), ndarray::Ix2(2, 5));
+    }
+
+    #[test]
+    fn fixed_tensor_from_array() {
+        let tensor = FixedTensor::<i64, Shape2<2, 2>>::from_array(arr2(&[[1, 2], [3, 4]])).unwrap();
+        assert_eq!(tensor[[1, 0]], 3);
+
+        let tensor = FixedTensor::<i64, Shape2<2, 2>>::from_array(arr2(&[[1, 2, 3]]));
+        assert!(matches!(
+            tensor,
+            Err(OrtError::FixedShape(FixedShapeError::Shape { .. }))
+        ));
+    }
+
+    #[test]
+    fn fixed_tensor_from_vec() {
+        let tensor =
+            FixedTensor::<f32, Shape3<1, 2, 2>>::from_vec(vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert_eq!(tensor.shape(), &[1, 2, 2]);
+        assert!(FixedTensor::<f32, Shape3<1, 2, 2>>::from_vec(vec![1.0]).is_err());
+    }
+}
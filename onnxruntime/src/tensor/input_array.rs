@@ -0,0 +1,198 @@
+//! Module containing input arrays of any element type
+
+use ndarray::{Array, ArrayD};
+
+use crate::{
+    tensor::{DynOrtValue, OrtMap, OrtSequence},
+    TensorElementDataType,
+};
+
+/// Input array of any element type, for models whose inputs differ in element type
+///
+/// Used by [`Session::run_mixed()`](../../session/struct.Session.html#method.run_mixed). Arrays of
+/// the supported element types, of any dimensionality, convert to it with `From`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputArray {
+    /// Array of `f32`
+    Float(ArrayD<f32>),
+    /// Array of `u8`
+    Uint8(ArrayD<u8>),
+    /// Array of `i8`
+    Int8(ArrayD<i8>),
+    /// Array of `u16`
+    Uint16(ArrayD<u16>),
+    /// Array of `i16`
+    Int16(ArrayD<i16>),
+    /// Array of `i32`
+    Int32(ArrayD<i32>),
+    /// Array of `i64`
+    Int64(ArrayD<i64>),
+    /// Array of `f64`
+    Double(ArrayD<f64>),
+    /// Array of `u32`
+    Uint32(ArrayD<u32>),
+    /// Array of `u64`
+    Uint64(ArrayD<u64>),
+    /// Array of strings
+    String(ArrayD<String>),
+}
+
+impl InputArray {
+    /// Element type of the array
+    pub fn element_type(&self) -> TensorElementDataType {
+        match self {
+            InputArray::Float(_) => TensorElementDataType::Float,
+            InputArray::Uint8(_) => TensorElementDataType::Uint8,
+            InputArray::Int8(_) => TensorElementDataType::Int8,
+            InputArray::Uint16(_) => TensorElementDataType::Uint16,
+            InputArray::Int16(_) => TensorElementDataType::Int16,
+            InputArray::Int32(_) => TensorElementDataType::Int32,
+            InputArray::Int64(_) => TensorElementDataType::Int64,
+            InputArray::Double(_) => TensorElementDataType::Double,
+            InputArray::Uint32(_) => TensorElementDataType::Uint32,
+            InputArray::Uint64(_) => TensorElementDataType::Uint64,
+            InputArray::String(_) => TensorElementDataType::String,
+        }
+    }
+
+    /// Dimensions of the array
+    pub fn shape(&self) -> &[usize] {
+        match self {
+            InputArray::Float(array) => array.shape(),
+            InputArray::Uint8(array) => array.shape(),
+            InputArray::Int8(array) => array.shape(),
+            InputArray::Uint16(array) => array.shape(),
+            InputArray::Int16(array) => array.shape(),
+            InputArray::Int32(array) => array.shape(),
+            InputArray::Int64(array) => array.shape(),
+            InputArray::Double(array) => array.shape(),
+            InputArray::Uint32(array) => array.shape(),
+            InputArray::Uint64(array) => array.shape(),
+            InputArray::String(array) => array.shape(),
+        }
+    }
+}
+
+macro_rules! impl_from_array {
+    ($type_:ty, $variant:ident) => {
+        impl<D> From<Array<$type_, D>> for InputArray
+        where
+            D: ndarray::Dimension,
+        {
+            fn from(array: Array<$type_, D>) -> Self {
+                InputArray::$variant(array.into_dyn())
+            }
+        }
+    };
+}
+
+impl_from_array!(f32, Float);
+impl_from_array!(u8, Uint8);
+impl_from_array!(i8, Int8);
+impl_from_array!(u16, Uint16);
+impl_from_array!(i16, Int16);
+impl_from_array!(i32, Int32);
+impl_from_array!(i64, Int64);
+impl_from_array!(f64, Double);
+impl_from_array!(u32, Uint32);
+impl_from_array!(u64, Uint64);
+impl_from_array!(String, String);
+
+/// Input of any type: an array, a sequence, a map or a value already created
+///
+/// Used by [`Session::run_mixed()`](../../session/struct.Session.html#method.run_mixed),
+/// [`Session::run_untyped()`](../../session/struct.Session.html#method.run_untyped) and
+/// [`Session::run_values()`](../../session/struct.Session.html#method.run_values), for models taking
+/// sequences or maps next to tensors. Arrays, [`InputArray`](enum.InputArray.html)s,
+/// [`OrtSequence`](../container/struct.OrtSequence.html)s,
+/// [`OrtMap`](../container/struct.OrtMap.html)s and
+/// [`DynOrtValue`](../dyn_ort_value/struct.DynOrtValue.html)s convert to it with `From`.
+#[derive(Debug)]
+pub enum InputValue {
+    /// Tensor
+    Array(InputArray),
+    /// Sequence of tensors or of maps
+    Sequence(OrtSequence),
+    /// Map
+    Map(OrtMap),
+    /// Value of any type, handed to the runtime as is
+    Value(DynOrtValue<'static>),
+}
+
+impl InputValue {
+    /// Dimensions of the value; sequences and maps have none, as inputs of rank 0
+    pub(crate) fn shape(&self) -> &[usize] {
+        match self {
+            InputValue::Array(array) => array.shape(),
+            InputValue::Sequence(_) | InputValue::Map(_) => &[],
+            InputValue::Value(value) => value.shape(),
+        }
+    }
+}
+
+impl From<InputArray> for InputValue {
+    fn from(array: InputArray) -> Self {
+        InputValue::Array(array)
+    }
+}
+
+impl From<OrtSequence> for InputValue {
+    fn from(sequence: OrtSequence) -> Self {
+        InputValue::Sequence(sequence)
+    }
+}
+
+impl From<OrtMap> for InputValue {
+    fn from(map: OrtMap) -> Self {
+        InputValue::Map(map)
+    }
+}
+
+impl From<DynOrtValue<'static>> for InputValue {
+    fn from(value: DynOrtValue<'static>) -> Self {
+        InputValue::Value(value)
+    }
+}
+
+impl<T, D> From<Array<T, D>> for InputValue
+where
+    Array<T, D>: Into<InputArray>,
+{
+    fn from(array: Array<T, D>) -> Self {
+        InputValue::Array(array.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr1, Array4};
+    use test_log::test;
+
+    #[test]
+    fn arrays_convert_with_their_element_type() {
+        let inputs: Vec<InputArray> = vec![
+            Array4::<f32>::zeros((1, 3, 2, 2)).into(),
+            arr1(&[1_i64, 2, 3]).into(),
+            arr1(&["a".to_owned()]).into(),
+        ];
+        let element_types: Vec<_> = inputs.iter().map(InputArray::element_type).collect();
+        assert_eq!(
+            element_types,
+            vec![
+                TensorElementDataType::Float,
+                TensorElementDataType::Int64,
+                TensorElementDataType::String
+            ]
+        );
+        assert_eq!(inputs[0].shape(), &[1, 3, 2, 2]);
+    }
+
+    #[test]
+    fn arrays_convert_to_input_values() {
+        let value: InputValue = arr1(&[1_i64, 2]).into();
+        assert!(matches!(value, InputValue::Array(InputArray::Int64(_))));
+        let value: InputValue = InputArray::from(arr1(&[1.0_f32])).into();
+        assert!(matches!(value, InputValue::Array(InputArray::Float(_))));
+    }
+}
@@ -0,0 +1,146 @@
+//! Module containing the conversion of values into inference inputs
+
+use std::fmt::Debug;
+
+use ndarray::{Array, ArrayBase, ArrayView};
+
+use crate::TypeToTensorElementDataType;
+
+/// Value usable as an input of [`Session::run()`](../../session/struct.Session.html#method.run)
+///
+/// Owned and borrowed arrays can both be passed. The runtime expects elements in row-major order
+/// without gaps (`ndarray`'s standard layout): [`Session::run()`](../../session/struct.Session.html#method.run)
+/// hands arrays already in the standard layout to the runtime in place, and copies arrays in any
+/// other layout (for example transposed or sliced with a step) to the standard layout first.
+pub trait IntoInput {
+    /// Type of the input's elements
+    type Elem: TypeToTensorElementDataType + Debug + Clone;
+    /// Dimensionality of the input
+    type Dim: ndarray::Dimension;
+
+    /// Convert to an owned array in standard layout, reusing the data when possible
+    fn into_input(self) -> Array<Self::Elem, Self::Dim>;
+
+    /// View of the input's elements, to check it or hand it to the runtime without converting it
+    fn input_view(&self) -> ArrayView<'_, Self::Elem, Self::Dim>;
+}
+
+/// Convert an array to the standard (row-major, contiguous) layout, without copying it if it already is
+///
+/// Logical element order is preserved: a transposed array is copied so that its elements are laid
+/// out in memory in the order of its transposed indices.
+pub fn into_standard_layout<T, D>(array: Array<T, D>) -> Array<T, D>
+where
+    T: Clone,
+    D: ndarray::Dimension,
+{
+    if array.is_standard_layout() {
+        array
+    } else {
+        array.as_standard_layout().into_owned()
+    }
+}
+
+impl<T, D> IntoInput for Array<T, D>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    type Elem = T;
+    type Dim = D;
+
+    fn into_input(self) -> Array<T, D> {
+        into_standard_layout(self)
+    }
+
+    fn input_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+
+impl<S, T, D> IntoInput for &ArrayBase<S, D>
+where
+    S: ndarray::Data<Elem = T>,
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    type Elem = T;
+    type Dim = D;
+
+    fn into_input(self) -> Array<T, D> {
+        // `to_owned()` keeps the memory order of contiguous arrays, transposed ones included
+        self.as_standard_layout().into_owned()
+    }
+
+    fn input_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+
+impl<'a, T, D> IntoInput for ndarray::ArrayView<'a, T, D>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    type Elem = T;
+    type Dim = D;
+
+    fn into_input(self) -> Array<T, D> {
+        // `to_owned()` keeps the memory order of contiguous arrays, transposed ones included
+        self.as_standard_layout().into_owned()
+    }
+
+    fn input_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr2, s, ShapeBuilder};
+    use test_log::test;
+
+    fn memory_order<T: Clone>(array: &Array<T, ndarray::Ix2>) -> Vec<T> {
+        array.as_slice_memory_order().unwrap().to_vec()
+    }
+
+    #[test]
+    fn into_input_standard_layout() {
+        let array = arr2(&[[1, 2, 3], [4, 5, 6]]);
+        let data_ptr = array.as_ptr();
+        let input = array.into_input();
+        assert_eq!(
+            input.as_ptr(),
+            data_ptr,
+            "standard layout arrays are not copied"
+        );
+
+        let transposed = input.clone().reversed_axes();
+        assert!(!transposed.is_standard_layout());
+        let input = transposed.into_input();
+        assert!(input.is_standard_layout());
+        assert_eq!(memory_order(&input), vec![1, 4, 2, 5, 3, 6]);
+
+        let fortran = Array::from_shape_vec((2, 2).f(), vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(memory_order(&fortran.view().into_input()), vec![1, 3, 2, 4]);
+        assert_eq!(memory_order(&(&fortran).into_input()), vec![1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn input_view_borrows_the_data() {
+        let array = arr2(&[[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(array.input_view().as_ptr(), array.as_ptr());
+        let view = array.slice(s![1.., ..]);
+        assert_eq!(view.input_view().to_slice(), Some(&[4, 5, 6][..]));
+    }
+
+    #[test]
+    fn into_input_strided() {
+        let array = arr2(&[[1, 2, 3, 4], [5, 6, 7, 8]]);
+        let strided = array.slice(s![.., ..;2]);
+        let input = strided.into_input();
+        assert!(input.is_standard_layout());
+        assert_eq!(memory_order(&input), vec![1, 3, 5, 7]);
+    }
+}
@@ -0,0 +1,242 @@
+//! Module containing helpers for the memory layout of image tensors
+//!
+//! Image models take a batch of images either as `NCHW` (channels first, one plane per channel,
+//! as exported from PyTorch) or as `NHWC` (channels last, pixels interleaved as in image buffers,
+//! as exported from TensorFlow and for most mobile runtimes). The helpers here build either
+//! layout from interleaved pixels, and convert between them, with loops over contiguous slices
+//! instead of `ndarray`'s element by element iteration over permuted axes, which is several
+//! times slower.
+
+use ndarray::{Array4, ArrayView4};
+
+use crate::session::Input;
+
+/// Memory layout of a batch of images
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Batch, channels, height, width: one plane per channel
+    Nchw,
+    /// Batch, height, width, channels: interleaved channels
+    Nhwc,
+}
+
+impl Layout {
+    /// Guess the layout of a model's image input from its dimensions
+    ///
+    /// Returns `None` if the input is not of rank 4, or if its channels cannot be told apart
+    /// from its other dimensions: a fixed dimension of 1, 3 or 4 (grayscale, RGB or RGBA) is
+    /// expected on exactly one of the candidate axes.
+    pub fn of_input(input: &Input) -> Option<Layout> {
+        let is_channels = |dimension: Option<u32>| matches!(dimension, Some(1) | Some(3) | Some(4));
+        match input.dimensions[..] {
+            [_, c, _, last] if is_channels(c) && !is_channels(last) => Some(Layout::Nchw),
+            [_, c, _, last] if is_channels(last) && !is_channels(c) => Some(Layout::Nhwc),
+            _ => None,
+        }
+    }
+
+    /// Shape of a batch of `batch` images of `height` by `width` pixels with `channels` channels
+    pub fn shape(self, batch: usize, channels: usize, height: usize, width: usize) -> [usize; 4] {
+        match self {
+            Layout::Nchw => [batch, channels, height, width],
+            Layout::Nhwc => [batch, height, width, channels],
+        }
+    }
+
+    /// Index of the channel axis
+    pub fn channel_axis(self) -> usize {
+        match self {
+            Layout::Nchw => 1,
+            Layout::Nhwc => 3,
+        }
+    }
+}
+
+/// Build a batch of one image in `layout` from interleaved pixels, converting each value with `f`
+///
+/// `pixels` holds `height * width * channels` values, row by row with interleaved channels, as
+/// in the buffers of the `image` crate. `f` receives a value and its channel, for example to
+/// normalize it with per channel mean and standard deviation.
+///
+/// # Panics
+///
+/// Panics if `pixels` does not hold `height * width * channels` values.
+///
+/// # Example
+///
+/// ```
+/// # use onnxruntime::tensor::layout::{image_tensor, Layout};
+/// let pixels = [255_u8, 0, 0, 0, 255, 0];
+/// let input = image_tensor(&pixels, 1, 2, 3, Layout::Nchw, |value, _channel| {
+///     f32::from(value) / 255.0
+/// });
+/// assert_eq!(input.shape(), &[1, 3, 1, 2]);
+/// assert_eq!(input.as_slice().unwrap(), &[1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+/// ```
+pub fn image_tensor<T, U, F>(
+    pixels: &[T],
+    height: usize,
+    width: usize,
+    channels: usize,
+    layout: Layout,
+    mut f: F,
+) -> Array4<U>
+where
+    T: Copy,
+    F: FnMut(T, usize) -> U,
+{
+    assert_eq!(
+        pixels.len(),
+        height * width * channels,
+        "Expected {}x{}x{} pixel values",
+        height,
+        width,
+        channels
+    );
+    let shape = layout.shape(1, channels, height, width);
+    let data: Vec<U> = match layout {
+        Layout::Nhwc => pixels
+            .chunks_exact(channels.max(1))
+            .flat_map(|pixel| pixel.iter().enumerate())
+            .map(|(channel, &value)| f(value, channel))
+            .collect(),
+        Layout::Nchw => (0..channels)
+            .flat_map(|channel| pixels.iter().skip(channel).step_by(channels))
+            .enumerate()
+            .map(|(index, &value)| f(value, index / (height * width)))
+            .collect(),
+    };
+    Array4::from_shape_vec(shape, data).expect("Shape matches the number of pixels")
+}
+
+/// Convert a batch of images from `NHWC` to `NCHW`
+pub fn nhwc_to_nchw<T>(images: ArrayView4<'_, T>) -> Array4<T>
+where
+    T: Copy,
+{
+    let (batch, height, width, channels) = images.dim();
+    let shape = Layout::Nchw.shape(batch, channels, height, width);
+    match images.as_slice() {
+        // Each image is a `height * width` by `channels` matrix to transpose
+        Some(data) => {
+            Array4::from_shape_vec(shape, transpose_batch(data, height * width, channels))
+                .expect("Transposed data has the same length")
+        }
+        None => images
+            .permuted_axes([0, 3, 1, 2])
+            .as_standard_layout()
+            .into_owned(),
+    }
+}
+
+/// Convert a batch of images from `NCHW` to `NHWC`
+pub fn nchw_to_nhwc<T>(images: ArrayView4<'_, T>) -> Array4<T>
+where
+    T: Copy,
+{
+    let (batch, channels, height, width) = images.dim();
+    let shape = Layout::Nhwc.shape(batch, channels, height, width);
+    match images.as_slice() {
+        // Each image is a `channels` by `height * width` matrix to transpose
+        Some(data) => {
+            Array4::from_shape_vec(shape, transpose_batch(data, channels, height * width))
+                .expect("Transposed data has the same length")
+        }
+        None => images
+            .permuted_axes([0, 2, 3, 1])
+            .as_standard_layout()
+            .into_owned(),
+    }
+}
+
+/// Side of the square blocks transposed at once, fitting a few cache lines per row
+const BLOCK: usize = 32;
+
+/// Transpose each of the `rows` by `cols` row-major matrices stored one after the other in `data`
+fn transpose_batch<T>(data: &[T], rows: usize, cols: usize) -> Vec<T>
+where
+    T: Copy,
+{
+    let matrix_len = rows * cols;
+    let mut transposed = Vec::with_capacity(data.len());
+    if matrix_len == 0 {
+        return transposed;
+    }
+    for matrix in data.chunks_exact(matrix_len) {
+        let start = transposed.len();
+        // Every element is written below, the initial values are only placeholders
+        transposed.extend_from_slice(matrix);
+        let out = &mut transposed[start..];
+        for row_block in (0..rows).step_by(BLOCK) {
+            let row_end = (row_block + BLOCK).min(rows);
+            for col_block in (0..cols).step_by(BLOCK) {
+                let col_end = (col_block + BLOCK).min(cols);
+                for row in row_block..row_end {
+                    let src = &matrix[row * cols..][col_block..col_end];
+                    for (col, &value) in (col_block..col_end).zip(src) {
+                        out[col * rows + row] = value;
+                    }
+                }
+            }
+        }
+    }
+    transposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TensorElementDataType;
+    use test_log::test;
+
+    #[test]
+    fn layout_conversions() {
+        let nhwc = Array4::from_shape_fn((2, 5, 37, 3), |(n, h, w, c)| {
+            (n * 10000 + h * 1000 + w * 10 + c) as u32
+        });
+        let nchw = nhwc_to_nchw(nhwc.view());
+        assert_eq!(nchw, nhwc.view().permuted_axes([0, 3, 1, 2]));
+        assert_eq!(nchw_to_nhwc(nchw.view()), nhwc);
+        // Non contiguous views take the slow path
+        let reversed = nhwc.slice(ndarray::s![.., ..;-1, .., ..]);
+        assert_eq!(nhwc_to_nchw(reversed), reversed.permuted_axes([0, 3, 1, 2]));
+
+        let pixels: Vec<u8> = nhwc.iter().take(5 * 37 * 3).map(|&v| v as u8).collect();
+        let planar = image_tensor(&pixels, 5, 37, 3, Layout::Nchw, |value, _| value);
+        assert_eq!(
+            planar,
+            nhwc_to_nchw(image_tensor(&pixels, 5, 37, 3, Layout::Nhwc, |value, _| value).view())
+        );
+        let channels = image_tensor(&pixels, 5, 37, 3, Layout::Nchw, |_, channel| channel);
+        assert!(channels
+            .outer_iter()
+            .next()
+            .unwrap()
+            .outer_iter()
+            .enumerate()
+            .all(|(c, plane)| plane.iter().all(|&v| v == c)));
+    }
+
+    #[test]
+    fn layout_of_input() {
+        let input = |dimensions: Vec<Option<u32>>| Input {
+            name: "image".to_owned(),
+            input_type: TensorElementDataType::Float,
+            dimensions,
+            container: None,
+        };
+        assert_eq!(
+            Layout::of_input(&input(vec![None, Some(3), Some(224), Some(224)])),
+            Some(Layout::Nchw)
+        );
+        assert_eq!(
+            Layout::of_input(&input(vec![Some(1), None, None, Some(3)])),
+            Some(Layout::Nhwc)
+        );
+        assert_eq!(
+            Layout::of_input(&input(vec![None, Some(3), Some(3), Some(3)])),
+            None
+        );
+        assert_eq!(Layout::of_input(&input(vec![None, Some(3)])), None);
+    }
+}
@@ -0,0 +1,251 @@
+//! Module containing tensor with memory backed by a memory-mapped file
+
+use std::{fmt::Debug, fs::File, marker::PhantomData, path::Path};
+
+#[cfg(unix)]
+use std::os::unix::io::AsRawFd;
+
+use memmap2::{Mmap, MmapOptions};
+use ndarray::ArrayViewD;
+
+use crate::{error::MmapTensorError, OrtError, Result, TypeToTensorElementDataType};
+
+/// Input tensor whose data lives in a memory-mapped file region
+///
+/// Useful for large inputs already stored on disk in their raw, native-endian layout (for example
+/// precomputed embeddings): the pages are handed to the runtime directly instead of being read into
+/// anonymous memory first.
+///
+/// The tensor owns the mapping; any value created from it for inference borrows it, so the mapping
+/// is guaranteed to outlive the inference using it.
+///
+/// Use [`Session::run_mmap()`](../../session/struct.Session.html#method.run_mmap) to perform
+/// inference on such tensors.
+///
+/// On Unix, shared memory segments can be mapped the same way (see
+/// [`from_fd()`](#method.from_fd) and [`from_shared_memory()`](#method.from_shared_memory)), so
+/// that a producer process (for example a camera capture daemon) hands its buffers to an
+/// inference process without any copy.
+#[derive(Debug)]
+pub struct MmapTensor<T>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+{
+    mmap: Mmap,
+    offset: usize,
+    shape: Vec<usize>,
+    element_type: PhantomData<T>,
+}
+
+impl<T> MmapTensor<T>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+{
+    /// Map `shape.iter().product()` elements of the file at `path`, starting `offset` bytes into it
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (by this or any other process) while the tensor is alive,
+    /// see [`memmap2::Mmap::map()`](https://docs.rs/memmap2/latest/memmap2/struct.Mmap.html#method.map),
+    /// and must hold valid values of `T`, see [`from_mmap()`](#method.from_mmap).
+    pub unsafe fn from_file<P>(path: P, offset: usize, shape: Vec<usize>) -> Result<MmapTensor<T>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(OrtError::FileDoesNotExists {
+                filename: path.to_path_buf(),
+            });
+        }
+
+        let file = File::open(path).map_err(MmapTensorError::IoError)?;
+        let mmap = MmapOptions::new()
+            .map(&file)
+            .map_err(MmapTensorError::IoError)?;
+        MmapTensor::from_mmap(mmap, offset, shape)
+    }
+
+    /// Map `shape.iter().product()` elements of the shared memory segment or file `fd` refers to,
+    /// starting `offset` bytes into it
+    ///
+    /// `fd` is typically a `memfd` or POSIX shared memory descriptor received from the producer
+    /// process over a Unix socket. It is only used to create the mapping and can be closed
+    /// afterwards.
+    ///
+    /// # Safety
+    ///
+    /// The mapped region must not be modified (by this or any other process) while the tensor is
+    /// alive: producers reusing their buffers must wait for the inference process to drop the
+    /// tensor before writing the next frame to the same region. The region must hold valid values
+    /// of `T`, see [`from_mmap()`](#method.from_mmap).
+    #[cfg(unix)]
+    pub unsafe fn from_fd<F>(fd: &F, offset: usize, shape: Vec<usize>) -> Result<MmapTensor<T>>
+    where
+        F: AsRawFd,
+    {
+        let mmap = MmapOptions::new()
+            .map(fd.as_raw_fd())
+            .map_err(MmapTensorError::IoError)?;
+        MmapTensor::from_mmap(mmap, offset, shape)
+    }
+
+    /// Map `shape.iter().product()` elements of the POSIX shared memory object `name` (as given
+    /// to `shm_open()`), starting `offset` bytes into it
+    ///
+    /// # Safety
+    ///
+    /// See [`from_fd()`](#method.from_fd).
+    #[cfg(target_os = "linux")]
+    pub unsafe fn from_shared_memory(
+        name: &str,
+        offset: usize,
+        shape: Vec<usize>,
+    ) -> Result<MmapTensor<T>> {
+        // glibc's `shm_open()` creates its objects in this tmpfs
+        let path = Path::new("/dev/shm").join(name.trim_start_matches('/'));
+        MmapTensor::from_file(path, offset, shape)
+    }
+
+    /// Use an existing mapping, interpreting the data `offset` bytes into it with the given `shape`
+    ///
+    /// Fails if the region is too small for `shape` or misaligned for `T`.
+    ///
+    /// # Safety
+    ///
+    /// The bytes of the region must be valid values of `T`. This holds for every bit pattern of
+    /// the numeric element types this crate implements
+    /// [`TypeToTensorElementDataType`](../../trait.TypeToTensorElementDataType.html) for, but not
+    /// for types with invalid bit patterns or padding, such as `bool` or user types implementing
+    /// the trait.
+    pub unsafe fn from_mmap(mmap: Mmap, offset: usize, shape: Vec<usize>) -> Result<MmapTensor<T>> {
+        if let crate::TensorElementDataType::String = T::tensor_element_data_type() {
+            return Err(OrtError::BorrowedStringTensor);
+        }
+
+        // A shape whose size overflows cannot fit in any mapping
+        let required = shape
+            .iter()
+            .try_fold(std::mem::size_of::<T>(), |bytes, &dim| {
+                bytes.checked_mul(dim)
+            })
+            .ok_or(OrtError::InvalidDimensions)?;
+        let available = mmap.len().saturating_sub(offset);
+        if offset > mmap.len() || required > available {
+            return Err(MmapTensorError::RegionTooSmall {
+                required,
+                available,
+            }
+            .into());
+        }
+
+        let alignment = std::mem::align_of::<T>();
+        if mmap[offset..].as_ptr().align_offset(alignment) != 0 {
+            return Err(MmapTensorError::Misaligned { offset, alignment }.into());
+        }
+
+        Ok(MmapTensor {
+            mmap,
+            offset,
+            shape,
+            element_type: PhantomData,
+        })
+    }
+
+    /// Shape of the tensor
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Flat view of the tensor's elements, in row-major order
+    pub fn data(&self) -> &[T] {
+        let len = self.shape.iter().product::<usize>();
+        let ptr = self.mmap[self.offset..].as_ptr() as *const T;
+        // Size and alignment were validated when the tensor was created.
+        unsafe { std::slice::from_raw_parts(ptr, len) }
+    }
+
+    /// View of the tensor's data as an [`ndarray::ArrayView`](https://docs.rs/ndarray/latest/ndarray/type.ArrayView.html)
+    pub fn view(&self) -> ArrayViewD<'_, T> {
+        ArrayViewD::from_shape(self.shape.as_slice(), self.data())
+            .expect("Shape was validated against the mapped region")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use test_log::test;
+
+    fn mmap_of(bytes: &[u8]) -> Mmap {
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(bytes).unwrap();
+        unsafe { Mmap::map(&file).unwrap() }
+    }
+
+    #[test]
+    fn mmaptensor_view_f32() {
+        let values = [1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        let tensor =
+            unsafe { MmapTensor::<f32>::from_mmap(mmap_of(&bytes), 0, vec![2, 3]) }.unwrap();
+        assert_eq!(tensor.shape(), &[2, 3]);
+        assert_eq!(tensor.data(), &values);
+        assert_eq!(tensor.view()[[1, 2]], 6.0);
+    }
+
+    #[test]
+    fn mmaptensor_with_offset() {
+        let bytes: Vec<u8> = [0_i32, 7, 8, 9]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        let tensor = unsafe { MmapTensor::<i32>::from_mmap(mmap_of(&bytes), 4, vec![3]) }.unwrap();
+        assert_eq!(tensor.data(), &[7, 8, 9]);
+    }
+
+    #[test]
+    fn mmaptensor_region_too_small() {
+        let tensor = unsafe { MmapTensor::<f32>::from_mmap(mmap_of(&[0; 8]), 0, vec![3]) };
+        assert!(matches!(
+            tensor,
+            Err(OrtError::MmapTensor(MmapTensorError::RegionTooSmall {
+                required: 12,
+                available: 8
+            }))
+        ));
+    }
+
+    #[test]
+    fn mmaptensor_overflowing_shape() {
+        let tensor = unsafe { MmapTensor::<f32>::from_mmap(mmap_of(&[0; 8]), 0, vec![1 << 62, 4]) };
+        assert!(matches!(tensor, Err(OrtError::InvalidDimensions)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn mmaptensor_from_fd() {
+        let mut file = tempfile::tempfile().unwrap();
+        let bytes: Vec<u8> = [1_u16, 2, 3, 4]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        file.write_all(&bytes).unwrap();
+        let tensor = unsafe { MmapTensor::<u16>::from_fd(&file, 2, vec![3]) }.unwrap();
+        drop(file);
+        assert_eq!(tensor.data(), &[2, 3, 4]);
+    }
+
+    #[test]
+    fn mmaptensor_misaligned() {
+        let tensor = unsafe { MmapTensor::<f32>::from_mmap(mmap_of(&[0; 16]), 2, vec![2]) };
+        assert!(matches!(
+            tensor,
+            Err(OrtError::MmapTensor(MmapTensorError::Misaligned {
+                offset: 2,
+                alignment: 4
+            }))
+        ));
+    }
+}
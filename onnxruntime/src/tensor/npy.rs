@@ -0,0 +1,521 @@
+//! Module containing NumPy `.npy` and `.npz` serialization of tensors
+//!
+//! Arrays are written exactly as `numpy.save()` and `numpy.savez()` write them, so inference
+//! results can be compared byte for byte with the ones of a Python reference implementation, and
+//! inputs saved from Python can be loaded directly.
+//!
+//! Outputs of [`Session::run()`](../../session/struct.Session.html#method.run) deref to
+//! `ndarray` views and can be written as is:
+//!
+//! ```no_run
+//! # use std::error::Error;
+//! # use onnxruntime::{environment::Environment, tensor::{npy, OrtOwnedTensor}};
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # let environment = Environment::builder().build()?;
+//! # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+//! let input = npy::load_npy::<f32, _>("input.npy")?;
+//! let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run([input])?;
+//! npy::save_npy("output.npy", &*outputs[0])?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    convert::TryFrom,
+    fs::File,
+    io::{BufReader, BufWriter, Read, Seek, Write},
+    path::Path,
+};
+
+use ndarray::{ArrayBase, ArrayD, IxDyn, ShapeBuilder};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::{error::NpyError, Result};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+/// Total header length (including magic and version) is padded to a multiple of this
+const HEADER_ALIGNMENT: usize = 64;
+
+/// Element types that can be stored in `.npy` files
+pub trait NpyElement: Sized + Clone {
+    /// NumPy type string of the element, as written in headers (for example `<f4`)
+    const DESCR: &'static str;
+
+    #[doc(hidden)]
+    fn write_le<W: Write>(&self, writer: &mut W) -> std::io::Result<()>;
+
+    #[doc(hidden)]
+    fn from_bytes(bytes: &[u8], little_endian: bool) -> Self;
+}
+
+macro_rules! impl_npy_element {
+    ($type_:ty, $descr:expr) => {
+        impl NpyElement for $type_ {
+            const DESCR: &'static str = $descr;
+
+            fn write_le<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+
+            fn from_bytes(bytes: &[u8], little_endian: bool) -> Self {
+                let mut array = [0; std::mem::size_of::<$type_>()];
+                array.copy_from_slice(bytes);
+                if little_endian {
+                    <$type_>::from_le_bytes(array)
+                } else {
+                    <$type_>::from_be_bytes(array)
+                }
+            }
+        }
+    };
+}
+
+impl_npy_element!(f32, "<f4");
+impl_npy_element!(f64, "<f8");
+impl_npy_element!(u8, "|u1");
+impl_npy_element!(i8, "|i1");
+impl_npy_element!(u16, "<u2");
+impl_npy_element!(i16, "<i2");
+impl_npy_element!(u32, "<u4");
+impl_npy_element!(i32, "<i4");
+impl_npy_element!(u64, "<u8");
+impl_npy_element!(i64, "<i8");
+
+/// Write an array in the `.npy` format
+pub fn write_npy<W, S, T, D>(mut writer: W, array: &ArrayBase<S, D>) -> Result<()>
+where
+    W: Write,
+    S: ndarray::Data<Elem = T>,
+    T: NpyElement,
+    D: ndarray::Dimension,
+{
+    let shape = match array.shape() {
+        [dimension] => format!("({},)", dimension),
+        shape => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|dimension| dimension.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        T::DESCR,
+        shape
+    );
+    // Magic, version and header length take 10 bytes; the header ends with a newline
+    let unpadded_len = MAGIC.len() + 4 + header.len() + 1;
+    let padding = (HEADER_ALIGNMENT - unpadded_len % HEADER_ALIGNMENT) % HEADER_ALIGNMENT;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let header_len = u16::try_from(header.len()).map_err(|_| NpyError::HeaderTooLarge)?;
+    writer.write_all(MAGIC).map_err(NpyError::IoError)?;
+    writer.write_all(&[1, 0]).map_err(NpyError::IoError)?;
+    writer
+        .write_all(&header_len.to_le_bytes())
+        .map_err(NpyError::IoError)?;
+    writer
+        .write_all(header.as_bytes())
+        .map_err(NpyError::IoError)?;
+    // Logical iteration order is row-major, whatever the array's memory layout
+    for value in array.iter() {
+        value.write_le(&mut writer).map_err(NpyError::IoError)?;
+    }
+    writer.flush().map_err(NpyError::IoError)?;
+
+    Ok(())
+}
+
+/// Read an array in the `.npy` format
+///
+/// Both little and big endian data are supported, as well as Fortran (column-major) order.
+pub fn read_npy<R, T>(mut reader: R) -> Result<ArrayD<T>>
+where
+    R: Read,
+    T: NpyElement,
+{
+    let mut preamble = [0; 8];
+    reader
+        .read_exact(&mut preamble)
+        .map_err(NpyError::IoError)?;
+    if &preamble[..6] != MAGIC {
+        return Err(NpyError::InvalidHeader("missing magic string".to_owned()).into());
+    }
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len).map_err(NpyError::IoError)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0; 4];
+            reader.read_exact(&mut len).map_err(NpyError::IoError)?;
+            u32::from_le_bytes(len) as usize
+        }
+        version => {
+            return Err(NpyError::InvalidHeader(format!("unsupported version {}", version)).into())
+        }
+    };
+    let mut header = vec![0; header_len];
+    reader.read_exact(&mut header).map_err(NpyError::IoError)?;
+    let header = String::from_utf8(header)
+        .map_err(|_| NpyError::InvalidHeader("header is not valid UTF-8".to_owned()))?;
+    let header = Header::parse(&header)?;
+
+    let little_endian = match header.descr.as_bytes().first() {
+        Some(b'>') => false,
+        Some(b'=') => cfg!(target_endian = "little"),
+        _ => true,
+    };
+    if header.descr.get(1..) != T::DESCR.get(1..) {
+        return Err(NpyError::NonMatchingElementType {
+            descr: header.descr,
+            requested: T::DESCR,
+        }
+        .into());
+    }
+
+    let element_size = std::mem::size_of::<T>();
+    let size = header
+        .shape
+        .iter()
+        .try_fold(element_size, |size, &dim| size.checked_mul(dim))
+        .ok_or_else(|| NpyError::InvalidHeader(format!("shape {:?} overflows", header.shape)))?;
+    // The header is not trusted to size the buffer: only the bytes actually there are read
+    let mut bytes = Vec::new();
+    reader
+        .take(size as u64)
+        .read_to_end(&mut bytes)
+        .map_err(NpyError::IoError)?;
+    if bytes.len() != size {
+        return Err(NpyError::InvalidHeader(format!(
+            "shape {:?} needs {} bytes of data, only {} remain",
+            header.shape,
+            size,
+            bytes.len()
+        ))
+        .into());
+    }
+    let data = bytes
+        .chunks_exact(element_size)
+        .map(|chunk| T::from_bytes(chunk, little_endian))
+        .collect();
+
+    let shape = IxDyn(&header.shape).set_f(header.fortran_order);
+    Ok(ArrayD::from_shape_vec(shape, data).expect("Data length was computed from the shape"))
+}
+
+/// Write an array to a `.npy` file
+pub fn save_npy<P, S, T, D>(path: P, array: &ArrayBase<S, D>) -> Result<()>
+where
+    P: AsRef<Path>,
+    S: ndarray::Data<Elem = T>,
+    T: NpyElement,
+    D: ndarray::Dimension,
+{
+    let file = File::create(path).map_err(NpyError::IoError)?;
+    write_npy(BufWriter::new(file), array)
+}
+
+/// Read an array from a `.npy` file
+pub fn load_npy<T, P>(path: P) -> Result<ArrayD<T>>
+where
+    T: NpyElement,
+    P: AsRef<Path>,
+{
+    let file = File::open(path).map_err(NpyError::IoError)?;
+    read_npy(BufReader::new(file))
+}
+
+/// Fields of a `.npy` header
+#[derive(Debug, PartialEq)]
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl Header {
+    /// Parse the Python dict literal of a header, as written by NumPy
+    fn parse(header: &str) -> Result<Header> {
+        let invalid = |what: &str| NpyError::InvalidHeader(format!("{} in {:?}", what, header));
+
+        let value_of = |key: &str| -> Option<&str> {
+            let start = header.find(&format!("'{}':", key))? + key.len() + 3;
+            Some(header[start..].trim_start())
+        };
+
+        let descr = value_of("descr")
+            .and_then(|value| value.strip_prefix('\''))
+            .and_then(|value| value.split('\'').next())
+            .ok_or_else(|| invalid("missing descr"))?
+            .to_owned();
+        let fortran_order = value_of("fortran_order")
+            .map(|value| value.starts_with("True"))
+            .ok_or_else(|| invalid("missing fortran_order"))?;
+        let shape = value_of("shape")
+            .and_then(|value| value.strip_prefix('('))
+            .and_then(|value| value.split(')').next())
+            .ok_or_else(|| invalid("missing shape"))?
+            .split(',')
+            .map(str::trim)
+            .filter(|dimension| !dimension.is_empty())
+            .map(|dimension| {
+                // Python 2 era files may write dimensions as longs, for example `3L`
+                dimension
+                    .trim_end_matches('L')
+                    .parse()
+                    .map_err(|_| invalid("invalid shape"))
+            })
+            .collect::<std::result::Result<Vec<usize>, _>>()?;
+
+        Ok(Header {
+            descr,
+            fortran_order,
+            shape,
+        })
+    }
+}
+
+/// Writer of `.npz` archives, as created by `numpy.savez()` (or `numpy.savez_compressed()`)
+pub struct NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    zip: ZipWriter<W>,
+    options: FileOptions,
+}
+
+impl NpzWriter<BufWriter<File>> {
+    /// Create a `.npz` file
+    pub fn create<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::create(path).map_err(NpyError::IoError)?;
+        Ok(NpzWriter::new(BufWriter::new(file)))
+    }
+}
+
+impl<W> NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    /// Write an uncompressed archive, like `numpy.savez()`
+    pub fn new(writer: W) -> Self {
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Stored),
+        }
+    }
+
+    /// Write a compressed archive, like `numpy.savez_compressed()`
+    pub fn new_compressed(writer: W) -> Self {
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Deflated),
+        }
+    }
+
+    /// Add an array, stored as `name.npy` in the archive (and loaded as `name` by NumPy)
+    pub fn add_array<S, T, D>(&mut self, name: &str, array: &ArrayBase<S, D>) -> Result<()>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: NpyElement,
+        D: ndarray::Dimension,
+    {
+        self.zip
+            .start_file(format!("{}.npy", name), self.options)
+            .map_err(NpyError::ZipError)?;
+        write_npy(&mut self.zip, array)
+    }
+
+    /// Write the archive's directory, returning the underlying writer
+    pub fn finish(mut self) -> Result<W> {
+        Ok(self.zip.finish().map_err(NpyError::ZipError)?)
+    }
+}
+
+/// Reader of `.npz` archives
+pub struct NpzReader<R>
+where
+    R: Read + Seek,
+{
+    zip: ZipArchive<R>,
+}
+
+impl NpzReader<BufReader<File>> {
+    /// Open a `.npz` file
+    pub fn open<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).map_err(NpyError::IoError)?;
+        NpzReader::new(BufReader::new(file))
+    }
+}
+
+impl<R> NpzReader<R>
+where
+    R: Read + Seek,
+{
+    /// Read the archive's directory
+    pub fn new(reader: R) -> Result<Self> {
+        Ok(NpzReader {
+            zip: ZipArchive::new(reader).map_err(NpyError::ZipError)?,
+        })
+    }
+
+    /// Names of the arrays in the archive
+    pub fn names(&self) -> Vec<String> {
+        self.zip
+            .file_names()
+            .map(|name| name.strip_suffix(".npy").unwrap_or(name).to_owned())
+            .collect()
+    }
+
+    /// Read the array called `name`
+    pub fn by_name<T>(&mut self, name: &str) -> Result<ArrayD<T>>
+    where
+        T: NpyElement,
+    {
+        let file = self
+            .zip
+            .by_name(&format!("{}.npy", name))
+            .map_err(NpyError::ZipError)?;
+        read_npy(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr0, arr1, arr2, Array3};
+    use std::io::Cursor;
+    use test_log::test;
+
+    #[test]
+    fn npy_header_matches_numpy() {
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &arr2(&[[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]])).unwrap();
+
+        // As written by `numpy.save()` for `np.array([[1, 2, 3], [4, 5, 6]], dtype=np.float32)`
+        let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }";
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!(u16::from_le_bytes([bytes[8], bytes[9]]), 118);
+        assert_eq!(&bytes[10..10 + header.len()], header.as_bytes());
+        assert_eq!(bytes[127], b'\n');
+        assert_eq!(bytes.len(), 128 + 6 * 4);
+        assert_eq!(&bytes[128..132], &1.0_f32.to_le_bytes());
+    }
+
+    #[test]
+    fn npy_roundtrip() {
+        let array = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 100 + j * 10 + k) as i64);
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &array).unwrap();
+        let read = read_npy::<_, i64>(Cursor::new(bytes)).unwrap();
+        assert_eq!(read, array.into_dyn());
+
+        // Non standard layout is written in logical order
+        let transposed = arr2(&[[1_u8, 2], [3, 4]]).reversed_axes();
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &transposed).unwrap();
+        assert_eq!(&bytes[bytes.len() - 4..], &[1, 3, 2, 4]);
+
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &arr1(&[7_i32])).unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("'shape': (1,)"));
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &arr0(7.5_f64)).unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("'shape': ()"));
+        assert_eq!(
+            read_npy::<_, f64>(Cursor::new(bytes)).unwrap(),
+            arr0(7.5).into_dyn()
+        );
+    }
+
+    #[test]
+    fn npy_wrong_element_type() {
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &arr1(&[1.0_f32])).unwrap();
+        let read = read_npy::<_, f64>(Cursor::new(bytes));
+        assert!(matches!(
+            read,
+            Err(crate::OrtError::Npy(
+                NpyError::NonMatchingElementType { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn npy_shape_larger_than_data() {
+        let mut bytes = Vec::new();
+        write_npy(&mut bytes, &arr1(&[1.0_f32, 2.0])).unwrap();
+        bytes.truncate(bytes.len() - 1);
+        let read = read_npy::<_, f32>(Cursor::new(&bytes));
+        assert!(matches!(
+            read,
+            Err(crate::OrtError::Npy(NpyError::InvalidHeader(_)))
+        ));
+
+        let header =
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (4611686018427387904, 4), }";
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        let read = read_npy::<_, f32>(Cursor::new(&bytes));
+        assert!(matches!(
+            read,
+            Err(crate::OrtError::Npy(NpyError::InvalidHeader(_)))
+        ));
+    }
+
+    #[test]
+    fn npy_header_parse() {
+        let header =
+            Header::parse("{'descr': '>i4', 'fortran_order': True, 'shape': (3L, 2L), }").unwrap();
+        assert_eq!(
+            header,
+            Header {
+                descr: ">i4".to_owned(),
+                fortran_order: true,
+                shape: vec![3, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn npz_roundtrip() {
+        let ids = arr2(&[[1_i64, 2], [3, 4]]);
+        let scores = arr1(&[0.5_f32, 0.25]);
+        for compressed in [false, true] {
+            let cursor = Cursor::new(Vec::new());
+            let mut writer = if compressed {
+                NpzWriter::new_compressed(cursor)
+            } else {
+                NpzWriter::new(cursor)
+            };
+            writer.add_array("ids", &ids).unwrap();
+            writer.add_array("scores", &scores).unwrap();
+            let bytes = writer.finish().unwrap().into_inner();
+
+            let mut reader = NpzReader::new(Cursor::new(bytes)).unwrap();
+            let mut names = reader.names();
+            names.sort();
+            assert_eq!(names, vec!["ids", "scores"]);
+            assert_eq!(
+                reader.by_name::<i64>("ids").unwrap(),
+                ids.clone().into_dyn()
+            );
+            assert_eq!(
+                reader.by_name::<f32>("scores").unwrap(),
+                scores.clone().into_dyn()
+            );
+        }
+    }
+}
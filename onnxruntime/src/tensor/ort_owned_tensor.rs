@@ -2,13 +2,19 @@
 
 use std::{fmt::Debug, marker::PhantomData, ops::Deref};
 
-use ndarray::{Array, ArrayView};
+use ndarray::{Array, ArrayView, CowArray};
 use tracing::debug;
 
 use onnxruntime_sys as sys;
 
 use crate::{
-    error::status_to_result, g_ort, memory::MemoryInfo, tensor::ndarray_tensor::NdArrayTensor,
+    error::{status_to_result, NonMatchingDimensionsError},
+    memory::MemoryInfo,
+    session::get_tensor_dimensions_into,
+    tensor::{
+        ndarray_tensor::NdArrayTensor,
+        raw::{bf16_to_f32, f16_to_f32},
+    },
     OrtError, Result, TypeToTensorElementDataType,
 };
 
@@ -31,6 +37,15 @@ where
 {
     pub(crate) tensor_ptr: *mut sys::OrtValue,
     array_view: ArrayView<'t, T, D>,
+    /// Storage for data converted while extracting (see `OrtOwnedTensorExtractor::upcast_half_to_f32`);
+    /// when present, `array_view` points into it instead of the runtime's memory.
+    _upcast_data: Option<Vec<f32>>,
+    /// Number of elements, as reported by the runtime
+    element_count: usize,
+    /// Dimensions, as reported by the runtime
+    shape: Vec<usize>,
+    /// Row-major strides of `shape`
+    strides: Vec<isize>,
     memory_info: PhantomData<&'m MemoryInfo>,
 }
 
@@ -59,6 +74,82 @@ where
     {
         self.array_view.softmax(axis)
     }
+
+    /// Number of elements in the tensor
+    pub fn len(&self) -> usize {
+        self.element_count
+    }
+
+    /// Whether the tensor has no elements (one of its dimensions is zero)
+    pub fn is_empty(&self) -> bool {
+        self.element_count == 0
+    }
+
+    /// Dimensions of the tensor, outermost first
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Distance, in elements, between consecutive indices along each dimension
+    ///
+    /// Tensors returned by the runtime are dense and in row-major order: the last stride is 1 and
+    /// each stride is the product of the following dimensions.
+    pub fn strides(&self) -> &[isize] {
+        &self.strides
+    }
+
+    /// Flat buffer of the tensor's elements, in row-major order
+    ///
+    /// Useful to hand the data onward (for example to a socket) without going through `ndarray`.
+    /// The element type was already checked against the runtime's when the tensor was extracted;
+    /// this fails if the data is not contiguous.
+    pub fn data(&self) -> Result<&[T]> {
+        self.array_view
+            .as_slice()
+            .ok_or(OrtError::NonContiguousTensor)
+    }
+
+    /// Borrow the tensor as an [`ndarray::CowArray`](https://docs.rs/ndarray/latest/ndarray/type.CowArray.html)
+    ///
+    /// Nothing is copied until the array is written to or converted with
+    /// [`CowArray::into_owned()`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html#method.into_owned),
+    /// so code reading outputs and code keeping them past the tensor's lifetime can share a type.
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use onnxruntime::{environment::Environment, tensor::OrtOwnedTensor};
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let environment = Environment::builder().build()?;
+    /// # let mut session = environment.new_session_builder()?.with_model_from_file("model.onnx")?;
+    /// # let input = ndarray::Array::<f32, _>::zeros((1, 3, 224, 224));
+    /// let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run([input])?;
+    /// let scores = outputs[0].as_cow();
+    /// let best = scores.iter().cloned().fold(f32::MIN, f32::max);
+    /// // Keep the scores after the outputs are dropped
+    /// let scores = scores.into_owned();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_cow(&self) -> CowArray<'_, T, D> {
+        CowArray::from(self.array_view.view())
+    }
+
+    /// Serialize the tensor as an ONNX `TensorProto` message, for use with other ONNX tooling
+    ///
+    /// See the [`tensor_proto`](../tensor_proto/index.html) module.
+    pub fn to_tensor_proto(&self, name: &str) -> Vec<u8> {
+        crate::tensor::tensor_proto::to_tensor_proto(name, &self.array_view)
+    }
+}
+
+impl<'a, 't, 'm, T, D> From<&'a OrtOwnedTensor<'t, 'm, T, D>> for CowArray<'a, T, D>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    fn from(tensor: &'a OrtOwnedTensor<'t, 'm, T, D>) -> Self {
+        tensor.as_cow()
+    }
 }
 
 #[derive(Debug)]
@@ -67,6 +158,8 @@ where
     D: ndarray::Dimension,
 {
     pub(crate) tensor_ptr: *mut sys::OrtValue,
+    /// Convert `float16`/`bfloat16` data to `f32` when `f32` is requested
+    pub(crate) upcast_half_to_f32: bool,
     memory_info: PhantomData<&'m MemoryInfo>,
     shape: D,
 }
@@ -78,6 +171,7 @@ where
     pub(crate) fn new(_memory_info: &'m MemoryInfo, shape: D) -> OrtOwnedTensorExtractor<'m, D> {
         OrtOwnedTensorExtractor {
             tensor_ptr: std::ptr::null_mut(),
+            upcast_half_to_f32: false,
             memory_info: PhantomData,
             shape,
         }
@@ -93,20 +187,46 @@ where
         assert_ne!(self.tensor_ptr, std::ptr::null_mut());
 
         let mut is_tensor = 0;
-        let status = unsafe { g_ort().IsTensor.unwrap()(self.tensor_ptr, &mut is_tensor) };
+        let status = unsafe { ort_api!(IsTensor)?(self.tensor_ptr, &mut is_tensor) };
         status_to_result(status).map_err(OrtError::IsTensor)?;
         (is_tensor == 1)
             .then(|| ())
             .ok_or(OrtError::IsTensorCheck)?;
 
+        let requested_type: sys::ONNXTensorElementDataType = T::tensor_element_data_type().into();
+        let (actual_type, element_count, shape) = self.tensor_type_and_shape()?;
+        if shape != self.shape.slice() || element_count != self.shape.size() {
+            return Err(OrtError::NonMatchingDimensions(
+                NonMatchingDimensionsError::OutputShape {
+                    expected: self.shape.slice().to_vec(),
+                    actual: shape,
+                },
+            ));
+        }
+        if actual_type != requested_type {
+            return match actual_type {
+                sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16
+                | sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16
+                    if self.upcast_half_to_f32
+                        && requested_type
+                            == sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT =>
+                {
+                    self.extract_upcast(actual_type, element_count, shape)
+                }
+                _ => Err(OrtError::NonMatchingTensorElementType {
+                    requested: requested_type,
+                    actual: actual_type,
+                }),
+            };
+        }
+
         // Get pointer to output tensor float values
         let mut output_array_ptr: *mut T = std::ptr::null_mut();
         let output_array_ptr_ptr: *mut *mut T = &mut output_array_ptr;
         let output_array_ptr_ptr_void: *mut *mut std::ffi::c_void =
             output_array_ptr_ptr as *mut *mut std::ffi::c_void;
-        let status = unsafe {
-            g_ort().GetTensorMutableData.unwrap()(self.tensor_ptr, output_array_ptr_ptr_void)
-        };
+        let status =
+            unsafe { ort_api!(GetTensorMutableData)?(self.tensor_ptr, output_array_ptr_ptr_void) };
         status_to_result(status).map_err(OrtError::IsTensor)?;
         assert_ne!(output_array_ptr, std::ptr::null_mut());
 
@@ -115,9 +235,93 @@ where
         Ok(OrtOwnedTensor {
             tensor_ptr: self.tensor_ptr,
             array_view,
+            _upcast_data: None,
+            element_count,
+            strides: row_major_strides(&shape),
+            shape,
             memory_info: PhantomData,
         })
     }
+
+    /// Element type, number of elements and dimensions of the tensor
+    fn tensor_type_and_shape(&self) -> Result<(sys::ONNXTensorElementDataType, usize, Vec<usize>)> {
+        let mut tensor_info_ptr: *mut sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+        let status =
+            unsafe { ort_api!(GetTensorTypeAndShape)?(self.tensor_ptr, &mut tensor_info_ptr) };
+        status_to_result(status).map_err(OrtError::GetTensorTypeAndShape)?;
+
+        let mut type_sys = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
+        let type_status =
+            unsafe { ort_api!(GetTensorElementType)?(tensor_info_ptr, &mut type_sys) };
+        let mut element_count: usize = 0;
+        let count_status =
+            unsafe { ort_api!(GetTensorShapeElementCount)?(tensor_info_ptr, &mut element_count) };
+        let mut dims = Vec::new();
+        let dims_result = unsafe { get_tensor_dimensions_into(tensor_info_ptr, &mut dims) };
+        unsafe { ort_api!(ReleaseTensorTypeAndShapeInfo)?(tensor_info_ptr) };
+        status_to_result(type_status).map_err(OrtError::TensorElementType)?;
+        status_to_result(count_status).map_err(OrtError::GetTensorShapeElementCount)?;
+        dims_result?;
+
+        let shape = dims.iter().map(|&dim| dim as usize).collect();
+        Ok((type_sys, element_count, shape))
+    }
+
+    /// Copy 16-bit floating point data into a `f32` buffer owned by the returned tensor
+    fn extract_upcast<'t, T>(
+        self,
+        half_type: sys::ONNXTensorElementDataType,
+        element_count: usize,
+        shape: Vec<usize>,
+    ) -> Result<OrtOwnedTensor<'t, 'm, T, D>>
+    where
+        T: TypeToTensorElementDataType + Debug + Clone,
+    {
+        // `T` reports itself as `Float`, so it must be `f32` (or have its exact layout).
+        assert_eq!(std::mem::size_of::<T>(), std::mem::size_of::<f32>());
+        assert_eq!(std::mem::align_of::<T>(), std::mem::align_of::<f32>());
+
+        let mut half_data_ptr: *mut u16 = std::ptr::null_mut();
+        let status = unsafe {
+            ort_api!(GetTensorMutableData)?(
+                self.tensor_ptr,
+                &mut half_data_ptr as *mut *mut u16 as *mut *mut std::ffi::c_void,
+            )
+        };
+        status_to_result(status).map_err(OrtError::GetTensorMutableData)?;
+        assert_ne!(half_data_ptr, std::ptr::null_mut());
+
+        let half_data = unsafe { std::slice::from_raw_parts(half_data_ptr, element_count) };
+        let convert = match half_type {
+            sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 => bf16_to_f32,
+            _ => f16_to_f32,
+        };
+        let upcast_data: Vec<f32> = half_data.iter().map(|&bits| convert(bits)).collect();
+
+        // The view points into the heap buffer of `upcast_data`, which is moved (but not
+        // reallocated) into the returned tensor and never modified afterwards.
+        let array_view =
+            unsafe { ArrayView::from_shape_ptr(self.shape, upcast_data.as_ptr() as *const T) };
+
+        Ok(OrtOwnedTensor {
+            tensor_ptr: self.tensor_ptr,
+            array_view,
+            _upcast_data: Some(upcast_data),
+            element_count,
+            strides: row_major_strides(&shape),
+            shape,
+            memory_info: PhantomData,
+        })
+    }
+}
+
+/// Strides, in elements, of a dense tensor of the given shape in row-major order
+fn row_major_strides(shape: &[usize]) -> Vec<isize> {
+    let mut strides = vec![1; shape.len()];
+    for axis in (1..shape.len()).rev() {
+        strides[axis - 1] = strides[axis] * shape[axis] as isize;
+    }
+    strides
 }
 
 impl<'t, 'm, T, D> Drop for OrtOwnedTensor<'t, 'm, T, D>
@@ -129,8 +333,54 @@ where
     #[tracing::instrument]
     fn drop(&mut self) {
         debug!("Dropping OrtOwnedTensor.");
-        unsafe { g_ort().ReleaseValue.unwrap()(self.tensor_ptr) }
+        if let Ok(release) = ort_api!(ReleaseValue) {
+            unsafe { release(self.tensor_ptr) };
+        }
 
         self.tensor_ptr = std::ptr::null_mut();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        environment::{tests::CONCURRENT_TEST_RUN, Environment},
+        LoggingLevel,
+    };
+    use test_log::test;
+
+    #[test]
+    fn data_is_the_tensor_in_row_major_order() {
+        let _run_lock = CONCURRENT_TEST_RUN.single_test_run();
+        let environment = Environment::builder()
+            .with_name("data_is_the_tensor_in_row_major_order")
+            .with_log_level(LoggingLevel::Warning)
+            .build()
+            .unwrap();
+        let session = environment
+            .new_session_builder()
+            .unwrap()
+            .with_model_from_file(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests")
+                    .join("data")
+                    .join("upsample.onnx"),
+            )
+            .unwrap();
+
+        let input = Array::from_shape_fn((1, 2, 2, 3), |(_, y, x, c)| (y * 6 + x * 3 + c) as f32);
+        let outputs: Vec<OrtOwnedTensor<f32, _>> = session.run([input.clone()]).unwrap();
+        let output = &outputs[0];
+
+        let data = output.data().unwrap();
+        assert_eq!(data.len(), output.len());
+        assert_eq!(data, output.iter().copied().collect::<Vec<_>>().as_slice());
+        assert_eq!(data[..3], input.as_slice().unwrap()[..3]);
+        assert_eq!(
+            data.as_ptr(),
+            output.as_ptr(),
+            "data() should borrow the tensor rather than copy it"
+        );
+    }
+}
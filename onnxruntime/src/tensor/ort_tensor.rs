@@ -1,17 +1,20 @@
 //! Module containing tensor with memory owned by Rust
 
-use std::{ffi, fmt::Debug, marker::PhantomData, ops::Deref};
+use std::marker::PhantomData;
+#[cfg(feature = "ndarray")]
+use std::{ffi, fmt::Debug, ops::Deref};
 
+#[cfg(feature = "ndarray")]
 use ndarray::Array;
 use tracing::{debug, error};
 
 use onnxruntime_sys as sys;
 
+#[cfg(feature = "ndarray")]
+use crate::tensor::{into_input::into_standard_layout, ndarray_tensor::NdArrayTensor};
 use crate::{
-    error::{assert_not_null_pointer, call_ort, status_to_result},
-    g_ort,
+    error::{assert_not_null_pointer, status_to_result},
     memory::MemoryInfo,
-    tensor::ndarray_tensor::NdArrayTensor,
     OrtError, Result, TensorElementDataType, TypeToTensorElementDataType,
 };
 
@@ -22,6 +25,7 @@ use crate::{
 ///
 /// **NOTE**: The type is not meant to be used directly, use an [`ndarray::Array`](https://docs.rs/ndarray/latest/ndarray/type.Array.html)
 /// instead.
+#[cfg(feature = "ndarray")]
 #[derive(Debug)]
 pub struct OrtTensor<'t, T, D>
 where
@@ -33,6 +37,7 @@ where
     memory_info: PhantomData<&'t MemoryInfo>,
 }
 
+#[cfg(feature = "ndarray")]
 impl<'t, T, D> OrtTensor<'t, T, D>
 where
     T: TypeToTensorElementDataType + Debug + Clone,
@@ -41,11 +46,14 @@ where
     pub(crate) fn from_array<'m>(
         memory_info: &'m MemoryInfo,
         allocator_ptr: *mut sys::OrtAllocator,
-        mut array: Array<T, D>,
+        array: Array<T, D>,
     ) -> Result<OrtTensor<'t, T, D>>
     where
         'm: 't, // 'm outlives 't
     {
+        // The runtime reads `array.len()` elements in row-major order from the data pointer
+        let mut array = into_standard_layout(array);
+
         // where onnxruntime will write the tensor data to
         let mut tensor_ptr: *mut sys::OrtValue = std::ptr::null_mut();
         let tensor_ptr_ptr: *mut *mut sys::OrtValue = &mut tensor_ptr;
@@ -64,7 +72,9 @@ where
             | TensorElementDataType::Int64
             | TensorElementDataType::Double
             | TensorElementDataType::Uint32
-            | TensorElementDataType::Uint64 => {
+            | TensorElementDataType::Uint64
+            | TensorElementDataType::Float16
+            | TensorElementDataType::Bfloat16 => {
                 // primitive data is already suitably laid out in memory; provide it to
                 // onnxruntime as is
                 let tensor_values_ptr: *mut std::ffi::c_void =
@@ -72,37 +82,33 @@ where
                 assert_not_null_pointer(tensor_values_ptr, "TensorValues")?;
 
                 unsafe {
-                    call_ort(|ort| {
-                        ort.CreateTensorWithDataAsOrtValue.unwrap()(
-                            memory_info.ptr,
-                            tensor_values_ptr,
-                            array.len() * std::mem::size_of::<T>(),
-                            shape_ptr,
-                            shape_len,
-                            T::tensor_element_data_type().into(),
-                            tensor_ptr_ptr,
-                        )
-                    })
+                    status_to_result(ort_api!(CreateTensorWithDataAsOrtValue)?(
+                        memory_info.ptr,
+                        tensor_values_ptr,
+                        array.len() * std::mem::size_of::<T>(),
+                        shape_ptr,
+                        shape_len,
+                        T::tensor_element_data_type().into(),
+                        tensor_ptr_ptr,
+                    ))
                 }
                 .map_err(OrtError::CreateTensorWithData)?;
                 assert_not_null_pointer(tensor_ptr, "Tensor")?;
 
                 let mut is_tensor = 0;
-                let status = unsafe { g_ort().IsTensor.unwrap()(tensor_ptr, &mut is_tensor) };
+                let status = unsafe { ort_api!(IsTensor)?(tensor_ptr, &mut is_tensor) };
                 status_to_result(status).map_err(OrtError::IsTensor)?;
             }
             TensorElementDataType::String => {
                 // create tensor without data -- data is filled in later
                 unsafe {
-                    call_ort(|ort| {
-                        ort.CreateTensorAsOrtValue.unwrap()(
-                            allocator_ptr,
-                            shape_ptr,
-                            shape_len,
-                            T::tensor_element_data_type().into(),
-                            tensor_ptr_ptr,
-                        )
-                    })
+                    status_to_result(ort_api!(CreateTensorAsOrtValue)?(
+                        allocator_ptr,
+                        shape_ptr,
+                        shape_len,
+                        T::tensor_element_data_type().into(),
+                        tensor_ptr_ptr,
+                    ))
                 }
                 .map_err(OrtError::CreateTensor)?;
 
@@ -124,13 +130,11 @@ where
                     .collect::<Vec<_>>();
 
                 unsafe {
-                    call_ort(|ort| {
-                        ort.FillStringTensor.unwrap()(
-                            tensor_ptr,
-                            string_pointers.as_ptr(),
-                            string_pointers.len(),
-                        )
-                    })
+                    status_to_result(ort_api!(FillStringTensor)?(
+                        tensor_ptr,
+                        string_pointers.as_ptr(),
+                        string_pointers.len(),
+                    ))
                 }
                 .map_err(OrtError::FillStringTensor)?;
             }
@@ -146,6 +150,7 @@ where
     }
 }
 
+#[cfg(feature = "ndarray")]
 impl<'t, T, D> Deref for OrtTensor<'t, T, D>
 where
     T: TypeToTensorElementDataType + Debug + Clone,
@@ -158,6 +163,7 @@ where
     }
 }
 
+#[cfg(feature = "ndarray")]
 impl<'t, T, D> Drop for OrtTensor<'t, T, D>
 where
     T: TypeToTensorElementDataType + Debug + Clone,
@@ -170,13 +176,16 @@ where
         if self.c_ptr.is_null() {
             error!("Null pointer, not calling free.");
         } else {
-            unsafe { g_ort().ReleaseValue.unwrap()(self.c_ptr) }
+            if let Ok(release) = ort_api!(ReleaseValue) {
+                unsafe { release(self.c_ptr) };
+            }
         }
 
         self.c_ptr = std::ptr::null_mut();
     }
 }
 
+#[cfg(feature = "ndarray")]
 impl<'t, T, D> OrtTensor<'t, T, D>
 where
     T: TypeToTensorElementDataType + Debug + Clone,
@@ -192,7 +201,107 @@ where
     }
 }
 
-#[cfg(test)]
+/// Tensor over data borrowed from elsewhere (for example a memory-mapped file, or an output
+/// buffer of the caller)
+///
+/// Contrary to [`OrtTensor`](struct.OrtTensor.html), the data is not owned: the ONNX Runtime value
+/// only points to it, so it cannot outlive the borrowed slice.
+#[derive(Debug)]
+pub(crate) struct OrtTensorRef<'v> {
+    pub(crate) c_ptr: *mut sys::OrtValue,
+    data: PhantomData<&'v [u8]>,
+}
+
+impl<'v> OrtTensorRef<'v> {
+    /// Create a tensor pointing to `data`, interpreted with the given `shape`
+    ///
+    /// Only primitive element types can be borrowed; string tensors need their data copied.
+    pub(crate) fn from_slice<T>(
+        memory_info: &'v MemoryInfo,
+        data: &'v [T],
+        shape: &[usize],
+    ) -> Result<OrtTensorRef<'v>>
+    where
+        T: TypeToTensorElementDataType,
+    {
+        // The runtime never writes to input tensors; the pointer is only mutable to match the C API.
+        OrtTensorRef::from_ptr::<T>(memory_info, data.as_ptr() as *mut T, data.len(), shape)
+    }
+
+    /// Create a tensor pointing to `data`, interpreted with the given `shape`, which the runtime
+    /// may write to (as an output of a run)
+    #[cfg(feature = "ndarray")]
+    pub(crate) fn from_mut_slice<T>(
+        memory_info: &'v MemoryInfo,
+        data: &'v mut [T],
+        shape: &[usize],
+    ) -> Result<OrtTensorRef<'v>>
+    where
+        T: TypeToTensorElementDataType,
+    {
+        OrtTensorRef::from_ptr::<T>(memory_info, data.as_mut_ptr(), data.len(), shape)
+    }
+
+    fn from_ptr<T>(
+        memory_info: &'v MemoryInfo,
+        data: *mut T,
+        len: usize,
+        shape: &[usize],
+    ) -> Result<OrtTensorRef<'v>>
+    where
+        T: TypeToTensorElementDataType,
+    {
+        if let TensorElementDataType::String = T::tensor_element_data_type() {
+            return Err(OrtError::BorrowedStringTensor);
+        }
+        if shape.iter().product::<usize>() != len {
+            return Err(OrtError::InvalidDimensions);
+        }
+
+        let mut tensor_ptr: *mut sys::OrtValue = std::ptr::null_mut();
+        let shape: Vec<i64> = shape.iter().map(|d: &usize| *d as i64).collect();
+
+        let tensor_values_ptr = data as *mut std::ffi::c_void;
+        assert_not_null_pointer(tensor_values_ptr, "TensorValues")?;
+
+        unsafe {
+            status_to_result(ort_api!(CreateTensorWithDataAsOrtValue)?(
+                memory_info.ptr,
+                tensor_values_ptr,
+                len * std::mem::size_of::<T>(),
+                shape.as_ptr(),
+                shape.len(),
+                T::tensor_element_data_type().into(),
+                &mut tensor_ptr,
+            ))
+        }
+        .map_err(OrtError::CreateTensorWithData)?;
+        assert_not_null_pointer(tensor_ptr, "Tensor")?;
+
+        Ok(OrtTensorRef {
+            c_ptr: tensor_ptr,
+            data: PhantomData,
+        })
+    }
+}
+
+impl<'v> Drop for OrtTensorRef<'v> {
+    #[tracing::instrument]
+    fn drop(&mut self) {
+        debug!("Dropping borrowed Tensor.");
+        if self.c_ptr.is_null() {
+            error!("Null pointer, not calling free.");
+        } else {
+            if let Ok(release) = ort_api!(ReleaseValue) {
+                unsafe { release(self.c_ptr) };
+            }
+        }
+
+        self.c_ptr = std::ptr::null_mut();
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
 mod tests {
     use super::*;
     use crate::{AllocatorType, MemType};
@@ -265,7 +374,9 @@ mod tests {
         let mut allocator_ptr: *mut sys::OrtAllocator = std::ptr::null_mut();
         unsafe {
             // this default non-arena allocator doesn't need to be deallocated
-            call_ort(|ort| ort.GetAllocatorWithDefaultOptions.unwrap()(&mut allocator_ptr))
+            status_to_result(ort_api!(GetAllocatorWithDefaultOptions).unwrap()(
+                &mut allocator_ptr,
+            ))
         }
         .unwrap();
         allocator_ptr
@@ -0,0 +1,146 @@
+//! Module containing helpers for statically-quantized models
+//!
+//! Statically-quantized models expect (and often produce) 8-bit integer tensors obtained
+//! through an affine transform of real values. The transforms implemented here follow the
+//! ONNX [`QuantizeLinear`](https://github.com/onnx/onnx/blob/main/docs/Operators.md#QuantizeLinear)
+//! and [`DequantizeLinear`](https://github.com/onnx/onnx/blob/main/docs/Operators.md#DequantizeLinear)
+//! operators, so the scale and zero-point stored in the model can be used as is.
+
+use ndarray::{Array, ArrayBase};
+
+/// Integer types a tensor can be quantized to
+pub trait QuantizedElement: Copy {
+    /// Smallest representable value, as a float
+    const MIN: f32;
+    /// Largest representable value, as a float
+    const MAX: f32;
+
+    /// Convert an already rounded and saturated value
+    fn from_f32(value: f32) -> Self;
+
+    /// Convert to float, losslessly
+    fn to_f32(self) -> f32;
+}
+
+macro_rules! impl_quantized_element {
+    ($type_:ty) => {
+        impl QuantizedElement for $type_ {
+            const MIN: f32 = <$type_>::MIN as f32;
+            const MAX: f32 = <$type_>::MAX as f32;
+
+            fn from_f32(value: f32) -> Self {
+                value as $type_
+            }
+
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+        }
+    };
+}
+
+impl_quantized_element!(u8);
+impl_quantized_element!(i8);
+
+/// Scale and zero-point of a linear (affine) quantization
+///
+/// A real value `x` is quantized as `saturate(round(x / scale) + zero_point)`, rounding half to even,
+/// and a quantized value `q` is dequantized as `(q - zero_point) * scale`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuantizationParameters<T>
+where
+    T: QuantizedElement,
+{
+    /// Real value represented by one quantization step
+    pub scale: f32,
+    /// Quantized value representing a real zero
+    pub zero_point: T,
+}
+
+impl<T> QuantizationParameters<T>
+where
+    T: QuantizedElement,
+{
+    /// Create parameters from a model's scale and zero-point
+    pub fn new(scale: f32, zero_point: T) -> Self {
+        QuantizationParameters { scale, zero_point }
+    }
+
+    /// Quantize a single value
+    pub fn quantize_value(&self, value: f32) -> T {
+        let quantized = round_half_to_even(value / self.scale) + self.zero_point.to_f32();
+        T::from_f32(quantized.max(T::MIN).min(T::MAX))
+    }
+
+    /// Dequantize a single value
+    pub fn dequantize_value(&self, value: T) -> f32 {
+        (value.to_f32() - self.zero_point.to_f32()) * self.scale
+    }
+
+    /// Quantize an array of real values, for example before feeding it to the model
+    pub fn quantize<S, D>(&self, array: &ArrayBase<S, D>) -> Array<T, D>
+    where
+        S: ndarray::Data<Elem = f32>,
+        D: ndarray::Dimension,
+    {
+        array.map(|&value| self.quantize_value(value))
+    }
+
+    /// Dequantize an array of quantized values, for example the output of the model
+    pub fn dequantize<S, D>(&self, array: &ArrayBase<S, D>) -> Array<f32, D>
+    where
+        S: ndarray::Data<Elem = T>,
+        D: ndarray::Dimension,
+    {
+        array.map(|&value| self.dequantize_value(value))
+    }
+}
+
+/// Round to the nearest integer, with ties going to the even one (as ONNX does)
+fn round_half_to_even(value: f32) -> f32 {
+    let rounded = value.round();
+    if (value - value.trunc()).abs() == 0.5 {
+        2.0 * (value / 2.0).round()
+    } else {
+        rounded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr1;
+    use test_log::test;
+
+    #[test]
+    fn rounding_ties_to_even() {
+        assert_eq!(round_half_to_even(2.5), 2.0);
+        assert_eq!(round_half_to_even(3.5), 4.0);
+        assert_eq!(round_half_to_even(-2.5), -2.0);
+        assert_eq!(round_half_to_even(-3.5), -4.0);
+        assert_eq!(round_half_to_even(2.4), 2.0);
+        assert_eq!(round_half_to_even(-2.6), -3.0);
+    }
+
+    #[test]
+    fn quantize_u8() {
+        let params = QuantizationParameters::new(0.5, 128_u8);
+        let quantized = params.quantize(&arr1(&[0.0_f32, 1.0, -1.0, 0.25, 1000.0, -1000.0]));
+        assert_eq!(quantized, arr1(&[128_u8, 130, 126, 128, 255, 0]));
+    }
+
+    #[test]
+    fn quantize_i8() {
+        let params = QuantizationParameters::new(0.1, -3_i8);
+        let quantized = params.quantize(&arr1(&[0.0_f32, 1.0, 20.0, -20.0]));
+        assert_eq!(quantized, arr1(&[-3_i8, 7, 127, -128]));
+    }
+
+    #[test]
+    fn dequantize_roundtrip() {
+        let params = QuantizationParameters::new(0.25, 10_u8);
+        let values = arr1(&[0.0_f32, 0.25, -2.5, 5.0]);
+        let roundtrip = params.dequantize(&params.quantize(&values));
+        assert_eq!(roundtrip, values);
+    }
+}
@@ -0,0 +1,290 @@
+//! Module containing tensors as flat buffers and shapes, independent of any array library
+
+use std::fmt::Debug;
+
+#[cfg(feature = "ndarray")]
+use ndarray::ArrayD;
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::status_to_result, numa, OrtError, Result, TensorElementDataType,
+    TypeToTensorElementDataType,
+};
+
+/// Tensor as a flat buffer of elements, in row-major order, and its shape
+///
+/// Used by [`Session::run_raw()`](../../session/struct.Session.html#method.run_raw) for inputs and
+/// outputs, so that inference does not require `ndarray`: applications on another array library, or
+/// built without the `ndarray` feature, convert from and to their own types.
+///
+/// With the `ndarray` feature, converts from and to
+/// [`ndarray::ArrayD`](https://docs.rs/ndarray/latest/ndarray/type.ArrayD.html) with `From`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawTensor<T> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+}
+
+impl<T> RawTensor<T> {
+    /// Tensor of the given shape over `data`
+    ///
+    /// Fails with [`OrtError::InvalidDimensions`](../../error/enum.OrtError.html#variant.InvalidDimensions)
+    /// if the number of elements does not match the shape.
+    pub fn new(data: Vec<T>, shape: Vec<usize>) -> Result<RawTensor<T>> {
+        if shape.iter().product::<usize>() != data.len() {
+            return Err(OrtError::InvalidDimensions);
+        }
+        Ok(RawTensor { data, shape })
+    }
+
+    /// Elements of the tensor, in row-major order
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Dimensions of the tensor, outermost first
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Number of elements in the tensor
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the tensor has no elements (one of its dimensions is zero)
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Elements and shape of the tensor
+    pub fn into_parts(self) -> (Vec<T>, Vec<usize>) {
+        (self.data, self.shape)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T> From<ArrayD<T>> for RawTensor<T>
+where
+    T: Clone,
+{
+    fn from(array: ArrayD<T>) -> Self {
+        let array = crate::tensor::into_input::into_standard_layout(array);
+        let shape = array.shape().to_vec();
+        RawTensor {
+            data: array.into_raw_vec(),
+            shape,
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T> From<RawTensor<T>> for ArrayD<T> {
+    fn from(tensor: RawTensor<T>) -> Self {
+        ArrayD::from_shape_vec(tensor.shape, tensor.data)
+            .expect("Shape matches the number of elements")
+    }
+}
+
+/// Copy the data of the runtime's tensor `tensor_ptr`, of the given shape, then release the tensor
+///
+/// With `upcast_half_to_f32`, `float16`/`bfloat16` data is converted when `f32` is requested. The
+/// copy is placed on `numa_node`, if any.
+pub(crate) fn extract<T>(
+    tensor_ptr: *mut sys::OrtValue,
+    shape: &[usize],
+    upcast_half_to_f32: bool,
+    numa_node: Option<u32>,
+) -> Result<RawTensor<T>>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+{
+    let tensor = copy_data(tensor_ptr, shape, upcast_half_to_f32, numa_node);
+    if let Ok(release) = ort_api!(ReleaseValue) {
+        unsafe { release(tensor_ptr) };
+    }
+    tensor
+}
+
+fn copy_data<T>(
+    tensor_ptr: *mut sys::OrtValue,
+    shape: &[usize],
+    upcast_half_to_f32: bool,
+    numa_node: Option<u32>,
+) -> Result<RawTensor<T>>
+where
+    T: TypeToTensorElementDataType + Debug + Clone,
+{
+    // String tensors hold the runtime's own string objects, which cannot be copied as elements
+    if let TensorElementDataType::String = T::tensor_element_data_type() {
+        return Err(OrtError::RawStringTensor);
+    }
+
+    let mut tensor_info_ptr: *mut sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+    let status = unsafe { ort_api!(GetTensorTypeAndShape)?(tensor_ptr, &mut tensor_info_ptr) };
+    status_to_result(status).map_err(OrtError::GetTensorTypeAndShape)?;
+    let mut actual = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
+    let status = unsafe { ort_api!(GetTensorElementType)?(tensor_info_ptr, &mut actual) };
+    unsafe { ort_api!(ReleaseTensorTypeAndShapeInfo)?(tensor_info_ptr) };
+    status_to_result(status).map_err(OrtError::TensorElementType)?;
+
+    let requested: sys::ONNXTensorElementDataType = T::tensor_element_data_type().into();
+    let data = match actual {
+        _ if actual == requested => {
+            numa::copy_to_node(tensor_data::<T>(tensor_ptr, shape)?, numa_node)
+        }
+        sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16
+        | sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16
+            if upcast_half_to_f32
+                && requested
+                    == sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT =>
+        {
+            upcast_half(tensor_ptr, shape, actual, numa_node)?
+        }
+        _ => return Err(OrtError::NonMatchingTensorElementType { requested, actual }),
+    };
+
+    Ok(RawTensor {
+        data,
+        shape: shape.to_vec(),
+    })
+}
+
+/// Elements of the `float16`/`bfloat16` (`half_type`) tensor `tensor_ptr`, of the given shape,
+/// converted to `f32`, with `T` being `f32`
+///
+/// The buffer is placed on `numa_node`, if any.
+pub(crate) fn upcast_half<T>(
+    tensor_ptr: *mut sys::OrtValue,
+    shape: &[usize],
+    half_type: sys::ONNXTensorElementDataType,
+    numa_node: Option<u32>,
+) -> Result<Vec<T>>
+where
+    T: TypeToTensorElementDataType,
+{
+    // `T` reports itself as `Float`, so it must be `f32` (or have its exact layout).
+    assert_eq!(T::tensor_element_data_type(), TensorElementDataType::Float);
+    assert_eq!(std::mem::size_of::<T>(), std::mem::size_of::<f32>());
+    assert_eq!(std::mem::align_of::<T>(), std::mem::align_of::<f32>());
+
+    let convert = match half_type {
+        sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 => bf16_to_f32,
+        _ => f16_to_f32,
+    };
+    let half_data = tensor_data::<u16>(tensor_ptr, shape)?;
+    let mut upcast_data: Vec<f32> = numa::vec_on_node(half_data.len(), numa_node);
+    upcast_data.extend(half_data.iter().map(|&bits| convert(bits)));
+    let mut upcast_data = std::mem::ManuallyDrop::new(upcast_data);
+    Ok(unsafe {
+        Vec::from_raw_parts(
+            upcast_data.as_mut_ptr() as *mut T,
+            upcast_data.len(),
+            upcast_data.capacity(),
+        )
+    })
+}
+
+/// Elements of the runtime's tensor `tensor_ptr`, of the given shape, read as `T`
+pub(crate) fn tensor_data<'v, T>(
+    tensor_ptr: *mut sys::OrtValue,
+    shape: &[usize],
+) -> Result<&'v [T]> {
+    let element_count = shape.iter().product();
+    if element_count == 0 {
+        return Ok(&[]);
+    }
+    let mut data_ptr: *mut T = std::ptr::null_mut();
+    let status = unsafe {
+        ort_api!(GetTensorMutableData)?(
+            tensor_ptr,
+            &mut data_ptr as *mut *mut T as *mut *mut std::ffi::c_void,
+        )
+    };
+    status_to_result(status).map_err(OrtError::GetTensorMutableData)?;
+    assert_ne!(data_ptr, std::ptr::null_mut());
+    Ok(unsafe { std::slice::from_raw_parts(data_ptr, element_count) })
+}
+
+/// Convert an IEEE 754 half precision float, stored as its bits, to `f32`
+pub(crate) fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x03ff) as u32;
+
+    match exponent {
+        0 => {
+            // Zero or subnormal: mantissa * 2^-24, exactly representable in f32
+            let magnitude = mantissa as f32 / 16_777_216.0;
+            if sign == 0 {
+                magnitude
+            } else {
+                -magnitude
+            }
+        }
+        // Infinity or NaN (keeping the NaN payload)
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (mantissa << 13)),
+        // Normal number: re-bias the exponent from 15 to 127
+        _ => f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13)),
+    }
+}
+
+/// Convert a bfloat16, stored as its bits, to `f32`
+pub(crate) fn bf16_to_f32(bits: u16) -> f32 {
+    // bfloat16 is the upper half of a f32
+    f32::from_bits((bits as u32) << 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn new_checks_number_of_elements() {
+        let tensor = RawTensor::new(vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        assert_eq!(tensor.shape(), &[2, 3]);
+        assert_eq!(tensor.len(), 6);
+        assert!(matches!(
+            RawTensor::new(vec![1.0_f32, 2.0], vec![2, 3]),
+            Err(OrtError::InvalidDimensions)
+        ));
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn array_round_trip() {
+        let array = ndarray::arr2(&[[1_i64, 2], [3, 4]])
+            .reversed_axes()
+            .into_dyn();
+        let tensor = RawTensor::from(array.clone());
+        assert_eq!(tensor.data(), &[1, 3, 2, 4]);
+        assert_eq!(ArrayD::from(tensor), array);
+    }
+
+    #[test]
+    fn f16_to_f32_conversion() {
+        assert_eq!(f16_to_f32(0x0000), 0.0);
+        assert_eq!(f16_to_f32(0x8000).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(f16_to_f32(0x3c00), 1.0);
+        assert_eq!(f16_to_f32(0xc000), -2.0);
+        assert_eq!(f16_to_f32(0x3555), 1365.0 / 4096.0);
+        assert_eq!(f16_to_f32(0x7bff), 65504.0);
+        // Smallest subnormal and largest subnormal
+        assert_eq!(f16_to_f32(0x0001), 2.0_f32.powi(-24));
+        assert_eq!(f16_to_f32(0x03ff), 1023.0 * 2.0_f32.powi(-24));
+        assert_eq!(f16_to_f32(0x7c00), f32::INFINITY);
+        assert_eq!(f16_to_f32(0xfc00), f32::NEG_INFINITY);
+        assert!(f16_to_f32(0x7e00).is_nan());
+    }
+
+    #[test]
+    fn bf16_to_f32_conversion() {
+        assert_eq!(bf16_to_f32(0x0000), 0.0);
+        assert_eq!(bf16_to_f32(0x3f80), 1.0);
+        assert_eq!(bf16_to_f32(0xc040), -3.0);
+        assert_eq!(bf16_to_f32(0x7f80), f32::INFINITY);
+        assert!(bf16_to_f32(0x7fc0).is_nan());
+    }
+}
@@ -0,0 +1,140 @@
+//! Module containing export of tensors to ONNX `TensorProto` messages
+//!
+//! The serialized bytes are the ones of `onnx.numpy_helper.from_array()`: numeric elements go
+//! to `raw_data` in little endian order and strings to `string_data`. They can be loaded with
+//! `onnx.load_tensor_from_string()`, used as initializers, or stored as test fixtures (the
+//! `output_N.pb` files of the ONNX backend test data).
+//!
+//! The encoder only writes the few fields it needs, so no protobuf library is required.
+
+use std::fmt::Debug;
+
+use ndarray::ArrayBase;
+
+use crate::{TensorElementDataType, TypeToTensorElementDataType};
+
+/// Field numbers of `onnx.TensorProto`
+mod field {
+    pub(super) const DIMS: u32 = 1;
+    pub(super) const DATA_TYPE: u32 = 2;
+    pub(super) const STRING_DATA: u32 = 6;
+    pub(super) const NAME: u32 = 8;
+    pub(super) const RAW_DATA: u32 = 9;
+}
+
+const WIRE_TYPE_VARINT: u32 = 0;
+const WIRE_TYPE_LENGTH_DELIMITED: u32 = 2;
+
+/// Serialize an array as an `onnx.TensorProto` message
+///
+/// The `name` field is left out when `name` is empty.
+pub fn to_tensor_proto<S, T, D>(name: &str, array: &ArrayBase<S, D>) -> Vec<u8>
+where
+    S: ndarray::Data<Elem = T>,
+    T: TypeToTensorElementDataType + Debug + Clone,
+    D: ndarray::Dimension,
+{
+    let element_type = T::tensor_element_data_type();
+    let mut message = Vec::new();
+
+    // Fields are written in field number order, like protobuf serializers do
+    for &dimension in array.shape() {
+        write_tag(&mut message, field::DIMS, WIRE_TYPE_VARINT);
+        write_varint(&mut message, dimension as u64);
+    }
+    write_tag(&mut message, field::DATA_TYPE, WIRE_TYPE_VARINT);
+    // `TensorProto.DataType` uses the same values as ONNX Runtime's element types
+    write_varint(&mut message, element_type as u64);
+    if element_type == TensorElementDataType::String {
+        for value in array.iter() {
+            let bytes = value
+                .try_utf8_bytes()
+                .expect("String tensor elements have utf8 contents");
+            write_bytes(&mut message, field::STRING_DATA, bytes);
+        }
+    }
+    if !name.is_empty() {
+        write_bytes(&mut message, field::NAME, name.as_bytes());
+    }
+    if element_type != TensorElementDataType::String {
+        let mut raw_data = Vec::with_capacity(array.len() * std::mem::size_of::<T>());
+        // Logical iteration order is row-major, whatever the array's memory layout
+        for value in array.iter() {
+            raw_data.extend_from_slice(&element_le_bytes(value));
+        }
+        write_bytes(&mut message, field::RAW_DATA, &raw_data);
+    }
+
+    message
+}
+
+/// Little endian bytes of a numeric element
+fn element_le_bytes<T>(value: &T) -> Vec<u8> {
+    // SAFETY: non-string element types are plain numeric types, as already relied on when
+    // handing arrays over to the runtime
+    let bytes = unsafe {
+        std::slice::from_raw_parts(value as *const T as *const u8, std::mem::size_of::<T>())
+    };
+    let mut bytes = bytes.to_vec();
+    if cfg!(target_endian = "big") {
+        bytes.reverse();
+    }
+    bytes
+}
+
+fn write_tag(buffer: &mut Vec<u8>, field_number: u32, wire_type: u32) {
+    write_varint(buffer, u64::from(field_number << 3 | wire_type));
+}
+
+fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn write_bytes(buffer: &mut Vec<u8>, field_number: u32, bytes: &[u8]) {
+    write_tag(buffer, field_number, WIRE_TYPE_LENGTH_DELIMITED);
+    write_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr0, arr1, arr2};
+    use test_log::test;
+
+    #[test]
+    fn tensor_proto_float() {
+        // As serialized by `numpy_helper.from_array(np.array([[1, 2], [3, 4]], np.float32), "y")`
+        let bytes = to_tensor_proto("y", &arr2(&[[1.0_f32, 2.0], [3.0, 4.0]]));
+        let mut expected = vec![0x08, 2, 0x08, 2, 0x10, 1, 0x42, 1, b'y', 0x4a, 16];
+        for value in &[1.0_f32, 2.0, 3.0, 4.0] {
+            expected.extend_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn tensor_proto_strings_and_scalars() {
+        let bytes = to_tensor_proto("", &arr1(&["ab", "c"]));
+        assert_eq!(
+            bytes,
+            vec![0x08, 2, 0x10, 8, 0x32, 2, b'a', b'b', 0x32, 1, b'c']
+        );
+
+        let bytes = to_tensor_proto("", &arr0(300_i64));
+        let mut expected = vec![0x10, 7, 0x4a, 8];
+        expected.extend_from_slice(&300_i64.to_le_bytes());
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn varint_encoding() {
+        let mut buffer = Vec::new();
+        write_varint(&mut buffer, 300);
+        assert_eq!(buffer, vec![0xac, 0x02]);
+    }
+}
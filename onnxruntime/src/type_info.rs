@@ -0,0 +1,271 @@
+//! Module describing the type of a model's inputs and outputs, or of a value
+//!
+//! A [`TypeInfo`](enum.TypeInfo.html) is read from the runtime, either for an input or output
+//! of a session (from which [`Input`](../session/struct.Input.html) and
+//! [`Output`](../session/struct.Output.html) are built) or for a value produced by a run.
+
+use std::{convert::TryFrom, fmt};
+
+use onnxruntime_sys as sys;
+
+use crate::{
+    error::{assert_not_null_pointer, status_to_result, OrtError, Result},
+    TensorElementDataType,
+};
+
+/// Type of an input, output or value: a tensor, or a container of them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeInfo {
+    /// Dense tensor
+    Tensor(TensorInfo),
+    /// Sparse tensor
+    SparseTensor(TensorInfo),
+    /// Sequence of values, all of the given type
+    Sequence(Box<TypeInfo>),
+    /// Map from keys of a given element type to values of a given type
+    Map {
+        /// Type of the keys
+        key: TensorElementDataType,
+        /// Type of the values
+        value: Box<TypeInfo>,
+    },
+    /// Value of the given type, which may be missing
+    Optional(Box<TypeInfo>),
+    /// Opaque type, defined by a custom operator for example
+    Opaque,
+}
+
+/// Element type and shape of a tensor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorInfo {
+    /// Type of the tensor's elements, `None` for the types this crate does not support
+    /// (booleans and complex numbers)
+    pub element_type: Option<TensorElementDataType>,
+    /// Shape of the tensor, with `None` for dynamic dimensions
+    ///
+    /// Empty for scalars, and for tensors whose shape is not known at all.
+    pub dimensions: Vec<Option<u32>>,
+}
+
+impl TypeInfo {
+    /// Tensor information, if the type is a dense tensor
+    pub fn tensor(&self) -> Option<&TensorInfo> {
+        match self {
+            TypeInfo::Tensor(tensor) => Some(tensor),
+            _ => None,
+        }
+    }
+
+    /// Read the type of a value
+    ///
+    /// # Safety
+    ///
+    /// `value` must point to a valid value.
+    pub(crate) unsafe fn from_value(value: *const sys::OrtValue) -> Result<TypeInfo> {
+        let mut typeinfo_ptr: *mut sys::OrtTypeInfo = std::ptr::null_mut();
+        let status = ort_api!(GetTypeInfo)?(value, &mut typeinfo_ptr);
+        status_to_result(status).map_err(OrtError::GetTypeInfo)?;
+        let typeinfo = OwnedTypeInfo::new(typeinfo_ptr)?;
+        TypeInfo::from_ptr(typeinfo.0)
+    }
+
+    /// Read the type of the session's input or output at `index`, with `f` being
+    /// `SessionGetInputTypeInfo` or `SessionGetOutputTypeInfo`
+    ///
+    /// # Safety
+    ///
+    /// `session_ptr` must point to a valid session, with an input or output at `index`.
+    pub(crate) unsafe fn from_session(
+        f: extern_system_fn! { unsafe fn(
+            *const sys::OrtSession,
+            usize,
+            *mut *mut sys::OrtTypeInfo,
+        ) -> *mut sys::OrtStatus },
+        session_ptr: *const sys::OrtSession,
+        index: usize,
+    ) -> Result<TypeInfo> {
+        let mut typeinfo_ptr: *mut sys::OrtTypeInfo = std::ptr::null_mut();
+        let status = f(session_ptr, index, &mut typeinfo_ptr);
+        status_to_result(status).map_err(OrtError::GetTypeInfo)?;
+        let typeinfo = OwnedTypeInfo::new(typeinfo_ptr)?;
+        TypeInfo::from_ptr(typeinfo.0)
+    }
+
+    /// Read `typeinfo_ptr`, which stays owned by the caller
+    unsafe fn from_ptr(typeinfo_ptr: *const sys::OrtTypeInfo) -> Result<TypeInfo> {
+        let mut onnx_type = sys::ONNXType::ONNX_TYPE_UNKNOWN;
+        let status = ort_api!(GetOnnxTypeFromTypeInfo)?(typeinfo_ptr, &mut onnx_type);
+        status_to_result(status).map_err(OrtError::GetOnnxType)?;
+
+        Ok(match onnx_type {
+            sys::ONNXType::ONNX_TYPE_TENSOR => {
+                TypeInfo::Tensor(TensorInfo::from_type_info(typeinfo_ptr)?)
+            }
+            sys::ONNXType::ONNX_TYPE_SPARSETENSOR => {
+                TypeInfo::SparseTensor(TensorInfo::from_type_info(typeinfo_ptr)?)
+            }
+            sys::ONNXType::ONNX_TYPE_SEQUENCE => {
+                let mut sequence_ptr: *const sys::OrtSequenceTypeInfo = std::ptr::null();
+                let status =
+                    ort_api!(CastTypeInfoToSequenceTypeInfo)?(typeinfo_ptr, &mut sequence_ptr);
+                status_to_result(status).map_err(OrtError::ContainedTypeInfo)?;
+                assert_not_null_pointer(sequence_ptr, "SequenceTypeInfo")?;
+
+                let mut element_ptr: *mut sys::OrtTypeInfo = std::ptr::null_mut();
+                let status = ort_api!(GetSequenceElementType)?(sequence_ptr, &mut element_ptr);
+                status_to_result(status).map_err(OrtError::ContainedTypeInfo)?;
+                let element = OwnedTypeInfo::new(element_ptr)?;
+                TypeInfo::Sequence(Box::new(TypeInfo::from_ptr(element.0)?))
+            }
+            sys::ONNXType::ONNX_TYPE_MAP => {
+                let mut map_ptr: *const sys::OrtMapTypeInfo = std::ptr::null();
+                let status = ort_api!(CastTypeInfoToMapTypeInfo)?(typeinfo_ptr, &mut map_ptr);
+                status_to_result(status).map_err(OrtError::ContainedTypeInfo)?;
+                assert_not_null_pointer(map_ptr, "MapTypeInfo")?;
+
+                let mut key_sys =
+                    sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
+                let status = ort_api!(GetMapKeyType)?(map_ptr, &mut key_sys);
+                status_to_result(status).map_err(OrtError::ContainedTypeInfo)?;
+                let key = TensorElementDataType::from_sys(key_sys)
+                    .ok_or(OrtError::UndefinedTensorElementType)?;
+
+                let mut value_ptr: *mut sys::OrtTypeInfo = std::ptr::null_mut();
+                let status = ort_api!(GetMapValueType)?(map_ptr, &mut value_ptr);
+                status_to_result(status).map_err(OrtError::ContainedTypeInfo)?;
+                let value = OwnedTypeInfo::new(value_ptr)?;
+                TypeInfo::Map {
+                    key,
+                    value: Box::new(TypeInfo::from_ptr(value.0)?),
+                }
+            }
+            sys::ONNXType::ONNX_TYPE_OPTIONAL => {
+                let mut optional_ptr: *const sys::OrtOptionalTypeInfo = std::ptr::null();
+                let status =
+                    ort_api!(CastTypeInfoToOptionalTypeInfo)?(typeinfo_ptr, &mut optional_ptr);
+                status_to_result(status).map_err(OrtError::ContainedTypeInfo)?;
+                assert_not_null_pointer(optional_ptr, "OptionalTypeInfo")?;
+
+                let mut contained_ptr: *mut sys::OrtTypeInfo = std::ptr::null_mut();
+                let status =
+                    ort_api!(GetOptionalContainedTypeInfo)?(optional_ptr, &mut contained_ptr);
+                status_to_result(status).map_err(OrtError::ContainedTypeInfo)?;
+                let contained = OwnedTypeInfo::new(contained_ptr)?;
+                TypeInfo::Optional(Box::new(TypeInfo::from_ptr(contained.0)?))
+            }
+            sys::ONNXType::ONNX_TYPE_OPAQUE | sys::ONNXType::ONNX_TYPE_UNKNOWN => TypeInfo::Opaque,
+        })
+    }
+}
+
+impl TensorInfo {
+    unsafe fn from_type_info(typeinfo_ptr: *const sys::OrtTypeInfo) -> Result<TensorInfo> {
+        // Owned by the type info, not to be released
+        let mut tensor_info_ptr: *const sys::OrtTensorTypeAndShapeInfo = std::ptr::null();
+        let status = ort_api!(CastTypeInfoToTensorInfo)?(typeinfo_ptr, &mut tensor_info_ptr);
+        status_to_result(status).map_err(OrtError::CastTypeInfoToTensorInfo)?;
+        assert_not_null_pointer(tensor_info_ptr, "TensorInfo")?;
+        TensorInfo::from_ptr(tensor_info_ptr)
+    }
+
+    unsafe fn from_ptr(
+        tensor_info_ptr: *const sys::OrtTensorTypeAndShapeInfo,
+    ) -> Result<TensorInfo> {
+        let mut type_sys = sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
+        let status = ort_api!(GetTensorElementType)?(tensor_info_ptr, &mut type_sys);
+        status_to_result(status).map_err(OrtError::TensorElementType)?;
+
+        let mut num_dims = 0;
+        let status = ort_api!(GetDimensionsCount)?(tensor_info_ptr, &mut num_dims);
+        status_to_result(status).map_err(OrtError::GetDimensionsCount)?;
+        let mut node_dims: Vec<i64> = vec![0; num_dims];
+        let status = ort_api!(GetDimensions)?(tensor_info_ptr, node_dims.as_mut_ptr(), num_dims);
+        status_to_result(status).map_err(OrtError::GetDimensions)?;
+
+        Ok(TensorInfo {
+            element_type: TensorElementDataType::from_sys(type_sys),
+            // Dynamic dimensions are reported as -1
+            dimensions: node_dims
+                .into_iter()
+                .map(|d| u32::try_from(d).ok())
+                .collect(),
+        })
+    }
+}
+
+impl fmt::Display for TypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeInfo::Tensor(tensor) => write!(f, "{}", tensor),
+            TypeInfo::SparseTensor(tensor) => write!(f, "sparse({})", tensor),
+            TypeInfo::Sequence(element) => write!(f, "seq({})", element),
+            TypeInfo::Map { key, value } => write!(f, "map({}, {})", key, value),
+            TypeInfo::Optional(contained) => write!(f, "optional({})", contained),
+            TypeInfo::Opaque => f.write_str("opaque"),
+        }
+    }
+}
+
+impl fmt::Display for TensorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.element_type {
+            Some(element_type) => write!(f, "{}[", element_type)?,
+            None => f.write_str("unsupported[")?,
+        }
+        for (i, dimension) in self.dimensions.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            match dimension {
+                Some(dimension) => write!(f, "{}", dimension)?,
+                None => f.write_str("?")?,
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+/// Type info returned by the runtime, released when dropped
+struct OwnedTypeInfo(*mut sys::OrtTypeInfo);
+
+impl OwnedTypeInfo {
+    fn new(ptr: *mut sys::OrtTypeInfo) -> Result<OwnedTypeInfo> {
+        assert_not_null_pointer(ptr, "TypeInfo")?;
+        Ok(OwnedTypeInfo(ptr))
+    }
+}
+
+impl Drop for OwnedTypeInfo {
+    fn drop(&mut self) {
+        if let Ok(release) = ort_api!(ReleaseTypeInfo) {
+            unsafe { release(self.0) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn display_nested_types() {
+        let tensor = TensorInfo {
+            element_type: Some(TensorElementDataType::Float),
+            dimensions: vec![None, Some(3)],
+        };
+        assert_eq!(tensor.to_string(), "float[?, 3]");
+
+        let map = TypeInfo::Map {
+            key: TensorElementDataType::Int64,
+            value: Box::new(TypeInfo::Tensor(TensorInfo {
+                element_type: None,
+                dimensions: vec![],
+            })),
+        };
+        let sequence = TypeInfo::Sequence(Box::new(map));
+        assert_eq!(sequence.to_string(), "seq(map(int64, unsupported[]))");
+        assert!(sequence.tensor().is_none());
+        assert_eq!(TypeInfo::Tensor(tensor.clone()).tensor(), Some(&tensor));
+    }
+}
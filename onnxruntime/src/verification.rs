@@ -0,0 +1,217 @@
+//! Module containing checks run on models before loading them
+//!
+//! A [`Verifier`](trait.Verifier.html) registered with
+//! [`SessionBuilder::with_model_verification()`](../session/struct.SessionBuilder.html#method.with_model_verification)
+//! sees the model's bytes before the runtime parses them, and can refuse to load the model, for
+//! example because its signature does not match. With the `ed25519` feature,
+//! [`Ed25519Verifier`](struct.Ed25519Verifier.html) checks detached Ed25519 signatures.
+//!
+//! Models loaded from a file are read to memory, verified, then loaded from these same bytes, so
+//! that the file cannot be swapped between the check and the load. Weights stored in external
+//! data files are still looked for next to the model (or in the directory given with
+//! [`SessionBuilder::with_external_data_dir()`](../session/struct.SessionBuilder.html#method.with_external_data_dir)),
+//! but they are not seen by the verifier.
+
+use std::{error::Error, fmt, path::Path};
+
+use crate::error::{OrtError, Result};
+
+/// Error returned by a [`Verifier`](trait.Verifier.html) refusing a model
+pub type VerificationError = Box<dyn Error + Send + Sync>;
+
+/// Information about a model being verified, besides its bytes
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct ModelMetadata<'m> {
+    /// File the model was read from, if loaded from a file
+    pub path: Option<&'m Path>,
+}
+
+/// Check run on a model's bytes before creating the session
+///
+/// Implemented for closures taking the bytes and the metadata.
+pub trait Verifier {
+    /// Accept the model, or refuse it with the reason why
+    fn verify(
+        &self,
+        model_bytes: &[u8],
+        metadata: &ModelMetadata<'_>,
+    ) -> std::result::Result<(), VerificationError>;
+}
+
+impl<F> Verifier for F
+where
+    F: Fn(&[u8], &ModelMetadata<'_>) -> std::result::Result<(), VerificationError>,
+{
+    fn verify(
+        &self,
+        model_bytes: &[u8],
+        metadata: &ModelMetadata<'_>,
+    ) -> std::result::Result<(), VerificationError> {
+        self(model_bytes, metadata)
+    }
+}
+
+/// Verifier held by the session builder
+pub(crate) struct ModelVerifier(Box<dyn Verifier>);
+
+impl ModelVerifier {
+    pub(crate) fn new<V>(verifier: V) -> ModelVerifier
+    where
+        V: Verifier + 'static,
+    {
+        ModelVerifier(Box::new(verifier))
+    }
+
+    pub(crate) fn verify(&self, model_bytes: &[u8], path: Option<&Path>) -> Result<()> {
+        self.0
+            .verify(model_bytes, &ModelMetadata { path })
+            .map_err(OrtError::ModelVerification)
+    }
+}
+
+impl fmt::Debug for ModelVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ModelVerifier")
+    }
+}
+
+/// Verifier checking a detached Ed25519 signature of the model
+///
+/// The signature is the one given with [`with_signature()`](#method.with_signature), or else the
+/// 64 bytes of the file named after the model with a `.sig` suffix (`model.onnx.sig` for
+/// `model.onnx`). The model is accepted if the signature matches any of the trusted public keys,
+/// so that keys can be rotated.
+#[cfg(feature = "ed25519")]
+#[derive(Debug, Clone)]
+pub struct Ed25519Verifier {
+    public_keys: Vec<[u8; 32]>,
+    signature: Option<Vec<u8>>,
+}
+
+#[cfg(feature = "ed25519")]
+impl Ed25519Verifier {
+    /// Verifier trusting `public_key`
+    pub fn new(public_key: [u8; 32]) -> Ed25519Verifier {
+        Ed25519Verifier {
+            public_keys: vec![public_key],
+            signature: None,
+        }
+    }
+
+    /// Trust `public_key` too
+    pub fn with_public_key(mut self, public_key: [u8; 32]) -> Ed25519Verifier {
+        self.public_keys.push(public_key);
+        self
+    }
+
+    /// Check `signature` rather than the one from the `.sig` file, for example for models
+    /// loaded from memory
+    pub fn with_signature<S>(mut self, signature: S) -> Ed25519Verifier
+    where
+        S: Into<Vec<u8>>,
+    {
+        self.signature = Some(signature.into());
+        self
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl Verifier for Ed25519Verifier {
+    fn verify(
+        &self,
+        model_bytes: &[u8],
+        metadata: &ModelMetadata<'_>,
+    ) -> std::result::Result<(), VerificationError> {
+        use ring::signature::{UnparsedPublicKey, ED25519};
+
+        let signature = match (&self.signature, metadata.path) {
+            (Some(signature), _) => signature.clone(),
+            (None, Some(path)) => {
+                let mut signature_path = path.as_os_str().to_owned();
+                signature_path.push(".sig");
+                std::fs::read(&signature_path).map_err(|err| {
+                    format!("Failed to read signature {:?}: {}", signature_path, err)
+                })?
+            }
+            (None, None) => return Err("No signature given for a model loaded from memory".into()),
+        };
+
+        let trusted = self.public_keys.iter().any(|public_key| {
+            UnparsedPublicKey::new(&ED25519, public_key)
+                .verify(model_bytes, &signature)
+                .is_ok()
+        });
+        if trusted {
+            Ok(())
+        } else {
+            Err("Model signature does not match any trusted key".into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn model_verifier_closure() {
+        let verifier = ModelVerifier::new(
+            |model_bytes: &[u8],
+             _: &ModelMetadata<'_>|
+             -> std::result::Result<(), VerificationError> {
+                if model_bytes.starts_with(b"\x08") {
+                    Ok(())
+                } else {
+                    Err("Not an ONNX model".into())
+                }
+            },
+        );
+        assert!(verifier.verify(b"\x08\x07", None).is_ok());
+        assert!(matches!(
+            verifier.verify(b"MZ", None),
+            Err(OrtError::ModelVerification(_))
+        ));
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn ed25519_verifier() {
+        use std::io::Write;
+
+        fn from_hex(hex: &str) -> Vec<u8> {
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                .collect()
+        }
+
+        // RFC 8032, test 2
+        let mut public_key = [0; 32];
+        public_key.copy_from_slice(&from_hex(
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        ));
+        let signature = from_hex(
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+             085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        );
+        let model_bytes = [0x72];
+
+        let verifier = Ed25519Verifier::new([0; 32]).with_public_key(public_key);
+        let in_memory = ModelMetadata { path: None };
+        assert!(verifier.verify(&model_bytes, &in_memory).is_err());
+        let signed = verifier.clone().with_signature(signature.clone());
+        assert!(signed.verify(&model_bytes, &in_memory).is_ok());
+        assert!(signed.verify(&[0x73], &in_memory).is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let model_path = dir.path().join("model.onnx");
+        let mut signature_file = std::fs::File::create(dir.path().join("model.onnx.sig")).unwrap();
+        signature_file.write_all(&signature).unwrap();
+        let from_file = ModelMetadata {
+            path: Some(&model_path),
+        };
+        assert!(verifier.verify(&model_bytes, &from_file).is_ok());
+    }
+}
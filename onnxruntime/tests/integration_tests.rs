@@ -18,7 +18,7 @@ mod download {
     use onnxruntime::{
         download::vision::{DomainBasedImageClassification, ImageClassification},
         environment::Environment,
-        GraphOptimizationLevel, LoggingLevel,
+        GraphOptimizationLevel, LoggingLevel, TensorElementDataType,
     };
 
     #[test]
@@ -31,7 +31,7 @@ mod download {
             .build()
             .unwrap();
 
-        let mut session = environment
+        let session = environment
             .new_session_builder()
             .unwrap()
             .with_optimization_level(GraphOptimizationLevel::Basic)
@@ -142,7 +142,7 @@ mod download {
             .build()
             .unwrap();
 
-        let mut session = environment
+        let session = environment
             .new_session_builder()
             .unwrap()
             .with_optimization_level(GraphOptimizationLevel::Basic)
@@ -241,7 +241,7 @@ mod download {
             .build()
             .unwrap();
 
-        let mut session = environment
+        let session = environment
             .new_session_builder()
             .unwrap()
             .with_optimization_level(GraphOptimizationLevel::Basic)
@@ -264,6 +264,15 @@ mod download {
             session.outputs[0].dimensions().collect::<Vec<_>>(),
             [None, None, None, Some(3)]
         );
+        let output_name = session.outputs[0].name.clone();
+        assert_eq!(
+            session.output_dtype(&output_name).unwrap(),
+            TensorElementDataType::Float
+        );
+        assert_eq!(session.output_rank(&output_name).unwrap(), Some(4));
+        assert!(session.output_rank("missing").is_err());
+        assert!(session.check_output_types::<f32>().is_ok());
+        assert!(session.check_output_types::<i64>().is_err());
 
         // Load image, converting to RGB format
         let image_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = image::open(